@@ -0,0 +1,51 @@
+//! Generates the objects of the compile-time benchmark, see `src/lib.rs`.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+fn env_usize(name: &str, default: usize) -> usize {
+    println!("cargo:rerun-if-env-changed={}", name);
+    env::var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+fn main() {
+    let objects = env_usize("LARGE_OBJECT_COUNT", 10);
+    let fields = env_usize("LARGE_OBJECT_FIELDS", 100);
+
+    let mut code = String::new();
+    for object in 0..objects {
+        writeln!(code, "pub struct Object{};", object).unwrap();
+        writeln!(code, "#[Object]").unwrap();
+        writeln!(code, "impl Object{} {{", object).unwrap();
+        for field in 0..fields {
+            writeln!(
+                code,
+                "    async fn field{}(&self, a: i32, #[graphql(default = 1)] b: i32) -> i32 {{ a + b + {} }}",
+                field, field
+            )
+            .unwrap();
+        }
+        writeln!(code, "}}").unwrap();
+    }
+
+    writeln!(code, "pub struct QueryRoot;").unwrap();
+    writeln!(code, "#[Object]").unwrap();
+    writeln!(code, "impl QueryRoot {{").unwrap();
+    for object in 0..objects {
+        writeln!(
+            code,
+            "    async fn object{}(&self) -> Object{} {{ Object{} }}",
+            object, object, object
+        )
+        .unwrap();
+    }
+    writeln!(code, "}}").unwrap();
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("objects.rs"), code).unwrap();
+}
@@ -0,0 +1,17 @@
+//! A compile-time benchmark of objects with many fields.
+//!
+//! `LARGE_OBJECT_COUNT` objects (10 by default) with `LARGE_OBJECT_FIELDS` fields each (100 by
+//! default) are generated by the build script. Time the build of this crate once its
+//! dependencies are built:
+//!
+//! ```shell
+//! cargo build && touch src/lib.rs && time cargo build
+//! ```
+
+use async_graphql::*;
+
+include!(concat!(env!("OUT_DIR"), "/objects.rs"));
+
+pub fn schema() -> Schema<QueryRoot, EmptyMutation, EmptySubscription> {
+    Schema::new(QueryRoot, EmptyMutation, EmptySubscription)
+}
@@ -1,3 +1,7 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::Path;
+
 use darling::ast::{Data, Fields};
 use darling::util::Ignored;
 use darling::{FromDeriveInput, FromField, FromMeta, FromVariant};
@@ -44,6 +48,12 @@ impl FromMeta for DefaultValue {
     }
 }
 
+#[derive(FromMeta)]
+pub struct MetaEntry {
+    pub key: String,
+    pub value: String,
+}
+
 #[derive(FromField)]
 #[darling(attributes(graphql), forward_attrs(doc))]
 pub struct SimpleObjectField {
@@ -70,6 +80,10 @@ pub struct SimpleObjectField {
     pub requires: Option<String>,
     #[darling(default)]
     pub guard: Option<Meta>,
+    #[darling(default)]
+    pub list_concurrency: Option<usize>,
+    #[darling(default, multiple, rename = "meta")]
+    pub meta: Vec<MetaEntry>,
 }
 
 #[derive(FromDeriveInput)]
@@ -94,6 +108,15 @@ pub struct SimpleObject {
     pub cache_control: CacheControl,
     #[darling(default)]
     pub extends: bool,
+    #[darling(default)]
+    pub guard: Option<Meta>,
+    #[darling(default, multiple, rename = "meta")]
+    pub meta: Vec<MetaEntry>,
+}
+
+/// The pairs of attributes that can't be used together, `*` stands for any other attribute.
+pub trait ConflictingAttrs {
+    const CONFLICTS: &'static [(&'static str, &'static str)];
 }
 
 #[derive(FromMeta, Default)]
@@ -103,10 +126,23 @@ pub struct Argument {
     pub desc: Option<String>,
     pub default: Option<DefaultValue>,
     pub default_with: Option<LitStr>,
+    pub default_with_ctx: Option<LitStr>,
     pub validator: Option<Meta>,
+    pub custom_validator: Option<LitStr>,
+    pub secret: bool,
+    pub sibling: Option<String>,
     pub key: bool, // for entity
 }
 
+impl ConflictingAttrs for Argument {
+    const CONFLICTS: &'static [(&'static str, &'static str)] = &[
+        ("sibling", "*"),
+        ("default", "default_with"),
+        ("default_with_ctx", "default"),
+        ("default_with_ctx", "default_with"),
+    ];
+}
+
 #[derive(FromMeta, Default)]
 #[darling(default)]
 pub struct Object {
@@ -117,6 +153,20 @@ pub struct Object {
     pub cache_control: CacheControl,
     pub extends: bool,
     pub use_type_description: bool,
+    pub guard: Option<Meta>,
+    #[darling(multiple, rename = "meta")]
+    pub meta: Vec<MetaEntry>,
+}
+
+#[derive(FromMeta)]
+pub struct Retry {
+    pub attempts: usize,
+    #[darling(default)]
+    pub backoff: Option<String>,
+    #[darling(default)]
+    pub delay: Option<u64>,
+    #[darling(default)]
+    pub predicate: Option<syn::Path>,
 }
 
 #[derive(FromMeta, Default)]
@@ -131,6 +181,15 @@ pub struct ObjectField {
     pub provides: Option<String>,
     pub requires: Option<String>,
     pub guard: Option<Meta>,
+    pub list_concurrency: Option<usize>,
+    pub retry: Option<Retry>,
+    pub fallback: Option<LitStr>,
+    #[darling(multiple, rename = "meta")]
+    pub meta: Vec<MetaEntry>,
+}
+
+impl ConflictingAttrs for ObjectField {
+    const CONFLICTS: &'static [(&'static str, &'static str)] = &[("skip", "*"), ("entity", "*")];
 }
 
 #[derive(FromDeriveInput)]
@@ -149,6 +208,8 @@ pub struct Enum {
     pub rename_items: Option<RenameRule>,
     #[darling(default)]
     pub remote: Option<String>,
+    #[darling(default, multiple, rename = "meta")]
+    pub meta: Vec<MetaEntry>,
 }
 
 #[derive(FromVariant)]
@@ -161,7 +222,15 @@ pub struct EnumItem {
     #[darling(default)]
     pub name: Option<String>,
     #[darling(default)]
+    pub value: Option<String>,
+    #[darling(default, multiple, rename = "alias")]
+    pub aliases: Vec<String>,
+    #[darling(default)]
     pub deprecation: Option<String>,
+    #[darling(default)]
+    pub fallback: bool,
+    #[darling(default, multiple, rename = "meta")]
+    pub meta: Vec<MetaEntry>,
 }
 
 #[derive(FromDeriveInput)]
@@ -176,6 +245,12 @@ pub struct Union {
     pub internal: bool,
     #[darling(default)]
     pub name: Option<String>,
+    #[darling(default, multiple, rename = "meta")]
+    pub meta: Vec<MetaEntry>,
+    #[darling(default)]
+    pub from_result: bool,
+    #[darling(default)]
+    pub from_either: bool,
 }
 
 #[derive(FromVariant)]
@@ -206,6 +281,10 @@ pub struct InputObjectField {
     pub validator: Option<Meta>,
     #[darling(default)]
     pub flatten: bool,
+    #[darling(default)]
+    pub secret: bool,
+    #[darling(default, multiple, rename = "meta")]
+    pub meta: Vec<MetaEntry>,
 }
 
 #[derive(FromDeriveInput)]
@@ -222,6 +301,10 @@ pub struct InputObject {
     pub name: Option<String>,
     #[darling(default)]
     pub rename_fields: Option<RenameRule>,
+    #[darling(default)]
+    pub validator: Option<syn::Path>,
+    #[darling(default, multiple, rename = "meta")]
+    pub meta: Vec<MetaEntry>,
 }
 
 #[derive(FromMeta)]
@@ -256,6 +339,10 @@ pub struct InterfaceField {
     pub provides: Option<String>,
     #[darling(default)]
     pub requires: Option<String>,
+    #[darling(default, multiple, rename = "meta")]
+    pub meta: Vec<MetaEntry>,
+    #[darling(default)]
+    pub object_resolver: bool,
 }
 
 #[derive(FromVariant)]
@@ -283,7 +370,11 @@ pub struct Interface {
     #[darling(default, multiple, rename = "field")]
     pub fields: Vec<InterfaceField>,
     #[darling(default)]
+    pub auto_fields: bool,
+    #[darling(default)]
     pub extends: bool,
+    #[darling(default, multiple, rename = "meta")]
+    pub meta: Vec<MetaEntry>,
 }
 
 #[derive(FromMeta, Default)]
@@ -292,6 +383,7 @@ pub struct Scalar {
     pub internal: bool,
     pub name: Option<String>,
     pub use_type_description: bool,
+    pub specified_by_url: Option<String>,
 }
 
 #[derive(FromMeta, Default)]
@@ -302,6 +394,7 @@ pub struct Subscription {
     pub rename_fields: Option<RenameRule>,
     pub rename_args: Option<RenameRule>,
     pub use_type_description: bool,
+    pub guard: Option<Meta>,
 }
 
 #[derive(FromMeta, Default)]
@@ -311,7 +404,17 @@ pub struct SubscriptionFieldArgument {
     pub desc: Option<String>,
     pub default: Option<DefaultValue>,
     pub default_with: Option<LitStr>,
+    pub default_with_ctx: Option<LitStr>,
     pub validator: Option<Meta>,
+    pub custom_validator: Option<LitStr>,
+}
+
+impl ConflictingAttrs for SubscriptionFieldArgument {
+    const CONFLICTS: &'static [(&'static str, &'static str)] = &[
+        ("default", "default_with"),
+        ("default_with_ctx", "default"),
+        ("default_with_ctx", "default_with"),
+    ];
 }
 
 #[derive(FromMeta, Default)]
@@ -323,6 +426,10 @@ pub struct SubscriptionField {
     pub guard: Option<Meta>,
 }
 
+impl ConflictingAttrs for SubscriptionField {
+    const CONFLICTS: &'static [(&'static str, &'static str)] = &[("skip", "*")];
+}
+
 #[derive(FromField)]
 pub struct MergedObjectField {
     pub ident: Option<Ident>,
@@ -342,9 +449,11 @@ pub struct MergedObject {
     #[darling(default)]
     pub name: Option<String>,
     #[darling(default)]
-    pub cache_control: CacheControl,
+    pub cache_control: Option<CacheControl>,
     #[darling(default)]
     pub extends: bool,
+    #[darling(default)]
+    pub guard: Option<Meta>,
 }
 
 #[derive(FromField)]
@@ -377,6 +486,8 @@ pub enum RenameRule {
     Pascal,
     #[darling(rename = "camelCase")]
     Camel,
+    #[darling(rename = "camelCase_acronyms")]
+    CamelAcronyms,
     #[darling(rename = "snake_case")]
     Snake,
     #[darling(rename = "SCREAMING_SNAKE_CASE")]
@@ -390,12 +501,72 @@ impl RenameRule {
             Self::Upper => name.as_ref().to_uppercase(),
             Self::Pascal => name.as_ref().to_pascal_case(),
             Self::Camel => name.as_ref().to_camel_case(),
+            Self::CamelAcronyms => to_camel_case_acronyms(name.as_ref()),
             Self::Snake => name.as_ref().to_snake_case(),
             Self::ScreamingSnake => name.as_ref().to_screaming_snake_case(),
         }
     }
 }
 
+/// Acronyms that are kept uppercase by `camelCase_acronyms`, in addition to the words that are
+/// already written in uppercase.
+const ACRONYMS: &[&str] = &[
+    "API", "CPU", "CSS", "CSV", "DNS", "GPU", "HTML", "HTTP", "HTTPS", "ID", "IP", "JSON", "JWT",
+    "PDF", "SQL", "SSH", "TCP", "TLS", "UDP", "URI", "URL", "UTC", "UUID", "XML",
+];
+
+/// Split a name into words at underscores and case changes, e.g. `URLPath` and `url_path` are
+/// both split into `URL` and `Path`/`path`.
+fn split_words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+
+    for (idx, c) in chars.iter().copied().enumerate() {
+        if c == '_' || c == '-' {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+
+        if c.is_uppercase() && !word.is_empty() {
+            let prev = chars[idx - 1];
+            let next_is_lower = matches!(chars.get(idx + 1), Some(c) if c.is_lowercase());
+            if !prev.is_uppercase() || next_is_lower {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+        word.push(c);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+/// Convert a name to camelCase, keeping acronyms uppercase: `user_id` becomes `userID` and
+/// `URLPath` becomes `urlPath` (instead of `userId` and `uRLPath`).
+fn to_camel_case_acronyms(name: &str) -> String {
+    let mut output = String::new();
+    for (idx, word) in split_words(name).into_iter().enumerate() {
+        let is_acronym = (word.len() > 1 && word.chars().all(|c| !c.is_lowercase()))
+            || ACRONYMS.contains(&word.to_uppercase().as_str());
+        if idx == 0 {
+            output.push_str(&word.to_lowercase());
+        } else if is_acronym {
+            output.push_str(&word.to_uppercase());
+        } else {
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                output.extend(first.to_uppercase());
+                output.push_str(&chars.as_str().to_lowercase());
+            }
+        }
+    }
+    output
+}
+
 #[derive(Debug, Copy, Clone)]
 pub enum RenameTarget {
     Type,
@@ -404,13 +575,90 @@ pub enum RenameTarget {
     Argument,
 }
 
+/// The default rename rules of the crate being compiled, set in its manifest:
+///
+/// ```toml
+/// [package.metadata.async-graphql]
+/// rename_fields = "camelCase_acronyms"
+/// rename_args = "camelCase_acronyms"
+/// rename_items = "SCREAMING_SNAKE_CASE"
+/// ```
+#[derive(Debug, Default, Copy, Clone)]
+struct DefaultRenameRules {
+    fields: Option<RenameRule>,
+    args: Option<RenameRule>,
+    items: Option<RenameRule>,
+}
+
+impl DefaultRenameRules {
+    fn get() -> Self {
+        thread_local! {
+            static RULES: RefCell<HashMap<String, DefaultRenameRules>> = Default::default();
+        }
+
+        let manifest_dir = match std::env::var("CARGO_MANIFEST_DIR") {
+            Ok(manifest_dir) => manifest_dir,
+            Err(_) => return Self::default(),
+        };
+        RULES.with(|rules| {
+            let mut rules = rules.borrow_mut();
+            match rules.get(&manifest_dir) {
+                Some(defaults) => *defaults,
+                None => {
+                    let defaults = Self::read(&manifest_dir);
+                    rules.insert(manifest_dir, defaults);
+                    defaults
+                }
+            }
+        })
+    }
+
+    fn read(manifest_dir: &str) -> Self {
+        let path = Path::new(manifest_dir).join("Cargo.toml");
+        let manifest = match std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|manifest| manifest.parse::<toml::Value>().ok())
+        {
+            Some(manifest) => manifest,
+            None => return Self::default(),
+        };
+        let table = manifest
+            .get("package")
+            .and_then(|package| package.get("metadata"))
+            .and_then(|metadata| metadata.get("async-graphql"));
+
+        let rule = |key: &str| {
+            let value = table?.get(key)?;
+            let rule = match value.as_str() {
+                Some(value) => RenameRule::from_string(value).map_err(|err| err.to_string()),
+                None => Err(format!("expected a string, found {}", value)),
+            };
+            match rule {
+                Ok(rule) => Some(rule),
+                Err(err) => panic!(
+                    "Invalid `{}` in the `package.metadata.async-graphql` table of {}: {}",
+                    key,
+                    path.display(),
+                    err
+                ),
+            }
+        };
+        Self {
+            fields: rule("rename_fields"),
+            args: rule("rename_args"),
+            items: rule("rename_items"),
+        }
+    }
+}
+
 impl RenameTarget {
     fn rule(&self) -> RenameRule {
+        let defaults = DefaultRenameRules::get();
         match self {
             RenameTarget::Type => RenameRule::Pascal,
-            RenameTarget::EnumItem => RenameRule::ScreamingSnake,
-            RenameTarget::Field => RenameRule::Camel,
-            RenameTarget::Argument => RenameRule::Camel,
+            RenameTarget::EnumItem => defaults.items.unwrap_or(RenameRule::ScreamingSnake),
+            RenameTarget::Field => defaults.fields.unwrap_or(RenameRule::Camel),
+            RenameTarget::Argument => defaults.args.unwrap_or(RenameRule::Camel),
         }
     }
 
@@ -1,11 +1,11 @@
-use darling::ast::Data;
+use darling::ast::{Data, Style};
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::ext::IdentExt;
 use syn::Error;
 
 use crate::args::{self, RenameRuleExt, RenameTarget};
-use crate::utils::{get_crate_name, get_rustdoc, GeneratorResult};
+use crate::utils::{generate_metadata, get_crate_name, get_rustdoc, GeneratorResult};
 
 pub fn generate(enum_args: &args::Enum) -> GeneratorResult<TokenStream> {
     let crate_name = get_crate_name(enum_args.internal);
@@ -19,16 +19,42 @@ pub fn generate(enum_args: &args::Enum) -> GeneratorResult<TokenStream> {
         .name
         .clone()
         .unwrap_or_else(|| RenameTarget::Type.rename(ident.to_string()));
+    let metadata = generate_metadata(&crate_name, None, &enum_args.meta);
 
     let desc = get_rustdoc(&enum_args.attrs)?
-        .map(|s| quote! { ::std::option::Option::Some(#s) })
+        .map(|s| quote! { ::std::option::Option::Some(::std::string::ToString::to_string(#s)) })
         .unwrap_or_else(|| quote! {::std::option::Option::None});
 
     let mut enum_items = Vec::new();
+    let mut enum_item_names = Vec::new();
+    let mut enum_item_patterns = Vec::new();
+    let mut accepted_names = Vec::new();
     let mut items = Vec::new();
     let mut schema_enum_items = Vec::new();
+    let mut fallback = None;
 
     for variant in e {
+        if variant.fallback {
+            if fallback.is_some() {
+                return Err(Error::new_spanned(
+                    &variant.ident,
+                    "Only one variant can be the fallback.",
+                )
+                .into());
+            }
+            let carries_value = match variant.fields.style {
+                Style::Unit => false,
+                Style::Tuple if variant.fields.len() == 1 => true,
+                _ => return Err(Error::new_spanned(
+                    &variant.ident,
+                    "The fallback variant must be a unit variant or have a single unnamed field.",
+                )
+                .into()),
+            };
+            fallback = Some((&variant.ident, carries_value));
+            continue;
+        }
+
         if !variant.fields.is_empty() {
             return Err(Error::new_spanned(
                 &variant.ident,
@@ -41,11 +67,33 @@ pub fn generate(enum_args: &args::Enum) -> GeneratorResult<TokenStream> {
         }
 
         let item_ident = &variant.ident;
-        let gql_item_name = variant.name.clone().unwrap_or_else(|| {
-            enum_args
-                .rename_items
-                .rename(variant.ident.unraw().to_string(), RenameTarget::EnumItem)
-        });
+        if variant.name.is_some() && variant.value.is_some() {
+            return Err(Error::new_spanned(
+                item_ident,
+                "The `name` and `value` of an enum item cannot be both specified.",
+            )
+            .into());
+        }
+        let gql_item_name = variant
+            .value
+            .clone()
+            .or_else(|| variant.name.clone())
+            .unwrap_or_else(|| {
+                enum_args
+                    .rename_items
+                    .rename(variant.ident.unraw().to_string(), RenameTarget::EnumItem)
+            });
+        for name in std::iter::once(&gql_item_name).chain(&variant.aliases) {
+            if accepted_names.contains(name) {
+                return Err(Error::new_spanned(
+                    item_ident,
+                    format!("The enum value \"{}\" is used by more than one item.", name),
+                )
+                .into());
+            }
+            accepted_names.push(name.clone());
+        }
+        let aliases = &variant.aliases;
         let item_deprecation = variant
             .deprecation
             .as_ref()
@@ -56,22 +104,36 @@ pub fn generate(enum_args: &args::Enum) -> GeneratorResult<TokenStream> {
             .unwrap_or_else(|| quote! {::std::option::Option::None});
 
         enum_items.push(item_ident);
+        enum_item_names.push(gql_item_name.clone());
+        enum_item_patterns.push(quote! { #gql_item_name #(| #aliases)* });
         items.push(quote! {
             #crate_name::resolver_utils::EnumItem {
                 name: #gql_item_name,
+                aliases: &[#(#aliases),*],
                 value: #ident::#item_ident,
             }
         });
+        let metadata = generate_metadata(&crate_name, Some(gql_item_name.as_str()), &variant.meta);
         schema_enum_items.push(quote! {
+            #metadata
             enum_items.insert(#gql_item_name, #crate_name::registry::MetaEnumValue {
                 name: #gql_item_name,
                 description: #item_desc,
                 deprecation: #item_deprecation,
+                aliases: &[#(#aliases),*],
             });
         });
     }
 
     let remote_conversion = if let Some(remote) = &enum_args.remote {
+        if let Some((fallback_ident, _)) = fallback {
+            return Err(Error::new_spanned(
+                fallback_ident,
+                "A remote enum cannot have a fallback variant.",
+            )
+            .into());
+        }
+
         let remote_ty = if let Ok(ty) = syn::parse_str::<syn::Type>(remote) {
             ty
         } else {
@@ -119,14 +181,8 @@ pub fn generate(enum_args: &args::Enum) -> GeneratorResult<TokenStream> {
         .into());
     }
 
-    let expanded = quote! {
-        #[allow(clippy::all, clippy::pedantic)]
-        impl #crate_name::resolver_utils::EnumType for #ident {
-            fn items() -> &'static [#crate_name::resolver_utils::EnumItem<#ident>] {
-                &[#(#items),*]
-            }
-        }
-
+    let has_fallback = fallback.is_some();
+    let type_impl = quote! {
         #[allow(clippy::all, clippy::pedantic)]
         impl #crate_name::Type for #ident {
             fn type_name() -> ::std::borrow::Cow<'static, ::std::primitive::str> {
@@ -135,6 +191,7 @@ pub fn generate(enum_args: &args::Enum) -> GeneratorResult<TokenStream> {
 
             fn create_type_info(registry: &mut #crate_name::registry::Registry) -> ::std::string::String {
                 registry.create_type::<Self, _>(|registry| {
+                    #metadata
                     #crate_name::registry::MetaType::Enum {
                         name: ::std::borrow::ToOwned::to_owned(#gql_typename),
                         description: #desc,
@@ -143,29 +200,110 @@ pub fn generate(enum_args: &args::Enum) -> GeneratorResult<TokenStream> {
                             #(#schema_enum_items)*
                             enum_items
                         },
+                        has_fallback: #has_fallback,
                     }
                 })
             }
         }
+    };
 
-        #[allow(clippy::all, clippy::pedantic)]
-        impl #crate_name::InputValueType for #ident {
-            fn parse(value: ::std::option::Option<#crate_name::Value>) -> #crate_name::InputValueResult<Self> {
-                #crate_name::resolver_utils::parse_enum(value.unwrap_or_default())
+    let value_impls = match fallback {
+        None => quote! {
+            #[allow(clippy::all, clippy::pedantic)]
+            impl #crate_name::resolver_utils::EnumType for #ident {
+                fn items() -> &'static [#crate_name::resolver_utils::EnumItem<#ident>] {
+                    &[#(#items),*]
+                }
+            }
+
+            #[allow(clippy::all, clippy::pedantic)]
+            impl #crate_name::InputValueType for #ident {
+                fn parse(value: ::std::option::Option<#crate_name::Value>) -> #crate_name::InputValueResult<Self> {
+                    #crate_name::resolver_utils::parse_enum(value.unwrap_or_default())
+                }
+
+                fn to_value(&self) -> #crate_name::Value {
+                    #crate_name::resolver_utils::enum_value(*self)
+                }
             }
 
-            fn to_value(&self) -> #crate_name::Value {
-                #crate_name::resolver_utils::enum_value(*self)
+            #[#crate_name::async_trait::async_trait(?Send)]
+            impl #crate_name::OutputValueType for #ident {
+                async fn resolve(&self, _: &#crate_name::ContextSelectionSet<'_>, _field: &#crate_name::Positioned<#crate_name::parser::types::Field>) -> #crate_name::ServerResult<#crate_name::Value> {
+                    ::std::result::Result::Ok(#crate_name::resolver_utils::enum_value(*self))
+                }
             }
-        }
+        },
+        Some((fallback_ident, carries_value)) => {
+            let (parse_fallback, output_fallback, to_value_fallback) = if carries_value {
+                (
+                    quote! { name => #ident::#fallback_ident(::std::convert::From::from(name)) },
+                    quote! {
+                        #ident::#fallback_ident(name) => ::std::result::Result::Ok(
+                            #crate_name::Value::Enum(#crate_name::Name::new(name)),
+                        )
+                    },
+                    quote! {
+                        #ident::#fallback_ident(name) => #crate_name::Value::Enum(#crate_name::Name::new(name))
+                    },
+                )
+            } else {
+                let message = format!(
+                    r#"Cannot output the fallback value of enum "{}"."#,
+                    gql_typename
+                );
+                (
+                    quote! { _ => #ident::#fallback_ident },
+                    quote! {
+                        #ident::#fallback_ident => ::std::result::Result::Err(
+                            #crate_name::ServerError::new(#message).at(field.pos),
+                        )
+                    },
+                    quote! { #ident::#fallback_ident => #crate_name::Value::Null },
+                )
+            };
 
-        #[#crate_name::async_trait::async_trait(?Send)]
-        impl #crate_name::OutputValueType for #ident {
-            async fn resolve(&self, _: &#crate_name::ContextSelectionSet<'_>, _field: &#crate_name::Positioned<#crate_name::parser::types::Field>) -> #crate_name::ServerResult<#crate_name::Value> {
-                ::std::result::Result::Ok(#crate_name::resolver_utils::enum_value(*self))
+            quote! {
+                #[allow(clippy::all, clippy::pedantic)]
+                impl #crate_name::InputValueType for #ident {
+                    fn parse(value: ::std::option::Option<#crate_name::Value>) -> #crate_name::InputValueResult<Self> {
+                        let value = value.unwrap_or_default();
+                        let name = match &value {
+                            #crate_name::Value::Enum(name) => name.as_str(),
+                            #crate_name::Value::String(name) => name.as_str(),
+                            _ => return ::std::result::Result::Err(#crate_name::InputValueError::expected_type(value)),
+                        };
+                        ::std::result::Result::Ok(match name {
+                            #(#enum_item_patterns => #ident::#enum_items,)*
+                            #parse_fallback,
+                        })
+                    }
+
+                    fn to_value(&self) -> #crate_name::Value {
+                        match self {
+                            #(#ident::#enum_items => #crate_name::Value::Enum(#crate_name::Name::new(#enum_item_names)),)*
+                            #to_value_fallback,
+                        }
+                    }
+                }
+
+                #[allow(clippy::all, clippy::pedantic)]
+                #[#crate_name::async_trait::async_trait(?Send)]
+                impl #crate_name::OutputValueType for #ident {
+                    async fn resolve(&self, _: &#crate_name::ContextSelectionSet<'_>, field: &#crate_name::Positioned<#crate_name::parser::types::Field>) -> #crate_name::ServerResult<#crate_name::Value> {
+                        match self {
+                            #(#ident::#enum_items => ::std::result::Result::Ok(#crate_name::Value::Enum(#crate_name::Name::new(#enum_item_names))),)*
+                            #output_fallback,
+                        }
+                    }
+                }
             }
         }
+    };
 
+    let expanded = quote! {
+        #type_impl
+        #value_impls
         #remote_conversion
     };
     Ok(expanded.into())
@@ -6,7 +6,8 @@ use syn::Error;
 
 use crate::args::{self, RenameRuleExt, RenameTarget};
 use crate::utils::{
-    generate_default, generate_validator, get_crate_name, get_rustdoc, GeneratorResult,
+    generate_default, generate_metadata, generate_validator, get_crate_name, get_rustdoc,
+    GeneratorResult,
 };
 
 pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream> {
@@ -41,9 +42,10 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
         .name
         .clone()
         .unwrap_or_else(|| RenameTarget::Type.rename(ident.to_string()));
+    let metadata = generate_metadata(&crate_name, None, &object_args.meta);
 
     let desc = get_rustdoc(&object_args.attrs)?
-        .map(|s| quote! { ::std::option::Option::Some(#s) })
+        .map(|s| quote! { ::std::option::Option::Some(::std::string::ToString::to_string(#s)) })
         .unwrap_or_else(|| quote! {::std::option::Option::None});
 
     let mut get_fields = Vec::new();
@@ -104,9 +106,9 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
             .as_ref()
             .map(|value| {
                 quote! {
-                    ::std::option::Option::Some(::std::string::ToString::to_string(
-                        &<#ty as #crate_name::InputValueType>::to_value(&#value)
-                    ))
+                    ::std::option::Option::Some(
+                        <#ty as #crate_name::InputValueType>::to_value(&#value).to_graphql_literal()
+                    )
                 }
             })
             .unwrap_or_else(|| quote!(::std::option::Option::None));
@@ -118,7 +120,7 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
                     match obj.get(#name) {
                         ::std::option::Option::Some(value) => {
                             #crate_name::InputValueType::parse(::std::option::Option::Some(::std::clone::Clone::clone(&value)))
-                                .map_err(#crate_name::InputValueError::propagate)?
+                                .map_err(|err| err.propagate_field(#name))?
                         },
                         ::std::option::Option::None => #default,
                     }
@@ -128,7 +130,7 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
             get_fields.push(quote! {
                 #[allow(non_snake_case)]
                 let #ident: #ty = #crate_name::InputValueType::parse(obj.get(#name).cloned())
-                    .map_err(#crate_name::InputValueError::propagate)?;
+                    .map_err(|err| err.propagate_field(#name))?;
             });
         }
 
@@ -140,17 +142,27 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
         });
 
         fields.push(ident);
+        let metadata = generate_metadata(&crate_name, Some(name.as_str()), &field.meta);
+        let secret = field.secret;
         schema_fields.push(quote! {
+            #metadata
             fields.insert(::std::borrow::ToOwned::to_owned(#name), #crate_name::registry::MetaInputValue {
                 name: #name,
                 description: #desc,
                 ty: <#ty as #crate_name::Type>::create_type_info(registry),
                 default_value: #schema_default,
                 validator: #validator,
+                is_secret: #secret,
             });
         })
     }
 
+    let validate = object_args.validator.as_ref().map(|validator| {
+        quote! {
+            #validator(&input_object)?;
+        }
+    });
+
     if get_fields.is_empty() {
         return Err(Error::new_spanned(
             &ident,
@@ -171,6 +183,7 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
                     name: ::std::borrow::ToOwned::to_owned(#gql_typename),
                     description: #desc,
                     input_fields: {
+                        #metadata
                         let mut fields = #crate_name::indexmap::IndexMap::new();
                         #(#schema_fields)*
                         fields
@@ -184,14 +197,16 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
             fn parse(value: ::std::option::Option<#crate_name::Value>) -> #crate_name::InputValueResult<Self> {
                 if let ::std::option::Option::Some(#crate_name::Value::Object(obj)) = value {
                     #(#get_fields)*
-                    ::std::result::Result::Ok(Self { #(#fields),* })
+                    let input_object = Self { #(#fields),* };
+                    #validate
+                    ::std::result::Result::Ok(input_object)
                 } else {
                     ::std::result::Result::Err(#crate_name::InputValueError::expected_type(value.unwrap_or_default()))
                 }
             }
 
             fn to_value(&self) -> #crate_name::Value {
-                let mut map = ::std::collections::BTreeMap::new();
+                let mut map = #crate_name::Map::new();
                 #(#put_fields)*
                 #crate_name::Value::Object(map)
             }
@@ -8,7 +8,9 @@ use syn::{visit_mut, Error, Lifetime, Type};
 
 use crate::args::{self, InterfaceField, InterfaceFieldArgument, RenameRuleExt, RenameTarget};
 use crate::output_type::OutputType;
-use crate::utils::{generate_default, get_crate_name, get_rustdoc, GeneratorResult};
+use crate::utils::{
+    generate_default, generate_metadata, get_crate_name, get_rustdoc, GeneratorResult,
+};
 
 pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream> {
     let crate_name = get_crate_name(interface_args.internal);
@@ -30,9 +32,10 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
         .name
         .clone()
         .unwrap_or_else(|| RenameTarget::Type.rename(ident.to_string()));
+    let metadata = generate_metadata(&crate_name, None, &interface_args.meta);
 
     let desc = get_rustdoc(&interface_args.attrs)?
-        .map(|s| quote! { ::std::option::Option::Some(#s) })
+        .map(|s| quote! { ::std::option::Option::Some(::std::string::ToString::to_string(#s)) })
         .unwrap_or_else(|| quote! {::std::option::Option::None});
 
     let mut registry_types = Vec::new();
@@ -121,7 +124,7 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
     let mut schema_fields = Vec::new();
     let mut resolvers = Vec::new();
 
-    if interface_args.fields.is_empty() {
+    if interface_args.fields.is_empty() && !interface_args.auto_fields {
         return Err(Error::new_spanned(
             &ident,
             "A GraphQL Interface type must define one or more fields.",
@@ -139,6 +142,8 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
         external,
         provides,
         requires,
+        meta,
+        object_resolver,
     } in &interface_args.fields
     {
         let (name, method_name) = if let Some(method) = method {
@@ -209,9 +214,9 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
                 .as_ref()
                 .map(|value| {
                     quote! {
-                        ::std::option::Option::Some(::std::string::ToString::to_string(
-                            &<#ty as #crate_name::InputValueType>::to_value(&#value)
-                        ))
+                        ::std::option::Option::Some(
+                            <#ty as #crate_name::InputValueType>::to_value(&#value).to_graphql_literal()
+                        )
                     }
                 })
                 .unwrap_or_else(|| quote! {::std::option::Option::None});
@@ -222,6 +227,7 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
                     ty: <#ty as #crate_name::Type>::create_type_info(registry),
                     default_value: #schema_default,
                     validator: ::std::option::Option::None,
+                    is_secret: false,
                 });
             });
         }
@@ -248,16 +254,9 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
         };
         let schema_ty = oty.value_type();
 
-        methods.push(quote! {
-            #[inline]
-            pub async fn #method_name<'ctx>(&self, #(#decl_params),*) -> #crate_name::Result<#ty> {
-                match self {
-                    #(#calls,)*
-                }
-            }
-        });
-
-        schema_fields.push(quote! {
+        let metadata = generate_metadata(&crate_name, Some(name.as_str()), meta);
+        let schema_field = quote! {
+            #metadata
             fields.insert(::std::string::ToString::to_string(#name), #crate_name::registry::MetaField {
                 name: ::std::string::ToString::to_string(#name),
                 description: #desc,
@@ -273,8 +272,36 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
                 provides: #provides,
                 requires: #requires,
             });
+        };
+
+        if *object_resolver {
+            // The field is resolved by the concrete object, its signature is checked against the
+            // registry when the schema is built.
+            schema_fields.push(quote! {
+                #schema_field
+                registry.add_object_resolved_field(#gql_typename, #name);
+            });
+            resolvers.push(quote! {
+                if ctx.item.node.name.node == #name {
+                    return match self {
+                        #(#ident::#enum_names(obj) => #crate_name::resolver_utils::ContainerType::resolve_field(obj, ctx).await,)*
+                    };
+                }
+            });
+            continue;
+        }
+
+        methods.push(quote! {
+            #[inline]
+            pub async fn #method_name<'ctx>(&self, #(#decl_params),*) -> #crate_name::Result<#ty> {
+                match self {
+                    #(#calls,)*
+                }
+            }
         });
 
+        schema_fields.push(schema_field);
+
         let resolve_obj = quote! {
             self.#method_name(#(#use_params),*)
                 .await
@@ -290,6 +317,23 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
         });
     }
 
+    // The fields added when the schema is built are resolved by the objects.
+    let (auto_fields, resolve_other_field) = if interface_args.auto_fields {
+        (
+            Some(quote! { registry.add_auto_fields_interface(#gql_typename); }),
+            quote! {
+                match self {
+                    #(#ident::#enum_names(obj) => #crate_name::resolver_utils::ContainerType::resolve_field(obj, ctx).await,)*
+                }
+            },
+        )
+    } else {
+        (
+            None,
+            quote! { ::std::result::Result::Ok(::std::option::Option::None) },
+        )
+    };
+
     let introspection_type_name = if get_introspection_typename.is_empty() {
         quote! { ::std::unreachable!() }
     } else {
@@ -321,6 +365,8 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
             fn create_type_info(registry: &mut #crate_name::registry::Registry) -> ::std::string::String {
                 registry.create_type::<Self, _>(|registry| {
                     #(#registry_types)*
+                    #metadata
+                    #auto_fields
 
                     #crate_name::registry::MetaType::Interface {
                         name: ::std::string::ToString::to_string(#gql_typename),
@@ -347,7 +393,7 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
         impl #generics #crate_name::resolver_utils::ContainerType for #ident #generics {
             async fn resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::ServerResult<::std::option::Option<#crate_name::Value>> {
                 #(#resolvers)*
-                ::std::result::Result::Ok(::std::option::Option::None)
+                #resolve_other_field
             }
 
             fn collect_all_fields<'__life>(&'__life self, ctx: &#crate_name::ContextSelectionSet<'__life>, fields: &mut #crate_name::resolver_utils::Fields<'__life>) -> #crate_name::ServerResult<()> {
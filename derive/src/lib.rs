@@ -15,13 +15,15 @@ mod output_type;
 mod scalar;
 mod simple_object;
 mod subscription;
+mod trusted_documents;
 mod union;
 mod utils;
+mod validate_query;
 
 use darling::{FromDeriveInput, FromMeta};
 use proc_macro::TokenStream;
 use syn::parse_macro_input;
-use syn::{AttributeArgs, DeriveInput, ItemImpl};
+use syn::{AttributeArgs, DeriveInput, ItemImpl, LitStr};
 
 #[proc_macro_attribute]
 #[allow(non_snake_case)]
@@ -32,7 +34,7 @@ pub fn Object(args: TokenStream, input: TokenStream) -> TokenStream {
     };
     let mut item_impl = parse_macro_input!(input as ItemImpl);
     match object::generate(&object_args, &mut item_impl) {
-        Ok(expanded) => expanded,
+        Ok(expanded) => utils::depend_on_manifest(expanded),
         Err(err) => err.write_errors().into(),
     }
 }
@@ -45,7 +47,7 @@ pub fn derive_simple_object(input: TokenStream) -> TokenStream {
             Err(err) => return TokenStream::from(err.write_errors()),
         };
     match simple_object::generate(&object_args) {
-        Ok(expanded) => expanded,
+        Ok(expanded) => utils::depend_on_manifest(expanded),
         Err(err) => err.write_errors().into(),
     }
 }
@@ -57,7 +59,7 @@ pub fn derive_enum(input: TokenStream) -> TokenStream {
         Err(err) => return TokenStream::from(err.write_errors()),
     };
     match r#enum::generate(&enum_args) {
-        Ok(expanded) => expanded,
+        Ok(expanded) => utils::depend_on_manifest(expanded),
         Err(err) => err.write_errors().into(),
     }
 }
@@ -70,7 +72,7 @@ pub fn derive_input_object(input: TokenStream) -> TokenStream {
             Err(err) => return TokenStream::from(err.write_errors()),
         };
     match input_object::generate(&object_args) {
-        Ok(expanded) => expanded,
+        Ok(expanded) => utils::depend_on_manifest(expanded),
         Err(err) => err.write_errors().into(),
     }
 }
@@ -83,7 +85,7 @@ pub fn derive_interface(input: TokenStream) -> TokenStream {
             Err(err) => return TokenStream::from(err.write_errors()),
         };
     match interface::generate(&interface_args) {
-        Ok(expanded) => expanded,
+        Ok(expanded) => utils::depend_on_manifest(expanded),
         Err(err) => err.write_errors().into(),
     }
 }
@@ -111,7 +113,7 @@ pub fn Subscription(args: TokenStream, input: TokenStream) -> TokenStream {
         };
     let mut item_impl = parse_macro_input!(input as ItemImpl);
     match subscription::generate(&object_args, &mut item_impl) {
-        Ok(expanded) => expanded,
+        Ok(expanded) => utils::depend_on_manifest(expanded),
         Err(err) => err.write_errors().into(),
     }
 }
@@ -169,3 +171,21 @@ pub fn derive_merged_description(input: TokenStream) -> TokenStream {
         Err(err) => err.write_errors().into(),
     }
 }
+
+#[proc_macro]
+pub fn include_trusted_documents(input: TokenStream) -> TokenStream {
+    let dir = parse_macro_input!(input as LitStr);
+    match trusted_documents::generate(&dir) {
+        Ok(expanded) => expanded,
+        Err(err) => err.write_errors().into(),
+    }
+}
+
+#[proc_macro]
+pub fn validate_query(input: TokenStream) -> TokenStream {
+    let query = parse_macro_input!(input as LitStr);
+    match validate_query::generate(&query) {
+        Ok(expanded) => expanded,
+        Err(err) => err.write_errors().into(),
+    }
+}
@@ -1,25 +1,23 @@
 use darling::ast::Data;
 use proc_macro::TokenStream;
-use proc_macro2::Span;
 use quote::quote;
-use syn::{Error, LitInt};
+use syn::{parse_quote, Error, Index, WhereClause};
 
 use crate::args::{self, RenameTarget};
-use crate::utils::{get_crate_name, get_rustdoc, GeneratorResult};
+use crate::utils::{generate_guards, get_crate_name, get_rustdoc, GeneratorResult};
 
 pub fn generate(object_args: &args::MergedObject) -> GeneratorResult<TokenStream> {
     let crate_name = get_crate_name(object_args.internal);
     let ident = &object_args.ident;
     let extends = object_args.extends;
     let generics = &object_args.generics;
-    let where_clause = &generics.where_clause;
     let gql_typename = object_args
         .name
         .clone()
         .unwrap_or_else(|| RenameTarget::Type.rename(ident.to_string()));
 
     let desc = get_rustdoc(&object_args.attrs)?
-        .map(|s| quote! { ::std::option::Option::Some(#s) })
+        .map(|s| quote! { ::std::option::Option::Some(::std::string::ToString::to_string(#s)) })
         .unwrap_or_else(|| quote! {::std::option::Option::None});
 
     let s = match &object_args.data {
@@ -31,58 +29,106 @@ pub fn generate(object_args: &args::MergedObject) -> GeneratorResult<TokenStream
         }
     };
 
-    let mut types = Vec::new();
-    for field in &s.fields {
-        types.push(&field.ty);
+    // Each part is resolved in turn instead of nesting them into a list of `MergedObject`s, so
+    // the size of the generated code grows linearly with the number of parts.
+    let mut create_parts = Vec::new();
+    let mut resolve_fields = Vec::new();
+    let mut find_entities = Vec::new();
+    let mut where_clause = generics
+        .where_clause
+        .clone()
+        .unwrap_or_else(|| WhereClause {
+            where_token: Default::default(),
+            predicates: Default::default(),
+        });
+    for (idx, field) in s.fields.iter().enumerate() {
+        let ty = &field.ty;
+        let member = match &field.ident {
+            Some(ident) => quote!(#ident),
+            None => {
+                let n = Index::from(idx);
+                quote!(#n)
+            }
+        };
+
+        create_parts.push(quote! {
+            parts.push((
+                ::std::any::type_name::<#ty>(),
+                registry.create_dummy_type::<#ty>(),
+            ));
+        });
+        resolve_fields.push(quote! {
+            if let ::std::option::Option::Some(value) =
+                #crate_name::resolver_utils::ContainerType::resolve_field(&self.#member, ctx).await?
+            {
+                return ::std::result::Result::Ok(::std::option::Option::Some(value));
+            }
+        });
+        find_entities.push(quote! {
+            if let ::std::option::Option::Some(value) =
+                #crate_name::resolver_utils::ContainerType::find_entity(&self.#member, ctx, params).await?
+            {
+                return ::std::result::Result::Ok(::std::option::Option::Some(value));
+            }
+        });
+        where_clause
+            .predicates
+            .push(parse_quote!(#ty: #crate_name::ObjectType));
     }
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
 
-    let create_merged_obj = {
-        let mut obj = quote! { #crate_name::MergedObjectTail };
-        for i in 0..types.len() {
-            let n = LitInt::new(&format!("{}", i), Span::call_site());
-            obj = quote! { #crate_name::MergedObject(&self.#n, #obj) };
-        }
-        quote! {
-            #obj
+    // The cache control of the merged object overrides the one merged from its parts.
+    let cache_control = match &object_args.cache_control {
+        Some(cache_control) => {
+            let public = cache_control.is_public();
+            let max_age = cache_control.max_age;
+            quote! {
+                #crate_name::CacheControl {
+                    public: #public,
+                    max_age: #max_age,
+                }
+            }
         }
+        None => quote!(cache_control),
     };
 
-    let merged_type = {
-        let mut obj = quote! { #crate_name::MergedObjectTail };
-        for ty in &types {
-            obj = quote! { #crate_name::MergedObject::<#ty, #obj> };
-        }
-        obj
+    // When this object is itself a part of another merged object, it is also asked to resolve
+    // the fields of the other parts, so the guard is only checked for its own fields.
+    let guard = match &object_args.guard {
+        Some(meta) => generate_guards(&crate_name, meta, &[])?,
+        None => None,
     };
+    let guard = guard.map(|guard| {
+        quote! {
+            let merged_type = #crate_name::registry::Registry::default().create_dummy_type::<Self>();
+            if merged_type.field_by_name(&ctx.item.node.name.node).is_some() {
+                #guard.check(ctx).await
+                    .map_err(|err| ctx.guard_error(err, #gql_typename))?;
+            }
+        }
+    });
 
     let expanded = quote! {
         #[allow(clippy::all, clippy::pedantic)]
-        impl #generics #crate_name::Type for #ident #generics #where_clause {
+        impl #impl_generics #crate_name::Type for #ident #ty_generics #where_clause {
             fn type_name() -> ::std::borrow::Cow<'static, ::std::primitive::str> {
                 ::std::borrow::Cow::Borrowed(#gql_typename)
             }
 
             fn create_type_info(registry: &mut #crate_name::registry::Registry) -> ::std::string::String {
                 registry.create_type::<Self, _>(|registry| {
-                    let mut fields = ::std::default::Default::default();
-                    let mut cache_control = ::std::default::Default::default();
-
-                    if let #crate_name::registry::MetaType::Object {
-                        fields: obj_fields,
-                        cache_control: obj_cache_control,
-                        ..
-                    } = registry.create_dummy_type::<#merged_type>() {
-                        fields = obj_fields;
-                        cache_control = obj_cache_control;
-                    }
+                    let mut parts = ::std::vec::Vec::new();
+                    #(#create_parts)*
+                    let (fields, cache_control, keys) =
+                        #crate_name::merge_object_parts(#gql_typename, parts);
 
                     #crate_name::registry::MetaType::Object {
                         name: ::std::borrow::ToOwned::to_owned(#gql_typename),
                         description: #desc,
                         fields,
-                        cache_control,
+                        cache_control: #cache_control,
                         extends: #extends,
-                        keys: ::std::option::Option::None,
+                        keys,
                     }
                 })
             }
@@ -90,25 +136,28 @@ pub fn generate(object_args: &args::MergedObject) -> GeneratorResult<TokenStream
 
         #[allow(clippy::all, clippy::pedantic)]
         #[#crate_name::async_trait::async_trait(?Send)]
-        impl #generics #crate_name::resolver_utils::ContainerType for #ident #generics #where_clause {
+        impl #impl_generics #crate_name::resolver_utils::ContainerType for #ident #ty_generics #where_clause {
             async fn resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::ServerResult<::std::option::Option<#crate_name::Value>> {
-                #create_merged_obj.resolve_field(ctx).await
+                #guard
+                #(#resolve_fields)*
+                ::std::result::Result::Ok(::std::option::Option::None)
             }
 
             async fn find_entity(&self, ctx: &#crate_name::Context<'_>, params: &#crate_name::Value) ->  #crate_name::ServerResult<::std::option::Option<#crate_name::Value>> {
-               #create_merged_obj.find_entity(ctx, params).await
+                #(#find_entities)*
+                ::std::result::Result::Ok(::std::option::Option::None)
             }
         }
 
         #[allow(clippy::all, clippy::pedantic)]
         #[#crate_name::async_trait::async_trait(?Send)]
-        impl #generics #crate_name::OutputValueType for #ident #generics #where_clause {
+        impl #impl_generics #crate_name::OutputValueType for #ident #ty_generics #where_clause {
             async fn resolve(&self, ctx: &#crate_name::ContextSelectionSet<'_>, _field: &#crate_name::Positioned<#crate_name::parser::types::Field>) -> #crate_name::ServerResult<#crate_name::Value> {
                 #crate_name::resolver_utils::resolve_container(ctx, self).await
             }
         }
 
-        impl #generics #crate_name::ObjectType for #ident #generics #where_clause {}
+        impl #impl_generics #crate_name::ObjectType for #ident #ty_generics #where_clause {}
     };
     Ok(expanded.into())
 }
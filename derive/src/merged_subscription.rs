@@ -16,7 +16,7 @@ pub fn generate(object_args: &args::MergedSubscription) -> GeneratorResult<Token
         .unwrap_or_else(|| RenameTarget::Type.rename(ident.to_string()));
 
     let desc = get_rustdoc(&object_args.attrs)?
-        .map(|s| quote! { ::std::option::Option::Some(#s) })
+        .map(|s| quote! { ::std::option::Option::Some(::std::string::ToString::to_string(#s)) })
         .unwrap_or_else(|| quote! {::std::option::Option::None});
 
     let s = match &object_args.data {
@@ -39,10 +39,14 @@ pub fn generate(object_args: &args::MergedSubscription) -> GeneratorResult<Token
         })
         .collect();
 
-    let merged_type = types.iter().fold(
-        quote!(#crate_name::MergedObjectTail),
-        |obj, ty| quote!(#crate_name::MergedObject::<#ty, #obj>),
-    );
+    let create_parts = types.iter().map(|ty| {
+        quote! {
+            parts.push((
+                ::std::any::type_name::<#ty>(),
+                registry.create_dummy_type::<#ty>(),
+            ));
+        }
+    });
 
     let expanded = quote! {
         #[allow(clippy::all, clippy::pedantic)]
@@ -53,14 +57,9 @@ pub fn generate(object_args: &args::MergedSubscription) -> GeneratorResult<Token
 
             fn create_type_info(registry: &mut #crate_name::registry::Registry) -> ::std::string::String {
                 registry.create_type::<Self, _>(|registry| {
-                    let mut fields = ::std::default::Default::default();
-
-                    if let #crate_name::registry::MetaType::Object {
-                        fields: obj_fields,
-                        ..
-                    } = registry.create_dummy_type::<#merged_type>() {
-                        fields = obj_fields;
-                    }
+                    let mut parts = ::std::vec::Vec::new();
+                    #(#create_parts)*
+                    let (fields, _, _) = #crate_name::merge_object_parts(#gql_typename, parts);
 
                     #crate_name::registry::MetaType::Object {
                         name: ::std::borrow::ToOwned::to_owned(#gql_typename),
@@ -1,12 +1,19 @@
+use std::collections::HashMap;
+
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::ext::IdentExt;
-use syn::{Block, Error, FnArg, ImplItem, ItemImpl, Pat, ReturnType, Type, TypeReference};
+use syn::{
+    Block, Error, FnArg, Ident, ImplItem, ImplItemMethod, ItemImpl, Pat, PatIdent, ReturnType,
+    Type, TypeReference,
+};
 
 use crate::args::{self, RenameRuleExt, RenameTarget};
 use crate::output_type::OutputType;
 use crate::utils::{
-    generate_default, generate_guards, generate_validator, get_cfg_attrs, get_crate_name,
+    check_method_attrs, generate_custom_validator, generate_default, generate_default_with,
+    generate_default_with_ctx, generate_guards, generate_list_concurrency, generate_metadata,
+    generate_retry_policy, generate_validator, get_cfg_attrs, get_crate_name,
     get_param_getter_ident, get_rustdoc, parse_graphql_attrs, remove_graphql_attrs,
     GeneratorResult,
 };
@@ -34,16 +41,30 @@ pub fn generate(
         .name
         .clone()
         .unwrap_or_else(|| RenameTarget::Type.rename(self_name.clone()));
+    let metadata = generate_metadata(&crate_name, None, &object_args.meta);
+    let type_guard = match &object_args.guard {
+        Some(meta) => generate_guards(&crate_name, meta, &[])?,
+        None => None,
+    };
+    let type_guard = type_guard.map(|guard| {
+        quote! {
+            #guard.check(ctx).await
+                .map_err(|err| ctx.guard_error(err, #gql_typename))?;
+        }
+    });
 
     let desc = if object_args.use_type_description {
-        quote! { ::std::option::Option::Some(<Self as #crate_name::Description>::description()) }
+        quote! { ::std::option::Option::Some(::std::string::ToString::to_string(<Self as #crate_name::Description>::description())) }
     } else {
         get_rustdoc(&item_impl.attrs)?
-            .map(|s| quote!(::std::option::Option::Some(#s)))
+            .map(|s| quote!(::std::option::Option::Some(::std::string::ToString::to_string(#s))))
             .unwrap_or_else(|| quote!(::std::option::Option::None))
     };
 
+    check_method_attrs::<args::ObjectField, args::Argument>(item_impl)?;
+    let siblings = collect_siblings(&crate_name, item_impl)?;
     let mut resolvers = Vec::new();
+    let mut resolver_fns = Vec::new();
     let mut schema_fields = Vec::new();
     let mut find_entities = Vec::new();
     let mut add_keys = Vec::new();
@@ -201,14 +222,13 @@ pub fn generate(
 
                 find_entities.push((
                     args.len(),
+                    quote! { #(#cfg_attrs)* },
+                    quote! { <#entity_type as #crate_name::Type>::type_name() },
                     quote! {
-                        #(#cfg_attrs)*
-                        if typename == &<#entity_type as #crate_name::Type>::type_name() {
-                            if let (#(#key_pat),*) = (#(#key_getter),*) {
-                                #(#requires_getter)*
-                                let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
-                                return #crate_name::OutputValueType::resolve(&#do_find, &ctx_obj, ctx.item).await.map(::std::option::Option::Some);
-                            }
+                        if let (#(#key_pat),*) = (#(#key_getter),*) {
+                            #(#requires_getter)*
+                            let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
+                            return #crate_name::OutputValueType::resolve(&#do_find, &ctx_obj, ctx.item).await.map(::std::option::Option::Some);
                         }
                     },
                 ));
@@ -319,6 +339,7 @@ pub fn generate(
                 let mut schema_args = Vec::new();
                 let mut use_params = Vec::new();
                 let mut get_params = Vec::new();
+                let mut param_idents = Vec::new();
 
                 for (
                     ident,
@@ -328,11 +349,32 @@ pub fn generate(
                         desc,
                         default,
                         default_with,
+                        default_with_ctx,
                         validator,
+                        custom_validator,
+                        secret,
+                        sibling,
                         ..
                     },
                 ) in args
                 {
+                    let param_ident = &ident.ident;
+                    if let Some(sibling) = sibling {
+                        // A field used by its siblings gets its parameters in its shared call.
+                        if siblings.contains_key(&method.sig.ident.to_string()) {
+                            continue;
+                        }
+                        let call = &siblings[&sibling];
+                        get_params.push(quote! {
+                            #[allow(non_snake_case)]
+                            let #param_ident: #ty = #call.await
+                                .map_err(|err| err.into_server_error().at(ctx.item.pos))?;
+                        });
+                        use_params.push(quote! { #param_ident });
+                        param_idents.push(param_ident.clone());
+                        continue;
+                    }
+
                     let name = name.clone().unwrap_or_else(|| {
                         object_args
                             .rename_args
@@ -342,14 +384,21 @@ pub fn generate(
                         .as_ref()
                         .map(|s| quote! {::std::option::Option::Some(#s)})
                         .unwrap_or_else(|| quote! {::std::option::Option::None});
+                    let default_with_ctx = generate_default_with_ctx(
+                        &crate_name,
+                        &ty,
+                        &default,
+                        &default_with,
+                        &default_with_ctx,
+                    )?;
                     let default = generate_default(&default, &default_with)?;
                     let schema_default = default
                         .as_ref()
                         .map(|value| {
                             quote! {
-                                ::std::option::Option::Some(::std::string::ToString::to_string(
-                                    &<#ty as #crate_name::InputValueType>::to_value(&#value)
-                                ))
+                                ::std::option::Option::Some(
+                                    <#ty as #crate_name::InputValueType>::to_value(&#value).to_graphql_literal()
+                                )
                             }
                         })
                         .unwrap_or_else(|| quote! {::std::option::Option::None});
@@ -362,18 +411,23 @@ pub fn generate(
                         None => quote!(::std::option::Option::None),
                     };
 
+                    let arg_schema_ty = match &default_with_ctx {
+                        Some((_, schema_ty)) => schema_ty.clone(),
+                        None => quote! { <#ty as #crate_name::Type>::create_type_info(registry) },
+                    };
                     schema_args.push(quote! {
                         args.insert(#name, #crate_name::registry::MetaInputValue {
                             name: #name,
                             description: #desc,
-                            ty: <#ty as #crate_name::Type>::create_type_info(registry),
+                            ty: #arg_schema_ty,
                             default_value: #schema_default,
                             validator: #validator,
+                            is_secret: #secret,
                         });
                     });
 
-                    let param_ident = &ident.ident;
                     use_params.push(quote! { #param_ident });
+                    param_idents.push(param_ident.clone());
 
                     let default = match default {
                         Some(default) => {
@@ -381,18 +435,33 @@ pub fn generate(
                         }
                         None => quote! { ::std::option::Option::None },
                     };
+                    let get_value = match &default_with_ctx {
+                        Some((default_with_ctx, _)) => {
+                            quote! { ctx.param_value_or_else(#name, #default_with_ctx) }
+                        }
+                        None => quote! { ctx.param_value(#name, #default) },
+                    };
+                    let custom_validator = custom_validator
+                        .as_ref()
+                        .map(|validator| generate_custom_validator(&name, &ident.ident, validator))
+                        .transpose()?;
                     let param_getter_name = get_param_getter_ident(&ident.ident.to_string());
                     get_params.push(quote! {
                         #[allow(non_snake_case)]
-                        let #param_getter_name = || -> #crate_name::ServerResult<#ty> { ctx.param_value(#name, #default) };
+                        let #param_getter_name = || -> #crate_name::ServerResult<#ty> { #get_value };
                         #[allow(non_snake_case)]
                         let #ident: #ty = #param_getter_name()?;
+                        #custom_validator
                     });
                 }
 
                 let schema_ty = ty.value_type();
 
+                let metadata =
+                    generate_metadata(&crate_name, Some(field_name.as_str()), &method_args.meta);
                 schema_fields.push(quote! {
+                    #(#cfg_attrs)*
+                    { #metadata }
                     #(#cfg_attrs)*
                     fields.insert(::std::borrow::ToOwned::to_owned(#field_name), #crate_name::registry::MetaField {
                         name: ::std::borrow::ToOwned::to_owned(#field_name),
@@ -426,34 +495,72 @@ pub fn generate(
                             .expect("invalid result type");
                 }
 
+                let call = match siblings.get(&field_ident.to_string()) {
+                    Some(call) => call.clone(),
+                    None => {
+                        generate_call(&crate_name, field_ident, &use_params, &method_args.retry)?
+                    }
+                };
                 let resolve_obj = quote! {
                     {
-                        let res = self.#field_ident(ctx, #(#use_params),*).await;
-                        res.map_err(|err| err.into_server_error().at(ctx.item.pos))?
+                        let res = #call.await;
+                        res.map_err(|err| err.into_server_error().at(ctx.item.pos))
                     }
                 };
+                let resolve_obj = match &method_args.fallback {
+                    Some(fallback) => {
+                        let fallback = generate_default_with(fallback)?;
+                        quote! {
+                            match #resolve_obj {
+                                ::std::result::Result::Ok(res) => res,
+                                ::std::result::Result::Err(err) => {
+                                    ctx.add_warning(err);
+                                    #fallback
+                                }
+                            }
+                        }
+                    }
+                    None => quote! { #resolve_obj? },
+                };
 
                 let guard = match &method_args.guard {
-                    Some(meta_list) => generate_guards(&crate_name, meta_list)?,
+                    Some(meta_list) => generate_guards(&crate_name, meta_list, &param_idents)?,
                     None => None,
                 };
 
                 let guard = guard.map(|guard| {
                     quote! {
                         #guard.check(ctx).await
-                            .map_err(|err| err.into_server_error().at(ctx.item.pos))?;
+                            .map_err(|err| ctx.guard_error(err, #gql_typename))?;
                     }
                 });
 
+                let list_concurrency =
+                    generate_list_concurrency(method_args.list_concurrency, &method.sig.ident)?;
+
+                let resolver_ident =
+                    format_ident!("__graphql_resolve_{}", method.sig.ident.unraw());
+                let body = quote! {
+                    #type_guard
+                    #(#get_params)*
+                    #guard
+                    let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set)#list_concurrency;
+                    let res = #resolve_obj;
+                    #crate_name::OutputValueType::resolve(&res, &ctx_obj, ctx.item).await.map(::std::option::Option::Some)
+                };
+                let resolver = quote! {
+                    async fn #resolver_ident(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::ServerResult<::std::option::Option<#crate_name::Value>> {
+                        #body
+                    }
+                };
+                resolver_fns.push(quote! {
+                    #(#cfg_attrs)*
+                    #[doc(hidden)]
+                    #resolver
+                });
                 resolvers.push(quote! {
                     #(#cfg_attrs)*
-                    if ctx.item.node.name.node == #field_name {
-                        #(#get_params)*
-                        #guard
-                        let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
-                        let res = #resolve_obj;
-                        return #crate_name::OutputValueType::resolve(&res, &ctx_obj, ctx.item).await.map(::std::option::Option::Some);
-                    }
+                    #field_name => self.#resolver_ident(ctx).await,
                 });
             }
 
@@ -472,8 +579,39 @@ pub fn generate(
         }
     };
 
-    find_entities.sort_by(|(a, _), (b, _)| b.cmp(a));
-    let find_entities_iter = find_entities.iter().map(|(_, code)| code);
+    find_entities.sort_by(|(a, _, _, _), (b, _, _, _)| b.cmp(a));
+    // Entities resolved by their own type are preferred to entity interfaces they implement.
+    let find_entities_iter = find_entities
+        .iter()
+        .map(|(_, cfg_attrs, entity_type_name, code)| {
+            quote! {
+                #cfg_attrs
+                if typename == &#entity_type_name {
+                    #code
+                }
+            }
+        })
+        .chain(
+            find_entities
+                .iter()
+                .map(|(_, cfg_attrs, entity_type_name, code)| {
+                    quote! {
+                        #cfg_attrs
+                        if ctx
+                            .schema_env
+                            .registry
+                            .types
+                            .get(&*#entity_type_name)
+                            .map_or(false, |ty| {
+                                matches!(ty, #crate_name::registry::MetaType::Interface { .. })
+                                    && ty.is_possible_type(typename)
+                            })
+                        {
+                            #code
+                        }
+                    }
+                }),
+        );
 
     if resolvers.is_empty() && create_entity_types.is_empty() {
         return Err(Error::new_spanned(
@@ -483,9 +621,17 @@ pub fn generate(
         .into());
     }
 
+    // Each field is resolved by its own method, so that `resolve_field` stays small however many
+    // fields the object has.
     let expanded = quote! {
         #item_impl
 
+        #[allow(clippy::all, clippy::pedantic, clippy::suspicious_else_formatting)]
+        #[allow(unused_braces, unused_variables, unused_parens, unused_mut, non_snake_case)]
+        impl #generics #self_ty #where_clause {
+            #(#resolver_fns)*
+        }
+
         #[allow(clippy::all, clippy::pedantic)]
         impl #generics #crate_name::Type for #self_ty #where_clause {
             fn type_name() -> ::std::borrow::Cow<'static, ::std::primitive::str> {
@@ -497,6 +643,7 @@ pub fn generate(
                     name: ::std::borrow::ToOwned::to_owned(#gql_typename),
                     description: #desc,
                     fields: {
+                        #metadata
                         let mut fields = #crate_name::indexmap::IndexMap::new();
                         #(#schema_fields)*
                         fields
@@ -512,12 +659,14 @@ pub fn generate(
         }
 
         #[allow(clippy::all, clippy::pedantic, clippy::suspicious_else_formatting)]
-        #[allow(unused_braces, unused_variables, unused_parens, unused_mut)]
+        #[allow(unused_braces, unused_variables, unused_parens, unused_mut, unreachable_patterns)]
         #[#crate_name::async_trait::async_trait(?Send)]
         impl#generics #crate_name::resolver_utils::ContainerType for #self_ty #where_clause {
             async fn resolve_field(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::ServerResult<::std::option::Option<#crate_name::Value>> {
-                #(#resolvers)*
-                ::std::result::Result::Ok(::std::option::Option::None)
+                match ctx.item.node.name.node.as_str() {
+                    #(#resolvers)*
+                    _ => ::std::result::Result::Ok(::std::option::Option::None),
+                }
             }
 
             async fn find_entity(&self, ctx: &#crate_name::Context<'_>, params: &#crate_name::Value) -> #crate_name::ServerResult<::std::option::Option<#crate_name::Value>> {
@@ -550,3 +699,144 @@ pub fn generate(
     };
     Ok(expanded.into())
 }
+
+/// The call of a resolver, retried according to its `retry` parameter.
+fn generate_call(
+    crate_name: &proc_macro2::TokenStream,
+    field_ident: &Ident,
+    use_params: &[proc_macro2::TokenStream],
+    retry: &Option<args::Retry>,
+) -> GeneratorResult<proc_macro2::TokenStream> {
+    Ok(match retry {
+        Some(retry) => {
+            let policy = generate_retry_policy(crate_name, retry, field_ident)?;
+            quote! {
+                #crate_name::resolver_utils::retry(ctx, &#policy, || {
+                    self.#field_ident(ctx, #(::std::clone::Clone::clone(&#use_params)),*)
+                })
+            }
+        }
+        None => quote! { self.#field_ident(ctx, #(#use_params),*) },
+    })
+}
+
+/// The siblings used by the parameters of a resolver, as `(parameter, type, sibling)`.
+fn sibling_params(method: &ImplItemMethod) -> GeneratorResult<Vec<(&PatIdent, &Type, String)>> {
+    let mut params = Vec::new();
+    for arg in &method.sig.inputs {
+        if let FnArg::Typed(pat) = arg {
+            if let Some(args::Argument {
+                sibling: Some(sibling),
+                ..
+            }) = parse_graphql_attrs::<args::Argument>(&pat.attrs)?
+            {
+                match &*pat.pat {
+                    Pat::Ident(ident) => params.push((ident, &*pat.ty, sibling)),
+                    _ => return Err(Error::new_spanned(pat, "Invalid argument type.").into()),
+                }
+            }
+        }
+    }
+    Ok(params)
+}
+
+/// The calls of the resolvers whose results are used by sibling fields, by resolver name. They
+/// are shared so that each resolver is called once per object.
+fn collect_siblings(
+    crate_name: &proc_macro2::TokenStream,
+    item_impl: &ItemImpl,
+) -> GeneratorResult<HashMap<String, proc_macro2::TokenStream>> {
+    let mut siblings = HashMap::new();
+    for item in &item_impl.items {
+        if let ImplItem::Method(method) = item {
+            for (param, _, name) in sibling_params(method)? {
+                generate_sibling(
+                    crate_name,
+                    item_impl,
+                    param,
+                    &name,
+                    &mut siblings,
+                    &mut Vec::new(),
+                )?;
+            }
+        }
+    }
+    Ok(siblings)
+}
+
+fn generate_sibling(
+    crate_name: &proc_macro2::TokenStream,
+    item_impl: &ItemImpl,
+    param: &PatIdent,
+    name: &str,
+    siblings: &mut HashMap<String, proc_macro2::TokenStream>,
+    visiting: &mut Vec<String>,
+) -> GeneratorResult<()> {
+    if siblings.contains_key(name) {
+        return Ok(());
+    }
+    if visiting.iter().any(|visiting| visiting == name) {
+        return Err(
+            Error::new_spanned(param, format!("Resolver `{}` depends on itself.", name)).into(),
+        );
+    }
+
+    let sibling = item_impl
+        .items
+        .iter()
+        .find_map(|item| match item {
+            ImplItem::Method(method) if method.sig.ident == name => Some(method),
+            _ => None,
+        })
+        .ok_or_else(|| Error::new_spanned(param, format!("Resolver `{}` does not exist.", name)))?;
+    let sibling_args: args::ObjectField = parse_graphql_attrs(&sibling.attrs)?.unwrap_or_default();
+    if sibling_args.skip || sibling_args.entity {
+        return Err(
+            Error::new_spanned(param, format!("Resolver `{}` is not a field.", name)).into(),
+        );
+    }
+
+    let params = sibling_params(sibling)?;
+    let args = sibling
+        .sig
+        .inputs
+        .iter()
+        .filter(|arg| matches!(arg, FnArg::Typed(pat) if !matches!(&*pat.ty, Type::Reference(_))))
+        .count();
+    if args != params.len() {
+        return Err(Error::new_spanned(
+            &sibling.sig,
+            "A field used by its sibling fields can only have sibling parameters.",
+        )
+        .into());
+    }
+
+    visiting.push(name.to_string());
+    let mut get_params = Vec::new();
+    let mut use_params = Vec::new();
+    for (param, ty, name) in &params {
+        generate_sibling(crate_name, item_impl, param, name, siblings, visiting)?;
+        let call = &siblings[name];
+        let ident = &param.ident;
+        get_params.push(quote! { let #ident: #ty = #call.await?; });
+        use_params.push(quote! { #ident });
+    }
+    visiting.pop();
+
+    let call = generate_call(
+        crate_name,
+        &sibling.sig.ident,
+        &use_params,
+        &sibling_args.retry,
+    )?;
+    siblings.insert(
+        name.to_string(),
+        quote! {
+            ctx.resolve_shared(::std::any::type_name::<Self>(), #name, || async move {
+                #(#get_params)*
+                #call.await
+            })
+        },
+    );
+    Ok(())
+}
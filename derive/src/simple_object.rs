@@ -2,10 +2,48 @@ use darling::ast::Data;
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::ext::IdentExt;
-use syn::Error;
+use syn::{Error, GenericArgument, Ident, PathArguments, Type, TypePath};
 
 use crate::args::{self, RenameRuleExt, RenameTarget};
-use crate::utils::{generate_guards, get_crate_name, get_rustdoc, GeneratorResult};
+use crate::utils::{
+    generate_guards, generate_list_concurrency, generate_metadata, get_crate_name, get_rustdoc,
+    GeneratorResult,
+};
+
+/// How the getter of a field gets its value, depending on the wrapper of the type of the field.
+enum FieldAccess<'a> {
+    /// The field is the value.
+    Direct,
+    /// `Arc<T>`, `Rc<T>` or `Box<T>`, the value is borrowed through the pointer.
+    Pointer(&'a Type),
+    /// An async `Mutex<T>` or `RwLock<T>`, the value is cloned while it is locked with the method.
+    Lock(&'a Type, Ident),
+    /// `OnceCell<T>`, the field is null until the cell is set.
+    OnceCell(&'a Type),
+}
+
+fn get_field_access(ty: &Type) -> FieldAccess<'_> {
+    if let Type::Path(TypePath { qself: None, path }) = ty {
+        if let Some(segment) = path.segments.last() {
+            if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                if let (1, Some(GenericArgument::Type(inner))) =
+                    (args.args.len(), args.args.first())
+                {
+                    let lock =
+                        |method| FieldAccess::Lock(inner, Ident::new(method, segment.ident.span()));
+                    return match segment.ident.to_string().as_str() {
+                        "Arc" | "Rc" | "Box" => FieldAccess::Pointer(inner),
+                        "Mutex" => lock("lock"),
+                        "RwLock" => lock("read"),
+                        "OnceCell" => FieldAccess::OnceCell(inner),
+                        _ => FieldAccess::Direct,
+                    };
+                }
+            }
+        }
+    }
+    FieldAccess::Direct
+}
 
 pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream> {
     let crate_name = get_crate_name(object_args.internal);
@@ -17,9 +55,20 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
         .name
         .clone()
         .unwrap_or_else(|| RenameTarget::Type.rename(ident.to_string()));
+    let metadata = generate_metadata(&crate_name, None, &object_args.meta);
+    let type_guard = match &object_args.guard {
+        Some(meta) => generate_guards(&crate_name, meta, &[])?,
+        None => None,
+    };
+    let type_guard = type_guard.map(|guard| {
+        quote! {
+            #guard.check(ctx).await
+                .map_err(|err| ctx.guard_error(err, #gql_typename))?;
+        }
+    });
 
     let desc = get_rustdoc(&object_args.attrs)?
-        .map(|s| quote! { ::std::option::Option::Some(#s) })
+        .map(|s| quote! { ::std::option::Option::Some(::std::string::ToString::to_string(#s)) })
         .unwrap_or_else(|| quote! {::std::option::Option::None});
 
     let s = match &object_args.data {
@@ -69,6 +118,12 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
         };
         let vis = &field.vis;
         let ty = &field.ty;
+        let access = get_field_access(ty);
+        let value_ty = match &access {
+            FieldAccess::Direct => quote! { #ty },
+            FieldAccess::Pointer(inner) | FieldAccess::Lock(inner, _) => quote! { #inner },
+            FieldAccess::OnceCell(inner) => quote! { ::std::option::Option<#inner> },
+        };
 
         let cache_control = {
             let public = field.cache_control.is_public();
@@ -81,12 +136,14 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
             }
         };
 
+        let metadata = generate_metadata(&crate_name, Some(field_name.as_str()), &field.meta);
         schema_fields.push(quote! {
+            #metadata
             fields.insert(::std::borrow::ToOwned::to_owned(#field_name), #crate_name::registry::MetaField {
                 name: ::std::borrow::ToOwned::to_owned(#field_name),
                 description: #field_desc,
                 args: ::std::default::Default::default(),
-                ty: <#ty as #crate_name::Type>::create_type_info(registry),
+                ty: <#value_ty as #crate_name::Type>::create_type_info(registry),
                 deprecation: #field_deprecation,
                 cache_control: #cache_control,
                 external: #external,
@@ -96,34 +153,55 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
         });
 
         let guard = match &field.guard {
-            Some(meta) => generate_guards(&crate_name, &meta)?,
+            Some(meta) => generate_guards(&crate_name, &meta, &[])?,
             None => None,
         };
-        let guard = guard.map(|guard| quote! { #guard.check(ctx).await.map_err(|err| err.into_server_error().at(ctx.item.pos))?; });
+        let guard = guard.map(|guard| quote! { #guard.check(ctx).await.map_err(|err| ctx.guard_error(err, #gql_typename))?; });
 
-        getters.push(if !field.owned {
-            quote! {
-                 #[inline]
-                 #[allow(missing_docs)]
-                 #vis async fn #ident(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::Result<&#ty> {
-                     ::std::result::Result::Ok(&self.#ident)
-                 }
-            }
-        } else {
+        let getter = |output: proc_macro2::TokenStream, value: proc_macro2::TokenStream| {
             quote! {
                 #[inline]
                 #[allow(missing_docs)]
-                #vis async fn #ident(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::Result<#ty> {
-                    ::std::result::Result::Ok(::std::clone::Clone::clone(&self.#ident))
+                #vis async fn #ident(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::Result<#output> {
+                    ::std::result::Result::Ok(#value)
                 }
             }
+        };
+        getters.push(match (&access, field.owned) {
+            (FieldAccess::Direct, false) => getter(quote! { &#ty }, quote! { &self.#ident }),
+            (FieldAccess::Direct, true) => getter(
+                quote! { #ty },
+                quote! { ::std::clone::Clone::clone(&self.#ident) },
+            ),
+            (FieldAccess::Pointer(inner), false) => {
+                getter(quote! { &#inner }, quote! { &*self.#ident })
+            }
+            (FieldAccess::Pointer(inner), true) => getter(
+                quote! { #inner },
+                quote! { ::std::clone::Clone::clone(&*self.#ident) },
+            ),
+            (FieldAccess::Lock(inner, method), _) => getter(
+                quote! { #inner },
+                quote! { ::std::clone::Clone::clone(&*self.#ident.#method().await) },
+            ),
+            (FieldAccess::OnceCell(inner), false) => getter(
+                quote! { ::std::option::Option<&#inner> },
+                quote! { self.#ident.get() },
+            ),
+            (FieldAccess::OnceCell(inner), true) => getter(
+                quote! { ::std::option::Option<#inner> },
+                quote! { ::std::option::Option::cloned(self.#ident.get()) },
+            ),
         });
 
+        let list_concurrency = generate_list_concurrency(field.list_concurrency, ident)?;
+
         resolvers.push(quote! {
             if ctx.item.node.name.node == #field_name {
+                #type_guard
                 #guard
                 let res = self.#ident(ctx).await.map_err(|err| err.into_server_error().at(ctx.item.pos))?;
-                let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
+                let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set)#list_concurrency;
                 return #crate_name::OutputValueType::resolve(&res, &ctx_obj, ctx.item).await.map(::std::option::Option::Some);
             }
         });
@@ -165,6 +243,7 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
                     name: ::std::borrow::ToOwned::to_owned(#gql_typename),
                     description: #desc,
                     fields: {
+                        #metadata
                         let mut fields = #crate_name::indexmap::IndexMap::new();
                         #(#schema_fields)*
                         fields
@@ -9,9 +9,9 @@ use syn::{
 use crate::args::{self, RenameRuleExt, RenameTarget, SubscriptionField};
 use crate::output_type::OutputType;
 use crate::utils::{
-    generate_default, generate_guards, generate_validator, get_cfg_attrs, get_crate_name,
-    get_param_getter_ident, get_rustdoc, parse_graphql_attrs, remove_graphql_attrs,
-    GeneratorResult,
+    check_method_attrs, generate_custom_validator, generate_default, generate_default_with_ctx,
+    generate_guards, generate_validator, get_cfg_attrs, get_crate_name, get_param_getter_ident,
+    get_rustdoc, parse_graphql_attrs, remove_graphql_attrs, GeneratorResult,
 };
 
 pub fn generate(
@@ -39,13 +39,24 @@ pub fn generate(
         .unwrap_or_else(|| RenameTarget::Type.rename(self_name.clone()));
 
     let desc = if subscription_args.use_type_description {
-        quote! { ::std::option::Option::Some(<Self as #crate_name::Description>::description()) }
+        quote! { ::std::option::Option::Some(::std::string::ToString::to_string(<Self as #crate_name::Description>::description())) }
     } else {
         get_rustdoc(&item_impl.attrs)?
-            .map(|s| quote!(::std::option::Option::Some(#s)))
+            .map(|s| quote!(::std::option::Option::Some(::std::string::ToString::to_string(#s))))
             .unwrap_or_else(|| quote!(::std::option::Option::None))
     };
 
+    let type_guard = match &subscription_args.guard {
+        Some(meta) => generate_guards(&crate_name, meta, &[])?,
+        None => None,
+    };
+    let type_guard = type_guard.map(|guard| {
+        quote! {
+            #guard.check(ctx).await.map_err(|err| ctx.guard_error(err, #gql_typename))?;
+        }
+    });
+
+    check_method_attrs::<SubscriptionField, args::SubscriptionFieldArgument>(item_impl)?;
     let mut create_stream = Vec::new();
     let mut schema_fields = Vec::new();
 
@@ -150,6 +161,7 @@ pub fn generate(
             let mut schema_args = Vec::new();
             let mut use_params = Vec::new();
             let mut get_params = Vec::new();
+            let mut param_idents = Vec::new();
 
             for (
                 ident,
@@ -159,7 +171,9 @@ pub fn generate(
                     desc,
                     default,
                     default_with,
+                    default_with_ctx,
                     validator,
+                    custom_validator,
                 },
             ) in args
             {
@@ -172,6 +186,13 @@ pub fn generate(
                     .as_ref()
                     .map(|s| quote! {::std::option::Option::Some(#s)})
                     .unwrap_or_else(|| quote! {::std::option::Option::None});
+                let default_with_ctx = generate_default_with_ctx(
+                    &crate_name,
+                    &ty,
+                    &default,
+                    &default_with,
+                    &default_with_ctx,
+                )?;
                 let default = generate_default(&default, &default_with)?;
 
                 let validator = match &validator {
@@ -186,35 +207,52 @@ pub fn generate(
                     .as_ref()
                     .map(|value| {
                         quote! {
-                            ::std::option::Option::Some(::std::string::ToString::to_string(
-                                &<#ty as #crate_name::InputValueType>::to_value(&#value)
-                            ))
+                            ::std::option::Option::Some(
+                                <#ty as #crate_name::InputValueType>::to_value(&#value).to_graphql_literal()
+                            )
                         }
                     })
                     .unwrap_or_else(|| quote! {::std::option::Option::None});
 
+                let arg_schema_ty = match &default_with_ctx {
+                    Some((_, schema_ty)) => schema_ty.clone(),
+                    None => quote! { <#ty as #crate_name::Type>::create_type_info(registry) },
+                };
                 schema_args.push(quote! {
                     args.insert(#name, #crate_name::registry::MetaInputValue {
                         name: #name,
                         description: #desc,
-                        ty: <#ty as #crate_name::Type>::create_type_info(registry),
+                        ty: #arg_schema_ty,
                         default_value: #schema_default,
                         validator: #validator,
+                        is_secret: false,
                     });
                 });
 
                 use_params.push(quote! { #ident });
+                param_idents.push(ident.ident.clone());
 
                 let default = match default {
                     Some(default) => quote! { ::std::option::Option::Some(|| -> #ty { #default }) },
                     None => quote! { ::std::option::Option::None },
                 };
+                let get_value = match &default_with_ctx {
+                    Some((default_with_ctx, _)) => {
+                        quote! { ctx.param_value_or_else(#name, #default_with_ctx) }
+                    }
+                    None => quote! { ctx.param_value(#name, #default) },
+                };
+                let custom_validator = custom_validator
+                    .as_ref()
+                    .map(|validator| generate_custom_validator(&name, &ident.ident, validator))
+                    .transpose()?;
                 let param_getter_name = get_param_getter_ident(&ident.ident.to_string());
                 get_params.push(quote! {
                     #[allow(non_snake_case)]
-                    let #param_getter_name = || -> #crate_name::ServerResult<#ty> { ctx.param_value(#name, #default) };
+                    let #param_getter_name = || -> #crate_name::ServerResult<#ty> { #get_value };
                     #[allow(non_snake_case)]
-                    let #ident: #ty = ctx.param_value(#name, #default)?;
+                    let #ident: #ty = #get_value?;
+                    #custom_validator
                 });
             }
 
@@ -273,18 +311,22 @@ pub fn generate(
             };
 
             let guard = match &field.guard {
-                Some(meta_list) => generate_guards(&crate_name, meta_list)?,
+                Some(meta_list) => generate_guards(&crate_name, meta_list, &param_idents)?,
                 None => None,
             };
-            let guard = guard.map(|guard| quote! {
-                #guard.check(ctx).await.map_err(|err| err.into_server_error().at(ctx.item.pos))?;
+            let guard = guard.map(|guard| {
+                quote! {
+                    #guard.check(ctx).await.map_err(|err| ctx.guard_error(err, #gql_typename))?;
+                }
             });
 
             let stream_fn = quote! {
+                #type_guard
                 #(#get_params)*
                 #guard
                 let field_name = ::std::sync::Arc::new(::std::clone::Clone::clone(&ctx.item.node.response_key().node));
                 let field = ::std::sync::Arc::new(::std::clone::Clone::clone(&ctx.item));
+                let arguments = ::std::sync::Arc::new(ctx.arguments().ok());
 
                 let pos = ctx.item.pos;
                 let schema_env = ::std::clone::Clone::clone(&ctx.schema_env);
@@ -296,6 +338,7 @@ pub fn generate(
                         let query_env = ::std::clone::Clone::clone(&query_env);
                         let field = ::std::clone::Clone::clone(&field);
                         let field_name = ::std::clone::Clone::clone(&field_name);
+                        let arguments = ::std::clone::Clone::clone(&arguments);
                         async move {
                             let resolve_id = #crate_name::ResolveId {
                                 parent: ::std::option::Option::Some(0),
@@ -325,6 +368,7 @@ pub fn generate(
                                 path_node: ctx_selection_set.path_node.as_ref().unwrap(),
                                 parent_type: #gql_typename,
                                 return_type: &<<#stream_ty as #crate_name::futures_util::stream::Stream>::Item as #crate_name::Type>::qualified_type_name(),
+                                arguments: ::std::option::Option::as_ref(&*arguments),
                             };
 
                             query_env.extensions.resolve_start(&ctx_extension, &ri);
@@ -0,0 +1,55 @@
+use std::path::Path;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Error, LitStr};
+
+use crate::utils::{get_crate_name, GeneratorResult};
+
+pub fn generate(dir: &LitStr) -> GeneratorResult<TokenStream> {
+    let crate_name = get_crate_name(false);
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|_| Error::new_spanned(dir, "CARGO_MANIFEST_DIR is not set."))?;
+    let path = Path::new(&manifest_dir).join(dir.value());
+    let entries = std::fs::read_dir(&path).map_err(|err| {
+        Error::new_spanned(dir, format!("Cannot read `{}`: {}", path.display(), err))
+    })?;
+
+    let mut files = Vec::new();
+    for entry in entries {
+        let file = entry
+            .map_err(|err| {
+                Error::new_spanned(dir, format!("Cannot read `{}`: {}", path.display(), err))
+            })?
+            .path();
+        if file.extension().and_then(|ext| ext.to_str()) == Some("graphql") {
+            files.push(file);
+        }
+    }
+    files.sort();
+
+    let mut documents = Vec::new();
+    for file in files {
+        let name = file
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default()
+            .to_string();
+        let source = std::fs::read_to_string(&file).map_err(|err| {
+            Error::new_spanned(dir, format!("Cannot read `{}`: {}", file.display(), err))
+        })?;
+        if let Err(err) = async_graphql_parser::parse_query(&source) {
+            return Err(
+                Error::new_spanned(dir, format!("Invalid document `{}`: {}", name, err)).into(),
+            );
+        }
+        let file = file.display().to_string();
+        documents.push(quote! { .add(#name, ::std::include_str!(#file)) });
+    }
+
+    let expanded = quote! {
+        #crate_name::trusted_documents::TrustedDocuments::new()
+            #(#documents)*
+    };
+    Ok(expanded.into())
+}
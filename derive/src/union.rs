@@ -7,7 +7,7 @@ use syn::visit_mut::VisitMut;
 use syn::{visit_mut, Error, Lifetime, Type};
 
 use crate::args::{self, RenameTarget};
-use crate::utils::{get_crate_name, get_rustdoc, GeneratorResult};
+use crate::utils::{generate_metadata, get_crate_name, get_rustdoc, GeneratorResult};
 
 pub fn generate(union_args: &args::Union) -> GeneratorResult<TokenStream> {
     let crate_name = get_crate_name(union_args.internal);
@@ -20,15 +20,17 @@ pub fn generate(union_args: &args::Union) -> GeneratorResult<TokenStream> {
         }
     };
     let mut enum_names = Vec::new();
+    let mut enum_types = Vec::new();
     let mut enum_items = HashSet::new();
     let mut type_into_impls = Vec::new();
     let gql_typename = union_args
         .name
         .clone()
         .unwrap_or_else(|| RenameTarget::Type.rename(ident.to_string()));
+    let metadata = generate_metadata(&crate_name, None, &union_args.meta);
 
     let desc = get_rustdoc(&union_args.attrs)?
-        .map(|s| quote! { ::std::option::Option::Some(#s) })
+        .map(|s| quote! { ::std::option::Option::Some(::std::string::ToString::to_string(#s)) })
         .unwrap_or_else(|| quote! {::std::option::Option::None});
 
     let mut registry_types = Vec::new();
@@ -70,6 +72,7 @@ pub fn generate(union_args: &args::Union) -> GeneratorResult<TokenStream> {
             }
 
             enum_names.push(enum_name);
+            enum_types.push(p);
 
             struct RemoveLifetime;
             impl VisitMut for RemoveLifetime {
@@ -148,6 +151,45 @@ pub fn generate(union_args: &args::Union) -> GeneratorResult<TokenStream> {
         .into());
     }
 
+    if union_args.from_result || union_args.from_either {
+        let (left_name, right_name, left_ty, right_ty) = match (&*enum_names, &*enum_types) {
+            ([left_name, right_name], [left_ty, right_ty]) => {
+                (left_name, right_name, left_ty, right_ty)
+            }
+            _ => return Err(Error::new_spanned(
+                ident,
+                "A union converted from a `Result` or an `Either` must have exactly two variants.",
+            )
+            .into()),
+        };
+        if union_args.from_result {
+            type_into_impls.push(quote! {
+                #[allow(clippy::all, clippy::pedantic)]
+                impl #generics ::std::convert::From<::std::result::Result<#left_ty, #right_ty>> for #ident #generics {
+                    fn from(res: ::std::result::Result<#left_ty, #right_ty>) -> Self {
+                        match res {
+                            ::std::result::Result::Ok(obj) => #ident::#left_name(obj),
+                            ::std::result::Result::Err(obj) => #ident::#right_name(obj),
+                        }
+                    }
+                }
+            });
+        }
+        if union_args.from_either {
+            type_into_impls.push(quote! {
+                #[allow(clippy::all, clippy::pedantic)]
+                impl #generics ::std::convert::From<#crate_name::either::Either<#left_ty, #right_ty>> for #ident #generics {
+                    fn from(value: #crate_name::either::Either<#left_ty, #right_ty>) -> Self {
+                        match value {
+                            #crate_name::either::Either::Left(obj) => #ident::#left_name(obj),
+                            #crate_name::either::Either::Right(obj) => #ident::#right_name(obj),
+                        }
+                    }
+                }
+            });
+        }
+    }
+
     let expanded = quote! {
         #(#type_into_impls)*
 
@@ -166,6 +208,7 @@ pub fn generate(union_args: &args::Union) -> GeneratorResult<TokenStream> {
             fn create_type_info(registry: &mut #crate_name::registry::Registry) -> ::std::string::String {
                 registry.create_type::<Self, _>(|registry| {
                     #(#registry_types)*
+                    #metadata
 
                     #crate_name::registry::MetaType::Union {
                         name: ::std::borrow::ToOwned::to_owned(#gql_typename),
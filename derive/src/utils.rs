@@ -1,11 +1,13 @@
 use darling::FromMeta;
 use proc_macro2::{Span, TokenStream, TokenTree};
 use proc_macro_crate::crate_name;
-use quote::quote;
-use syn::{Attribute, Error, Expr, Ident, Lit, LitStr, Meta, NestedMeta};
+use quote::{quote, ToTokens};
+use syn::{
+    Attribute, Error, Expr, FnArg, Ident, ImplItem, ItemImpl, Lit, LitStr, Meta, NestedMeta,
+};
 use thiserror::Error;
 
-use crate::args;
+use crate::args::{self, ConflictingAttrs};
 
 #[derive(Error, Debug)]
 pub enum GeneratorError {
@@ -14,6 +16,9 @@ pub enum GeneratorError {
 
     #[error("{0}")]
     Darling(#[from] darling::Error),
+
+    #[error("{}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n"))]
+    Multiple(Vec<GeneratorError>),
 }
 
 impl GeneratorError {
@@ -21,12 +26,41 @@ impl GeneratorError {
         match self {
             GeneratorError::Syn(err) => err.to_compile_error(),
             GeneratorError::Darling(err) => err.write_errors(),
+            GeneratorError::Multiple(errors) => errors
+                .into_iter()
+                .map(GeneratorError::write_errors)
+                .collect(),
+        }
+    }
+
+    /// Report all the errors, if there are any.
+    pub fn from_errors(mut errors: Vec<GeneratorError>) -> GeneratorResult<()> {
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(errors.remove(0)),
+            _ => Err(GeneratorError::Multiple(errors)),
         }
     }
 }
 
 pub type GeneratorResult<T> = std::result::Result<T, GeneratorError>;
 
+/// Make the crate being compiled depend on its manifest, which sets the default rename rules, so
+/// that it's rebuilt when they change.
+pub fn depend_on_manifest(expanded: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let mut expanded = TokenStream::from(expanded);
+    if let Ok(manifest_dir) = std::env::var("CARGO_MANIFEST_DIR") {
+        let path = std::path::Path::new(&manifest_dir).join("Cargo.toml");
+        if path.is_file() {
+            let path = path.to_string_lossy();
+            expanded.extend(quote! {
+                const _: &[u8] = ::std::include_bytes!(#path);
+            });
+        }
+    }
+    expanded.into()
+}
+
 pub fn get_crate_name(internal: bool) -> TokenStream {
     if internal {
         quote! { crate }
@@ -118,11 +152,39 @@ pub fn generate_validator(crate_name: &TokenStream, args: &Meta) -> GeneratorRes
     }
 }
 
+pub fn generate_custom_validator(
+    name: &str,
+    ident: &Ident,
+    validator: &LitStr,
+) -> GeneratorResult<TokenStream> {
+    let validator = syn::parse_str::<Expr>(&validator.value())?;
+    Ok(quote! {
+        ctx.check_param(#name, &#ident, &(#validator)).await?;
+    })
+}
+
 pub fn generate_guards(
     crate_name: &TokenStream,
     args: &Meta,
+    params: &[Ident],
 ) -> GeneratorResult<Option<TokenStream>> {
     match args {
+        Meta::NameValue(nv) if nv.path.is_ident("guard") => {
+            if let Lit::Str(value) = &nv.lit {
+                // The arguments are available to the expression by reference.
+                let expr = syn::parse_str::<Expr>(&value.value())?;
+                Ok(Some(quote! {
+                    ({
+                        #[allow(unused_imports)]
+                        use #crate_name::guard::GuardExt as _;
+                        #(#[allow(unused_variables)] let #params = &#params;)*
+                        #expr
+                    })
+                }))
+            } else {
+                Err(Error::new_spanned(&nv.lit, "Value must be string literal").into())
+            }
+        }
         Meta::List(args) => match args.path.get_ident() {
             Some(ident) => match ident.to_string().as_str() {
                 "guard" => {
@@ -134,7 +196,7 @@ pub fn generate_guards(
                         .into());
                     }
                     if let NestedMeta::Meta(rule) = &args.nested[0] {
-                        generate_guards(crate_name, rule)
+                        generate_guards(crate_name, rule, params)
                     } else {
                         Err(Error::new_spanned(&args.nested[0], "Invalid rule.").into())
                     }
@@ -150,12 +212,12 @@ pub fn generate_guards(
                     let first_rule: Option<TokenStream>;
                     let second_rule: Option<TokenStream>;
                     if let NestedMeta::Meta(rule) = &args.nested[0] {
-                        first_rule = generate_guards(crate_name, rule)?;
+                        first_rule = generate_guards(crate_name, rule, params)?;
                     } else {
                         return Err(Error::new_spanned(&args.nested[0], "Invalid rule.").into());
                     }
                     if let NestedMeta::Meta(rule) = &args.nested[1] {
-                        second_rule = generate_guards(crate_name, rule)?;
+                        second_rule = generate_guards(crate_name, rule, params)?;
                     } else {
                         return Err(Error::new_spanned(&args.nested[1], "Invalid rule.").into());
                     }
@@ -174,12 +236,12 @@ pub fn generate_guards(
                     let first_rule: Option<TokenStream>;
                     let second_rule: Option<TokenStream>;
                     if let NestedMeta::Meta(rule) = &args.nested[0] {
-                        first_rule = generate_guards(crate_name, rule)?;
+                        first_rule = generate_guards(crate_name, rule, params)?;
                     } else {
                         return Err(Error::new_spanned(&args.nested[0], "Invalid rule.").into());
                     }
                     if let NestedMeta::Meta(rule) = &args.nested[1] {
-                        second_rule = generate_guards(crate_name, rule)?;
+                        second_rule = generate_guards(crate_name, rule, params)?;
                     } else {
                         return Err(Error::new_spanned(&args.nested[1], "Invalid rule.").into());
                     }
@@ -187,6 +249,21 @@ pub fn generate_guards(
                         quote! { #crate_name::guard::GuardExt::or(#first_rule, #second_rule) },
                     ))
                 }
+                "not" => {
+                    if args.nested.len() != 1 {
+                        return Err(Error::new_spanned(
+                            args,
+                            "not operator support only 1 operand.",
+                        )
+                        .into());
+                    }
+                    if let NestedMeta::Meta(rule) = &args.nested[0] {
+                        let rule = generate_guards(crate_name, rule, params)?;
+                        Ok(Some(quote! { #crate_name::guard::GuardExt::not(#rule) }))
+                    } else {
+                        Err(Error::new_spanned(&args.nested[0], "Invalid rule.").into())
+                    }
+                }
                 "chain" => {
                     if args.nested.len() < 2 {
                         return Err(Error::new_spanned(
@@ -198,7 +275,7 @@ pub fn generate_guards(
                     let mut guards: Option<TokenStream> = None;
                     for arg in &args.nested {
                         if let NestedMeta::Meta(rule) = &arg {
-                            let guard = generate_guards(crate_name, rule)?;
+                            let guard = generate_guards(crate_name, rule, params)?;
                             if guards.is_none() {
                                 guards = guard;
                             } else {
@@ -221,7 +298,7 @@ pub fn generate_guards(
                     let mut guards: Option<TokenStream> = None;
                     for arg in &args.nested {
                         if let NestedMeta::Meta(rule) = &arg {
-                            let guard = generate_guards(crate_name, rule)?;
+                            let guard = generate_guards(crate_name, rule, params)?;
                             if guards.is_none() {
                                 guards = guard;
                             } else {
@@ -266,6 +343,8 @@ pub fn generate_guards(
             },
             None => Err(Error::new_spanned(args, "Invalid guards").into()),
         },
+        // A guard without properties, e.g. a unit struct.
+        Meta::Path(path) => Ok(Some(quote! { #path })),
         _ => Err(Error::new_spanned(args, "Invalid guards").into()),
     }
 }
@@ -319,7 +398,7 @@ fn generate_default_value(lit: &Lit) -> GeneratorResult<TokenStream> {
     }
 }
 
-fn generate_default_with(lit: &LitStr) -> GeneratorResult<TokenStream> {
+pub fn generate_default_with(lit: &LitStr) -> GeneratorResult<TokenStream> {
     let str = lit.value();
     let tokens: TokenStream = str
         .parse()
@@ -341,6 +420,113 @@ pub fn generate_default(
     }
 }
 
+/// The default value of an argument computed from the context, and the type of the argument in
+/// the schema, which is nullable because the default value is not known in advance.
+pub fn generate_default_with_ctx(
+    crate_name: &TokenStream,
+    ty: &impl ToTokens,
+    default: &Option<args::DefaultValue>,
+    default_with: &Option<LitStr>,
+    default_with_ctx: &Option<LitStr>,
+) -> GeneratorResult<Option<(TokenStream, TokenStream)>> {
+    let lit = match default_with_ctx {
+        Some(lit) => lit,
+        None => return Ok(None),
+    };
+    if default.is_some() || default_with.is_some() {
+        return Err(Error::new_spanned(
+            lit,
+            "default_with_ctx cannot be used with default or default_with",
+        )
+        .into());
+    }
+    let expr = generate_default_with(lit)?;
+    Ok(Some((
+        quote! { |ctx: &#crate_name::Context<'_>| -> #crate_name::Result<#ty> { ::std::result::Result::Ok(#expr) } },
+        quote! {
+            ::std::string::ToString::to_string(
+                &#crate_name::registry::MetaTypeName::create(
+                    &<#ty as #crate_name::Type>::create_type_info(registry)
+                ).unwrap_non_null()
+            )
+        },
+    )))
+}
+
+pub fn generate_metadata(
+    crate_name: &TokenStream,
+    field_name: Option<&str>,
+    meta: &[args::MetaEntry],
+) -> TokenStream {
+    if meta.is_empty() {
+        return quote! {};
+    }
+    // The coordinate uses the name the type is registered with, which may differ from the
+    // declared name.
+    let coordinate = match field_name {
+        Some(field_name) => quote! {
+            ::std::format!("{}.{}", <Self as #crate_name::Type>::type_name(), #field_name)
+        },
+        None => quote! {
+            ::std::string::ToString::to_string(&<Self as #crate_name::Type>::type_name())
+        },
+    };
+    let entries = meta.iter().map(|args::MetaEntry { key, value }| {
+        quote! {
+            registry.add_metadata(#coordinate, #key, #value);
+        }
+    });
+    quote! { #(#entries)* }
+}
+
+pub fn generate_list_concurrency(
+    list_concurrency: Option<usize>,
+    field: &Ident,
+) -> GeneratorResult<Option<TokenStream>> {
+    match list_concurrency {
+        Some(0) => {
+            Err(Error::new_spanned(field, "The list_concurrency must be greater than 0.").into())
+        }
+        Some(list_concurrency) => Ok(Some(quote!(.with_list_concurrency(#list_concurrency)))),
+        None => Ok(None),
+    }
+}
+
+pub fn generate_retry_policy(
+    crate_name: &TokenStream,
+    retry: &args::Retry,
+    field: &Ident,
+) -> GeneratorResult<TokenStream> {
+    let attempts = retry.attempts;
+    if attempts == 0 {
+        return Err(Error::new_spanned(field, "The retry attempts must be greater than 0.").into());
+    }
+    let backoff = match retry.backoff.as_deref() {
+        None => None,
+        Some("constant") => Some(quote!(.backoff(#crate_name::resolver_utils::Backoff::Constant))),
+        Some("exponential") => {
+            Some(quote!(.backoff(#crate_name::resolver_utils::Backoff::Exponential)))
+        }
+        Some(_) => {
+            return Err(Error::new_spanned(
+                field,
+                "The retry backoff must be \"constant\" or \"exponential\".",
+            )
+            .into())
+        }
+    };
+    let delay = retry
+        .delay
+        .map(|delay| quote!(.delay(::std::time::Duration::from_millis(#delay))));
+    let predicate = retry
+        .predicate
+        .as_ref()
+        .map(|predicate| quote!(.predicate(#predicate)));
+    Ok(quote! {
+        #crate_name::resolver_utils::RetryPolicy::new(#attempts)#backoff#delay#predicate
+    })
+}
+
 pub fn get_param_getter_ident(name: &str) -> Ident {
     Ident::new(&format!("__{}_getter", name), Span::call_site())
 }
@@ -353,16 +539,88 @@ pub fn get_cfg_attrs(attrs: &[Attribute]) -> Vec<Attribute> {
         .collect()
 }
 
-pub fn parse_graphql_attrs<T: FromMeta>(attrs: &[Attribute]) -> GeneratorResult<Option<T>> {
+pub fn parse_graphql_attrs<T: FromMeta + ConflictingAttrs>(
+    attrs: &[Attribute],
+) -> GeneratorResult<Option<T>> {
     for attr in attrs {
         if attr.path.is_ident("graphql") {
             let meta = attr.parse_meta()?;
-            return Ok(Some(T::from_meta(&meta)?));
+            let conflicts = check_conflicts(&meta, T::CONFLICTS);
+            return match (conflicts, T::from_meta(&meta)) {
+                (Ok(()), Ok(value)) => Ok(Some(value)),
+                (Ok(()), Err(err)) => Err(err.into()),
+                (Err(err), Ok(_)) => Err(err),
+                (Err(conflicts), Err(err)) => {
+                    Err(GeneratorError::Multiple(vec![conflicts, err.into()]))
+                }
+            };
         }
     }
     Ok(None)
 }
 
+/// Report the attributes of `meta` that can't be used with another one of its attributes, at the
+/// span of the attribute.
+fn check_conflicts(meta: &Meta, conflicts: &[(&str, &str)]) -> GeneratorResult<()> {
+    let items: Vec<(String, &Meta)> = match meta {
+        Meta::List(list) => list
+            .nested
+            .iter()
+            .filter_map(|nested| match nested {
+                NestedMeta::Meta(meta) => meta
+                    .path()
+                    .get_ident()
+                    .map(|ident| (ident.to_string(), meta)),
+                NestedMeta::Lit(_) => None,
+            })
+            .collect(),
+        _ => return Ok(()),
+    };
+
+    let mut errors: Option<Error> = None;
+    for (name, conflict) in conflicts {
+        if !items.iter().any(|(item, _)| item == name) {
+            continue;
+        }
+        for (item, meta) in &items {
+            if item != name && (*conflict == "*" || item == conflict) {
+                let err =
+                    Error::new_spanned(meta, format!("`{}` can't be used with `{}`.", item, name));
+                match &mut errors {
+                    Some(errors) => errors.combine(err),
+                    None => errors = Some(err),
+                }
+            }
+        }
+    }
+    errors.map_or(Ok(()), |err| Err(err.into()))
+}
+
+/// Parse the attributes of the methods of an impl block and of their arguments, so that the errors
+/// in all of them are reported at once instead of only the first one.
+pub fn check_method_attrs<F, A>(item_impl: &ItemImpl) -> GeneratorResult<()>
+where
+    F: FromMeta + ConflictingAttrs,
+    A: FromMeta + ConflictingAttrs,
+{
+    let mut errors = Vec::new();
+    for item in &item_impl.items {
+        if let ImplItem::Method(method) = item {
+            if let Err(err) = parse_graphql_attrs::<F>(&method.attrs) {
+                errors.push(err);
+            }
+            for arg in &method.sig.inputs {
+                if let FnArg::Typed(pat) = arg {
+                    if let Err(err) = parse_graphql_attrs::<A>(&pat.attrs) {
+                        errors.push(err);
+                    }
+                }
+            }
+        }
+    }
+    GeneratorError::from_errors(errors)
+}
+
 pub fn remove_graphql_attrs(attrs: &mut Vec<Attribute>) {
     if let Some((idx, _)) = attrs
         .iter()
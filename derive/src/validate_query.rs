@@ -0,0 +1,12 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Error, LitStr};
+
+use crate::utils::GeneratorResult;
+
+pub fn generate(query: &LitStr) -> GeneratorResult<TokenStream> {
+    if let Err(err) = async_graphql_parser::parse_query(query.value()) {
+        return Err(Error::new_spanned(query, format!("Invalid query: {}", err)).into());
+    }
+    Ok(quote!(#query).into())
+}
@@ -1,3 +1,4 @@
+use async_graphql::http::{WebSocket, WebSocketProtocols};
 use async_graphql::{Data, ObjectType, Result, Schema, SubscriptionType};
 use futures_util::{future, StreamExt};
 use warp::filters::ws;
@@ -64,28 +65,38 @@ where
     Subscription: SubscriptionType + Send + Sync + 'static,
     F: FnOnce(serde_json::Value) -> Result<Data> + Send + Sync + Clone + 'static,
 {
-    warp::ws().map(move |ws: ws::Ws| {
-        let schema = schema.clone();
-        let initializer = initializer.clone();
+    warp::ws()
+        .and(warp::header::optional::<String>("sec-websocket-protocol"))
+        .map(move |ws: ws::Ws, protocols: Option<String>| {
+            let schema = schema.clone();
+            let initializer = initializer.clone();
+            let protocol = protocols
+                .and_then(|protocols| WebSocketProtocols::from_sec_websocket_protocol(&protocols))
+                .unwrap_or_default();
 
-        let reply = ws.on_upgrade(move |websocket| {
-            let (ws_sender, ws_receiver) = websocket.split();
+            let reply = ws.on_upgrade(move |websocket| {
+                let (ws_sender, ws_receiver) = websocket.split();
 
-            async move {
-                let _ = async_graphql::http::WebSocket::with_data(
-                    schema,
-                    ws_receiver
-                        .take_while(|msg| future::ready(msg.is_ok()))
-                        .map(Result::unwrap)
-                        .map(ws::Message::into_bytes),
-                    initializer,
-                )
-                .map(ws::Message::text)
-                .map(Ok)
-                .forward(ws_sender)
-                .await;
-            }
-        });
-        warp::reply::with_header(reply, "Sec-WebSocket-Protocol", "graphql-ws")
-    })
+                async move {
+                    let _ = WebSocket::with_data(
+                        schema,
+                        ws_receiver
+                            .take_while(|msg| future::ready(msg.is_ok()))
+                            .map(Result::unwrap)
+                            .map(ws::Message::into_bytes),
+                        initializer,
+                    )
+                    .protocol(protocol)
+                    .map(ws::Message::text)
+                    .map(Ok)
+                    .forward(ws_sender)
+                    .await;
+                }
+            });
+            warp::reply::with_header(
+                reply,
+                "Sec-WebSocket-Protocol",
+                protocol.sec_websocket_protocol(),
+            )
+        })
 }
@@ -0,0 +1,592 @@
+//! Conversions between executable documents of this crate and the
+//! [`graphql-parser`](https://crates.io/crates/graphql-parser) crate.
+//!
+//! Converting into a `graphql_parser::query::Document` is infallible; integers which do not fit
+//! in an `i32` are converted into floats, as `graphql-parser` cannot represent them otherwise.
+//! Converting from a `graphql_parser::query::Document` performs the same checks as
+//! [`parse_query`](fn.parse_query.html).
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+use ::graphql_parser::query as gp;
+use ::graphql_parser::Pos as GpPos;
+use async_graphql_value::{Name, Number, Value};
+
+use crate::parse::{
+    build_document, DefinitionItem, FragmentDefinitionItem, OperationDefinitionItem,
+};
+use crate::types::*;
+use crate::{Error, Pos, Positioned, Result};
+
+impl From<GpPos> for Pos {
+    fn from(pos: GpPos) -> Self {
+        Self {
+            line: pos.line,
+            column: pos.column,
+        }
+    }
+}
+
+impl From<Pos> for GpPos {
+    fn from(pos: Pos) -> Self {
+        Self {
+            line: pos.line,
+            column: pos.column,
+        }
+    }
+}
+
+impl<'a, T: gp::Text<'a>> From<gp::Type<'a, T>> for Type {
+    fn from(ty: gp::Type<'a, T>) -> Self {
+        match ty {
+            gp::Type::NamedType(name) => Self {
+                base: BaseType::Named(Name::new(name)),
+                nullable: true,
+            },
+            gp::Type::ListType(ty) => Self {
+                base: BaseType::List(Box::new(Self::from(*ty))),
+                nullable: true,
+            },
+            gp::Type::NonNullType(ty) => Self {
+                nullable: false,
+                ..Self::from(*ty)
+            },
+        }
+    }
+}
+
+impl From<Type> for gp::Type<'static, String> {
+    fn from(ty: Type) -> Self {
+        let base = match ty.base {
+            BaseType::Named(name) => gp::Type::NamedType(name.to_string()),
+            BaseType::List(ty) => gp::Type::ListType(Box::new(Self::from(*ty))),
+        };
+        if ty.nullable {
+            base
+        } else {
+            gp::Type::NonNullType(Box::new(base))
+        }
+    }
+}
+
+impl<'a, T: gp::Text<'a>> TryFrom<gp::Document<'a, T>> for ExecutableDocument {
+    type Error = Error;
+
+    fn try_from(doc: gp::Document<'a, T>) -> Result<Self> {
+        build_document(
+            doc.definitions
+                .into_iter()
+                .map(|definition| match definition {
+                    gp::Definition::Operation(operation) => {
+                        convert_operation(operation).map(DefinitionItem::Operation)
+                    }
+                    gp::Definition::Fragment(fragment) => {
+                        convert_fragment(fragment).map(DefinitionItem::Fragment)
+                    }
+                })
+                .collect::<Result<_>>()?,
+        )
+    }
+}
+
+impl From<ExecutableDocument> for gp::Document<'static, String> {
+    fn from(doc: ExecutableDocument) -> Self {
+        let mut definitions: Vec<(Pos, gp::Definition<'static, String>)> = Vec::new();
+
+        match doc.operations {
+            DocumentOperations::Single(operation) => {
+                definitions.push((
+                    operation.pos,
+                    gp::Definition::Operation(export_operation(None, operation)),
+                ));
+            }
+            DocumentOperations::Multiple(operations) => {
+                definitions.extend(operations.into_iter().map(|(name, operation)| {
+                    (
+                        operation.pos,
+                        gp::Definition::Operation(export_operation(Some(name), operation)),
+                    )
+                }));
+            }
+        }
+
+        definitions.extend(doc.fragments.into_iter().map(|(name, fragment)| {
+            (
+                fragment.pos,
+                gp::Definition::Fragment(gp::FragmentDefinition {
+                    position: fragment.pos.into(),
+                    name: name.to_string(),
+                    type_condition: gp::TypeCondition::On(
+                        fragment.node.type_condition.node.on.node.to_string(),
+                    ),
+                    directives: export_directives(fragment.node.directives),
+                    selection_set: export_selection_set(fragment.node.selection_set),
+                }),
+            )
+        }));
+
+        // Operations and fragments are stored in hash maps, so restore the source order.
+        definitions.sort_by_key(|(pos, _)| *pos);
+
+        Self {
+            definitions: definitions
+                .into_iter()
+                .map(|(_, definition)| definition)
+                .collect(),
+        }
+    }
+}
+
+fn convert_operation<'a, T: gp::Text<'a>>(
+    operation: gp::OperationDefinition<'a, T>,
+) -> Result<Positioned<OperationDefinitionItem>> {
+    let (ty, position, name, variable_definitions, directives, selection_set) = match operation {
+        gp::OperationDefinition::SelectionSet(selection_set) => {
+            let pos = Pos::from(selection_set.span.0);
+            return Ok(Positioned::new(
+                OperationDefinitionItem {
+                    name: None,
+                    definition: OperationDefinition {
+                        ty: OperationType::Query,
+                        variable_definitions: Vec::new(),
+                        directives: Vec::new(),
+                        selection_set: convert_selection_set(selection_set)?,
+                    },
+                },
+                pos,
+            ));
+        }
+        gp::OperationDefinition::Query(query) => (
+            OperationType::Query,
+            query.position,
+            query.name,
+            query.variable_definitions,
+            query.directives,
+            query.selection_set,
+        ),
+        gp::OperationDefinition::Mutation(mutation) => (
+            OperationType::Mutation,
+            mutation.position,
+            mutation.name,
+            mutation.variable_definitions,
+            mutation.directives,
+            mutation.selection_set,
+        ),
+        gp::OperationDefinition::Subscription(subscription) => (
+            OperationType::Subscription,
+            subscription.position,
+            subscription.name,
+            subscription.variable_definitions,
+            subscription.directives,
+            subscription.selection_set,
+        ),
+    };
+    let pos = Pos::from(position);
+
+    Ok(Positioned::new(
+        OperationDefinitionItem {
+            name: name.map(|name| Positioned::new(Name::new(name), pos)),
+            definition: OperationDefinition {
+                ty,
+                variable_definitions: variable_definitions
+                    .into_iter()
+                    .map(convert_variable_definition)
+                    .collect::<Result<_>>()?,
+                directives: convert_directives(directives)?,
+                selection_set: convert_selection_set(selection_set)?,
+            },
+        },
+        pos,
+    ))
+}
+
+fn convert_fragment<'a, T: gp::Text<'a>>(
+    fragment: gp::FragmentDefinition<'a, T>,
+) -> Result<Positioned<FragmentDefinitionItem>> {
+    let pos = Pos::from(fragment.position);
+    let gp::TypeCondition::On(on) = fragment.type_condition;
+
+    Ok(Positioned::new(
+        FragmentDefinitionItem {
+            name: Positioned::new(Name::new(fragment.name), pos),
+            definition: FragmentDefinition {
+                // graphql-parser doesn't support fragment arguments.
+                variable_definitions: Vec::new(),
+                type_condition: Positioned::new(
+                    TypeCondition {
+                        on: Positioned::new(Name::new(on), pos),
+                    },
+                    pos,
+                ),
+                directives: convert_directives(fragment.directives)?,
+                selection_set: convert_selection_set(fragment.selection_set)?,
+            },
+        },
+        pos,
+    ))
+}
+
+fn convert_variable_definition<'a, T: gp::Text<'a>>(
+    definition: gp::VariableDefinition<'a, T>,
+) -> Result<Positioned<VariableDefinition>> {
+    let pos = Pos::from(definition.position);
+
+    Ok(Positioned::new(
+        VariableDefinition {
+            name: Positioned::new(Name::new(definition.name), pos),
+            var_type: Positioned::new(definition.var_type.into(), pos),
+            default_value: definition
+                .default_value
+                .map(|value| {
+                    convert_value(value, pos)?
+                        .into_const()
+                        .map(|value| Positioned::new(value, pos))
+                        .ok_or_else(|| Error::Syntax {
+                            message: "variables are not allowed in constant values".to_string(),
+                            start: pos,
+                            end: None,
+                        })
+                })
+                .transpose()?,
+            // graphql-parser doesn't support directives on variable definitions.
+            directives: Vec::new(),
+        },
+        pos,
+    ))
+}
+
+fn convert_selection_set<'a, T: gp::Text<'a>>(
+    selection_set: gp::SelectionSet<'a, T>,
+) -> Result<Positioned<SelectionSet>> {
+    Ok(Positioned::new(
+        SelectionSet {
+            items: selection_set
+                .items
+                .into_iter()
+                .map(convert_selection)
+                .collect::<Result<_>>()?,
+        },
+        selection_set.span.0.into(),
+    ))
+}
+
+fn convert_selection<'a, T: gp::Text<'a>>(
+    selection: gp::Selection<'a, T>,
+) -> Result<Positioned<Selection>> {
+    Ok(match selection {
+        gp::Selection::Field(field) => {
+            let pos = Pos::from(field.position);
+            Positioned::new(
+                Selection::Field(Positioned::new(
+                    Field {
+                        alias: field
+                            .alias
+                            .map(|alias| Positioned::new(Name::new(alias), pos)),
+                        name: Positioned::new(Name::new(field.name), pos),
+                        arguments: convert_arguments(field.arguments, pos)?,
+                        // graphql-parser doesn't support nullability designators.
+                        nullability: None,
+                        directives: convert_directives(field.directives)?,
+                        selection_set: convert_selection_set(field.selection_set)?,
+                    },
+                    pos,
+                )),
+                pos,
+            )
+        }
+        gp::Selection::FragmentSpread(spread) => {
+            let pos = Pos::from(spread.position);
+            Positioned::new(
+                Selection::FragmentSpread(Positioned::new(
+                    FragmentSpread {
+                        fragment_name: Positioned::new(Name::new(spread.fragment_name), pos),
+                        arguments: Vec::new(),
+                        directives: convert_directives(spread.directives)?,
+                    },
+                    pos,
+                )),
+                pos,
+            )
+        }
+        gp::Selection::InlineFragment(fragment) => {
+            let pos = Pos::from(fragment.position);
+            Positioned::new(
+                Selection::InlineFragment(Positioned::new(
+                    InlineFragment {
+                        type_condition: fragment.type_condition.map(|gp::TypeCondition::On(on)| {
+                            Positioned::new(
+                                TypeCondition {
+                                    on: Positioned::new(Name::new(on), pos),
+                                },
+                                pos,
+                            )
+                        }),
+                        directives: convert_directives(fragment.directives)?,
+                        selection_set: convert_selection_set(fragment.selection_set)?,
+                    },
+                    pos,
+                )),
+                pos,
+            )
+        }
+    })
+}
+
+fn convert_directives<'a, T: gp::Text<'a>>(
+    directives: Vec<gp::Directive<'a, T>>,
+) -> Result<Vec<Positioned<Directive>>> {
+    directives
+        .into_iter()
+        .map(|directive| {
+            let pos = Pos::from(directive.position);
+            Ok(Positioned::new(
+                Directive {
+                    name: Positioned::new(Name::new(directive.name), pos),
+                    arguments: convert_arguments(directive.arguments, pos)?,
+                },
+                pos,
+            ))
+        })
+        .collect()
+}
+
+fn convert_arguments<'a, T: gp::Text<'a>>(
+    arguments: Vec<(T::Value, gp::Value<'a, T>)>,
+    pos: Pos,
+) -> Result<Vec<(Positioned<Name>, Positioned<Value>)>> {
+    arguments
+        .into_iter()
+        .map(|(name, value)| {
+            Ok((
+                Positioned::new(Name::new(name), pos),
+                Positioned::new(convert_value(value, pos)?, pos),
+            ))
+        })
+        .collect()
+}
+
+fn convert_value<'a, T: gp::Text<'a>>(value: gp::Value<'a, T>, pos: Pos) -> Result<Value> {
+    Ok(match value {
+        gp::Value::Variable(name) => Value::Variable(Name::new(name)),
+        gp::Value::Int(n) => Value::Number(n.as_i64().unwrap_or_default().into()),
+        gp::Value::Float(n) => Value::Number(Number::from_f64(n).ok_or_else(|| Error::Syntax {
+            message: format!("invalid float {}", n),
+            start: pos,
+            end: None,
+        })?),
+        gp::Value::String(s) => Value::String(s),
+        gp::Value::Boolean(b) => Value::Boolean(b),
+        gp::Value::Null => Value::Null,
+        gp::Value::Enum(name) => Value::Enum(Name::new(name)),
+        gp::Value::List(items) => Value::List(
+            items
+                .into_iter()
+                .map(|item| convert_value(item, pos))
+                .collect::<Result<_>>()?,
+        ),
+        gp::Value::Object(fields) => Value::Object(
+            fields
+                .into_iter()
+                .map(|(name, value)| Ok((Name::new(name), convert_value(value, pos)?)))
+                .collect::<Result<_>>()?,
+        ),
+    })
+}
+
+fn export_operation(
+    name: Option<Name>,
+    operation: Positioned<OperationDefinition>,
+) -> gp::OperationDefinition<'static, String> {
+    let position = operation.pos.into();
+    let name = name.map(|name| name.to_string());
+    let operation = operation.node;
+
+    if name.is_none()
+        && operation.ty == OperationType::Query
+        && operation.variable_definitions.is_empty()
+        && operation.directives.is_empty()
+    {
+        return gp::OperationDefinition::SelectionSet(export_selection_set(
+            operation.selection_set,
+        ));
+    }
+
+    let variable_definitions = operation
+        .variable_definitions
+        .into_iter()
+        .map(|definition| gp::VariableDefinition {
+            position: definition.pos.into(),
+            name: definition.node.name.node.to_string(),
+            var_type: definition.node.var_type.node.into(),
+            default_value: definition
+                .node
+                .default_value
+                .map(|value| export_value(value.node.into_value())),
+        })
+        .collect();
+    let directives = export_directives(operation.directives);
+    let selection_set = export_selection_set(operation.selection_set);
+
+    match operation.ty {
+        OperationType::Query => gp::OperationDefinition::Query(gp::Query {
+            position,
+            name,
+            variable_definitions,
+            directives,
+            selection_set,
+        }),
+        OperationType::Mutation => gp::OperationDefinition::Mutation(gp::Mutation {
+            position,
+            name,
+            variable_definitions,
+            directives,
+            selection_set,
+        }),
+        OperationType::Subscription => gp::OperationDefinition::Subscription(gp::Subscription {
+            position,
+            name,
+            variable_definitions,
+            directives,
+            selection_set,
+        }),
+    }
+}
+
+fn export_selection_set(
+    selection_set: Positioned<SelectionSet>,
+) -> gp::SelectionSet<'static, String> {
+    let pos = GpPos::from(selection_set.pos);
+
+    gp::SelectionSet {
+        span: (pos, pos),
+        items: selection_set
+            .node
+            .items
+            .into_iter()
+            .map(|selection| match selection.node {
+                Selection::Field(field) => gp::Selection::Field(gp::Field {
+                    position: field.pos.into(),
+                    alias: field.node.alias.map(|alias| alias.node.to_string()),
+                    name: field.node.name.node.to_string(),
+                    arguments: export_arguments(field.node.arguments),
+                    directives: export_directives(field.node.directives),
+                    selection_set: export_selection_set(field.node.selection_set),
+                }),
+                Selection::FragmentSpread(spread) => {
+                    gp::Selection::FragmentSpread(gp::FragmentSpread {
+                        position: spread.pos.into(),
+                        fragment_name: spread.node.fragment_name.node.to_string(),
+                        directives: export_directives(spread.node.directives),
+                    })
+                }
+                Selection::InlineFragment(fragment) => {
+                    gp::Selection::InlineFragment(gp::InlineFragment {
+                        position: fragment.pos.into(),
+                        type_condition: fragment.node.type_condition.map(|condition| {
+                            gp::TypeCondition::On(condition.node.on.node.to_string())
+                        }),
+                        directives: export_directives(fragment.node.directives),
+                        selection_set: export_selection_set(fragment.node.selection_set),
+                    })
+                }
+            })
+            .collect(),
+    }
+}
+
+fn export_directives(
+    directives: Vec<Positioned<Directive>>,
+) -> Vec<gp::Directive<'static, String>> {
+    directives
+        .into_iter()
+        .map(|directive| gp::Directive {
+            position: directive.pos.into(),
+            name: directive.node.name.node.to_string(),
+            arguments: export_arguments(directive.node.arguments),
+        })
+        .collect()
+}
+
+fn export_arguments(
+    arguments: Vec<(Positioned<Name>, Positioned<Value>)>,
+) -> Vec<(String, gp::Value<'static, String>)> {
+    arguments
+        .into_iter()
+        .map(|(name, value)| (name.node.to_string(), export_value(value.node)))
+        .collect()
+}
+
+fn export_value(value: Value) -> gp::Value<'static, String> {
+    match value {
+        Value::Variable(name) => gp::Value::Variable(name.to_string()),
+        Value::Null => gp::Value::Null,
+        Value::Number(n) => match n.as_i64() {
+            Some(i) if i >= i64::from(i32::MIN) && i <= i64::from(i32::MAX) => {
+                gp::Value::Int((i as i32).into())
+            }
+            _ => gp::Value::Float(n.as_f64().unwrap_or_default()),
+        },
+        Value::String(s) => gp::Value::String(s),
+        Value::Boolean(b) => gp::Value::Boolean(b),
+        Value::Enum(name) => gp::Value::Enum(name.to_string()),
+        Value::List(items) => gp::Value::List(items.into_iter().map(export_value).collect()),
+        Value::Object(fields) => gp::Value::Object(
+            fields
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), export_value(value)))
+                .collect::<BTreeMap<_, _>>(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_query;
+
+    #[test]
+    fn round_trip() {
+        let source = r#"
+            query Q($id: ID! = 1, $names: [String]) @a(x: $id) {
+                user(id: $id, big: 3000000000, filter: {name: "a", tags: [A, B]}) {
+                    ...F
+                    ... on User @skip(if: true) {
+                        name(upper: true)
+                    }
+                }
+            }
+
+            fragment F on User {
+                alias: id
+            }
+        "#;
+
+        let expected = gp::parse_query::<&str>(source).unwrap().to_string();
+        let doc = parse_query(source).unwrap();
+        assert_eq!(gp::Document::from(doc).to_string(), expected);
+
+        let doc = ExecutableDocument::try_from(gp::parse_query::<&str>(source).unwrap()).unwrap();
+        assert_eq!(gp::Document::from(doc).to_string(), expected);
+    }
+
+    #[test]
+    fn shorthand_query() {
+        let doc = ExecutableDocument::try_from(gp::parse_query::<&str>("{ a }").unwrap()).unwrap();
+        assert!(matches!(doc.operations, DocumentOperations::Single(_)));
+        assert_eq!(gp::Document::from(doc).to_string(), "{\n  a\n}\n");
+    }
+
+    #[test]
+    fn duplicated_operation() {
+        let doc = gp::parse_query::<&str>("query A { a } query A { b }").unwrap();
+        assert!(matches!(
+            ExecutableDocument::try_from(doc),
+            Err(Error::OperationDuplicated { .. })
+        ));
+    }
+}
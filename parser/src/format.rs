@@ -0,0 +1,433 @@
+//! Rendering executable documents back to GraphQL source.
+//!
+//! This is useful for logging normalized queries, or for tooling that modifies a parsed document.
+//!
+//! ```
+//! use async_graphql_parser::{format::{format_document, FormatOptions}, parse_query};
+//!
+//! let doc = parse_query("query Q($b: Int) { b(x: $b) a }").unwrap();
+//! assert_eq!(
+//!     format_document(&doc, &FormatOptions::default().sorted(true)),
+//!     "query Q($b: Int) {\n  a\n  b(x: $b)\n}\n",
+//! );
+//! ```
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use async_graphql_value::{Name, Value};
+
+use crate::types::*;
+use crate::Positioned;
+
+/// Options used by [`format_document`](fn.format_document.html).
+#[derive(Debug, Clone)]
+pub struct FormatOptions {
+    indent: String,
+    sorted: bool,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            indent: "  ".to_string(),
+            sorted: false,
+        }
+    }
+}
+
+impl FormatOptions {
+    /// Set the string used for a single level of indentation, two spaces by default.
+    #[must_use]
+    pub fn indent(self, indent: impl Into<String>) -> Self {
+        Self {
+            indent: indent.into(),
+            ..self
+        }
+    }
+
+    /// Whether to sort definitions, variables, arguments, selections and the fields of object
+    /// values by name, instead of keeping the order of the source. Disabled by default.
+    #[must_use]
+    pub fn sorted(self, sorted: bool) -> Self {
+        Self { sorted, ..self }
+    }
+}
+
+/// Render an executable document as GraphQL source.
+///
+/// Operations are written before fragments when sorting, otherwise all definitions are written
+/// in the order they appeared in the source.
+#[must_use]
+pub fn format_document(doc: &ExecutableDocument, options: &FormatOptions) -> String {
+    let mut definitions: Vec<Definition<'_>> = doc
+        .operations
+        .iter()
+        .map(|(name, operation)| Definition::Operation(name, operation))
+        .chain(
+            doc.fragments
+                .iter()
+                .map(|(name, fragment)| Definition::Fragment(name, fragment)),
+        )
+        .collect();
+
+    if options.sorted {
+        definitions.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+    } else {
+        definitions.sort_by_key(Definition::pos);
+    }
+
+    let mut writer = Writer {
+        options,
+        output: String::new(),
+        level: 0,
+    };
+    for (idx, definition) in definitions.into_iter().enumerate() {
+        if idx > 0 {
+            writer.output.push('\n');
+        }
+        match definition {
+            Definition::Operation(name, operation) => writer.write_operation(name, operation),
+            Definition::Fragment(name, fragment) => writer.write_fragment(name, fragment),
+        }
+    }
+    writer.output
+}
+
+enum Definition<'a> {
+    Operation(Option<&'a Name>, &'a Positioned<OperationDefinition>),
+    Fragment(&'a Name, &'a Positioned<FragmentDefinition>),
+}
+
+impl<'a> Definition<'a> {
+    fn pos(&self) -> crate::Pos {
+        match self {
+            Self::Operation(_, operation) => operation.pos,
+            Self::Fragment(_, fragment) => fragment.pos,
+        }
+    }
+
+    fn sort_key(&self) -> (bool, Option<&'a str>) {
+        match self {
+            Self::Operation(name, _) => (false, name.map(Name::as_str)),
+            Self::Fragment(name, _) => (true, Some(name.as_str())),
+        }
+    }
+}
+
+struct Writer<'a> {
+    options: &'a FormatOptions,
+    output: String,
+    level: usize,
+}
+
+impl<'a> Writer<'a> {
+    fn write_indent(&mut self) {
+        for _ in 0..self.level {
+            self.output.push_str(&self.options.indent);
+        }
+    }
+
+    fn write_operation(
+        &mut self,
+        name: Option<&Name>,
+        operation: &Positioned<OperationDefinition>,
+    ) {
+        let operation = &operation.node;
+        let shorthand = name.is_none()
+            && operation.ty == OperationType::Query
+            && operation.variable_definitions.is_empty()
+            && operation.directives.is_empty();
+
+        if !shorthand {
+            write!(self.output, "{}", operation.ty).unwrap();
+            if let Some(name) = name {
+                write!(self.output, " {}", name).unwrap();
+            }
+            self.write_variable_definitions(&operation.variable_definitions);
+            self.write_directives(&operation.directives);
+            self.output.push(' ');
+        }
+        self.write_selection_set(&operation.selection_set);
+        self.output.push('\n');
+    }
+
+    fn write_fragment(&mut self, name: &Name, fragment: &Positioned<FragmentDefinition>) {
+        let fragment = &fragment.node;
+        write!(self.output, "fragment {}", name).unwrap();
+        self.write_variable_definitions(&fragment.variable_definitions);
+        write!(self.output, " on {}", fragment.type_condition.node.on.node).unwrap();
+        self.write_directives(&fragment.directives);
+        self.output.push(' ');
+        self.write_selection_set(&fragment.selection_set);
+        self.output.push('\n');
+    }
+
+    fn write_variable_definitions(&mut self, definitions: &[Positioned<VariableDefinition>]) {
+        if definitions.is_empty() {
+            return;
+        }
+
+        let mut definitions: Vec<_> = definitions.iter().map(|def| &def.node).collect();
+        if self.options.sorted {
+            definitions.sort_by(|a, b| a.name.node.cmp(&b.name.node));
+        }
+
+        self.output.push('(');
+        for (idx, definition) in definitions.into_iter().enumerate() {
+            if idx > 0 {
+                self.output.push_str(", ");
+            }
+            write!(
+                self.output,
+                "${}: {}",
+                definition.name.node, definition.var_type.node
+            )
+            .unwrap();
+            if let Some(default_value) = &definition.default_value {
+                self.output.push_str(" = ");
+                self.write_value(&default_value.node.clone().into_value());
+            }
+            self.write_directives(&definition.directives);
+        }
+        self.output.push(')');
+    }
+
+    fn write_directives(&mut self, directives: &[Positioned<Directive>]) {
+        for directive in directives {
+            write!(self.output, " @{}", directive.node.name.node).unwrap();
+            self.write_arguments(&directive.node.arguments);
+        }
+    }
+
+    fn write_arguments(&mut self, arguments: &[(Positioned<Name>, Positioned<Value>)]) {
+        if arguments.is_empty() {
+            return;
+        }
+
+        let mut arguments: Vec<_> = arguments.iter().collect();
+        if self.options.sorted {
+            arguments.sort_by(|a, b| a.0.node.cmp(&b.0.node));
+        }
+
+        self.output.push('(');
+        for (idx, (name, value)) in arguments.into_iter().enumerate() {
+            if idx > 0 {
+                self.output.push_str(", ");
+            }
+            write!(self.output, "{}: ", name.node).unwrap();
+            self.write_value(&value.node);
+        }
+        self.output.push(')');
+    }
+
+    fn write_selection_set(&mut self, selection_set: &Positioned<SelectionSet>) {
+        let mut items: Vec<_> = selection_set.node.items.iter().map(|s| &s.node).collect();
+        if self.options.sorted {
+            // Fields come first, then fragment spreads, then inline fragments.
+            items.sort_by(|a, b| selection_sort_key(a).cmp(&selection_sort_key(b)));
+        }
+
+        self.output.push_str("{\n");
+        self.level += 1;
+        for selection in items {
+            self.write_indent();
+            match selection {
+                Selection::Field(field) => {
+                    let field = &field.node;
+                    if let Some(alias) = &field.alias {
+                        write!(self.output, "{}: ", alias.node).unwrap();
+                    }
+                    self.output.push_str(&field.name.node);
+                    self.write_arguments(&field.arguments);
+                    if let Some(nullability) = field.nullability {
+                        write!(self.output, "{}", nullability).unwrap();
+                    }
+                    self.write_directives(&field.directives);
+                    if !field.selection_set.node.items.is_empty() {
+                        self.output.push(' ');
+                        self.write_selection_set(&field.selection_set);
+                    }
+                }
+                Selection::FragmentSpread(spread) => {
+                    write!(self.output, "...{}", spread.node.fragment_name.node).unwrap();
+                    self.write_arguments(&spread.node.arguments);
+                    self.write_directives(&spread.node.directives);
+                }
+                Selection::InlineFragment(fragment) => {
+                    self.output.push_str("...");
+                    if let Some(type_condition) = &fragment.node.type_condition {
+                        write!(self.output, " on {}", type_condition.node.on.node).unwrap();
+                    }
+                    self.write_directives(&fragment.node.directives);
+                    self.output.push(' ');
+                    self.write_selection_set(&fragment.node.selection_set);
+                }
+            }
+            self.output.push('\n');
+        }
+        self.level -= 1;
+        self.write_indent();
+        self.output.push('}');
+    }
+
+    fn write_value(&mut self, value: &Value) {
+        match value {
+            Value::List(items) => {
+                self.output.push('[');
+                for (idx, item) in items.iter().enumerate() {
+                    if idx > 0 {
+                        self.output.push_str(", ");
+                    }
+                    self.write_value(item);
+                }
+                self.output.push(']');
+            }
+            Value::Object(fields) => {
+                let mut fields: Vec<_> = fields.iter().collect();
+                if self.options.sorted {
+                    fields.sort_by(|a, b| a.0.cmp(b.0));
+                }
+                self.output.push('{');
+                for (idx, (name, value)) in fields.into_iter().enumerate() {
+                    if idx > 0 {
+                        self.output.push_str(", ");
+                    }
+                    write!(self.output, "{}: ", name).unwrap();
+                    self.write_value(value);
+                }
+                self.output.push('}');
+            }
+            value => write!(self.output, "{}", value).unwrap(),
+        }
+    }
+}
+
+fn selection_sort_key(selection: &Selection) -> (u8, Option<&str>) {
+    match selection {
+        Selection::Field(field) => (0, Some(field.node.response_key().node.as_str())),
+        Selection::FragmentSpread(spread) => (1, Some(spread.node.fragment_name.node.as_str())),
+        Selection::InlineFragment(_) => (2, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_query;
+
+    const QUERY: &str = r#"
+        fragment B on T { z y }
+        query Q($b: [Int!] = [1, 2], $a: String) @dir(y: 1, x: {b: "s\n", a: ENUM}) {
+            ... on T @skip(if: false) { x }
+            ...B
+            b: field(y: $b, x: $a) { inner }
+            a
+        }
+        fragment A on T { a }
+    "#;
+
+    #[test]
+    fn original_order() {
+        let doc = parse_query(QUERY).unwrap();
+        assert_eq!(
+            format_document(&doc, &FormatOptions::default()),
+            r#"fragment B on T {
+  z
+  y
+}
+
+query Q($b: [Int!] = [1, 2], $a: String) @dir(y: 1, x: {b: "s\n", a: ENUM}) {
+  ... on T @skip(if: false) {
+    x
+  }
+  ...B
+  b: field(y: $b, x: $a) {
+    inner
+  }
+  a
+}
+
+fragment A on T {
+  a
+}
+"#
+        );
+    }
+
+    #[test]
+    fn sorted() {
+        let doc = parse_query(QUERY).unwrap();
+        let formatted = format_document(&doc, &FormatOptions::default().sorted(true).indent("\t"));
+        assert_eq!(
+            formatted,
+            r#"query Q($a: String, $b: [Int!] = [1, 2]) @dir(x: {a: ENUM, b: "s\n"}, y: 1) {
+	a
+	b: field(x: $a, y: $b) {
+		inner
+	}
+	...B
+	... on T @skip(if: false) {
+		x
+	}
+}
+
+fragment A on T {
+	a
+}
+
+fragment B on T {
+	y
+	z
+}
+"#
+        );
+
+        // Formatting is stable.
+        let doc = parse_query(&formatted).unwrap();
+        assert_eq!(
+            format_document(&doc, &FormatOptions::default().sorted(true).indent("\t")),
+            formatted
+        );
+    }
+
+    #[test]
+    fn variable_directives() {
+        let doc = parse_query("query($a: Int = 1 @dir(x: 1) @other) { a(x: $a) }").unwrap();
+        assert_eq!(
+            format_document(&doc, &FormatOptions::default()),
+            "query($a: Int = 1 @dir(x: 1) @other) {\n  a(x: $a)\n}\n"
+        );
+    }
+
+    #[test]
+    fn fragment_arguments() {
+        let doc = parse_query(
+            "query { ...F(b: 2, a: $a) } fragment F($b: Int = 1, $a: String) on T { x(y: $b) }",
+        )
+        .unwrap();
+        assert_eq!(
+            format_document(&doc, &FormatOptions::default().sorted(true)),
+            "{\n  ...F(a: $a, b: 2)\n}\n\nfragment F($a: String, $b: Int = 1) on T {\n  x(y: $b)\n}\n"
+        );
+    }
+
+    #[test]
+    fn nullability() {
+        let doc = parse_query("{ a! b(x: 1)? @skip(if: false) { c } }").unwrap();
+        assert_eq!(
+            format_document(&doc, &FormatOptions::default()),
+            "{\n  a!\n  b(x: 1)? @skip(if: false) {\n    c\n  }\n}\n"
+        );
+    }
+
+    #[test]
+    fn shorthand() {
+        let doc = parse_query("{ a { b } }").unwrap();
+        assert_eq!(
+            format_document(&doc, &FormatOptions::default()),
+            "{\n  a {\n    b\n  }\n}\n"
+        );
+    }
+}
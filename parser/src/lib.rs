@@ -3,21 +3,34 @@
 //!
 //! It uses the [pest](https://crates.io/crates/pest) crate to parse the input and then transforms
 //! it into Rust types.
+//!
+//! Without the default `std` feature the crate only depends on `alloc`, so documents can be
+//! parsed in `no_std` environments. This requires pest 2.4 or later, and the operations and
+//! fragments of an [`ExecutableDocument`](types/struct.ExecutableDocument.html) are stored in a
+//! `BTreeMap` instead of a `HashMap`.
 #![warn(missing_docs)]
 #![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 use crate::types::OperationType;
+use alloc::string::{String, ToString};
 use async_graphql_value::Name;
+use core::fmt::{self, Display, Formatter};
 use pest::error::LineColLocation;
 use pest::RuleType;
 use serde::{Serialize, Serializer};
-use std::fmt::{self, Display, Formatter};
 
 pub use parse::{parse_query, parse_schema};
 pub use pos::{Pos, Positioned};
 
+pub mod format;
 pub mod types;
+pub mod visit;
 
+#[cfg(feature = "graphql-parser")]
+mod compat;
 mod parse;
 mod pos;
 
@@ -127,6 +140,7 @@ impl Display for Error {
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
 impl<R: RuleType> From<pest::error::Error<R>> for Error {
@@ -145,7 +159,7 @@ impl<R: RuleType> From<pest::error::Error<R>> for Error {
 }
 
 /// An alias for `Result<T, Error>`.
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
 
 /// An iterator over the positions inside an error.
 ///
@@ -204,7 +218,7 @@ impl DoubleEndedIterator for ErrorPositions {
     }
 }
 
-impl std::iter::FusedIterator for ErrorPositions {}
+impl core::iter::FusedIterator for ErrorPositions {}
 
 impl ExactSizeIterator for ErrorPositions {
     fn len(&self) -> usize {
@@ -217,7 +231,7 @@ impl ExactSizeIterator for ErrorPositions {
 }
 
 impl Serialize for ErrorPositions {
-    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
         serializer.collect_seq(self.clone())
     }
 }
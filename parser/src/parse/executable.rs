@@ -17,6 +17,11 @@ pub fn parse_query<T: AsRef<str>>(input: T) -> Result<ExecutableDocument> {
         &mut pc,
     )?;
 
+    build_document(items)
+}
+
+/// Assemble the definitions of a document, checking that operations and fragments are unique.
+pub(crate) fn build_document(items: Vec<DefinitionItem>) -> Result<ExecutableDocument> {
     let mut operations = None;
     let mut fragments: HashMap<_, Positioned<FragmentDefinition>> = HashMap::new();
 
@@ -106,7 +111,7 @@ fn parse_definition_items(
         .collect::<Result<_>>()?)
 }
 
-enum DefinitionItem {
+pub(crate) enum DefinitionItem {
     Operation(Positioned<OperationDefinitionItem>),
     Fragment(Positioned<FragmentDefinitionItem>),
 }
@@ -126,9 +131,9 @@ fn parse_definition_item(pair: Pair<Rule>, pc: &mut PositionCalculator) -> Resul
     })
 }
 
-struct OperationDefinitionItem {
-    name: Option<Positioned<Name>>,
-    definition: OperationDefinition,
+pub(crate) struct OperationDefinitionItem {
+    pub(crate) name: Option<Positioned<Name>>,
+    pub(crate) definition: OperationDefinition,
 }
 
 fn parse_operation_definition_item(
@@ -211,6 +216,10 @@ fn parse_variable_definition(
     let default_value = parse_if_rule(&mut pairs, Rule::default_value, |pair| {
         parse_default_value(pair, pc)
     })?;
+    let directives = parse_opt_const_directives(&mut pairs, pc)?
+        .into_iter()
+        .map(|directive| directive.map(ConstDirective::into_directive))
+        .collect();
 
     debug_assert_eq!(pairs.next(), None);
 
@@ -219,6 +228,7 @@ fn parse_variable_definition(
             name: variable,
             var_type,
             default_value,
+            directives,
         },
         pos,
     ))
@@ -271,6 +281,13 @@ fn parse_field(pair: Pair<Rule>, pc: &mut PositionCalculator) -> Result<Position
     let arguments = parse_if_rule(&mut pairs, Rule::arguments, |pair| {
         parse_arguments(pair, pc)
     })?;
+    let nullability = parse_if_rule(&mut pairs, Rule::nullability_designator, |pair| {
+        Ok(match pair.as_str() {
+            "!" => Nullability::Required,
+            "?" => Nullability::Optional,
+            _ => unreachable!(),
+        })
+    })?;
     let directives = parse_opt_directives(&mut pairs, pc)?;
     let selection_set = parse_if_rule(&mut pairs, Rule::selection_set, |pair| {
         parse_selection_set(pair, pc)
@@ -283,6 +300,7 @@ fn parse_field(pair: Pair<Rule>, pc: &mut PositionCalculator) -> Result<Position
             alias,
             name,
             arguments: arguments.unwrap_or_default(),
+            nullability,
             directives,
             selection_set: selection_set.unwrap_or_default(),
         },
@@ -305,6 +323,9 @@ fn parse_fragment_spread(
     let mut pairs = pair.into_inner();
 
     let fragment_name = parse_name(pairs.next().unwrap(), pc)?;
+    let arguments = parse_if_rule(&mut pairs, Rule::arguments, |pair| {
+        parse_arguments(pair, pc)
+    })?;
     let directives = parse_opt_directives(&mut pairs, pc)?;
 
     debug_assert_eq!(pairs.next(), None);
@@ -312,6 +333,7 @@ fn parse_fragment_spread(
     Ok(Positioned::new(
         FragmentSpread {
             fragment_name,
+            arguments: arguments.unwrap_or_default(),
             directives,
         },
         pos,
@@ -345,9 +367,9 @@ fn parse_inline_fragment(
     ))
 }
 
-struct FragmentDefinitionItem {
-    name: Positioned<Name>,
-    definition: FragmentDefinition,
+pub(crate) struct FragmentDefinitionItem {
+    pub(crate) name: Positioned<Name>,
+    pub(crate) definition: FragmentDefinition,
 }
 
 fn parse_fragment_definition_item(
@@ -360,6 +382,9 @@ fn parse_fragment_definition_item(
     let mut pairs = pair.into_inner();
 
     let name = parse_name(pairs.next().unwrap(), pc)?;
+    let variable_definitions = parse_if_rule(&mut pairs, Rule::variable_definitions, |pair| {
+        parse_variable_definitions(pair, pc)
+    })?;
     let type_condition = parse_type_condition(pairs.next().unwrap(), pc)?;
     let directives = parse_opt_directives(&mut pairs, pc)?;
     let selection_set = parse_selection_set(pairs.next().unwrap(), pc)?;
@@ -370,6 +395,7 @@ fn parse_fragment_definition_item(
         FragmentDefinitionItem {
             name,
             definition: FragmentDefinition {
+                variable_definitions: variable_definitions.unwrap_or_default(),
                 type_condition,
                 directives,
                 selection_set,
@@ -425,6 +451,95 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_variable_directives() {
+        let doc = parse_query("query($a: Int = 1 @dir(x: 1), $b: Int) { a }").unwrap();
+        let operation = match &doc.operations {
+            DocumentOperations::Single(operation) => &operation.node,
+            _ => unreachable!(),
+        };
+        let definitions = &operation.variable_definitions;
+        assert_eq!(definitions[0].node.directives.len(), 1);
+        let directive = &definitions[0].node.directives[0].node;
+        assert_eq!(directive.name.node, "dir");
+        assert_eq!(
+            directive.get_argument("x").map(|value| &value.node),
+            Some(&Value::Number(1.into()))
+        );
+        assert!(definitions[1].node.directives.is_empty());
+
+        // The arguments of the directives must be constant.
+        assert!(parse_query("query($a: Int @dir(x: $a)) { a }").is_err());
+    }
+
+    #[test]
+    fn test_parse_fragment_arguments() {
+        let doc = parse_query(
+            "{ ...F(a: 1, b: $b) ...G } fragment F($a: Int = 0, $b: String!) on T { x(a: $a) } fragment G on T { y }",
+        )
+        .unwrap();
+        let fragment = &doc.fragments["F"].node;
+        assert_eq!(fragment.variable_definitions.len(), 2);
+        assert_eq!(fragment.variable_definitions[0].node.name.node, "a");
+        assert!(doc.fragments["G"].node.variable_definitions.is_empty());
+
+        let operation = match &doc.operations {
+            DocumentOperations::Single(operation) => &operation.node,
+            _ => unreachable!(),
+        };
+        let spreads: Vec<_> = operation
+            .selection_set
+            .node
+            .items
+            .iter()
+            .map(|selection| match &selection.node {
+                Selection::FragmentSpread(spread) => &spread.node,
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(
+            spreads[0]
+                .arguments
+                .iter()
+                .map(|(name, value)| (name.node.as_str(), value.node.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("a", Value::Number(1.into())),
+                ("b", Value::Variable(Name::new("b"))),
+            ]
+        );
+        assert!(spreads[1].arguments.is_empty());
+    }
+
+    #[test]
+    fn test_parse_nullability() {
+        let doc = parse_query("{ a! b(x: 1)? @skip(if: false) c }").unwrap();
+        let operation = match &doc.operations {
+            DocumentOperations::Single(operation) => &operation.node,
+            _ => unreachable!(),
+        };
+        let nullability: Vec<_> = operation
+            .selection_set
+            .node
+            .items
+            .iter()
+            .map(|selection| match &selection.node {
+                Selection::Field(field) => field.node.nullability,
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(
+            nullability,
+            vec![
+                Some(Nullability::Required),
+                Some(Nullability::Optional),
+                None
+            ]
+        );
+
+        assert!(parse_query("{ a!? }").is_err());
+    }
+
     #[test]
     fn test_parse_overflowing_int() {
         let query_ok = format!("mutation {{ add(big: {}) }} ", std::i32::MAX);
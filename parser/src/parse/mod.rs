@@ -5,10 +5,15 @@
 use crate::pos::{PositionCalculator, Positioned};
 use crate::types::*;
 use crate::{Error, Result};
+#[cfg(not(feature = "std"))]
+use alloc::collections::{btree_map as hash_map, BTreeMap as HashMap};
+use alloc::string::String;
+use alloc::vec::Vec;
 use pest::iterators::{Pair, Pairs};
 use pest::Parser;
 use pest_derive::Parser;
-use std::collections::hash_map::{self, HashMap};
+#[cfg(feature = "std")]
+use std::collections::{hash_map, HashMap};
 use utils::*;
 
 mod executable;
@@ -17,6 +22,10 @@ mod utils;
 
 use async_graphql_value::{ConstValue, Name, Number, Value};
 pub use executable::parse_query;
+#[cfg(feature = "graphql-parser")]
+pub(crate) use executable::{
+    build_document, DefinitionItem, FragmentDefinitionItem, OperationDefinitionItem,
+};
 pub use service::parse_schema;
 
 #[derive(Parser)]
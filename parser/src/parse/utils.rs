@@ -1,5 +1,7 @@
 use super::Rule;
 use crate::Result;
+use alloc::string::String;
+use alloc::vec::Vec;
 use pest::iterators::{Pair, Pairs};
 
 pub(super) fn next_if_rule<'a>(pairs: &mut Pairs<'a, Rule>, rule: Rule) -> Option<Pair<'a, Rule>> {
@@ -91,7 +93,7 @@ fn test_block_string_value() {
 pub(super) fn string_value(s: &str) -> String {
     let mut chars = s.chars();
 
-    std::iter::from_fn(|| {
+    core::iter::from_fn(|| {
         Some(match chars.next()? {
             '\\' => match chars.next().expect("backslash at end") {
                 c @ '\"' | c @ '\\' | c @ '/' => c,
@@ -100,7 +102,7 @@ pub(super) fn string_value(s: &str) -> String {
                 'n' => '\n',
                 'r' => '\r',
                 't' => '\t',
-                'u' => std::char::from_u32(
+                'u' => core::char::from_u32(
                     (0..4)
                         .map(|_| chars.next().unwrap().to_digit(16).unwrap())
                         .fold(0, |acc, digit| acc * 16 + digit),
@@ -1,11 +1,12 @@
+use alloc::string::String;
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::Chars;
 use pest::iterators::Pair;
 use pest::RuleType;
 use serde::{Deserialize, Serialize};
-use std::borrow::{Borrow, BorrowMut};
-use std::cmp::Ordering;
-use std::fmt;
-use std::hash::{Hash, Hasher};
-use std::str::Chars;
 
 /// Original position of an element in source code.
 ///
@@ -68,7 +68,7 @@ impl<'a> Iterator for OperationsIter<'a> {
     }
 }
 
-impl<'a> std::iter::FusedIterator for OperationsIter<'a> {}
+impl<'a> core::iter::FusedIterator for OperationsIter<'a> {}
 
 impl<'a> ExactSizeIterator for OperationsIter<'a> {
     fn len(&self) -> usize {
@@ -117,6 +117,8 @@ pub struct VariableDefinition {
     pub var_type: Positioned<Type>,
     /// The optional default value of the variable.
     pub default_value: Option<Positioned<ConstValue>>,
+    /// The directives of the variable definition. Their arguments are constant values.
+    pub directives: Vec<Positioned<Directive>>,
 }
 
 impl VariableDefinition {
@@ -191,6 +193,9 @@ pub struct Field {
     pub name: Positioned<Name>,
     /// The arguments to the field, empty if no arguments are provided.
     pub arguments: Vec<(Positioned<Name>, Positioned<Value>)>,
+    /// The nullability designator of the field, `!` or `?`. This is part of the experimental
+    /// [client controlled nullability RFC](https://github.com/graphql/graphql-spec/pull/895).
+    pub nullability: Option<Nullability>,
     /// The directives in the field selector.
     pub directives: Vec<Positioned<Directive>>,
     /// The subfields being selected in this field, if it is an object. Empty if no fields are
@@ -198,6 +203,24 @@ pub struct Field {
     pub selection_set: Positioned<SelectionSet>,
 }
 
+/// A nullability designator chosen by the client for a field.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Nullability {
+    /// `!`, the field is required and a null value is an error.
+    Required,
+    /// `?`, the field is optional and an error resolving it results in a null value.
+    Optional,
+}
+
+impl Display for Nullability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Required => "!",
+            Self::Optional => "?",
+        })
+    }
+}
+
 impl Field {
     /// Get the response key of the field. This is the alias if present and the name otherwise.
     #[must_use]
@@ -222,6 +245,10 @@ impl Field {
 pub struct FragmentSpread {
     /// The name of the fragment being selected.
     pub fragment_name: Positioned<Name>,
+    /// The arguments of the fragment selector, bound to the variables of the fragment. This is
+    /// part of the experimental
+    /// [fragment arguments RFC](https://github.com/graphql/graphql-spec/pull/1081).
+    pub arguments: Vec<(Positioned<Name>, Positioned<Value>)>,
     /// The directives in the fragment selector.
     pub directives: Vec<Positioned<Directive>>,
 }
@@ -244,6 +271,10 @@ pub struct InlineFragment {
 /// [Reference](https://spec.graphql.org/June2018/#FragmentDefinition).
 #[derive(Debug, Clone)]
 pub struct FragmentDefinition {
+    /// The variables of the fragment, which are set by the arguments of each fragment spread.
+    /// This is part of the experimental
+    /// [fragment arguments RFC](https://github.com/graphql/graphql-spec/pull/1081).
+    pub variable_definitions: Vec<Positioned<VariableDefinition>>,
     /// The type this fragment operates on.
     pub type_condition: Positioned<TypeCondition>,
     /// Directives in the fragment.
@@ -10,9 +10,17 @@ mod executable;
 mod service;
 
 use crate::pos::Positioned;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
 use async_graphql_value::{ConstValue, Name, Value};
+use core::fmt::{self, Display, Formatter, Write};
+
+// `HashMap` isn't available without std, the documents use a `BTreeMap` instead.
+#[cfg(not(feature = "std"))]
+use alloc::collections::{btree_map as hash_map, BTreeMap as HashMap};
+#[cfg(feature = "std")]
 use std::collections::{hash_map, HashMap};
-use std::fmt::{self, Display, Formatter, Write};
 
 pub use executable::*;
 pub use service::*;
@@ -0,0 +1,401 @@
+//! Traversal of executable documents.
+//!
+//! [`Visit`](trait.Visit.html) walks a document by reference and
+//! [`VisitMut`](trait.VisitMut.html) walks it by mutable reference, which can be used to rewrite
+//! a query before it is executed. Each method of the traits has a default implementation which
+//! calls the free function of the same name to visit the children of the node, so an overriding
+//! method can call that function to continue the traversal.
+//!
+//! ```
+//! use async_graphql_parser::parse_query;
+//! use async_graphql_parser::types::{Field, Selection, SelectionSet};
+//! use async_graphql_parser::visit::{self, Visit, VisitMut};
+//! use async_graphql_parser::Positioned;
+//!
+//! struct CountFields(usize);
+//!
+//! impl<'a> Visit<'a> for CountFields {
+//!     fn visit_field(&mut self, field: &'a Positioned<Field>) {
+//!         self.0 += 1;
+//!         visit::visit_field(self, field);
+//!     }
+//! }
+//!
+//! struct StripField(&'static str);
+//!
+//! impl VisitMut for StripField {
+//!     fn visit_selection_set_mut(&mut self, selection_set: &mut Positioned<SelectionSet>) {
+//!         let name = self.0;
+//!         selection_set.node.items.retain(|selection| match &selection.node {
+//!             Selection::Field(field) => field.node.name.node != name,
+//!             _ => true,
+//!         });
+//!         visit::visit_selection_set_mut(self, selection_set);
+//!     }
+//! }
+//!
+//! let mut doc = parse_query("{ a { secret b } secret }").unwrap();
+//! StripField("secret").visit_document_mut(&mut doc);
+//!
+//! let mut count = CountFields(0);
+//! count.visit_document(&doc);
+//! assert_eq!(count.0, 2);
+//! ```
+
+use async_graphql_value::{Name, Value};
+
+use crate::types::*;
+use crate::Positioned;
+
+/// A visitor over an executable document.
+#[allow(unused_variables)]
+pub trait Visit<'a> {
+    /// Visit a document.
+    fn visit_document(&mut self, doc: &'a ExecutableDocument) {
+        visit_document(self, doc);
+    }
+
+    /// Visit an operation definition.
+    fn visit_operation_definition(
+        &mut self,
+        name: Option<&'a Name>,
+        operation: &'a Positioned<OperationDefinition>,
+    ) {
+        visit_operation_definition(self, operation);
+    }
+
+    /// Visit a fragment definition.
+    fn visit_fragment_definition(
+        &mut self,
+        name: &'a Name,
+        fragment: &'a Positioned<FragmentDefinition>,
+    ) {
+        visit_fragment_definition(self, fragment);
+    }
+
+    /// Visit a variable definition.
+    fn visit_variable_definition(&mut self, variable: &'a Positioned<VariableDefinition>) {
+        visit_variable_definition(self, variable);
+    }
+
+    /// Visit a selection set.
+    fn visit_selection_set(&mut self, selection_set: &'a Positioned<SelectionSet>) {
+        visit_selection_set(self, selection_set);
+    }
+
+    /// Visit a selection.
+    fn visit_selection(&mut self, selection: &'a Positioned<Selection>) {
+        visit_selection(self, selection);
+    }
+
+    /// Visit a field.
+    fn visit_field(&mut self, field: &'a Positioned<Field>) {
+        visit_field(self, field);
+    }
+
+    /// Visit a fragment spread.
+    fn visit_fragment_spread(&mut self, fragment_spread: &'a Positioned<FragmentSpread>) {
+        visit_fragment_spread(self, fragment_spread);
+    }
+
+    /// Visit an inline fragment.
+    fn visit_inline_fragment(&mut self, inline_fragment: &'a Positioned<InlineFragment>) {
+        visit_inline_fragment(self, inline_fragment);
+    }
+
+    /// Visit a directive.
+    fn visit_directive(&mut self, directive: &'a Positioned<Directive>) {
+        visit_directive(self, directive);
+    }
+
+    /// Visit an argument of a field, directive or fragment spread.
+    fn visit_argument(&mut self, name: &'a Positioned<Name>, value: &'a Positioned<Value>) {}
+}
+
+/// Visit the operations and fragments of a document.
+pub fn visit_document<'a, V: Visit<'a> + ?Sized>(v: &mut V, doc: &'a ExecutableDocument) {
+    for (name, operation) in doc.operations.iter() {
+        v.visit_operation_definition(name, operation);
+    }
+    for (name, fragment) in &doc.fragments {
+        v.visit_fragment_definition(name, fragment);
+    }
+}
+
+/// Visit the variable definitions, directives and selection set of an operation.
+pub fn visit_operation_definition<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    operation: &'a Positioned<OperationDefinition>,
+) {
+    for variable in &operation.node.variable_definitions {
+        v.visit_variable_definition(variable);
+    }
+    for directive in &operation.node.directives {
+        v.visit_directive(directive);
+    }
+    v.visit_selection_set(&operation.node.selection_set);
+}
+
+/// Visit the directives of a variable definition.
+pub fn visit_variable_definition<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    variable: &'a Positioned<VariableDefinition>,
+) {
+    for directive in &variable.node.directives {
+        v.visit_directive(directive);
+    }
+}
+
+/// Visit the variable definitions, directives and selection set of a fragment definition.
+pub fn visit_fragment_definition<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    fragment: &'a Positioned<FragmentDefinition>,
+) {
+    for variable in &fragment.node.variable_definitions {
+        v.visit_variable_definition(variable);
+    }
+    for directive in &fragment.node.directives {
+        v.visit_directive(directive);
+    }
+    v.visit_selection_set(&fragment.node.selection_set);
+}
+
+/// Visit the selections of a selection set.
+pub fn visit_selection_set<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    selection_set: &'a Positioned<SelectionSet>,
+) {
+    for selection in &selection_set.node.items {
+        v.visit_selection(selection);
+    }
+}
+
+/// Visit the field, fragment spread or inline fragment of a selection.
+pub fn visit_selection<'a, V: Visit<'a> + ?Sized>(v: &mut V, selection: &'a Positioned<Selection>) {
+    match &selection.node {
+        Selection::Field(field) => v.visit_field(field),
+        Selection::FragmentSpread(fragment_spread) => v.visit_fragment_spread(fragment_spread),
+        Selection::InlineFragment(inline_fragment) => v.visit_inline_fragment(inline_fragment),
+    }
+}
+
+/// Visit the arguments, directives and selection set of a field.
+pub fn visit_field<'a, V: Visit<'a> + ?Sized>(v: &mut V, field: &'a Positioned<Field>) {
+    for (name, value) in &field.node.arguments {
+        v.visit_argument(name, value);
+    }
+    for directive in &field.node.directives {
+        v.visit_directive(directive);
+    }
+    v.visit_selection_set(&field.node.selection_set);
+}
+
+/// Visit the arguments and directives of a fragment spread.
+pub fn visit_fragment_spread<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    fragment_spread: &'a Positioned<FragmentSpread>,
+) {
+    for (name, value) in &fragment_spread.node.arguments {
+        v.visit_argument(name, value);
+    }
+    for directive in &fragment_spread.node.directives {
+        v.visit_directive(directive);
+    }
+}
+
+/// Visit the directives and selection set of an inline fragment.
+pub fn visit_inline_fragment<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    inline_fragment: &'a Positioned<InlineFragment>,
+) {
+    for directive in &inline_fragment.node.directives {
+        v.visit_directive(directive);
+    }
+    v.visit_selection_set(&inline_fragment.node.selection_set);
+}
+
+/// Visit the arguments of a directive.
+pub fn visit_directive<'a, V: Visit<'a> + ?Sized>(v: &mut V, directive: &'a Positioned<Directive>) {
+    for (name, value) in &directive.node.arguments {
+        v.visit_argument(name, value);
+    }
+}
+
+/// A visitor that can modify an executable document in place.
+#[allow(unused_variables)]
+pub trait VisitMut {
+    /// Visit a document.
+    fn visit_document_mut(&mut self, doc: &mut ExecutableDocument) {
+        visit_document_mut(self, doc);
+    }
+
+    /// Visit an operation definition.
+    fn visit_operation_definition_mut(
+        &mut self,
+        name: Option<&Name>,
+        operation: &mut Positioned<OperationDefinition>,
+    ) {
+        visit_operation_definition_mut(self, operation);
+    }
+
+    /// Visit a fragment definition.
+    fn visit_fragment_definition_mut(
+        &mut self,
+        name: &Name,
+        fragment: &mut Positioned<FragmentDefinition>,
+    ) {
+        visit_fragment_definition_mut(self, fragment);
+    }
+
+    /// Visit a variable definition.
+    fn visit_variable_definition_mut(&mut self, variable: &mut Positioned<VariableDefinition>) {
+        visit_variable_definition_mut(self, variable);
+    }
+
+    /// Visit a selection set.
+    fn visit_selection_set_mut(&mut self, selection_set: &mut Positioned<SelectionSet>) {
+        visit_selection_set_mut(self, selection_set);
+    }
+
+    /// Visit a selection.
+    fn visit_selection_mut(&mut self, selection: &mut Positioned<Selection>) {
+        visit_selection_mut(self, selection);
+    }
+
+    /// Visit a field.
+    fn visit_field_mut(&mut self, field: &mut Positioned<Field>) {
+        visit_field_mut(self, field);
+    }
+
+    /// Visit a fragment spread.
+    fn visit_fragment_spread_mut(&mut self, fragment_spread: &mut Positioned<FragmentSpread>) {
+        visit_fragment_spread_mut(self, fragment_spread);
+    }
+
+    /// Visit an inline fragment.
+    fn visit_inline_fragment_mut(&mut self, inline_fragment: &mut Positioned<InlineFragment>) {
+        visit_inline_fragment_mut(self, inline_fragment);
+    }
+
+    /// Visit a directive.
+    fn visit_directive_mut(&mut self, directive: &mut Positioned<Directive>) {
+        visit_directive_mut(self, directive);
+    }
+
+    /// Visit an argument of a field, directive or fragment spread.
+    fn visit_argument_mut(&mut self, name: &mut Positioned<Name>, value: &mut Positioned<Value>) {}
+}
+
+/// Visit the operations and fragments of a document.
+pub fn visit_document_mut<V: VisitMut + ?Sized>(v: &mut V, doc: &mut ExecutableDocument) {
+    match &mut doc.operations {
+        DocumentOperations::Single(operation) => v.visit_operation_definition_mut(None, operation),
+        DocumentOperations::Multiple(operations) => {
+            for (name, operation) in operations {
+                v.visit_operation_definition_mut(Some(name), operation);
+            }
+        }
+    }
+    for (name, fragment) in &mut doc.fragments {
+        v.visit_fragment_definition_mut(name, fragment);
+    }
+}
+
+/// Visit the variable definitions, directives and selection set of an operation.
+pub fn visit_operation_definition_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    operation: &mut Positioned<OperationDefinition>,
+) {
+    for variable in &mut operation.node.variable_definitions {
+        v.visit_variable_definition_mut(variable);
+    }
+    for directive in &mut operation.node.directives {
+        v.visit_directive_mut(directive);
+    }
+    v.visit_selection_set_mut(&mut operation.node.selection_set);
+}
+
+/// Visit the directives of a variable definition.
+pub fn visit_variable_definition_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    variable: &mut Positioned<VariableDefinition>,
+) {
+    for directive in &mut variable.node.directives {
+        v.visit_directive_mut(directive);
+    }
+}
+
+/// Visit the variable definitions, directives and selection set of a fragment definition.
+pub fn visit_fragment_definition_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    fragment: &mut Positioned<FragmentDefinition>,
+) {
+    for variable in &mut fragment.node.variable_definitions {
+        v.visit_variable_definition_mut(variable);
+    }
+    for directive in &mut fragment.node.directives {
+        v.visit_directive_mut(directive);
+    }
+    v.visit_selection_set_mut(&mut fragment.node.selection_set);
+}
+
+/// Visit the selections of a selection set.
+pub fn visit_selection_set_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    selection_set: &mut Positioned<SelectionSet>,
+) {
+    for selection in &mut selection_set.node.items {
+        v.visit_selection_mut(selection);
+    }
+}
+
+/// Visit the field, fragment spread or inline fragment of a selection.
+pub fn visit_selection_mut<V: VisitMut + ?Sized>(v: &mut V, selection: &mut Positioned<Selection>) {
+    match &mut selection.node {
+        Selection::Field(field) => v.visit_field_mut(field),
+        Selection::FragmentSpread(fragment_spread) => v.visit_fragment_spread_mut(fragment_spread),
+        Selection::InlineFragment(inline_fragment) => v.visit_inline_fragment_mut(inline_fragment),
+    }
+}
+
+/// Visit the arguments, directives and selection set of a field.
+pub fn visit_field_mut<V: VisitMut + ?Sized>(v: &mut V, field: &mut Positioned<Field>) {
+    for (name, value) in &mut field.node.arguments {
+        v.visit_argument_mut(name, value);
+    }
+    for directive in &mut field.node.directives {
+        v.visit_directive_mut(directive);
+    }
+    v.visit_selection_set_mut(&mut field.node.selection_set);
+}
+
+/// Visit the arguments and directives of a fragment spread.
+pub fn visit_fragment_spread_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    fragment_spread: &mut Positioned<FragmentSpread>,
+) {
+    for (name, value) in &mut fragment_spread.node.arguments {
+        v.visit_argument_mut(name, value);
+    }
+    for directive in &mut fragment_spread.node.directives {
+        v.visit_directive_mut(directive);
+    }
+}
+
+/// Visit the directives and selection set of an inline fragment.
+pub fn visit_inline_fragment_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    inline_fragment: &mut Positioned<InlineFragment>,
+) {
+    for directive in &mut inline_fragment.node.directives {
+        v.visit_directive_mut(directive);
+    }
+    v.visit_selection_set_mut(&mut inline_fragment.node.selection_set);
+}
+
+/// Visit the arguments of a directive.
+pub fn visit_directive_mut<V: VisitMut + ?Sized>(v: &mut V, directive: &mut Positioned<Directive>) {
+    for (name, value) in &mut directive.node.arguments {
+        v.visit_argument_mut(name, value);
+    }
+}
@@ -0,0 +1,106 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use futures_util::stream::Stream;
+use rdkafka::consumer::{CommitMode, Consumer, StreamConsumer};
+use rdkafka::{ClientConfig, Message, Offset, TopicPartitionList};
+use serde::de::DeserializeOwned;
+
+use crate::Result;
+
+/// Exposes Kafka topics as subscription streams.
+///
+/// Each subscription creates its own consumer. By default every consumer has its own consumer
+/// group, so every subscriber receives all the events published after it subscribed. With
+/// [`group_id`](#method.group_id), the subscribers share one group and each event is delivered
+/// to only one of them.
+///
+/// A message is only read from Kafka when the stream is polled, so a slow client doesn't make
+/// the server buffer the topic. The offset of a message is committed once the next one is
+/// requested, i.e. after the subscription has sent it.
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "kafka")))]
+#[derive(Clone)]
+pub struct KafkaEventSource {
+    config: ClientConfig,
+    group_id: Option<String>,
+}
+
+impl KafkaEventSource {
+    /// Creates an event source whose consumers use `config`, which must at least set
+    /// `bootstrap.servers`.
+    pub fn new(config: ClientConfig) -> Self {
+        Self {
+            config,
+            group_id: None,
+        }
+    }
+
+    /// Share the consumer group `group_id` between all the subscribers.
+    #[must_use]
+    pub fn group_id(self, group_id: impl Into<String>) -> Self {
+        Self {
+            group_id: Some(group_id.into()),
+            ..self
+        }
+    }
+
+    /// Subscribe to `topic` and decode the payloads from JSON.
+    ///
+    /// The stream ends when the consumer fails. A payload that can't be decoded results in an
+    /// error for that event only.
+    pub fn subscribe<T: DeserializeOwned + 'static>(
+        &self,
+        topic: &str,
+    ) -> Result<impl Stream<Item = Result<T>> + 'static> {
+        let mut config = self.config.clone();
+        let group_id = self.group_id.clone().unwrap_or_else(unique_group_id);
+        config
+            .set("group.id", &group_id)
+            .set("enable.auto.commit", "false");
+        if self.group_id.is_none() && config.get("auto.offset.reset").is_none() {
+            config.set("auto.offset.reset", "latest");
+        }
+        let consumer: StreamConsumer = config.create()?;
+        consumer.subscribe(&[topic])?;
+
+        Ok(async_stream::stream! {
+            loop {
+                let (item, position) = match consumer.recv().await {
+                    Ok(msg) => {
+                        let item = match msg.payload() {
+                            Some(payload) => serde_json::from_slice(payload).map_err(Into::into),
+                            None => Err("The message has no payload".into()),
+                        };
+                        (item, (msg.topic().to_string(), msg.partition(), msg.offset()))
+                    }
+                    Err(_) => break,
+                };
+                yield item;
+
+                let (topic, partition, offset) = position;
+                let mut offsets = TopicPartitionList::new();
+                if offsets
+                    .add_partition_offset(&topic, partition, Offset::Offset(offset + 1))
+                    .is_ok()
+                {
+                    let _ = consumer.commit(&offsets, CommitMode::Async);
+                }
+            }
+        })
+    }
+}
+
+fn unique_group_id() -> String {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos())
+        .unwrap_or_default();
+    format!(
+        "async-graphql-{}-{:x}-{}",
+        std::process::id(),
+        nanos,
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
@@ -0,0 +1,70 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::rc::{Rc, Weak};
+use std::task::{Poll, Waker};
+
+use futures_util::stream::{self, LocalBoxStream, StreamExt};
+
+use crate::broker::SubscriptionBroker;
+use crate::Result;
+
+#[derive(Default)]
+struct Subscriber {
+    queue: VecDeque<Vec<u8>>,
+    waker: Option<Waker>,
+}
+
+type Topics = HashMap<String, Vec<Weak<RefCell<Subscriber>>>>;
+
+/// A broker within a single process.
+///
+/// Cloning it returns a handle to the same broker. The payloads are queued for each subscriber
+/// until its stream is polled.
+#[derive(Clone, Default)]
+pub struct MemoryBroker {
+    topics: Rc<RefCell<Topics>>,
+}
+
+#[async_trait::async_trait(?Send)]
+impl SubscriptionBroker for MemoryBroker {
+    async fn publish_bytes(&self, topic: &str, payload: Vec<u8>) -> Result<()> {
+        let mut topics = self.topics.borrow_mut();
+        if let Some(subscribers) = topics.get_mut(topic) {
+            subscribers.retain(|subscriber| match subscriber.upgrade() {
+                Some(subscriber) => {
+                    let mut subscriber = subscriber.borrow_mut();
+                    subscriber.queue.push_back(payload.clone());
+                    if let Some(waker) = subscriber.waker.take() {
+                        waker.wake();
+                    }
+                    true
+                }
+                None => false,
+            });
+            if subscribers.is_empty() {
+                topics.remove(topic);
+            }
+        }
+        Ok(())
+    }
+
+    async fn subscribe_bytes(&self, topic: &str) -> Result<LocalBoxStream<'static, Vec<u8>>> {
+        let subscriber = Rc::new(RefCell::new(Subscriber::default()));
+        self.topics
+            .borrow_mut()
+            .entry(topic.to_string())
+            .or_default()
+            .push(Rc::downgrade(&subscriber));
+        Ok(stream::poll_fn(move |cx| {
+            let mut subscriber = subscriber.borrow_mut();
+            match subscriber.queue.pop_front() {
+                Some(payload) => Poll::Ready(Some(payload)),
+                None => {
+                    subscriber.waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+        })
+        .boxed_local())
+    }
+}
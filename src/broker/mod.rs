@@ -0,0 +1,119 @@
+//! Brokers for publishing events to subscriptions
+//!
+//! A [`SubscriptionBroker`](trait.SubscriptionBroker.html) delivers the payloads published to a
+//! topic to all the streams subscribed to it. [`MemoryBroker`](struct.MemoryBroker.html) works
+//! within a single process, the `redis` and `nats` features add brokers that fan out the events
+//! to all the servers of a deployment. With the `kafka` feature,
+//! [`KafkaEventSource`](struct.KafkaEventSource.html) exposes Kafka topics as subscriptions.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use async_graphql::*;
+//! use async_graphql::broker::{self, MemoryBroker};
+//! use futures_util::stream::{Stream, StreamExt};
+//! use futures_util::FutureExt;
+//!
+//! struct Query;
+//!
+//! #[Object]
+//! impl Query {
+//!     async fn value(&self) -> i32 {
+//!         10
+//!     }
+//! }
+//!
+//! struct Mutation;
+//!
+//! #[Object]
+//! impl Mutation {
+//!     async fn post(&self, ctx: &Context<'_>, message: String) -> Result<bool> {
+//!         broker::publish(ctx.data::<MemoryBroker>()?, "messages", &message).await?;
+//!         Ok(true)
+//!     }
+//! }
+//!
+//! struct Subscription;
+//!
+//! #[Subscription]
+//! impl Subscription {
+//!     async fn messages(&self, ctx: &Context<'_>) -> Result<impl Stream<Item = Result<String>>> {
+//!         broker::subscribe(ctx.data::<MemoryBroker>()?, "messages").await
+//!     }
+//! }
+//!
+//! async_std::task::block_on(async move {
+//!     let schema = Schema::build(Query, Mutation, Subscription)
+//!         .data(MemoryBroker::default())
+//!         .finish();
+//!     let mut stream = schema.execute_stream("subscription { messages }").boxed_local();
+//!     assert!(stream.next().now_or_never().is_none());
+//!     schema.execute(r#"mutation { post(message: "hello") }"#).await;
+//!     assert_eq!(stream.next().await.unwrap().data, value!({ "messages": "hello" }));
+//! });
+//! ```
+
+#[cfg(feature = "kafka")]
+mod kafka;
+mod memory;
+#[cfg(feature = "nats")]
+mod nats;
+#[cfg(feature = "redis")]
+mod redis;
+
+use futures_util::stream::{LocalBoxStream, Stream, StreamExt};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::Result;
+
+#[cfg(feature = "redis")]
+pub use self::redis::RedisBroker;
+#[cfg(feature = "kafka")]
+pub use kafka::KafkaEventSource;
+pub use memory::MemoryBroker;
+#[cfg(feature = "nats")]
+pub use nats::NatsBroker;
+
+/// Publishes payloads to topics and subscribes to them.
+///
+/// The payloads are opaque bytes, [`publish`](fn.publish.html) and
+/// [`subscribe`](fn.subscribe.html) encode them as JSON.
+#[async_trait::async_trait(?Send)]
+pub trait SubscriptionBroker {
+    /// Publish `payload` to all the current subscribers of `topic`.
+    async fn publish_bytes(&self, topic: &str, payload: Vec<u8>) -> Result<()>;
+
+    /// Subscribe to `topic`. The stream returns the payloads published after this call, it ends
+    /// if the connection to the broker is lost.
+    async fn subscribe_bytes(&self, topic: &str) -> Result<LocalBoxStream<'static, Vec<u8>>>;
+}
+
+/// Publish `payload` to all the current subscribers of `topic`, encoded as JSON.
+pub async fn publish<B, T>(broker: &B, topic: &str, payload: &T) -> Result<()>
+where
+    B: SubscriptionBroker + ?Sized,
+    T: Serialize,
+{
+    broker
+        .publish_bytes(topic, serde_json::to_vec(payload)?)
+        .await
+}
+
+/// Subscribe to `topic` and decode the payloads from JSON.
+///
+/// The stream can be returned from a subscription resolver directly. A payload that can't be
+/// decoded results in an error for that event only.
+pub async fn subscribe<B, T>(
+    broker: &B,
+    topic: &str,
+) -> Result<impl Stream<Item = Result<T>> + 'static>
+where
+    B: SubscriptionBroker + ?Sized,
+    T: DeserializeOwned + 'static,
+{
+    Ok(broker
+        .subscribe_bytes(topic)
+        .await?
+        .map(|payload| Ok(serde_json::from_slice(&payload)?)))
+}
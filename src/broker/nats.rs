@@ -0,0 +1,29 @@
+use futures_util::stream::{LocalBoxStream, StreamExt};
+
+use crate::broker::SubscriptionBroker;
+use crate::Result;
+
+/// A broker using NATS subjects as topics.
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "nats")))]
+#[derive(Clone)]
+pub struct NatsBroker(async_nats::Client);
+
+impl NatsBroker {
+    /// Creates a broker using the client `client`.
+    pub fn new(client: async_nats::Client) -> Self {
+        Self(client)
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl SubscriptionBroker for NatsBroker {
+    async fn publish_bytes(&self, topic: &str, payload: Vec<u8>) -> Result<()> {
+        self.0.publish(topic.to_string(), payload.into()).await?;
+        Ok(())
+    }
+
+    async fn subscribe_bytes(&self, topic: &str) -> Result<LocalBoxStream<'static, Vec<u8>>> {
+        let subscriber = self.0.subscribe(topic.to_string()).await?;
+        Ok(subscriber.map(|msg| msg.payload.to_vec()).boxed_local())
+    }
+}
@@ -0,0 +1,42 @@
+use deadpool_redis::redis::{AsyncCommands, Client};
+use futures_util::stream::{LocalBoxStream, StreamExt};
+
+use crate::broker::SubscriptionBroker;
+use crate::Result;
+
+/// A broker using Redis Pub/Sub.
+///
+/// Payloads are published with a pooled connection, each subscription opens its own connection
+/// because a connection in subscriber mode can't run other commands.
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "redis")))]
+#[derive(Clone)]
+pub struct RedisBroker {
+    pool: deadpool_redis::Pool,
+    client: Client,
+}
+
+impl RedisBroker {
+    /// Creates a broker publishing with the connections of `pool` and subscribing with
+    /// connections opened by `client`.
+    pub fn new(pool: deadpool_redis::Pool, client: Client) -> Self {
+        Self { pool, client }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl SubscriptionBroker for RedisBroker {
+    async fn publish_bytes(&self, topic: &str, payload: Vec<u8>) -> Result<()> {
+        let mut conn = self.pool.get().await?;
+        conn.publish::<_, _, ()>(topic, payload).await?;
+        Ok(())
+    }
+
+    async fn subscribe_bytes(&self, topic: &str) -> Result<LocalBoxStream<'static, Vec<u8>>> {
+        let mut pubsub = self.client.get_async_connection().await?.into_pubsub();
+        pubsub.subscribe(topic).await?;
+        Ok(pubsub
+            .into_on_message()
+            .map(|msg| msg.get_payload_bytes().to_vec())
+            .boxed_local())
+    }
+}
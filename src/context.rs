@@ -1,11 +1,14 @@
 //! Query context.
 
 use std::any::{Any, TypeId};
+use std::cell::RefCell;
 use std::collections::{BTreeMap, HashMap};
 use std::convert::TryFrom;
 use std::fmt::{self, Debug, Display, Formatter};
+use std::future::Future;
 use std::ops::Deref;
-use std::sync::atomic::AtomicUsize;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 use fnv::FnvHashMap;
@@ -15,12 +18,16 @@ use serde::Serialize;
 
 use crate::extensions::Extensions;
 use crate::parser::types::{
-    Directive, Field, FragmentDefinition, OperationDefinition, SelectionSet,
+    Directive, Field, FragmentDefinition, OperationDefinition, OperationType, SelectionSet,
 };
+use crate::registry::MetaField;
+use crate::resolver_utils::StreamedList;
 use crate::schema::SchemaEnv;
+use crate::types::SubscriptionFrame;
+use crate::validators::CustomValidator;
 use crate::{
-    Error, InputValueType, Lookahead, Pos, Positioned, Result, ServerError, ServerResult,
-    UploadValue, Value,
+    Error, ErrorExtensions, InputValueType, Lookahead, Map, PathSegment, Pos, Positioned, Result,
+    ServerError, ServerResult, UploadValue, Value, VariablesError,
 };
 use async_graphql_value::{Name, Value as InputValue};
 
@@ -54,7 +61,7 @@ impl Variables {
     #[must_use]
     pub fn from_value(value: Value) -> Self {
         match value {
-            Value::Object(obj) => Self(obj),
+            Value::Object(obj) => Self(obj.into_iter().collect()),
             _ => Self::default(),
         }
     }
@@ -62,18 +69,60 @@ impl Variables {
     /// Get the values from a JSON value.
     ///
     /// If the value is not a map or the keys of a map are not valid GraphQL names, then no
-    /// variables will be returned.
+    /// variables will be returned. Use [`try_from_json`](#method.try_from_json) to get the reason.
     #[must_use]
     pub fn from_json(value: serde_json::Value) -> Self {
-        Value::from_json(value)
-            .map(Self::from_value)
-            .unwrap_or_default()
+        Self::try_from_json(value).unwrap_or_default()
+    }
+
+    /// Get the variables from a JSON value.
+    ///
+    /// `null` is treated as no variables.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the value is not an object, if a key is not a valid GraphQL name, or if the value
+    /// of a variable cannot be converted.
+    pub fn try_from_json(value: serde_json::Value) -> Result<Self, VariablesError> {
+        let map = match value {
+            serde_json::Value::Null => return Ok(Self::default()),
+            serde_json::Value::Object(map) => map,
+            serde_json::Value::Bool(_) => return Err(VariablesError::NotAnObject("a boolean")),
+            serde_json::Value::Number(_) => return Err(VariablesError::NotAnObject("a number")),
+            serde_json::Value::String(_) => return Err(VariablesError::NotAnObject("a string")),
+            serde_json::Value::Array(_) => return Err(VariablesError::NotAnObject("a list")),
+        };
+
+        let mut variables = Self::default();
+        for (name, value) in map {
+            if !is_valid_name(&name) {
+                return Err(VariablesError::InvalidName(name));
+            }
+            let value = Value::from_json(value).map_err(|err| VariablesError::InvalidValue {
+                name: name.clone(),
+                message: err.to_string(),
+            })?;
+            variables.0.insert(Name::new(name), value);
+        }
+        Ok(variables)
+    }
+
+    /// Insert a variable, returning the previous value if there was one.
+    pub fn insert<T: InputValueType>(&mut self, name: impl AsRef<str>, value: T) -> Option<Value> {
+        self.0.insert(Name::new(name), value.to_value())
+    }
+
+    /// Add a variable, replacing any previous value.
+    #[must_use]
+    pub fn with<T: InputValueType>(mut self, name: impl AsRef<str>, value: T) -> Self {
+        self.insert(name, value);
+        self
     }
 
     /// Get the variables as a GraphQL value.
     #[must_use]
     pub fn into_value(self) -> Value {
-        Value::Object(self.0)
+        Value::Object(self.0.into_iter().collect())
     }
 
     pub(crate) fn variable_path(&mut self, path: &str) -> Option<&mut Value> {
@@ -99,6 +148,12 @@ impl From<Variables> for Value {
     }
 }
 
+pub(crate) fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c == '_' || c.is_ascii_alphabetic())
+        && chars.all(|c| c == '_' || c.is_ascii_alphanumeric())
+}
+
 /// Schema/Context data.
 ///
 /// This is a type map, allowing you to store anything inside it.
@@ -208,6 +263,19 @@ impl<'a> QueryPathNode<'a> {
         res
     }
 
+    /// Get the path represented by `Vec<PathSegment>`.
+    #[must_use]
+    pub fn to_path(&self) -> Vec<PathSegment> {
+        let mut res = Vec::new();
+        self.for_each(|s| {
+            res.push(match s {
+                QueryPathSegment::Name(name) => PathSegment::Field((*name).to_string()),
+                QueryPathSegment::Index(idx) => PathSegment::Index(*idx),
+            });
+        });
+        res
+    }
+
     /// Iterate over the parents of the node.
     pub fn parents(&self) -> Parents<'_> {
         Parents(self)
@@ -294,6 +362,29 @@ impl Display for ResolveId {
     }
 }
 
+/// A value computed at most once, by the first caller, while the others wait for it.
+type MemoCell = Rc<futures_util::lock::Mutex<Option<Box<dyn Any>>>>;
+
+async fn memoize_in<V, F, Fut>(cell: MemoCell, f: F) -> V
+where
+    V: Clone + 'static,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = V>,
+{
+    let mut value = cell.lock().await;
+    if let Some(value) = value.as_ref().and_then(|value| value.downcast_ref::<V>()) {
+        return value.clone();
+    }
+    let res = f().await;
+    *value = Some(Box::new(res.clone()));
+    res
+}
+
+/// The results of the fields of an object that are used by its sibling fields, by the name of
+/// the type and of the resolver.
+#[derive(Default)]
+pub(crate) struct FieldResults(RefCell<HashMap<(&'static str, &'static str), MemoCell>>);
+
 /// Query context.
 ///
 /// **This type is not stable and should not be used directly.**
@@ -303,6 +394,8 @@ pub struct ContextBase<'a, T> {
     pub path_node: Option<QueryPathNode<'a>>,
     pub(crate) resolve_id: ResolveId,
     pub(crate) inc_resolve_id: &'a AtomicUsize,
+    pub(crate) list_concurrency: Option<usize>,
+    pub(crate) field_results: Option<&'a FieldResults>,
     #[doc(hidden)]
     pub item: T,
     #[doc(hidden)]
@@ -316,9 +409,60 @@ pub struct QueryEnvInner {
     pub extensions: Extensions,
     pub variables: Variables,
     pub operation: Positioned<OperationDefinition>,
+    pub operation_name: Option<Name>,
     pub fragments: HashMap<Name, Positioned<FragmentDefinition>>,
     pub uploads: Vec<UploadValue>,
     pub ctx_data: Arc<Data>,
+    pub errors: spin::Mutex<Vec<ServerError>>,
+    pub warnings: spin::Mutex<Vec<ServerError>>,
+    pub memoized: spin::Mutex<HashMap<(TypeId, String), MemoCell>>,
+    /// The number of values and the approximate size in bytes of the response, counted only if
+    /// the schema limits them.
+    pub response_nodes: AtomicUsize,
+    pub response_bytes: AtomicUsize,
+    /// The number of fields resolved, counted only if the schema limits it.
+    pub resolved_fields: AtomicUsize,
+    /// The lists with a `@stream` directive that are delivered after the initial response, if
+    /// the query is executed incrementally.
+    pub streams: Option<spin::Mutex<Vec<StreamedList>>>,
+    /// The id of the last event received by the client of a subscription.
+    pub last_event_id: Option<String>,
+    /// The id of the event of a subscription that is being output.
+    pub event_id: spin::Mutex<Option<String>>,
+    /// The response without data of a subscription that is being output.
+    pub(crate) subscription_frame: spin::Mutex<Option<SubscriptionFrame>>,
+}
+
+impl QueryEnvInner {
+    pub(crate) fn new(
+        extensions: Extensions,
+        variables: Variables,
+        operation_name: Option<Name>,
+        operation: Positioned<OperationDefinition>,
+        fragments: HashMap<Name, Positioned<FragmentDefinition>>,
+        uploads: Vec<UploadValue>,
+        data: Data,
+    ) -> Self {
+        Self {
+            extensions,
+            variables,
+            operation,
+            operation_name,
+            fragments,
+            uploads,
+            ctx_data: Arc::new(data),
+            errors: Default::default(),
+            warnings: Default::default(),
+            memoized: Default::default(),
+            response_nodes: Default::default(),
+            response_bytes: Default::default(),
+            resolved_fields: Default::default(),
+            streams: None,
+            last_event_id: None,
+            event_id: Default::default(),
+            subscription_frame: Default::default(),
+        }
+    }
 }
 
 #[doc(hidden)]
@@ -352,6 +496,8 @@ impl QueryEnv {
             path_node,
             resolve_id,
             inc_resolve_id,
+            list_concurrency: None,
+            field_results: None,
             item,
             schema_env,
             query_env: self,
@@ -385,6 +531,8 @@ impl<'a, T> ContextBase<'a, T> {
             item: field,
             resolve_id: self.get_child_resolve_id(),
             inc_resolve_id: self.inc_resolve_id,
+            list_concurrency: None,
+            field_results: self.field_results,
             schema_env: self.schema_env,
             query_env: self.query_env,
         }
@@ -400,11 +548,261 @@ impl<'a, T> ContextBase<'a, T> {
             item: selection_set,
             resolve_id: self.resolve_id,
             inc_resolve_id: &self.inc_resolve_id,
+            list_concurrency: self.list_concurrency,
+            field_results: self.field_results,
+            schema_env: self.schema_env,
+            query_env: self.query_env,
+        }
+    }
+
+    pub(crate) fn with_field_results<'b>(
+        &'b self,
+        field_results: &'b FieldResults,
+    ) -> ContextBase<'b, T>
+    where
+        T: Copy,
+    {
+        ContextBase {
+            path_node: self.path_node,
+            resolve_id: self.resolve_id,
+            inc_resolve_id: self.inc_resolve_id,
+            list_concurrency: self.list_concurrency,
+            field_results: Some(field_results),
+            item: self.item,
+            schema_env: self.schema_env,
+            query_env: self.query_env,
+        }
+    }
+
+    /// Call the resolver `name` of the type `type_name` with `f`, or return its result if it was
+    /// already called for the object being resolved, so that sibling fields can use it.
+    #[doc(hidden)]
+    pub async fn resolve_shared<V, F, Fut>(
+        &self,
+        type_name: &'static str,
+        name: &'static str,
+        f: F,
+    ) -> Result<V>
+    where
+        V: Clone + 'static,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<V>>,
+    {
+        let field_results = match self.field_results {
+            Some(field_results) => field_results,
+            None => return f().await,
+        };
+        let cell = field_results
+            .0
+            .borrow_mut()
+            .entry((type_name, name))
+            .or_default()
+            .clone();
+        memoize_in(cell, f).await
+    }
+
+    /// Compute a value once per request.
+    ///
+    /// The first call with a key and a value type calls `f`, the other calls with the same key
+    /// and type return a clone of its value, waiting for it if it is still being computed. Use
+    /// it for computations that several fields need, such as loading the current user or their
+    /// permissions. Return a `Result` to memoize the errors too.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    /// use std::cell::Cell;
+    ///
+    /// #[derive(Default)]
+    /// struct Query {
+    ///     loads: Cell<i32>,
+    /// }
+    ///
+    /// impl Query {
+    ///     async fn permissions(&self, ctx: &Context<'_>) -> Vec<String> {
+    ///         ctx.memoize("permissions", || async {
+    ///             self.loads.set(self.loads.get() + 1);
+    ///             vec!["read".to_string()]
+    ///         })
+    ///         .await
+    ///     }
+    /// }
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn can_read(&self, ctx: &Context<'_>) -> bool {
+    ///         self.permissions(ctx).await.iter().any(|p| p == "read")
+    ///     }
+    ///
+    ///     async fn can_write(&self, ctx: &Context<'_>) -> bool {
+    ///         self.permissions(ctx).await.iter().any(|p| p == "write")
+    ///     }
+    ///
+    ///     async fn loads(&self) -> i32 {
+    ///         self.loads.get()
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async move {
+    ///     let schema = Schema::new(Query::default(), EmptyMutation, EmptySubscription);
+    ///     let res = schema.execute("{ canRead canWrite }").await.into_result().unwrap().data;
+    ///     assert_eq!(res, value!({ "canRead": true, "canWrite": false }));
+    ///     let res = schema.execute("{ loads }").await.into_result().unwrap().data;
+    ///     assert_eq!(res, value!({ "loads": 1 }));
+    /// });
+    /// ```
+    pub async fn memoize<V, F, Fut>(&self, key: impl Into<String>, f: F) -> V
+    where
+        V: Clone + 'static,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = V>,
+    {
+        let cell = self
+            .query_env
+            .memoized
+            .lock()
+            .entry((TypeId::of::<V>(), key.into()))
+            .or_default()
+            .clone();
+        memoize_in(cell, f).await
+    }
+
+    #[doc(hidden)]
+    pub fn with_list_concurrency(self, list_concurrency: usize) -> Self {
+        ContextBase {
+            list_concurrency: Some(list_concurrency),
+            ..self
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn with_index(&'a self, idx: usize) -> ContextBase<'a, T>
+    where
+        T: Copy,
+    {
+        ContextBase {
+            path_node: Some(QueryPathNode {
+                parent: self.path_node.as_ref(),
+                segment: QueryPathSegment::Index(idx),
+            }),
+            item: self.item,
+            resolve_id: self.get_child_resolve_id(),
+            inc_resolve_id: self.inc_resolve_id,
+            list_concurrency: self.list_concurrency,
+            field_results: self.field_results,
             schema_env: self.schema_env,
             query_env: self.query_env,
         }
     }
 
+    /// Report an error without failing the current resolver.
+    ///
+    /// The path of the error is relative to the current path, and the error is returned in the
+    /// response alongside the data.
+    pub fn add_error(&self, error: ServerError) {
+        let error = self.relative_to_path(error);
+        self.query_env.errors.lock().push(error);
+    }
+
+    /// Report an error that doesn't affect the data, such as a resolver that failed and returned
+    /// a fallback value instead.
+    ///
+    /// The warnings are returned in the `warnings` extension of the response, with the same
+    /// format as the errors. The path of the warning is relative to the current path.
+    pub fn add_warning(&self, error: ServerError) {
+        let error = self.relative_to_path(error);
+        self.query_env.warnings.lock().push(error);
+    }
+
+    /// The id of the last event of the subscription received by the client, if it reconnected
+    /// to resume it, see `Request::last_event_id`. The events that follow it can be replayed
+    /// before the live ones.
+    pub fn last_event_id(&self) -> Option<&str> {
+        self.query_env.last_event_id.as_deref()
+    }
+
+    /// Add a value of the response, the value of the field with the response key `key` or an
+    /// item of a list, to the size of the response, and fail with a `RESPONSE_TOO_LARGE` error
+    /// if it exceeds the limits of the schema.
+    ///
+    /// The objects and lists are counted as their fields and items are resolved, so only their
+    /// brackets are counted with their own value. The responses of subscriptions aren't counted.
+    pub(crate) fn count_response_value(
+        &self,
+        key: Option<&str>,
+        value: &Value,
+    ) -> ServerResult<()> {
+        let env = self.schema_env;
+        if (env.max_response_nodes.is_none() && env.max_response_bytes.is_none())
+            || self.query_env.operation.node.ty == OperationType::Subscription
+        {
+            return Ok(());
+        }
+
+        let nodes = self
+            .query_env
+            .response_nodes
+            .fetch_add(1, Ordering::Relaxed)
+            + 1;
+        if matches!(env.max_response_nodes, Some(max_nodes) if nodes > max_nodes) {
+            return Err(response_too_large());
+        }
+
+        if let Some(max_bytes) = env.max_response_bytes {
+            // The key is quoted and followed by a colon, and the values are separated by commas.
+            let key_bytes = key.map_or(0, |key| key.len() + 3);
+            let value_bytes = match value {
+                Value::Object(_) | Value::List(_) => 2,
+                value => serde_json::to_string(value).map_or(0, |json| json.len()),
+            };
+            let bytes = key_bytes + value_bytes + 1;
+            let total_bytes = self
+                .query_env
+                .response_bytes
+                .fetch_add(bytes, Ordering::Relaxed)
+                + bytes;
+            if total_bytes > max_bytes {
+                return Err(response_too_large());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Count the resolution of a field, and fail with a `FIELD_BUDGET_EXCEEDED` error if the
+    /// request resolves more fields than the schema allows. The fields of subscriptions aren't
+    /// counted.
+    pub(crate) fn count_resolved_field(&self) -> ServerResult<()> {
+        if let Some(max_fields) = self.schema_env.max_resolved_fields {
+            if self.query_env.operation.node.ty != OperationType::Subscription {
+                let fields = self
+                    .query_env
+                    .resolved_fields
+                    .fetch_add(1, Ordering::Relaxed)
+                    + 1;
+                if fields > max_fields {
+                    return Err(Error::new(format!(
+                        "The request resolves more than {} fields.",
+                        max_fields
+                    ))
+                    .extend_with(|_, e| e.set("code", "FIELD_BUDGET_EXCEEDED"))
+                    .into_server_error());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn relative_to_path(&self, mut error: ServerError) -> ServerError {
+        if let Some(path_node) = &self.path_node {
+            let mut path = path_node.to_path();
+            path.append(&mut error.path);
+            error.path = path;
+        }
+        error
+    }
+
     /// Gets the global data defined in the `Context` or `Schema`.
     ///
     /// If both `Schema` and `Query` have the same data type, the data in the `Query` is obtained.
@@ -459,8 +857,49 @@ impl<'a, T> ContextBase<'a, T> {
             .ok_or_else(|| ServerError::new(format!("Variable {} is not defined.", name)).at(pos))
     }
 
-    fn resolve_input_value(&self, value: Positioned<InputValue>) -> ServerResult<Value> {
+    /// Whether the variable has a value, provided in the request or as the default value of
+    /// its definition. A variable without a value is treated as if it was not specified at all,
+    /// so the default value of the argument or input object field it is used in applies.
+    fn var_has_value(&self, name: &str) -> bool {
+        self.query_env
+            .operation
+            .node
+            .variable_definitions
+            .iter()
+            .find(|def| def.node.name.node == name)
+            .filter(|def| {
+                self.query_env.variables.0.contains_key(&def.node.name.node)
+                    || def.node.default_value.is_some()
+            })
+            .is_some()
+    }
+
+    fn remove_missing_variables(&self, value: &mut InputValue) {
+        match value {
+            InputValue::List(items) => items
+                .iter_mut()
+                .for_each(|item| self.remove_missing_variables(item)),
+            InputValue::Object(fields) => {
+                fields.retain(|_, value| match value {
+                    InputValue::Variable(name) => self.var_has_value(name),
+                    _ => true,
+                });
+                fields
+                    .values_mut()
+                    .for_each(|value| self.remove_missing_variables(value));
+            }
+            _ => {}
+        }
+    }
+
+    /// Replace the variables in an input value of the query by their values, as it is done for
+    /// the arguments of the fields.
+    ///
+    /// The fields of input objects set to a variable without a value are removed, so that their
+    /// default value applies. Fails if the value uses a variable which is not defined.
+    pub fn resolve_input_value(&self, mut value: Positioned<InputValue>) -> ServerResult<Value> {
         let pos = value.pos;
+        self.remove_missing_variables(&mut value.node);
         value
             .node
             .into_const_with(|name| self.var_value(&name, pos))
@@ -501,33 +940,118 @@ impl<'a, T> ContextBase<'a, T> {
 
         Ok(false)
     }
-}
 
-impl<'a> ContextBase<'a, &'a Positioned<SelectionSet>> {
-    #[doc(hidden)]
-    pub fn with_index(&'a self, idx: usize) -> ContextBase<'a, &'a Positioned<SelectionSet>> {
-        ContextBase {
-            path_node: Some(QueryPathNode {
-                parent: self.path_node.as_ref(),
-                segment: QueryPathSegment::Index(idx),
-            }),
-            item: self.item,
-            resolve_id: self.get_child_resolve_id(),
-            inc_resolve_id: self.inc_resolve_id,
-            schema_env: self.schema_env,
-            query_env: self.query_env,
+    /// Returns the initial count and label of the `@stream` directive, if there is one and it
+    /// is enabled.
+    pub(crate) fn stream_directive(
+        &self,
+        directives: &[Positioned<Directive>],
+    ) -> ServerResult<Option<(usize, Option<String>)>> {
+        let directive = match directives
+            .iter()
+            .find(|directive| directive.node.name.node == "stream")
+        {
+            Some(directive) => directive,
+            None => return Ok(None),
+        };
+        let argument = |name: &str| -> ServerResult<Option<(Pos, Value)>> {
+            match directive.node.get_argument(name) {
+                Some(value) => Ok(Some((value.pos, self.resolve_input_value(value.clone())?))),
+                None => Ok(None),
+            }
+        };
+
+        if let Some((pos, value)) = argument("if")? {
+            if !<bool as InputValueType>::parse(Some(value))
+                .map_err(|e| e.into_server_error().at(pos))?
+            {
+                return Ok(None);
+            }
         }
+        let initial_count = match argument("initialCount")? {
+            Some((pos, value)) => {
+                let initial_count = <i32 as InputValueType>::parse(Some(value))
+                    .map_err(|e| e.into_server_error().at(pos))?;
+                if initial_count < 0 {
+                    return Err(ServerError::new(
+                        "The initialCount of @stream must not be negative.",
+                    )
+                    .at(pos));
+                }
+                initial_count as usize
+            }
+            None => 0,
+        };
+        let label = match argument("label")? {
+            Some((pos, value)) => <Option<String> as InputValueType>::parse(Some(value))
+                .map_err(|e| e.into_server_error().at(pos))?,
+            None => None,
+        };
+        Ok(Some((initial_count, label)))
     }
 }
 
+fn response_too_large() -> ServerError {
+    Error::new("The response is too large.")
+        .extend_with(|_, e| e.set("code", "RESPONSE_TOO_LARGE"))
+        .into_server_error()
+}
+
 impl<'a> ContextBase<'a, &'a Positioned<Field>> {
+    /// Get the argument with the given name, unless its value is a variable without a value.
+    fn get_argument(&self, name: &str) -> Option<&Positioned<InputValue>> {
+        self.item
+            .node
+            .get_argument(name)
+            .filter(|value| match &value.node {
+                InputValue::Variable(name) => self.var_has_value(name),
+                _ => true,
+            })
+    }
+
+    /// The arguments of the field in the query with the variables replaced by their values.
+    ///
+    /// The arguments set to a variable without a value and the default values of the arguments
+    /// are not included. Fails if an argument uses a variable which is not defined.
+    pub fn arguments(&self) -> ServerResult<Map<Value>> {
+        let mut arguments = Map::default();
+        for (name, _) in &self.item.node.arguments {
+            if let Some(value) = self.get_argument(&name.node) {
+                arguments.insert(name.node.clone(), self.resolve_input_value(value.clone())?);
+            }
+        }
+        Ok(arguments)
+    }
+
+    /// The arguments of the field with the variables replaced by their values and the secret
+    /// values redacted, see `Extension::mutation_field_end`.
+    pub(crate) fn redacted_arguments(&self, field: Option<&MetaField>) -> Map<Value> {
+        self.item
+            .node
+            .arguments
+            .iter()
+            .filter_map(|(name, _)| {
+                let value = self.get_argument(&name.node)?;
+                let mut value = self.resolve_input_value(value.clone()).ok()?;
+                if let Some(arg) = field.and_then(|field| field.args.get(name.node.as_str())) {
+                    self.schema_env.registry.redact_secrets(arg, &mut value);
+                }
+                Some((name.node.clone(), value))
+            })
+            .collect()
+    }
+
     #[doc(hidden)]
     pub fn param_value<T: InputValueType>(
         &self,
         name: &str,
         default: Option<fn() -> T>,
     ) -> ServerResult<T> {
-        let value = self.item.node.get_argument(name).cloned();
+        let value = match default {
+            Some(_) => self.get_argument(name),
+            None => self.item.node.get_argument(name),
+        }
+        .cloned();
         if value.is_none() {
             if let Some(default) = default {
                 return Ok(default());
@@ -540,6 +1064,59 @@ impl<'a> ContextBase<'a, &'a Positioned<Field>> {
         InputValueType::parse(value).map_err(|e| e.into_server_error().at(pos))
     }
 
+    #[doc(hidden)]
+    pub fn param_value_or_else<T: InputValueType>(
+        &self,
+        name: &str,
+        default: impl FnOnce(&Context<'_>) -> Result<T>,
+    ) -> ServerResult<T> {
+        if let Some(value) = self.get_argument(name) {
+            let pos = value.pos;
+            match self.resolve_input_value(value.clone())? {
+                Value::Null => {}
+                value => {
+                    return InputValueType::parse(Some(value))
+                        .map_err(|e| e.into_server_error().at(pos))
+                }
+            }
+        }
+        default(self).map_err(|err| err.into_server_error().at(self.item.pos))
+    }
+
+    #[doc(hidden)]
+    pub async fn check_param<T: InputValueType, V: CustomValidator<T>>(
+        &self,
+        name: &str,
+        value: &T,
+        validator: &V,
+    ) -> ServerResult<()> {
+        validator.check(self, value).await.map_err(|reason| {
+            let pos = self
+                .item
+                .node
+                .get_argument(name)
+                .map_or(self.item.pos, |value| value.pos);
+            ServerError::new(format!(
+                "Invalid value for argument \"{}\", {}",
+                name, reason
+            ))
+            .at(pos)
+        })
+    }
+
+    #[doc(hidden)]
+    pub fn guard_error(&self, err: Error, type_name: &str) -> ServerError {
+        if self.schema_env.hide_forbidden_fields {
+            ServerError::new(format!(
+                "Unknown field \"{}\" on type \"{}\".",
+                self.item.node.name.node, type_name
+            ))
+            .at(self.item.pos)
+        } else {
+            err.into_server_error().at(self.item.pos)
+        }
+    }
+
     /// Creates a uniform interface to inspect the forthcoming selections.
     ///
     /// # Examples
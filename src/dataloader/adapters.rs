@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+
+use crate::dataloader::Loader;
+
+/// Loads the rows whose primary key is one of the keys, with one query per batch.
+///
+/// `query` runs the batch query, e.g. `SELECT * FROM users WHERE id = ANY($1)`, and `key`
+/// returns the primary key of a row. A key without row has no value.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use async_graphql::dataloader::{DataLoader, PrimaryKeyLoader};
+///
+/// #[derive(Clone, SimpleObject)]
+/// struct User {
+///     id: i32,
+///     name: String,
+/// }
+///
+/// async fn find_users(ids: Vec<i32>) -> Result<Vec<User>> {
+///     // `SELECT id, name FROM users WHERE id = ANY($1)`
+///     Ok(ids
+///         .into_iter()
+///         .filter(|id| *id < 100)
+///         .map(|id| User { id, name: format!("user{}", id) })
+///         .collect())
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let loader = DataLoader::new(PrimaryKeyLoader::new(find_users, |user: &User| user.id));
+///     assert_eq!(loader.load_one(1).await.unwrap().unwrap().name, "user1");
+///     assert!(loader.load_one(100).await.unwrap().is_none());
+/// });
+/// ```
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "dataloader_adapters")))]
+pub struct PrimaryKeyLoader<F, G> {
+    query: F,
+    key: G,
+}
+
+impl<F, G> PrimaryKeyLoader<F, G> {
+    /// Creates a loader running `query` for each batch of keys, `key` returns the primary key of
+    /// a row.
+    pub fn new(query: F, key: G) -> Self {
+        Self { query, key }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<K, V, E, F, Fut, G> Loader<K> for PrimaryKeyLoader<F, G>
+where
+    K: Hash + Eq + Clone + 'static,
+    V: Clone + 'static,
+    E: Clone + 'static,
+    F: Fn(Vec<K>) -> Fut + 'static,
+    Fut: Future<Output = Result<Vec<V>, E>>,
+    G: Fn(&V) -> K + 'static,
+{
+    type Value = V;
+    type Error = E;
+
+    async fn load(&self, keys: &[K]) -> Result<HashMap<K, V>, E> {
+        let rows = (self.query)(keys.to_vec()).await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| ((self.key)(&row), row))
+            .collect())
+    }
+}
+
+/// Loads the rows whose foreign key is one of the keys, with one query per batch, e.g. the posts
+/// of users.
+///
+/// `query` runs the batch query, e.g. `SELECT * FROM posts WHERE user_id = ANY($1)`, and
+/// `foreign_key` returns the foreign key of a row. The value of a key is its rows in the order of
+/// the query, a key without row has no rows.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use async_graphql::dataloader::{DataLoader, ForeignKeyLoader};
+///
+/// #[derive(Clone, SimpleObject)]
+/// struct Post {
+///     id: i32,
+///     user_id: i32,
+/// }
+///
+/// async fn find_posts(user_ids: Vec<i32>) -> Result<Vec<Post>> {
+///     // `SELECT id, user_id FROM posts WHERE user_id = ANY($1) ORDER BY id`
+///     Ok(user_ids
+///         .into_iter()
+///         .filter(|user_id| *user_id == 1)
+///         .flat_map(|user_id| vec![Post { id: 1, user_id }, Post { id: 2, user_id }])
+///         .collect())
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let loader = DataLoader::new(ForeignKeyLoader::new(find_posts, |post: &Post| post.user_id));
+///     assert_eq!(loader.load_one(1).await.unwrap().unwrap().len(), 2);
+///     assert_eq!(loader.load_one(2).await.unwrap().unwrap().len(), 0);
+/// });
+/// ```
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "dataloader_adapters")))]
+pub struct ForeignKeyLoader<F, G> {
+    query: F,
+    foreign_key: G,
+}
+
+impl<F, G> ForeignKeyLoader<F, G> {
+    /// Creates a loader running `query` for each batch of keys, `foreign_key` returns the
+    /// foreign key of a row.
+    pub fn new(query: F, foreign_key: G) -> Self {
+        Self { query, foreign_key }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<K, V, E, F, Fut, G> Loader<K> for ForeignKeyLoader<F, G>
+where
+    K: Hash + Eq + Clone + 'static,
+    V: Clone + 'static,
+    E: Clone + 'static,
+    F: Fn(Vec<K>) -> Fut + 'static,
+    Fut: Future<Output = Result<Vec<V>, E>>,
+    G: Fn(&V) -> K + 'static,
+{
+    type Value = Vec<V>;
+    type Error = E;
+
+    async fn load(&self, keys: &[K]) -> Result<HashMap<K, Vec<V>>, E> {
+        let mut values: HashMap<K, Vec<V>> =
+            keys.iter().map(|key| (key.clone(), Vec::new())).collect();
+        for row in (self.query)(keys.to_vec()).await? {
+            if let Some(rows) = values.get_mut(&(self.foreign_key)(&row)) {
+                rows.push(row);
+            }
+        }
+        Ok(values)
+    }
+}
@@ -0,0 +1,302 @@
+//! Batch loading for resolvers
+//!
+//! A field that loads an entity for each item of a list, e.g. the author of each post, makes one
+//! call to the database per item. A [`DataLoader`](struct.DataLoader.html) collects the keys
+//! that the resolvers running concurrently ask for and loads them with a single call to its
+//! [`Loader`](trait.Loader.html).
+//!
+//! The resolvers get the loader from the data of the context. Add it to the data of the schema
+//! to share it between all the requests, or to the data of each request, e.g. to cache the
+//! values for the duration of the request only with [`DataLoader::with_cache`](struct.DataLoader.html#method.with_cache).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use async_graphql::*;
+//! use async_graphql::dataloader::{DataLoader, Loader};
+//! use std::cell::Cell;
+//! use std::collections::HashMap;
+//! use std::rc::Rc;
+//!
+//! struct UserNameLoader {
+//!     calls: Rc<Cell<usize>>,
+//! }
+//!
+//! #[async_trait::async_trait(?Send)]
+//! impl Loader<i32> for UserNameLoader {
+//!     type Value = String;
+//!     type Error = Error;
+//!
+//!     async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, String>> {
+//!         self.calls.set(self.calls.get() + 1);
+//!         Ok(keys.iter().map(|id| (*id, format!("user{}", id))).collect())
+//!     }
+//! }
+//!
+//! struct Post(i32);
+//!
+//! #[Object]
+//! impl Post {
+//!     async fn author(&self, ctx: &Context<'_>) -> Result<Option<String>> {
+//!         ctx.data_unchecked::<DataLoader<UserNameLoader>>().load_one(self.0).await
+//!     }
+//! }
+//!
+//! struct Query;
+//!
+//! #[Object]
+//! impl Query {
+//!     async fn posts(&self) -> Vec<Post> {
+//!         vec![Post(1), Post(2), Post(1)]
+//!     }
+//! }
+//!
+//! async_std::task::block_on(async move {
+//!     let calls = Rc::new(Cell::new(0));
+//!     let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+//!         .data(DataLoader::new(UserNameLoader { calls: calls.clone() }))
+//!         .finish();
+//!     let res = schema.execute("{ posts { author } }").await;
+//!     assert_eq!(res.data, value!({
+//!         "posts": [{ "author": "user1" }, { "author": "user2" }, { "author": "user1" }]
+//!     }));
+//!     assert_eq!(calls.get(), 1);
+//! });
+//! ```
+//!
+//! With the `dataloader_adapters` feature, [`PrimaryKeyLoader`](struct.PrimaryKeyLoader.html)
+//! and [`ForeignKeyLoader`](struct.ForeignKeyLoader.html) implement the loader of the most common
+//! cases from a function running the batch query, e.g. with SQLx or SeaORM.
+
+#[cfg(feature = "dataloader_adapters")]
+mod adapters;
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::hash::Hash;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+
+use futures_util::future::{FutureExt, LocalBoxFuture, Shared};
+
+#[cfg(feature = "dataloader_adapters")]
+pub use adapters::{ForeignKeyLoader, PrimaryKeyLoader};
+
+/// Loads the values of a batch of keys.
+#[async_trait::async_trait(?Send)]
+pub trait Loader<K: Hash + Eq + Clone + 'static>: 'static {
+    /// The type of the values.
+    type Value: Clone + 'static;
+
+    /// The type of the error, it is returned to each of the loads of the batch.
+    type Error: Clone + 'static;
+
+    /// Load the values of `keys`, the keys without value are left out of the map.
+    async fn load(&self, keys: &[K]) -> Result<HashMap<K, Self::Value>, Self::Error>;
+}
+
+type BatchFuture<K, V, E> = Shared<LocalBoxFuture<'static, Result<Rc<HashMap<K, V>>, E>>>;
+
+/// A batch collecting keys until it is loaded, its keys are taken when the load starts.
+struct Batch<K, V, E> {
+    keys: Rc<RefCell<Option<HashSet<K>>>>,
+    future: BatchFuture<K, V, E>,
+}
+
+/// The state of the loads of the keys of type `K`.
+struct KeyState<K, V, E> {
+    batch: Option<Batch<K, V, E>>,
+    cache: Option<HashMap<K, V>>,
+}
+
+/// Batches the loads of a [`Loader`](trait.Loader.html) and optionally caches the values.
+///
+/// The keys loaded while the executor runs the resolvers concurrently, i.e. until a resolver
+/// that waits for the batch is polled again, are loaded together.
+pub struct DataLoader<T> {
+    loader: Rc<T>,
+    max_batch_size: usize,
+    cache: bool,
+    states: RefCell<HashMap<TypeId, Box<dyn Any>>>,
+}
+
+impl<T> DataLoader<T> {
+    /// Create a loader without cache, whose batches have no maximum size.
+    pub fn new(loader: T) -> Self {
+        Self {
+            loader: Rc::new(loader),
+            max_batch_size: usize::MAX,
+            cache: false,
+            states: Default::default(),
+        }
+    }
+
+    /// Set the maximum number of keys of a batch, the keys that don't fit are loaded in another
+    /// batch.
+    pub fn max_batch_size(self, max_batch_size: usize) -> Self {
+        Self {
+            max_batch_size: max_batch_size.max(1),
+            ..self
+        }
+    }
+
+    /// Cache the loaded values for the lifetime of the loader, they are not loaded again.
+    pub fn with_cache(self) -> Self {
+        Self {
+            cache: true,
+            ..self
+        }
+    }
+
+    /// Get the loader.
+    pub fn loader(&self) -> &T {
+        &self.loader
+    }
+
+    /// Remove the cached values for the keys of type `K`.
+    pub fn clear<K>(&self)
+    where
+        K: Hash + Eq + Clone + 'static,
+        T: Loader<K>,
+    {
+        self.with_state::<K, _>(|state| {
+            if let Some(cache) = &mut state.cache {
+                cache.clear();
+            }
+        });
+    }
+
+    /// Load the value of `key`, `None` if it has none.
+    pub async fn load_one<K>(&self, key: K) -> Result<Option<T::Value>, T::Error>
+    where
+        K: Hash + Eq + Clone + 'static,
+        T: Loader<K>,
+    {
+        let mut values = self.load_many(std::iter::once(key.clone())).await?;
+        Ok(values.remove(&key))
+    }
+
+    /// Load the values of `keys`, the keys without value are left out of the map.
+    pub async fn load_many<K>(
+        &self,
+        keys: impl IntoIterator<Item = K>,
+    ) -> Result<HashMap<K, T::Value>, T::Error>
+    where
+        K: Hash + Eq + Clone + 'static,
+        T: Loader<K>,
+    {
+        let mut values = HashMap::new();
+        let mut futures = Vec::new();
+        self.with_state::<K, _>(|state| {
+            for key in keys {
+                if let Some(value) = state.cache.as_ref().and_then(|cache| cache.get(&key)) {
+                    values.insert(key, value.clone());
+                    continue;
+                }
+                let batch = match &state.batch {
+                    Some(batch)
+                        if batch.keys.borrow().as_ref().map(HashSet::len).unwrap_or(0)
+                            < self.max_batch_size =>
+                    {
+                        batch
+                    }
+                    _ => state.batch.insert(self.new_batch::<K>()),
+                };
+                batch.keys.borrow_mut().as_mut().unwrap().insert(key);
+                if !futures
+                    .iter()
+                    .any(|future: &BatchFuture<K, T::Value, T::Error>| future.ptr_eq(&batch.future))
+                {
+                    futures.push(batch.future.clone());
+                }
+            }
+        });
+
+        if !futures.is_empty() {
+            // Let the other resolvers add their keys before the batch is loaded.
+            YieldNow(false).await;
+        }
+        for future in futures {
+            let loaded = future.await?;
+            self.with_state::<K, _>(|state| {
+                if let Some(cache) = &mut state.cache {
+                    cache.extend(loaded.iter().map(|(k, v)| (k.clone(), v.clone())));
+                }
+            });
+            values.extend(loaded.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+        Ok(values)
+    }
+
+    fn new_batch<K>(&self) -> Batch<K, T::Value, T::Error>
+    where
+        K: Hash + Eq + Clone + 'static,
+        T: Loader<K>,
+    {
+        let keys = Rc::new(RefCell::new(Some(HashSet::new())));
+        let loader = self.loader.clone();
+        let batch_keys = keys.clone();
+        let future = async move {
+            let keys = batch_keys
+                .borrow_mut()
+                .take()
+                .unwrap_or_default()
+                .into_iter()
+                .collect::<Vec<_>>();
+            loader.load(&keys).await.map(Rc::new)
+        };
+        Batch {
+            keys,
+            future: future.boxed_local().shared(),
+        }
+    }
+
+    fn with_state<K, R>(&self, f: impl FnOnce(&mut KeyState<K, T::Value, T::Error>) -> R) -> R
+    where
+        K: Hash + Eq + Clone + 'static,
+        T: Loader<K>,
+    {
+        let mut states = self.states.borrow_mut();
+        let state = states
+            .entry(TypeId::of::<K>())
+            .or_insert_with(|| {
+                Box::new(KeyState::<K, T::Value, T::Error> {
+                    batch: None,
+                    cache: if self.cache {
+                        Some(HashMap::new())
+                    } else {
+                        None
+                    },
+                })
+            })
+            .downcast_mut::<KeyState<K, T::Value, T::Error>>()
+            .unwrap();
+
+        // A batch that has started loading doesn't take more keys.
+        if let Some(batch) = &state.batch {
+            if batch.keys.borrow().is_none() {
+                state.batch = None;
+            }
+        }
+        f(state)
+    }
+}
+
+/// Returns `Pending` once, the executor polls the other futures before polling it again.
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            return Poll::Ready(());
+        }
+        self.0 = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
@@ -17,6 +17,11 @@ impl ErrorExtensionValues {
     pub fn set(&mut self, name: impl AsRef<str>, value: impl Into<Value>) {
         self.0.insert(name.as_ref().to_string(), value.into());
     }
+
+    /// Get an extension value.
+    pub fn get(&self, name: impl AsRef<str>) -> Option<&Value> {
+        self.0.get(name.as_ref())
+    }
 }
 
 /// An error in a GraphQL server.
@@ -111,9 +116,18 @@ pub type ServerResult<T> = std::result::Result<T, ServerError>;
 /// An error parsing an input value.
 ///
 /// This type is generic over T as it uses T's type name when converting to a regular error.
+///
+/// Besides its message, the error keeps the expected type and the kind of the actual value of
+/// [`expected_type`](#method.expected_type) errors and the path of the field that failed to
+/// parse, they are added to the extensions of the server error as `expectedType`, `actualKind`
+/// and `inputPath` so that clients can handle them without parsing the message.
 #[derive(Debug)]
 pub struct InputValueError<T> {
     message: String,
+    field_path: Vec<String>,
+    rendered_fields: usize,
+    type_mismatch: Option<Box<(String, &'static str)>>,
+    extensions: Option<Box<ErrorExtensionValues>>,
     phantom: PhantomData<T>,
 }
 
@@ -121,6 +135,10 @@ impl<T: InputValueType> InputValueError<T> {
     fn new(message: String) -> Self {
         Self {
             message,
+            field_path: Vec::new(),
+            rendered_fields: 0,
+            type_mismatch: None,
+            extensions: None,
             phantom: PhantomData,
         }
     }
@@ -128,11 +146,13 @@ impl<T: InputValueType> InputValueError<T> {
     /// The expected input type did not match the actual input type.
     #[must_use]
     pub fn expected_type(actual: Value) -> Self {
-        Self::new(format!(
+        let mut err = Self::new(format!(
             r#"Expected input type "{}", found {}."#,
             T::type_name(),
             actual
-        ))
+        ));
+        err.type_mismatch = Some(Box::new((T::type_name().into_owned(), value_kind(&actual))));
+        err
     }
 
     /// A custom error message.
@@ -144,22 +164,143 @@ impl<T: InputValueType> InputValueError<T> {
         Self::new(format!(r#"Failed to parse "{}": {}"#, T::type_name(), msg))
     }
 
+    /// Attribute the error to a field of the input value.
+    ///
+    /// Call this again to prepend the names of the enclosing fields, the field path is included
+    /// in the error message.
+    #[must_use]
+    pub fn with_field(mut self, name: impl Into<String>) -> Self {
+        self.field_path.insert(0, name.into());
+        self.rendered_fields += 1;
+        self
+    }
+
+    /// Set an extension of the server error, e.g. a custom error code.
+    #[must_use]
+    pub fn with_extension(mut self, name: impl AsRef<str>, value: impl Into<Value>) -> Self {
+        self.extensions
+            .get_or_insert_with(Default::default)
+            .set(name, value);
+        self
+    }
+
+    /// The error message, without the field path.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The name of the expected type, if the error is a type mismatch.
+    pub fn expected(&self) -> Option<&str> {
+        self.type_mismatch
+            .as_ref()
+            .map(|type_mismatch| type_mismatch.0.as_str())
+    }
+
+    /// The kind of the actual value, e.g. `"string"` or `"object"`, if the error is a type
+    /// mismatch.
+    pub fn actual(&self) -> Option<&'static str> {
+        self.type_mismatch
+            .as_ref()
+            .map(|type_mismatch| type_mismatch.1)
+    }
+
+    /// The path of the field that failed to parse, from the outermost input value.
+    pub fn field_path(&self) -> &[String] {
+        &self.field_path
+    }
+
+    /// The extensions set with [`with_extension`](#method.with_extension).
+    pub fn extensions(&self) -> Option<&ErrorExtensionValues> {
+        self.extensions.as_deref()
+    }
+
+    fn full_message(&self) -> String {
+        if self.rendered_fields == 0 {
+            self.message.clone()
+        } else {
+            format!(
+                r#"{} (at field "{}")"#,
+                self.message,
+                self.field_path[..self.rendered_fields].join(".")
+            )
+        }
+    }
+
     /// Propagate the error message to a different type.
     pub fn propagate<U: InputValueType>(self) -> InputValueError<U> {
         if T::type_name() != U::type_name() {
-            InputValueError::new(format!(
-                r#"{} (occurred while parsing "{}")"#,
-                self.message,
-                U::type_name()
-            ))
+            InputValueError {
+                message: format!(
+                    r#"{} (occurred while parsing "{}")"#,
+                    self.full_message(),
+                    U::type_name()
+                ),
+                field_path: self.field_path,
+                rendered_fields: 0,
+                type_mismatch: self.type_mismatch,
+                extensions: self.extensions,
+                phantom: PhantomData,
+            }
         } else {
-            InputValueError::new(self.message)
+            InputValueError {
+                message: self.message,
+                field_path: self.field_path,
+                rendered_fields: self.rendered_fields,
+                type_mismatch: self.type_mismatch,
+                extensions: self.extensions,
+                phantom: PhantomData,
+            }
+        }
+    }
+
+    /// Propagate the error of the field `name` of an input object to the input object, the field
+    /// is added to the path of the error but not to its message.
+    #[doc(hidden)]
+    pub fn propagate_field<U: InputValueType>(self, name: &str) -> InputValueError<U> {
+        let mut err = self.propagate::<U>();
+        err.field_path.insert(0, name.to_string());
+        if err.rendered_fields > 0 {
+            err.rendered_fields += 1;
         }
+        err
     }
 
     /// Convert the error into a server error.
     pub fn into_server_error(self) -> ServerError {
-        ServerError::new(self.message)
+        let message = self.full_message();
+        let path = self.field_path;
+        let mut extensions = self
+            .extensions
+            .map(|extensions| *extensions)
+            .unwrap_or_default();
+        if let Some(type_mismatch) = self.type_mismatch {
+            let (expected, actual) = *type_mismatch;
+            extensions.set("expectedType", expected);
+            extensions.set("actualKind", actual);
+        }
+        if !path.is_empty() {
+            extensions.set("inputPath", path);
+        }
+        ServerError {
+            message,
+            locations: Vec::new(),
+            path: Vec::new(),
+            extensions: Some(extensions).filter(|extensions| !extensions.0.is_empty()),
+        }
+    }
+}
+
+/// The kind of a value, as reported by `InputValueError`.
+fn value_kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Boolean(_) => "boolean",
+        Value::Enum(_) => "enum",
+        Value::List(_) => "list",
+        Value::Object(_) => "object",
+        Value::Raw(_) => "raw",
     }
 }
 
@@ -262,6 +403,28 @@ pub enum ParseRequestError {
     UnsupportedBatch,
 }
 
+/// An error converting JSON into variables.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum VariablesError {
+    /// The variables were not an object.
+    #[error("Variables must be an object, found {0}")]
+    NotAnObject(&'static str),
+
+    /// The name of a variable is not a valid GraphQL name.
+    #[error("Invalid variable name \"{0}\"")]
+    InvalidName(String),
+
+    /// The value of a variable could not be converted.
+    #[error("Invalid value for variable \"{name}\": {message}")]
+    InvalidValue {
+        /// The name of the variable.
+        name: String,
+        /// The reason the value is invalid.
+        message: String,
+    },
+}
+
 #[cfg(feature = "multipart")]
 impl From<multer::Error> for ParseRequestError {
     fn from(err: multer::Error) -> Self {
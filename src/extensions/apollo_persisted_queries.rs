@@ -7,7 +7,7 @@ use serde::Deserialize;
 use sha2::{Digest, Sha256};
 
 use crate::extensions::{Extension, ExtensionContext, ExtensionFactory};
-use crate::{from_value, Request, ServerError, ServerResult};
+use crate::{from_value, Error, ErrorExtensions, Request, ServerError, ServerResult};
 
 #[derive(Deserialize)]
 struct PersistedQuery {
@@ -50,8 +50,79 @@ impl CacheStorage for LruCacheStorage {
     }
 }
 
+/// Redis-based cache, shared by all the servers connected to the same Redis instance.
+///
+/// Queries are stored under `{prefix}{sha256 hash}`. A Redis error is treated as a cache miss,
+/// so the client sends the full query again.
+#[cfg(feature = "redis")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "redis")))]
+#[derive(Clone)]
+pub struct RedisCacheStorage {
+    pool: deadpool_redis::Pool,
+    prefix: String,
+    ttl: Option<usize>,
+}
+
+#[cfg(feature = "redis")]
+impl RedisCacheStorage {
+    /// Creates a new Redis cache using the connections of `pool`, the key prefix is `apq:`.
+    pub fn new(pool: deadpool_redis::Pool) -> Self {
+        Self {
+            pool,
+            prefix: "apq:".to_string(),
+            ttl: None,
+        }
+    }
+
+    /// Set the prefix of the keys.
+    #[must_use]
+    pub fn prefix(self, prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+            ..self
+        }
+    }
+
+    /// Expire the queries after `seconds`, by default they never expire.
+    #[must_use]
+    pub fn ttl(self, seconds: usize) -> Self {
+        Self {
+            ttl: Some(seconds),
+            ..self
+        }
+    }
+}
+
+#[cfg(feature = "redis")]
+#[async_trait::async_trait(?Send)]
+impl CacheStorage for RedisCacheStorage {
+    async fn get(&self, key: String) -> Option<String> {
+        use deadpool_redis::redis::AsyncCommands;
+
+        let mut conn = self.pool.get().await.ok()?;
+        conn.get(format!("{}{}", self.prefix, key)).await.ok()?
+    }
+
+    async fn set(&self, key: String, query: String) {
+        use deadpool_redis::redis::AsyncCommands;
+
+        if let Ok(mut conn) = self.pool.get().await {
+            let key = format!("{}{}", self.prefix, key);
+            let _: Result<(), _> = match self.ttl {
+                Some(ttl) => conn.set_ex(key, query, ttl).await,
+                None => conn.set(key, query).await,
+            };
+        }
+    }
+}
+
 /// Apollo persisted queries extension.
 ///
+/// A client sends the SHA-256 hash of its query in the `persistedQuery` extension of the
+/// request, without the query. If the query isn't in the cache storage, the request fails with a
+/// `PersistedQueryNotFound` error whose `code` extension is `PERSISTED_QUERY_NOT_FOUND`, and the
+/// client sends the query again with its hash, to be stored in the cache.
+///
 /// [Reference](https://www.apollographql.com/docs/react/api/link/persisted-queries/)
 #[cfg_attr(feature = "nightly", doc(cfg(feature = "apollo_persisted_queries")))]
 pub struct ApolloPersistedQueries<T>(T);
@@ -96,12 +167,14 @@ impl<T: CacheStorage> Extension for ApolloPersistedQueriesExtension<T> {
                 if let Some(query) = self.storage.get(persisted_query.sha256_hash).await {
                     Ok(Request { query, ..request })
                 } else {
-                    Err(ServerError::new("PersistedQueryNotFound".to_string()))
+                    Err(Error::new("PersistedQueryNotFound")
+                        .extend_with(|_, e| e.set("code", "PERSISTED_QUERY_NOT_FOUND"))
+                        .into_server_error())
                 }
             } else {
                 let sha256_hash = format!("{:x}", Sha256::digest(request.query.as_bytes()));
 
-                if (persisted_query.sha256_hash != sha256_hash) {
+                if persisted_query.sha256_hash != sha256_hash {
                     Err(ServerError::new("provided sha does not match query"))
                 } else {
                     self.storage.set(sha256_hash, request.query.clone()).await;
@@ -113,71 +186,3 @@ impl<T: CacheStorage> Extension for ApolloPersistedQueriesExtension<T> {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    #[async_std::test]
-    async fn test() {
-        use super::*;
-        use crate::*;
-
-        struct Query;
-
-        #[Object(internal)]
-        impl Query {
-            async fn value(&self) -> i32 {
-                100
-            }
-        }
-
-        let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
-            .extension(ApolloPersistedQueries::new(LruCacheStorage::new(256)))
-            .finish();
-
-        let mut request = Request::new("{ value }");
-        request.extensions.insert(
-            "persistedQuery".to_string(),
-            value!({
-                "version": 1,
-                "sha256Hash": "854174ebed716fe24fd6659c30290aecd9bc1d17dc4f47939a1848a1b8ed3c6b",
-            }),
-        );
-
-        assert_eq!(
-            schema.execute(request).await.into_result().unwrap().data,
-            value!({
-                "value": 100
-            })
-        );
-
-        let mut request = Request::new("");
-        request.extensions.insert(
-            "persistedQuery".to_string(),
-            value!({
-                "version": 1,
-                "sha256Hash": "854174ebed716fe24fd6659c30290aecd9bc1d17dc4f47939a1848a1b8ed3c6b",
-            }),
-        );
-
-        assert_eq!(
-            schema.execute(request).await.into_result().unwrap().data,
-            value!({
-                "value": 100
-            })
-        );
-
-        let mut request = Request::new("");
-        request.extensions.insert(
-            "persistedQuery".to_string(),
-            value!({
-                "version": 1,
-                "sha256Hash": "def",
-            }),
-        );
-
-        assert_eq!(
-            schema.execute(request).await.into_result().unwrap_err(),
-            vec![ServerError::new("PersistedQueryNotFound")]
-        );
-    }
-}
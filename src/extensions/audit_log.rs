@@ -0,0 +1,151 @@
+//! Audit log extension.
+
+use std::rc::Rc;
+
+use crate::extensions::{Extension, ExtensionContext, ExtensionFactory, MutationFieldInfo};
+use crate::{Map, ServerError, Value};
+
+/// A field of the mutation root that was resolved, recorded by
+/// [`AuditLog`](struct.AuditLog.html).
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditRecord {
+    /// The name of the operation, `None` if it is anonymous.
+    pub operation_name: Option<String>,
+    /// The name of the field, not its alias.
+    pub field_name: String,
+    /// The arguments in the query with the variables replaced by their values. The values of the
+    /// arguments and input fields marked as `secret` are replaced by `"[REDACTED]"`.
+    pub arguments: Map<Value>,
+    /// The identity of the caller, as returned by the function set with `AuditLog::identity`.
+    pub identity: Option<String>,
+    /// The error of the field, `None` if it succeeded.
+    pub error: Option<ServerError>,
+}
+
+/// Receives the records of [`AuditLog`](struct.AuditLog.html).
+pub trait AuditLogSink: 'static {
+    /// Deliver a record, called after each field of the mutation root resolves.
+    fn send(&self, record: AuditRecord);
+}
+
+impl<F: Fn(AuditRecord) + 'static> AuditLogSink for F {
+    fn send(&self, record: AuditRecord) {
+        self(record)
+    }
+}
+
+type IdentityFn = dyn Fn(&ExtensionContext<'_>) -> Option<String>;
+
+/// Audit log extension.
+///
+/// Records each field of the mutation root after it resolves, with its arguments, the identity
+/// of the caller and whether it succeeded. Mark the arguments and the input fields that must not
+/// be logged, such as passwords, with `#[graphql(secret)]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use async_graphql::extensions::{AuditLog, AuditRecord};
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// struct UserId(String);
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn value(&self) -> i32 {
+///         10
+///     }
+/// }
+///
+/// struct Mutation;
+///
+/// #[Object]
+/// impl Mutation {
+///     async fn login(&self, name: String, #[graphql(secret)] password: String) -> bool {
+///         true
+///     }
+/// }
+///
+/// let records = Rc::new(RefCell::new(Vec::new()));
+/// let schema = Schema::build(Query, Mutation, EmptySubscription)
+///     .extension(
+///         AuditLog::new({
+///             let records = records.clone();
+///             move |record: AuditRecord| records.borrow_mut().push(record)
+///         })
+///         .identity(|ctx| ctx.data_opt::<UserId>().map(|id| id.0.clone())),
+///     )
+///     .finish();
+///
+/// # async_std::task::block_on(async move {
+/// let request = Request::new(r#"mutation { login(name: "me", password: "1234") }"#)
+///     .data(UserId("1".to_string()));
+/// schema.execute(request).await.into_result().unwrap();
+///
+/// let records = records.borrow();
+/// assert_eq!(records[0].field_name, "login");
+/// assert_eq!(records[0].identity.as_deref(), Some("1"));
+/// assert_eq!(
+///     Value::Object(records[0].arguments.clone()),
+///     value!({ "name": "me", "password": "[REDACTED]" })
+/// );
+/// assert!(records[0].error.is_none());
+/// # });
+/// ```
+pub struct AuditLog {
+    sink: Rc<dyn AuditLogSink>,
+    identity: Option<Rc<IdentityFn>>,
+}
+
+impl AuditLog {
+    /// Creates an audit log extension delivering the records to `sink`.
+    pub fn new(sink: impl AuditLogSink) -> Self {
+        Self {
+            sink: Rc::new(sink),
+            identity: None,
+        }
+    }
+
+    /// Set the function extracting the identity of the caller, usually from the data of the
+    /// request. By default the identity is `None`.
+    #[must_use]
+    pub fn identity(
+        self,
+        identity: impl Fn(&ExtensionContext<'_>) -> Option<String> + 'static,
+    ) -> Self {
+        Self {
+            identity: Some(Rc::new(identity)),
+            ..self
+        }
+    }
+}
+
+impl ExtensionFactory for AuditLog {
+    fn create(&self) -> Box<dyn Extension> {
+        Box::new(AuditLogExtension {
+            sink: self.sink.clone(),
+            identity: self.identity.clone(),
+        })
+    }
+}
+
+struct AuditLogExtension {
+    sink: Rc<dyn AuditLogSink>,
+    identity: Option<Rc<IdentityFn>>,
+}
+
+impl Extension for AuditLogExtension {
+    fn mutation_field_end(&mut self, ctx: &ExtensionContext<'_>, info: &MutationFieldInfo<'_>) {
+        self.sink.send(AuditRecord {
+            operation_name: info.operation_name.map(ToString::to_string),
+            field_name: info.field_name.to_string(),
+            arguments: info.arguments.clone(),
+            identity: self.identity.as_ref().and_then(|identity| identity(ctx)),
+            error: info.error.cloned(),
+        });
+    }
+}
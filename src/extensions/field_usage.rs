@@ -0,0 +1,178 @@
+//! Field usage analytics extension.
+
+use std::cell::RefCell;
+use std::mem;
+use std::rc::Rc;
+
+use crate::extensions::{Extension, ExtensionContext, ExtensionFactory};
+use crate::{Request, ServerResult};
+
+/// The fields selected by one request, recorded by [`FieldUsage`](struct.FieldUsage.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldUsageRecord {
+    /// The name of the operation, as sent in the request.
+    pub operation_name: Option<String>,
+    /// The fields the document selects, as `Type.field` schema coordinates, sorted.
+    pub fields: Vec<String>,
+}
+
+/// Receives the batches of records of [`FieldUsage`](struct.FieldUsage.html).
+pub trait FieldUsageSink: 'static {
+    /// Deliver a batch of records, in the order the requests were validated.
+    fn send(&self, batch: Vec<FieldUsageRecord>);
+}
+
+impl<F: Fn(Vec<FieldUsageRecord>) + 'static> FieldUsageSink for F {
+    fn send(&self, batch: Vec<FieldUsageRecord>) {
+        self(batch)
+    }
+}
+
+struct FieldUsageInner {
+    sink: Box<dyn FieldUsageSink>,
+    batch_size: usize,
+    batch: RefCell<Vec<FieldUsageRecord>>,
+}
+
+impl FieldUsageInner {
+    fn flush(&self) {
+        let batch = mem::take(&mut *self.batch.borrow_mut());
+        if !batch.is_empty() {
+            self.sink.send(batch);
+        }
+    }
+}
+
+impl Drop for FieldUsageInner {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+/// Field usage analytics extension.
+///
+/// Records which fields each valid request selects, so you can tell whether a field is still
+/// used before deprecating or removing it. The records are delivered to the sink in batches of
+/// `batch_size` (100 by default), and the last partial batch when `flush` is called or when the
+/// last clone of the extension is dropped.
+///
+/// Requests that fail the validation or the limits are not recorded.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use async_graphql::extensions::{FieldUsage, FieldUsageRecord};
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn value(&self) -> i32 {
+///         10
+///     }
+///
+///     #[graphql(deprecation = "Use `value` instead")]
+///     async fn old_value(&self) -> i32 {
+///         10
+///     }
+/// }
+///
+/// let records = Rc::new(RefCell::new(Vec::new()));
+/// let usage = FieldUsage::new({
+///     let records = records.clone();
+///     move |batch: Vec<FieldUsageRecord>| records.borrow_mut().extend(batch)
+/// });
+/// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+///     .extension(usage.clone())
+///     .finish();
+///
+/// # async_std::task::block_on(async move {
+/// schema.execute("{ value oldValue }").await;
+/// usage.flush();
+/// assert_eq!(
+///     *records.borrow(),
+///     vec![FieldUsageRecord {
+///         operation_name: None,
+///         fields: vec!["Query.oldValue".to_string(), "Query.value".to_string()],
+///     }]
+/// );
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct FieldUsage(Rc<FieldUsageInner>);
+
+impl FieldUsage {
+    /// Creates a field usage extension delivering the records to `sink`.
+    pub fn new(sink: impl FieldUsageSink) -> Self {
+        Self(Rc::new(FieldUsageInner {
+            sink: Box::new(sink),
+            batch_size: 100,
+            batch: Default::default(),
+        }))
+    }
+
+    /// Set the number of records in a batch, a size of 0 is treated as 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the extension has already been cloned.
+    #[must_use]
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        Rc::get_mut(&mut self.0)
+            .expect("The batch size must be set before the extension is cloned.")
+            .batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Deliver the records that are not part of a full batch yet.
+    pub fn flush(&self) {
+        self.0.flush();
+    }
+}
+
+impl ExtensionFactory for FieldUsage {
+    fn create(&self) -> Box<dyn Extension> {
+        Box::new(FieldUsageExtension {
+            inner: self.0.clone(),
+            operation_name: None,
+        })
+    }
+}
+
+struct FieldUsageExtension {
+    inner: Rc<FieldUsageInner>,
+    operation_name: Option<String>,
+}
+
+#[async_trait::async_trait(?Send)]
+impl Extension for FieldUsageExtension {
+    async fn prepare_request(
+        &mut self,
+        _ctx: &ExtensionContext<'_>,
+        request: Request,
+    ) -> ServerResult<Request> {
+        self.operation_name = request.operation_name.clone();
+        Ok(request)
+    }
+
+    fn collects_field_usage(&self) -> bool {
+        true
+    }
+
+    fn field_usage(&mut self, _ctx: &ExtensionContext<'_>, fields: &[String]) {
+        let is_full = {
+            let mut batch = self.inner.batch.borrow_mut();
+            batch.push(FieldUsageRecord {
+                operation_name: self.operation_name.take(),
+                fields: fields.to_vec(),
+            });
+            batch.len() >= self.inner.batch_size
+        };
+        if is_full {
+            self.inner.flush();
+        }
+    }
+}
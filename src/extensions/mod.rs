@@ -4,21 +4,24 @@
 pub mod apollo_persisted_queries;
 #[cfg(feature = "apollo_tracing")]
 mod apollo_tracing;
+mod audit_log;
+mod field_usage;
 #[cfg(feature = "log")]
 mod logger;
 #[cfg(feature = "tracing")]
 mod tracing;
 
 use std::any::{Any, TypeId};
-use std::collections::BTreeMap;
 
 use crate::context::{QueryPathNode, ResolveId};
 use crate::parser::types::ExecutableDocument;
 use crate::{Data, Request, Result, ServerError, ServerResult, Variables};
-use crate::{Error, Name, Value};
+use crate::{Error, Map, Name, Value};
 
 #[cfg(feature = "apollo_tracing")]
 pub use self::apollo_tracing::ApolloTracing;
+pub use self::audit_log::{AuditLog, AuditLogSink, AuditRecord};
+pub use self::field_usage::{FieldUsage, FieldUsageRecord, FieldUsageSink};
 #[cfg(feature = "log")]
 pub use self::logger::Logger;
 #[cfg(feature = "tracing")]
@@ -85,6 +88,28 @@ pub struct ResolveInfo<'a> {
 
     /// Current return type, is qualified name.
     pub return_type: &'a str,
+
+    /// The arguments of the field with the variables replaced by their values, see
+    /// `Context::arguments`. `None` for the items of lists and the entities of `_entities`, or if
+    /// an argument uses a variable which is not defined.
+    pub arguments: Option<&'a Map<Value>>,
+}
+
+/// Parameters for `Extension::mutation_field_end`
+pub struct MutationFieldInfo<'a> {
+    /// The name of the operation, `None` if it is anonymous.
+    pub operation_name: Option<&'a str>,
+
+    /// The name of the field, not its alias.
+    pub field_name: &'a str,
+
+    /// The arguments in the query with the variables replaced by their values, default values
+    /// are not included. The values of the arguments and input fields marked as `secret` are
+    /// replaced by `"[REDACTED]"`.
+    pub arguments: &'a Map<Value>,
+
+    /// The error of the field, `None` if it resolved successfully.
+    pub error: Option<&'a ServerError>,
 }
 
 /// Represents a GraphQL extension
@@ -117,12 +142,31 @@ pub trait Extension: 'static {
     /// Called at the end of the parse.
     fn parse_end(&mut self, ctx: &ExtensionContext<'_>, document: &ExecutableDocument) {}
 
+    /// Called after the parse to rewrite the document before it is validated, for example with
+    /// [`VisitMut`](../parser/visit/trait.VisitMut.html).
+    fn transform_document(
+        &mut self,
+        ctx: &ExtensionContext<'_>,
+        document: &mut ExecutableDocument,
+    ) {
+    }
+
     /// Called at the begin of the validation.
     fn validation_start(&mut self, ctx: &ExtensionContext<'_>) {}
 
     /// Called at the end of the validation.
     fn validation_end(&mut self, ctx: &ExtensionContext<'_>) {}
 
+    /// Whether `field_usage` should be called. Collecting the fields walks the whole document, so
+    /// it is only done if an extension asks for it.
+    fn collects_field_usage(&self) -> bool {
+        false
+    }
+
+    /// Called after the validation and the limit checks with the fields the document selects, as
+    /// `Type.field` [schema coordinates](../struct.SchemaCoordinate.html), sorted.
+    fn field_usage(&mut self, ctx: &ExtensionContext<'_>, fields: &[String]) {}
+
     /// Called at the begin of the execution.
     fn execution_start(&mut self, ctx: &ExtensionContext<'_>) {}
 
@@ -135,6 +179,9 @@ pub trait Extension: 'static {
     /// Called at the end of the resolve field.
     fn resolve_end(&mut self, ctx: &ExtensionContext<'_>, info: &ResolveInfo<'_>) {}
 
+    /// Called after each field of the mutation root resolves, whether it succeeded or not.
+    fn mutation_field_end(&mut self, ctx: &ExtensionContext<'_>, info: &MutationFieldInfo<'_>) {}
+
     /// Called when an error occurs.
     fn error(&mut self, ctx: &ExtensionContext<'_>, err: &ServerError) {}
 
@@ -174,6 +221,11 @@ impl<T> ErrorLogger for Result<T, Vec<ServerError>> {
 pub trait ExtensionFactory: 'static {
     /// Create an extended instance.
     fn create(&self) -> Box<dyn Extension>;
+
+    /// The name of the extension, reported by `Schema::check_health`.
+    fn name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
 }
 
 #[doc(hidden)]
@@ -230,6 +282,18 @@ impl Extensions {
         }
     }
 
+    pub fn transform_document(
+        &mut self,
+        ctx: &ExtensionContext<'_>,
+        document: &mut ExecutableDocument,
+    ) {
+        if let Some(e) = &mut self.0 {
+            e.get_mut()
+                .iter_mut()
+                .for_each(|e| e.transform_document(ctx, document));
+        }
+    }
+
     pub fn validation_start(&mut self, ctx: &ExtensionContext<'_>) {
         if let Some(e) = &mut self.0 {
             e.get_mut().iter_mut().for_each(|e| e.validation_start(ctx));
@@ -242,6 +306,22 @@ impl Extensions {
         }
     }
 
+    pub fn collects_field_usage(&mut self) -> bool {
+        match &mut self.0 {
+            Some(e) => e.get_mut().iter().any(|e| e.collects_field_usage()),
+            None => false,
+        }
+    }
+
+    pub fn field_usage(&mut self, ctx: &ExtensionContext<'_>, fields: &[String]) {
+        if let Some(e) = &mut self.0 {
+            e.get_mut()
+                .iter_mut()
+                .filter(|e| e.collects_field_usage())
+                .for_each(|e| e.field_usage(ctx, fields));
+        }
+    }
+
     pub fn execution_start(&self, ctx: &ExtensionContext<'_>) {
         if let Some(e) = &self.0 {
             e.lock().iter_mut().for_each(|e| e.execution_start(ctx));
@@ -268,6 +348,14 @@ impl Extensions {
         }
     }
 
+    pub fn mutation_field_end(&self, ctx: &ExtensionContext<'_>, info: &MutationFieldInfo<'_>) {
+        if let Some(e) = &self.0 {
+            e.lock()
+                .iter_mut()
+                .for_each(|e| e.mutation_field_end(ctx, info));
+        }
+    }
+
     pub fn error(&self, ctx: &ExtensionContext<'_>, err: &ServerError) {
         if let Some(e) = &self.0 {
             e.lock().iter_mut().for_each(|e| e.error(ctx, err));
@@ -286,7 +374,7 @@ impl Extensions {
                         None
                     }
                 })
-                .collect::<BTreeMap<_, _>>();
+                .collect::<Map<_>>();
             if value.is_empty() {
                 None
             } else {
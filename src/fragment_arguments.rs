@@ -0,0 +1,145 @@
+//! Substitution of [fragment arguments](https://github.com/graphql/graphql-spec/pull/1081).
+//!
+//! Each spread of a fragment that defines variables is replaced by an inline fragment with the
+//! same type condition, whose selection set has the variables of the fragment replaced by the
+//! arguments of the spread. The rest of the executor never sees fragment variables.
+
+use std::collections::HashMap;
+
+use crate::parser::types::{
+    Directive, ExecutableDocument, Field, FragmentDefinition, FragmentSpread, InlineFragment,
+    Selection,
+};
+use crate::parser::visit::{
+    visit_directive_mut, visit_field_mut, visit_fragment_spread_mut, visit_selection_mut, VisitMut,
+};
+use crate::{Name, Positioned};
+use async_graphql_value::Value;
+
+/// Replace the spreads of fragments with variables by inline fragments. The document must be
+/// valid.
+pub(crate) fn inline_fragment_arguments(doc: &mut ExecutableDocument) {
+    if doc
+        .fragments
+        .values()
+        .all(|fragment| fragment.node.variable_definitions.is_empty())
+    {
+        return;
+    }
+
+    let fragments = doc.fragments.clone();
+    FragmentInliner {
+        fragments: &fragments,
+    }
+    .visit_document_mut(doc);
+}
+
+struct FragmentInliner<'a> {
+    fragments: &'a HashMap<Name, Positioned<FragmentDefinition>>,
+}
+
+impl<'a> VisitMut for FragmentInliner<'a> {
+    fn visit_selection_mut(&mut self, selection: &mut Positioned<Selection>) {
+        if let Selection::FragmentSpread(spread) = &selection.node {
+            if let Some(fragment) = self
+                .fragments
+                .get(&spread.node.fragment_name.node)
+                .filter(|fragment| !fragment.node.variable_definitions.is_empty())
+            {
+                selection.node = Selection::InlineFragment(inline_fragment(spread, &fragment.node));
+            }
+        }
+        visit_selection_mut(self, selection);
+    }
+}
+
+fn inline_fragment(
+    spread: &Positioned<FragmentSpread>,
+    fragment: &FragmentDefinition,
+) -> Positioned<InlineFragment> {
+    // A variable without an argument or a default value is unset, just like a missing
+    // operation variable.
+    let bindings = fragment
+        .variable_definitions
+        .iter()
+        .map(|definition| {
+            let name = &definition.node.name.node;
+            let value = spread
+                .node
+                .arguments
+                .iter()
+                .find(|(arg_name, _)| &arg_name.node == name)
+                .map(|(_, value)| value.node.clone())
+                .or_else(|| {
+                    definition
+                        .node
+                        .default_value
+                        .as_ref()
+                        .map(|value| value.node.clone().into_value())
+                });
+            (name.clone(), value)
+        })
+        .collect();
+
+    let mut selection_set = fragment.selection_set.clone();
+    Substitute { bindings }.visit_selection_set_mut(&mut selection_set);
+
+    Positioned::new(
+        InlineFragment {
+            type_condition: Some(fragment.type_condition.clone()),
+            directives: spread.node.directives.clone(),
+            selection_set,
+        },
+        spread.pos,
+    )
+}
+
+struct Substitute {
+    bindings: HashMap<Name, Option<Value>>,
+}
+
+impl Substitute {
+    fn is_unset(&self, value: &Value) -> bool {
+        matches!(value, Value::Variable(name) if matches!(self.bindings.get(name), Some(None)))
+    }
+
+    fn substitute_arguments(&self, arguments: &mut Vec<(Positioned<Name>, Positioned<Value>)>) {
+        arguments.retain(|(_, value)| !self.is_unset(&value.node));
+        for (_, value) in arguments {
+            self.substitute(&mut value.node);
+        }
+    }
+
+    fn substitute(&self, value: &mut Value) {
+        match value {
+            Value::Variable(name) => {
+                if let Some(binding) = self.bindings.get(name) {
+                    *value = binding.clone().unwrap_or(Value::Null);
+                }
+            }
+            Value::List(items) => items.iter_mut().for_each(|item| self.substitute(item)),
+            Value::Object(fields) => {
+                fields.retain(|_, value| !self.is_unset(value));
+                fields.values_mut().for_each(|value| self.substitute(value));
+            }
+            _ => {}
+        }
+    }
+}
+
+impl VisitMut for Substitute {
+    fn visit_field_mut(&mut self, field: &mut Positioned<Field>) {
+        self.substitute_arguments(&mut field.node.arguments);
+        visit_field_mut(self, field);
+    }
+
+    fn visit_fragment_spread_mut(&mut self, fragment_spread: &mut Positioned<FragmentSpread>) {
+        self.substitute_arguments(&mut fragment_spread.node.arguments);
+        visit_fragment_spread_mut(self, fragment_spread);
+    }
+
+    fn visit_directive_mut(&mut self, directive: &mut Positioned<Directive>) {
+        self.substitute_arguments(&mut directive.node.arguments);
+        visit_directive_mut(self, directive);
+    }
+}
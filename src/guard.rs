@@ -1,10 +1,64 @@
 //! Field guards
+//!
+//! A guard is checked before a field is resolved, which allows access control to be declared on
+//! the fields instead of being checked in every resolver:
+//!
+//! ```rust
+//! use async_graphql::*;
+//! use async_graphql::guard::Guard;
+//!
+//! #[derive(Eq, PartialEq, Copy, Clone)]
+//! enum Role {
+//!     Admin,
+//!     Staff,
+//! }
+//!
+//! struct RoleGuard {
+//!     role: Role,
+//! }
+//!
+//! #[async_trait::async_trait(?Send)]
+//! impl Guard for RoleGuard {
+//!     async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+//!         if ctx.data_opt::<Role>() == Some(&self.role) {
+//!             Ok(())
+//!         } else {
+//!             Err("Forbidden".into())
+//!         }
+//!     }
+//! }
+//!
+//! struct Query;
+//!
+//! #[Object]
+//! impl Query {
+//!     #[graphql(guard(or(RoleGuard(role = "Role::Admin"), RoleGuard(role = "Role::Staff"))))]
+//!     async fn value(&self) -> i32 {
+//!         10
+//!     }
+//! }
+//!
+//! async_std::task::block_on(async move {
+//!     let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+//!     let res = schema.execute(Request::new("{ value }").data(Role::Staff)).await;
+//!     assert_eq!(res.data, value!({ "value": 10 }));
+//!     let res = schema.execute("{ value }").await;
+//!     assert_eq!(res.errors[0].message, "Forbidden");
+//! });
+//! ```
 
-use crate::{Context, Result};
+use futures_util::future::{select_ok, try_join_all};
+
+use crate::{Context, Error, Result};
 
 /// Field guard
 ///
 /// Guard is a pre-condition for a field that is resolved if `Ok(())` is returned, otherwise an error is returned.
+/// The error can carry extensions, e.g. `Err(Error::new("Forbidden").extend_with(|_, e| e.set("code", "FORBIDDEN")))`,
+/// which are kept in the response.
+///
+/// Use [`SchemaBuilder::hide_forbidden_fields`](../struct.SchemaBuilder.html#method.hide_forbidden_fields)
+/// to respond as if the field didn't exist instead.
 ///
 /// This trait is defined through the [`async-trait`](https://crates.io/crates/async-trait) macro.
 #[async_trait::async_trait(?Send)]
@@ -20,10 +74,16 @@ pub trait GuardExt: Guard + Sized {
         And(self, other)
     }
 
-    /// Perform `or` operator on two rules
+    /// Perform `or` operator on two rules, the second rule is only checked if the first one
+    /// forbids access.
     fn or<R: Guard>(self, other: R) -> Or<Self, R> {
         Or(self, other)
     }
+
+    /// Perform `not` operator on the rule, access is forbidden if the rule allows it.
+    fn not(self) -> Not<Self> {
+        Not(self)
+    }
 }
 
 impl<T: Guard> GuardExt for T {}
@@ -45,7 +105,69 @@ pub struct Or<A: Guard, B: Guard>(A, B);
 #[async_trait::async_trait(?Send)]
 impl<A: Guard, B: Guard> Guard for Or<A, B> {
     async fn check(&self, ctx: &Context<'_>) -> Result<()> {
-        let second_result = self.1.check(ctx).await;
-        self.0.check(ctx).await.or(second_result)
+        match self.0.check(ctx).await {
+            Ok(()) => Ok(()),
+            Err(_) => self.1.check(ctx).await,
+        }
+    }
+}
+
+/// Guard for [`GuardExt::not`](trait.GuardExt.html#method.not).
+pub struct Not<A: Guard>(A);
+
+#[async_trait::async_trait(?Send)]
+impl<A: Guard> Guard for Not<A> {
+    async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+        match self.0.check(ctx).await {
+            Ok(()) => Err(Error::new("Forbidden")),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+/// A guard that allows access if any of its guards does.
+///
+/// The guards are checked concurrently. If all of them fail, the error of the last one to fail
+/// is returned, an empty list forbids access.
+pub struct RaceGuard(Vec<Box<dyn Guard>>);
+
+impl RaceGuard {
+    /// Create a guard from a list of guards.
+    pub fn new(guards: Vec<Box<dyn Guard>>) -> Self {
+        Self(guards)
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Guard for RaceGuard {
+    async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+        if self.0.is_empty() {
+            return Err(Error::new("Forbidden"));
+        }
+        select_ok(self.0.iter().map(|guard| guard.check(ctx)))
+            .await
+            .map(|_| ())
+    }
+}
+
+/// A guard that allows access if all of its guards do.
+///
+/// The guards are checked concurrently, and the first error is returned. An empty list allows
+/// access.
+pub struct AllGuard(Vec<Box<dyn Guard>>);
+
+impl AllGuard {
+    /// Create a guard from a list of guards.
+    pub fn new(guards: Vec<Box<dyn Guard>>) -> Self {
+        Self(guards)
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Guard for AllGuard {
+    async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+        try_join_all(self.0.iter().map(|guard| guard.check(ctx)))
+            .await
+            .map(|_| ())
     }
 }
@@ -0,0 +1,74 @@
+//! Health checks
+//!
+//! `Schema::check_health` runs the checks of a readiness probe on the schema: it creates an
+//! instance of every extension, executes the query `{ __typename }` and checks that every type
+//! referenced in the registry is registered. The [`HealthStatus`](struct.HealthStatus.html) it
+//! returns serializes to JSON, to be returned by the probe endpoint.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use async_graphql::*;
+//!
+//! struct Query;
+//!
+//! #[Object]
+//! impl Query {
+//!     async fn value(&self) -> i32 {
+//!         10
+//!     }
+//! }
+//!
+//! let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+//!
+//! async_std::task::block_on(async move {
+//!     let status = schema.check_health().await;
+//!     assert!(status.healthy);
+//!     assert_eq!(
+//!         status.checks.iter().map(|check| check.name).collect::<Vec<_>>(),
+//!         vec!["extensions", "query", "registry"]
+//!     );
+//! });
+//! ```
+
+use serde::Serialize;
+
+/// The result of `Schema::check_health`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HealthStatus {
+    /// Whether every check passed.
+    pub healthy: bool,
+    /// The checks, in the order they ran.
+    pub checks: Vec<HealthCheck>,
+}
+
+impl HealthStatus {
+    pub(crate) fn new(checks: Vec<HealthCheck>) -> Self {
+        Self {
+            healthy: checks.iter().all(|check| check.healthy),
+            checks,
+        }
+    }
+}
+
+/// The result of one of the checks of `Schema::check_health`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HealthCheck {
+    /// The name of the check, `extensions`, `query` or `registry`.
+    pub name: &'static str,
+    /// Whether the check passed.
+    pub healthy: bool,
+    /// The problems found by the check.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+}
+
+impl HealthCheck {
+    pub(crate) fn new(name: &'static str, errors: Vec<String>) -> Self {
+        Self {
+            name,
+            healthy: errors.is_empty(),
+            errors,
+        }
+    }
+}
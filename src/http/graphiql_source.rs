@@ -1,60 +1,187 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
 /// Generate the page for GraphIQL
 pub fn graphiql_source(graphql_endpoint_url: &str, subscription_endpoint: Option<&str>) -> String {
+    let mut config = GraphiQLConfig::new(graphql_endpoint_url);
+    if let Some(subscription_endpoint) = subscription_endpoint {
+        config = config.subscription_endpoint(subscription_endpoint);
+    }
+    graphiql_source_with_config(config)
+}
+
+/// Generate the page for GraphIQL from a config.
+///
+/// # Example
+///
+/// ```rust
+/// use async_graphql::http::*;
+///
+/// let page = graphiql_source_with_config(
+///     GraphiQLConfig::new("/graphql")
+///         .subscription_endpoint("ws://localhost:8000/ws")
+///         .subscription_protocol(SubscriptionProtocol::GraphQLWs)
+///         .with_header("Authorization", "Bearer 123")
+///         .editor_theme("dracula"),
+/// );
+/// assert!(page.contains("graphql-ws"));
+/// ```
+pub fn graphiql_source_with_config(config: GraphiQLConfig) -> String {
+    let client_script = match config.subscription_protocol {
+        SubscriptionProtocol::SubscriptionsTransportWs => {
+            "//unpkg.com/subscriptions-transport-ws@0.9.18/browser/client.js"
+        }
+        SubscriptionProtocol::GraphQLWs => "//unpkg.com/graphql-ws@4/umd/graphql-ws.min.js",
+        SubscriptionProtocol::Sse => "//unpkg.com/graphql-sse@1/umd/graphql-sse.min.js",
+    };
+    let theme_stylesheet = match config.editor_theme {
+        Some(theme) => format!(
+            r#"<link href="https://unpkg.com/codemirror@5/theme/{}.css" rel="stylesheet" />"#,
+            theme
+        ),
+        None => String::new(),
+    };
+
     r#"
     <html>
   <head>
     <title>Simple GraphiQL Example</title>
-    <link href="https://unpkg.com/graphiql/graphiql.min.css" rel="stylesheet" />
+    <link href="https://unpkg.com/graphiql@1/graphiql.min.css" rel="stylesheet" />
+    THEME_STYLESHEET
   </head>
   <body style="margin: 0;">
     <div id="graphiql" style="height: 100vh;"></div>
 
-    <script src="//unpkg.com/subscriptions-transport-ws@0.8.3/browser/client.js"></script>
-    <script src="//unpkg.com/graphiql-subscriptions-fetcher@0.0.2/browser/client.js"></script>
+    <script src="CLIENT_SCRIPT"></script>
     <script
       crossorigin
-      src="https://unpkg.com/react/umd/react.production.min.js"
+      src="https://unpkg.com/react@17/umd/react.production.min.js"
     ></script>
     <script
       crossorigin
-      src="https://unpkg.com/react-dom/umd/react-dom.production.min.js"
+      src="https://unpkg.com/react-dom@17/umd/react-dom.production.min.js"
     ></script>
     <script
       crossorigin
-      src="https://unpkg.com/graphiql/graphiql.min.js"
+      src="https://unpkg.com/graphiql@1/graphiql.min.js"
     ></script>
 
     <script>
-      var fetcher = graphQLParams =>
-        fetch('GRAPHQL_URL', {
-          method: 'post',
-          headers: { 'Content-Type': 'application/json' },
-          body: JSON.stringify(graphQLParams),
-        })
-          .then(response => response.json())
-          .catch(() => response.text());
-
-      var subscription_url = GRAPHQL_SUBSCRIPTION_URL;
-
-      if (subscription_url) {
-        var subscriptionClient = new window.SubscriptionsTransportWs.SubscriptionClient(GRAPHQL_SUBSCRIPTION_URL, { reconnect: true });
-        fetcher = window.GraphiQLSubscriptionsFetcher.graphQLFetcher(subscriptionClient, fetcher);
+      var config = GRAPHIQL_CONFIG;
+      var options = { url: config.endpoint, headers: config.headers };
+
+      if (config.subscriptionEndpoint) {
+        switch (config.subscriptionProtocol) {
+          case 'subscriptions-transport-ws':
+            options.legacyWsClient = new window.SubscriptionsTransportWs.SubscriptionClient(
+              config.subscriptionEndpoint,
+              { reconnect: true, connectionParams: config.headers },
+            );
+            break;
+          case 'graphql-ws':
+            options.wsClient = window.graphqlWs.createClient({
+              url: config.subscriptionEndpoint,
+              connectionParams: config.headers,
+            });
+            break;
+          case 'sse':
+            options.wsClient = window.graphqlSse.createClient({
+              url: config.subscriptionEndpoint,
+              headers: config.headers,
+            });
+            break;
+        }
       }
 
       ReactDOM.render(
-        React.createElement(GraphiQL, { fetcher }),
+        React.createElement(GraphiQL, {
+          fetcher: GraphiQL.createFetcher(options),
+          editorTheme: config.editorTheme,
+        }),
         document.getElementById('graphiql'),
       );
     </script>
   </body>
 </html>
     "#
-    .replace("GRAPHQL_URL", graphql_endpoint_url)
+    .replace("THEME_STYLESHEET", &theme_stylesheet)
+    .replace("CLIENT_SCRIPT", client_script)
     .replace(
-        "GRAPHQL_SUBSCRIPTION_URL",
-        &match subscription_endpoint {
-            Some(url) => format!("'{}'", url),
-            None => "null".to_string(),
+        "GRAPHIQL_CONFIG",
+        &match serde_json::to_string(&config) {
+            // Don't let a value close the script element.
+            Ok(str) => str.replace("</", "<\\/"),
+            Err(_) => "{}".to_string(),
         },
     )
 }
+
+/// The protocol GraphIQL uses to run subscriptions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum SubscriptionProtocol {
+    /// The [`subscriptions-transport-ws`](https://github.com/apollographql/subscriptions-transport-ws/blob/master/PROTOCOL.md)
+    /// protocol, implemented by [`WebSocket`](struct.WebSocket.html).
+    #[serde(rename = "subscriptions-transport-ws")]
+    SubscriptionsTransportWs,
+    /// The [`graphql-ws`](https://github.com/enisdenjo/graphql-ws/blob/master/PROTOCOL.md)
+    /// protocol, also known as `graphql-transport-ws`.
+    #[serde(rename = "graphql-ws")]
+    GraphQLWs,
+    /// Server-sent events, as implemented by [`graphql-sse`](https://github.com/enisdenjo/graphql-sse).
+    /// The subscription endpoint is an HTTP URL.
+    #[serde(rename = "sse")]
+    Sse,
+}
+
+/// Config for GraphIQL
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphiQLConfig<'a> {
+    endpoint: &'a str,
+    subscription_endpoint: Option<&'a str>,
+    subscription_protocol: SubscriptionProtocol,
+    headers: Option<HashMap<&'a str, &'a str>>,
+    editor_theme: Option<&'a str>,
+}
+
+impl<'a> GraphiQLConfig<'a> {
+    /// Create a config for GraphIQL.
+    pub fn new(endpoint: &'a str) -> Self {
+        Self {
+            endpoint,
+            subscription_endpoint: None,
+            subscription_protocol: SubscriptionProtocol::SubscriptionsTransportWs,
+            headers: Default::default(),
+            editor_theme: None,
+        }
+    }
+
+    /// Set subscription endpoint, for example: `ws://localhost:8000`.
+    pub fn subscription_endpoint(mut self, endpoint: &'a str) -> Self {
+        self.subscription_endpoint = Some(endpoint);
+        self
+    }
+
+    /// Set the subscription protocol, default is `SubscriptionProtocol::SubscriptionsTransportWs`.
+    pub fn subscription_protocol(mut self, protocol: SubscriptionProtocol) -> Self {
+        self.subscription_protocol = protocol;
+        self
+    }
+
+    /// Set HTTP header for per query. The headers are also sent as the connection parameters
+    /// of the WebSocket protocols.
+    pub fn with_header(mut self, name: &'a str, value: &'a str) -> Self {
+        self.headers
+            .get_or_insert_with(HashMap::new)
+            .insert(name, value);
+        self
+    }
+
+    /// Set the [CodeMirror theme](https://codemirror.net/demo/theme.html) of the editors, for
+    /// example: `dracula`.
+    pub fn editor_theme(mut self, theme: &'a str) -> Self {
+        self.editor_theme = Some(theme);
+        self
+    }
+}
@@ -4,17 +4,23 @@ mod graphiql_source;
 #[cfg(feature = "multipart")]
 mod multipart;
 mod playground_source;
+mod response_stream;
+mod sse;
 mod websocket;
 
 use futures_util::io::{AsyncRead, AsyncReadExt};
 
 use crate::{BatchRequest, ParseRequestError, Request};
 
-pub use graphiql_source::graphiql_source;
+pub use graphiql_source::{
+    graphiql_source, graphiql_source_with_config, GraphiQLConfig, SubscriptionProtocol,
+};
 #[cfg(feature = "multipart")]
 pub use multipart::MultipartOptions;
 pub use playground_source::{playground_source, GraphQLPlaygroundConfig};
-pub use websocket::WebSocket;
+pub use response_stream::{response_size, write_response, ResponseStream};
+pub use sse::{sse_events, LAST_EVENT_ID};
+pub use websocket::{WebSocket, WebSocketProtocols};
 
 #[cfg(feature = "multipart")]
 #[cfg_attr(feature = "nightly", doc(cfg(feature = "multipart")))]
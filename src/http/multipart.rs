@@ -4,8 +4,11 @@ use std::pin::Pin;
 use std::task::{Context, Poll};
 
 use bytes::Bytes;
+use futures_channel::mpsc;
+use futures_util::future::{self, Either};
 use futures_util::io::AsyncRead;
-use futures_util::stream::Stream;
+use futures_util::sink::SinkExt;
+use futures_util::stream::{Stream, StreamExt};
 use multer::{Constraints, Multipart, SizeLimit};
 use pin_project_lite::pin_project;
 
@@ -18,7 +21,8 @@ use crate::{BatchRequest, ParseRequestError, UploadValue};
 pub struct MultipartOptions {
     /// The maximum file size.
     pub max_file_size: Option<usize>,
-    /// The maximum number of files.
+    /// The maximum number of files, more files fail the request with
+    /// [`ParseRequestError::PayloadTooLarge`](../enum.ParseRequestError.html).
     pub max_num_files: Option<usize>,
 }
 
@@ -45,15 +49,40 @@ pub(super) async fn receive_batch_multipart(
     boundary: impl Into<String>,
     opts: MultipartOptions,
 ) -> Result<BatchRequest, ParseRequestError> {
-    let mut multipart = Multipart::new_with_constraints(
-        ReaderStream::new(body),
+    // `Multipart` requires a `Send` stream, the chunks of the body are sent to it through a
+    // channel while it is parsed.
+    let (mut tx, rx) = mpsc::channel(1);
+    let feed = async move {
+        let body = ReaderStream::new(body);
+        futures_util::pin_mut!(body);
+        while let Some(chunk) = body.next().await {
+            if tx.send(chunk).await.is_err() {
+                break;
+            }
+        }
+    };
+    let parse = parse_multipart(rx, boundary.into(), opts);
+    futures_util::pin_mut!(feed, parse);
+    match future::select(parse, feed).await {
+        Either::Left((res, _)) => res,
+        Either::Right(((), parse)) => parse.await,
+    }
+}
+
+async fn parse_multipart(
+    body: mpsc::Receiver<io::Result<Bytes>>,
+    boundary: String,
+    opts: MultipartOptions,
+) -> Result<BatchRequest, ParseRequestError> {
+    let mut multipart = Multipart::with_constraints(
+        body,
         boundary,
         Constraints::new().size_limit({
             let mut limit = SizeLimit::new();
             if let (Some(max_file_size), Some(max_num_files)) =
-                (opts.max_file_size, opts.max_file_size)
+                (opts.max_file_size, opts.max_num_files)
             {
-                limit = limit.whole_stream((max_file_size * max_num_files) as u64);
+                limit = limit.whole_stream(max_file_size.saturating_mul(max_num_files) as u64);
             }
             if let Some(max_file_size) = opts.max_file_size {
                 limit = limit.per_field(max_file_size as u64);
@@ -85,10 +114,15 @@ pub(super) async fn receive_batch_multipart(
             _ => {
                 if let Some(name) = field.name().map(ToString::to_string) {
                     if let Some(filename) = field.file_name().map(ToString::to_string) {
+                        if let Some(max_num_files) = opts.max_num_files {
+                            if files.len() >= max_num_files {
+                                return Err(ParseRequestError::PayloadTooLarge);
+                            }
+                        }
                         let content_type = field.content_type().map(ToString::to_string);
                         let mut file = tempfile::tempfile().map_err(ParseRequestError::Io)?;
-                        while let Some(chunk) = field.chunk().await.unwrap() {
-                            file.write(&chunk).map_err(ParseRequestError::Io)?;
+                        while let Some(chunk) = field.chunk().await? {
+                            file.write_all(&chunk).map_err(ParseRequestError::Io)?;
                         }
                         file.seek(SeekFrom::Start(0))?;
                         files.push((name, filename, content_type, file));
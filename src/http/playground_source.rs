@@ -564,6 +564,8 @@ pub struct GraphQLPlaygroundConfig<'a> {
     endpoint: &'a str,
     subscription_endpoint: Option<&'a str>,
     headers: Option<HashMap<&'a str, &'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    settings: Option<HashMap<&'a str, &'a str>>,
 }
 
 impl<'a> GraphQLPlaygroundConfig<'a> {
@@ -573,6 +575,7 @@ impl<'a> GraphQLPlaygroundConfig<'a> {
             endpoint,
             subscription_endpoint: None,
             headers: Default::default(),
+            settings: Default::default(),
         }
     }
 
@@ -593,4 +596,18 @@ impl<'a> GraphQLPlaygroundConfig<'a> {
         }
         self
     }
+
+    /// Set the theme of the editor, `dark` or `light`.
+    pub fn theme(self, theme: &'a str) -> Self {
+        self.with_setting("editor.theme", theme)
+    }
+
+    /// Set a [Playground setting](https://github.com/graphql/graphql-playground#settings), for
+    /// example: `request.credentials`.
+    pub fn with_setting(mut self, name: &'a str, value: &'a str) -> Self {
+        self.settings
+            .get_or_insert_with(HashMap::new)
+            .insert(name, value);
+        self
+    }
 }
@@ -0,0 +1,211 @@
+use std::collections::VecDeque;
+use std::io::{self, Write};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::io::{AsyncWrite, AsyncWriteExt};
+use futures_util::stream::Stream;
+
+use crate::{BatchResponse, Name, Response, Value};
+
+/// Returns the length in bytes of the JSON serialization of a response, without serializing it
+/// into memory.
+///
+/// It can be used as the `Content-Length` of a streamed response, or to decide whether the
+/// response is large enough to be worth compressing.
+pub fn response_size(response: &BatchResponse) -> usize {
+    struct Counter(usize);
+
+    impl Write for Counter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0 += buf.len();
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut counter = Counter(0);
+    let _ = serde_json::to_writer(&mut counter, response);
+    counter.0
+}
+
+/// Serialize a response as JSON into `writer`, in chunks of about `chunk_size` bytes.
+///
+/// The output is the same as `serde_json::to_vec(&response)`.
+pub async fn write_response(
+    response: impl Into<BatchResponse>,
+    writer: impl AsyncWrite,
+    chunk_size: usize,
+) -> io::Result<()> {
+    futures_util::pin_mut!(writer);
+    for chunk in ResponseStream::new(response, chunk_size) {
+        writer.write_all(&chunk).await?;
+    }
+    writer.flush().await
+}
+
+/// A response serialized as JSON in chunks.
+///
+/// The response is serialized while the chunks are read, so a large response is never held in
+/// memory as a single string. Each chunk is at least `chunk_size` bytes long, except the last
+/// one, and can be a bit longer because strings are not split.
+///
+/// It is both an `Iterator` and a `Stream` of chunks.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use async_graphql::http::ResponseStream;
+///
+/// let response = Response::new(value!({ "values": [1, 2, 3] }));
+/// let chunks: Vec<Vec<u8>> = ResponseStream::new(response, 8).collect();
+/// assert_eq!(chunks.concat(), br#"{"data":{"values":[1,2,3]}}"#.to_vec());
+/// ```
+pub struct ResponseStream {
+    chunk_size: usize,
+    pieces: VecDeque<Piece>,
+    stack: Vec<Frame>,
+    buf: Vec<u8>,
+}
+
+enum Piece {
+    Raw(Vec<u8>),
+    Value(Value),
+}
+
+enum Frame {
+    List(std::vec::IntoIter<Value>, bool),
+    Object(indexmap::map::IntoIter<Name, Value>, bool),
+}
+
+impl ResponseStream {
+    /// Serialize `response` in chunks of about `chunk_size` bytes.
+    pub fn new(response: impl Into<BatchResponse>, chunk_size: usize) -> Self {
+        let mut pieces = VecDeque::new();
+        match response.into() {
+            BatchResponse::Single(response) => push_response(&mut pieces, response),
+            BatchResponse::Batch(responses) => {
+                pieces.push_back(Piece::Raw(b"[".to_vec()));
+                for (idx, response) in responses.into_iter().enumerate() {
+                    if idx > 0 {
+                        pieces.push_back(Piece::Raw(b",".to_vec()));
+                    }
+                    push_response(&mut pieces, response);
+                }
+                pieces.push_back(Piece::Raw(b"]".to_vec()));
+            }
+        }
+        Self {
+            chunk_size: chunk_size.max(1),
+            pieces,
+            stack: Vec::new(),
+            buf: Vec::new(),
+        }
+    }
+
+    fn write_value(&mut self, value: Value) {
+        match value {
+            Value::List(items) => {
+                self.buf.push(b'[');
+                self.stack.push(Frame::List(items.into_iter(), true));
+            }
+            Value::Object(map) => {
+                self.buf.push(b'{');
+                self.stack.push(Frame::Object(map.into_iter(), true));
+            }
+            value => {
+                let _ = serde_json::to_writer(&mut self.buf, &value);
+            }
+        }
+    }
+
+    /// Serialize the next step, returns false when the response is fully serialized.
+    fn step(&mut self) -> bool {
+        match self.stack.last_mut() {
+            Some(Frame::List(items, first)) => match items.next() {
+                Some(item) => {
+                    if !std::mem::replace(first, false) {
+                        self.buf.push(b',');
+                    }
+                    self.write_value(item);
+                }
+                None => {
+                    self.buf.push(b']');
+                    self.stack.pop();
+                }
+            },
+            Some(Frame::Object(fields, first)) => match fields.next() {
+                Some((name, value)) => {
+                    if !std::mem::replace(first, false) {
+                        self.buf.push(b',');
+                    }
+                    let _ = serde_json::to_writer(&mut self.buf, name.as_str());
+                    self.buf.push(b':');
+                    self.write_value(value);
+                }
+                None => {
+                    self.buf.push(b'}');
+                    self.stack.pop();
+                }
+            },
+            None => match self.pieces.pop_front() {
+                Some(Piece::Raw(bytes)) => self.buf.extend_from_slice(&bytes),
+                Some(Piece::Value(value)) => self.write_value(value),
+                None => return false,
+            },
+        }
+        true
+    }
+}
+
+fn push_response(pieces: &mut VecDeque<Piece>, response: Response) {
+    let Response {
+        data,
+        extensions,
+        errors,
+        ..
+    } = response;
+
+    pieces.push_back(Piece::Raw(br#"{"data":"#.to_vec()));
+    pieces.push_back(Piece::Value(data));
+    let mut tail = Vec::new();
+    if let Some(extensions) = extensions {
+        tail.extend_from_slice(br#","extensions":"#);
+        let _ = serde_json::to_writer(&mut tail, &extensions);
+    }
+    if !errors.is_empty() {
+        tail.extend_from_slice(br#","errors":"#);
+        let _ = serde_json::to_writer(&mut tail, &errors);
+    }
+    tail.push(b'}');
+    pieces.push_back(Piece::Raw(tail));
+}
+
+impl Iterator for ResponseStream {
+    type Item = Vec<u8>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.buf.len() < self.chunk_size {
+            if !self.step() {
+                break;
+            }
+        }
+        if self.buf.is_empty() {
+            None
+        } else {
+            Some(std::mem::take(&mut self.buf))
+        }
+    }
+}
+
+impl Stream for ResponseStream {
+    type Item = Vec<u8>;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.get_mut().next())
+    }
+}
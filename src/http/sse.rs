@@ -0,0 +1,74 @@
+use futures_util::stream::{self, Stream, StreamExt};
+
+use crate::Response;
+
+/// The header in which a client reconnecting to a server-sent events stream sends the id of the
+/// last event it received, to be set as `Request::last_event_id`.
+pub const LAST_EVENT_ID: &str = "Last-Event-ID";
+
+/// Format the responses of a subscription as server-sent events, the body of a
+/// `text/event-stream` response.
+///
+/// Each response is a `next` event with the response as JSON in its data and the id of the
+/// response, if it has one, in its id, except the keep-alive responses which are sent as
+/// comments. The stream ends with a `complete` event, following the
+/// [GraphQL over SSE protocol](https://github.com/enisdenjo/graphql-sse/blob/master/PROTOCOL.md).
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use async_graphql::http::sse_events;
+/// use futures_util::stream::{self, Stream, StreamExt};
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn value(&self) -> i32 {
+///         10
+///     }
+/// }
+///
+/// struct Subscription;
+///
+/// #[Subscription]
+/// impl Subscription {
+///     async fn values<'a>(&self, ctx: &'a Context<'a>) -> impl Stream<Item = i32> + 'a {
+///         let after = ctx.last_event_id().and_then(|id| id.parse().ok()).unwrap_or(0);
+///         stream::iter(after + 1..=3).event_ids(ctx, |n| n.to_string())
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(Query, EmptyMutation, Subscription);
+///     let request = Request::new("subscription { values }").last_event_id("1");
+///     let events: Vec<String> = sse_events(schema.execute_stream(request)).collect().await;
+///     assert_eq!(events, vec![
+///         "event: next\nid: 2\ndata: {\"data\":{\"values\":2}}\n\n",
+///         "event: next\nid: 3\ndata: {\"data\":{\"values\":3}}\n\n",
+///         "event: complete\ndata:\n\n",
+///     ]);
+/// });
+/// ```
+pub fn sse_events(responses: impl Stream<Item = Response>) -> impl Stream<Item = String> {
+    responses
+        .map(|resp| {
+            if resp.keep_alive {
+                return ":\n\n".to_string();
+            }
+            let mut event = "event: next\n".to_string();
+            if let Some(event_id) = &resp.event_id {
+                event.push_str("id: ");
+                event.push_str(event_id);
+                event.push('\n');
+            }
+            event.push_str("data: ");
+            event.push_str(&serde_json::to_string(&resp).unwrap_or_default());
+            event.push_str("\n\n");
+            event
+        })
+        .chain(stream::once(async {
+            "event: complete\ndata:\n\n".to_string()
+        }))
+}
@@ -2,24 +2,101 @@
 
 use std::collections::HashMap;
 use std::pin::Pin;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::task::{Context, Poll};
+use std::time::Duration;
 
 use futures_util::stream::Stream;
 use pin_project_lite::pin_project;
 use serde::{Deserialize, Serialize};
 
+use crate::parser::types::OperationType;
+use crate::runtime;
 use crate::{Data, Error, ObjectType, Request, Response, Result, Schema, SubscriptionType};
 
+/// The protocols of GraphQL over websocket.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum WebSocketProtocols {
+    /// The legacy `graphql-ws` protocol of
+    /// [subscriptions-transport-ws](https://github.com/apollographql/subscriptions-transport-ws/blob/master/PROTOCOL.md).
+    #[default]
+    SubscriptionsTransportWS,
+    /// The `graphql-transport-ws` protocol of
+    /// [graphql-ws](https://github.com/enisdenjo/graphql-ws/blob/master/PROTOCOL.md).
+    GraphQLWS,
+}
+
+impl WebSocketProtocols {
+    /// The name of the protocol in the `Sec-WebSocket-Protocol` header.
+    pub fn sec_websocket_protocol(&self) -> &'static str {
+        match self {
+            WebSocketProtocols::SubscriptionsTransportWS => "graphql-ws",
+            WebSocketProtocols::GraphQLWS => "graphql-transport-ws",
+        }
+    }
+
+    /// Select the first protocol supported of the comma separated list of the
+    /// `Sec-WebSocket-Protocol` header sent by the client.
+    pub fn from_sec_websocket_protocol(header: &str) -> Option<Self> {
+        header
+            .split(',')
+            .find_map(|protocol| protocol.trim().parse().ok())
+    }
+
+    /// The message of a connection error, `None` to close the connection.
+    fn connection_error(&self, err: Error) -> Option<String> {
+        match self {
+            WebSocketProtocols::SubscriptionsTransportWS => Some(
+                serde_json::to_string(&ServerMessage::ConnectionError { payload: err }).unwrap(),
+            ),
+            WebSocketProtocols::GraphQLWS => None,
+        }
+    }
+
+    fn keep_alive(&self) -> String {
+        serde_json::to_string(&match self {
+            WebSocketProtocols::SubscriptionsTransportWS => ServerMessage::KeepAlive,
+            WebSocketProtocols::GraphQLWS => ServerMessage::Ping,
+        })
+        .unwrap()
+    }
+}
+
+impl FromStr for WebSocketProtocols {
+    type Err = Error;
+
+    fn from_str(protocol: &str) -> Result<Self> {
+        match protocol {
+            "graphql-ws" => Ok(WebSocketProtocols::SubscriptionsTransportWS),
+            "graphql-transport-ws" => Ok(WebSocketProtocols::GraphQLWS),
+            _ => Err(Error::new(format!(
+                "Unsupported Sec-WebSocket-Protocol: {}",
+                protocol
+            ))),
+        }
+    }
+}
+
 pin_project! {
     /// A GraphQL connection over websocket.
     ///
-    /// [Reference](https://github.com/apollographql/subscriptions-transport-ws/blob/master/PROTOCOL.md).
+    /// It speaks the legacy `graphql-ws` protocol unless another one is selected with
+    /// [`protocol`](#method.protocol), with the protocol in the `Sec-WebSocket-Protocol` header
+    /// of the handshake, e.g. with
+    /// [`WebSocketProtocols::from_sec_websocket_protocol`](enum.WebSocketProtocols.html#method.from_sec_websocket_protocol).
+    ///
+    /// The stream ends when the connection should be closed: the client terminated it, or, with
+    /// the `graphql-transport-ws` protocol, it sent an invalid message or its initialization
+    /// failed.
     pub struct WebSocket<S, F, Query, Mutation, Subscription> {
+        protocol: WebSocketProtocols,
         data_initializer: Option<F>,
         data: Arc<Data>,
         schema: Schema<Query, Mutation, Subscription>,
         streams: HashMap<String, Pin<Box<dyn Stream<Item = Response> >>>,
+        keep_alive: Option<Pin<Box<dyn Stream<Item = ()>>>>,
+        allowed_operations: Option<Vec<OperationType>>,
         #[pin]
         stream: S,
     }
@@ -32,10 +109,13 @@ impl<S, Query, Mutation, Subscription>
     #[must_use]
     pub fn new(schema: Schema<Query, Mutation, Subscription>, stream: S) -> Self {
         Self {
+            protocol: WebSocketProtocols::default(),
             data_initializer: None,
             data: Arc::default(),
             schema,
             streams: HashMap::new(),
+            keep_alive: None,
+            allowed_operations: None,
             stream,
         }
     }
@@ -54,15 +134,51 @@ impl<S, F, Query, Mutation, Subscription> WebSocket<S, F, Query, Mutation, Subsc
         data_initializer: Option<F>,
     ) -> Self {
         Self {
+            protocol: WebSocketProtocols::default(),
             data_initializer,
             data: Arc::default(),
             schema,
             streams: HashMap::new(),
+            keep_alive: None,
+            allowed_operations: None,
             stream,
         }
     }
 }
 
+impl<S, F, Query, Mutation, Subscription> WebSocket<S, F, Query, Mutation, Subscription> {
+    /// Speak the `protocol`, negotiated in the `Sec-WebSocket-Protocol` header.
+    #[must_use]
+    pub fn protocol(mut self, protocol: WebSocketProtocols) -> Self {
+        self.protocol = protocol;
+        self
+    }
+
+    /// Send a keep-alive message to the client every `period`, with the timer set with
+    /// [`SchemaBuilder::timer`](../struct.SchemaBuilder.html#method.timer).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the schema has no timer.
+    #[must_use]
+    pub fn keep_alive(mut self, period: Duration) -> Self {
+        let timer =
+            self.schema.env.timer.clone().expect(
+                "WebSocket::keep_alive requires a timer, set one with SchemaBuilder::timer",
+            );
+        self.keep_alive = Some(runtime::interval(timer, period));
+        self
+    }
+
+    /// Only allow the operations of these types, e.g. only subscriptions when queries and
+    /// mutations are served over HTTP. See `Request::allowed_operations`.
+    #[must_use]
+    pub fn allowed_operations(mut self, types: impl IntoIterator<Item = OperationType>) -> Self {
+        self.allowed_operations = Some(types.into_iter().collect());
+        self
+    }
+}
+
 impl<S, F, Query, Mutation, Subscription> Stream for WebSocket<S, F, Query, Mutation, Subscription>
 where
     S: Stream,
@@ -83,15 +199,16 @@ where
                 None => return Poll::Ready(None),
             };
 
-            let message: ClientMessage = match serde_json::from_slice(message.as_ref()) {
-                Ok(message) => message,
+            let message = match serde_json::from_slice::<ClientMessage>(message.as_ref()) {
+                Ok(message) if message.is_supported(*this.protocol) => message,
+                Ok(_) => {
+                    return Poll::Ready(this.protocol.connection_error(Error::new(format!(
+                        "Unsupported message for the {} protocol",
+                        this.protocol.sec_websocket_protocol()
+                    ))))
+                }
                 Err(e) => {
-                    return Poll::Ready(Some(
-                        serde_json::to_string(&ServerMessage::ConnectionError {
-                            payload: Error::new(e.to_string()),
-                        })
-                        .unwrap(),
-                    ))
+                    return Poll::Ready(this.protocol.connection_error(Error::new(e.to_string())))
                 }
             };
 
@@ -101,14 +218,7 @@ where
                         if let Some(data_initializer) = this.data_initializer.take() {
                             *this.data = Arc::new(match data_initializer(payload) {
                                 Ok(data) => data,
-                                Err(e) => {
-                                    return Poll::Ready(Some(
-                                        serde_json::to_string(&ServerMessage::ConnectionError {
-                                            payload: e,
-                                        })
-                                        .unwrap(),
-                                    ))
-                                }
+                                Err(e) => return Poll::Ready(this.protocol.connection_error(e)),
                             });
                         }
                     }
@@ -118,8 +228,15 @@ where
                 }
                 ClientMessage::Start {
                     id,
-                    payload: request,
+                    payload: mut request,
+                }
+                | ClientMessage::Subscribe {
+                    id,
+                    payload: mut request,
                 } => {
+                    if let Some(allowed_operations) = this.allowed_operations {
+                        request.allowed_operations = Some(allowed_operations.clone());
+                    }
                     this.streams.insert(
                         id,
                         Box::pin(
@@ -135,17 +252,39 @@ where
                         ));
                     }
                 }
+                ClientMessage::Complete { id } => {
+                    this.streams.remove(id);
+                }
+                ClientMessage::Ping { payload } => {
+                    return Poll::Ready(Some(
+                        serde_json::to_string(&ServerMessage::Pong { payload }).unwrap(),
+                    ));
+                }
+                ClientMessage::Pong => {}
                 ClientMessage::ConnectionTerminate => return Poll::Ready(None),
             }
         }
 
         for (id, stream) in &mut *this.streams {
             match Pin::new(stream).poll_next(cx) {
-                Poll::Ready(Some(payload)) => {
+                Poll::Ready(Some(payload)) if payload.keep_alive => {
+                    return Poll::Ready(Some(this.protocol.keep_alive()));
+                }
+                Poll::Ready(Some(mut payload)) => {
+                    let event_id = payload.event_id.take();
+                    let payload = Box::new(payload);
                     return Poll::Ready(Some(
-                        serde_json::to_string(&ServerMessage::Data {
-                            id,
-                            payload: Box::new(payload),
+                        serde_json::to_string(&match this.protocol {
+                            WebSocketProtocols::SubscriptionsTransportWS => ServerMessage::Data {
+                                id,
+                                event_id,
+                                payload,
+                            },
+                            WebSocketProtocols::GraphQLWS => ServerMessage::Next {
+                                id,
+                                event_id,
+                                payload,
+                            },
                         })
                         .unwrap(),
                     ));
@@ -161,6 +300,14 @@ where
             }
         }
 
+        if let Some(keep_alive) = this.keep_alive {
+            match keep_alive.as_mut().poll_next(cx) {
+                Poll::Ready(Some(())) => return Poll::Ready(Some(this.protocol.keep_alive())),
+                Poll::Ready(None) => *this.keep_alive = None,
+                Poll::Pending => {}
+            }
+        }
+
         Poll::Pending
     }
 }
@@ -170,23 +317,66 @@ where
 enum ClientMessage<'a> {
     ConnectionInit { payload: Option<serde_json::Value> },
     Start { id: String, payload: Request },
+    Subscribe { id: String, payload: Request },
     Stop { id: &'a str },
+    Complete { id: &'a str },
+    Ping { payload: Option<serde_json::Value> },
+    Pong,
     ConnectionTerminate,
 }
 
+impl ClientMessage<'_> {
+    fn is_supported(&self, protocol: WebSocketProtocols) -> bool {
+        match self {
+            ClientMessage::ConnectionInit { .. } => true,
+            ClientMessage::Start { .. }
+            | ClientMessage::Stop { .. }
+            | ClientMessage::ConnectionTerminate => {
+                protocol == WebSocketProtocols::SubscriptionsTransportWS
+            }
+            ClientMessage::Subscribe { .. }
+            | ClientMessage::Complete { .. }
+            | ClientMessage::Ping { .. }
+            | ClientMessage::Pong => protocol == WebSocketProtocols::GraphQLWS,
+        }
+    }
+}
+
 #[derive(Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 enum ServerMessage<'a> {
-    ConnectionError { payload: Error },
+    ConnectionError {
+        payload: Error,
+    },
     ConnectionAck,
-    Data { id: &'a str, payload: Box<Response> },
+    Data {
+        id: &'a str,
+        /// The id of the event, sent back by the client as the `lastEventId` of the payload of
+        /// a `start` message to resume the subscription.
+        #[serde(rename = "eventId", skip_serializing_if = "Option::is_none")]
+        event_id: Option<String>,
+        payload: Box<Response>,
+    },
+    /// The `data` message of the `graphql-transport-ws` protocol.
+    Next {
+        id: &'a str,
+        #[serde(rename = "eventId", skip_serializing_if = "Option::is_none")]
+        event_id: Option<String>,
+        payload: Box<Response>,
+    },
     // Not used by this library, as it's not necessary to send
     // Error {
     //     id: &'a str,
     //     payload: serde_json::Value,
     // },
-    Complete { id: &'a str },
-    // Not used by this library
-    // #[serde(rename = "ka")]
-    // KeepAlive
+    Complete {
+        id: &'a str,
+    },
+    #[serde(rename = "ka")]
+    KeepAlive,
+    Ping,
+    Pong {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        payload: Option<serde_json::Value>,
+    },
 }
@@ -0,0 +1,176 @@
+//! Idempotency keys for mutations
+//!
+//! A client that retries a mutation, for example after a timeout, can't tell whether the first
+//! attempt was executed. If it sends a unique key in the `idempotencyKey` extension of the
+//! request, and the schema has an [`IdempotencyStore`](trait.IdempotencyStore.html) set with
+//! `SchemaBuilder::idempotency_store`, a mutation sent again with the same key returns the
+//! response of the first execution instead of running the resolvers again.
+//!
+//! A request reusing a key with another query, operation name or variables is rejected. The keys
+//! are shared by all the clients, use `SchemaBuilder::idempotency_key_by` to scope them, e.g. to
+//! the current user.
+//!
+//! The key is ignored for queries. Two requests with the same key that run at the same time are
+//! both executed.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use async_graphql::*;
+//! use async_graphql::idempotency::MemoryIdempotencyStore;
+//! use async_graphql::runtime::Timer;
+//! use futures_util::future::{FutureExt, LocalBoxFuture};
+//! use std::cell::Cell;
+//! use std::time::{Duration, Instant};
+//!
+//! struct AsyncStdTimer(Instant);
+//!
+//! impl Timer for AsyncStdTimer {
+//!     fn sleep(&self, duration: Duration) -> LocalBoxFuture<'static, ()> {
+//!         async_std::task::sleep(duration).boxed_local()
+//!     }
+//!
+//!     fn now(&self) -> Duration {
+//!         self.0.elapsed()
+//!     }
+//! }
+//!
+//! struct Query;
+//!
+//! #[Object]
+//! impl Query {
+//!     async fn value(&self) -> i32 {
+//!         10
+//!     }
+//! }
+//!
+//! #[derive(Default)]
+//! struct Mutation(Cell<i32>);
+//!
+//! #[Object]
+//! impl Mutation {
+//!     async fn increment(&self) -> i32 {
+//!         self.0.set(self.0.get() + 1);
+//!         self.0.get()
+//!     }
+//! }
+//!
+//! let schema = Schema::build(Query, Mutation::default(), EmptySubscription)
+//!     .idempotency_store(
+//!         MemoryIdempotencyStore::new(AsyncStdTimer(Instant::now())),
+//!         Duration::from_secs(60),
+//!     )
+//!     .finish();
+//!
+//! async_std::task::block_on(async move {
+//!     let request = || {
+//!         let mut request = Request::new("mutation { increment }");
+//!         request.extensions.insert("idempotencyKey".to_string(), value!("a1b2"));
+//!         request
+//!     };
+//!     let data = schema.execute(request()).await.data;
+//!     assert_eq!(data, value!({ "increment": 1 }));
+//!     assert_eq!(schema.execute(request()).await.data, data);
+//!     assert_eq!(
+//!         schema.execute("mutation { increment }").await.data,
+//!         value!({ "increment": 2 })
+//!     );
+//! });
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use sha2::{Digest, Sha256};
+
+use crate::runtime::Timer;
+use crate::{Request, Response, Value};
+
+pub(crate) type IdempotencyKeyFn = dyn Fn(&Request) -> Option<String> + Send + Sync;
+
+/// The idempotency store of a schema, and how the keys are read from the requests.
+pub(crate) struct Idempotency {
+    pub(crate) store: Box<dyn IdempotencyStore>,
+    pub(crate) ttl: Duration,
+    pub(crate) key: Box<IdempotencyKeyFn>,
+}
+
+/// The key of a request by default: its `idempotencyKey` extension.
+pub(crate) fn default_key(request: &Request) -> Option<String> {
+    match request.extensions.get("idempotencyKey") {
+        Some(Value::String(key)) => Some(key.clone()),
+        _ => None,
+    }
+}
+
+/// The fingerprint of a request: the SHA-256 digest of its query, operation name and variables.
+pub(crate) fn fingerprint(request: &Request) -> String {
+    let request =
+        serde_json::to_vec(&(&request.query, &request.operation_name, &request.variables))
+            .unwrap_or_default();
+    format!("{:x}", Sha256::digest(&request))
+}
+
+/// The response of a mutation sent with an idempotency key.
+#[derive(Debug, Clone)]
+pub struct StoredResponse {
+    /// The fingerprint of the request, a request sent again with the same key but another
+    /// query, operation name or variables is rejected.
+    pub fingerprint: String,
+    /// The response.
+    pub response: Response,
+}
+
+/// Stores the responses of the mutations sent with an idempotency key.
+#[async_trait::async_trait(?Send)]
+pub trait IdempotencyStore: Send + Sync + 'static {
+    /// Load the response stored for `key`, `None` if there is none or it has expired.
+    async fn get(&self, key: &str) -> Option<StoredResponse>;
+
+    /// Store the response of the mutation sent with `key`, it must be returned by `get` for at
+    /// least `ttl`.
+    async fn set(&self, key: String, response: StoredResponse, ttl: Duration);
+}
+
+/// An idempotency store within a single process.
+///
+/// Cloning it returns a handle to the same store. The expired responses are removed when a new
+/// one is stored.
+#[derive(Clone)]
+pub struct MemoryIdempotencyStore {
+    timer: Arc<dyn Timer>,
+    responses: Arc<spin::Mutex<HashMap<String, (Duration, StoredResponse)>>>,
+}
+
+impl MemoryIdempotencyStore {
+    /// Creates an empty store, the responses expire according to the clock of `timer`, see the
+    /// [`runtime`](../runtime/index.html) module.
+    pub fn new(timer: impl Timer) -> Self {
+        Self {
+            timer: Arc::new(timer),
+            responses: Default::default(),
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl IdempotencyStore for MemoryIdempotencyStore {
+    async fn get(&self, key: &str) -> Option<StoredResponse> {
+        let now = self.timer.now();
+        self.responses
+            .lock()
+            .get(key)
+            .filter(|(expires_at, _)| *expires_at > now)
+            .map(|(_, response)| response.clone())
+    }
+
+    async fn set(&self, key: String, response: StoredResponse, ttl: Duration) {
+        let now = self.timer.now();
+        // A `ttl` too long to be added to the clock never expires.
+        let expires_at = now.checked_add(ttl).unwrap_or(Duration::MAX);
+        let mut responses = self.responses.lock();
+        responses.retain(|_, (expires_at, _)| *expires_at > now);
+        responses.insert(key, (expires_at, response));
+    }
+}
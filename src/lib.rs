@@ -64,11 +64,50 @@
 //! - `tracing`: Enable the [tracing extension](extensions/struct.Tracing.html).
 //! - `multipart`: Support [sending files over HTTP multipart](http/fn.receive_body.html).
 //! - `unblock`: Support [asynchronous reader for Upload](types/struct.Upload.html)
+//! - `dataloader_adapters`: [Loaders](dataloader/index.html) by primary key and by foreign key, from a function running the batch query.
+//! - `tokio`, `async-std`, `smol`, `wasm`: Implement the [timer and spawner](runtime/index.html) of the runtime of the same name.
+//! - `redis`: Share [persisted queries](extensions/apollo_persisted_queries/struct.RedisCacheStorage.html) and [subscription events](broker/struct.RedisBroker.html) through Redis, with the [`deadpool-redis` crate](https://crates.io/crates/deadpool-redis), on a tokio runtime.
+//! - `nats`: Fan out subscription events through NATS with the [`NatsBroker`](broker/struct.NatsBroker.html), using the [`async-nats` crate](https://crates.io/crates/async-nats) on a tokio runtime.
+//! - `kafka`: Expose Kafka topics as subscriptions with the [`KafkaEventSource`](broker/struct.KafkaEventSource.html), using the [`rdkafka` crate](https://crates.io/crates/rdkafka).
 //! - `bson`: Integrate with the [`bson` crate](https://crates.io/crates/bson).
-//! - `chrono`: Integrate with the [`chrono` crate](https://crates.io/crates/chrono).
+//! - `chrono`: Integrate with the [`chrono` crate](https://crates.io/crates/chrono), including durations and [intervals](types/struct.Interval.html).
 //! - `chrono-tz`: Integrate with the [`chrono-tz` crate](https://crates.io/crates/chrono-tz).
 //! - `url`: Integrate with the [`url` crate](https://crates.io/crates/url).
 //! - `uuid`: Integrate with the [`uuid` crate](https://crates.io/crates/uuid).
+//! - `isocountry`: Implement the `CountryCode` scalar for the ISO 3166-1 country codes of the [`isocountry` crate](https://crates.io/crates/isocountry).
+//! - `language-tags`: Implement the `LanguageTag` scalar for the BCP 47 language tags of the [`language-tags` crate](https://crates.io/crates/language-tags).
+//! - `email_address`: Implement the `EmailAddress` scalar for the email addresses of the [`email_address` crate](https://crates.io/crates/email_address).
+//! - `phonenumber`: Implement the `PhoneNumber` scalar for the phone numbers of the [`phonenumber` crate](https://crates.io/crates/phonenumber), output in E.164 format.
+//! - `currency`: Add the [`CurrencyCode`](types/struct.CurrencyCode.html) scalar for the ISO 4217 currency codes.
+//! - `duration`: Implement the `Duration` scalar for `std::time::Duration`, as ISO 8601 durations.
+//! - `geo`: Integrate with the [`geo-types` crate](https://crates.io/crates/geo-types), the geometries are [GeoJSON](types/trait.GeoJsonGeometry.html) scalars or [objects](types/struct.GeoObject.html).
+//! - `graphql_parser`: Convert query documents to and from the [`graphql-parser` crate](https://crates.io/crates/graphql-parser).
+//! - `client_controlled_nullability`: Experimental support for [client controlled nullability](https://github.com/graphql/graphql-spec/pull/895), such as `user(id: 1)! { name? }`. Not activated by default, fields can't have nullability designators without it.
+//! - `fragment_arguments`: Experimental support for [fragment arguments](https://github.com/graphql/graphql-spec/pull/1081), such as `...avatar(size: 64)`. Not activated by default, fragments can't define variables without it.
+//!
+//! ## No `Send` requirement
+//!
+//! Unlike upstream `async-graphql`, resolvers, their futures and streams, guards, validators,
+//! extensions and context data don't need to be `Send` or `Sync`, so types such as `Rc` and
+//! `RefCell` can be used directly with a single-threaded executor. This is always the case, not a
+//! feature flag. Traits that are implemented with `async_trait` must use
+//! `#[async_trait::async_trait(?Send)]`.
+//!
+//! ## Default rename rules
+//!
+//! The fields, arguments and enum items are renamed with the `camelCase`, `camelCase` and
+//! `SCREAMING_SNAKE_CASE` rules, unless a type selects another rule with `rename_fields`,
+//! `rename_args` or `rename_items`. A crate can change these defaults in its `Cargo.toml`, for
+//! example to keep acronyms uppercase in all the names (`user_id` becomes `userID`):
+//!
+//! ```toml
+//! [package.metadata.async-graphql]
+//! rename_fields = "camelCase_acronyms"
+//! rename_args = "camelCase_acronyms"
+//! rename_items = "SCREAMING_SNAKE_CASE"
+//! ```
+//!
+//! The defaults apply to the types derived in that crate only.
 //!
 //! ## Integrations
 //!
@@ -159,19 +198,31 @@
 
 mod base;
 mod error;
+#[cfg(feature = "fragment_arguments")]
+mod fragment_arguments;
 mod look_ahead;
 mod model;
 mod request;
 mod response;
 mod schema;
+mod shared_subscription;
 mod subscription;
+mod subscription_ext;
 mod validation;
 
+pub mod broker;
 pub mod context;
+pub mod dataloader;
 pub mod extensions;
 pub mod guard;
+pub mod health;
 pub mod http;
+pub mod idempotency;
+pub mod lint;
 pub mod resolver_utils;
+pub mod runtime;
+pub mod schema_registry;
+pub mod trusted_documents;
 pub mod types;
 pub mod validators;
 
@@ -184,6 +235,9 @@ pub use async_stream;
 pub use async_trait;
 #[doc(hidden)]
 pub use context::ContextSelectionSet;
+#[cfg(feature = "either")]
+#[doc(hidden)]
+pub use either;
 #[doc(hidden)]
 pub use futures_util;
 #[doc(hidden)]
@@ -195,8 +249,8 @@ pub use subscription::SubscriptionType;
 
 pub use async_graphql_parser as parser;
 pub use async_graphql_value::{
-    from_value, to_value, value, ConstValue as Value, DeserializerError, Name, Number,
-    SerializerError,
+    from_value, to_value, value, ConstValue as Value, DeserializerError, Map, Name, Number,
+    RawValue, SerializerError, ValueDeserializer, ValueSerializer,
 };
 pub use base::{
     Description, InputObjectType, InputValueType, InterfaceType, ObjectType, OutputValueType, Type,
@@ -204,16 +258,21 @@ pub use base::{
 };
 pub use error::{
     Error, ErrorExtensionValues, ErrorExtensions, InputValueError, InputValueResult,
-    ParseRequestError, PathSegment, Result, ResultExt, ServerError, ServerResult,
+    ParseRequestError, PathSegment, Result, ResultExt, ServerError, ServerResult, VariablesError,
 };
 pub use look_ahead::Lookahead;
-pub use registry::CacheControl;
+pub use registry::{
+    CacheControl, DirectiveView, EnumValueView, FieldView, InputValueView, IntrospectedInputField,
+    IntrospectedInputType, MetaTypeName, ParseSchemaCoordinateError, SchemaCoordinate,
+    SchemaMember, SchemaView, TagFilter, TypeKind, TypeView,
+};
 pub use request::{BatchRequest, Request};
 #[doc(no_inline)]
 pub use resolver_utils::{ContainerType, EnumType, ScalarType};
-pub use response::{BatchResponse, Response};
+pub use response::{BatchResponse, IncrementalResponse, Response};
 pub use schema::{Schema, SchemaBuilder, SchemaEnv};
-pub use validation::ValidationMode;
+pub use subscription_ext::SubscriptionStreamExt;
+pub use validation::{QueryAnalysis, SpecVersion, ValidationMode};
 
 pub use context::*;
 #[doc(no_inline)]
@@ -239,11 +298,13 @@ pub type FieldResult<T> = Result<T>;
 /// | Attribute     | description               | Type     | Optional |
 /// |---------------|---------------------------|----------|----------|
 /// | name          | Object name               | string   | Y        |
-/// | rename_fields | Rename all the fields according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
-/// | rename_args   | Rename all the arguments according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
+/// | rename_fields | Rename all the fields according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "camelCase_acronyms", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
+/// | rename_args   | Rename all the arguments according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "camelCase_acronyms", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
 /// | cache_control | Object cache control      | [`CacheControl`](struct.CacheControl.html) | Y        |
 /// | extends       | Add fields to an entity that's defined in another service | bool | Y |
 /// | use_type_description | Specifies that the description of the type is on the type declaration. [`Description`]()(derive.Description.html) | bool | Y |
+/// | meta          | Attach a key/value pair to the type, can be repeated: `meta(key = "k", value = "v")`. The pairs are returned by the `__metadata` root field, see [`SchemaBuilder::enable_metadata_introspection`](struct.SchemaBuilder.html#method.enable_metadata_introspection) | MetaEntry | Y |
+/// | guard         | Guard checked before resolving any field of this type | [`Guard`](guard/trait.Guard.html) | Y        |
 ///
 /// # Field parameters
 ///
@@ -257,7 +318,11 @@ pub type FieldResult<T> = Result<T>;
 /// | external      | Mark a field as owned by another service. This allows service A to use fields from service B while also knowing at runtime the types of that field. | bool | Y |
 /// | provides      | Annotate the expected returned fieldset from a field on a base type that is guaranteed to be selectable by the gateway. | string | Y |
 /// | requires      | Annotate the required input fieldset from a base type for a resolver. It is used to develop a query plan where the required fields may not be needed by the client, but the service may need additional information from other services. | string | Y |
-/// | guard         | Field of guard            | [`Guard`](guard/trait.Guard.html) | Y        |
+/// | guard         | Field of guard, `guard(...)` or an expression that can use the arguments by reference: `guard = "RoleGuard::new(Role::Admin).or(OwnerGuard::new(id))"` | [`Guard`](guard/trait.Guard.html) | Y        |
+/// | list_concurrency | The maximum number of items of the returned list that are resolved concurrently, overrides [`SchemaBuilder::list_concurrency`](struct.SchemaBuilder.html#method.list_concurrency) | usize | Y |
+/// | retry         | Retry the resolver when it fails: `retry(attempts = 3, backoff = "exponential", delay = 100, predicate = "is_transient")`. `backoff` is "constant" or "exponential", `delay` is the delay before the second attempt in milliseconds and `predicate` is a `fn(&Error) -> bool` selecting the errors to retry. The arguments must implement `Clone`. A delay other than 0 requires a [timer](struct.SchemaBuilder.html#method.timer). See [`RetryPolicy`](resolver_utils/struct.RetryPolicy.html) | RetryPolicy | Y |
+/// | fallback      | Expression of the value to return when the resolver fails, the error is then reported in the `warnings` extension of the response instead of the errors | string | Y |
+/// | meta          | Attach a key/value pair to the field, can be repeated: `meta(key = "k", value = "v")` | MetaEntry | Y |
 ///
 /// # Field argument parameters
 ///
@@ -268,9 +333,31 @@ pub type FieldResult<T> = Result<T>;
 /// | default      | Use `Default::default` for default value | none        | Y        |
 /// | default      | Argument default value                   | literal     | Y        |
 /// | default_with | Expression to generate default value     | code string | Y        |
+/// | default_with_ctx | Expression to generate default value, which can use `ctx: &Context<'_>`. It is evaluated when the argument is missing or null, and its value is checked by `custom_validator`. The argument is nullable in the schema | code string | Y |
 /// | validator    | Input value validator                    | [`InputValueValidator`](validators/trait.InputValueValidator.html) | Y        |
+/// | custom_validator | Expression to generate a validator that is called with the context and the parsed value | [`CustomValidator`](validators/trait.CustomValidator.html) | Y |
+/// | secret       | Redact the value in the [`AuditLog`](extensions/struct.AuditLog.html) | bool        | Y        |
+/// | sibling      | Name of the resolver of a field of the same object whose result is passed instead of an argument. The resolver is called once per object even if several fields use it, and its only parameters can be other siblings. The type of the parameter is its result type, which must implement `Clone` | string | Y |
 /// | key          | Is entity key                            | bool        | Y        |
 ///
+/// `skip` and `entity` can't be used with other field parameters, `sibling` can't be used with
+/// other argument parameters and an argument can have only one of `default`, `default_with` and
+/// `default_with_ctx`. All the invalid parameters of the methods are reported at once.
+///
+/// ```compile_fail
+/// use async_graphql::*;
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     #[graphql(skip, name = "b")]
+///     async fn a(&self) -> i32 {
+///         10
+///     }
+/// }
+/// ```
+///
 /// # Valid field return types
 ///
 /// - Scalar values, such as `i32` and `bool`. `usize`, `isize`, `u128` and `i128` are not
@@ -352,14 +439,22 @@ pub use async_graphql_derive::Object;
 ///
 /// Similar to `Object`, but defined on a structure that automatically generates getters for all fields. For a list of valid field types, see [`Object`](attr.Object.html). All fields are converted to camelCase.
 ///
+/// The getters see through the wrapper of a field:
+///
+/// - `Arc<T>`, `Rc<T>` and `Box<T>` fields are of type `T`.
+/// - Async `Mutex<T>` and `RwLock<T>` fields, e.g. of `async-std` or `tokio`, are of type `T`, the getter locks them and clones the value.
+/// - `OnceCell<T>` fields are of type `Option<T>`, null until the cell is set.
+///
 /// # Macro parameters
 ///
 /// | Attribute     | description               | Type     | Optional |
 /// |---------------|---------------------------|----------|----------|
 /// | name          | Object name               | string   | Y        |
-/// | rename_fields | Rename all the fields according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
+/// | rename_fields | Rename all the fields according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "camelCase_acronyms", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
 /// | cache_control | Object cache control      | [`CacheControl`](struct.CacheControl.html) | Y        |
 /// | extends       | Add fields to an entity that's defined in another service | bool | Y |
+/// | meta          | Attach a key/value pair to the type, can be repeated: `meta(key = "k", value = "v")` | MetaEntry | Y |
+/// | guard         | Guard checked before resolving any field of this type | [`Guard`](guard/trait.Guard.html) | Y        |
 ///
 /// # Field parameters
 ///
@@ -374,6 +469,8 @@ pub use async_graphql_derive::Object;
 /// | provides      | Annotate the expected returned fieldset from a field on a base type that is guaranteed to be selectable by the gateway. | string | Y |
 /// | requires      | Annotate the required input fieldset from a base type for a resolver. It is used to develop a query plan where the required fields may not be needed by the client, but the service may need additional information from other services. | string | Y |
 /// | guard         | Field of guard            | [`Guard`](guard/trait.Guard.html) | Y        |
+/// | list_concurrency | The maximum number of items of the returned list that are resolved concurrently, overrides [`SchemaBuilder::list_concurrency`](struct.SchemaBuilder.html#method.list_concurrency) | usize | Y |
+/// | meta          | Attach a key/value pair to the field, can be repeated: `meta(key = "k", value = "v")` | MetaEntry | Y |
 ///
 /// # Examples
 ///
@@ -404,15 +501,23 @@ pub use async_graphql_derive::SimpleObject;
 /// | Attribute    | description               | Type     | Optional |
 /// |--------------|---------------------------|----------|----------|
 /// | name         | Enum name                 | string   | Y        |
-/// | rename_items | Rename all the fields according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
+/// | rename_items | Rename all the fields according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "camelCase_acronyms", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
 /// | remote       | Derive a remote enum      | string   | Y        |
+/// | meta          | Attach a key/value pair to the type, can be repeated: `meta(key = "k", value = "v")` | MetaEntry | Y |
 ///
 /// # Item parameters
 ///
 /// | Attribute   | description               | Type     | Optional |
 /// |-------------|---------------------------|----------|----------|
 /// | name        | Item name                 | string   | Y        |
+/// | value       | Value of the item on the wire, the same as `name` but it can't be used together with it | string | Y |
+/// | alias       | Another value accepted for the item in inputs, e.g. its name before it was renamed, can be repeated. Aliases are not included in the schema and the item is always output as its name | string | Y |
 /// | deprecation | Item deprecation reason   | string   | Y        |
+/// | fallback    | Use this item for values that are not in the enum, it must be a unit variant or hold the raw value in a single field. It is not included in the schema. | bool | Y |
+/// | meta        | Attach a key/value pair to the item, can be repeated: `meta(key = "k", value = "v")` | MetaEntry | Y |
+///
+/// An enum with a fallback item does not implement `EnumType`. Resolving a fallback item which
+/// holds the raw value outputs that value, resolving a unit fallback item is an error.
 ///
 /// # Examples
 ///
@@ -460,7 +565,9 @@ pub use async_graphql_derive::Enum;
 /// | Attribute     | description               | Type     | Optional |
 /// |---------------|---------------------------|----------|----------|
 /// | name          | Object name               | string   | Y        |
-/// | rename_fields | Rename all the fields according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
+/// | rename_fields | Rename all the fields according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "camelCase_acronyms", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
+/// | validator     | Function called with the parsed object to validate it as a whole, e.g. to compare fields: `fn(&Self) -> Result<(), InputValueError<Self>>` | code path | Y |
+/// | meta          | Attach a key/value pair to the type, can be repeated: `meta(key = "k", value = "v")` | MetaEntry | Y |
 ///
 /// # Field parameters
 ///
@@ -472,6 +579,8 @@ pub use async_graphql_derive::Enum;
 /// | default_with | Expression to generate default value     | code string | Y        |
 /// | validator    | Input value validator                    | [`InputValueValidator`](validators/trait.InputValueValidator.html) | Y        |
 /// | flatten      | Similar to serde (flatten)               | boolean     | Y        |
+/// | secret       | Redact the value in the [`AuditLog`](extensions/struct.AuditLog.html) | boolean     | Y        |
+/// | meta          | Attach a key/value pair to the field, can be repeated: `meta(key = "k", value = "v")` | MetaEntry | Y |
 ///
 /// # Examples
 ///
@@ -516,10 +625,12 @@ pub use async_graphql_derive::InputObject;
 /// | Attribute     | description               | Type     | Optional |
 /// |---------------|---------------------------|----------|----------|
 /// | name          | Object name               | string   | Y        |
-/// | rename_fields | Rename all the fields according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
-/// | rename_args   | Rename all the arguments according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
+/// | rename_fields | Rename all the fields according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "camelCase_acronyms", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
+/// | rename_args   | Rename all the arguments according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "camelCase_acronyms", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
 /// | field         | Fields of this Interface  | [InterfaceField] | N |
+/// | auto_fields   | Also add the fields that all the possible types have with the same type and arguments when the schema is built, they are resolved by the objects. The schema build panics if the possible types have a field with different types or arguments. `field` is optional with this flag | bool | Y |
 /// | extends       | Add fields to an entity that's defined in another service | bool | Y |
+/// | meta          | Attach a key/value pair to the type, can be repeated: `meta(key = "k", value = "v")` | MetaEntry | Y |
 ///
 /// # Field parameters
 ///
@@ -534,6 +645,8 @@ pub use async_graphql_derive::InputObject;
 /// | external    | Mark a field as owned by another service. This allows service A to use fields from service B while also knowing at runtime the types of that field. | bool | Y |
 /// | provides    | Annotate the expected returned fieldset from a field on a base type that is guaranteed to be selectable by the gateway. | string | Y |
 /// | requires    | Annotate the required input fieldset from a base type for a resolver. It is used to develop a query plan where the required fields may not be needed by the client, but the service may need additional information from other services. | string | Y |
+/// | meta          | Attach a key/value pair to the field, can be repeated: `meta(key = "k", value = "v")` | MetaEntry | Y |
+/// | object_resolver | Resolve the field with the resolvers of the objects instead of a method of the interface, see [Resolve fields by the objects](#resolve-fields-by-the-objects) | bool | Y |
 ///
 /// # Field argument parameters
 ///
@@ -639,6 +752,64 @@ pub use async_graphql_derive::InputObject;
 ///     }));
 /// });
 /// ```
+///
+/// # Resolve fields by the objects
+///
+/// By default the interface calls a method with the signature of the field on the object, so the
+/// objects may need a method that converts their data to the type of the interface field. With
+/// `object_resolver`, the field is resolved by the resolver of the object itself and no method
+/// is required. `type` is then only used for the schema, and the type and arguments of the field
+/// of each object are checked when the schema is built: the type must be the type of the
+/// interface field or a subtype of it, e.g. an object implementing an interface type, and the
+/// arguments must be the same, except for additional optional ones.
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// #[derive(SimpleObject)]
+/// struct Circle {
+///     name: String,
+///     radius: f64,
+/// }
+///
+/// struct Square {
+///     side: f64,
+/// }
+///
+/// #[Object]
+/// impl Square {
+///     async fn name(&self) -> &str {
+///         "square"
+///     }
+/// }
+///
+/// #[derive(Interface)]
+/// #[graphql(field(name = "name", type = "String", object_resolver))]
+/// enum Shape {
+///     Circle(Circle),
+///     Square(Square),
+/// }
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     async fn shapes(&self) -> Vec<Shape> {
+///         vec![
+///             Circle { name: "circle".to_string(), radius: 1.0 }.into(),
+///             Square { side: 2.0 }.into(),
+///         ]
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+///     let res = schema.execute("{ shapes { name } }").await.into_result().unwrap().data;
+///     assert_eq!(res, value!({
+///         "shapes": [{ "name": "circle" }, { "name": "square" }]
+///     }));
+/// });
+/// ```
 pub use async_graphql_derive::Interface;
 
 /// Define a GraphQL union
@@ -650,6 +821,9 @@ pub use async_graphql_derive::Interface;
 /// | Attribute   | description               | Type     | Optional |
 /// |-------------|---------------------------|----------|----------|
 /// | name        | Object name               | string   | Y        |
+/// | meta          | Attach a key/value pair to the type, can be repeated: `meta(key = "k", value = "v")` | MetaEntry | Y |
+/// | from_result | Implement `From<Result<A, B>>` for a union of two variants, `Ok` is converted to the first one and `Err` to the second one, e.g. for the "payload or error" result of a mutation | bool | Y |
+/// | from_either | Implement `From<Either<A, B>>` for a union of two variants, requires the `either` feature. This gives another name to the union of an `Either` | bool | Y |
 ///
 /// # Item parameters
 ///
@@ -726,9 +900,10 @@ pub use async_graphql_derive::Union;
 /// | Attribute     | description               | Type     | Optional |
 /// |---------------|---------------------------|----------|----------|
 /// | name          | Object name               | string   | Y        |
-/// | rename_fields | Rename all the fields according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
-/// | rename_args   | Rename all the arguments according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
+/// | rename_fields | Rename all the fields according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "camelCase_acronyms", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
+/// | rename_args   | Rename all the arguments according to the given case convention. The possible values are "lowercase", "UPPERCASE", "PascalCase", "camelCase", "camelCase_acronyms", "snake_case", "SCREAMING_SNAKE_CASE".| string   | Y        |
 /// | use_type_description | Specifies that the description of the type is on the type declaration. [`Description`]()(derive.Description.html) | bool | Y |
+/// | guard         | Guard checked before resolving any field of this type | [`Guard`](guard/trait.Guard.html) | Y        |
 ///
 /// # Field parameters
 ///
@@ -736,7 +911,7 @@ pub use async_graphql_derive::Union;
 /// |-------------|---------------------------|----------|----------|
 /// | name        | Field name                | string   | Y        |
 /// | deprecation | Field deprecation reason  | string   | Y        |
-/// | guard       | Field of guard            | [`Guard`](guard/trait.Guard.html) | Y        |
+/// | guard       | Field of guard, `guard(...)` or an expression that can use the arguments by reference: `guard = "RoleGuard::new(Role::Admin).or(OwnerGuard::new(id))"` | [`Guard`](guard/trait.Guard.html) | Y        |
 ///
 /// # Field argument parameters
 ///
@@ -747,7 +922,9 @@ pub use async_graphql_derive::Union;
 /// | default      | Use `Default::default` for default value | none        | Y        |
 /// | default      | Argument default value                   | literal     | Y        |
 /// | default_with | Expression to generate default value     | code string | Y        |
+/// | default_with_ctx | Expression to generate default value, which can use `ctx: &Context<'_>`. It is evaluated when the argument is missing or null, and its value is checked by `custom_validator`. The argument is nullable in the schema | code string | Y |
 /// | validator    | Input value validator                    | [`InputValueValidator`](validators/trait.InputValueValidator.html) | Y        |
+/// | custom_validator | Expression to generate a validator that is called with the context and the parsed value | [`CustomValidator`](validators/trait.CustomValidator.html) | Y |
 ///
 /// # Examples
 ///
@@ -774,6 +951,7 @@ pub use async_graphql_derive::Subscription;
 /// | Attribute   | description               | Type     | Optional |
 /// |-------------|---------------------------|----------|----------|
 /// | name        | Scalar name               | string   | Y        |
+/// | specified_by_url | URL of the specification of the scalar, see [`SpecVersion`](enum.SpecVersion.html) | string | Y |
 ///
 pub use async_graphql_derive::Scalar;
 
@@ -786,9 +964,16 @@ pub use async_graphql_derive::Scalar;
 /// | Attribute     | description               | Type     | Optional |
 /// |---------------|---------------------------|----------|----------|
 /// | name          | Object name               | string   | Y        |
-/// | cache_control | Object cache control      | [`CacheControl`](struct.CacheControl.html) | Y        |
+/// | cache_control | Object cache control, overrides the cache control merged from the parts | [`CacheControl`](struct.CacheControl.html) | Y        |
 /// | extends       | Add fields to an entity that's defined in another service | bool | Y |
 /// | use_type_description | Specifies that the description of the type is on the type declaration. [`Description`]()(derive.Description.html) | bool | Y |
+/// | guard         | Guard checked before resolving any field of the merged object, including the fields of its parts | [`Guard`](guard/trait.Guard.html) | Y        |
+///
+/// The federation keys of the parts are the keys of the merged object. The parts can't define
+/// fields with the same name, building a schema with such a merged object panics.
+///
+/// Any number of parts can be merged, and a part can be a type parameter of the merged object or
+/// be removed by a `cfg` attribute.
 ///
 /// # Examples
 ///
@@ -899,3 +1084,35 @@ pub use async_graphql_derive::MergedSubscription;
 /// });
 /// ```
 pub use async_graphql_derive::Description;
+
+/// Embed the `.graphql` files of a directory as [`TrustedDocuments`](trusted_documents/struct.TrustedDocuments.html).
+///
+/// The path is relative to the directory of the `Cargo.toml` of the crate. The documents are
+/// parsed at compile time, and named after their file. Files added to the directory are only
+/// picked up when the invoking crate is rebuilt.
+///
+/// ```ignore
+/// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+///     .trusted_documents(include_trusted_documents!("graphql/operations"))
+///     .finish();
+/// ```
+pub use async_graphql_derive::include_trusted_documents;
+
+/// Check the syntax of a query at compile time.
+///
+/// It expands to the string literal, and fails to compile if the query can't be parsed. The
+/// query can't be validated against the schema at compile time, check it in a test with
+/// [`Schema::validate_static`](struct.Schema.html#method.validate_static).
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// const QUERY: &str = validate_query!("{ value }");
+/// ```
+///
+/// ```compile_fail
+/// use async_graphql::*;
+///
+/// const QUERY: &str = validate_query!("{ value ");
+/// ```
+pub use async_graphql_derive::validate_query;
@@ -0,0 +1,61 @@
+use crate::lint::{is_builtin_field, is_builtin_type, LintContext, LintRule};
+use crate::{MetaTypeName, SchemaView};
+
+/// Fields returning a `Boolean` must start with one of the allowed prefixes, e.g. `isActive`.
+pub struct BooleanPrefix {
+    prefixes: Vec<String>,
+}
+
+impl Default for BooleanPrefix {
+    fn default() -> Self {
+        Self::new(&["is", "has", "can", "should", "was", "will", "did"])
+    }
+}
+
+impl BooleanPrefix {
+    /// Allow the specified prefixes instead of the default ones, which are `is`, `has`, `can`,
+    /// `should`, `was`, `will` and `did`.
+    pub fn new<T: AsRef<str>>(prefixes: &[T]) -> Self {
+        Self {
+            prefixes: prefixes
+                .iter()
+                .map(|prefix| prefix.as_ref().to_string())
+                .collect(),
+        }
+    }
+
+    fn has_prefix(&self, name: &str) -> bool {
+        self.prefixes.iter().any(|prefix| {
+            matches!(
+                name.strip_prefix(prefix.as_str()).and_then(|rest| rest.chars().next()),
+                Some(c) if c.is_ascii_uppercase()
+            )
+        })
+    }
+}
+
+impl LintRule for BooleanPrefix {
+    fn name(&self) -> &'static str {
+        "boolean-prefix"
+    }
+
+    fn check(&self, ctx: &mut LintContext, schema: SchemaView<'_>) {
+        for ty in schema.types_iter().filter(|ty| !is_builtin_type(ty.name())) {
+            for field in ty.fields().filter(|field| !is_builtin_field(field.name())) {
+                let is_boolean = matches!(
+                    MetaTypeName::create(field.type_ref()).unwrap_non_null(),
+                    MetaTypeName::Named("Boolean")
+                );
+                if is_boolean && !self.has_prefix(field.name()) {
+                    ctx.report(
+                        format!("{}.{}", ty.name(), field.name()),
+                        format!(
+                            "Boolean fields should start with one of: {}",
+                            self.prefixes.join(", ")
+                        ),
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::lint::{is_builtin_type, LintContext, LintRule};
+use crate::{SchemaView, TypeKind};
+
+/// Enums must have at least one value.
+pub struct EmptyEnums;
+
+impl LintRule for EmptyEnums {
+    fn name(&self) -> &'static str {
+        "empty-enums"
+    }
+
+    fn check(&self, ctx: &mut LintContext, schema: SchemaView<'_>) {
+        for ty in schema.types_iter() {
+            if ty.kind() == TypeKind::Enum
+                && !is_builtin_type(ty.name())
+                && ty.enum_values().next().is_none()
+            {
+                ctx.report(ty.name(), "The enum has no values");
+            }
+        }
+    }
+}
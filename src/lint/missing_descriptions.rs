@@ -0,0 +1,88 @@
+use crate::lint::{is_builtin_field, is_builtin_type, LintContext, LintRule};
+use crate::SchemaView;
+
+/// Types and fields must have a description.
+///
+/// Input fields, enum values and arguments are only checked when enabled.
+#[derive(Default)]
+pub struct MissingDescriptions {
+    input_fields: bool,
+    enum_values: bool,
+    arguments: bool,
+}
+
+impl MissingDescriptions {
+    /// Whether input fields must have a description, default is false.
+    pub fn input_fields(self, input_fields: bool) -> Self {
+        Self {
+            input_fields,
+            ..self
+        }
+    }
+
+    /// Whether enum values must have a description, default is false.
+    pub fn enum_values(self, enum_values: bool) -> Self {
+        Self {
+            enum_values,
+            ..self
+        }
+    }
+
+    /// Whether field arguments must have a description, default is false.
+    pub fn arguments(self, arguments: bool) -> Self {
+        Self { arguments, ..self }
+    }
+}
+
+impl LintRule for MissingDescriptions {
+    fn name(&self) -> &'static str {
+        "missing-descriptions"
+    }
+
+    fn check(&self, ctx: &mut LintContext, schema: SchemaView<'_>) {
+        for ty in schema.types_iter().filter(|ty| !is_builtin_type(ty.name())) {
+            if ty.description().is_none() {
+                ctx.report(ty.name(), "The type has no description");
+            }
+
+            for field in ty.fields().filter(|field| !is_builtin_field(field.name())) {
+                let coordinate = format!("{}.{}", ty.name(), field.name());
+                if field.description().is_none() {
+                    ctx.report(&coordinate, "The field has no description");
+                }
+                if self.arguments {
+                    for arg in field.args().filter(|arg| arg.description().is_none()) {
+                        ctx.report(
+                            format!("{}({}:)", coordinate, arg.name()),
+                            "The argument has no description",
+                        );
+                    }
+                }
+            }
+
+            if self.input_fields {
+                for field in ty
+                    .input_fields()
+                    .filter(|field| field.description().is_none())
+                {
+                    ctx.report(
+                        format!("{}.{}", ty.name(), field.name()),
+                        "The input field has no description",
+                    );
+                }
+            }
+
+            if self.enum_values {
+                for value in ty
+                    .enum_values()
+                    .filter(|value| value.description().is_none())
+                {
+                    ctx.report(
+                        format!("{}.{}", ty.name(), value.name()),
+                        "The enum value has no description",
+                    );
+                }
+            }
+        }
+    }
+}
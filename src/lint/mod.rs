@@ -0,0 +1,150 @@
+//! Schema linter
+//!
+//! A [`Linter`](struct.Linter.html) runs a set of rules over the types of a schema and returns
+//! the problems it found, e.g. to enforce an API style guide in a test.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use async_graphql::*;
+//! use async_graphql::lint::{BooleanPrefix, Linter};
+//!
+//! struct Query;
+//!
+//! /// The root of all queries.
+//! #[Object]
+//! impl Query {
+//!     /// Whether the service is up.
+//!     async fn healthy(&self) -> bool {
+//!         true
+//!     }
+//! }
+//!
+//! let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+//! let diagnostics = Linter::new()
+//!     .rule(BooleanPrefix::default())
+//!     .lint(schema.registry());
+//! assert_eq!(diagnostics.len(), 1);
+//! assert_eq!(diagnostics[0].rule, "boolean-prefix");
+//! assert_eq!(diagnostics[0].coordinate, "Query.healthy");
+//! ```
+
+mod boolean_prefix;
+mod empty_enums;
+mod missing_descriptions;
+mod naming_conventions;
+mod unused_types;
+
+use serde::Serialize;
+
+use crate::SchemaView;
+
+pub use boolean_prefix::BooleanPrefix;
+pub use empty_enums::EmptyEnums;
+pub use missing_descriptions::MissingDescriptions;
+pub use naming_conventions::NamingConventions;
+pub use unused_types::UnusedTypes;
+
+/// A problem found by a lint rule.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LintDiagnostic {
+    /// The name of the rule that reported the problem.
+    pub rule: &'static str,
+    /// The schema coordinate of the problem, e.g. `Query`, `Query.user` or `Query.user(id:)`.
+    pub coordinate: String,
+    /// The description of the problem.
+    pub message: String,
+}
+
+/// Collects the diagnostics of a lint rule.
+pub struct LintContext {
+    rule: &'static str,
+    diagnostics: Vec<LintDiagnostic>,
+}
+
+impl LintContext {
+    /// Report a problem at a schema coordinate.
+    pub fn report(&mut self, coordinate: impl Into<String>, message: impl Into<String>) {
+        self.diagnostics.push(LintDiagnostic {
+            rule: self.rule,
+            coordinate: coordinate.into(),
+            message: message.into(),
+        });
+    }
+}
+
+/// A lint rule.
+///
+/// You can write your own rules by implementing this trait.
+pub trait LintRule {
+    /// The name of the rule, in kebab case.
+    fn name(&self) -> &'static str;
+
+    /// Check the schema and report the problems found.
+    fn check(&self, ctx: &mut LintContext, schema: SchemaView<'_>);
+}
+
+/// Runs lint rules over a schema.
+///
+/// `Linter::default()` runs all the rules of this module with their default settings, while
+/// `Linter::new()` starts without any rules.
+pub struct Linter {
+    rules: Vec<Box<dyn LintRule>>,
+}
+
+impl Default for Linter {
+    fn default() -> Self {
+        Self::new()
+            .rule(NamingConventions)
+            .rule(MissingDescriptions::default())
+            .rule(EmptyEnums)
+            .rule(UnusedTypes)
+            .rule(BooleanPrefix::default())
+    }
+}
+
+impl Linter {
+    /// Create a linter without any rules.
+    pub fn new() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Add a rule, replacing the rule with the same name if there is one.
+    pub fn rule(mut self, rule: impl LintRule + 'static) -> Self {
+        self.rules.retain(|r| r.name() != rule.name());
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Remove the rule called `name`.
+    pub fn without(mut self, name: &str) -> Self {
+        self.rules.retain(|rule| rule.name() != name);
+        self
+    }
+
+    /// Run the rules over a schema, the diagnostics are grouped by rule.
+    pub fn lint(&self, schema: SchemaView<'_>) -> Vec<LintDiagnostic> {
+        let mut diagnostics = Vec::new();
+        for rule in &self.rules {
+            let mut ctx = LintContext {
+                rule: rule.name(),
+                diagnostics: Vec::new(),
+            };
+            rule.check(&mut ctx, schema);
+            diagnostics.extend(ctx.diagnostics);
+        }
+        diagnostics
+    }
+}
+
+/// Whether a type is defined by GraphQL or by this crate rather than by the user: the built-in
+/// scalars, and the introspection and federation types whose names start with `_`.
+fn is_builtin_type(name: &str) -> bool {
+    name.starts_with('_') || matches!(name, "Int" | "Float" | "String" | "Boolean" | "ID")
+}
+
+/// Whether a field is defined by this crate, i.e. the introspection and federation fields of
+/// the query root.
+fn is_builtin_field(name: &str) -> bool {
+    name.starts_with('_')
+}
@@ -0,0 +1,68 @@
+use crate::lint::{is_builtin_field, is_builtin_type, LintContext, LintRule};
+use crate::SchemaView;
+
+/// Types must be `PascalCase`, fields and arguments `camelCase` and enum values
+/// `SCREAMING_SNAKE_CASE`.
+pub struct NamingConventions;
+
+impl LintRule for NamingConventions {
+    fn name(&self) -> &'static str {
+        "naming-conventions"
+    }
+
+    fn check(&self, ctx: &mut LintContext, schema: SchemaView<'_>) {
+        for ty in schema.types_iter().filter(|ty| !is_builtin_type(ty.name())) {
+            if !is_pascal_case(ty.name()) {
+                ctx.report(ty.name(), "Type names should be PascalCase");
+            }
+
+            for field in ty.fields().filter(|field| !is_builtin_field(field.name())) {
+                let coordinate = format!("{}.{}", ty.name(), field.name());
+                if !is_camel_case(field.name()) {
+                    ctx.report(&coordinate, "Field names should be camelCase");
+                }
+                for arg in field.args() {
+                    if !is_camel_case(arg.name()) {
+                        ctx.report(
+                            format!("{}({}:)", coordinate, arg.name()),
+                            "Argument names should be camelCase",
+                        );
+                    }
+                }
+            }
+
+            for field in ty.input_fields() {
+                if !is_camel_case(field.name()) {
+                    ctx.report(
+                        format!("{}.{}", ty.name(), field.name()),
+                        "Input field names should be camelCase",
+                    );
+                }
+            }
+
+            for value in ty.enum_values() {
+                if !is_screaming_snake_case(value.name()) {
+                    ctx.report(
+                        format!("{}.{}", ty.name(), value.name()),
+                        "Enum values should be SCREAMING_SNAKE_CASE",
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn is_pascal_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase()) && !name.contains('_')
+}
+
+fn is_camel_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase()) && !name.contains('_')
+}
+
+fn is_screaming_snake_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name
+            .chars()
+            .all(|c| c == '_' || c.is_ascii_uppercase() || c.is_ascii_digit())
+}
@@ -0,0 +1,46 @@
+use std::collections::HashSet;
+
+use crate::lint::{is_builtin_field, is_builtin_type, LintContext, LintRule};
+use crate::SchemaView;
+
+/// Every type must be reachable from the root types, through fields, arguments, input fields,
+/// possible types or implemented interfaces.
+pub struct UnusedTypes;
+
+impl LintRule for UnusedTypes {
+    fn name(&self) -> &'static str {
+        "unused-types"
+    }
+
+    fn check(&self, ctx: &mut LintContext, schema: SchemaView<'_>) {
+        let mut used = HashSet::new();
+        let mut pending: Vec<&str> = std::iter::once(schema.query_type())
+            .chain(schema.mutation_type())
+            .chain(schema.subscription_type())
+            .map(|ty| ty.name())
+            .collect();
+
+        while let Some(name) = pending.pop() {
+            if !used.insert(name) {
+                continue;
+            }
+            let ty = match schema.type_by_name(name) {
+                Some(ty) => ty,
+                None => continue,
+            };
+            for field in ty.fields().filter(|field| !is_builtin_field(field.name())) {
+                pending.push(field.type_name());
+                pending.extend(field.args().map(|arg| arg.type_name()));
+            }
+            pending.extend(ty.input_fields().map(|field| field.type_name()));
+            pending.extend(ty.possible_types());
+            pending.extend(ty.interfaces());
+        }
+
+        for ty in schema.types_iter() {
+            if !used.contains(ty.name()) && !is_builtin_type(ty.name()) {
+                ctx.report(ty.name(), "The type is not reachable from the root types");
+            }
+        }
+    }
+}
@@ -96,4 +96,8 @@ impl<'a> __Directive<'a> {
             })
             .collect()
     }
+
+    async fn is_repeatable(&self) -> bool {
+        self.directive.is_repeatable
+    }
 }
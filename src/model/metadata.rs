@@ -0,0 +1,28 @@
+use crate::{registry, Object};
+
+pub struct __MetadataEntry {
+    key: &'static str,
+    value: &'static str,
+}
+
+impl __MetadataEntry {
+    pub fn list(registry: &registry::Registry, coordinate: &str) -> Vec<__MetadataEntry> {
+        registry
+            .metadata(coordinate)
+            .iter()
+            .map(|(key, value)| __MetadataEntry { key, value })
+            .collect()
+    }
+}
+
+/// A key/value pair attached to a type, a field or an enum value with the `meta` attribute.
+#[Object(internal, name = "__MetadataEntry")]
+impl __MetadataEntry {
+    async fn key(&self) -> String {
+        self.key.to_string()
+    }
+
+    async fn value(&self) -> String {
+        self.value.to_string()
+    }
+}
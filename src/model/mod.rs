@@ -3,6 +3,7 @@ mod enum_value;
 mod field;
 mod input_value;
 mod kind;
+mod metadata;
 mod schema;
 mod r#type;
 
@@ -11,5 +12,6 @@ pub use enum_value::__EnumValue;
 pub use field::__Field;
 pub use input_value::__InputValue;
 pub use kind::__TypeKind;
+pub use metadata::__MetadataEntry;
 pub use r#type::__Type;
 pub use schema::__Schema;
@@ -1,5 +1,5 @@
 use crate::model::{__EnumValue, __Field, __InputValue, __TypeKind};
-use crate::{registry, Object};
+use crate::{registry, Context, Object, SpecVersion};
 
 enum TypeDetail<'a> {
     Named(&'a registry::MetaType),
@@ -36,6 +36,16 @@ impl<'a> __Type<'a> {
             },
         }
     }
+
+    fn implements(&self, name: &str) -> Vec<__Type<'a>> {
+        self.registry
+            .implements
+            .get(name)
+            .unwrap_or(&Default::default())
+            .iter()
+            .map(|ty| __Type::new(self.registry, ty))
+            .collect()
+    }
 }
 
 /// The fundamental unit of any GraphQL Schema is the type. There are many kinds of types in GraphQL as represented by the `__TypeKind` enum.
@@ -68,26 +78,7 @@ impl<'a> __Type<'a> {
 
     async fn description(&self) -> Option<String> {
         match &self.detail {
-            TypeDetail::Named(ty) => match ty {
-                registry::MetaType::Scalar { description, .. } => {
-                    description.map(ToString::to_string)
-                }
-                registry::MetaType::Object { description, .. } => {
-                    description.map(ToString::to_string)
-                }
-                registry::MetaType::Interface { description, .. } => {
-                    description.map(ToString::to_string)
-                }
-                registry::MetaType::Union { description, .. } => {
-                    description.map(ToString::to_string)
-                }
-                registry::MetaType::Enum { description, .. } => {
-                    description.map(ToString::to_string)
-                }
-                registry::MetaType::InputObject { description, .. } => {
-                    description.map(ToString::to_string)
-                }
-            },
+            TypeDetail::Named(ty) => ty.description().map(ToString::to_string),
             TypeDetail::NonNull(_) => None,
             TypeDetail::List(_) => None,
         }
@@ -116,19 +107,17 @@ impl<'a> __Type<'a> {
         }
     }
 
-    async fn interfaces(&self) -> Option<Vec<__Type<'a>>> {
-        if let TypeDetail::Named(registry::MetaType::Object { name, .. }) = &self.detail {
-            Some(
-                self.registry
-                    .implements
-                    .get(name)
-                    .unwrap_or(&Default::default())
-                    .iter()
-                    .map(|ty| __Type::new(self.registry, ty))
-                    .collect(),
-            )
-        } else {
-            None
+    async fn interfaces(&self, ctx: &Context<'_>) -> Option<Vec<__Type<'a>>> {
+        match &self.detail {
+            TypeDetail::Named(registry::MetaType::Object { name, .. }) => {
+                Some(self.implements(name))
+            }
+            TypeDetail::Named(registry::MetaType::Interface { name, .. })
+                if ctx.schema_env.spec_version == SpecVersion::October2021 =>
+            {
+                Some(self.implements(name))
+            }
+            _ => None,
         }
     }
 
@@ -194,6 +183,16 @@ impl<'a> __Type<'a> {
         }
     }
 
+    #[graphql(name = "specifiedByURL")]
+    async fn specified_by_url(&self) -> Option<&'static str> {
+        match &self.detail {
+            TypeDetail::Named(registry::MetaType::Scalar {
+                specified_by_url, ..
+            }) => *specified_by_url,
+            _ => None,
+        }
+    }
+
     async fn of_type(&self) -> Option<__Type<'a>> {
         if let TypeDetail::List(ty) = &self.detail {
             Some(__Type::new(self.registry, &ty))
@@ -0,0 +1,291 @@
+use std::collections::HashSet;
+
+use crate::registry::{MetaType, MetaTypeName, Registry};
+
+/// The metadata key used to tag types and fields.
+const TAG_KEY: &str = "tag";
+
+/// Scalars that are always part of a schema.
+const BUILTIN_SCALARS: &[&str] = &["Boolean", "Int", "Float", "String", "ID"];
+
+/// Selects the types and fields that are part of a schema contract.
+///
+/// Tags are attached with the `tag` metadata key, e.g.
+/// `#[graphql(meta(key = "tag", value = "internal"))]`.
+///
+/// - Types, fields, input fields and enum values with an excluded tag are hidden. An input type
+///   with an excluded required field is hidden, with the fields that use it.
+/// - If any tags are included, only the object and interface fields that have an included tag,
+///   or whose type has an included tag, are kept.
+///
+/// See [`Schema::build_contract`](struct.Schema.html#method.build_contract).
+#[derive(Debug, Default, Clone)]
+pub struct TagFilter {
+    include: HashSet<String>,
+    exclude: HashSet<String>,
+}
+
+impl TagFilter {
+    /// Create a filter that keeps everything.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Only keep the fields tagged with `tag`, or that belong to a type tagged with `tag`.
+    #[must_use]
+    pub fn include(mut self, tag: impl Into<String>) -> Self {
+        self.include.insert(tag.into());
+        self
+    }
+
+    /// Hide everything tagged with `tag`.
+    #[must_use]
+    pub fn exclude(mut self, tag: impl Into<String>) -> Self {
+        self.exclude.insert(tag.into());
+        self
+    }
+
+    fn has_tag(registry: &Registry, coordinate: &str, tags: &HashSet<String>) -> bool {
+        registry
+            .metadata(coordinate)
+            .iter()
+            .any(|(key, value)| *key == TAG_KEY && tags.contains(*value))
+    }
+
+    fn is_excluded(&self, registry: &Registry, coordinate: &str) -> bool {
+        Self::has_tag(registry, coordinate, &self.exclude)
+    }
+
+    fn is_included(&self, registry: &Registry, type_name: &str, field_name: &str) -> bool {
+        self.include.is_empty()
+            || type_name.starts_with("__")
+            || field_name.starts_with('_')
+            || Self::has_tag(registry, type_name, &self.include)
+            || Self::has_tag(
+                registry,
+                &format!("{}.{}", type_name, field_name),
+                &self.include,
+            )
+    }
+}
+
+impl Registry {
+    /// Remove the types and fields that are not part of the contract described by `filter`.
+    pub(crate) fn apply_contract(&mut self, filter: &TagFilter) {
+        self.remove_tagged(filter);
+        while self.remove_dangling() {}
+        self.remove_unreachable();
+    }
+
+    fn remove_tagged(&mut self, filter: &TagFilter) {
+        let excluded_types: Vec<String> = self
+            .types
+            .keys()
+            .filter(|name| *name != &self.query_type && filter.is_excluded(self, name))
+            .cloned()
+            .collect();
+        for name in excluded_types {
+            self.types.remove(&name);
+        }
+
+        let mut types = std::mem::take(&mut self.types);
+        let mut removed_types = Vec::new();
+        for ty in types.values_mut() {
+            match ty {
+                MetaType::Object { name, fields, .. }
+                | MetaType::Interface { name, fields, .. } => {
+                    fields.retain(|field_name, _| {
+                        !filter.is_excluded(self, &format!("{}.{}", name, field_name))
+                            && filter.is_included(self, name, field_name)
+                    });
+                }
+                MetaType::InputObject {
+                    name, input_fields, ..
+                } => {
+                    // A required input field can't be hidden, so the whole input type goes.
+                    let mut required_excluded = false;
+                    input_fields.retain(|field_name, field| {
+                        let excluded =
+                            filter.is_excluded(self, &format!("{}.{}", name, field_name));
+                        required_excluded |= excluded
+                            && field.default_value.is_none()
+                            && MetaTypeName::create(&field.ty).is_non_null();
+                        !excluded
+                    });
+                    if required_excluded {
+                        removed_types.push(name.clone());
+                    }
+                }
+                MetaType::Enum {
+                    name, enum_values, ..
+                } => {
+                    enum_values.retain(|value_name, _| {
+                        !filter.is_excluded(self, &format!("{}.{}", name, value_name))
+                    });
+                }
+                MetaType::Scalar { .. } | MetaType::Union { .. } => {}
+            }
+        }
+        for name in removed_types {
+            types.remove(&name);
+        }
+        self.types = types;
+    }
+
+    /// Remove everything that refers to a type that no longer exists, returns `true` if anything
+    /// was removed.
+    fn remove_dangling(&mut self) -> bool {
+        let existing: HashSet<String> = self.types.keys().cloned().collect();
+        let exists = |ty: &str| existing.contains(MetaTypeName::concrete_typename(ty));
+        let mut changed = false;
+        let mut removed_types = Vec::new();
+
+        for ty in self.types.values_mut() {
+            match ty {
+                MetaType::Object { name, fields, .. }
+                | MetaType::Interface { name, fields, .. } => {
+                    let count = fields.len();
+                    fields.retain(|_, field| {
+                        exists(&field.ty) && field.args.values().all(|arg| exists(&arg.ty))
+                    });
+                    changed |= fields.len() != count;
+                    if fields.is_empty() {
+                        removed_types.push(name.clone());
+                    }
+                }
+                MetaType::InputObject {
+                    name, input_fields, ..
+                } => {
+                    // A required input field can't be hidden, so the whole input type goes.
+                    let required_missing = input_fields.values().any(|field| {
+                        !exists(&field.ty)
+                            && field.default_value.is_none()
+                            && MetaTypeName::create(&field.ty).is_non_null()
+                    });
+                    let count = input_fields.len();
+                    input_fields.retain(|_, field| exists(&field.ty));
+                    changed |= input_fields.len() != count;
+                    if required_missing || input_fields.is_empty() {
+                        removed_types.push(name.clone());
+                    }
+                }
+                MetaType::Union {
+                    name,
+                    possible_types,
+                    ..
+                } => {
+                    let count = possible_types.len();
+                    possible_types.retain(|ty| existing.contains(ty));
+                    changed |= possible_types.len() != count;
+                    if possible_types.is_empty() {
+                        removed_types.push(name.clone());
+                    }
+                }
+                MetaType::Enum {
+                    name, enum_values, ..
+                } => {
+                    if enum_values.is_empty() {
+                        removed_types.push(name.clone());
+                    }
+                }
+                MetaType::Scalar { .. } => {}
+            }
+
+            if let MetaType::Interface { possible_types, .. } = ty {
+                let count = possible_types.len();
+                possible_types.retain(|ty| existing.contains(ty));
+                changed |= possible_types.len() != count;
+            }
+        }
+
+        for name in removed_types {
+            if name != self.query_type {
+                self.types.remove(&name);
+                changed = true;
+            }
+        }
+
+        self.implements.retain(|name, _| existing.contains(name));
+        for interfaces in self.implements.values_mut() {
+            interfaces.retain(|name| existing.contains(name));
+        }
+
+        if let Some(mutation_type) = &self.mutation_type {
+            if !self.types.contains_key(mutation_type) {
+                self.mutation_type = None;
+            }
+        }
+        if let Some(subscription_type) = &self.subscription_type {
+            if !self.types.contains_key(subscription_type) {
+                self.subscription_type = None;
+            }
+        }
+
+        changed
+    }
+
+    /// Remove the types that can't be reached from the root types.
+    fn remove_unreachable(&mut self) {
+        let mut reachable = HashSet::new();
+        let mut pending: Vec<String> = std::iter::once(self.query_type.clone())
+            .chain(self.mutation_type.clone())
+            .chain(self.subscription_type.clone())
+            .chain(BUILTIN_SCALARS.iter().map(ToString::to_string))
+            .chain(
+                self.directives
+                    .values()
+                    .flat_map(|directive| directive.args.values())
+                    .map(|arg| MetaTypeName::concrete_typename(&arg.ty).to_string()),
+            )
+            .collect();
+
+        while let Some(name) = pending.pop() {
+            if !reachable.insert(name.clone()) {
+                continue;
+            }
+            let ty = match self.types.get(&name) {
+                Some(ty) => ty,
+                None => continue,
+            };
+
+            let mut refer = |ty: &str| {
+                let name = MetaTypeName::concrete_typename(ty);
+                if !reachable.contains(name) {
+                    pending.push(name.to_string());
+                }
+            };
+            match ty {
+                MetaType::Object { fields, .. } | MetaType::Interface { fields, .. } => {
+                    for field in fields.values() {
+                        refer(&field.ty);
+                        for arg in field.args.values() {
+                            refer(&arg.ty);
+                        }
+                    }
+                }
+                MetaType::InputObject { input_fields, .. } => {
+                    for field in input_fields.values() {
+                        refer(&field.ty);
+                    }
+                }
+                MetaType::Scalar { .. } | MetaType::Enum { .. } | MetaType::Union { .. } => {}
+            }
+            if let Some(possible_types) = ty.possible_types() {
+                for possible_type in possible_types {
+                    refer(possible_type);
+                }
+            }
+            if let Some(interfaces) = self.implements.get(&name) {
+                for interface in interfaces {
+                    refer(interface);
+                }
+            }
+        }
+
+        self.types.retain(|name, _| reachable.contains(name));
+        self.implements.retain(|name, _| reachable.contains(name));
+        for interfaces in self.implements.values_mut() {
+            interfaces.retain(|name| reachable.contains(name));
+        }
+    }
+}
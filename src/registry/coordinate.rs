@@ -0,0 +1,197 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use thiserror::Error;
+
+/// A [schema coordinate](https://github.com/graphql/graphql-spec/pull/794), the name of a member
+/// of a schema such as `User.email` or `Query.user(id:)`.
+///
+/// Coordinates are parsed with [`str::parse`](https://doc.rust-lang.org/std/primitive.str.html#method.parse)
+/// and looked up with
+/// [`SchemaView::lookup_coordinate`](struct.SchemaView.html#method.lookup_coordinate).
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::SchemaCoordinate;
+///
+/// let coordinate: SchemaCoordinate = "Query.user(id:)".parse().unwrap();
+/// assert_eq!(
+///     coordinate,
+///     SchemaCoordinate::Argument {
+///         type_name: "Query".to_string(),
+///         field: "user".to_string(),
+///         argument: "id".to_string(),
+///     }
+/// );
+/// assert_eq!(coordinate.to_string(), "Query.user(id:)");
+/// assert!("Query.user(id)".parse::<SchemaCoordinate>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SchemaCoordinate {
+    /// A named type, `Type`.
+    Type(String),
+    /// A field of an object or an interface, a field of an input object or a value of an enum,
+    /// `Type.member`.
+    Member {
+        /// The name of the type.
+        type_name: String,
+        /// The name of the member.
+        member: String,
+    },
+    /// An argument of a field, `Type.field(argument:)`.
+    Argument {
+        /// The name of the type.
+        type_name: String,
+        /// The name of the field.
+        field: String,
+        /// The name of the argument.
+        argument: String,
+    },
+    /// A directive, `@directive`.
+    Directive(String),
+    /// An argument of a directive, `@directive(argument:)`.
+    DirectiveArgument {
+        /// The name of the directive, without the `@`.
+        directive: String,
+        /// The name of the argument.
+        argument: String,
+    },
+}
+
+/// An error parsing a [`SchemaCoordinate`](enum.SchemaCoordinate.html).
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Invalid schema coordinate \"{0}\"")]
+pub struct ParseSchemaCoordinateError(String);
+
+fn is_name(s: &str) -> bool {
+    let mut chars = s.chars();
+    matches!(chars.next(), Some(c) if c == '_' || c.is_ascii_alphabetic())
+        && chars.all(|c| c == '_' || c.is_ascii_alphanumeric())
+}
+
+/// Split `name(argument:)` into the name and the argument.
+fn parse_argument(s: &str) -> Option<(&str, Option<&str>)> {
+    match s.find('(') {
+        Some(idx) => {
+            let argument = s[idx + 1..]
+                .strip_suffix(":)")
+                .filter(|argument| is_name(argument))?;
+            Some((&s[..idx], Some(argument)))
+        }
+        None => Some((s, None)),
+    }
+}
+
+impl FromStr for SchemaCoordinate {
+    type Err = ParseSchemaCoordinateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseSchemaCoordinateError(s.to_string());
+
+        if let Some(directive) = s.strip_prefix('@') {
+            let (name, argument) = parse_argument(directive).ok_or_else(err)?;
+            if !is_name(name) {
+                return Err(err());
+            }
+            return Ok(match argument {
+                Some(argument) => SchemaCoordinate::DirectiveArgument {
+                    directive: name.to_string(),
+                    argument: argument.to_string(),
+                },
+                None => SchemaCoordinate::Directive(name.to_string()),
+            });
+        }
+
+        let (type_name, member) = match s.find('.') {
+            Some(idx) => (&s[..idx], Some(&s[idx + 1..])),
+            None => (s, None),
+        };
+        if !is_name(type_name) {
+            return Err(err());
+        }
+        let member = match member {
+            Some(member) => member,
+            None => return Ok(SchemaCoordinate::Type(type_name.to_string())),
+        };
+
+        let (member, argument) = parse_argument(member).ok_or_else(err)?;
+        if !is_name(member) {
+            return Err(err());
+        }
+        Ok(match argument {
+            Some(argument) => SchemaCoordinate::Argument {
+                type_name: type_name.to_string(),
+                field: member.to_string(),
+                argument: argument.to_string(),
+            },
+            None => SchemaCoordinate::Member {
+                type_name: type_name.to_string(),
+                member: member.to_string(),
+            },
+        })
+    }
+}
+
+impl Display for SchemaCoordinate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaCoordinate::Type(name) => write!(f, "{}", name),
+            SchemaCoordinate::Member { type_name, member } => {
+                write!(f, "{}.{}", type_name, member)
+            }
+            SchemaCoordinate::Argument {
+                type_name,
+                field,
+                argument,
+            } => write!(f, "{}.{}({}:)", type_name, field, argument),
+            SchemaCoordinate::Directive(name) => write!(f, "@{}", name),
+            SchemaCoordinate::DirectiveArgument {
+                directive,
+                argument,
+            } => write!(f, "@{}({}:)", directive, argument),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_display() {
+        for s in &[
+            "User",
+            "User.email",
+            "Query.user(id:)",
+            "@deprecated",
+            "@deprecated(reason:)",
+            "_Any",
+            "__Type.fields(includeDeprecated:)",
+        ] {
+            let coordinate: SchemaCoordinate = s.parse().unwrap();
+            assert_eq!(coordinate.to_string(), *s);
+        }
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for s in &[
+            "",
+            "1User",
+            "User.",
+            ".email",
+            "User.email.domain",
+            "User(id:)",
+            "Query.user(id)",
+            "Query.user(:)",
+            "Query.user(id:).name",
+            "Query.user (id:)",
+            "@",
+            "@deprecated.reason",
+            "@@deprecated",
+        ] {
+            assert!(s.parse::<SchemaCoordinate>().is_err(), "{}", s);
+        }
+    }
+}
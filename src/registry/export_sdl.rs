@@ -6,6 +6,15 @@ impl Registry {
     pub fn export_sdl(&self, federation: bool) -> String {
         let mut sdl = String::new();
 
+        if federation && self.has_entity_interfaces() {
+            // Entity interfaces are a Federation 2.3 feature.
+            writeln!(
+                sdl,
+                r#"extend schema @link(url: "https://specs.apollo.dev/federation/v2.3", import: ["@key", "@external", "@requires", "@provides"])"#
+            )
+            .ok();
+        }
+
         for ty in self.types.values() {
             if ty.name().starts_with("__") {
                 continue;
@@ -88,7 +97,10 @@ impl Registry {
     fn export_type(&self, ty: &MetaType, sdl: &mut String, federation: bool) {
         match ty {
             MetaType::Scalar {
-                name, description, ..
+                name,
+                description,
+                specified_by_url,
+                ..
             } => {
                 const SYSTEM_SCALARS: &[&str] = &["Int", "Float", "String", "Boolean", "ID"];
                 const FEDERATION_SCALARS: &[&str] = &["Any"];
@@ -98,16 +110,19 @@ impl Registry {
                 }
                 if export_scalar {
                     if description.is_some() && !federation {
-                        writeln!(sdl, "\"\"\"\n{}\n\"\"\"", description.unwrap()).ok();
+                        writeln!(sdl, "\"\"\"\n{}\n\"\"\"", description.as_ref().unwrap()).ok();
+                    }
+                    write!(sdl, "scalar {}", name).ok();
+                    if let Some(url) = specified_by_url {
+                        write!(sdl, " @specifiedBy(url: \"{}\")", url).ok();
                     }
-                    writeln!(sdl, "scalar {}", name).ok();
+                    writeln!(sdl).ok();
                 }
             }
             MetaType::Object {
                 name,
                 fields,
                 extends,
-                keys,
                 description,
                 ..
             } => {
@@ -123,7 +138,7 @@ impl Registry {
                 }
 
                 if description.is_some() && !federation {
-                    writeln!(sdl, "\"\"\"\n{}\n\"\"\"", description.unwrap()).ok();
+                    writeln!(sdl, "\"\"\"\n{}\n\"\"\"", description.as_ref().unwrap()).ok();
                 }
                 if federation && *extends {
                     write!(sdl, "extend ").ok();
@@ -132,10 +147,8 @@ impl Registry {
                 self.write_implements(sdl, name);
 
                 if federation {
-                    if let Some(keys) = keys {
-                        for key in keys {
-                            write!(sdl, "@key(fields: \"{}\") ", key).ok();
-                        }
+                    for key in self.entity_keys(name) {
+                        write!(sdl, "@key(fields: \"{}\") ", key).ok();
                     }
                 }
 
@@ -147,25 +160,22 @@ impl Registry {
                 name,
                 fields,
                 extends,
-                keys,
                 description,
                 ..
             } => {
                 if description.is_some() && !federation {
-                    writeln!(sdl, "\"\"\"\n{}\n\"\"\"", description.unwrap()).ok();
+                    writeln!(sdl, "\"\"\"\n{}\n\"\"\"", description.as_ref().unwrap()).ok();
                 }
                 if federation && *extends {
                     write!(sdl, "extend ").ok();
                 }
                 write!(sdl, "interface {} ", name).ok();
+                self.write_implements(sdl, name);
                 if federation {
-                    if let Some(keys) = keys {
-                        for key in keys {
-                            write!(sdl, "@key(fields: \"{}\") ", key).ok();
-                        }
+                    for key in self.entity_keys(name) {
+                        write!(sdl, "@key(fields: \"{}\") ", key).ok();
                     }
                 }
-                self.write_implements(sdl, name);
 
                 writeln!(sdl, "{{").ok();
                 Self::export_fields(sdl, fields.values(), federation);
@@ -178,7 +188,7 @@ impl Registry {
                 ..
             } => {
                 if description.is_some() && !federation {
-                    writeln!(sdl, "\"\"\"\n{}\n\"\"\"", description.unwrap()).ok();
+                    writeln!(sdl, "\"\"\"\n{}\n\"\"\"", description.as_ref().unwrap()).ok();
                 }
                 write!(sdl, "enum {} ", name).ok();
                 writeln!(sdl, "{{").ok();
@@ -194,7 +204,7 @@ impl Registry {
                 ..
             } => {
                 if description.is_some() && !federation {
-                    writeln!(sdl, "\"\"\"\n{}\n\"\"\"", description.unwrap()).ok();
+                    writeln!(sdl, "\"\"\"\n{}\n\"\"\"", description.as_ref().unwrap()).ok();
                 }
                 write!(sdl, "input {} ", name).ok();
                 writeln!(sdl, "{{").ok();
@@ -213,7 +223,7 @@ impl Registry {
                 ..
             } => {
                 if description.is_some() && !federation {
-                    writeln!(sdl, "\"\"\"\n{}\n\"\"\"", description.unwrap()).ok();
+                    writeln!(sdl, "\"\"\"\n{}\n\"\"\"", description.as_ref().unwrap()).ok();
                 }
                 write!(sdl, "union {} =", name).ok();
                 for ty in possible_types {
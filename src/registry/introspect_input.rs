@@ -0,0 +1,134 @@
+use serde::Serialize;
+
+use crate::registry::{MetaInputValue, MetaType, MetaTypeName, Registry};
+use crate::validators::ValidatorDescription;
+
+/// The shape of an input type, returned by
+/// [`Schema::introspect_input_type`](struct.Schema.html#method.introspect_input_type).
+///
+/// Unlike the introspection query, this includes the validators of the input fields, so it can
+/// be used to generate forms.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum IntrospectedInputType {
+    /// A scalar, e.g. `Int`.
+    Scalar {
+        /// The type name.
+        name: String,
+    },
+    /// An enum and its values.
+    Enum {
+        /// The type name.
+        name: String,
+        /// The names of the values.
+        values: Vec<String>,
+    },
+    /// An input object and its fields.
+    InputObject {
+        /// The type name.
+        name: String,
+        /// The type description.
+        description: Option<String>,
+        /// The input fields, in the order they were defined.
+        fields: Vec<IntrospectedInputField>,
+    },
+    /// An input object that already appears higher up in the tree, its fields are left out to
+    /// avoid infinite recursion.
+    Recursive {
+        /// The type name.
+        name: String,
+    },
+}
+
+/// An input field of an [`IntrospectedInputType`](enum.IntrospectedInputType.html).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntrospectedInputField {
+    /// The field name.
+    pub name: String,
+    /// The field description.
+    pub description: Option<String>,
+    /// The type reference, e.g. `[Int!]!`.
+    pub type_ref: String,
+    /// Whether a value must be provided, i.e. the type is non-null and there is no default value.
+    pub required: bool,
+    /// The default value, in GraphQL syntax.
+    pub default_value: Option<String>,
+    /// The validator of the field, if it can be described.
+    pub validator: Option<ValidatorDescription>,
+    /// The named type of the field.
+    #[serde(rename = "type")]
+    pub ty: IntrospectedInputType,
+}
+
+impl Registry {
+    /// Describe the input type called `name`, including the types of its fields. Returns `None`
+    /// if there is no such input type.
+    pub fn introspect_input_type(&self, name: &str) -> Option<IntrospectedInputType> {
+        match self.types.get(name)? {
+            MetaType::Scalar { .. } | MetaType::Enum { .. } | MetaType::InputObject { .. } => {
+                Some(self.introspect_input_type_inner(name, &mut Vec::new()))
+            }
+            _ => None,
+        }
+    }
+
+    fn introspect_input_type_inner(
+        &self,
+        name: &str,
+        parents: &mut Vec<String>,
+    ) -> IntrospectedInputType {
+        match self.types.get(name) {
+            Some(MetaType::Enum {
+                name, enum_values, ..
+            }) => IntrospectedInputType::Enum {
+                name: name.clone(),
+                values: enum_values.keys().map(ToString::to_string).collect(),
+            },
+            Some(MetaType::InputObject {
+                name,
+                description,
+                input_fields,
+            }) => {
+                if parents.contains(name) {
+                    return IntrospectedInputType::Recursive { name: name.clone() };
+                }
+                parents.push(name.clone());
+                let fields = input_fields
+                    .values()
+                    .map(|field| self.introspect_input_field(field, parents))
+                    .collect();
+                parents.pop();
+                IntrospectedInputType::InputObject {
+                    name: name.clone(),
+                    description: description.clone(),
+                    fields,
+                }
+            }
+            _ => IntrospectedInputType::Scalar {
+                name: name.to_string(),
+            },
+        }
+    }
+
+    fn introspect_input_field(
+        &self,
+        field: &MetaInputValue,
+        parents: &mut Vec<String>,
+    ) -> IntrospectedInputField {
+        IntrospectedInputField {
+            name: field.name.to_string(),
+            description: field.description.map(ToString::to_string),
+            type_ref: field.ty.clone(),
+            required: field.default_value.is_none()
+                && MetaTypeName::create(&field.ty).is_non_null(),
+            default_value: field.default_value.clone(),
+            validator: field
+                .validator
+                .as_ref()
+                .and_then(|validator| validator.describe()),
+            ty: self
+                .introspect_input_type_inner(MetaTypeName::concrete_typename(&field.ty), parents),
+        }
+    }
+}
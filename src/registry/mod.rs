@@ -1,5 +1,9 @@
 mod cache_control;
+mod contract;
+mod coordinate;
 mod export_sdl;
+mod introspect_input;
+mod schema_view;
 
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
@@ -7,24 +11,33 @@ use std::sync::Arc;
 use indexmap::map::IndexMap;
 use indexmap::set::IndexSet;
 
+use crate::context::is_valid_name;
 use crate::parser::types::{BaseType as ParsedBaseType, Type as ParsedType};
 use crate::validators::InputValueValidator;
-use crate::{model, Any, Type, Value};
+use crate::{model, Any, SpecVersion, Type, Value};
 
 pub use cache_control::CacheControl;
+pub use contract::TagFilter;
+pub use coordinate::{ParseSchemaCoordinateError, SchemaCoordinate};
+pub use introspect_input::{IntrospectedInputField, IntrospectedInputType};
+pub use schema_view::{
+    DirectiveView, EnumValueView, FieldView, InputValueView, SchemaMember, SchemaView, TypeKind,
+    TypeView,
+};
 
 fn strip_brackets(type_name: &str) -> Option<&str> {
-    if let Some(rest) = type_name.strip_prefix('[') {
-        Some(&rest[..rest.len() - 1])
-    } else {
-        None
-    }
+    type_name.strip_prefix('[')?.strip_suffix(']')
 }
 
+/// The outermost layer of a qualified type name such as `[String!]!`, as used by the registry and
+/// by introspection.
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum MetaTypeName<'a> {
+    /// A list of the contained type, e.g. `[String!]` contains `String!`.
     List(&'a str),
+    /// The non-null variant of the contained type, e.g. `String!` contains `String`.
     NonNull(&'a str),
+    /// A named type, e.g. `String`.
     Named(&'a str),
 }
 
@@ -39,6 +52,10 @@ impl<'a> std::fmt::Display for MetaTypeName<'a> {
 }
 
 impl<'a> MetaTypeName<'a> {
+    /// Split off the outermost layer of a qualified type name.
+    ///
+    /// The name is expected to be in the canonical form returned by
+    /// [`normalize`](#method.normalize).
     pub fn create(type_name: &str) -> MetaTypeName {
         if let Some(type_name) = type_name.strip_suffix('!') {
             MetaTypeName::NonNull(type_name)
@@ -49,6 +66,43 @@ impl<'a> MetaTypeName<'a> {
         }
     }
 
+    /// Check the syntax of a qualified type name and return its canonical form, without any
+    /// whitespace or commas, e.g. `[ String! ]` becomes `[String!]`.
+    ///
+    /// Returns `None` if the name is not a valid GraphQL type, for example `[String` or `String!!`.
+    pub fn normalize(type_name: &str) -> Option<String> {
+        fn check(type_name: &str) -> bool {
+            match MetaTypeName::create(type_name) {
+                MetaTypeName::NonNull(ty) => !ty.ends_with('!') && check(ty),
+                MetaTypeName::List(ty) => check(ty),
+                MetaTypeName::Named(ty) => is_valid_name(ty),
+            }
+        }
+
+        let mut normalized = String::with_capacity(type_name.len());
+        let mut name_interrupted = false;
+        for c in type_name.chars() {
+            match c {
+                ' ' | '\t' | '\n' | '\r' | ',' | '\u{feff}' => {
+                    name_interrupted =
+                        normalized.ends_with(|c: char| c != '[' && c != ']' && c != '!');
+                }
+                '[' | ']' | '!' => {
+                    normalized.push(c);
+                    name_interrupted = false;
+                }
+                _ if name_interrupted => return None,
+                _ => normalized.push(c),
+            }
+        }
+        if check(&normalized) {
+            Some(normalized)
+        } else {
+            None
+        }
+    }
+
+    /// The named type at the core of a qualified type name, e.g. `String` for `[String!]!`.
     pub fn concrete_typename(type_name: &str) -> &str {
         match MetaTypeName::create(type_name) {
             MetaTypeName::List(type_name) => Self::concrete_typename(type_name),
@@ -57,10 +111,17 @@ impl<'a> MetaTypeName<'a> {
         }
     }
 
+    /// Whether this is a non-null type.
     pub fn is_non_null(&self) -> bool {
         matches!(self, MetaTypeName::NonNull(_))
     }
 
+    /// Whether this is a list type.
+    pub fn is_list(&self) -> bool {
+        matches!(self, MetaTypeName::List(_))
+    }
+
+    /// The nullable variant of this type.
     pub fn unwrap_non_null(&self) -> Self {
         match self {
             MetaTypeName::NonNull(ty) => MetaTypeName::create(ty),
@@ -68,6 +129,7 @@ impl<'a> MetaTypeName<'a> {
         }
     }
 
+    /// Whether a value of type `sub` can be used where this type is expected.
     pub fn is_subtype(&self, sub: &MetaTypeName<'_>) -> bool {
         match (self, sub) {
             (MetaTypeName::NonNull(super_type), MetaTypeName::NonNull(sub_type))
@@ -92,6 +154,7 @@ pub struct MetaInputValue {
     pub ty: String,
     pub default_value: Option<String>,
     pub validator: Option<Arc<dyn InputValueValidator>>,
+    pub is_secret: bool,
 }
 
 #[derive(Clone)]
@@ -107,22 +170,52 @@ pub struct MetaField {
     pub provides: Option<&'static str>,
 }
 
+impl MetaField {
+    /// Create a field called `name` of type `ty`, as returned by `Type::create_type_info`.
+    pub fn new(name: impl Into<String>, ty: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            args: Default::default(),
+            ty: ty.into(),
+            deprecation: None,
+            cache_control: Default::default(),
+            external: false,
+            requires: None,
+            provides: None,
+        }
+    }
+
+    /// Set the description of the field.
+    #[must_use]
+    pub fn description(self, description: &'static str) -> Self {
+        Self {
+            description: Some(description),
+            ..self
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct MetaEnumValue {
     pub name: &'static str,
     pub description: Option<&'static str>,
     pub deprecation: Option<&'static str>,
+    /// Other names accepted for the value in inputs, which are not exposed in the schema.
+    pub aliases: &'static [&'static str],
 }
 
+#[derive(Clone)]
 pub enum MetaType {
     Scalar {
         name: String,
-        description: Option<&'static str>,
+        description: Option<String>,
         is_valid: fn(value: &Value) -> bool,
+        specified_by_url: Option<&'static str>,
     },
     Object {
         name: String,
-        description: Option<&'static str>,
+        description: Option<String>,
         fields: IndexMap<String, MetaField>,
         cache_control: CacheControl,
         extends: bool,
@@ -130,7 +223,7 @@ pub enum MetaType {
     },
     Interface {
         name: String,
-        description: Option<&'static str>,
+        description: Option<String>,
         fields: IndexMap<String, MetaField>,
         possible_types: IndexSet<String>,
         extends: bool,
@@ -138,17 +231,18 @@ pub enum MetaType {
     },
     Union {
         name: String,
-        description: Option<&'static str>,
+        description: Option<String>,
         possible_types: IndexSet<String>,
     },
     Enum {
         name: String,
-        description: Option<&'static str>,
+        description: Option<String>,
         enum_values: IndexMap<&'static str, MetaEnumValue>,
+        has_fallback: bool,
     },
     InputObject {
         name: String,
-        description: Option<&'static str>,
+        description: Option<String>,
         input_fields: IndexMap<String, MetaInputValue>,
     },
 }
@@ -177,6 +271,17 @@ impl MetaType {
         }
     }
 
+    pub fn description(&self) -> Option<&str> {
+        match self {
+            MetaType::Scalar { description, .. } => description.as_deref(),
+            MetaType::Object { description, .. } => description.as_deref(),
+            MetaType::Interface { description, .. } => description.as_deref(),
+            MetaType::Union { description, .. } => description.as_deref(),
+            MetaType::Enum { description, .. } => description.as_deref(),
+            MetaType::InputObject { description, .. } => description.as_deref(),
+        }
+    }
+
     pub fn is_composite(&self) -> bool {
         match self {
             MetaType::Object { .. } => true,
@@ -228,6 +333,29 @@ impl MetaType {
         }
     }
 
+    /// Create an object type with the fields in the specified order.
+    ///
+    /// This is meant for implementing `Type::create_type_info` by hand, together with
+    /// [`resolve_field_value`](../resolver_utils/fn.resolve_field_value.html) for
+    /// `ContainerType::resolve_field`.
+    pub fn object(
+        name: impl Into<String>,
+        description: Option<String>,
+        fields: impl IntoIterator<Item = MetaField>,
+    ) -> Self {
+        MetaType::Object {
+            name: name.into(),
+            description,
+            fields: fields
+                .into_iter()
+                .map(|field| (field.name.clone(), field))
+                .collect(),
+            cache_control: Default::default(),
+            extends: false,
+            keys: None,
+        }
+    }
+
     pub fn type_overlap(&self, ty: &MetaType) -> bool {
         if self as *const MetaType == ty as *const MetaType {
             return true;
@@ -247,14 +375,16 @@ impl MetaType {
     }
 }
 
+#[derive(Clone)]
 pub struct MetaDirective {
     pub name: &'static str,
     pub description: Option<&'static str>,
     pub locations: Vec<model::__DirectiveLocation>,
     pub args: IndexMap<&'static str, MetaInputValue>,
+    pub is_repeatable: bool,
 }
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct Registry {
     pub types: IndexMap<String, MetaType>,
     pub directives: HashMap<String, MetaDirective>,
@@ -262,6 +392,10 @@ pub struct Registry {
     pub query_type: String,
     pub mutation_type: Option<String>,
     pub subscription_type: Option<String>,
+    pub inherited_descriptions: HashMap<String, String>,
+    pub metadata: HashMap<String, Vec<(&'static str, &'static str)>>,
+    pub object_resolved_fields: HashSet<(String, String)>,
+    pub auto_fields_interfaces: HashSet<String>,
 }
 
 impl Registry {
@@ -300,6 +434,16 @@ impl Registry {
                 }
             }
 
+            // Do not overwrite existing metadata.
+            for (coordinate, entries) in dummy_registry.metadata {
+                self.metadata.entry(coordinate).or_insert(entries);
+            }
+
+            self.object_resolved_fields
+                .extend(dummy_registry.object_resolved_fields);
+            self.auto_fields_interfaces
+                .extend(dummy_registry.auto_fields_interfaces);
+
             // Do not overwrite existing implements.
             for (name, interfaces) in dummy_registry.implements {
                 if let Some(current_interfaces) = self.implements.get_mut(&name) {
@@ -370,21 +514,23 @@ impl Registry {
     }
 
     fn create_entity_type(&mut self) {
+        // A union can only contain objects, so an entity interface contributes the objects
+        // implementing it.
         let possible_types = self
             .types
             .values()
-            .filter_map(|ty| match ty {
+            .flat_map(|ty| match ty {
                 MetaType::Object {
                     name,
                     keys: Some(keys),
                     ..
-                } if !keys.is_empty() => Some(name.clone()),
+                } if !keys.is_empty() => vec![name.clone()],
                 MetaType::Interface {
-                    name,
                     keys: Some(keys),
+                    possible_types,
                     ..
-                } if !keys.is_empty() => Some(name.clone()),
-                _ => None,
+                } if !keys.is_empty() => possible_types.iter().cloned().collect(),
+                _ => Vec::new(),
             })
             .collect();
 
@@ -398,6 +544,69 @@ impl Registry {
         );
     }
 
+    /// The keys of an entity. An object without keys of its own has the keys of the entity
+    /// interfaces it implements.
+    pub(crate) fn entity_keys(&self, name: &str) -> Vec<&str> {
+        let own_keys = match self.types.get(name) {
+            Some(MetaType::Object {
+                keys: Some(keys), ..
+            })
+            | Some(MetaType::Interface {
+                keys: Some(keys), ..
+            }) if !keys.is_empty() => return keys.iter().map(String::as_str).collect(),
+            Some(MetaType::Object { .. }) => self.implements.get(name),
+            _ => None,
+        };
+        let mut interfaces: Vec<_> = own_keys.into_iter().flatten().collect();
+        interfaces.sort();
+        let mut keys = Vec::new();
+        for interface in interfaces {
+            if let Some(MetaType::Interface {
+                keys: Some(interface_keys),
+                ..
+            }) = self.types.get(interface)
+            {
+                for key in interface_keys {
+                    if !keys.contains(&key.as_str()) {
+                        keys.push(key.as_str());
+                    }
+                }
+            }
+        }
+        keys
+    }
+
+    /// Whether an interface has keys, which requires Federation 2.
+    pub(crate) fn has_entity_interfaces(&self) -> bool {
+        self.types.values().any(|ty| {
+            matches!(ty, MetaType::Interface {
+                keys: Some(keys), ..
+            } if !keys.is_empty())
+        })
+    }
+
+    pub(crate) fn create_metadata_introspection_types(&mut self) {
+        let entry_type = <model::__MetadataEntry as Type>::create_type_info(self);
+
+        let query_root = self.types.get_mut(&self.query_type).unwrap();
+        if let MetaType::Object { fields, .. } = query_root {
+            let mut field = MetaField::new("__metadata", format!("[{}!]!", entry_type))
+                .description("The key/value pairs attached with the `meta` attribute to the type or the field at a schema coordinate, e.g. `User` or `User.name`.");
+            field.args.insert(
+                "coordinate",
+                MetaInputValue {
+                    name: "coordinate",
+                    description: None,
+                    ty: "String!".to_string(),
+                    default_value: None,
+                    validator: None,
+                    is_secret: false,
+                },
+            );
+            fields.insert("__metadata".to_string(), field);
+        }
+    }
+
     pub(crate) fn create_federation_types(&mut self) {
         Any::create_type_info(self);
 
@@ -464,6 +673,7 @@ impl Registry {
                                 ty: "[_Any!]!".to_string(),
                                 default_value: None,
                                 validator: None,
+                                is_secret: false,
                             },
                         );
                         args
@@ -523,7 +733,9 @@ impl Registry {
         names.into_iter().collect()
     }
 
-    pub fn set_description<T: Type>(&mut self, desc: &'static str) {
+    pub fn set_description<T: Type>(&mut self, desc: impl Into<String>) {
+        let desc = desc.into();
+        self.inherited_descriptions.remove(&*T::type_name());
         match self.types.get_mut(&*T::type_name()) {
             Some(MetaType::Scalar { description, .. }) => *description = Some(desc),
             Some(MetaType::Object { description, .. }) => *description = Some(desc),
@@ -534,4 +746,401 @@ impl Registry {
             None => {}
         }
     }
+
+    /// Attach a key/value pair to a type (`Type`) or to a field or enum value (`Type.field`).
+    pub fn add_metadata(
+        &mut self,
+        coordinate: impl Into<String>,
+        key: &'static str,
+        value: &'static str,
+    ) {
+        self.metadata
+            .entry(coordinate.into())
+            .or_default()
+            .push((key, value));
+    }
+
+    /// Get the key/value pairs attached to a type (`Type`) or to a field or enum value
+    /// (`Type.field`).
+    pub fn metadata(&self, coordinate: &str) -> &[(&'static str, &'static str)] {
+        self.metadata
+            .get(coordinate)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Redact the value of an argument or an input field marked as `secret`, or the values of the
+    /// secret input fields it contains.
+    pub(crate) fn redact_secrets(&self, input_value: &MetaInputValue, value: &mut Value) {
+        if input_value.is_secret {
+            *value = Value::String("[REDACTED]".to_string());
+        } else {
+            self.redact_secret_fields(&input_value.ty, value);
+        }
+    }
+
+    fn redact_secret_fields(&self, type_name: &str, value: &mut Value) {
+        match (MetaTypeName::create(type_name), value) {
+            (MetaTypeName::NonNull(type_name), value) => {
+                self.redact_secret_fields(type_name, value)
+            }
+            (MetaTypeName::List(type_name), Value::List(items)) => items
+                .iter_mut()
+                .for_each(|item| self.redact_secret_fields(type_name, item)),
+            // A single value is accepted for a list.
+            (MetaTypeName::List(type_name), value) => self.redact_secret_fields(type_name, value),
+            (MetaTypeName::Named(type_name), Value::Object(fields)) => {
+                if let Some(MetaType::InputObject { input_fields, .. }) = self.types.get(type_name)
+                {
+                    for (name, value) in fields {
+                        if let Some(input_field) = input_fields.get(name.as_str()) {
+                            self.redact_secrets(input_field, value);
+                        }
+                    }
+                }
+            }
+            (MetaTypeName::Named(_), _) => {}
+        }
+    }
+
+    /// Mark a field of an interface as resolved by the resolvers of the objects implementing it.
+    pub fn add_object_resolved_field(&mut self, interface: &str, field: &str) {
+        self.object_resolved_fields
+            .insert((interface.to_string(), field.to_string()));
+    }
+
+    /// Add the fields that all the possible types of the interface have, with the same type and
+    /// arguments, to the interface once all types have been registered.
+    pub fn add_auto_fields_interface(&mut self, interface: &str) {
+        self.auto_fields_interfaces.insert(interface.to_string());
+    }
+
+    /// Add the common fields of the possible types of the interfaces with automatic fields, they
+    /// are resolved by the objects.
+    ///
+    /// A field that all the possible types have, but with different types or arguments, is an
+    /// error.
+    pub(crate) fn apply_auto_interface_fields(&mut self) -> Result<(), String> {
+        let mut errors = Vec::new();
+
+        for interface_name in std::mem::take(&mut self.auto_fields_interfaces) {
+            let possible_types = match self.types.get(&interface_name) {
+                Some(MetaType::Interface { possible_types, .. }) => possible_types,
+                _ => continue,
+            };
+            let mut types = possible_types
+                .iter()
+                .filter_map(|name| Some((name, self.types.get(name)?.fields()?)));
+            let (first_name, first_fields) = match types.next() {
+                Some(first) => first,
+                None => continue,
+            };
+            let types = types.collect::<Vec<_>>();
+
+            let mut common_fields = Vec::new();
+            'fields: for field in first_fields.values() {
+                if field.name.starts_with("__") {
+                    continue;
+                }
+                let mut conflict = None;
+                for (type_name, fields) in &types {
+                    let other = match fields.get(&field.name) {
+                        Some(other) => other,
+                        None => continue 'fields,
+                    };
+                    if conflict.is_some() {
+                        continue;
+                    }
+                    if other.ty != field.ty {
+                        conflict = Some(format!(
+                            r#"Field "{}.{}" has type "{}" in "{}" and type "{}" in "{}"."#,
+                            interface_name, field.name, field.ty, first_name, other.ty, type_name
+                        ));
+                    } else if other.args.len() != field.args.len()
+                        || field.args.iter().any(|(name, arg)| {
+                            other.args.get(name).map(|other_arg| &other_arg.ty) != Some(&arg.ty)
+                        })
+                    {
+                        conflict = Some(format!(
+                            r#"Field "{}.{}" has different arguments in "{}" and "{}"."#,
+                            interface_name, field.name, first_name, type_name
+                        ));
+                    }
+                }
+                match conflict {
+                    Some(conflict) => errors.push(conflict),
+                    None => common_fields.push(field.clone()),
+                }
+            }
+
+            if let Some(MetaType::Interface { fields, .. }) = self.types.get_mut(&interface_name) {
+                for field in common_fields {
+                    if !fields.contains_key(&field.name) {
+                        fields.insert(field.name.clone(), field);
+                    }
+                }
+                if fields.is_empty() {
+                    errors.push(format!(
+                        r#"Interface "{}" has no fields in common with its possible types."#,
+                        interface_name
+                    ));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            errors.sort();
+            Err(errors.join("\n"))
+        }
+    }
+
+    /// Check that the possible types of an interface have a field compatible with each of its
+    /// fields that are resolved by the objects.
+    ///
+    /// The type of the field of the object must be the type of the interface field or a subtype
+    /// of it, and the field must have the same arguments, except for additional optional ones.
+    pub(crate) fn check_object_resolved_fields(&self) -> Result<(), String> {
+        let mut errors = Vec::new();
+
+        for (interface_name, field_name) in &self.object_resolved_fields {
+            let (interface_field, possible_types) = match self.types.get(interface_name) {
+                Some(MetaType::Interface {
+                    fields,
+                    possible_types,
+                    ..
+                }) => match fields.get(field_name) {
+                    Some(field) => (field, possible_types),
+                    None => continue,
+                },
+                _ => continue,
+            };
+
+            for type_name in possible_types {
+                let field = match self
+                    .types
+                    .get(type_name)
+                    .and_then(|ty| ty.field_by_name(field_name))
+                {
+                    Some(field) => field,
+                    None => {
+                        errors.push(format!(
+                            r#"Field "{}.{}" is not provided by "{}"."#,
+                            interface_name, field_name, type_name
+                        ));
+                        continue;
+                    }
+                };
+
+                if !self.is_valid_implementation_type(
+                    &MetaTypeName::create(&interface_field.ty),
+                    &MetaTypeName::create(&field.ty),
+                ) {
+                    errors.push(format!(
+                        r#"Field "{}.{}" has type "{}", which is not compatible with type "{}" of the interface "{}"."#,
+                        type_name, field_name, field.ty, interface_field.ty, interface_name
+                    ));
+                }
+
+                for (arg_name, arg) in &interface_field.args {
+                    match field.args.get(arg_name) {
+                        Some(object_arg) if object_arg.ty == arg.ty => {}
+                        Some(object_arg) => errors.push(format!(
+                            r#"Argument "{}" of field "{}.{}" has type "{}", but the interface "{}" expects type "{}"."#,
+                            arg_name, type_name, field_name, object_arg.ty, interface_name, arg.ty
+                        )),
+                        None => errors.push(format!(
+                            r#"Argument "{}" expected by interface "{}" is not provided by field "{}.{}"."#,
+                            arg_name, interface_name, type_name, field_name
+                        )),
+                    }
+                }
+                for (arg_name, arg) in &field.args {
+                    if !interface_field.args.contains_key(arg_name)
+                        && MetaTypeName::create(&arg.ty).is_non_null()
+                        && arg.default_value.is_none()
+                    {
+                        errors.push(format!(
+                            r#"Argument "{}" of field "{}.{}" is required, but the interface "{}" does not have it."#,
+                            arg_name, type_name, field_name, interface_name
+                        ));
+                    }
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            errors.sort();
+            Err(errors.join("\n"))
+        }
+    }
+
+    /// Check that every type referenced by the root types, the fields, the arguments, the
+    /// possible types and the directives is registered, and is an output or an input type as
+    /// expected. Returns the problems, sorted.
+    pub(crate) fn check_types(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        let mut check = |ty: &str, input: bool, position: String| {
+            let name = MetaTypeName::concrete_typename(ty);
+            match self.types.get(name) {
+                None => errors.push(format!(
+                    r#"Type "{}" of {} is not registered."#,
+                    name, position
+                )),
+                Some(meta) if input && !meta.is_input() => errors.push(format!(
+                    r#"Type "{}" of {} is not an input type."#,
+                    name, position
+                )),
+                Some(MetaType::InputObject { .. }) if !input => errors.push(format!(
+                    r#"Type "{}" of {} is not an output type."#,
+                    name, position
+                )),
+                _ => {}
+            }
+        };
+
+        for (root, name) in [
+            ("query", Some(&self.query_type)),
+            ("mutation", self.mutation_type.as_ref()),
+            ("subscription", self.subscription_type.as_ref()),
+        ] {
+            if let Some(name) = name {
+                check(name, false, format!("the {} root", root));
+            }
+        }
+
+        for (type_name, ty) in &self.types {
+            for (field_name, field) in ty.fields().into_iter().flatten() {
+                check(
+                    &field.ty,
+                    false,
+                    format!(r#"field "{}.{}""#, type_name, field_name),
+                );
+                for (arg_name, arg) in &field.args {
+                    check(
+                        &arg.ty,
+                        true,
+                        format!(r#"argument "{}.{}({}:)""#, type_name, field_name, arg_name),
+                    );
+                }
+            }
+            if let MetaType::InputObject { input_fields, .. } = ty {
+                for (field_name, field) in input_fields {
+                    check(
+                        &field.ty,
+                        true,
+                        format!(r#"input field "{}.{}""#, type_name, field_name),
+                    );
+                }
+            }
+            for possible_type in ty.possible_types().into_iter().flatten() {
+                check(
+                    possible_type,
+                    false,
+                    format!(r#"the possible types of "{}""#, type_name),
+                );
+            }
+        }
+
+        for directive in self.directives.values() {
+            for (arg_name, arg) in &directive.args {
+                check(
+                    &arg.ty,
+                    true,
+                    format!(r#"argument "@{}({}:)""#, directive.name, arg_name),
+                );
+            }
+        }
+
+        errors.sort();
+        errors
+    }
+
+    fn is_valid_implementation_type(
+        &self,
+        interface_type: &MetaTypeName<'_>,
+        object_type: &MetaTypeName<'_>,
+    ) -> bool {
+        match (interface_type, object_type) {
+            (MetaTypeName::NonNull(interface_type), MetaTypeName::NonNull(object_type)) => self
+                .is_valid_implementation_type(
+                    &MetaTypeName::create(interface_type),
+                    &MetaTypeName::create(object_type),
+                ),
+            (MetaTypeName::NonNull(_), _) => false,
+            (_, MetaTypeName::NonNull(object_type)) => self
+                .is_valid_implementation_type(interface_type, &MetaTypeName::create(object_type)),
+            (MetaTypeName::List(interface_type), MetaTypeName::List(object_type)) => self
+                .is_valid_implementation_type(
+                    &MetaTypeName::create(interface_type),
+                    &MetaTypeName::create(object_type),
+                ),
+            (MetaTypeName::Named(interface_type), MetaTypeName::Named(object_type)) => {
+                interface_type == object_type
+                    || matches!(
+                        self.types.get(*interface_type),
+                        Some(ty) if ty.is_possible_type(object_type)
+                    )
+            }
+            _ => false,
+        }
+    }
+
+    /// Append the description of the type `from` to the description of the type `type_name`
+    /// once all types have been registered.
+    pub fn inherit_description(&mut self, type_name: impl Into<String>, from: impl Into<String>) {
+        self.inherited_descriptions
+            .insert(type_name.into(), from.into());
+    }
+
+    pub(crate) fn apply_inherited_descriptions(&mut self) {
+        for (type_name, from) in std::mem::take(&mut self.inherited_descriptions) {
+            let inherited = match self.types.get(&from).and_then(MetaType::description) {
+                Some(description) => description.to_string(),
+                None => continue,
+            };
+            let description = match self.types.get_mut(&type_name) {
+                Some(MetaType::Scalar { description, .. })
+                | Some(MetaType::Object { description, .. })
+                | Some(MetaType::Interface { description, .. })
+                | Some(MetaType::Union { description, .. })
+                | Some(MetaType::Enum { description, .. })
+                | Some(MetaType::InputObject { description, .. }) => description,
+                None => continue,
+            };
+            *description = Some(match description.take() {
+                Some(description) => format!("{}\n\n{}", description, inherited),
+                None => inherited,
+            });
+        }
+    }
+
+    /// Remove what the specified edition of the specification doesn't know about.
+    pub(crate) fn apply_spec_version(&mut self, spec_version: SpecVersion) {
+        if spec_version != SpecVersion::June2018 {
+            return;
+        }
+        for (type_name, field_name) in &[
+            ("__Type", "specifiedByURL"),
+            ("__Directive", "isRepeatable"),
+        ] {
+            if let Some(MetaType::Object { fields, .. }) = self.types.get_mut(*type_name) {
+                fields.shift_remove(*field_name);
+            }
+        }
+        for ty in self.types.values_mut() {
+            if let MetaType::Scalar {
+                specified_by_url, ..
+            } = ty
+            {
+                *specified_by_url = None;
+            }
+        }
+        for directive in self.directives.values_mut() {
+            directive.is_repeatable = false;
+        }
+    }
 }
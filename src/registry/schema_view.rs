@@ -0,0 +1,514 @@
+use crate::registry::{
+    CacheControl, MetaDirective, MetaEnumValue, MetaField, MetaInputValue, MetaType, MetaTypeName,
+    Registry, SchemaCoordinate,
+};
+
+/// A read-only view of the types of a schema, returned by
+/// [`Schema::registry`](struct.Schema.html#method.registry).
+///
+/// It lets documentation generators and linters walk the schema without running an
+/// introspection query. Unlike introspection, it also exposes cache control and metadata.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     /// Add two numbers.
+///     async fn add(&self, a: i32, b: i32) -> i32 {
+///         a + b
+///     }
+///
+///     #[graphql(deprecation = "Use `add` instead")]
+///     async fn sum(&self, a: i32, b: i32) -> i32 {
+///         a + b
+///     }
+/// }
+///
+/// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+/// let query = schema.registry().query_type();
+/// let fields: Vec<_> = query
+///     .fields()
+///     .filter(|field| !field.is_deprecated() && !field.name().starts_with("__"))
+///     .map(|field| (field.name(), field.description()))
+///     .collect();
+/// assert_eq!(fields, vec![("add", Some("Add two numbers."))]);
+/// ```
+#[derive(Clone, Copy)]
+pub struct SchemaView<'a> {
+    registry: &'a Registry,
+}
+
+impl<'a> SchemaView<'a> {
+    pub(crate) fn new(registry: &'a Registry) -> Self {
+        Self { registry }
+    }
+
+    /// All named types in the order they were registered, including the introspection types
+    /// whose names start with `__`.
+    pub fn types_iter(&self) -> impl Iterator<Item = TypeView<'a>> + 'a {
+        let registry = self.registry;
+        registry
+            .types
+            .values()
+            .map(move |ty| TypeView { registry, ty })
+    }
+
+    /// The named type called `name`.
+    pub fn type_by_name(&self, name: &str) -> Option<TypeView<'a>> {
+        let registry = self.registry;
+        registry.types.get(name).map(|ty| TypeView { registry, ty })
+    }
+
+    /// The query root type.
+    pub fn query_type(&self) -> TypeView<'a> {
+        self.type_by_name(&self.registry.query_type).unwrap()
+    }
+
+    /// The mutation root type, if the schema has mutations.
+    pub fn mutation_type(&self) -> Option<TypeView<'a>> {
+        self.registry
+            .mutation_type
+            .as_deref()
+            .and_then(|name| self.type_by_name(name))
+    }
+
+    /// The subscription root type, if the schema has subscriptions.
+    pub fn subscription_type(&self) -> Option<TypeView<'a>> {
+        self.registry
+            .subscription_type
+            .as_deref()
+            .and_then(|name| self.type_by_name(name))
+    }
+
+    /// All directives, sorted by name.
+    pub fn directives_iter(&self) -> impl Iterator<Item = DirectiveView<'a>> + 'a {
+        let mut directives: Vec<_> = self.registry.directives.values().collect();
+        directives.sort_by_key(|directive| directive.name);
+        directives
+            .into_iter()
+            .map(|directive| DirectiveView { directive })
+    }
+
+    /// The member of the schema named by a [schema coordinate](enum.SchemaCoordinate.html) such
+    /// as `User.email`, or `None` if the coordinate is invalid or the member does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     #[graphql(deprecation = "Use `sum` instead")]
+    ///     async fn add(&self, a: i32, b: i32) -> i32 {
+    ///         a + b
+    ///     }
+    /// }
+    ///
+    /// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    /// let registry = schema.registry();
+    /// match registry.lookup_coordinate("Query.add") {
+    ///     Some(SchemaMember::Field(field)) => {
+    ///         assert_eq!(field.deprecation_reason(), Some("Use `sum` instead"))
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// assert!(registry.lookup_coordinate("Query.add(a:)").is_some());
+    /// assert!(registry.lookup_coordinate("Query.add(c:)").is_none());
+    /// ```
+    pub fn lookup_coordinate(&self, coordinate: &str) -> Option<SchemaMember<'a>> {
+        self.lookup(&coordinate.parse().ok()?)
+    }
+
+    /// The member of the schema named by a parsed schema coordinate.
+    pub fn lookup(&self, coordinate: &SchemaCoordinate) -> Option<SchemaMember<'a>> {
+        match coordinate {
+            SchemaCoordinate::Type(name) => self.type_by_name(name).map(SchemaMember::Type),
+            SchemaCoordinate::Member { type_name, member } => {
+                let ty = self.type_by_name(type_name)?;
+                match ty.kind() {
+                    TypeKind::Object | TypeKind::Interface => {
+                        ty.field_by_name(member).map(SchemaMember::Field)
+                    }
+                    TypeKind::InputObject => ty
+                        .input_fields()
+                        .find(|field| field.name() == member)
+                        .map(SchemaMember::InputField),
+                    TypeKind::Enum => ty
+                        .enum_values()
+                        .find(|value| value.name() == member)
+                        .map(SchemaMember::EnumValue),
+                    TypeKind::Scalar | TypeKind::Union => None,
+                }
+            }
+            SchemaCoordinate::Argument {
+                type_name,
+                field,
+                argument,
+            } => self
+                .type_by_name(type_name)?
+                .field_by_name(field)?
+                .args()
+                .find(|arg| arg.name() == argument)
+                .map(SchemaMember::Argument),
+            SchemaCoordinate::Directive(name) => self
+                .registry
+                .directives
+                .get(name)
+                .map(|directive| SchemaMember::Directive(DirectiveView { directive })),
+            SchemaCoordinate::DirectiveArgument {
+                directive,
+                argument,
+            } => self
+                .registry
+                .directives
+                .get(directive)
+                .and_then(|directive| {
+                    DirectiveView { directive }
+                        .args()
+                        .find(|arg| arg.name() == argument)
+                })
+                .map(SchemaMember::DirectiveArgument),
+        }
+    }
+}
+
+/// A member of a [`SchemaView`](struct.SchemaView.html), found by
+/// [`SchemaView::lookup_coordinate`](struct.SchemaView.html#method.lookup_coordinate).
+#[derive(Clone, Copy)]
+pub enum SchemaMember<'a> {
+    /// A named type.
+    Type(TypeView<'a>),
+    /// A field of an object or an interface.
+    Field(FieldView<'a>),
+    /// An argument of a field.
+    Argument(InputValueView<'a>),
+    /// A field of an input object.
+    InputField(InputValueView<'a>),
+    /// A value of an enum.
+    EnumValue(EnumValueView<'a>),
+    /// A directive.
+    Directive(DirectiveView<'a>),
+    /// An argument of a directive.
+    DirectiveArgument(InputValueView<'a>),
+}
+
+/// The kind of a named type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeKind {
+    /// A scalar.
+    Scalar,
+    /// An object.
+    Object,
+    /// An interface.
+    Interface,
+    /// A union.
+    Union,
+    /// An enum.
+    Enum,
+    /// An input object.
+    InputObject,
+}
+
+/// A named type of a [`SchemaView`](struct.SchemaView.html).
+#[derive(Clone, Copy)]
+pub struct TypeView<'a> {
+    registry: &'a Registry,
+    ty: &'a MetaType,
+}
+
+impl<'a> TypeView<'a> {
+    /// The type name.
+    pub fn name(&self) -> &'a str {
+        self.ty.name()
+    }
+
+    /// The kind of the type.
+    pub fn kind(&self) -> TypeKind {
+        match self.ty {
+            MetaType::Scalar { .. } => TypeKind::Scalar,
+            MetaType::Object { .. } => TypeKind::Object,
+            MetaType::Interface { .. } => TypeKind::Interface,
+            MetaType::Union { .. } => TypeKind::Union,
+            MetaType::Enum { .. } => TypeKind::Enum,
+            MetaType::InputObject { .. } => TypeKind::InputObject,
+        }
+    }
+
+    /// The type description.
+    pub fn description(&self) -> Option<&'a str> {
+        self.ty.description()
+    }
+
+    /// The URL of the specification of a custom scalar.
+    pub fn specified_by_url(&self) -> Option<&'a str> {
+        match self.ty {
+            MetaType::Scalar {
+                specified_by_url, ..
+            } => *specified_by_url,
+            _ => None,
+        }
+    }
+
+    /// The cache control of an object.
+    pub fn cache_control(&self) -> Option<CacheControl> {
+        match self.ty {
+            MetaType::Object { cache_control, .. } => Some(*cache_control),
+            _ => None,
+        }
+    }
+
+    /// The fields of an object or an interface, empty for other kinds. The fields of the query
+    /// root include the introspection fields `__schema` and `__type`.
+    pub fn fields(&self) -> impl Iterator<Item = FieldView<'a>> + 'a {
+        let registry = self.registry;
+        let type_name = self.name();
+        self.ty
+            .fields()
+            .into_iter()
+            .flat_map(|fields| fields.values())
+            .map(move |field| FieldView {
+                registry,
+                type_name,
+                field,
+            })
+    }
+
+    /// The field of an object or an interface called `name`.
+    pub fn field_by_name(&self, name: &str) -> Option<FieldView<'a>> {
+        self.ty.field_by_name(name).map(|field| FieldView {
+            registry: self.registry,
+            type_name: self.name(),
+            field,
+        })
+    }
+
+    /// The fields of an input object, empty for other kinds.
+    pub fn input_fields(&self) -> impl Iterator<Item = InputValueView<'a>> + 'a {
+        let input_fields = match self.ty {
+            MetaType::InputObject { input_fields, .. } => Some(input_fields),
+            _ => None,
+        };
+        input_fields
+            .into_iter()
+            .flat_map(|input_fields| input_fields.values())
+            .map(|value| InputValueView { value })
+    }
+
+    /// The values of an enum, empty for other kinds.
+    pub fn enum_values(&self) -> impl Iterator<Item = EnumValueView<'a>> + 'a {
+        let registry = self.registry;
+        let type_name = self.name();
+        let enum_values = match self.ty {
+            MetaType::Enum { enum_values, .. } => Some(enum_values),
+            _ => None,
+        };
+        enum_values
+            .into_iter()
+            .flat_map(|enum_values| enum_values.values())
+            .map(move |value| EnumValueView {
+                registry,
+                type_name,
+                value,
+            })
+    }
+
+    /// The names of the possible types of an interface or a union, empty for other kinds.
+    pub fn possible_types(&self) -> impl Iterator<Item = &'a str> + 'a {
+        self.ty
+            .possible_types()
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+    }
+
+    /// The names of the interfaces implemented by an object, sorted by name.
+    pub fn interfaces(&self) -> Vec<&'a str> {
+        let mut interfaces: Vec<_> = self
+            .registry
+            .implements
+            .get(self.name())
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .collect();
+        interfaces.sort_unstable();
+        interfaces
+    }
+
+    /// The key/value pairs attached to the type with `meta`.
+    pub fn metadata(&self) -> &'a [(&'static str, &'static str)] {
+        self.registry.metadata(self.name())
+    }
+}
+
+/// A field of an object or an interface.
+#[derive(Clone, Copy)]
+pub struct FieldView<'a> {
+    registry: &'a Registry,
+    type_name: &'a str,
+    field: &'a MetaField,
+}
+
+impl<'a> FieldView<'a> {
+    /// The field name.
+    pub fn name(&self) -> &'a str {
+        &self.field.name
+    }
+
+    /// The field description.
+    pub fn description(&self) -> Option<&'a str> {
+        self.field.description
+    }
+
+    /// The type reference, e.g. `[Int!]!`.
+    pub fn type_ref(&self) -> &'a str {
+        &self.field.ty
+    }
+
+    /// The named type of the field, e.g. `Int` for `[Int!]!`.
+    pub fn type_name(&self) -> &'a str {
+        MetaTypeName::concrete_typename(&self.field.ty)
+    }
+
+    /// The arguments in the order they were defined.
+    pub fn args(&self) -> impl Iterator<Item = InputValueView<'a>> + 'a {
+        self.field
+            .args
+            .values()
+            .map(|value| InputValueView { value })
+    }
+
+    /// Whether the field is deprecated.
+    pub fn is_deprecated(&self) -> bool {
+        self.field.deprecation.is_some()
+    }
+
+    /// The reason the field is deprecated.
+    pub fn deprecation_reason(&self) -> Option<&'a str> {
+        self.field.deprecation
+    }
+
+    /// The cache control of the field.
+    pub fn cache_control(&self) -> CacheControl {
+        self.field.cache_control
+    }
+
+    /// The key/value pairs attached to the field with `meta`.
+    pub fn metadata(&self) -> &'a [(&'static str, &'static str)] {
+        self.registry
+            .metadata(&format!("{}.{}", self.type_name, self.field.name))
+    }
+}
+
+/// An argument of a field or a directive, or a field of an input object.
+#[derive(Clone, Copy)]
+pub struct InputValueView<'a> {
+    value: &'a MetaInputValue,
+}
+
+impl<'a> InputValueView<'a> {
+    /// The name.
+    pub fn name(&self) -> &'a str {
+        self.value.name
+    }
+
+    /// The description.
+    pub fn description(&self) -> Option<&'a str> {
+        self.value.description
+    }
+
+    /// The type reference, e.g. `[Int!]!`.
+    pub fn type_ref(&self) -> &'a str {
+        &self.value.ty
+    }
+
+    /// The named type, e.g. `Int` for `[Int!]!`.
+    pub fn type_name(&self) -> &'a str {
+        MetaTypeName::concrete_typename(&self.value.ty)
+    }
+
+    /// The default value, in GraphQL syntax.
+    pub fn default_value(&self) -> Option<&'a str> {
+        self.value.default_value.as_deref()
+    }
+
+    /// Whether the value is marked as `secret` and redacted in audit logs.
+    pub fn is_secret(&self) -> bool {
+        self.value.is_secret
+    }
+}
+
+/// A value of an enum.
+#[derive(Clone, Copy)]
+pub struct EnumValueView<'a> {
+    registry: &'a Registry,
+    type_name: &'a str,
+    value: &'a MetaEnumValue,
+}
+
+impl<'a> EnumValueView<'a> {
+    /// The value name.
+    pub fn name(&self) -> &'a str {
+        self.value.name
+    }
+
+    /// The value description.
+    pub fn description(&self) -> Option<&'a str> {
+        self.value.description
+    }
+
+    /// Whether the value is deprecated.
+    pub fn is_deprecated(&self) -> bool {
+        self.value.deprecation.is_some()
+    }
+
+    /// The reason the value is deprecated.
+    pub fn deprecation_reason(&self) -> Option<&'a str> {
+        self.value.deprecation
+    }
+
+    /// The key/value pairs attached to the value with `meta`.
+    pub fn metadata(&self) -> &'a [(&'static str, &'static str)] {
+        self.registry
+            .metadata(&format!("{}.{}", self.type_name, self.value.name))
+    }
+}
+
+/// A directive.
+#[derive(Clone, Copy)]
+pub struct DirectiveView<'a> {
+    directive: &'a MetaDirective,
+}
+
+impl<'a> DirectiveView<'a> {
+    /// The directive name, without the `@`.
+    pub fn name(&self) -> &'a str {
+        self.directive.name
+    }
+
+    /// The directive description.
+    pub fn description(&self) -> Option<&'a str> {
+        self.directive.description
+    }
+
+    /// The arguments in the order they were defined.
+    pub fn args(&self) -> impl Iterator<Item = InputValueView<'a>> + 'a {
+        self.directive
+            .args
+            .values()
+            .map(|value| InputValueView { value })
+    }
+
+    /// Whether the directive can be used more than once at the same location.
+    pub fn is_repeatable(&self) -> bool {
+        self.directive.is_repeatable
+    }
+}
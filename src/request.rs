@@ -2,8 +2,11 @@ use std::any::Any;
 use std::collections::HashMap;
 use std::fmt::{self, Debug, Formatter};
 
+use serde::de::value::MapAccessDeserializer;
+use serde::de::{MapAccess, Visitor};
 use serde::{Deserialize, Deserializer, Serialize};
 
+use crate::parser::types::OperationType;
 use crate::{Data, ParseRequestError, UploadValue, Value, Variables};
 
 /// GraphQL request.
@@ -38,6 +41,15 @@ pub struct Request {
     /// The extensions config of the request.
     #[serde(default)]
     pub extensions: HashMap<String, Value>,
+
+    /// The operation types the request may execute, all of them if `None`.
+    #[serde(skip)]
+    pub allowed_operations: Option<Vec<OperationType>>,
+
+    /// The id of the last event of a subscription received by the client, sent when it
+    /// reconnects to resume the subscription. See `Context::last_event_id`.
+    #[serde(default)]
+    pub last_event_id: Option<String>,
 }
 
 impl Request {
@@ -50,6 +62,8 @@ impl Request {
             uploads: Vec::default(),
             data: Data::default(),
             extensions: Default::default(),
+            allowed_operations: None,
+            last_event_id: None,
         }
     }
 
@@ -66,6 +80,26 @@ impl Request {
         Self { variables, ..self }
     }
 
+    /// Only allow the request to execute operations of these types, e.g. only queries for an
+    /// HTTP `GET` request or for a read-only replica.
+    ///
+    /// The selected operation is checked during validation, other types fail with an error.
+    pub fn allowed_operations(self, types: impl IntoIterator<Item = OperationType>) -> Self {
+        Self {
+            allowed_operations: Some(types.into_iter().collect()),
+            ..self
+        }
+    }
+
+    /// Set the id of the last event of the subscription received by the client, e.g. from the
+    /// `Last-Event-ID` header of a server-sent events request.
+    pub fn last_event_id(self, id: impl Into<String>) -> Self {
+        Self {
+            last_event_id: Some(id.into()),
+            ..self
+        }
+    }
+
     /// Insert some data for this request.
     pub fn data<D: Any>(mut self, data: D) -> Self {
         self.data.insert(data);
@@ -88,6 +122,43 @@ impl Request {
     }
 }
 
+/// Construct a [`Request`](struct.Request.html).
+///
+/// The variables are written with the same JSON-like syntax as [`value!`](macro.value.html), so
+/// any Rust expression implementing `Serialize` can be interpolated. An operation name can be
+/// given after the variables.
+///
+/// ```
+/// use async_graphql::*;
+///
+/// let id = 10;
+/// let request = request!("query($id: Int!, $tags: [String!]!) { a(id: $id, tags: $tags) }", {
+///     "id": id,
+///     "tags": ["a", "b"],
+/// });
+/// assert_eq!(
+///     request.variables.into_value(),
+///     value!({ "id": 10, "tags": ["a", "b"] }),
+/// );
+///
+/// let request = request!("query A { a } query B { b }", {}, "B");
+/// assert_eq!(request.operation_name.as_deref(), Some("B"));
+/// ```
+#[macro_export]
+macro_rules! request {
+    ($query:expr $(,)?) => {
+        $crate::Request::new($query)
+    };
+
+    ($query:expr, { $($variables:tt)* } $(, $operation_name:expr)? $(,)?) => {{
+        let request = $crate::Request::new($query).variables($crate::Variables::from_value(
+            $crate::value!({ $($variables)* }),
+        ));
+        $(let request = request.operation_name($operation_name);)?
+        request
+    }};
+}
+
 impl<T: Into<String>> From<T> for Request {
     fn from(query: T) -> Self {
         Self::new(query)
@@ -101,6 +172,8 @@ impl Debug for Request {
             .field("operation_name", &self.operation_name)
             .field("variables", &self.variables)
             .field("extensions", &self.extensions)
+            .field("allowed_operations", &self.allowed_operations)
+            .field("last_event_id", &self.last_event_id)
             .finish()
     }
 }
@@ -112,6 +185,7 @@ impl Debug for Request {
 #[serde(untagged)]
 pub enum BatchRequest {
     /// Single query
+    #[serde(deserialize_with = "deserialize_request_map")]
     Single(Request),
 
     /// Non-empty array of queries
@@ -132,6 +206,58 @@ impl BatchRequest {
             Self::Batch(_) => Err(ParseRequestError::UnsupportedBatch),
         }
     }
+
+    /// Returns an iterator over the requests.
+    pub fn iter(&self) -> impl Iterator<Item = &Request> {
+        match self {
+            BatchRequest::Single(request) => {
+                Box::new(std::iter::once(request)) as Box<dyn Iterator<Item = &Request>>
+            }
+            BatchRequest::Batch(requests) => Box::new(requests.iter()),
+        }
+    }
+
+    /// Returns an iterator that allows modifying each request.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Request> {
+        match self {
+            BatchRequest::Single(request) => {
+                Box::new(std::iter::once(request)) as Box<dyn Iterator<Item = &mut Request>>
+            }
+            BatchRequest::Batch(requests) => Box::new(requests.iter_mut()),
+        }
+    }
+
+    /// Insert some data for each request, e.g. the data extracted from the HTTP request that
+    /// carried the batch.
+    pub fn data<D: Any + Clone>(mut self, data: D) -> Self {
+        for request in self.iter_mut() {
+            request.data.insert(data.clone());
+        }
+        self
+    }
+}
+
+/// Only accept a map for a single request, serde would also accept a sequence of its fields,
+/// which would turn an empty batch into an empty request.
+fn deserialize_request_map<'de, D>(deserializer: D) -> Result<Request, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct RequestVisitor;
+
+    impl<'de> Visitor<'de> for RequestVisitor {
+        type Value = Request;
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("a request object")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+            Request::deserialize(MapAccessDeserializer::new(map))
+        }
+    }
+
+    deserializer.deserialize_map(RequestVisitor)
 }
 
 fn deserialize_non_empty_vec<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
@@ -211,6 +337,30 @@ mod tests {
         assert_eq!(request.query, "{ a b c }");
     }
 
+    #[test]
+    fn test_request_macro() {
+        let request = request!("{ a b c }");
+        assert!(request.variables.0.is_empty());
+        assert!(request.operation_name.is_none());
+        assert_eq!(request.query, "{ a b c }");
+
+        let v2 = vec![1, 2, 3];
+        let request = request!("{ a b c }", {
+            "v1": 100,
+            "v2": v2,
+            "v3": { "a": "str" },
+        }, "a");
+        assert_eq!(
+            request.variables.into_value(),
+            value!({
+                "v1": 100,
+                "v2": [1, 2, 3],
+                "v3": { "a": "str" },
+            })
+        );
+        assert_eq!(request.operation_name.as_deref(), Some("a"));
+    }
+
     #[test]
     fn test_deserialize_request_with_null_variables() {
         let request: Request = from_value(value! ({
@@ -0,0 +1,241 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::runtime::Timer;
+use crate::{Context, Error, ErrorExtensions, Result};
+
+/// The state of the calls to a downstream service, used by
+/// [`ResilientResolver`](struct.ResilientResolver.html).
+///
+/// Implement it to share the state between processes or to use another policy than
+/// [`ConsecutiveFailureBreaker`](struct.ConsecutiveFailureBreaker.html).
+pub trait CircuitBreaker: 'static {
+    /// Whether a call may be made, `false` if the circuit is open.
+    fn allow(&self) -> bool;
+
+    /// Record that an allowed call succeeded.
+    fn record_success(&self);
+
+    /// Record that an allowed call failed.
+    fn record_failure(&self);
+}
+
+/// The state of a [`ConsecutiveFailureBreaker`](struct.ConsecutiveFailureBreaker.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// The calls are made.
+    Closed,
+    /// The calls fail fast.
+    Open,
+    /// A trial call is made to find out whether the downstream service recovered.
+    HalfOpen,
+}
+
+/// The times are read from the clock of the timer.
+#[derive(Clone, Copy)]
+enum State {
+    Closed { failures: usize },
+    Open { until: Duration },
+    HalfOpen { since: Duration },
+}
+
+/// A circuit breaker that opens after a number of consecutive failures.
+///
+/// Once `reset_timeout` has elapsed, a single trial call is allowed: the circuit closes if it
+/// succeeds and opens again if it fails. Cloning it returns a handle to the same state.
+#[derive(Clone)]
+pub struct ConsecutiveFailureBreaker {
+    failure_threshold: usize,
+    reset_timeout: Duration,
+    timer: Arc<dyn Timer>,
+    state: Rc<Cell<State>>,
+}
+
+impl ConsecutiveFailureBreaker {
+    /// Creates a circuit breaker that opens after `failure_threshold` consecutive failures, a
+    /// threshold of 0 is treated as 1, and allows a trial call after `reset_timeout`, measured
+    /// with the clock of `timer`, see the [`runtime`](../runtime/index.html) module.
+    pub fn new(failure_threshold: usize, reset_timeout: Duration, timer: impl Timer) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            reset_timeout,
+            timer: Arc::new(timer),
+            state: Rc::new(Cell::new(State::Closed { failures: 0 })),
+        }
+    }
+
+    /// The time `reset_timeout` after `since`, the circuit stays open if it overflows.
+    fn after_reset_timeout(&self, since: Duration) -> Duration {
+        since
+            .checked_add(self.reset_timeout)
+            .unwrap_or(Duration::MAX)
+    }
+
+    /// The current state.
+    pub fn state(&self) -> CircuitState {
+        match self.state.get() {
+            State::Closed { .. } => CircuitState::Closed,
+            State::Open { until } if until <= self.timer.now() => CircuitState::HalfOpen,
+            State::Open { .. } => CircuitState::Open,
+            State::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    fn open(&self) {
+        self.state.set(State::Open {
+            until: self.after_reset_timeout(self.timer.now()),
+        });
+    }
+}
+
+impl CircuitBreaker for ConsecutiveFailureBreaker {
+    fn allow(&self) -> bool {
+        let now = self.timer.now();
+        let trial_at = match self.state.get() {
+            State::Closed { .. } => return true,
+            State::Open { until } => until,
+            // A trial call that never completed, e.g. because the request was cancelled, doesn't
+            // keep the circuit open forever.
+            State::HalfOpen { since } => self.after_reset_timeout(since),
+        };
+        if trial_at > now {
+            return false;
+        }
+        self.state.set(State::HalfOpen { since: now });
+        true
+    }
+
+    fn record_success(&self) {
+        self.state.set(State::Closed { failures: 0 });
+    }
+
+    fn record_failure(&self) {
+        match self.state.get() {
+            State::Closed { failures } if failures + 1 < self.failure_threshold => {
+                self.state.set(State::Closed {
+                    failures: failures + 1,
+                })
+            }
+            _ => self.open(),
+        }
+    }
+}
+
+/// The circuit breakers of the downstream services, by name. Add it to the data of the schema
+/// to use [`ResilientResolver::from_ctx`](struct.ResilientResolver.html#method.from_ctx).
+#[derive(Clone, Default)]
+pub struct CircuitBreakers(HashMap<String, Rc<dyn CircuitBreaker>>);
+
+impl CircuitBreakers {
+    /// Add the circuit breaker of the downstream service called `name`.
+    #[must_use]
+    pub fn with(mut self, name: impl Into<String>, breaker: impl CircuitBreaker) -> Self {
+        self.0.insert(name.into(), Rc::new(breaker));
+        self
+    }
+
+    /// The circuit breaker of the downstream service called `name`.
+    pub fn get(&self, name: &str) -> Option<&dyn CircuitBreaker> {
+        self.0.get(name).map(|breaker| &**breaker)
+    }
+}
+
+/// Runs resolvers calling a downstream service through a circuit breaker.
+///
+/// While the circuit is open, the resolvers are not called and fail fast with an error whose
+/// `code` extension is `CIRCUIT_OPEN`, instead of waiting for a service that is known to be
+/// unhealthy.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use async_graphql::resolver_utils::{
+///     CircuitBreakers, CircuitState, ConsecutiveFailureBreaker, ResilientResolver,
+/// };
+/// use async_graphql::runtime::Timer;
+/// use futures_util::future::{FutureExt, LocalBoxFuture};
+/// use std::time::{Duration, Instant};
+///
+/// struct AsyncStdTimer(Instant);
+///
+/// impl Timer for AsyncStdTimer {
+///     fn sleep(&self, duration: Duration) -> LocalBoxFuture<'static, ()> {
+///         async_std::task::sleep(duration).boxed_local()
+///     }
+///
+///     fn now(&self) -> Duration {
+///         self.0.elapsed()
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn price(&self, ctx: &Context<'_>) -> Result<i32> {
+///         ResilientResolver::from_ctx(ctx, "pricing")?
+///             .resolve(|| async { Err("Timeout".into()) })
+///             .await
+///     }
+/// }
+///
+/// let breaker =
+///     ConsecutiveFailureBreaker::new(2, Duration::from_secs(30), AsyncStdTimer(Instant::now()));
+/// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+///     .data(CircuitBreakers::default().with("pricing", breaker.clone()))
+///     .finish();
+///
+/// async_std::task::block_on(async move {
+///     schema.execute("{ price }").await;
+///     schema.execute("{ price }").await;
+///     assert_eq!(breaker.state(), CircuitState::Open);
+///     let err = schema.execute("{ price }").await.errors.remove(0);
+///     assert_eq!(err.message, "The downstream service is unavailable.");
+/// });
+/// ```
+pub struct ResilientResolver<'a> {
+    breaker: &'a dyn CircuitBreaker,
+}
+
+impl<'a> ResilientResolver<'a> {
+    /// Use the circuit breaker `breaker`.
+    pub fn new(breaker: &'a dyn CircuitBreaker) -> Self {
+        Self { breaker }
+    }
+
+    /// Use the circuit breaker called `name` of the
+    /// [`CircuitBreakers`](struct.CircuitBreakers.html) in the data of the context.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no `CircuitBreakers` or none is called `name`.
+    pub fn from_ctx(ctx: &'a Context<'_>, name: &str) -> Result<Self> {
+        ctx.data::<CircuitBreakers>()?
+            .get(name)
+            .map(Self::new)
+            .ok_or_else(|| Error::new(format!("Circuit breaker `{}` does not exist.", name)))
+    }
+
+    /// Call `f` unless the circuit is open, and record whether it succeeded.
+    pub async fn resolve<T, F, Fut>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        if !self.breaker.allow() {
+            return Err(Error::new("The downstream service is unavailable.")
+                .extend_with(|_, e| e.set("code", "CIRCUIT_OPEN")));
+        }
+        let res = f().await;
+        match &res {
+            Ok(_) => self.breaker.record_success(),
+            Err(_) => self.breaker.record_failure(),
+        }
+        res
+    }
+}
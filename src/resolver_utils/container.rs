@@ -1,13 +1,18 @@
-use std::collections::BTreeMap;
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::future::Future;
 use std::pin::Pin;
+use std::rc::Rc;
 
-use crate::extensions::{ErrorLogger, ExtensionContext, ResolveInfo};
-use crate::parser::types::Selection;
+use crate::context::FieldResults;
+use crate::extensions::{ErrorLogger, ExtensionContext, MutationFieldInfo, ResolveInfo};
+#[cfg(feature = "client_controlled_nullability")]
+use crate::parser::types::Nullability;
+use crate::parser::types::{Field, OperationType, Selection};
 use crate::registry::MetaType;
 use crate::{
-    Context, ContextSelectionSet, Name, OutputValueType, PathSegment, ServerError, ServerResult,
-    Value,
+    Context, ContextSelectionSet, Map, Name, OutputValueType, PathSegment, Positioned, ServerError,
+    ServerResult, Value,
 };
 
 /// Represents a GraphQL container object.
@@ -30,7 +35,9 @@ pub trait ContainerType: OutputValueType {
     /// Collect all the fields of the container that are queried in the selection set.
     ///
     /// Objects do not have to override this, but interfaces and unions must call it on their
-    /// internal type.
+    /// internal type. A custom container that wraps another one, e.g. to add fields to it, can
+    /// call it on the wrapped container so that `__typename`, aliases and fragments are handled
+    /// by the wrapped type.
     fn collect_all_fields<'a>(
         &'a self,
         ctx: &ContextSelectionSet<'a>,
@@ -61,6 +68,96 @@ impl<T: ContainerType> ContainerType for &T {
     }
 }
 
+/// Resolve the field selected in `ctx` to `value`, for use in `ContainerType::resolve_field`.
+///
+/// `__typename`, aliases and fragments are handled by `resolve_container`, so a hand-written
+/// container only has to match the field name.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::borrow::Cow;
+///
+/// use async_graphql::*;
+/// use async_graphql::parser::types::Field;
+/// use async_graphql::registry::{MetaField, MetaType, Registry};
+/// use async_graphql::resolver_utils::{resolve_container, resolve_field_value, ContainerType};
+///
+/// /// A page of items with its number.
+/// struct Page<T> {
+///     number: i32,
+///     items: Vec<T>,
+/// }
+///
+/// impl<T: OutputValueType> Type for Page<T> {
+///     fn type_name() -> Cow<'static, str> {
+///         Cow::Owned(format!("{}Page", T::type_name()))
+///     }
+///
+///     fn create_type_info(registry: &mut Registry) -> String {
+///         registry.create_type::<Self, _>(|registry| {
+///             MetaType::object(
+///                 Self::type_name(),
+///                 None,
+///                 vec![
+///                     MetaField::new("number", i32::create_type_info(registry)),
+///                     MetaField::new("items", Vec::<T>::create_type_info(registry))
+///                         .description("The items of the page"),
+///                 ],
+///             )
+///         })
+///     }
+/// }
+///
+/// #[async_trait::async_trait(?Send)]
+/// impl<T: OutputValueType> ContainerType for Page<T> {
+///     async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+///         match ctx.item.node.name.node.as_str() {
+///             "number" => resolve_field_value(ctx, &self.number).await,
+///             "items" => resolve_field_value(ctx, &self.items).await,
+///             _ => Ok(None),
+///         }
+///     }
+/// }
+///
+/// #[async_trait::async_trait(?Send)]
+/// impl<T: OutputValueType> OutputValueType for Page<T> {
+///     async fn resolve(
+///         &self,
+///         ctx: &ContextSelectionSet<'_>,
+///         _field: &Positioned<Field>,
+///     ) -> ServerResult<Value> {
+///         resolve_container(ctx, self).await
+///     }
+/// }
+///
+/// impl<T: OutputValueType> ObjectType for Page<T> {}
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn page(&self) -> Page<i32> {
+///         Page { number: 1, items: vec![1, 2] }
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+///     let res = schema.execute("{ page { __typename n: number items } }").await;
+///     assert_eq!(res.data, value!({
+///         "page": { "__typename": "IntPage", "n": 1, "items": [1, 2] }
+///     }));
+/// });
+/// ```
+pub async fn resolve_field_value<T: OutputValueType + ?Sized>(
+    ctx: &Context<'_>,
+    value: &T,
+) -> ServerResult<Option<Value>> {
+    let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
+    value.resolve(&ctx_obj, ctx.item).await.map(Some)
+}
+
 /// Resolve an container by executing each of the fields concurrently.
 pub async fn resolve_container<'a, T: ContainerType>(
     ctx: &ContextSelectionSet<'a>,
@@ -82,21 +179,25 @@ async fn resolve_container_inner<'a, T: ContainerType>(
     root: &'a T,
     parallel: bool,
 ) -> ServerResult<Value> {
-    let mut fields = Fields(Vec::new());
-    fields.add_set(ctx, root)?;
+    let field_results = FieldResults::default();
+    let ctx = ctx.with_field_results(&field_results);
+    let mut fields = Fields::default();
+    fields.add_set(&ctx, root)?;
 
     let res = if parallel {
-        futures_util::future::try_join_all(fields.0).await?
+        futures_util::future::try_join_all(fields.futures).await?
     } else {
-        let mut results = Vec::with_capacity(fields.0.len());
-        for field in fields.0 {
+        let mut results = Vec::with_capacity(fields.futures.len());
+        for field in fields.futures {
             results.push(field.await?);
         }
         results
     };
 
-    let mut map = BTreeMap::new();
+    let mut map = Map::new();
     for (name, value) in res {
+        ctx.count_response_value(Some(&name), &value)
+            .map_err(|e| e.path(PathSegment::Field(name.to_string())))?;
         if let Value::Object(b) = value {
             if let Some(Value::Object(a)) = map.get_mut(&name) {
                 a.extend(b);
@@ -113,7 +214,14 @@ async fn resolve_container_inner<'a, T: ContainerType>(
 type BoxFieldFuture<'a> = Pin<Box<dyn Future<Output = ServerResult<(Name, Value)>> + 'a>>;
 
 /// A set of fields on an container that are being selected.
-pub struct Fields<'a>(Vec<BoxFieldFuture<'a>>);
+///
+/// Fields with the same response key, e.g. the same field selected directly and through a
+/// fragment, are resolved once with their selection sets merged.
+#[derive(Default)]
+pub struct Fields<'a> {
+    futures: Vec<BoxFieldFuture<'a>>,
+    groups: HashMap<&'a str, Rc<RefCell<Vec<&'a Positioned<Field>>>>>,
+}
 
 impl<'a> Fields<'a> {
     /// Add another set of fields to this set of fields using the given container.
@@ -130,12 +238,18 @@ impl<'a> Fields<'a> {
             match &selection.node {
                 Selection::Field(field) => {
                     if field.node.name.node == "__typename" {
-                        // Get the typename
-                        let ctx_field = ctx.with_field(field);
-                        let field_name = ctx_field.item.node.response_key().node.clone();
+                        // Get the typename, once per response key even if it is also selected
+                        // in fragments.
+                        let response_key = field.node.response_key().node.as_str();
+                        if self.groups.contains_key(response_key) {
+                            continue;
+                        }
+                        self.groups
+                            .insert(response_key, Rc::new(RefCell::new(vec![field])));
+                        let field_name = field.node.response_key().node.clone();
                         let typename = root.introspection_type_name().into_owned();
 
-                        self.0.push(Box::pin(async move {
+                        self.futures.push(Box::pin(async move {
                             Ok((field_name, Value::String(typename)))
                         }));
                         continue;
@@ -151,15 +265,36 @@ impl<'a> Fields<'a> {
                         }
                     }
 
-                    self.0.push(Box::pin({
+                    let response_key = field.node.response_key().node.as_str();
+                    if let Some(group) = self.groups.get(response_key) {
+                        group.borrow_mut().push(field);
+                        continue;
+                    }
+                    let group = Rc::new(RefCell::new(vec![field]));
+                    self.groups.insert(response_key, group.clone());
+
+                    self.futures.push(Box::pin({
                         // TODO: investigate removing this
                         let ctx = ctx.clone();
                         async move {
+                            let group = group.borrow().clone();
+                            let merged_field;
+                            let field = match group.as_slice() {
+                                [field] => *field,
+                                fields => {
+                                    merged_field = merge_fields(fields);
+                                    &merged_field
+                                }
+                            };
                             let ctx_field = ctx.with_field(field);
                             let field_name = ctx_field.item.node.response_key().node.clone();
+                            ctx_field.count_resolved_field().map_err(|e| {
+                                e.at(field.pos)
+                                    .path(PathSegment::Field(field_name.to_string()))
+                            })?;
 
                             let res = if ctx_field.query_env.extensions.is_empty() {
-                                match root.resolve_field(&ctx_field).await {
+                                match resolve_field(root, &ctx_field).await {
                                     Ok(value) => Ok((field_name, value.unwrap_or_default())),
                                     Err(e) => {
                                         Err(e.path(PathSegment::Field(field_name.to_string())))
@@ -172,20 +307,18 @@ impl<'a> Fields<'a> {
                                 };
 
                                 let type_name = T::type_name();
+                                let meta_field = ctx_field
+                                    .schema_env
+                                    .registry
+                                    .types
+                                    .get(type_name.as_ref())
+                                    .and_then(|ty| ty.field_by_name(field.node.name.node.as_str()));
+                                let arguments = ctx_field.arguments().ok();
                                 let resolve_info = ResolveInfo {
                                     resolve_id: ctx_field.resolve_id,
                                     path_node: ctx_field.path_node.as_ref().unwrap(),
                                     parent_type: &type_name,
-                                    return_type: match ctx_field
-                                        .schema_env
-                                        .registry
-                                        .types
-                                        .get(type_name.as_ref())
-                                        .and_then(|ty| {
-                                            ty.field_by_name(field.node.name.node.as_str())
-                                        })
-                                        .map(|field| &field.ty)
-                                    {
+                                    return_type: match meta_field.map(|field| &field.ty) {
                                         Some(ty) => &ty,
                                         None => {
                                             return Err(ServerError::new(format!(
@@ -196,6 +329,7 @@ impl<'a> Fields<'a> {
                                             .path(PathSegment::Field(field_name.to_string())));
                                         }
                                     },
+                                    arguments: arguments.as_ref(),
                                 };
 
                                 ctx_field
@@ -203,13 +337,28 @@ impl<'a> Fields<'a> {
                                     .extensions
                                     .resolve_start(&ctx_extension, &resolve_info);
 
-                                let res = match root.resolve_field(&ctx_field).await {
+                                let res = match resolve_field(root, &ctx_field).await {
                                     Ok(value) => Ok((field_name, value.unwrap_or_default())),
                                     Err(e) => {
                                         Err(e.path(PathSegment::Field(field_name.to_string())))
                                     }
                                 }
-                                .log_error(&ctx_extension, &ctx_field.query_env.extensions)?;
+                                .log_error(&ctx_extension, &ctx_field.query_env.extensions);
+
+                                if ctx.path_node.is_none()
+                                    && ctx.query_env.operation.node.ty == OperationType::Mutation
+                                {
+                                    ctx_field.query_env.extensions.mutation_field_end(
+                                        &ctx_extension,
+                                        &MutationFieldInfo {
+                                            operation_name: ctx.query_env.operation_name.as_deref(),
+                                            field_name: &field.node.name.node,
+                                            arguments: &ctx_field.redacted_arguments(meta_field),
+                                            error: res.as_ref().err(),
+                                        },
+                                    );
+                                }
+                                let res = res?;
 
                                 ctx_field
                                     .query_env
@@ -289,3 +438,49 @@ impl<'a> Fields<'a> {
         Ok(())
     }
 }
+
+/// Resolve a field of the container, honoring the nullability designator of the field.
+///
+/// A required field (`field!`) that resolves to null is an error. An optional field (`field?`)
+/// resolves to null if there is an error, which is added to the errors of the response, so
+/// errors inside it don't propagate any further.
+async fn resolve_field<T: ContainerType>(
+    root: &T,
+    ctx: &Context<'_>,
+) -> ServerResult<Option<Value>> {
+    let res = root.resolve_field(ctx).await;
+
+    #[cfg(feature = "client_controlled_nullability")]
+    match (ctx.item.node.nullability, res) {
+        (Some(Nullability::Required), Ok(None))
+        | (Some(Nullability::Required), Ok(Some(Value::Null))) => Err(ServerError::new(format!(
+            r#"Cannot return null for required field "{}.{}"."#,
+            T::type_name(),
+            ctx.item.node.name.node
+        ))
+        .at(ctx.item.pos)),
+        (Some(Nullability::Optional), Err(err)) => {
+            ctx.add_error(err);
+            Ok(Some(Value::Null))
+        }
+        (_, res) => res,
+    }
+
+    #[cfg(not(feature = "client_controlled_nullability"))]
+    res
+}
+
+/// Merge fields with the same response key into the first one by concatenating their selection
+/// sets.
+fn merge_fields(fields: &[&Positioned<Field>]) -> Positioned<Field> {
+    let mut merged = fields[0].clone();
+    for field in &fields[1..] {
+        merged
+            .node
+            .selection_set
+            .node
+            .items
+            .extend(field.node.selection_set.node.items.iter().cloned());
+    }
+    merged
+}
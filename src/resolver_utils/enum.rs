@@ -4,6 +4,8 @@ use crate::{InputValueError, InputValueResult, InputValueType, Name, Type, Value
 pub struct EnumItem<T> {
     /// The name of the variant.
     pub name: &'static str,
+    /// Other names accepted for the variant when parsing an input value.
+    pub aliases: &'static [&'static str],
     /// The value of the variant.
     pub value: T,
 }
@@ -19,14 +21,14 @@ pub trait EnumType: Type + Sized + Eq + Copy + Sized + 'static {
 /// This can be used to implement `InputValueType::parse`.
 pub fn parse_enum<T: EnumType + InputValueType>(value: Value) -> InputValueResult<T> {
     let value = match &value {
-        Value::Enum(s) => s,
+        Value::Enum(s) => s.as_str(),
         Value::String(s) => s.as_str(),
         _ => return Err(InputValueError::expected_type(value)),
     };
 
     T::items()
         .iter()
-        .find(|item| item.name == value)
+        .find(|item| item.name == value || item.aliases.contains(&value))
         .map(|item| item.value)
         .ok_or_else(|| {
             InputValueError::custom(format_args!(
@@ -1,10 +1,16 @@
+use futures_util::stream::{LocalBoxStream, StreamExt, TryStreamExt};
+
 use crate::extensions::{ErrorLogger, ExtensionContext, ResolveInfo};
 use crate::parser::types::Field;
 use crate::{
-    ContextSelectionSet, OutputValueType, PathSegment, Positioned, ServerResult, Type, Value,
+    ContextSelectionSet, OutputValueType, PathSegment, Positioned, QueryPathNode, ServerResult,
+    Type, Value,
 };
 
 /// Resolve an list by executing each of the items concurrently.
+///
+/// At most `list_concurrency` items of the field, or of the schema if the field doesn't set it,
+/// are resolved at the same time.
 pub async fn resolve_list<'a, T: OutputValueType + 'a>(
     ctx: &ContextSelectionSet<'a>,
     field: &Positioned<Field>,
@@ -21,17 +27,18 @@ pub async fn resolve_list<'a, T: OutputValueType + 'a>(
                 query_data: &ctx.query_env.ctx_data,
             };
 
-            if ctx_idx.query_env.extensions.is_empty() {
+            let value = if ctx_idx.query_env.extensions.is_empty() {
                 OutputValueType::resolve(&item, &ctx_idx, field)
                     .await
                     .map_err(|e| e.path(PathSegment::Index(idx)))
-                    .log_error(&ctx_extension, &ctx_idx.query_env.extensions)
+                    .log_error(&ctx_extension, &ctx_idx.query_env.extensions)?
             } else {
                 let resolve_info = ResolveInfo {
                     resolve_id: ctx_idx.resolve_id,
                     path_node: ctx_idx.path_node.as_ref().unwrap(),
                     parent_type: &Vec::<T>::type_name(),
                     return_type: &T::qualified_type_name(),
+                    arguments: None,
                 };
 
                 ctx_idx
@@ -49,12 +56,99 @@ pub async fn resolve_list<'a, T: OutputValueType + 'a>(
                     .extensions
                     .resolve_end(&ctx_extension, &resolve_info);
 
-                Ok(res)
-            }
+                res
+            };
+            ctx_idx
+                .count_response_value(None, &value)
+                .map_err(|e| e.path(PathSegment::Index(idx)))?;
+            ServerResult::Ok(value)
         });
     }
 
-    Ok(Value::List(
-        futures_util::future::try_join_all(futures).await?,
-    ))
+    let list_concurrency = ctx.list_concurrency.or(ctx.schema_env.list_concurrency);
+    let items = match list_concurrency {
+        Some(list_concurrency) => {
+            futures_util::stream::iter(futures)
+                .buffered(list_concurrency.max(1))
+                .try_collect()
+                .await?
+        }
+        None => futures_util::future::try_join_all(futures).await?,
+    };
+    Ok(Value::List(items))
+}
+
+/// Resolve a list from a stream of items.
+///
+/// If the field has a `@stream` directive and the query is executed incrementally, only the
+/// initial items are resolved, the rest of the stream is delivered in subsequent payloads.
+/// Otherwise the whole stream is collected and resolved with `resolve_list`.
+pub async fn resolve_list_stream<'a, T: OutputValueType + 'static>(
+    ctx: &ContextSelectionSet<'a>,
+    field: &Positioned<Field>,
+    mut stream: LocalBoxStream<'static, T>,
+) -> ServerResult<Value> {
+    let directive = ctx.stream_directive(&field.node.directives)?;
+    let (streams, (initial_count, label)) = match (&ctx.query_env.streams, directive) {
+        (Some(streams), Some(directive)) => (streams, directive),
+        _ => {
+            let items = stream.collect::<Vec<_>>().await;
+            return resolve_list(ctx, field, &items, Some(items.len())).await;
+        }
+    };
+
+    let items = stream
+        .by_ref()
+        .take(initial_count)
+        .collect::<Vec<_>>()
+        .await;
+    let value = resolve_list(ctx, field, &items, Some(items.len())).await?;
+    if items.len() == initial_count {
+        streams.lock().push(StreamedList {
+            label,
+            path: ctx
+                .path_node
+                .as_ref()
+                .map(QueryPathNode::to_path)
+                .unwrap_or_default(),
+            field: field.clone(),
+            next_index: initial_count,
+            items: Box::new(stream),
+        });
+    }
+    Ok(value)
+}
+
+/// The rest of a list with a `@stream` directive, that is delivered after the initial response.
+#[doc(hidden)]
+pub struct StreamedList {
+    pub(crate) label: Option<String>,
+    pub(crate) path: Vec<PathSegment>,
+    pub(crate) field: Positioned<Field>,
+    pub(crate) next_index: usize,
+    pub(crate) items: Box<dyn StreamedItems>,
+}
+
+/// A stream of items of any output type.
+#[doc(hidden)]
+#[async_trait::async_trait(?Send)]
+pub trait StreamedItems {
+    /// Pull the next item from the stream and resolve it.
+    async fn resolve_next(
+        &mut self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> Option<ServerResult<Value>>;
+}
+
+#[async_trait::async_trait(?Send)]
+impl<T: OutputValueType + 'static> StreamedItems for LocalBoxStream<'static, T> {
+    async fn resolve_next(
+        &mut self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> Option<ServerResult<Value>> {
+        let item = self.next().await?;
+        Some(OutputValueType::resolve(&item, ctx, field).await)
+    }
 }
@@ -1,12 +1,16 @@
 //! Utilities for implementing
 //! [`OutputValueType::resolve`](trait.OutputValueType.html#tymethod.resolve).
 
+mod circuit_breaker;
 mod container;
 mod r#enum;
 mod list;
+mod retry;
 mod scalar;
 
+pub use circuit_breaker::*;
 pub use container::*;
 pub use list::*;
 pub use r#enum::*;
+pub use retry::*;
 pub use scalar::*;
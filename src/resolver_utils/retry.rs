@@ -0,0 +1,162 @@
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use crate::{Context, Error, ErrorExtensions, Result};
+
+/// How the delay between the attempts of a [`RetryPolicy`](struct.RetryPolicy.html) grows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backoff {
+    /// The same delay before each attempt.
+    Constant,
+    /// The delay doubles after each attempt.
+    Exponential,
+}
+
+/// A policy to retry a resolver that fails, used by the `retry` parameter of the fields of
+/// `#[Object]` and by [`retry`](fn.retry.html).
+///
+/// Only retry resolvers that are idempotent, e.g. reads from a flaky service. By default, all
+/// errors are retried with an exponential backoff starting at 100 milliseconds.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    attempts: usize,
+    backoff: Backoff,
+    delay: Duration,
+    predicate: Option<fn(&Error) -> bool>,
+}
+
+impl RetryPolicy {
+    /// Creates a policy that makes at most `attempts` attempts, an `attempts` of 0 is treated
+    /// as 1.
+    pub fn new(attempts: usize) -> Self {
+        Self {
+            attempts: attempts.max(1),
+            backoff: Backoff::Exponential,
+            delay: Duration::from_millis(100),
+            predicate: None,
+        }
+    }
+
+    /// Set how the delay between the attempts grows.
+    #[must_use]
+    pub fn backoff(self, backoff: Backoff) -> Self {
+        Self { backoff, ..self }
+    }
+
+    /// Set the delay before the second attempt.
+    #[must_use]
+    pub fn delay(self, delay: Duration) -> Self {
+        Self { delay, ..self }
+    }
+
+    /// Only retry the errors for which `predicate` returns `true`.
+    #[must_use]
+    pub fn predicate(self, predicate: fn(&Error) -> bool) -> Self {
+        Self {
+            predicate: Some(predicate),
+            ..self
+        }
+    }
+
+    /// The delay before the attempt following the attempt `attempt`, counted from 1, with a
+    /// random jitter of up to half of it so that retries of concurrent requests spread out.
+    fn delay_after(&self, attempt: usize) -> Duration {
+        let delay = match self.backoff {
+            Backoff::Constant => self.delay,
+            Backoff::Exponential => self
+                .delay
+                .checked_mul(2u32.saturating_pow(attempt as u32 - 1))
+                .unwrap_or(Duration::MAX),
+        };
+        delay / 2 + delay.mul_f64(random() / 2.0)
+    }
+}
+
+/// A random number in `[0, 1)`, the standard library seeds each `RandomState` differently.
+fn random() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u8(0);
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Call `f` until it succeeds, fails with an error that doesn't match the predicate of the
+/// policy, or has been called as many times as the policy allows.
+///
+/// The attempts are separated by the timer set with
+/// [`SchemaBuilder::timer`](../struct.SchemaBuilder.html#method.timer). The final error has the
+/// number of attempts made in its `attempts` extension.
+///
+/// # Panics
+///
+/// Panics if an attempt is retried after a delay and the schema has no timer, a policy without
+/// delay, i.e. with `RetryPolicy::delay(Duration::from_secs(0))`, doesn't need one.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use async_graphql::resolver_utils::{retry, RetryPolicy};
+/// use std::cell::Cell;
+/// use std::time::Duration;
+///
+/// #[derive(Default)]
+/// struct Query {
+///     calls: Cell<i32>,
+/// }
+///
+/// #[Object]
+/// impl Query {
+///     async fn value(&self, ctx: &Context<'_>) -> Result<i32> {
+///         let policy = RetryPolicy::new(3).delay(Duration::from_secs(0));
+///         retry(ctx, &policy, || async {
+///             self.calls.set(self.calls.get() + 1);
+///             if self.calls.get() < 3 {
+///                 Err("Unavailable".into())
+///             } else {
+///                 Ok(self.calls.get())
+///             }
+///         })
+///         .await
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(Query::default(), EmptyMutation, EmptySubscription);
+///     let res = schema.execute("{ value }").await.into_result().unwrap().data;
+///     assert_eq!(res, value!({ "value": 3 }));
+/// });
+/// ```
+pub async fn retry<T, F, Fut>(ctx: &Context<'_>, policy: &RetryPolicy, mut f: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut attempt = 1;
+    loop {
+        let err = match f().await {
+            Ok(value) => return Ok(value),
+            Err(err) => err,
+        };
+        let retryable = match policy.predicate {
+            Some(predicate) => predicate(&err),
+            None => true,
+        };
+        if !retryable || attempt >= policy.attempts {
+            return Err(err.extend_with(|_, e| e.set("attempts", attempt as i32)));
+        }
+        let delay = policy.delay_after(attempt);
+        if delay > Duration::from_secs(0) {
+            ctx.schema_env
+                .timer
+                .as_ref()
+                .expect(
+                    "Retrying after a delay requires a timer, set one with SchemaBuilder::timer",
+                )
+                .sleep(delay)
+                .await;
+        }
+        attempt += 1;
+    }
+}
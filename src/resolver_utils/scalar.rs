@@ -93,7 +93,11 @@ pub trait ScalarType: Sized {
 #[macro_export]
 macro_rules! scalar {
     ($ty:ty, $name:literal, $desc:literal) => {
-        $crate::scalar_internal!($ty, $name, ::std::option::Option::Some($desc));
+        $crate::scalar_internal!(
+            $ty,
+            $name,
+            ::std::option::Option::Some(::std::string::ToString::to_string($desc))
+        );
     };
 
     ($ty:ty, $name:literal) => {
@@ -121,6 +125,7 @@ macro_rules! scalar_internal {
                     name: ::std::borrow::ToOwned::to_owned($name),
                     description: $desc,
                     is_valid: |value| <$ty as $crate::ScalarType>::is_valid(value),
+                    specified_by_url: ::std::option::Option::None,
                 })
             }
         }
@@ -1,9 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{CacheControl, Result, ServerError, Value};
+use crate::{CacheControl, Map, Name, PathSegment, Result, ServerError, Value};
 
 /// Query response
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct Response {
     /// Data of query result
     #[serde(default)]
@@ -20,6 +20,15 @@ pub struct Response {
     /// Errors
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub errors: Vec<ServerError>,
+
+    /// The id of the event of a subscription, set with `SubscriptionStreamExt::event_ids`
+    #[serde(skip)]
+    pub event_id: Option<String>,
+
+    /// Whether the response only keeps the connection of a subscription alive, see
+    /// `SubscriptionMessage::KeepAlive`.
+    #[serde(skip)]
+    pub keep_alive: bool,
 }
 
 impl Response {
@@ -47,6 +56,29 @@ impl Response {
         Self { extensions, ..self }
     }
 
+    /// Add the warnings to the `warnings` extension of the response.
+    pub(crate) fn warnings(self, warnings: Vec<ServerError>) -> Self {
+        if warnings.is_empty() {
+            return self;
+        }
+        self.extension("warnings", crate::to_value(warnings).unwrap_or_default())
+    }
+
+    /// Set the extension `name` of the response.
+    pub(crate) fn extension(mut self, name: &str, value: Value) -> Self {
+        match &mut self.extensions {
+            Some(Value::Object(extensions)) => {
+                extensions.insert(Name::new(name), value);
+            }
+            extensions => {
+                let mut map = Map::new();
+                map.insert(Name::new(name), value);
+                *extensions = Some(Value::Object(map));
+            }
+        }
+        self
+    }
+
     /// Set the cache control of the response.
     #[must_use]
     pub fn cache_control(self, cache_control: CacheControl) -> Self {
@@ -123,6 +155,47 @@ impl From<Vec<Response>> for BatchResponse {
     }
 }
 
+/// A payload of an incremental response, see
+/// [`Schema::execute_incremental`](struct.Schema.html#method.execute_incremental).
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum IncrementalResponse {
+    /// The response to the query, without the items that are delivered later.
+    #[serde(rename_all = "camelCase")]
+    Initial {
+        /// The response.
+        #[serde(flatten)]
+        response: Response,
+        /// Whether more payloads follow.
+        has_next: bool,
+    },
+
+    /// An item of a list with a `@stream` directive.
+    #[serde(rename_all = "camelCase")]
+    Items {
+        /// The item, or `null` if it couldn't be resolved.
+        items: Option<Vec<Value>>,
+        /// The path of the item, ending with its index in the list.
+        path: Vec<PathSegment>,
+        /// The label of the `@stream` directive.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        label: Option<String>,
+        /// Errors
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        errors: Vec<ServerError>,
+        /// Whether more payloads follow, always `true` since a stream can only be known to have
+        /// ended after its last item.
+        has_next: bool,
+    },
+
+    /// The last payload, sent once all the streams have ended.
+    #[serde(rename_all = "camelCase")]
+    Completed {
+        /// Whether more payloads follow, always `false`.
+        has_next: bool,
+    },
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,215 @@
+//! Timers and task spawning of the async runtime
+//!
+//! The crate doesn't depend on an async runtime. The features that wait, such as the retries of
+//! [`retry`](../resolver_utils/fn.retry.html) or the keep-alive messages of
+//! [`WebSocket`](../http/struct.WebSocket.html), use the [`Timer`](trait.Timer.html) set with
+//! [`SchemaBuilder::timer`](../struct.SchemaBuilder.html#method.timer).
+//!
+//! An implementation of [`Timer`](trait.Timer.html) and [`Spawner`](trait.Spawner.html) is
+//! provided for each of the supported runtimes, enabled by the feature of the same name:
+//!
+//! - `tokio`: [`TokioRuntime`](struct.TokioRuntime.html)
+//! - `async-std`: [`AsyncStdRuntime`](struct.AsyncStdRuntime.html)
+//! - `smol`: [`SmolRuntime`](struct.SmolRuntime.html)
+//! - `wasm`: [`WasmRuntime`](struct.WasmRuntime.html), for `wasm32-unknown-unknown` in a
+//!   browser or another JavaScript host.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use async_graphql::*;
+//! use async_graphql::runtime::Timer;
+//! use futures_util::future::{FutureExt, LocalBoxFuture};
+//! use std::time::{Duration, Instant};
+//!
+//! struct AsyncStdTimer(Instant);
+//!
+//! impl Timer for AsyncStdTimer {
+//!     fn sleep(&self, duration: Duration) -> LocalBoxFuture<'static, ()> {
+//!         async_std::task::sleep(duration).boxed_local()
+//!     }
+//!
+//!     fn now(&self) -> Duration {
+//!         self.0.elapsed()
+//!     }
+//! }
+//!
+//! struct Query;
+//!
+//! #[Object]
+//! impl Query {
+//!     async fn value(&self) -> i32 {
+//!         10
+//!     }
+//! }
+//!
+//! let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+//!     .timer(AsyncStdTimer(Instant::now()))
+//!     .finish();
+//! ```
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::future::LocalBoxFuture;
+use futures_util::stream::{self, LocalBoxStream, StreamExt};
+
+/// Runs futures in the background.
+///
+/// The futures don't need to be `Send`, they run on the thread that spawns them.
+pub trait Spawner: Send + Sync + 'static {
+    /// Run `future` to completion in the background.
+    fn spawn(&self, future: LocalBoxFuture<'static, ()>);
+}
+
+/// The timer and the clock of the runtime.
+pub trait Timer: Send + Sync + 'static {
+    /// A future that completes after `duration`.
+    fn sleep(&self, duration: Duration) -> LocalBoxFuture<'static, ()>;
+
+    /// The time elapsed since a fixed point, such as the creation of the timer, it shouldn't
+    /// decrease.
+    ///
+    /// It is used instead of `std::time::Instant::now`, which panics on
+    /// `wasm32-unknown-unknown`.
+    fn now(&self) -> Duration;
+}
+
+/// A stream that yields every `period`, the first time after `period`.
+pub(crate) fn interval(timer: Arc<dyn Timer>, period: Duration) -> LocalBoxStream<'static, ()> {
+    stream::unfold(timer, move |timer| async move {
+        timer.sleep(period).await;
+        Some(((), timer))
+    })
+    .boxed_local()
+}
+
+#[cfg(any(feature = "tokio", feature = "async-std", feature = "smol"))]
+fn elapsed() -> Duration {
+    use once_cell::sync::Lazy;
+    use std::time::Instant;
+
+    static START: Lazy<Instant> = Lazy::new(Instant::now);
+    START.elapsed()
+}
+
+/// The [`tokio`](https://crates.io/crates/tokio) runtime.
+///
+/// The futures are spawned with `tokio::task::spawn_local`, so they must be spawned from a
+/// `tokio::task::LocalSet`.
+#[cfg(feature = "tokio")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "tokio")))]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TokioRuntime;
+
+#[cfg(feature = "tokio")]
+impl Spawner for TokioRuntime {
+    fn spawn(&self, future: LocalBoxFuture<'static, ()>) {
+        tokio::task::spawn_local(future);
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Timer for TokioRuntime {
+    fn sleep(&self, duration: Duration) -> LocalBoxFuture<'static, ()> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+
+    fn now(&self) -> Duration {
+        elapsed()
+    }
+}
+
+/// The [`async-std`](https://crates.io/crates/async-std) runtime.
+#[cfg(feature = "async-std")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "async-std")))]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AsyncStdRuntime;
+
+#[cfg(feature = "async-std")]
+impl Spawner for AsyncStdRuntime {
+    fn spawn(&self, future: LocalBoxFuture<'static, ()>) {
+        async_std::task::spawn_local(future);
+    }
+}
+
+#[cfg(feature = "async-std")]
+impl Timer for AsyncStdRuntime {
+    fn sleep(&self, duration: Duration) -> LocalBoxFuture<'static, ()> {
+        Box::pin(async_std::task::sleep(duration))
+    }
+
+    fn now(&self) -> Duration {
+        elapsed()
+    }
+}
+
+#[cfg(feature = "smol")]
+thread_local! {
+    static SMOL_EXECUTOR: smol::LocalExecutor<'static> = smol::LocalExecutor::new();
+}
+
+/// The [`smol`](https://crates.io/crates/smol) runtime.
+///
+/// The futures are spawned on an executor of the current thread, which runs while a future is
+/// run with [`SmolRuntime::block_on`](#method.block_on).
+#[cfg(feature = "smol")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "smol")))]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SmolRuntime;
+
+#[cfg(feature = "smol")]
+impl SmolRuntime {
+    /// Run `future` to completion on the current thread, and the futures spawned by this thread
+    /// meanwhile.
+    pub fn block_on<T>(future: impl std::future::Future<Output = T>) -> T {
+        SMOL_EXECUTOR.with(|executor| smol::block_on(executor.run(future)))
+    }
+}
+
+#[cfg(feature = "smol")]
+impl Spawner for SmolRuntime {
+    fn spawn(&self, future: LocalBoxFuture<'static, ()>) {
+        SMOL_EXECUTOR.with(|executor| executor.spawn(future).detach());
+    }
+}
+
+#[cfg(feature = "smol")]
+impl Timer for SmolRuntime {
+    fn sleep(&self, duration: Duration) -> LocalBoxFuture<'static, ()> {
+        Box::pin(async move {
+            smol::Timer::after(duration).await;
+        })
+    }
+
+    fn now(&self) -> Duration {
+        elapsed()
+    }
+}
+
+/// The runtime of `wasm32-unknown-unknown`, the futures are spawned with
+/// [`wasm-bindgen-futures`](https://crates.io/crates/wasm-bindgen-futures), the timer is
+/// `setTimeout` and the clock is `Date.now()`.
+#[cfg(feature = "wasm")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "wasm")))]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WasmRuntime;
+
+#[cfg(feature = "wasm")]
+impl Spawner for WasmRuntime {
+    fn spawn(&self, future: LocalBoxFuture<'static, ()>) {
+        wasm_bindgen_futures::spawn_local(future);
+    }
+}
+
+#[cfg(feature = "wasm")]
+impl Timer for WasmRuntime {
+    fn sleep(&self, duration: Duration) -> LocalBoxFuture<'static, ()> {
+        let millis = duration.as_millis().min(u32::MAX as u128) as u32;
+        Box::pin(gloo_timers::future::TimeoutFuture::new(millis))
+    }
+
+    fn now(&self) -> Duration {
+        Duration::from_secs_f64(js_sys::Date::now() / 1000.0)
+    }
+}
@@ -1,39 +1,73 @@
 use std::any::Any;
-use std::collections::BTreeMap;
+use std::collections::{HashMap, VecDeque};
 use std::ops::Deref;
+use std::panic::AssertUnwindSafe;
+use std::rc::Rc;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
+use std::time::Duration;
 
-use futures_util::stream::{self, Stream, StreamExt};
+use futures_util::stream::{self, LocalBoxStream, Stream, StreamExt};
 use indexmap::map::IndexMap;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 
 use crate::context::{Data, QueryEnvInner, ResolveId};
 use crate::extensions::{ErrorLogger, ExtensionContext, ExtensionFactory, Extensions};
+use crate::health::{HealthCheck, HealthStatus};
+use crate::idempotency::{self, Idempotency, IdempotencyKeyFn, IdempotencyStore, StoredResponse};
 use crate::model::__DirectiveLocation;
 use crate::parser::parse_query;
-use crate::parser::types::{DocumentOperations, OperationType};
-use crate::registry::{MetaDirective, MetaInputValue, Registry};
+use crate::parser::types::{
+    DocumentOperations, ExecutableDocument, OperationDefinition, OperationType, Selection,
+};
+use crate::registry::{
+    IntrospectedInputType, MetaDirective, MetaInputValue, Registry, SchemaView, TagFilter,
+};
 use crate::resolver_utils::{resolve_container, resolve_container_serial};
+use crate::runtime::Timer;
+use crate::schema_registry::{PublishError, SchemaMetadata, SchemaRegistry};
+use crate::shared_subscription::{SharedSubscriptions, SubscriptionKeyFn};
 use crate::subscription::collect_subscription_streams;
-use crate::types::QueryRoot;
-use crate::validation::{check_rules, CheckResult, ValidationMode};
+use crate::trusted_documents::{TrustedDocument, TrustedDocuments};
+use crate::types::{register_scalar, QueryRoot, SubscriptionFrame};
+use crate::validation::{
+    check_rules, collect_type_usage, CheckResult, QueryAnalysis, SpecVersion, ValidationMode,
+};
 use crate::{
-    BatchRequest, BatchResponse, CacheControl, ContextBase, ObjectType, QueryEnv, Request,
-    Response, ServerError, SubscriptionType, Type, Value, ID,
+    from_value, to_value, BatchRequest, BatchResponse, CacheControl, ContextBase,
+    IncrementalResponse, InputValueError, Int64Representation, Map, Name, ObjectType, PathSegment,
+    Positioned, QueryEnv, Request, Response, RuntimeScalar, ServerError, ServerResult,
+    SubscriptionType, Type, Value, Variables, ID,
 };
 
+type RegistryOp = dyn FnOnce(&mut Registry);
+
 /// Schema builder
 pub struct SchemaBuilder<Query, Mutation, Subscription> {
     validation_mode: ValidationMode,
     query: QueryRoot<Query>,
     mutation: Mutation,
     subscription: Subscription,
-    registry: Registry,
+    registry_ops: Vec<Box<RegistryOp>>,
     data: Data,
     complexity: Option<usize>,
     depth: Option<usize>,
+    max_response_nodes: Option<usize>,
+    max_response_bytes: Option<usize>,
+    max_resolved_fields: Option<usize>,
     extensions: Vec<Box<dyn ExtensionFactory>>,
     enable_federation: bool,
+    enable_metadata_introspection: bool,
+    hide_forbidden_fields: bool,
+    spec_version: SpecVersion,
+    list_concurrency: Option<usize>,
+    int64_representation: Int64Representation,
+    timer: Option<Arc<dyn Timer>>,
+    idempotency: Option<(Box<dyn IdempotencyStore>, Duration)>,
+    idempotency_key: Option<Box<IdempotencyKeyFn>>,
+    trusted_documents: TrustedDocuments,
+    shared_subscriptions: Option<Box<SubscriptionKeyFn>>,
 }
 
 impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription> {
@@ -41,10 +75,30 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
     ///
     /// You can use this function to register schema types that are not directly referenced.
     pub fn register_type<T: Type>(mut self) -> Self {
-        T::create_type_info(&mut self.registry);
+        let create_type_info: fn(&mut Registry) -> String = T::create_type_info;
+        self.registry_ops.push(Box::new(move |registry| {
+            create_type_info(registry);
+        }));
         self
     }
 
+    /// Register a scalar for a type that can't implement `ScalarType`, e.g. a type of another
+    /// crate, used in the schema as a [`RuntimeScalar<T>`](struct.RuntimeScalar.html).
+    ///
+    /// `parse` converts an input value to `T`, `to_value` converts `T` to an output value and
+    /// `is_valid` checks the input values during validation. The scalar is registered in the
+    /// schema even if no field uses it.
+    pub fn register_scalar<T: 'static>(
+        self,
+        name: impl Into<String>,
+        parse: fn(Value) -> Result<T, InputValueError<RuntimeScalar<T>>>,
+        to_value: fn(&T) -> Value,
+        is_valid: fn(&Value) -> bool,
+    ) -> Self {
+        register_scalar(name.into(), parse, to_value, is_valid);
+        self.register_type::<RuntimeScalar<T>>()
+    }
+
     /// Disable introspection queries.
     pub fn disable_introspection(mut self) -> Self {
         self.query.disable_introspection = true;
@@ -63,6 +117,40 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
         self
     }
 
+    /// Set the maximum number of values a response can have, counting every field and every
+    /// item of a list. By default there is no limit.
+    ///
+    /// The execution fails with a `RESPONSE_TOO_LARGE` error as soon as the limit is exceeded,
+    /// e.g. by a list that is much longer than expected.
+    pub fn limit_response_nodes(mut self, nodes: usize) -> Self {
+        self.max_response_nodes = Some(nodes);
+        self
+    }
+
+    /// Set the maximum size in bytes of the data of a response, as compact JSON. By default there
+    /// is no limit.
+    ///
+    /// The size is estimated as the fields are resolved, overestimating it by a byte per object
+    /// and list, and the execution fails with a `RESPONSE_TOO_LARGE` error as soon as the limit
+    /// is exceeded. The size of the fields that are resolved but then dropped, e.g. because of an
+    /// error in a sibling field, is counted too.
+    pub fn limit_response_bytes(mut self, bytes: usize) -> Self {
+        self.max_response_bytes = Some(bytes);
+        self
+    }
+
+    /// Set the maximum number of fields a request can resolve, counting every field of every
+    /// item of the lists. By default there is no limit.
+    ///
+    /// Unlike the complexity, which is computed before the execution, this is counted as the
+    /// fields are resolved, and the execution fails with a `FIELD_BUDGET_EXCEEDED` error before
+    /// the first field over the limit is resolved, e.g. when a list is much longer than its
+    /// complexity assumes.
+    pub fn limit_resolved_fields(mut self, fields: usize) -> Self {
+        self.max_resolved_fields = Some(fields);
+        self
+    }
+
     /// Add an extension to the schema.
     ///
     /// # Examples
@@ -94,32 +182,167 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
         self
     }
 
+    /// Set the maximum number of items of a list that are resolved concurrently, the items are
+    /// still returned in order. By default there is no limit, and a limit of 0 is treated as 1.
+    ///
+    /// Fields can override this with `#[graphql(list_concurrency = 16)]`.
+    pub fn list_concurrency(mut self, list_concurrency: usize) -> Self {
+        self.list_concurrency = Some(list_concurrency);
+        self
+    }
+
+    /// Set how the `Int64` and `UInt64` scalars are output, default is
+    /// `Int64Representation::Number`.
+    pub fn int64_representation(mut self, representation: Int64Representation) -> Self {
+        self.int64_representation = representation;
+        self
+    }
+
+    /// Set the timer of the async runtime, see the [`runtime`](runtime/index.html) module. It is
+    /// used to wait between the attempts of the resolvers that are retried, see
+    /// [`RetryPolicy`](resolver_utils/struct.RetryPolicy.html), by the timed combinators of
+    /// [`SubscriptionStreamExt`](trait.SubscriptionStreamExt.html) and by the keep-alive messages
+    /// of [`WebSocket`](http/struct.WebSocket.html).
+    pub fn timer(mut self, timer: impl Timer) -> Self {
+        self.timer = Some(Arc::new(timer));
+        self
+    }
+
     /// Set the validation mode, default is `ValidationMode::Strict`.
     pub fn validation_mode(mut self, validation_mode: ValidationMode) -> Self {
         self.validation_mode = validation_mode;
         self
     }
 
+    /// Set the edition of the GraphQL specification the schema follows, default is
+    /// `SpecVersion::June2018`.
+    pub fn spec_version(mut self, spec_version: SpecVersion) -> Self {
+        self.spec_version = spec_version;
+        self
+    }
+
+    /// Replay the response of a mutation sent again with the same `idempotencyKey` request
+    /// extension within `ttl`, instead of executing it again. See the
+    /// [`idempotency`](idempotency/index.html) module.
+    pub fn idempotency_store(mut self, store: impl IdempotencyStore, ttl: Duration) -> Self {
+        self.idempotency = Some((Box::new(store), ttl));
+        self
+    }
+
+    /// Store the responses of the mutations under the key returned by `key` instead of the
+    /// `idempotencyKey` request extension, e.g. the extension prefixed with the id of the current
+    /// user so that the keys of different users don't collide. The requests for which it returns
+    /// `None` are executed without idempotency.
+    pub fn idempotency_key_by(
+        mut self,
+        key: impl Fn(&Request) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.idempotency_key = Some(Box::new(key));
+        self
+    }
+
+    /// Register documents that can be executed by their hash with `Schema::execute_persisted`.
+    /// See the [`trusted_documents`](trusted_documents/index.html) module.
+    pub fn trusted_documents(mut self, documents: TrustedDocuments) -> Self {
+        self.trusted_documents.documents.extend(documents.documents);
+        self
+    }
+
+    /// Execute a subscription once for all the requests for which `key` returns the same key
+    /// and that run at the same time, each of its responses is sent to all of them. The
+    /// subscriptions of the requests for which it returns `None` are not shared.
+    ///
+    /// `key` is called with the request and the data of the context, i.e. the data of the
+    /// request, or of the connection for a [`WebSocket`](http/struct.WebSocket.html). The
+    /// subscription is executed with the data of
+    /// the first of these requests, and a request joining it only receives the responses that
+    /// follow, so the key must tell apart the requests whose responses differ, e.g. by including
+    /// the query, the variables and the current user.
+    pub fn share_subscriptions_by(
+        mut self,
+        key: impl Fn(&Request, &Data) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.shared_subscriptions = Some(Box::new(key));
+        self
+    }
+
     /// Enable federation, which is automatically enabled if the Query has least one entity definition.
     pub fn enable_federation(mut self) -> Self {
         self.enable_federation = true;
         self
     }
 
+    /// Add the `__metadata(coordinate: String!)` root field returning the key/value pairs
+    /// attached with the `meta` attribute to the type or the field at a schema coordinate, e.g.
+    /// `User` or `User.name`.
+    ///
+    /// Like `__schema`, the field is hidden from introspection and is disabled by
+    /// [`disable_introspection`](#method.disable_introspection).
+    pub fn enable_metadata_introspection(mut self) -> Self {
+        self.enable_metadata_introspection = true;
+        self
+    }
+
+    /// Report guard failures as unknown fields, with the same message that the validator uses
+    /// for fields that don't exist, so that clients can't find out about fields they may not
+    /// access.
+    pub fn hide_forbidden_fields(mut self) -> Self {
+        self.hide_forbidden_fields = true;
+        self
+    }
+
     /// Override the description of the specified type.
-    pub fn override_description<T: Type>(mut self, desc: &'static str) -> Self {
-        self.registry.set_description::<T>(desc);
+    pub fn override_description<T: Type>(mut self, desc: impl Into<String>) -> Self {
+        let set_description: fn(&mut Registry, String) =
+            |registry, desc| registry.set_description::<T>(desc);
+        let desc = desc.into();
+        self.registry_ops
+            .push(Box::new(move |registry| set_description(registry, desc)));
         self
     }
+}
 
+impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
+where
+    Query: ObjectType + 'static,
+    Mutation: ObjectType + 'static,
+    Subscription: SubscriptionType + 'static,
+{
     /// Build schema.
-    pub fn finish(mut self) -> Schema<Query, Mutation, Subscription> {
+    ///
+    /// # Panics
+    ///
+    /// Panics if an object doesn't have a field compatible with an interface field that is
+    /// resolved by the objects implementing the interface, or if the possible types of an
+    /// interface with `auto_fields` have a field with different types or arguments, or if the
+    /// parts of a merged object define fields with the same name.
+    ///
+    /// Also panics if a trusted document is invalid or doesn't contain exactly one operation.
+    pub fn finish(self) -> Schema<Query, Mutation, Subscription> {
+        // The types are created once all the scalars are registered.
+        let mut registry = Schema::<Query, Mutation, Subscription>::create_registry();
+        for op in self.registry_ops {
+            op(&mut registry);
+        }
+
+        if let Err(err) = registry.apply_auto_interface_fields() {
+            panic!("{}", err);
+        }
+        if let Err(err) = registry.check_object_resolved_fields() {
+            panic!("{}", err);
+        }
+
         // federation
-        if self.enable_federation || self.registry.has_entities() {
-            self.registry.create_federation_types();
+        if self.enable_federation || registry.has_entities() {
+            registry.create_federation_types();
+        }
+        if self.enable_metadata_introspection {
+            registry.create_metadata_introspection_types();
         }
+        registry.apply_inherited_descriptions();
+        registry.apply_spec_version(self.spec_version);
 
-        Schema(Arc::new(SchemaInner {
+        let mut inner = SchemaInner {
             validation_mode: self.validation_mode,
             query: self.query,
             mutation: self.mutation,
@@ -127,18 +350,76 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
             complexity: self.complexity,
             depth: self.depth,
             extensions: self.extensions,
-            env: SchemaEnv(Arc::new(SchemaEnvInner {
-                registry: self.registry,
-                data: self.data,
-            })),
-        }))
+            idempotency: {
+                let key = self.idempotency_key;
+                self.idempotency.map(|(store, ttl)| Idempotency {
+                    store,
+                    ttl,
+                    key: key.unwrap_or_else(|| Box::new(idempotency::default_key)),
+                })
+            },
+            trusted_documents: Default::default(),
+            shared_subscriptions: self.shared_subscriptions.map(SharedSubscriptions::new),
+        };
+        for (name, source) in &self.trusted_documents.documents {
+            match inner.prepare_trusted_document(&registry, source) {
+                Ok(document) => {
+                    inner
+                        .trusted_documents
+                        .insert(TrustedDocuments::hash(source), document);
+                }
+                Err(errors) => panic!(
+                    "Invalid trusted document `{}`: {}",
+                    name,
+                    errors
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            }
+        }
+
+        Schema {
+            inner: Arc::new(inner),
+            env: SchemaEnv::new(SchemaEnvInner {
+                registry,
+                data: Rc::new(self.data),
+                hide_forbidden_fields: self.hide_forbidden_fields,
+                spec_version: self.spec_version,
+                list_concurrency: self.list_concurrency,
+                int64_representation: self.int64_representation,
+                max_response_nodes: self.max_response_nodes,
+                max_response_bytes: self.max_response_bytes,
+                max_resolved_fields: self.max_resolved_fields,
+                timer: self.timer,
+                warm_documents: Default::default(),
+            }),
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct SchemaEnvInner {
     pub registry: Registry,
-    pub data: Data,
+    pub data: Rc<Data>,
+    pub hide_forbidden_fields: bool,
+    pub spec_version: SpecVersion,
+    pub list_concurrency: Option<usize>,
+    pub int64_representation: Int64Representation,
+    pub max_response_nodes: Option<usize>,
+    pub max_response_bytes: Option<usize>,
+    pub max_resolved_fields: Option<usize>,
+    pub timer: Option<Arc<dyn Timer>>,
+    pub(crate) warm_documents: spin::Mutex<HashMap<String, WarmDocument>>,
+}
+
+/// A document parsed and validated by `Schema::warm_up`.
+pub(crate) struct WarmDocument {
+    document: ExecutableDocument,
+    cache_control: CacheControl,
+    /// The response of the single introspection query of the document, and its name.
+    introspection: Option<(Option<Name>, Response)>,
 }
 
 #[doc(hidden)]
@@ -153,6 +434,12 @@ impl Deref for SchemaEnv {
     }
 }
 
+impl SchemaEnv {
+    pub(crate) fn new(inner: SchemaEnvInner) -> SchemaEnv {
+        SchemaEnv(Arc::new(inner))
+    }
+}
+
 #[doc(hidden)]
 pub struct SchemaInner<Query, Mutation, Subscription> {
     pub(crate) validation_mode: ValidationMode,
@@ -162,17 +449,78 @@ pub struct SchemaInner<Query, Mutation, Subscription> {
     pub(crate) complexity: Option<usize>,
     pub(crate) depth: Option<usize>,
     pub(crate) extensions: Vec<Box<dyn ExtensionFactory>>,
-    pub(crate) env: SchemaEnv,
+    pub(crate) idempotency: Option<Idempotency>,
+    pub(crate) trusted_documents: HashMap<String, TrustedDocument>,
+    pub(crate) shared_subscriptions: Option<SharedSubscriptions>,
+}
+
+impl<Query, Mutation, Subscription> SchemaInner<Query, Mutation, Subscription> {
+    fn check_limits(&self, complexity: usize, depth: usize) -> Result<(), ServerError> {
+        if let Some(limit_complexity) = self.complexity {
+            if complexity > limit_complexity {
+                return Err(ServerError::new("Query is too complex."));
+            }
+        }
+
+        if let Some(limit_depth) = self.depth {
+            if depth > limit_depth {
+                return Err(ServerError::new("Query is nested too deep."));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parse a document and check its rules and limits.
+    fn check_document(
+        &self,
+        registry: &Registry,
+        query: &str,
+    ) -> Result<(ExecutableDocument, CacheControl), Vec<ServerError>> {
+        let document = parse_query(query).map_err(|e| vec![ServerError::from(e)])?;
+        let CheckResult {
+            cache_control,
+            complexity,
+            depth,
+        } = check_rules(registry, &document, None, self.validation_mode)?;
+        self.check_limits(complexity, depth).map_err(|e| vec![e])?;
+        Ok((document, cache_control))
+    }
+
+    fn prepare_trusted_document(
+        &self,
+        registry: &Registry,
+        source: &str,
+    ) -> Result<TrustedDocument, Vec<ServerError>> {
+        #[allow(unused_mut)]
+        let (mut document, cache_control) = self.check_document(registry, source)?;
+        #[cfg(feature = "fragment_arguments")]
+        crate::fragment_arguments::inline_fragment_arguments(&mut document);
+        let (operation_name, operation) =
+            select_operation(document.operations, None).map_err(|e| vec![e])?;
+        Ok(TrustedDocument {
+            operation_name,
+            operation,
+            fragments: document.fragments,
+            cache_control,
+        })
+    }
 }
 
 /// GraphQL schema.
 ///
 /// Cloning a schema is cheap, so it can be easily shared.
-pub struct Schema<Query, Mutation, Subscription>(Arc<SchemaInner<Query, Mutation, Subscription>>);
+pub struct Schema<Query, Mutation, Subscription> {
+    inner: Arc<SchemaInner<Query, Mutation, Subscription>>,
+    pub(crate) env: SchemaEnv,
+}
 
 impl<Query, Mutation, Subscription> Clone for Schema<Query, Mutation, Subscription> {
     fn clone(&self) -> Self {
-        Schema(self.0.clone())
+        Schema {
+            inner: self.inner.clone(),
+            env: self.env.clone(),
+        }
     }
 }
 
@@ -195,7 +543,7 @@ impl<Query, Mutation, Subscription> Deref for Schema<Query, Mutation, Subscripti
     type Target = SchemaInner<Query, Mutation, Subscription>;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.inner
     }
 }
 
@@ -223,12 +571,25 @@ where
             },
             mutation,
             subscription,
-            registry: Self::create_registry(),
+            registry_ops: Vec::new(),
             data: Default::default(),
             complexity: None,
             depth: None,
+            max_response_nodes: None,
+            max_response_bytes: None,
+            max_resolved_fields: None,
             extensions: Default::default(),
             enable_federation: false,
+            enable_metadata_introspection: false,
+            hide_forbidden_fields: false,
+            spec_version: SpecVersion::June2018,
+            list_concurrency: None,
+            int64_representation: Int64Representation::Number,
+            timer: None,
+            idempotency: None,
+            idempotency_key: None,
+            trusted_documents: Default::default(),
+            shared_subscriptions: None,
         }
     }
 
@@ -248,6 +609,10 @@ where
             } else {
                 Some(Subscription::type_name().to_string())
             },
+            inherited_descriptions: Default::default(),
+            metadata: Default::default(),
+            object_resolved_fields: Default::default(),
+            auto_fields_interfaces: Default::default(),
         };
 
         registry.add_directive(MetaDirective {
@@ -266,9 +631,11 @@ where
                     ty: "Boolean!".to_string(),
                     default_value: None,
                     validator: None,
+                    is_secret: false,
                 });
                 args
-            }
+            },
+            is_repeatable: false,
         });
 
         registry.add_directive(MetaDirective {
@@ -287,9 +654,46 @@ where
                     ty: "Boolean!".to_string(),
                     default_value: None,
                     validator: None,
+                    is_secret: false,
                 });
                 args
-            }
+            },
+            is_repeatable: false,
+        });
+
+        registry.add_directive(MetaDirective {
+            name: "stream",
+            description: Some("Directs the executor to deliver the items of this list after the initial response, when the query is executed incrementally."),
+            locations: vec![__DirectiveLocation::FIELD],
+            args: {
+                let mut args = IndexMap::new();
+                args.insert("if", MetaInputValue {
+                    name: "if",
+                    description: Some("Streamed when true."),
+                    ty: "Boolean!".to_string(),
+                    default_value: Some("true".to_string()),
+                    validator: None,
+                    is_secret: false,
+                });
+                args.insert("initialCount", MetaInputValue {
+                    name: "initialCount",
+                    description: Some("The number of items in the initial response."),
+                    ty: "Int!".to_string(),
+                    default_value: Some("0".to_string()),
+                    validator: None,
+                    is_secret: false,
+                });
+                args.insert("label", MetaInputValue {
+                    name: "label",
+                    description: Some("Identifies the payloads of this list."),
+                    ty: "String".to_string(),
+                    default_value: None,
+                    validator: None,
+                    is_secret: false,
+                });
+                args
+            },
+            is_repeatable: false,
         });
 
         registry.add_directive(MetaDirective {
@@ -297,6 +701,7 @@ where
             description: Some("Directs the executor to query only when the field exists."),
             locations: vec![__DirectiveLocation::FIELD],
             args: Default::default(),
+            is_repeatable: false,
         });
 
         // register scalars
@@ -328,7 +733,21 @@ where
 
     /// Returns SDL(Schema Definition Language) of this schema.
     pub fn sdl(&self) -> String {
-        self.0.env.registry.export_sdl(false)
+        self.env.registry.export_sdl(false)
+    }
+
+    /// Publish the SDL of this schema to `registry`, see the
+    /// [`schema_registry`](schema_registry/index.html) module.
+    pub async fn publish(
+        &self,
+        registry: &impl SchemaRegistry,
+        metadata: &SchemaMetadata,
+    ) -> Result<(), PublishError> {
+        let sdl = self
+            .env
+            .registry
+            .export_sdl(self.env.registry.has_entities());
+        registry.publish(&sdl, metadata).await
     }
 
     /// Get all names in this schema
@@ -337,21 +756,173 @@ where
     /// is usually used to compress type names, field names, directive names, and parameter names. This function gets all the names
     /// so you can create this dictionary.
     pub fn names(&self) -> Vec<String> {
-        self.0.env.registry.names()
+        self.env.registry.names()
     }
 
-    async fn prepare_request(
-        &self,
-        request: Request,
-    ) -> Result<(QueryEnvInner, CacheControl), Vec<ServerError>> {
-        // create extension instances
-        let mut extensions: Extensions = self
-            .0
+    /// Create a contract of this schema, a view that hides the types and fields matched by the
+    /// specified filter.
+    ///
+    /// Types and fields are tagged with the `tag` metadata key, for example
+    /// `#[graphql(meta(key = "tag", value = "internal"))]`. Fields that refer to hidden types are
+    /// hidden as well, and types that are no longer reachable from the root types are removed.
+    ///
+    /// The contract shares the resolvers, extensions and data of this schema, only the types that
+    /// can be queried and introspected are different.
+    ///
+    /// ```
+    /// use async_graphql::*;
+    ///
+    /// #[derive(SimpleObject)]
+    /// #[graphql(meta(key = "tag", value = "internal"))]
+    /// struct AuditLog {
+    ///     entries: Vec<String>,
+    /// }
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn value(&self) -> i32 {
+    ///         10
+    ///     }
+    ///
+    ///     async fn audit_log(&self) -> AuditLog {
+    ///         AuditLog { entries: Vec::new() }
+    ///     }
+    /// }
+    ///
+    /// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    /// let public = schema.build_contract(TagFilter::new().exclude("internal"));
+    ///
+    /// async_std::task::block_on(async move {
+    ///     assert!(schema.execute("{ auditLog { entries } }").await.is_ok());
+    ///     assert!(public.execute("{ auditLog { entries } }").await.is_err());
+    ///     assert!(public.execute("{ value }").await.is_ok());
+    /// });
+    /// ```
+    pub fn build_contract(&self, filter: TagFilter) -> Self {
+        let mut registry = self.env.registry.clone();
+        registry.apply_contract(&filter);
+        Schema {
+            inner: self.inner.clone(),
+            env: SchemaEnv::new(SchemaEnvInner {
+                registry,
+                data: self.env.data.clone(),
+                hide_forbidden_fields: self.env.hide_forbidden_fields,
+                spec_version: self.env.spec_version,
+                list_concurrency: self.env.list_concurrency,
+                int64_representation: self.env.int64_representation,
+                max_response_nodes: self.env.max_response_nodes,
+                max_response_bytes: self.env.max_response_bytes,
+                max_resolved_fields: self.env.max_resolved_fields,
+                timer: self.env.timer.clone(),
+                warm_documents: Default::default(),
+            }),
+        }
+    }
+
+    /// Describe the input type called `name`, including its fields, their default values and
+    /// their validators, e.g. to generate a form for it. Returns `None` if there is no such
+    /// input type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    /// use async_graphql::validators::StringMinLength;
+    ///
+    /// #[derive(InputObject)]
+    /// struct NewUser {
+    ///     #[graphql(validator(StringMinLength(length = "3")))]
+    ///     name: String,
+    ///     #[graphql(default = 18)]
+    ///     age: i32,
+    /// }
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn check(&self, user: NewUser) -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    /// let form = serde_json::to_value(schema.introspect_input_type("NewUser").unwrap()).unwrap();
+    /// assert_eq!(form["fields"][0]["validator"]["validator"]["params"]["length"], 3);
+    /// assert_eq!(form["fields"][1]["required"], false);
+    /// ```
+    pub fn introspect_input_type(&self, name: &str) -> Option<IntrospectedInputType> {
+        self.env.registry.introspect_input_type(name)
+    }
+
+    /// A read-only view of the types, fields and directives of this schema, e.g. to generate
+    /// documentation. See [`SchemaView`](struct.SchemaView.html).
+    pub fn registry(&self) -> SchemaView<'_> {
+        SchemaView::new(&self.env.registry)
+    }
+
+    /// Check that this schema can execute requests, e.g. for a readiness probe, see the
+    /// [`health`](health/index.html) module.
+    ///
+    /// The query is not executed if an extension can't be created, since it would fail the same
+    /// way.
+    pub async fn check_health(&self) -> HealthStatus {
+        let mut checks = Vec::new();
+
+        let extension_errors: Vec<_> = self
+            .inner
+            .extensions
+            .iter()
+            .filter_map(|factory| {
+                std::panic::catch_unwind(AssertUnwindSafe(|| factory.create()))
+                    .err()
+                    .map(|_| format!("Extension \"{}\" can't be created.", factory.name()))
+            })
+            .collect();
+        let query_errors = if extension_errors.is_empty() {
+            let response = self.execute("{ __typename }").await;
+            let typename = match &response.data {
+                Value::Object(data) => data.get("__typename"),
+                _ => None,
+            };
+            let mut errors: Vec<_> = response.errors.iter().map(ToString::to_string).collect();
+            if errors.is_empty()
+                && typename != Some(&Value::String(self.env.registry.query_type.clone()))
+            {
+                errors.push(format!("Unexpected data {}.", response.data));
+            }
+            errors
+        } else {
+            vec!["Not executed, an extension can't be created.".to_string()]
+        };
+        checks.push(HealthCheck::new("extensions", extension_errors));
+        checks.push(HealthCheck::new("query", query_errors));
+
+        let mut registry_errors = self.env.registry.check_types();
+        if let Err(err) = self.env.registry.check_object_resolved_fields() {
+            registry_errors.extend(err.lines().map(ToString::to_string));
+        }
+        checks.push(HealthCheck::new("registry", registry_errors));
+
+        HealthStatus::new(checks)
+    }
+
+    fn create_extensions(&self) -> Extensions {
+        self.inner
             .extensions
             .iter()
             .map(|factory| factory.create())
             .collect::<Vec<_>>()
-            .into();
+            .into()
+    }
+
+    async fn prepare_request(
+        &self,
+        request: Request,
+    ) -> Result<(QueryEnvInner, CacheControl), Vec<ServerError>> {
+        let mut extensions = self.create_extensions();
 
         let mut request = request;
         let data = std::mem::take(&mut request.data);
@@ -363,81 +934,306 @@ where
         let request = extensions.prepare_request(&ctx_extension, request).await?;
 
         extensions.parse_start(&ctx_extension, &request.query, &request.variables);
-        let document = parse_query(&request.query)
-            .map_err(Into::<ServerError>::into)
-            .log_error(&ctx_extension, &extensions)?;
+        let warm = self
+            .env
+            .warm_documents
+            .lock()
+            .get(&request.query)
+            .map(|warm| (warm.document.clone(), warm.cache_control));
+        let (mut document, warm_cache_control) = match warm {
+            Some((document, cache_control)) => (document, Some(cache_control)),
+            None => (
+                parse_query(&request.query)
+                    .map_err(Into::<ServerError>::into)
+                    .log_error(&ctx_extension, &extensions)?,
+                None,
+            ),
+        };
         extensions.parse_end(&ctx_extension, &document);
+        extensions.transform_document(&ctx_extension, &mut document);
 
         // check rules
         extensions.validation_start(&ctx_extension);
-        let CheckResult {
-            cache_control,
-            complexity,
-            depth,
-        } = check_rules(
-            &self.env.registry,
-            &document,
-            Some(&request.variables),
-            self.validation_mode,
-        )
-        .log_error(&ctx_extension, &extensions)?;
-        extensions.validation_end(&ctx_extension);
+        let cache_control = match warm_cache_control {
+            // Only the arguments using variables weren't validated by `warm_up`.
+            Some(cache_control) if request.variables.0.is_empty() => cache_control,
+            _ => {
+                let CheckResult {
+                    cache_control,
+                    complexity,
+                    depth,
+                } = check_rules(
+                    &self.env.registry,
+                    &document,
+                    Some(&request.variables),
+                    self.validation_mode,
+                )
+                .log_error(&ctx_extension, &extensions)?;
 
-        // check limit
-        if let Some(limit_complexity) = self.complexity {
-            if complexity > limit_complexity {
-                return Err(vec![ServerError::new("Query is too complex.")])
-                    .log_error(&ctx_extension, &extensions);
+                // check limit
+                self.check_limits(complexity, depth)
+                    .map_err(|e| vec![e])
+                    .log_error(&ctx_extension, &extensions)?;
+                cache_control
             }
-        }
+        };
+        extensions.validation_end(&ctx_extension);
 
-        if let Some(limit_depth) = self.depth {
-            if depth > limit_depth {
-                return Err(vec![ServerError::new("Query is nested too deep.")])
-                    .log_error(&ctx_extension, &extensions);
-            }
+        #[cfg(feature = "fragment_arguments")]
+        crate::fragment_arguments::inline_fragment_arguments(&mut document);
+
+        if extensions.collects_field_usage() {
+            let (fields, _) =
+                collect_type_usage(&self.env.registry, &document, Some(&request.variables));
+            extensions.field_usage(&ctx_extension, &fields);
         }
 
-        let operation = if let Some(operation_name) = &request.operation_name {
-            match document.operations {
-                DocumentOperations::Single(_) => None,
-                DocumentOperations::Multiple(mut operations) => {
-                    operations.remove(operation_name.as_str())
-                }
-            }
-            .ok_or_else(|| {
-                ServerError::new(format!(r#"Unknown operation named "{}""#, operation_name))
-            })
-        } else {
-            match document.operations {
-                DocumentOperations::Single(operation) => Ok(operation),
-                DocumentOperations::Multiple(map) if map.len() == 1 => {
-                    Ok(map.into_iter().next().unwrap().1)
+        let (operation_name, operation) =
+            match select_operation(document.operations, request.operation_name.as_deref()) {
+                Ok(operation) => operation,
+                Err(e) => {
+                    extensions.error(&ctx_extension, &e);
+                    return Err(vec![e]);
                 }
-                DocumentOperations::Multiple(_) => {
-                    Err(ServerError::new("Operation name required in request."))
-                }
-            }
-        };
-        let operation = match operation {
-            Ok(operation) => operation,
-            Err(e) => {
+            };
+
+        if let Some(allowed_operations) = &request.allowed_operations {
+            if !allowed_operations.contains(&operation.node.ty) {
+                let e = ServerError::new(format!(
+                    "The {} operation is not allowed for this request.",
+                    operation.node.ty
+                ))
+                .at(operation.pos);
                 extensions.error(&ctx_extension, &e);
                 return Err(vec![e]);
             }
-        };
+        }
 
-        let env = QueryEnvInner {
+        let mut env = QueryEnvInner::new(
             extensions,
-            variables: request.variables,
+            request.variables,
+            operation_name,
             operation,
-            fragments: document.fragments,
-            uploads: request.uploads,
-            ctx_data: Arc::new(data),
-        };
+            document.fragments,
+            request.uploads,
+            data,
+        );
+        env.last_event_id = request.last_event_id;
         Ok((env, cache_control))
     }
 
+    /// Parse, validate and cache the documents `queries`, to execute them faster, e.g. on the
+    /// cold start of a serverless function.
+    ///
+    /// A request whose query is one of these documents, byte for byte, isn't parsed again, and
+    /// isn't validated again if it has no variables. The parsing and validation hooks of the
+    /// extensions are still called, but a document rewritten by `transform_document` isn't
+    /// validated again either.
+    ///
+    /// The response of a document made of a single query selecting only introspection fields is
+    /// cached too, and returned as is to the requests without variables if the schema has no
+    /// extensions.
+    ///
+    /// The valid documents are cached even if others are invalid, the errors of the invalid ones
+    /// are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn add(&self, a: i32, b: i32) -> i32 {
+    ///         a + b
+    ///     }
+    /// }
+    ///
+    /// const ADD: &str = "query Add($a: Int!) { add(a: $a, b: 10) }";
+    ///
+    /// async_std::task::block_on(async move {
+    ///     let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    ///     schema.warm_up(&[ADD, "{ __schema { queryType { name } } }"]).await.unwrap();
+    ///
+    ///     let request = Request::new(ADD).variables(Variables::from_value(value!({ "a": 5 })));
+    ///     assert_eq!(schema.execute(request).await.data, value!({ "add": 15 }));
+    /// });
+    /// ```
+    pub async fn warm_up(&self, queries: &[&str]) -> Result<(), Vec<ServerError>> {
+        let mut errors = Vec::new();
+        for query in queries {
+            let (document, cache_control) = match self.check_document(&self.env.registry, query) {
+                Ok(checked) => checked,
+                Err(query_errors) => {
+                    errors.extend(query_errors);
+                    continue;
+                }
+            };
+            let introspection_operation = introspection_operation(&document);
+            self.env.warm_documents.lock().insert(
+                query.to_string(),
+                WarmDocument {
+                    document,
+                    cache_control,
+                    introspection: None,
+                },
+            );
+
+            if let Some(operation_name) = introspection_operation {
+                if self.inner.extensions.is_empty() {
+                    let response = self.execute(*query).await;
+                    if response.is_ok() {
+                        if let Some(warm) = self.env.warm_documents.lock().get_mut(*query) {
+                            warm.introspection = Some((operation_name, response));
+                        }
+                    }
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// The cached response of the introspection query of `request`, see `warm_up`.
+    fn warm_introspection(&self, request: &Request) -> Option<Response> {
+        if !self.inner.extensions.is_empty() || !request.variables.0.is_empty() {
+            return None;
+        }
+        if let Some(allowed_operations) = &request.allowed_operations {
+            if !allowed_operations.contains(&OperationType::Query) {
+                return None;
+            }
+        }
+        let warm_documents = self.env.warm_documents.lock();
+        let (operation_name, response) =
+            warm_documents.get(&request.query)?.introspection.as_ref()?;
+        match (&request.operation_name, operation_name) {
+            (None, _) => {}
+            (Some(name), Some(operation_name)) if name == operation_name.as_str() => {}
+            _ => return None,
+        }
+        Some(response.clone())
+    }
+
+    /// Parse and validate a query document without executing it.
+    ///
+    /// All the operations of the document are validated, so this can be used to check a persisted
+    /// document when it is registered. The operations of the returned document can be inspected
+    /// with `DocumentOperations::iter`.
+    pub fn validate(&self, query: &str) -> Result<ExecutableDocument, Vec<ServerError>> {
+        let (document, _) = self.check_document(&self.env.registry, query)?;
+        Ok(document)
+    }
+
+    /// Parse and validate a query document against the schema of these root types, without
+    /// building it.
+    ///
+    /// The schema is the one `Schema::new` builds, the settings of the `SchemaBuilder` such as the
+    /// validation mode and the limits are not applied. Use it in the tests to check the queries
+    /// that the application executes itself, e.g. those written with
+    /// [`validate_query!`](macro.validate_query.html).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn value(&self) -> i32 {
+    ///         10
+    ///     }
+    /// }
+    ///
+    /// type MySchema = Schema<Query, EmptyMutation, EmptySubscription>;
+    ///
+    /// const VALUE_QUERY: &str = validate_query!("{ value }");
+    /// const TYPO_QUERY: &str = validate_query!("{ valeu }");
+    ///
+    /// assert!(MySchema::validate_static(VALUE_QUERY).is_ok());
+    /// let errors = MySchema::validate_static(TYPO_QUERY).unwrap_err();
+    /// assert_eq!(errors[0].message, r#"Unknown field "valeu" on type "Query"."#);
+    /// ```
+    pub fn validate_static(query: &str) -> Result<ExecutableDocument, Vec<ServerError>> {
+        let mut registry = Self::create_registry();
+        if let Err(err) = registry.apply_auto_interface_fields() {
+            return Err(vec![ServerError::new(err)]);
+        }
+        if registry.has_entities() {
+            registry.create_federation_types();
+        }
+        registry.apply_inherited_descriptions();
+        registry.apply_spec_version(SpecVersion::June2018);
+
+        let document = parse_query(query).map_err(|e| vec![ServerError::from(e)])?;
+        check_rules(&registry, &document, None, ValidationMode::Strict)?;
+        Ok(document)
+    }
+
+    /// Parse, validate and analyze a request without executing it.
+    ///
+    /// The limits set with `SchemaBuilder::limit_complexity` and `SchemaBuilder::limit_depth`
+    /// are not checked, the analysis reports the values to compare them with. Extensions are not
+    /// called, so the request must contain the query itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// #[derive(SimpleObject)]
+    /// struct User {
+    ///     name: String,
+    /// }
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn me(&self) -> User {
+    ///         User { name: "me".to_string() }
+    ///     }
+    /// }
+    ///
+    /// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    /// let analysis = schema.analyze("{ me { name } }").unwrap();
+    /// assert_eq!(analysis.depth, 1);
+    /// assert_eq!(analysis.complexity, 2);
+    /// assert_eq!(analysis.fields, vec!["Query.me", "User.name"]);
+    /// assert_eq!(analysis.types, vec!["Query", "String", "User"]);
+    /// ```
+    pub fn analyze(&self, request: impl Into<Request>) -> Result<QueryAnalysis, Vec<ServerError>> {
+        let request = request.into();
+        let document = parse_query(&request.query).map_err(|e| vec![ServerError::from(e)])?;
+        let CheckResult {
+            cache_control,
+            complexity,
+            depth,
+        } = check_rules(
+            &self.env.registry,
+            &document,
+            Some(&request.variables),
+            self.validation_mode,
+        )?;
+        let (fields, types) =
+            collect_type_usage(&self.env.registry, &document, Some(&request.variables));
+        Ok(QueryAnalysis {
+            depth,
+            complexity,
+            cache_control,
+            fields,
+            types,
+        })
+    }
+
     async fn execute_once(&self, env: QueryEnv) -> Response {
         // execute
         let inc_resolve_id = AtomicUsize::default();
@@ -445,6 +1241,8 @@ where
             path_node: None,
             resolve_id: ResolveId::root(),
             inc_resolve_id: &inc_resolve_id,
+            list_concurrency: None,
+            field_results: None,
             item: &env.operation.node.selection_set,
             schema_env: &self.env,
             query_env: &env,
@@ -469,25 +1267,189 @@ where
         env.extensions.execution_end(&ctx_extension);
         let extensions = env.extensions.result(&ctx_extension);
 
-        match data {
+        let mut resp = match data {
             Ok(data) => Response::new(data),
             Err(e) => Response::from_errors(vec![e]),
         }
         .extensions(extensions)
+        .warnings(env.warnings.lock().drain(..).collect());
+        resp.errors.extend(env.errors.lock().drain(..));
+        resp
+    }
+
+    /// Execute the trusted document whose hash is `hash`, see
+    /// [`SchemaBuilder::trusted_documents`](struct.SchemaBuilder.html#method.trusted_documents).
+    ///
+    /// The document is neither parsed nor validated again, the variables are checked when the
+    /// arguments using them are parsed. The `prepare_request`, parsing and validation hooks of
+    /// the extensions are not called.
+    pub async fn execute_persisted(&self, hash: &str, variables: Variables) -> Response {
+        self.execute_persisted_with_data(hash, variables, Data::default())
+            .await
+    }
+
+    /// Execute the trusted document whose hash is `hash` with the data of the request.
+    pub async fn execute_persisted_with_data(
+        &self,
+        hash: &str,
+        variables: Variables,
+        data: Data,
+    ) -> Response {
+        let document = match self.trusted_documents.get(hash) {
+            Some(document) => document,
+            None => {
+                return Response::from_errors(vec![ServerError::new(format!(
+                    "Unknown trusted document \"{}\".",
+                    hash
+                ))])
+            }
+        };
+        let env = QueryEnvInner::new(
+            self.create_extensions(),
+            variables,
+            document.operation_name.clone(),
+            document.operation.clone(),
+            document.fragments.clone(),
+            Vec::new(),
+            data,
+        );
+        self.execute_once(QueryEnv::new(env))
+            .await
+            .cache_control(document.cache_control)
     }
 
     /// Execute a GraphQL query.
     pub async fn execute(&self, request: impl Into<Request>) -> Response {
         let request = request.into();
+        if let Some(response) = self.warm_introspection(&request) {
+            return response;
+        }
+        let idempotency_key = match &self.idempotency {
+            Some(idempotency) => {
+                if let Some(key) = request.extensions.get("idempotencyKey") {
+                    if !matches!(key, Value::String(_)) {
+                        return Response::from_errors(vec![ServerError::new(
+                            "Invalid \"idempotencyKey\" extension, expected a string.",
+                        )]);
+                    }
+                }
+                (idempotency.key)(&request).map(|key| (key, idempotency::fingerprint(&request)))
+            }
+            None => None,
+        };
+
         match self.prepare_request(request).await {
-            Ok((env, cache_control)) => self
-                .execute_once(QueryEnv::new(env))
-                .await
-                .cache_control(cache_control),
+            Ok((env, cache_control)) => {
+                let idempotency = match (&self.idempotency, idempotency_key) {
+                    (Some(idempotency), Some((key, fingerprint)))
+                        if env.operation.node.ty == OperationType::Mutation =>
+                    {
+                        if let Some(stored) = idempotency.store.get(&key).await {
+                            if stored.fingerprint != fingerprint {
+                                return Response::from_errors(vec![ServerError::new(
+                                    "The \"idempotencyKey\" was already used by another request.",
+                                )]);
+                            }
+                            return stored.response;
+                        }
+                        Some((idempotency, key, fingerprint))
+                    }
+                    _ => None,
+                };
+
+                let response = self
+                    .execute_once(QueryEnv::new(env))
+                    .await
+                    .cache_control(cache_control);
+                if let Some((idempotency, key, fingerprint)) = idempotency {
+                    let stored = StoredResponse {
+                        fingerprint,
+                        response: response.clone(),
+                    };
+                    idempotency.store.set(key, stored, idempotency.ttl).await;
+                }
+                response
+            }
             Err(errors) => Response::from_errors(errors),
         }
     }
 
+    /// Execute a GraphQL query with typed variables and deserialize the data of the response.
+    ///
+    /// This is useful for executing queries against a schema from within the same process, the
+    /// variables are serialized to GraphQL values and the response data is deserialized into `T`
+    /// without going through JSON. The variables must serialize to a map, or to `null` for no
+    /// variables.
+    ///
+    /// ```
+    /// use async_graphql::*;
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn add(&self, a: i32, b: i32) -> i32 {
+    ///         a + b
+    ///     }
+    /// }
+    ///
+    /// #[derive(Serialize)]
+    /// struct AddVariables {
+    ///     a: i32,
+    ///     b: i32,
+    /// }
+    ///
+    /// #[derive(Deserialize)]
+    /// struct AddData {
+    ///     add: i32,
+    /// }
+    ///
+    /// async_std::task::block_on(async move {
+    ///     let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    ///     let data: AddData = schema
+    ///         .execute_typed(
+    ///             "query($a: Int!, $b: Int!) { add(a: $a, b: $b) }",
+    ///             AddVariables { a: 10, b: 20 },
+    ///         )
+    ///         .await
+    ///         .unwrap();
+    ///     assert_eq!(data.add, 30);
+    /// });
+    /// ```
+    pub async fn execute_typed<T: DeserializeOwned>(
+        &self,
+        request: impl Into<Request>,
+        variables: impl Serialize,
+    ) -> Result<T, Vec<ServerError>> {
+        let variables = match to_value(variables) {
+            Ok(Value::Null) => Variables::default(),
+            Ok(value @ Value::Object(_)) => Variables::from_value(value),
+            Ok(_) => {
+                return Err(vec![ServerError::new(
+                    "Variables must be serialized to an object.",
+                )])
+            }
+            Err(err) => {
+                return Err(vec![ServerError::new(format!(
+                    "Failed to serialize variables: {}",
+                    err
+                ))])
+            }
+        };
+        let data = self
+            .execute(request.into().variables(variables))
+            .await
+            .into_result()?
+            .data;
+        from_value(data).map_err(|err| {
+            vec![ServerError::new(format!(
+                "Failed to deserialize response data: {}",
+                err
+            ))]
+        })
+    }
+
     /// Execute a GraphQL batch query.
     pub async fn execute_batch(&self, batch_request: BatchRequest) -> BatchResponse {
         match batch_request {
@@ -505,11 +1467,26 @@ where
         &self,
         request: impl Into<Request>,
         ctx_data: Arc<Data>,
+    ) -> LocalBoxStream<'static, Response> {
+        let request = request.into();
+        if let Some(shared_subscriptions) = &self.shared_subscriptions {
+            if let Some(key) = shared_subscriptions.key(&request, &ctx_data) {
+                return shared_subscriptions.subscribe(key, || {
+                    self.execute_stream_once(request, ctx_data).boxed_local()
+                });
+            }
+        }
+        self.execute_stream_once(request, ctx_data).boxed_local()
+    }
+
+    fn execute_stream_once(
+        &self,
+        request: Request,
+        ctx_data: Arc<Data>,
     ) -> impl Stream<Item = Response> {
         let schema = self.clone();
 
         async_stream::stream! {
-            let request = request.into();
             let (mut env, cache_control) = match schema.prepare_request(request).await {
                 Ok(res) => res,
                 Err(errors) => {
@@ -556,14 +1533,30 @@ where
             while let Some(data) = stream.next().await {
                 let is_err = data.is_err();
                 let extensions = env.extensions.result(&ctx_extension);
-                yield match data {
+                let mut resp = match data {
                     Ok((name, value)) => {
-                        let mut map = BTreeMap::new();
+                        let mut map = Map::new();
                         map.insert(name, value);
                         Response::new(Value::Object(map))
                     },
                     Err(e) => Response::from_errors(vec![e]),
-                }.extensions(extensions);
+                }
+                .extensions(extensions)
+                .warnings(env.warnings.lock().drain(..).collect());
+                resp.errors.extend(env.errors.lock().drain(..));
+                resp.event_id = env.event_id.lock().take();
+                match env.subscription_frame.lock().take() {
+                    Some(SubscriptionFrame::KeepAlive) => {
+                        resp.data = Value::Null;
+                        resp.keep_alive = true;
+                    }
+                    Some(SubscriptionFrame::Progress(progress)) => {
+                        resp.data = Value::Null;
+                        resp = resp.extension("progress", progress);
+                    }
+                    None => {}
+                }
+                yield resp;
                 if is_err {
                     break;
                 }
@@ -571,6 +1564,102 @@ where
         }
     }
 
+    /// Execute a GraphQL query incrementally.
+    ///
+    /// The first payload is the response to the query, without the items that lists with a
+    /// `@stream` directive deliver later. It is followed by a payload for each of these items,
+    /// which is only resolved when the stream is polled for it, and a last payload once all the
+    /// lists have ended. See [`ListStream`](struct.ListStream.html).
+    pub fn execute_incremental(
+        &self,
+        request: impl Into<Request>,
+    ) -> impl Stream<Item = IncrementalResponse> {
+        let schema = self.clone();
+        let request = request.into();
+
+        async_stream::stream! {
+            let (mut env, cache_control) = match schema.prepare_request(request).await {
+                Ok(res) => res,
+                Err(errors) => {
+                    yield IncrementalResponse::Initial {
+                        response: Response::from_errors(errors),
+                        has_next: false,
+                    };
+                    return;
+                }
+            };
+            env.streams = Some(Default::default());
+            let env = QueryEnv::new(env);
+            let streams = env.streams.as_ref().unwrap();
+
+            let response = schema
+                .execute_once(env.clone())
+                .await
+                .cache_control(cache_control);
+            let mut pending: VecDeque<_> = streams.lock().drain(..).collect();
+            yield IncrementalResponse::Initial {
+                response,
+                has_next: !pending.is_empty(),
+            };
+            if pending.is_empty() {
+                return;
+            }
+
+            let inc_resolve_id = AtomicUsize::default();
+            while let Some(mut list) = pending.pop_front() {
+                let mut path = list.path.clone();
+                path.push(PathSegment::Index(list.next_index));
+
+                let item = {
+                    let ctx = env.create_context(
+                        &schema.env,
+                        None,
+                        &list.field.node.selection_set,
+                        ResolveId::root(),
+                        &inc_resolve_id,
+                    );
+                    list.items.resolve_next(&ctx, &list.field).await
+                };
+                let item = match item {
+                    Some(item) => item,
+                    None => continue,
+                };
+                list.next_index += 1;
+
+                // The item is resolved without a parent path, so the paths of its errors and
+                // nested streams are relative to it.
+                let mut errors: Vec<_> = env.errors.lock().drain(..).collect();
+                let items = match item {
+                    Ok(value) => Some(vec![value]),
+                    Err(err) => {
+                        errors.push(err);
+                        None
+                    }
+                };
+                for error in &mut errors {
+                    error.path = path.iter().cloned().chain(error.path.drain(..)).collect();
+                }
+                for mut nested in streams.lock().drain(..) {
+                    nested.path = path.iter().cloned().chain(nested.path).collect();
+                    pending.push_back(nested);
+                }
+
+                let failed = items.is_none();
+                yield IncrementalResponse::Items {
+                    items,
+                    path,
+                    label: list.label.clone(),
+                    errors,
+                    has_next: true,
+                };
+                if !failed {
+                    pending.push_back(list);
+                }
+            }
+            yield IncrementalResponse::Completed { has_next: false };
+        }
+    }
+
     /// Execute a GraphQL subscription.
     pub fn execute_stream(&self, request: impl Into<Request>) -> impl Stream<Item = Response> {
         let mut request = request.into();
@@ -578,3 +1667,63 @@ where
         self.execute_stream_with_ctx_data(request, Arc::new(ctx_data))
     }
 }
+
+/// The name of the operation of `document` if it is its only operation, and a query selecting
+/// only introspection fields.
+fn introspection_operation(document: &ExecutableDocument) -> Option<Option<Name>> {
+    let mut operations = document.operations.iter();
+    let (operation_name, operation) = operations.next()?;
+    if operations.next().is_some() || operation.node.ty != OperationType::Query {
+        return None;
+    }
+    let introspection_only = operation
+        .node
+        .selection_set
+        .node
+        .items
+        .iter()
+        .all(|selection| {
+            matches!(
+                &selection.node,
+                Selection::Field(field)
+                    if matches!(field.node.name.node.as_str(), "__schema" | "__type" | "__typename")
+            )
+        });
+    if introspection_only {
+        Some(operation_name.cloned())
+    } else {
+        None
+    }
+}
+
+/// Select the operation to execute from the operations of a document.
+fn select_operation(
+    operations: DocumentOperations,
+    operation_name: Option<&str>,
+) -> ServerResult<(Option<Name>, Positioned<OperationDefinition>)> {
+    match (operations, operation_name) {
+        (DocumentOperations::Single(operation), None) => Ok((None, operation)),
+        (DocumentOperations::Single(operation), Some(operation_name)) => Err(ServerError::new(
+            format!(r#"Unknown operation named "{}""#, operation_name),
+        )
+        .at(operation.pos)),
+        (DocumentOperations::Multiple(mut operations), Some(operation_name)) => {
+            let (name, operation) = operations.remove_entry(operation_name).ok_or_else(|| {
+                ServerError::new(format!(r#"Unknown operation named "{}""#, operation_name))
+            })?;
+            Ok((Some(name), operation))
+        }
+        (DocumentOperations::Multiple(operations), None) if operations.len() == 1 => {
+            let (name, operation) = operations.into_iter().next().unwrap();
+            Ok((Some(name), operation))
+        }
+        (DocumentOperations::Multiple(operations), None) => {
+            let mut error = ServerError::new("Operation name required in request.");
+            let mut positions: Vec<_> =
+                operations.values().map(|operation| operation.pos).collect();
+            positions.sort();
+            error.locations = positions;
+            Err(error)
+        }
+    }
+}
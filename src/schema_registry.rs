@@ -0,0 +1,401 @@
+//! Publishing the schema to a schema registry
+//!
+//! `Schema::publish` sends the SDL of the schema and the [`SchemaMetadata`](struct.SchemaMetadata.html)
+//! of the service to a [`SchemaRegistry`](trait.SchemaRegistry.html), so that every deployment
+//! registers the version of the schema it serves. It is usually called once, on startup.
+//!
+//! The registries of [GraphQL Hive](struct.HiveRegistry.html), [Apollo
+//! GraphOS](struct.ApolloRegistry.html) and [WunderGraph Cosmo](struct.CosmoRegistry.html) are
+//! provided, they send their requests with an [`HttpClient`](trait.HttpClient.html) implemented
+//! by the application with the HTTP client it already uses. Another registry is supported by
+//! implementing `SchemaRegistry`.
+//!
+//! The SDL of a schema that has entities includes the federation directives.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use async_graphql::*;
+//! use async_graphql::schema_registry::{HiveRegistry, HttpClient, SchemaMetadata};
+//!
+//! struct Query;
+//!
+//! #[Object]
+//! impl Query {
+//!     async fn value(&self) -> i32 {
+//!         10
+//!     }
+//! }
+//!
+//! struct Client;
+//!
+//! #[async_trait::async_trait(?Send)]
+//! impl HttpClient for Client {
+//!     async fn post(
+//!         &self,
+//!         url: &str,
+//!         headers: &[(&str, String)],
+//!         body: String,
+//!     ) -> std::result::Result<String, String> {
+//!         // Send the request with your HTTP client.
+//!         Ok(r#"{ "data": { "schemaPublish": { "__typename": "SchemaPublishSuccess" } } }"#.to_string())
+//!     }
+//! }
+//!
+//! let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+//! let registry = HiveRegistry::new(Client, "<access token>");
+//! let metadata = SchemaMetadata::new("products")
+//!     .version("1.4.0")
+//!     .commit("4f2a9c1")
+//!     .url("http://products:8000/graphql");
+//!
+//! async_std::task::block_on(async move {
+//!     schema.publish(&registry, &metadata).await.unwrap();
+//! });
+//! ```
+
+use serde_json::json;
+use thiserror::Error;
+
+/// The service a schema is published for.
+#[derive(Debug, Clone, Default)]
+pub struct SchemaMetadata {
+    /// The name of the service, the subgraph name in a federated graph.
+    pub service_name: String,
+
+    /// The version of the service.
+    pub version: Option<String>,
+
+    /// The commit the service was built from.
+    pub commit: Option<String>,
+
+    /// The URL the service is reachable at.
+    pub url: Option<String>,
+}
+
+impl SchemaMetadata {
+    /// Create the metadata of the service named `service_name`.
+    pub fn new(service_name: impl Into<String>) -> Self {
+        Self {
+            service_name: service_name.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Set the version of the service.
+    #[must_use]
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    /// Set the commit the service was built from.
+    #[must_use]
+    pub fn commit(mut self, commit: impl Into<String>) -> Self {
+        self.commit = Some(commit.into());
+        self
+    }
+
+    /// Set the URL the service is reachable at.
+    #[must_use]
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// The revision of the schema, the version, else the commit.
+    fn revision(&self) -> Option<&str> {
+        self.version.as_deref().or(self.commit.as_deref())
+    }
+}
+
+/// An error publishing a schema.
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum PublishError {
+    /// The request couldn't be sent, or its response status isn't successful.
+    #[error("Cannot send the schema to the registry: {0}")]
+    Transport(String),
+
+    /// The response can't be understood.
+    #[error("Invalid response from the registry: {0}")]
+    InvalidResponse(String),
+
+    /// The registry rejected the schema, with these messages.
+    #[error("The registry rejected the schema: {}", .0.join(", "))]
+    Rejected(Vec<String>),
+}
+
+/// A schema registry.
+#[async_trait::async_trait(?Send)]
+pub trait SchemaRegistry {
+    /// Publish the SDL `sdl` of the service described by `metadata`.
+    async fn publish(&self, sdl: &str, metadata: &SchemaMetadata) -> Result<(), PublishError>;
+}
+
+/// The HTTP client the registries send their requests with.
+#[async_trait::async_trait(?Send)]
+pub trait HttpClient {
+    /// Send a POST request with a JSON `body` and `headers` to `url`, and return the body of the
+    /// response.
+    ///
+    /// An error is returned if the request can't be sent, or the status of the response isn't
+    /// successful.
+    async fn post(
+        &self,
+        url: &str,
+        headers: &[(&str, String)],
+        body: String,
+    ) -> Result<String, String>;
+}
+
+async fn post_json(
+    client: &impl HttpClient,
+    url: &str,
+    headers: &[(&str, String)],
+    body: serde_json::Value,
+) -> Result<serde_json::Value, PublishError> {
+    let mut all_headers = vec![("content-type", "application/json".to_string())];
+    all_headers.extend(headers.iter().cloned());
+    let response = client
+        .post(url, &all_headers, body.to_string())
+        .await
+        .map_err(PublishError::Transport)?;
+    let response: serde_json::Value = serde_json::from_str(&response)
+        .map_err(|err| PublishError::InvalidResponse(err.to_string()))?;
+    if let Some(errors) = response.get("errors").and_then(|errors| errors.as_array()) {
+        if !errors.is_empty() {
+            return Err(PublishError::Rejected(messages(errors)));
+        }
+    }
+    Ok(response)
+}
+
+fn messages(errors: &[serde_json::Value]) -> Vec<String> {
+    errors
+        .iter()
+        .map(|error| {
+            error
+                .get("message")
+                .and_then(|message| message.as_str())
+                .unwrap_or("Unknown error")
+                .to_string()
+        })
+        .collect()
+}
+
+const HIVE_PUBLISH: &str = r#"mutation schemaPublish($input: SchemaPublishInput!) {
+  schemaPublish(input: $input) {
+    __typename
+    ... on SchemaPublishError { errors { nodes { message } } }
+    ... on SchemaPublishMissingServiceError { message }
+    ... on SchemaPublishMissingUrlError { message }
+  }
+}"#;
+
+/// The [GraphQL Hive](https://the-guild.dev/graphql/hive) registry.
+pub struct HiveRegistry<C> {
+    client: C,
+    endpoint: String,
+    token: String,
+}
+
+impl<C: HttpClient> HiveRegistry<C> {
+    /// Create the registry of the Hive cloud, `token` is a registry access token of the target.
+    pub fn new(client: C, token: impl Into<String>) -> Self {
+        Self {
+            client,
+            endpoint: "https://app.graphql-hive.com/graphql".to_string(),
+            token: token.into(),
+        }
+    }
+
+    /// Use the GraphQL API of a self-hosted Hive at `endpoint`.
+    #[must_use]
+    pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = endpoint.into();
+        self
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<C: HttpClient> SchemaRegistry for HiveRegistry<C> {
+    async fn publish(&self, sdl: &str, metadata: &SchemaMetadata) -> Result<(), PublishError> {
+        let body = json!({
+            "query": HIVE_PUBLISH,
+            "operationName": "schemaPublish",
+            "variables": {
+                "input": {
+                    "sdl": sdl,
+                    "author": metadata.service_name,
+                    "commit": metadata.revision().unwrap_or("unknown"),
+                    "service": metadata.service_name,
+                    "url": metadata.url,
+                },
+            },
+        });
+        let headers = [("authorization", format!("Bearer {}", self.token))];
+        let response = post_json(&self.client, &self.endpoint, &headers, body).await?;
+        let result = &response["data"]["schemaPublish"];
+        match result["__typename"].as_str() {
+            Some("SchemaPublishSuccess") => Ok(()),
+            Some("SchemaPublishError") => Err(PublishError::Rejected(messages(
+                result["errors"]["nodes"]
+                    .as_array()
+                    .map(Vec::as_slice)
+                    .unwrap_or_default(),
+            ))),
+            Some(_) => Err(PublishError::Rejected(messages(std::slice::from_ref(
+                result,
+            )))),
+            None => Err(PublishError::InvalidResponse(
+                "Missing \"schemaPublish\" result.".to_string(),
+            )),
+        }
+    }
+}
+
+const APOLLO_PUBLISH: &str = r#"mutation SubgraphPublish($graphId: ID!, $variant: String!, $name: String!, $url: String, $revision: String!, $sdl: String!) {
+  graph(id: $graphId) {
+    publishSubgraph(graphVariant: $variant, name: $name, url: $url, revision: $revision, activePartialSchema: { sdl: $sdl }) {
+      errors { message }
+    }
+  }
+}"#;
+
+/// The [Apollo GraphOS](https://www.apollographql.com/docs/graphos/) registry, the schema is
+/// published as a subgraph named after the service.
+pub struct ApolloRegistry<C> {
+    client: C,
+    endpoint: String,
+    api_key: String,
+    graph_id: String,
+    variant: String,
+}
+
+impl<C: HttpClient> ApolloRegistry<C> {
+    /// Create the registry of the graph `graph_ref`, `graph@variant` or `graph` for the
+    /// `current` variant, `api_key` is a graph API key.
+    pub fn new(client: C, api_key: impl Into<String>, graph_ref: &str) -> Self {
+        let (graph_id, variant) = match graph_ref.split_once('@') {
+            Some((graph_id, variant)) => (graph_id, variant),
+            None => (graph_ref, "current"),
+        };
+        Self {
+            client,
+            endpoint: "https://api.apollographql.com/api/graphql".to_string(),
+            api_key: api_key.into(),
+            graph_id: graph_id.to_string(),
+            variant: variant.to_string(),
+        }
+    }
+
+    /// Use the Platform API at `endpoint`.
+    #[must_use]
+    pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = endpoint.into();
+        self
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<C: HttpClient> SchemaRegistry for ApolloRegistry<C> {
+    async fn publish(&self, sdl: &str, metadata: &SchemaMetadata) -> Result<(), PublishError> {
+        let body = json!({
+            "query": APOLLO_PUBLISH,
+            "operationName": "SubgraphPublish",
+            "variables": {
+                "graphId": self.graph_id,
+                "variant": self.variant,
+                "name": metadata.service_name,
+                "url": metadata.url,
+                "revision": metadata.revision().unwrap_or("unknown"),
+                "sdl": sdl,
+            },
+        });
+        let headers = [
+            ("x-api-key", self.api_key.clone()),
+            ("apollographql-client-name", "async-graphql".to_string()),
+        ];
+        let response = post_json(&self.client, &self.endpoint, &headers, body).await?;
+        let result = &response["data"]["graph"]["publishSubgraph"];
+        if result.is_null() {
+            return Err(PublishError::InvalidResponse(format!(
+                "Unknown graph \"{}\".",
+                self.graph_id
+            )));
+        }
+        match result["errors"].as_array() {
+            Some(errors) if !errors.is_empty() => Err(PublishError::Rejected(messages(errors))),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The [WunderGraph Cosmo](https://cosmo-docs.wundergraph.com/) registry, the schema is
+/// published as a federated subgraph named after the service.
+pub struct CosmoRegistry<C> {
+    client: C,
+    endpoint: String,
+    api_key: String,
+    namespace: String,
+}
+
+impl<C: HttpClient> CosmoRegistry<C> {
+    /// Create the registry of the Cosmo cloud, `api_key` is an API key of the organization.
+    pub fn new(client: C, api_key: impl Into<String>) -> Self {
+        Self {
+            client,
+            endpoint: "https://cosmo-cp.wundergraph.com".to_string(),
+            api_key: api_key.into(),
+            namespace: "default".to_string(),
+        }
+    }
+
+    /// Use the control plane at `endpoint`.
+    #[must_use]
+    pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = endpoint.into();
+        self
+    }
+
+    /// Publish the subgraph in `namespace` instead of `default`.
+    #[must_use]
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = namespace.into();
+        self
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<C: HttpClient> SchemaRegistry for CosmoRegistry<C> {
+    async fn publish(&self, sdl: &str, metadata: &SchemaMetadata) -> Result<(), PublishError> {
+        let url = format!(
+            "{}/wg.cosmo.platform.v1.PlatformService/PublishFederatedSubgraph",
+            self.endpoint.trim_end_matches('/')
+        );
+        let body = json!({
+            "name": metadata.service_name,
+            "namespace": self.namespace,
+            "schema": sdl,
+            "routingUrl": metadata.url,
+        });
+        let headers = [
+            ("authorization", format!("Bearer {}", self.api_key)),
+            ("connect-protocol-version", "1".to_string()),
+        ];
+        let response = post_json(&self.client, &url, &headers, body).await?;
+        if let Some(errors) = response["compositionErrors"].as_array() {
+            if !errors.is_empty() {
+                return Err(PublishError::Rejected(messages(errors)));
+            }
+        }
+        match response["response"]["code"].as_str() {
+            None | Some("OK") => Ok(()),
+            Some(code) => Err(PublishError::Rejected(vec![response["response"]
+                ["details"]
+                .as_str()
+                .unwrap_or(code)
+                .to_string()])),
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::pin::Pin;
+use std::rc::{Rc, Weak};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::task::{Context, Poll, Waker};
+
+use futures_util::stream::{LocalBoxStream, Stream, StreamExt};
+
+use crate::{Data, Request, Response};
+
+pub(crate) type SubscriptionKeyFn = dyn Fn(&Request, &Data) -> Option<String> + Send + Sync;
+
+/// The subscriptions executed once for all the requests with the same key.
+pub(crate) struct SharedSubscriptions {
+    key: Box<SubscriptionKeyFn>,
+    /// Identifies the subscriptions of this schema in `STREAMS`.
+    id: usize,
+}
+
+/// The running shared subscriptions, by schema and key.
+type Streams = HashMap<(usize, String), Weak<RefCell<Fanout>>>;
+
+thread_local! {
+    /// The streams aren't `Send`, so each thread has its own shared subscriptions.
+    static STREAMS: RefCell<Streams> = RefCell::new(HashMap::new());
+}
+
+/// A subscription and the streams of the requests sharing it.
+struct Fanout {
+    /// The stream of the subscription, `None` once it has ended.
+    upstream: Option<LocalBoxStream<'static, Response>>,
+    subscribers: Vec<Weak<RefCell<Subscriber>>>,
+}
+
+#[derive(Default)]
+struct Subscriber {
+    queue: VecDeque<Response>,
+    waker: Option<Waker>,
+}
+
+impl SharedSubscriptions {
+    pub(crate) fn new(key: Box<SubscriptionKeyFn>) -> Self {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        Self {
+            key,
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+
+    /// The key of the subscription executed for `request` with the data of the context
+    /// `ctx_data`, `None` if it is not shared.
+    pub(crate) fn key(&self, request: &Request, ctx_data: &Data) -> Option<String> {
+        (self.key)(request, ctx_data)
+    }
+
+    /// Subscribe to the subscription with the key `key`, which is started with `execute` if no
+    /// request with this key is running.
+    pub(crate) fn subscribe(
+        &self,
+        key: String,
+        execute: impl FnOnce() -> LocalBoxStream<'static, Response>,
+    ) -> LocalBoxStream<'static, Response> {
+        let key = (self.id, key);
+        let running = STREAMS.with(|streams| {
+            let mut streams = streams.borrow_mut();
+            streams.retain(|_, fanout| fanout.strong_count() > 0);
+            streams.get(&key).and_then(Weak::upgrade)
+        });
+        let fanout = match running {
+            Some(fanout) if fanout.borrow().upstream.is_some() => fanout,
+            _ => {
+                // The subscription is executed without borrowing `STREAMS`, it may share another
+                // subscription.
+                let fanout = Rc::new(RefCell::new(Fanout {
+                    upstream: Some(execute()),
+                    subscribers: Vec::new(),
+                }));
+                STREAMS.with(|streams| {
+                    streams.borrow_mut().insert(key, Rc::downgrade(&fanout));
+                });
+                fanout
+            }
+        };
+        let subscriber = Rc::new(RefCell::new(Subscriber::default()));
+        fanout
+            .borrow_mut()
+            .subscribers
+            .push(Rc::downgrade(&subscriber));
+        SharedStream { fanout, subscriber }.boxed_local()
+    }
+}
+
+/// The stream of a request sharing a subscription.
+///
+/// The upstream is polled by the request that polls with nothing in its queue, and each of
+/// its responses is queued for the other requests.
+struct SharedStream {
+    fanout: Rc<RefCell<Fanout>>,
+    subscriber: Rc<RefCell<Subscriber>>,
+}
+
+impl Stream for SharedStream {
+    type Item = Response;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if let Some(resp) = this.subscriber.borrow_mut().queue.pop_front() {
+            return Poll::Ready(Some(resp));
+        }
+
+        let mut fanout = this.fanout.borrow_mut();
+        let upstream = match &mut fanout.upstream {
+            Some(upstream) => upstream,
+            None => return Poll::Ready(None),
+        };
+        match upstream.poll_next_unpin(cx) {
+            Poll::Ready(Some(resp)) => {
+                let this_subscriber = &this.subscriber;
+                fanout
+                    .subscribers
+                    .retain(|subscriber| match subscriber.upgrade() {
+                        Some(subscriber) if Rc::ptr_eq(&subscriber, this_subscriber) => true,
+                        Some(subscriber) => {
+                            let mut subscriber = subscriber.borrow_mut();
+                            subscriber.queue.push_back(resp.clone());
+                            if let Some(waker) = subscriber.waker.take() {
+                                waker.wake();
+                            }
+                            true
+                        }
+                        None => false,
+                    });
+                Poll::Ready(Some(resp))
+            }
+            Poll::Ready(None) => {
+                fanout.upstream = None;
+                fanout.wake_all();
+                Poll::Ready(None)
+            }
+            Poll::Pending => {
+                this.subscriber.borrow_mut().waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl Drop for SharedStream {
+    fn drop(&mut self) {
+        // The upstream may only wake this stream, another one takes over polling it.
+        let mut fanout = self.fanout.borrow_mut();
+        let this_subscriber = &self.subscriber;
+        fanout
+            .subscribers
+            .retain(|subscriber| match subscriber.upgrade() {
+                Some(subscriber) => !Rc::ptr_eq(&subscriber, this_subscriber),
+                None => false,
+            });
+        fanout.wake_all();
+    }
+}
+
+impl Fanout {
+    fn wake_all(&self) {
+        for subscriber in self.subscribers.iter().filter_map(Weak::upgrade) {
+            if let Some(waker) = subscriber.borrow_mut().waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
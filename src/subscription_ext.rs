@@ -0,0 +1,228 @@
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::future::{self, Either};
+use futures_util::stream::{self, LocalBoxStream, Stream, StreamExt};
+
+use crate::runtime::Timer;
+use crate::{Context, SubscriptionMessage};
+
+/// Combinators for the streams returned by subscription resolvers.
+///
+/// The timed combinators, `debounce`, `throttle`, `batch_within` and `heartbeat`, wait with the timer set
+/// with [`SchemaBuilder::timer`](struct.SchemaBuilder.html#method.timer) and panic if the schema
+/// has none.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use futures_util::stream::{self, Stream, StreamExt};
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn value(&self) -> i32 {
+///         10
+///     }
+/// }
+///
+/// struct Subscription;
+///
+/// #[Subscription]
+/// impl Subscription {
+///     async fn scores<'a>(&self, ctx: &'a Context<'a>) -> impl Stream<Item = String> + 'a {
+///         stream::iter(vec![("alice", 10), ("bob", 20), ("alice", 30)])
+///             .filter_by_ctx(ctx, |ctx, (user, _)| ctx.data_unchecked::<String>() == user)
+///             .map_event(ctx, |_, (_, score)| format!("{} points", score))
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(Query, EmptyMutation, Subscription);
+///     let request = Request::new("subscription { scores }").data("alice".to_string());
+///     let scores: Vec<_> = schema.execute_stream(request).map(|resp| resp.data).collect().await;
+///     assert_eq!(scores, vec![
+///         value!({ "scores": "10 points" }),
+///         value!({ "scores": "30 points" }),
+///     ]);
+/// });
+/// ```
+pub trait SubscriptionStreamExt: Stream + Sized {
+    /// Only output the events for which `f`, called with the context of the subscription,
+    /// returns `true`, e.g. the events the current user may see.
+    fn filter_by_ctx<'a, F>(self, ctx: &'a Context<'a>, mut f: F) -> LocalBoxStream<'a, Self::Item>
+    where
+        Self: 'a,
+        F: FnMut(&Context<'a>, &Self::Item) -> bool + 'a,
+    {
+        self.filter(move |item| future::ready(f(ctx, item)))
+            .boxed_local()
+    }
+
+    /// Convert the events with `f`, called with the context of the subscription.
+    fn map_event<'a, F, T>(self, ctx: &'a Context<'a>, mut f: F) -> LocalBoxStream<'a, T>
+    where
+        Self: 'a,
+        F: FnMut(&Context<'a>, Self::Item) -> T + 'a,
+    {
+        self.map(move |item| f(ctx, item)).boxed_local()
+    }
+
+    /// Output the event returned by `initial`, e.g. a snapshot of the current state, before the
+    /// events of the stream.
+    ///
+    /// Create the stream, e.g. subscribe to a broker, before `initial` reads the state so that
+    /// no update is missed between them.
+    fn start_with<'a, F>(self, initial: F) -> LocalBoxStream<'a, Self::Item>
+    where
+        Self: 'a,
+        F: Future<Output = Self::Item> + 'a,
+    {
+        stream::once(initial).chain(self).boxed_local()
+    }
+
+    /// Set the id of the response of each event to the one returned by `f`, it is sent to the
+    /// client by the transports that support resuming subscriptions, see
+    /// `Context::last_event_id`.
+    fn event_ids<'a, F>(self, ctx: &'a Context<'a>, mut f: F) -> LocalBoxStream<'a, Self::Item>
+    where
+        Self: 'a,
+        F: FnMut(&Self::Item) -> String + 'a,
+    {
+        self.map(move |item| {
+            *ctx.query_env.event_id.lock() = Some(f(&item));
+            item
+        })
+        .boxed_local()
+    }
+
+    /// Only output an event once no other event arrived for `duration`, the events followed by
+    /// another one within `duration` are skipped. The last event is output when the stream
+    /// ends.
+    fn debounce<'a>(self, ctx: &Context<'_>, duration: Duration) -> LocalBoxStream<'a, Self::Item>
+    where
+        Self: 'a,
+    {
+        let timer = required_timer(ctx, "debounce");
+        let mut stream = self.boxed_local();
+        Box::pin(async_stream::stream! {
+            let mut pending = match stream.next().await {
+                Some(item) => item,
+                None => return,
+            };
+            loop {
+                match future::select(stream.next(), timer.sleep(duration)).await {
+                    Either::Left((Some(item), _)) => pending = item,
+                    Either::Left((None, _)) => {
+                        yield pending;
+                        return;
+                    }
+                    Either::Right(((), _)) => {
+                        yield pending;
+                        pending = match stream.next().await {
+                            Some(item) => item,
+                            None => return,
+                        };
+                    }
+                }
+            }
+        })
+    }
+
+    /// Output at most one event every `duration`, the events arriving within `duration` of the
+    /// last output one are skipped.
+    fn throttle<'a>(self, ctx: &Context<'_>, duration: Duration) -> LocalBoxStream<'a, Self::Item>
+    where
+        Self: 'a,
+    {
+        let timer = required_timer(ctx, "throttle");
+        let mut stream = self.boxed_local();
+        Box::pin(async_stream::stream! {
+            while let Some(item) = stream.next().await {
+                yield item;
+                let mut sleep = timer.sleep(duration);
+                loop {
+                    match future::select(stream.next(), sleep).await {
+                        Either::Left((Some(_), next_sleep)) => sleep = next_sleep,
+                        Either::Left((None, _)) => return,
+                        Either::Right(((), _)) => break,
+                    }
+                }
+            }
+        })
+    }
+
+    /// Output the events in batches, a batch holds the events arriving within `duration` of its
+    /// first one, at most `max_len` of them.
+    fn batch_within<'a>(
+        self,
+        ctx: &Context<'_>,
+        duration: Duration,
+        max_len: usize,
+    ) -> LocalBoxStream<'a, Vec<Self::Item>>
+    where
+        Self: 'a,
+    {
+        let timer = required_timer(ctx, "batch_within");
+        let mut stream = self.boxed_local();
+        Box::pin(async_stream::stream! {
+            while let Some(item) = stream.next().await {
+                let mut batch = vec![item];
+                let mut sleep = timer.sleep(duration);
+                while batch.len() < max_len {
+                    match future::select(stream.next(), sleep).await {
+                        Either::Left((Some(item), next_sleep)) => {
+                            batch.push(item);
+                            sleep = next_sleep;
+                        }
+                        Either::Left((None, _)) => {
+                            yield batch;
+                            return;
+                        }
+                        Either::Right(((), _)) => break,
+                    }
+                }
+                yield batch;
+            }
+        })
+    }
+
+    /// Output a [`SubscriptionMessage::KeepAlive`](enum.SubscriptionMessage.html) whenever no
+    /// event arrived for `period`, e.g. to keep proxies from closing the connection while a long
+    /// computation runs.
+    fn heartbeat<'a>(
+        self,
+        ctx: &Context<'_>,
+        period: Duration,
+    ) -> LocalBoxStream<'a, SubscriptionMessage<Self::Item>>
+    where
+        Self: 'a,
+    {
+        let timer = required_timer(ctx, "heartbeat");
+        let mut stream = self.boxed_local();
+        Box::pin(async_stream::stream! {
+            loop {
+                match future::select(stream.next(), timer.sleep(period)).await {
+                    Either::Left((Some(item), _)) => yield SubscriptionMessage::Event(item),
+                    Either::Left((None, _)) => return,
+                    Either::Right(((), _)) => yield SubscriptionMessage::KeepAlive,
+                }
+            }
+        })
+    }
+}
+
+impl<S: Stream> SubscriptionStreamExt for S {}
+
+fn required_timer(ctx: &Context<'_>, combinator: &str) -> Arc<dyn Timer> {
+    match &ctx.schema_env.timer {
+        Some(timer) => timer.clone(),
+        None => panic!(
+            "SubscriptionStreamExt::{} requires a timer, set one with SchemaBuilder::timer",
+            combinator
+        ),
+    }
+}
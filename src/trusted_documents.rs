@@ -0,0 +1,106 @@
+//! Trusted documents
+//!
+//! A server that only executes the operations of its own clients can register their documents
+//! with `SchemaBuilder::trusted_documents`. They are parsed and validated once, when the schema
+//! is built, and `Schema::execute_persisted` executes them by the SHA-256 hash of their source,
+//! without parsing or validating anything but the variables at runtime.
+//!
+//! [`include_trusted_documents!`](../macro.include_trusted_documents.html) embeds the `.graphql`
+//! files of a directory into the binary, and fails to compile if one of them can't be parsed.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use async_graphql::*;
+//! use async_graphql::trusted_documents::TrustedDocuments;
+//!
+//! struct Query;
+//!
+//! #[Object]
+//! impl Query {
+//!     async fn add(&self, a: i32, b: i32) -> i32 {
+//!         a + b
+//!     }
+//! }
+//!
+//! let source = "query Add($a: Int!) { add(a: $a, b: 10) }";
+//! let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+//!     .trusted_documents(TrustedDocuments::new().add("add.graphql", source))
+//!     .finish();
+//!
+//! async_std::task::block_on(async move {
+//!     let hash = TrustedDocuments::hash(source);
+//!     let variables = Variables::from_value(value!({ "a": 5 }));
+//!     let res = schema.execute_persisted(&hash, variables).await;
+//!     assert_eq!(res.data, value!({ "add": 15 }));
+//! });
+//! ```
+
+use std::collections::HashMap;
+
+use sha2::{Digest, Sha256};
+
+use crate::parser::types::{FragmentDefinition, OperationDefinition};
+use crate::{CacheControl, Name, Positioned};
+
+/// The sources of the trusted documents, by name.
+#[derive(Debug, Clone, Default)]
+pub struct TrustedDocuments {
+    pub(crate) documents: Vec<(String, String)>,
+}
+
+impl TrustedDocuments {
+    /// Creates an empty set of trusted documents.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the document `source`, `name` identifies it in the panic message of
+    /// `SchemaBuilder::finish` if it is invalid.
+    #[must_use]
+    pub fn add(mut self, name: impl Into<String>, source: impl Into<String>) -> Self {
+        self.documents.push((name.into(), source.into()));
+        self
+    }
+
+    /// The names of the documents and their hashes, to give them to the clients.
+    pub fn hashes(&self) -> impl Iterator<Item = (&str, String)> {
+        self.documents
+            .iter()
+            .map(|(name, source)| (name.as_str(), Self::hash(source)))
+    }
+
+    /// The hash of a document, the SHA-256 digest of its source as lowercase hexadecimal.
+    pub fn hash(source: &str) -> String {
+        format!("{:x}", Sha256::digest(source.as_bytes()))
+    }
+}
+
+/// A trusted document that has been parsed and validated.
+pub(crate) struct TrustedDocument {
+    pub(crate) operation_name: Option<Name>,
+    pub(crate) operation: Positioned<OperationDefinition>,
+    pub(crate) fragments: HashMap<Name, Positioned<FragmentDefinition>>,
+    pub(crate) cache_control: CacheControl,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(
+            TrustedDocuments::hash(""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            TrustedDocuments::hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            TrustedDocuments::hash(&"a".repeat(1000)),
+            "41edece42d63e8d9bf515a9ba6932e1c20cbc9f5a5d134645adb5db1b9737ea3"
+        );
+    }
+}
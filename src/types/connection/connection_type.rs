@@ -1,12 +1,11 @@
 use std::borrow::Cow;
 
 use futures_util::stream::{Stream, StreamExt, TryStreamExt};
-use indexmap::map::IndexMap;
 
 use crate::connection::edge::Edge;
 use crate::connection::page_info::PageInfo;
 use crate::parser::types::Field;
-use crate::resolver_utils::{resolve_container, ContainerType};
+use crate::resolver_utils::{resolve_container, resolve_field_value, ContainerType};
 use crate::types::connection::{CursorType, EmptyFields};
 use crate::{
     registry, Context, ContextSelectionSet, ObjectType, OutputValueType, Positioned, Result,
@@ -141,51 +140,24 @@ where
                 unreachable!()
             };
 
-            registry::MetaType::Object {
-                name: Self::type_name().to_string(),
-                description: None,
-                fields: {
-                    let mut fields = IndexMap::new();
-
-                    fields.insert(
-                        "pageInfo".to_string(),
-                        registry::MetaField {
-                            name: "pageInfo".to_string(),
-                            description: Some("Information to aid in pagination."),
-                            args: Default::default(),
-                            ty: PageInfo::create_type_info(registry),
-                            deprecation: None,
-                            cache_control: Default::default(),
-                            external: false,
-                            requires: None,
-                            provides: None,
-                        },
-                    );
-
-                    fields.insert(
-                        "edges".to_string(),
-                        registry::MetaField {
-                            name: "edges".to_string(),
-                            description: Some("A list of edges."),
-                            args: Default::default(),
-                            ty: <Option<Vec<Option<Edge<C, T, EE>>>> as Type>::create_type_info(
-                                registry,
-                            ),
-                            deprecation: None,
-                            cache_control: Default::default(),
-                            external: false,
-                            requires: None,
-                            provides: None,
-                        },
-                    );
-
-                    fields.extend(additional_fields);
-                    fields
-                },
-                cache_control: Default::default(),
-                extends: false,
-                keys: None,
-            }
+            registry::MetaType::object(
+                Self::type_name(),
+                Some(format!(
+                    "A connection to a list of {} items.",
+                    T::type_name()
+                )),
+                vec![
+                    registry::MetaField::new("pageInfo", PageInfo::create_type_info(registry))
+                        .description("Information to aid in pagination."),
+                    registry::MetaField::new(
+                        "edges",
+                        <Option<Vec<Option<Edge<C, T, EE>>>> as Type>::create_type_info(registry),
+                    )
+                    .description("A list of edges."),
+                ]
+                .into_iter()
+                .chain(additional_fields.into_iter().map(|(_, field)| field)),
+            )
         })
     }
 }
@@ -199,25 +171,19 @@ where
     EE: ObjectType,
 {
     async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
-        if ctx.item.node.name.node == "pageInfo" {
-            let page_info = PageInfo {
-                has_previous_page: self.has_previous_page,
-                has_next_page: self.has_next_page,
-                start_cursor: self.edges.first().map(|edge| edge.cursor.encode_cursor()),
-                end_cursor: self.edges.last().map(|edge| edge.cursor.encode_cursor()),
-            };
-            let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
-            return OutputValueType::resolve(&page_info, &ctx_obj, ctx.item)
-                .await
-                .map(Some);
-        } else if ctx.item.node.name.node == "edges" {
-            let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
-            return OutputValueType::resolve(&self.edges, &ctx_obj, ctx.item)
-                .await
-                .map(Some);
+        match ctx.item.node.name.node.as_str() {
+            "pageInfo" => {
+                let page_info = PageInfo {
+                    has_previous_page: self.has_previous_page,
+                    has_next_page: self.has_next_page,
+                    start_cursor: self.edges.first().map(|edge| edge.cursor.encode_cursor()),
+                    end_cursor: self.edges.last().map(|edge| edge.cursor.encode_cursor()),
+                };
+                resolve_field_value(ctx, &page_info).await
+            }
+            "edges" => resolve_field_value(ctx, &self.edges).await,
+            _ => self.additional_fields.resolve_field(ctx).await,
         }
-
-        self.additional_fields.resolve_field(ctx).await
     }
 }
 
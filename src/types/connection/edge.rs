@@ -1,10 +1,8 @@
 use std::borrow::Cow;
 
-use indexmap::map::IndexMap;
-
 use crate::connection::EmptyFields;
 use crate::parser::types::Field;
-use crate::resolver_utils::{resolve_container, ContainerType};
+use crate::resolver_utils::{resolve_container, resolve_field_value, ContainerType};
 use crate::types::connection::CursorType;
 use crate::{
     registry, Context, ContextSelectionSet, ObjectType, OutputValueType, Positioned, ServerResult,
@@ -52,6 +50,8 @@ where
 
     fn create_type_info(registry: &mut registry::Registry) -> String {
         registry.create_type::<Self, _>(|registry| {
+            registry.inherit_description(Self::type_name(), T::type_name());
+
             let additional_fields = if let registry::MetaType::Object { fields, .. } =
                 registry.create_dummy_type::<E>()
             {
@@ -60,49 +60,18 @@ where
                 unreachable!()
             };
 
-            registry::MetaType::Object {
-                name: Self::type_name().to_string(),
-                description: Some("An edge in a connection."),
-                fields: {
-                    let mut fields = IndexMap::new();
-
-                    fields.insert(
-                        "node".to_string(),
-                        registry::MetaField {
-                            name: "node".to_string(),
-                            description: Some("The item at the end of the edge"),
-                            args: Default::default(),
-                            ty: T::create_type_info(registry),
-                            deprecation: None,
-                            cache_control: Default::default(),
-                            external: false,
-                            requires: None,
-                            provides: None,
-                        },
-                    );
-
-                    fields.insert(
-                        "cursor".to_string(),
-                        registry::MetaField {
-                            name: "cursor".to_string(),
-                            description: Some("A cursor for use in pagination"),
-                            args: Default::default(),
-                            ty: String::create_type_info(registry),
-                            deprecation: None,
-                            cache_control: Default::default(),
-                            external: false,
-                            requires: None,
-                            provides: None,
-                        },
-                    );
-
-                    fields.extend(additional_fields);
-                    fields
-                },
-                cache_control: Default::default(),
-                extends: false,
-                keys: None,
-            }
+            registry::MetaType::object(
+                Self::type_name(),
+                Some("An edge in a connection.".to_string()),
+                vec![
+                    registry::MetaField::new("node", T::create_type_info(registry))
+                        .description("The item at the end of the edge"),
+                    registry::MetaField::new("cursor", String::create_type_info(registry))
+                        .description("A cursor for use in pagination"),
+                ]
+                .into_iter()
+                .chain(additional_fields.into_iter().map(|(_, field)| field)),
+            )
         })
     }
 }
@@ -115,16 +84,11 @@ where
     E: ObjectType,
 {
     async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
-        if ctx.item.node.name.node == "node" {
-            let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
-            return OutputValueType::resolve(&self.node, &ctx_obj, ctx.item)
-                .await
-                .map(Some);
-        } else if ctx.item.node.name.node == "cursor" {
-            return Ok(Some(Value::String(self.cursor.encode_cursor())));
+        match ctx.item.node.name.node.as_str() {
+            "node" => resolve_field_value(ctx, &self.node).await,
+            "cursor" => Ok(Some(Value::String(self.cursor.encode_cursor()))),
+            _ => self.additional_fields.resolve_field(ctx).await,
         }
-
-        self.additional_fields.resolve_field(ctx).await
     }
 }
 
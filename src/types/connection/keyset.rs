@@ -0,0 +1,288 @@
+use crate::connection::{Connection, CursorType, Edge};
+use crate::{Result, Value};
+
+/// A cursor made of the values of the sort key of a row, e.g. `(created_at, id)`.
+///
+/// It is encoded as a JSON array.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeysetCursor(pub Vec<Value>);
+
+impl CursorType for KeysetCursor {
+    type Error = serde_json::Error;
+
+    fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
+        serde_json::from_str(s).map(KeysetCursor)
+    }
+
+    fn encode_cursor(&self) -> String {
+        serde_json::to_string(&self.0).unwrap_or_default()
+    }
+}
+
+/// The style of the SQL parameter placeholders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Placeholder {
+    /// `?`, as used by MySQL and SQLite.
+    QuestionMark,
+    /// `$1`, `$2`, ..., as used by PostgreSQL. The numbering starts after the specified number
+    /// of parameters that come before the pagination parameters in the statement.
+    Numbered(usize),
+}
+
+/// Maps the arguments of a Relay connection to keyset pagination SQL.
+///
+/// The rows must be ordered by a unique key, e.g. `(created_at, id)`. Instead of an offset, a
+/// page starts right after (or ends right before) the key of a cursor, so pages stay stable when
+/// rows are inserted and the database can use an index on the key.
+///
+/// The column names are inserted into the SQL as they are, so they must not come from user
+/// input. The cursor values are always passed as parameters.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use async_graphql::connection::*;
+///
+/// #[derive(SimpleObject, Clone)]
+/// struct Post {
+///     id: i32,
+///     created_at: i32,
+/// }
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn posts(
+///         &self,
+///         after: Option<String>,
+///         before: Option<String>,
+///         first: Option<i32>,
+///         last: Option<i32>,
+///     ) -> Result<Connection<KeysetCursor, Post>> {
+///         query(after, before, first, last, |after, before, first, last| async move {
+///             let keyset = KeysetPagination::new(&["created_at", "id"])
+///                 .build(after, before, first, last)?;
+///             let sql = format!("SELECT id, created_at FROM posts{}", keyset.sql());
+///             assert_eq!(sql, "SELECT id, created_at FROM posts ORDER BY created_at ASC, id ASC LIMIT 3");
+///             // Run `sql` with `keyset.params()`.
+///             let rows = vec![
+///                 Post { id: 1, created_at: 10 },
+///                 Post { id: 2, created_at: 10 },
+///                 Post { id: 3, created_at: 11 },
+///             ];
+///             Ok(keyset.connection(rows, |post| vec![post.created_at.into(), post.id.into()]))
+///         })
+///         .await
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+///     let res = schema.execute("{ posts(first: 2) { edges { cursor } pageInfo { hasNextPage } } }").await;
+///     assert_eq!(res.data, value!({
+///         "posts": {
+///             "edges": [{ "cursor": "[10,1]" }, { "cursor": "[10,2]" }],
+///             "pageInfo": { "hasNextPage": true },
+///         }
+///     }));
+/// });
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeysetPagination {
+    columns: Vec<String>,
+    descending: bool,
+    placeholder: Placeholder,
+}
+
+impl KeysetPagination {
+    /// Paginate over the specified key columns, in ascending order.
+    pub fn new<T: AsRef<str>>(columns: &[T]) -> Self {
+        Self {
+            columns: columns
+                .iter()
+                .map(|column| column.as_ref().to_string())
+                .collect(),
+            descending: false,
+            placeholder: Placeholder::QuestionMark,
+        }
+    }
+
+    /// Whether the rows are sorted in descending order, default is false.
+    #[must_use]
+    pub fn descending(self, descending: bool) -> Self {
+        Self { descending, ..self }
+    }
+
+    /// Set the style of the parameter placeholders, default is `Placeholder::QuestionMark`.
+    #[must_use]
+    pub fn placeholder(self, placeholder: Placeholder) -> Self {
+        Self {
+            placeholder,
+            ..self
+        }
+    }
+
+    /// Build the query of a page from the parsed connection arguments, as passed to the closure
+    /// of [`query`](fn.query.html).
+    ///
+    /// Fails if a cursor doesn't have one value per key column, or if both `first` and `last`
+    /// are set.
+    pub fn build(
+        &self,
+        after: Option<KeysetCursor>,
+        before: Option<KeysetCursor>,
+        first: Option<usize>,
+        last: Option<usize>,
+    ) -> Result<KeysetQuery> {
+        if first.is_some() && last.is_some() {
+            return Err(
+                "The \"first\" and \"last\" parameters cannot exist at the same time".into(),
+            );
+        }
+        for cursor in after.iter().chain(&before) {
+            if cursor.0.len() != self.columns.len() {
+                return Err(format!(
+                    "The cursor must have {} values, but it has {}",
+                    self.columns.len(),
+                    cursor.0.len()
+                )
+                .into());
+            }
+        }
+
+        let columns = format!("({})", self.columns.join(", "));
+        let mut conditions = Vec::new();
+        let mut params = Vec::new();
+        let (after_op, before_op) = if self.descending {
+            ("<", ">")
+        } else {
+            (">", "<")
+        };
+        for (cursor, op) in after
+            .iter()
+            .map(|cursor| (cursor, after_op))
+            .chain(before.iter().map(|cursor| (cursor, before_op)))
+        {
+            let placeholders: Vec<_> = cursor
+                .0
+                .iter()
+                .map(|value| {
+                    params.push(value.clone());
+                    match self.placeholder {
+                        Placeholder::QuestionMark => "?".to_string(),
+                        Placeholder::Numbered(offset) => format!("${}", offset + params.len()),
+                    }
+                })
+                .collect();
+            conditions.push(format!("{} {} ({})", columns, op, placeholders.join(", ")));
+        }
+
+        // Paginating backwards reads the rows in reverse order from the end of the page.
+        let reversed = last.is_some();
+        let ascending = self.descending == reversed;
+        let order_by = self
+            .columns
+            .iter()
+            .map(|column| format!("{} {}", column, if ascending { "ASC" } else { "DESC" }))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Ok(KeysetQuery {
+            condition: if conditions.is_empty() {
+                None
+            } else {
+                Some(conditions.join(" AND "))
+            },
+            order_by,
+            params,
+            page_size: first.or(last),
+            reversed,
+            has_after: after.is_some(),
+            has_before: before.is_some(),
+        })
+    }
+}
+
+/// The SQL of a page, returned by [`KeysetPagination::build`](struct.KeysetPagination.html#method.build).
+#[derive(Debug, Clone)]
+pub struct KeysetQuery {
+    condition: Option<String>,
+    order_by: String,
+    params: Vec<Value>,
+    page_size: Option<usize>,
+    reversed: bool,
+    has_after: bool,
+    has_before: bool,
+}
+
+impl KeysetQuery {
+    /// The condition selecting the rows between the cursors, e.g. `(created_at, id) > (?, ?)`.
+    pub fn condition(&self) -> Option<&str> {
+        self.condition.as_deref()
+    }
+
+    /// The columns to sort by, e.g. `created_at ASC, id ASC`.
+    pub fn order_by(&self) -> &str {
+        &self.order_by
+    }
+
+    /// The maximum number of rows to fetch. It is one more than the page size, the extra row
+    /// tells whether there is another page.
+    pub fn limit(&self) -> Option<usize> {
+        self.page_size.map(|page_size| page_size + 1)
+    }
+
+    /// The values of the parameters of the condition, in order.
+    pub fn params(&self) -> &[Value] {
+        &self.params
+    }
+
+    /// The `WHERE`, `ORDER BY` and `LIMIT` clauses, with a leading space, to append to a
+    /// `SELECT` statement without any of these clauses.
+    pub fn sql(&self) -> String {
+        let mut sql = String::new();
+        if let Some(condition) = &self.condition {
+            sql.push_str(" WHERE ");
+            sql.push_str(condition);
+        }
+        sql.push_str(" ORDER BY ");
+        sql.push_str(&self.order_by);
+        if let Some(limit) = self.limit() {
+            sql.push_str(&format!(" LIMIT {}", limit));
+        }
+        sql
+    }
+
+    /// Build the connection from the rows returned by the query, in the order they were
+    /// returned. `key` returns the values of the key columns of a row, they are used as the
+    /// cursor of its edge.
+    pub fn connection<T, F>(&self, mut rows: Vec<T>, mut key: F) -> Connection<KeysetCursor, T>
+    where
+        F: FnMut(&T) -> Vec<Value>,
+    {
+        let has_more = match self.page_size {
+            Some(page_size) if rows.len() > page_size => {
+                rows.truncate(page_size);
+                true
+            }
+            _ => false,
+        };
+        if self.reversed {
+            rows.reverse();
+        }
+        let (has_previous_page, has_next_page) = if self.reversed {
+            (has_more, self.has_before)
+        } else {
+            (self.has_after, has_more || self.has_before)
+        };
+
+        let mut connection = Connection::new(has_previous_page, has_next_page);
+        connection.append(
+            rows.into_iter()
+                .map(|row| Edge::new(KeysetCursor(key(&row)), row)),
+        );
+        connection
+    }
+}
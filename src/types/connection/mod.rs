@@ -3,6 +3,7 @@
 mod connection_type;
 mod cursor;
 mod edge;
+mod keyset;
 mod page_info;
 
 use std::fmt::Display;
@@ -12,6 +13,7 @@ use crate::{Result, SimpleObject};
 pub use connection_type::Connection;
 pub use cursor::CursorType;
 pub use edge::Edge;
+pub use keyset::{KeysetCursor, KeysetPagination, KeysetQuery, Placeholder};
 pub use page_info::PageInfo;
 
 /// Empty additional fields
@@ -0,0 +1,429 @@
+//! Scaffolding of CRUD fields for admin-style APIs
+//!
+//! Implement [`DataSource`](trait.DataSource.html) for a type, then merge
+//! [`CrudQuery`](struct.CrudQuery.html) into the query root and
+//! [`CrudMutation`](struct.CrudMutation.html) into the mutation root. For an item type called
+//! `Book` this generates the following fields:
+//!
+//! ```graphql
+//! type Query {
+//!   book(id: ID!): Book
+//!   books(after: String, before: String, first: Int, last: Int): BookConnection!
+//! }
+//!
+//! type Mutation {
+//!   createBook(input: CreateInput!): Book!
+//!   updateBook(id: ID!, input: UpdateInput!): Book
+//!   deleteBook(id: ID!): Boolean!
+//! }
+//! ```
+//!
+//! `CreateInput` and `UpdateInput` stand for the input types of the data source. The field names
+//! can be changed with [`DataSource::singular_name`](trait.DataSource.html#method.singular_name)
+//! and [`DataSource::plural_name`](trait.DataSource.html#method.plural_name).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::cell::RefCell;
+//! use async_graphql::*;
+//! use async_graphql::connection::*;
+//! use async_graphql::crud::*;
+//!
+//! #[derive(SimpleObject, Clone)]
+//! struct Book {
+//!     id: ID,
+//!     title: String,
+//! }
+//!
+//! #[derive(InputObject)]
+//! struct NewBook {
+//!     title: String,
+//! }
+//!
+//! #[derive(Default)]
+//! struct Books(RefCell<Vec<Book>>);
+//!
+//! #[async_trait::async_trait(?Send)]
+//! impl DataSource for Books {
+//!     type Item = Book;
+//!     type Cursor = usize;
+//!     type CreateInput = NewBook;
+//!     type UpdateInput = NewBook;
+//!
+//!     async fn get(&self, _ctx: &Context<'_>, id: ID) -> Result<Option<Book>> {
+//!         Ok(self.0.borrow().iter().find(|book| book.id == id).cloned())
+//!     }
+//!
+//!     async fn list(
+//!         &self,
+//!         _ctx: &Context<'_>,
+//!         after: Option<usize>,
+//!         _before: Option<usize>,
+//!         first: Option<usize>,
+//!         _last: Option<usize>,
+//!     ) -> Result<Connection<usize, Book>> {
+//!         let books = self.0.borrow();
+//!         let start = after.map(|after| after + 1).unwrap_or(0);
+//!         let end = first.map(|first| (start + first).min(books.len())).unwrap_or(books.len());
+//!         let mut connection = Connection::new(start > 0, end < books.len());
+//!         connection.append((start..end).map(|n| Edge::new(n, books[n].clone())));
+//!         Ok(connection)
+//!     }
+//!
+//!     async fn create(&self, _ctx: &Context<'_>, input: NewBook) -> Result<Book> {
+//!         let mut books = self.0.borrow_mut();
+//!         let book = Book { id: books.len().into(), title: input.title };
+//!         books.push(book.clone());
+//!         Ok(book)
+//!     }
+//!
+//!     async fn update(&self, _ctx: &Context<'_>, id: ID, input: NewBook) -> Result<Option<Book>> {
+//!         let mut books = self.0.borrow_mut();
+//!         Ok(books.iter_mut().find(|book| book.id == id).map(|book| {
+//!             book.title = input.title;
+//!             book.clone()
+//!         }))
+//!     }
+//!
+//!     async fn delete(&self, _ctx: &Context<'_>, id: ID) -> Result<bool> {
+//!         let mut books = self.0.borrow_mut();
+//!         let len = books.len();
+//!         books.retain(|book| book.id != id);
+//!         Ok(books.len() < len)
+//!     }
+//! }
+//!
+//! async_std::task::block_on(async move {
+//!     let schema = Schema::new(
+//!         CrudQuery(Books::default()),
+//!         CrudMutation(Books::default()),
+//!         EmptySubscription,
+//!     );
+//!     let res = schema.execute(r#"mutation { createBook(input: { title: "Dune" }) { id } }"#).await;
+//!     assert_eq!(res.data, value!({ "createBook": { "id": "0" } }));
+//! });
+//! ```
+
+use std::borrow::Cow;
+use std::fmt::Display;
+
+use indexmap::map::IndexMap;
+
+use crate::connection::{query, Connection, CursorType};
+use crate::parser::types::Field;
+use crate::registry::{MetaField, MetaInputValue, MetaType, Registry};
+use crate::resolver_utils::{resolve_container, ContainerType};
+use crate::{
+    Context, ContextSelectionSet, InputValueType, ObjectType, OutputValueType, Positioned, Result,
+    ServerResult, Type, Value, ID,
+};
+
+/// The storage behind the fields generated by [`CrudQuery`](struct.CrudQuery.html) and
+/// [`CrudMutation`](struct.CrudMutation.html).
+#[async_trait::async_trait(?Send)]
+pub trait DataSource {
+    /// The type of the items, usually a `SimpleObject`.
+    type Item: OutputValueType;
+
+    /// The cursor used to paginate the list of items.
+    type Cursor: CursorType;
+
+    /// The input of the create mutation, usually an `InputObject`.
+    type CreateInput: InputValueType;
+
+    /// The input of the update mutation, usually an `InputObject`.
+    type UpdateInput: InputValueType;
+
+    /// The name of the field returning a single item, the item type name in camel case by
+    /// default, e.g. `book` for `Book`.
+    fn singular_name() -> String {
+        let type_name = Self::Item::type_name();
+        let mut chars = type_name.chars();
+        match chars.next() {
+            Some(c) => c.to_lowercase().chain(chars).collect(),
+            None => String::new(),
+        }
+    }
+
+    /// The name of the field returning the list of items, the singular name followed by `s` by
+    /// default, e.g. `books` for `Book`.
+    fn plural_name() -> String {
+        format!("{}s", Self::singular_name())
+    }
+
+    /// Get the item with the specified id.
+    async fn get(&self, ctx: &Context<'_>, id: ID) -> Result<Option<Self::Item>>;
+
+    /// Get a page of items, the parameters have been validated like in
+    /// [`connection::query`](connection/fn.query.html).
+    async fn list(
+        &self,
+        ctx: &Context<'_>,
+        after: Option<Self::Cursor>,
+        before: Option<Self::Cursor>,
+        first: Option<usize>,
+        last: Option<usize>,
+    ) -> Result<Connection<Self::Cursor, Self::Item>>;
+
+    /// Create an item.
+    async fn create(&self, ctx: &Context<'_>, input: Self::CreateInput) -> Result<Self::Item>;
+
+    /// Update the item with the specified id, returns `None` if there is no such item.
+    async fn update(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+        input: Self::UpdateInput,
+    ) -> Result<Option<Self::Item>>;
+
+    /// Delete the item with the specified id, returns whether it existed.
+    async fn delete(&self, ctx: &Context<'_>, id: ID) -> Result<bool>;
+}
+
+fn input_value(name: &'static str, ty: String) -> MetaInputValue {
+    MetaInputValue {
+        name,
+        description: None,
+        ty,
+        default_value: None,
+        validator: None,
+        is_secret: false,
+    }
+}
+
+fn field(
+    name: String,
+    description: &'static str,
+    args: Vec<MetaInputValue>,
+    ty: String,
+) -> (String, MetaField) {
+    (
+        name.clone(),
+        MetaField {
+            name,
+            description: Some(description),
+            args: args.into_iter().map(|arg| (arg.name, arg)).collect(),
+            ty,
+            deprecation: None,
+            cache_control: Default::default(),
+            external: false,
+            requires: None,
+            provides: None,
+        },
+    )
+}
+
+/// The query fields of a [`DataSource`](trait.DataSource.html): `item(id)` and
+/// `items(after, before, first, last)`.
+pub struct CrudQuery<D>(pub D);
+
+impl<D: DataSource> Type for CrudQuery<D>
+where
+    <D::Cursor as CursorType>::Error: Display + 'static,
+{
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(format!("{}CrudQuery", D::Item::type_name()))
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        registry.create_type::<Self, _>(|registry| {
+            let mut fields = IndexMap::new();
+            fields.extend(vec![
+                field(
+                    D::singular_name(),
+                    "Get an item by its id.",
+                    vec![input_value("id", ID::create_type_info(registry))],
+                    <Option<D::Item>>::create_type_info(registry),
+                ),
+                field(
+                    D::plural_name(),
+                    "Get a page of items.",
+                    vec![
+                        input_value("after", <Option<String>>::create_type_info(registry)),
+                        input_value("before", <Option<String>>::create_type_info(registry)),
+                        input_value("first", <Option<i32>>::create_type_info(registry)),
+                        input_value("last", <Option<i32>>::create_type_info(registry)),
+                    ],
+                    Connection::<D::Cursor, D::Item>::create_type_info(registry),
+                ),
+            ]);
+            MetaType::Object {
+                name: Self::type_name().to_string(),
+                description: None,
+                fields,
+                cache_control: Default::default(),
+                extends: false,
+                keys: None,
+            }
+        })
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<D: DataSource> ContainerType for CrudQuery<D>
+where
+    <D::Cursor as CursorType>::Error: Display + 'static,
+{
+    async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+        let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
+        if ctx.item.node.name.node == D::singular_name() {
+            let id = ctx.param_value("id", None)?;
+            let res = self
+                .0
+                .get(ctx, id)
+                .await
+                .map_err(|err| err.into_server_error().at(ctx.item.pos))?;
+            return OutputValueType::resolve(&res, &ctx_obj, ctx.item)
+                .await
+                .map(Some);
+        } else if ctx.item.node.name.node == D::plural_name() {
+            let after = ctx.param_value("after", None)?;
+            let before = ctx.param_value("before", None)?;
+            let first = ctx.param_value("first", None)?;
+            let last = ctx.param_value("last", None)?;
+            let res = query(after, before, first, last, |after, before, first, last| {
+                self.0.list(ctx, after, before, first, last)
+            })
+            .await
+            .map_err(|err| err.into_server_error().at(ctx.item.pos))?;
+            return OutputValueType::resolve(&res, &ctx_obj, ctx.item)
+                .await
+                .map(Some);
+        }
+        Ok(None)
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<D: DataSource> OutputValueType for CrudQuery<D>
+where
+    <D::Cursor as CursorType>::Error: Display + 'static,
+{
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        resolve_container(ctx, self).await
+    }
+}
+
+impl<D: DataSource> ObjectType for CrudQuery<D> where
+    <D::Cursor as CursorType>::Error: Display + 'static
+{
+}
+
+/// The mutation fields of a [`DataSource`](trait.DataSource.html): `createItem(input)`,
+/// `updateItem(id, input)` and `deleteItem(id)`.
+pub struct CrudMutation<D>(pub D);
+
+impl<D: DataSource> CrudMutation<D> {
+    fn field_name(action: &str) -> String {
+        let singular_name = D::singular_name();
+        let mut chars = singular_name.chars();
+        match chars.next() {
+            Some(c) => format!("{}{}{}", action, c.to_uppercase(), chars.as_str()),
+            None => action.to_string(),
+        }
+    }
+}
+
+impl<D: DataSource> Type for CrudMutation<D> {
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(format!("{}CrudMutation", D::Item::type_name()))
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        registry.create_type::<Self, _>(|registry| {
+            let mut fields = IndexMap::new();
+            fields.extend(vec![
+                field(
+                    Self::field_name("create"),
+                    "Create an item.",
+                    vec![input_value(
+                        "input",
+                        D::CreateInput::create_type_info(registry),
+                    )],
+                    D::Item::create_type_info(registry),
+                ),
+                field(
+                    Self::field_name("update"),
+                    "Update an item, returns null if there is no item with this id.",
+                    vec![
+                        input_value("id", ID::create_type_info(registry)),
+                        input_value("input", D::UpdateInput::create_type_info(registry)),
+                    ],
+                    <Option<D::Item>>::create_type_info(registry),
+                ),
+                field(
+                    Self::field_name("delete"),
+                    "Delete an item, returns whether there was an item with this id.",
+                    vec![input_value("id", ID::create_type_info(registry))],
+                    bool::create_type_info(registry),
+                ),
+            ]);
+            MetaType::Object {
+                name: Self::type_name().to_string(),
+                description: None,
+                fields,
+                cache_control: Default::default(),
+                extends: false,
+                keys: None,
+            }
+        })
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<D: DataSource> ContainerType for CrudMutation<D> {
+    async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+        let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
+        if ctx.item.node.name.node == Self::field_name("create") {
+            let input = ctx.param_value("input", None)?;
+            let res = self
+                .0
+                .create(ctx, input)
+                .await
+                .map_err(|err| err.into_server_error().at(ctx.item.pos))?;
+            return OutputValueType::resolve(&res, &ctx_obj, ctx.item)
+                .await
+                .map(Some);
+        } else if ctx.item.node.name.node == Self::field_name("update") {
+            let id = ctx.param_value("id", None)?;
+            let input = ctx.param_value("input", None)?;
+            let res = self
+                .0
+                .update(ctx, id, input)
+                .await
+                .map_err(|err| err.into_server_error().at(ctx.item.pos))?;
+            return OutputValueType::resolve(&res, &ctx_obj, ctx.item)
+                .await
+                .map(Some);
+        } else if ctx.item.node.name.node == Self::field_name("delete") {
+            let id = ctx.param_value("id", None)?;
+            let res = self
+                .0
+                .delete(ctx, id)
+                .await
+                .map_err(|err| err.into_server_error().at(ctx.item.pos))?;
+            return OutputValueType::resolve(&res, &ctx_obj, ctx.item)
+                .await
+                .map(Some);
+        }
+        Ok(None)
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<D: DataSource> OutputValueType for CrudMutation<D> {
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        resolve_container(ctx, self).await
+    }
+}
+
+impl<D: DataSource> ObjectType for CrudMutation<D> {}
@@ -0,0 +1,283 @@
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::parser::types::{Field, Selection, SelectionSet};
+use crate::registry::{MetaType, MetaTypeName, Registry};
+use crate::{
+    ContextSelectionSet, Map, OutputValueType, PathSegment, Positioned, ServerError, ServerResult,
+    Type, Value,
+};
+
+type TypenameFn = dyn Fn(&Value) -> String;
+
+/// A value of the interface or union `T` built at runtime, e.g. from the response of another
+/// service, instead of a Rust type for each of its possible types.
+///
+/// The value is an object with the fields of its type, by name, and the fields selected by the
+/// query are picked from it, following the fragments that apply to its type. The name of its
+/// type, and of the values of interface and union types nested in it, is returned by the
+/// `typename` function, it must be a possible type of the interface or the union. The missing
+/// fields are `null`.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// #[derive(SimpleObject)]
+/// struct Book {
+///     title: String,
+/// }
+///
+/// #[derive(SimpleObject)]
+/// struct Movie {
+///     title: String,
+///     minutes: i32,
+/// }
+///
+/// #[derive(Union)]
+/// enum Media {
+///     Book(Book),
+///     Movie(Movie),
+/// }
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn media(&self) -> Vec<DynamicObject<Media>> {
+///         let items = vec![
+///             value!({ "kind": "book", "title": "Dune" }),
+///             value!({ "kind": "movie", "title": "Alien", "minutes": 117 }),
+///         ];
+///         DynamicObject::list(items, |item| match item {
+///             Value::Object(item) if item.get("kind") == Some(&value!("book")) => "Book".to_string(),
+///             _ => "Movie".to_string(),
+///         })
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+///     let res = schema
+///         .execute("{ media { __typename ... on Movie { title minutes } } }")
+///         .await;
+///     assert_eq!(res.data, value!({
+///         "media": [
+///             { "__typename": "Book" },
+///             { "__typename": "Movie", "title": "Alien", "minutes": 117 },
+///         ]
+///     }));
+/// });
+/// ```
+pub struct DynamicObject<T> {
+    value: Value,
+    typename: Rc<TypenameFn>,
+    _marker: PhantomData<T>,
+}
+
+impl<T> DynamicObject<T> {
+    /// Create a value of `T`, the name of its type is returned by `typename`.
+    pub fn new(value: Value, typename: impl Fn(&Value) -> String + 'static) -> Self {
+        Self {
+            value,
+            typename: Rc::new(typename),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Create a list of values of `T`, the names of their types are returned by `typename`.
+    pub fn list(
+        values: impl IntoIterator<Item = Value>,
+        typename: impl Fn(&Value) -> String + 'static,
+    ) -> Vec<Self> {
+        let typename: Rc<TypenameFn> = Rc::new(typename);
+        values
+            .into_iter()
+            .map(|value| Self {
+                value,
+                typename: typename.clone(),
+                _marker: PhantomData,
+            })
+            .collect()
+    }
+}
+
+impl<T: Type> Type for DynamicObject<T> {
+    fn type_name() -> Cow<'static, str> {
+        T::type_name()
+    }
+
+    fn introspection_type_name(&self) -> Cow<'static, str> {
+        Cow::Owned((self.typename)(&self.value))
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        T::create_type_info(registry)
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<T: Type> OutputValueType for DynamicObject<T> {
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        project_value(ctx, &T::type_name(), &self.value, &*self.typename)
+            .map_err(|e| e.at(field.pos))
+    }
+}
+
+/// Pick the fields selected in `ctx` from `value`, a value of the named type `type_name`.
+fn project_value(
+    ctx: &ContextSelectionSet<'_>,
+    type_name: &str,
+    value: &Value,
+    typename: &TypenameFn,
+) -> ServerResult<Value> {
+    match value {
+        Value::Null => Ok(Value::Null),
+        Value::List(items) => {
+            let mut projected = Vec::with_capacity(items.len());
+            for (idx, item) in items.iter().enumerate() {
+                let ctx_idx = ctx.with_index(idx);
+                let item = project_value(&ctx_idx, type_name, item, typename)
+                    .map_err(|e| e.path(PathSegment::Index(idx)))?;
+                ctx_idx.count_response_value(None, &item)?;
+                projected.push(item);
+            }
+            Ok(Value::List(projected))
+        }
+        Value::Object(object) => {
+            let registry = &ctx.schema_env.registry;
+            let concrete_type = match registry.types.get(type_name) {
+                Some(ty) if ty.is_abstract() => {
+                    let concrete_type = typename(value);
+                    if !ty.is_possible_type(&concrete_type) {
+                        return Err(ServerError::new(format!(
+                            r#""{}" is not a possible type of "{}"."#,
+                            concrete_type, type_name
+                        )));
+                    }
+                    concrete_type
+                }
+                _ => type_name.to_string(),
+            };
+            let mut projected = Map::default();
+            project_selection_set(
+                ctx,
+                &ctx.item,
+                &concrete_type,
+                object,
+                typename,
+                &mut projected,
+            )?;
+            Ok(Value::Object(projected))
+        }
+        _ => Err(ServerError::new(format!(
+            r#"Expected an object of type "{}", found {}."#,
+            type_name, value
+        ))),
+    }
+}
+
+/// Add the fields of `selection_set` that apply to the object type `type_name` to `projected`.
+fn project_selection_set(
+    ctx: &ContextSelectionSet<'_>,
+    selection_set: &Positioned<SelectionSet>,
+    type_name: &str,
+    object: &Map<Value>,
+    typename: &TypenameFn,
+    projected: &mut Map<Value>,
+) -> ServerResult<()> {
+    let registry = &ctx.schema_env.registry;
+    for selection in &selection_set.node.items {
+        if ctx.is_skip(&selection.node.directives())? {
+            continue;
+        }
+
+        let (type_condition, selection_set) = match &selection.node {
+            Selection::Field(field) => {
+                let response_key = field.node.response_key().node.clone();
+                let ctx_field = ctx.with_field(field);
+                let value = if field.node.name.node == "__typename" {
+                    Value::String(type_name.to_string())
+                } else {
+                    let value = object
+                        .get(field.node.name.node.as_str())
+                        .cloned()
+                        .unwrap_or_default();
+                    if field.node.selection_set.node.items.is_empty() {
+                        value
+                    } else {
+                        let field_type = registry
+                            .types
+                            .get(type_name)
+                            .and_then(|ty| ty.field_by_name(&field.node.name.node))
+                            .map(|field| MetaTypeName::concrete_typename(&field.ty))
+                            .unwrap_or_default();
+                        project_value(
+                            &ctx_field.with_selection_set(&field.node.selection_set),
+                            field_type,
+                            &value,
+                            typename,
+                        )
+                        .map_err(|e| e.at(field.pos))?
+                    }
+                };
+                ctx_field
+                    .count_response_value(Some(&response_key), &value)
+                    .map_err(|e| e.path(PathSegment::Field(response_key.to_string())))?;
+
+                match (projected.get_mut(&response_key), value) {
+                    (Some(Value::Object(existing)), Value::Object(value)) => existing.extend(value),
+                    (_, value) => {
+                        projected.insert(response_key, value);
+                    }
+                }
+                continue;
+            }
+            Selection::FragmentSpread(spread) => {
+                match ctx.query_env.fragments.get(&spread.node.fragment_name.node) {
+                    Some(fragment) => (
+                        Some(&fragment.node.type_condition.node.on.node),
+                        &fragment.node.selection_set,
+                    ),
+                    None => {
+                        return Err(ServerError::new(format!(
+                            r#"Unknown fragment "{}"."#,
+                            spread.node.fragment_name.node
+                        ))
+                        .at(spread.pos));
+                    }
+                }
+            }
+            Selection::InlineFragment(fragment) => (
+                fragment
+                    .node
+                    .type_condition
+                    .as_ref()
+                    .map(|condition| &condition.node.on.node),
+                &fragment.node.selection_set,
+            ),
+        };
+
+        let applies = match type_condition {
+            Some(condition) => {
+                condition == type_name
+                    || matches!(
+                        registry.types.get(condition.as_str()),
+                        Some(ty @ MetaType::Interface { .. }) | Some(ty @ MetaType::Union { .. })
+                            if ty.is_possible_type(type_name)
+                    )
+            }
+            None => true,
+        };
+        if applies {
+            project_selection_set(ctx, selection_set, type_name, object, typename, projected)?;
+        }
+    }
+    Ok(())
+}
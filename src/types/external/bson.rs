@@ -1,6 +1,9 @@
+use std::convert::TryFrom;
+
 use bson::oid::ObjectId;
 #[cfg(feature = "chrono")]
 use bson::DateTime as UtcDateTime;
+use bson::{Bson, Document};
 #[cfg(feature = "chrono")]
 use chrono::{DateTime, Utc};
 
@@ -33,3 +36,39 @@ impl ScalarType for UtcDateTime {
         (**self).to_value()
     }
 }
+
+/// A scalar that can represent any JSON value.
+///
+/// BSON types without a JSON equivalent, such as `ObjectId`, are written in relaxed extended
+/// JSON, e.g. `{"$oid": "..."}`, and are parsed back from it.
+#[Scalar(internal, name = "JSON")]
+impl ScalarType for Bson {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        Ok(Bson::try_from(value.into_json()?)?)
+    }
+
+    fn to_value(&self) -> Value {
+        Value::from_json(self.clone().into_relaxed_extjson()).unwrap_or_default()
+    }
+}
+
+/// A scalar that can represent any JSON Object value.
+///
+/// BSON types without a JSON equivalent, such as `ObjectId`, are written in relaxed extended
+/// JSON, e.g. `{"$oid": "..."}`, and are parsed back from it.
+#[Scalar(internal, name = "JSONObject")]
+impl ScalarType for Document {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::Object(_) => match Bson::parse(value).map_err(InputValueError::propagate)? {
+                Bson::Document(document) => Ok(document),
+                _ => Err("Expected a document".into()),
+            },
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Bson::Document(self.clone()).to_value()
+    }
+}
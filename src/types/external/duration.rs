@@ -0,0 +1,218 @@
+#[cfg(feature = "duration")]
+use std::time::Duration;
+
+use crate::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+/// A duration parsed from an ISO 8601 duration, the sign, seconds and nanoseconds.
+pub(crate) struct Iso8601Duration {
+    pub(crate) negative: bool,
+    pub(crate) secs: u64,
+    pub(crate) nanos: u32,
+}
+
+/// Parse an ISO 8601 duration, e.g. `P1DT2H30M` or `PT0.5S`, with an optional leading `-`.
+///
+/// The days are 24 hours and the weeks 7 days, years and months are not accepted because their
+/// length varies.
+pub(crate) fn parse_iso8601_duration(s: &str) -> Result<Iso8601Duration, String> {
+    let invalid = |reason: &str| format!(r#"Invalid ISO 8601 duration "{}": {}"#, s, reason);
+
+    let (negative, rest) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let mut rest = rest
+        .strip_prefix('P')
+        .ok_or_else(|| invalid("it must start with \"P\"."))?;
+
+    let mut secs: u64 = 0;
+    let mut nanos: u32 = 0;
+    let mut in_time = false;
+    let mut has_component = false;
+    let mut last_unit = 0;
+    while !rest.is_empty() {
+        if let Some(time) = rest.strip_prefix('T') {
+            if in_time {
+                return Err(invalid("\"T\" is repeated."));
+            }
+            in_time = true;
+            rest = time;
+            if rest.is_empty() {
+                return Err(invalid(
+                    "\"T\" must be followed by hours, minutes or seconds.",
+                ));
+            }
+            continue;
+        }
+
+        let len = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.' && c != ',')
+            .ok_or_else(|| invalid("a number must be followed by its unit."))?;
+        let (number, unit) = (&rest[..len], rest[len..].chars().next().unwrap());
+        rest = &rest[len + unit.len_utf8()..];
+        if number.is_empty() {
+            return Err(invalid(&format!("\"{}\" must follow a number.", unit)));
+        }
+
+        // The units in the order they must appear, with their length in seconds.
+        let (order, unit_secs) = match (in_time, unit) {
+            (false, 'Y') | (false, 'M') => {
+                return Err(invalid(
+                    "years and months are not accepted because their length varies.",
+                ))
+            }
+            (false, 'W') => (1, 7 * 24 * 3600),
+            (false, 'D') => (2, 24 * 3600),
+            (true, 'H') => (3, 3600),
+            (true, 'M') => (4, 60),
+            (true, 'S') => (5, 1),
+            _ => return Err(invalid(&format!("\"{}\" is not a unit here.", unit))),
+        };
+        if order <= last_unit {
+            return Err(invalid(&format!("\"{}\" is out of order.", unit)));
+        }
+        last_unit = order;
+        has_component = true;
+
+        let (whole, fraction) = match number.find(&['.', ','][..]) {
+            Some(pos) => (&number[..pos], Some(&number[pos + 1..])),
+            None => (number, None),
+        };
+        let whole: u64 = whole
+            .parse()
+            .map_err(|_| invalid(&format!("\"{}\" is not a number.", number)))?;
+        secs = whole
+            .checked_mul(unit_secs)
+            .and_then(|n| n.checked_add(secs))
+            .ok_or_else(|| invalid("it is too long."))?;
+        if let Some(fraction) = fraction {
+            if unit != 'S' || !rest.is_empty() {
+                return Err(invalid("only the seconds can have a fraction."));
+            }
+            if fraction.is_empty()
+                || fraction.len() > 9
+                || !fraction.chars().all(|c| c.is_ascii_digit())
+            {
+                return Err(invalid("the fraction of seconds must have 1 to 9 digits."));
+            }
+            nanos = format!("{:0<9}", fraction).parse().unwrap();
+        }
+    }
+    if !has_component {
+        return Err(invalid("it has no components."));
+    }
+
+    Ok(Iso8601Duration {
+        negative,
+        secs,
+        nanos,
+    })
+}
+
+/// Format a duration as an ISO 8601 duration in hours, minutes and seconds, e.g. `PT26H0.5S`.
+pub(crate) fn format_iso8601_duration(duration: Iso8601Duration) -> String {
+    let mut s = String::new();
+    if duration.negative && (duration.secs > 0 || duration.nanos > 0) {
+        s.push('-');
+    }
+    s.push_str("PT");
+    let (hours, minutes, secs) = (
+        duration.secs / 3600,
+        duration.secs / 60 % 60,
+        duration.secs % 60,
+    );
+    if hours > 0 {
+        s.push_str(&format!("{}H", hours));
+    }
+    if minutes > 0 {
+        s.push_str(&format!("{}M", minutes));
+    }
+    if duration.nanos > 0 {
+        let fraction = format!("{:09}", duration.nanos);
+        s.push_str(&format!("{}.{}S", secs, fraction.trim_end_matches('0')));
+    } else if secs > 0 || s.len() == 2 {
+        s.push_str(&format!("{}S", secs));
+    }
+    s
+}
+
+/// A duration, as an ISO 8601 duration in weeks, days, hours, minutes and seconds,
+/// e.g. `P1DT2H30M` or `PT0.5S`, output in hours, minutes and seconds.
+#[cfg(feature = "duration")]
+#[Scalar(internal, name = "Duration")]
+impl ScalarType for Duration {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(s) => {
+                let duration = parse_iso8601_duration(s).map_err(InputValueError::custom)?;
+                if duration.negative {
+                    return Err(InputValueError::custom(format!(
+                        r#"Invalid duration "{}": it must not be negative."#,
+                        s
+                    )));
+                }
+                Ok(Duration::new(duration.secs, duration.nanos))
+            }
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(format_iso8601_duration(Iso8601Duration {
+            negative: false,
+            secs: self.as_secs(),
+            nanos: self.subsec_nanos(),
+        }))
+    }
+}
+
+/// Convert an ISO 8601 duration to a `chrono::Duration`.
+#[cfg(feature = "chrono")]
+pub(crate) fn to_chrono_duration(
+    s: &str,
+    duration: Iso8601Duration,
+) -> Result<chrono::Duration, String> {
+    let std = std::time::Duration::new(duration.secs, duration.nanos);
+    let duration_abs = chrono::Duration::from_std(std)
+        .map_err(|_| format!(r#"Invalid duration "{}": it is too long."#, s))?;
+    Ok(if duration.negative {
+        -duration_abs
+    } else {
+        duration_abs
+    })
+}
+
+/// A duration, as an ISO 8601 duration in weeks, days, hours, minutes and seconds,
+/// e.g. `P1DT2H30M` or `-PT0.5S`, output in hours, minutes and seconds.
+#[cfg(feature = "chrono")]
+#[Scalar(internal, name = "Duration")]
+impl ScalarType for chrono::Duration {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(s) => {
+                let duration = parse_iso8601_duration(s).map_err(InputValueError::custom)?;
+                to_chrono_duration(s, duration).map_err(InputValueError::custom)
+            }
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_))
+    }
+
+    fn to_value(&self) -> Value {
+        let negative = *self < chrono::Duration::zero();
+        let abs = if negative { -*self } else { *self };
+        let abs = abs.to_std().unwrap_or_default();
+        Value::String(format_iso8601_duration(Iso8601Duration {
+            negative,
+            secs: abs.as_secs(),
+            nanos: abs.subsec_nanos(),
+        }))
+    }
+}
@@ -0,0 +1,74 @@
+use std::borrow::Cow;
+
+use either::Either;
+
+use crate::parser::types::Field;
+use crate::registry::{MetaType, Registry};
+use crate::resolver_utils::{resolve_container, ContainerType, Fields};
+use crate::{
+    Context, ContextSelectionSet, ObjectType, OutputValueType, Positioned, ServerResult, Type,
+    UnionType, Value,
+};
+
+/// A union of the object types `L` and `R`, named `LOrR`, e.g. `UserOrNotFound`.
+///
+/// To give the union another name, derive `Union` on an enum with the two types and
+/// `#[graphql(from_either)]`, and convert the `Either` into it.
+impl<L: ObjectType, R: ObjectType> Type for Either<L, R> {
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(format!("{}Or{}", L::type_name(), R::type_name()))
+    }
+
+    fn introspection_type_name(&self) -> Cow<'static, str> {
+        match self {
+            Either::Left(_) => L::type_name(),
+            Either::Right(_) => R::type_name(),
+        }
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        registry.create_type::<Self, _>(|registry| {
+            L::create_type_info(registry);
+            R::create_type_info(registry);
+            let mut possible_types = indexmap::IndexSet::new();
+            possible_types.insert(L::type_name().into_owned());
+            possible_types.insert(R::type_name().into_owned());
+            MetaType::Union {
+                name: Self::type_name().into_owned(),
+                description: None,
+                possible_types,
+            }
+        })
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<L: ObjectType, R: ObjectType> ContainerType for Either<L, R> {
+    async fn resolve_field(&self, _ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+        Ok(None)
+    }
+
+    fn collect_all_fields<'a>(
+        &'a self,
+        ctx: &ContextSelectionSet<'a>,
+        fields: &mut Fields<'a>,
+    ) -> ServerResult<()> {
+        match self {
+            Either::Left(obj) => obj.collect_all_fields(ctx, fields),
+            Either::Right(obj) => obj.collect_all_fields(ctx, fields),
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<L: ObjectType, R: ObjectType> OutputValueType for Either<L, R> {
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        resolve_container(ctx, self).await
+    }
+}
+
+impl<L: ObjectType, R: ObjectType> UnionType for Either<L, R> {}
@@ -0,0 +1,37 @@
+use email_address::{EmailAddress, Options};
+
+use crate::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+/// An email address, e.g. `user@example.com`, output with its domain in lowercase.
+#[Scalar(
+    internal,
+    name = "EmailAddress",
+    specified_by_url = "https://tools.ietf.org/html/rfc5322#section-3.4.1"
+)]
+impl ScalarType for EmailAddress {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(s) => {
+                let options = Options::default().without_display_text();
+                let address = EmailAddress::parse_with_options(&s, options)?;
+                Ok(EmailAddress::parse_with_options(
+                    &format!(
+                        "{}@{}",
+                        address.local_part(),
+                        address.domain().to_lowercase()
+                    ),
+                    options,
+                )?)
+            }
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.as_str().to_string())
+    }
+}
@@ -0,0 +1,28 @@
+use isocountry::CountryCode;
+
+use crate::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+/// An ISO 3166-1 country code, input as an alpha-2 or alpha-3 code, e.g. `NL` or `NLD`, and
+/// output as an alpha-2 code.
+#[Scalar(
+    internal,
+    name = "CountryCode",
+    specified_by_url = "https://www.iso.org/iso-3166-country-codes.html"
+)]
+impl ScalarType for CountryCode {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(s) if s.len() == 3 => Ok(CountryCode::for_alpha3(&s)?),
+            Value::String(s) => Ok(CountryCode::for_alpha2(&s)?),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.alpha2().to_string())
+    }
+}
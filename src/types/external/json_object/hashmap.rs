@@ -1,4 +1,4 @@
-use std::collections::{BTreeMap, HashMap};
+use std::collections::HashMap;
 
 use crate::{
     InputValueError, InputValueResult, InputValueType, Name, OutputValueType, Scalar, ScalarType,
@@ -23,10 +23,10 @@ where
     }
 
     fn to_value(&self) -> Value {
-        let mut map = BTreeMap::new();
-        for (name, value) in self {
-            map.insert(Name::new(name), value.to_value());
-        }
-        Value::Object(map)
+        Value::Object(
+            self.iter()
+                .map(|(name, value)| (Name::new(name), value.to_value()))
+                .collect(),
+        )
     }
 }
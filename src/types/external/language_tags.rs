@@ -0,0 +1,31 @@
+use language_tags::LanguageTag;
+
+use crate::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+/// A BCP 47 language tag, e.g. `en-US`, whose subtags must be in the IANA Language Subtag
+/// Registry, output with the case of the subtags normalized.
+#[Scalar(
+    internal,
+    name = "LanguageTag",
+    specified_by_url = "https://tools.ietf.org/html/bcp47"
+)]
+impl ScalarType for LanguageTag {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(s) => {
+                let tag = LanguageTag::parse(&s)?;
+                tag.validate()?;
+                Ok(tag)
+            }
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.as_str().to_string())
+    }
+}
@@ -17,8 +17,20 @@ mod bson;
 mod chrono_tz;
 #[cfg(feature = "chrono")]
 mod datetime;
+#[cfg(any(feature = "duration", feature = "chrono"))]
+pub(crate) mod duration;
+#[cfg(feature = "either")]
+mod either;
+#[cfg(feature = "email_address")]
+mod email_address;
+#[cfg(feature = "isocountry")]
+mod isocountry;
+#[cfg(feature = "language-tags")]
+mod language_tags;
 #[cfg(feature = "chrono")]
 mod naive_time;
+#[cfg(feature = "phonenumber")]
+mod phonenumber;
 #[cfg(feature = "url")]
 mod url;
 #[cfg(feature = "uuid")]
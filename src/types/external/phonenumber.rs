@@ -0,0 +1,36 @@
+use phonenumber::{Mode, PhoneNumber};
+
+use crate::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+/// A phone number in international format, e.g. `+31 20 123 4567`, output in E.164 format,
+/// e.g. `+31201234567`.
+#[Scalar(
+    internal,
+    name = "PhoneNumber",
+    specified_by_url = "https://www.itu.int/rec/T-REC-E.164"
+)]
+impl ScalarType for PhoneNumber {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(s) => {
+                let number = phonenumber::parse(None, &s)?;
+                if !phonenumber::is_valid(&number) {
+                    return Err(InputValueError::custom(format!(
+                        r#"Invalid phone number "{}"."#,
+                        s
+                    )));
+                }
+                Ok(number)
+            }
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.format().mode(Mode::E164).to_string())
+    }
+}
@@ -0,0 +1,364 @@
+use std::borrow::Cow;
+use std::ops::{Deref, DerefMut};
+
+use geo_types::{Coord, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
+use serde::de::DeserializeOwned;
+
+use crate::parser::types::Field;
+use crate::resolver_utils::{resolve_container, resolve_field_value, ContainerType};
+use crate::{
+    from_value, registry, to_value, Context, ContextSelectionSet, InputValueError,
+    InputValueResult, Map, Name, ObjectType, OutputValueType, Positioned, Scalar, ScalarType,
+    ServerResult, Type, Value,
+};
+
+/// A geometry of the [`geo-types` crate](https://crates.io/crates/geo-types) with a GeoJSON
+/// representation, an object with its `type` and its `coordinates` as defined by
+/// [RFC 7946](https://tools.ietf.org/html/rfc7946).
+///
+/// The positions are `[longitude, latitude]`, the longitudes must be from -180 to 180 and the
+/// latitudes from -90 to 90.
+pub trait GeoJsonGeometry: Sized {
+    /// The GeoJSON type of the geometry, e.g. `Point`.
+    const TYPE: &'static str;
+
+    /// The coordinates of the geometry, e.g. `Vec<f64>` for a point.
+    type Coordinates: DeserializeOwned + serde::Serialize + OutputValueType;
+
+    /// Get the coordinates of the geometry.
+    fn coordinates(&self) -> Self::Coordinates;
+
+    /// Create the geometry from its coordinates, or return why they are invalid.
+    fn from_coordinates(coordinates: Self::Coordinates) -> Result<Self, String>;
+}
+
+fn position(position: &[f64]) -> Result<Coord<f64>, String> {
+    match *position {
+        [x, y] => {
+            if !(-180.0..=180.0).contains(&x) {
+                return Err(format!("The longitude {} is not from -180 to 180.", x));
+            }
+            if !(-90.0..=90.0).contains(&y) {
+                return Err(format!("The latitude {} is not from -90 to 90.", y));
+            }
+            Ok(Coord { x, y })
+        }
+        _ => Err(format!(
+            "A position must have a longitude and a latitude, found {} numbers.",
+            position.len()
+        )),
+    }
+}
+
+fn positions(positions: &[Vec<f64>]) -> Result<Vec<Coord<f64>>, String> {
+    positions.iter().map(|p| position(p)).collect()
+}
+
+fn line_string(coordinates: &[Vec<f64>]) -> Result<LineString<f64>, String> {
+    if coordinates.len() < 2 {
+        return Err("A line string must have at least 2 positions.".to_string());
+    }
+    Ok(LineString(positions(coordinates)?))
+}
+
+fn polygon(rings: &[Vec<Vec<f64>>]) -> Result<Polygon<f64>, String> {
+    let mut rings = rings.iter().map(|ring| {
+        let ring = LineString(positions(ring)?);
+        if ring.0.len() < 4 {
+            return Err("A linear ring must have at least 4 positions.".to_string());
+        }
+        if ring.0.first() != ring.0.last() {
+            return Err("A linear ring must end with its first position.".to_string());
+        }
+        Ok(ring)
+    });
+    let exterior = rings
+        .next()
+        .ok_or_else(|| "A polygon must have an exterior ring.".to_string())??;
+    Ok(Polygon::new(exterior, rings.collect::<Result<_, _>>()?))
+}
+
+fn line_string_coordinates(line_string: &LineString<f64>) -> Vec<Vec<f64>> {
+    line_string.0.iter().map(|c| vec![c.x, c.y]).collect()
+}
+
+fn polygon_coordinates(polygon: &Polygon<f64>) -> Vec<Vec<Vec<f64>>> {
+    std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .map(line_string_coordinates)
+        .collect()
+}
+
+impl GeoJsonGeometry for Point<f64> {
+    const TYPE: &'static str = "Point";
+    type Coordinates = Vec<f64>;
+
+    fn coordinates(&self) -> Self::Coordinates {
+        vec![self.x(), self.y()]
+    }
+
+    fn from_coordinates(coordinates: Self::Coordinates) -> Result<Self, String> {
+        position(&coordinates).map(Point)
+    }
+}
+
+impl GeoJsonGeometry for MultiPoint<f64> {
+    const TYPE: &'static str = "MultiPoint";
+    type Coordinates = Vec<Vec<f64>>;
+
+    fn coordinates(&self) -> Self::Coordinates {
+        self.0.iter().map(|p| vec![p.x(), p.y()]).collect()
+    }
+
+    fn from_coordinates(coordinates: Self::Coordinates) -> Result<Self, String> {
+        Ok(MultiPoint(
+            positions(&coordinates)?.into_iter().map(Point).collect(),
+        ))
+    }
+}
+
+impl GeoJsonGeometry for LineString<f64> {
+    const TYPE: &'static str = "LineString";
+    type Coordinates = Vec<Vec<f64>>;
+
+    fn coordinates(&self) -> Self::Coordinates {
+        line_string_coordinates(self)
+    }
+
+    fn from_coordinates(coordinates: Self::Coordinates) -> Result<Self, String> {
+        line_string(&coordinates)
+    }
+}
+
+impl GeoJsonGeometry for MultiLineString<f64> {
+    const TYPE: &'static str = "MultiLineString";
+    type Coordinates = Vec<Vec<Vec<f64>>>;
+
+    fn coordinates(&self) -> Self::Coordinates {
+        self.0.iter().map(line_string_coordinates).collect()
+    }
+
+    fn from_coordinates(coordinates: Self::Coordinates) -> Result<Self, String> {
+        coordinates
+            .iter()
+            .map(|coordinates| line_string(coordinates))
+            .collect::<Result<_, _>>()
+            .map(MultiLineString)
+    }
+}
+
+impl GeoJsonGeometry for Polygon<f64> {
+    const TYPE: &'static str = "Polygon";
+    type Coordinates = Vec<Vec<Vec<f64>>>;
+
+    fn coordinates(&self) -> Self::Coordinates {
+        polygon_coordinates(self)
+    }
+
+    fn from_coordinates(coordinates: Self::Coordinates) -> Result<Self, String> {
+        polygon(&coordinates)
+    }
+}
+
+impl GeoJsonGeometry for MultiPolygon<f64> {
+    const TYPE: &'static str = "MultiPolygon";
+    type Coordinates = Vec<Vec<Vec<Vec<f64>>>>;
+
+    fn coordinates(&self) -> Self::Coordinates {
+        self.0.iter().map(polygon_coordinates).collect()
+    }
+
+    fn from_coordinates(coordinates: Self::Coordinates) -> Result<Self, String> {
+        coordinates
+            .iter()
+            .map(|coordinates| polygon(coordinates))
+            .collect::<Result<_, _>>()
+            .map(MultiPolygon)
+    }
+}
+
+fn parse_geometry<T: GeoJsonGeometry + ScalarType + crate::InputValueType>(
+    value: Value,
+) -> InputValueResult<T> {
+    let mut obj = match value {
+        Value::Object(obj) => obj,
+        _ => return Err(InputValueError::expected_type(value)),
+    };
+    match obj.get("type") {
+        Some(Value::String(ty)) if ty == T::TYPE => {}
+        _ => {
+            return Err(InputValueError::custom(format!(
+                r#"The type of the geometry must be "{}"."#,
+                T::TYPE
+            )))
+        }
+    }
+    let coordinates = obj.remove("coordinates").unwrap_or_default();
+    let coordinates = from_value(coordinates)
+        .map_err(|_| InputValueError::custom(format!("Invalid coordinates of a {}.", T::TYPE)))?;
+    T::from_coordinates(coordinates).map_err(InputValueError::custom)
+}
+
+fn geometry_to_value<T: GeoJsonGeometry>(geometry: &T) -> Value {
+    let mut map = Map::new();
+    map.insert(Name::new("type"), Value::String(T::TYPE.to_string()));
+    map.insert(
+        Name::new("coordinates"),
+        to_value(geometry.coordinates()).unwrap_or_default(),
+    );
+    Value::Object(map)
+}
+
+macro_rules! geo_scalar {
+    ($ty:ident, $name:literal, $desc:literal) => {
+        #[doc = $desc]
+        #[Scalar(internal, name = $name, specified_by_url = "https://tools.ietf.org/html/rfc7946")]
+        impl ScalarType for $ty<f64> {
+            fn parse(value: Value) -> InputValueResult<Self> {
+                parse_geometry(value)
+            }
+
+            fn is_valid(value: &Value) -> bool {
+                matches!(value, Value::Object(_))
+            }
+
+            fn to_value(&self) -> Value {
+                geometry_to_value(self)
+            }
+        }
+    };
+}
+
+geo_scalar!(
+    Point,
+    "Point",
+    "A GeoJSON `Point` geometry, a position, e.g. `{ \"type\": \"Point\", \"coordinates\": ... }`."
+);
+geo_scalar!(
+    MultiPoint,
+    "MultiPoint",
+    "A GeoJSON `MultiPoint` geometry, positions, e.g. `{ \"type\": \"MultiPoint\", \"coordinates\": ... }`."
+);
+geo_scalar!(
+    LineString,
+    "LineString",
+    "A GeoJSON `LineString` geometry, a line through at least 2 positions, e.g. `{ \"type\": \"LineString\", \"coordinates\": ... }`."
+);
+geo_scalar!(
+    MultiLineString,
+    "MultiLineString",
+    "A GeoJSON `MultiLineString` geometry, lines, e.g. `{ \"type\": \"MultiLineString\", \"coordinates\": ... }`."
+);
+geo_scalar!(
+    Polygon,
+    "Polygon",
+    "A GeoJSON `Polygon` geometry, an exterior ring and holes, each closed, e.g. `{ \"type\": \"Polygon\", \"coordinates\": ... }`."
+);
+geo_scalar!(
+    MultiPolygon,
+    "MultiPolygon",
+    "A GeoJSON `MultiPolygon` geometry, polygons, e.g. `{ \"type\": \"MultiPolygon\", \"coordinates\": ... }`."
+);
+
+/// A geometry output as an object with the fields of its GeoJSON representation, e.g.
+/// `GeoPoint { type: String! coordinates: [Float!]! }` for a `Point`, instead of a scalar.
+///
+/// The objects let clients select the fields and know the shape of the coordinates, they are
+/// output only, the geometries are input as scalars.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use geo_types::Point;
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn shop(&self) -> GeoObject<Point<f64>> {
+///         GeoObject(Point::new(4.89, 52.37))
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+///     let res = schema.execute("{ shop { type coordinates } }").await;
+///     assert_eq!(res.data, value!({
+///         "shop": { "type": "Point", "coordinates": [4.89, 52.37] }
+///     }));
+/// });
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "geo")))]
+pub struct GeoObject<T>(pub T);
+
+impl<T> Deref for GeoObject<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for GeoObject<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: GeoJsonGeometry> From<T> for GeoObject<T> {
+    fn from(geometry: T) -> Self {
+        Self(geometry)
+    }
+}
+
+impl<T: GeoJsonGeometry> Type for GeoObject<T> {
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(format!("Geo{}", T::TYPE))
+    }
+
+    fn create_type_info(registry: &mut registry::Registry) -> String {
+        registry.create_type::<Self, _>(|registry| {
+            registry::MetaType::object(
+                Self::type_name(),
+                Some(format!("A GeoJSON `{}` geometry.", T::TYPE)),
+                vec![
+                    registry::MetaField::new("type", String::create_type_info(registry))
+                        .description("The type of the geometry."),
+                    registry::MetaField::new(
+                        "coordinates",
+                        T::Coordinates::create_type_info(registry),
+                    )
+                    .description(
+                        "The coordinates of the geometry, as [longitude, latitude] positions.",
+                    ),
+                ],
+            )
+        })
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<T: GeoJsonGeometry> ContainerType for GeoObject<T> {
+    async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+        match ctx.item.node.name.node.as_str() {
+            "type" => Ok(Some(Value::String(T::TYPE.to_string()))),
+            "coordinates" => resolve_field_value(ctx, &self.0.coordinates()).await,
+            _ => Ok(None),
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<T: GeoJsonGeometry> OutputValueType for GeoObject<T> {
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        resolve_container(ctx, self).await
+    }
+}
+
+impl<T: GeoJsonGeometry> ObjectType for GeoObject<T> {}
@@ -0,0 +1,160 @@
+use std::borrow::Cow;
+use std::ops::{Deref, DerefMut};
+
+use serde::{Deserialize, Serialize};
+
+use crate::parser::types::Field;
+use crate::registry::{MetaType, Registry};
+use crate::{
+    ContextSelectionSet, InputValueError, InputValueResult, InputValueType, Number,
+    OutputValueType, Positioned, ScalarType, ServerResult, Type, Value,
+};
+
+/// How the [`Int64`](struct.Int64.html) and [`UInt64`](struct.UInt64.html) scalars are output,
+/// set with [`SchemaBuilder::int64_representation`](struct.SchemaBuilder.html#method.int64_representation).
+///
+/// Both representations are accepted as input.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Int64Representation {
+    /// Output a number, the default.
+    Number,
+
+    /// Output a string, for the clients that parse numbers as doubles, e.g. JavaScript, which
+    /// lose the precision of the integers larger than 2^53.
+    String,
+}
+
+macro_rules! int64_scalar {
+    ($(#[$attr:meta])* $ty:ident, $inner:ty, $name:literal, $as_inner:ident, $is_inner:ident) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $ty(pub $inner);
+
+        impl Deref for $ty {
+            type Target = $inner;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        impl From<$inner> for $ty {
+            fn from(value: $inner) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$ty> for $inner {
+            fn from(value: $ty) -> Self {
+                value.0
+            }
+        }
+
+        impl ScalarType for $ty {
+            fn parse(value: Value) -> InputValueResult<Self> {
+                let n = match &value {
+                    Value::Number(n) if n.is_f64() => return Err("Invalid number".into()),
+                    Value::Number(n) => n.$as_inner(),
+                    Value::String(s) => s.parse().ok(),
+                    _ => return Err(InputValueError::expected_type(value)),
+                };
+                n.map(Self).ok_or_else(|| {
+                    format!(
+                        "Only integers from {} to {} are accepted.",
+                        <$inner>::MIN,
+                        <$inner>::MAX
+                    )
+                    .into()
+                })
+            }
+
+            fn is_valid(value: &Value) -> bool {
+                match value {
+                    Value::Number(n) => n.$is_inner(),
+                    Value::String(s) => s.parse::<$inner>().is_ok(),
+                    _ => false,
+                }
+            }
+
+            fn to_value(&self) -> Value {
+                Value::Number(Number::from(self.0))
+            }
+        }
+
+        impl Type for $ty {
+            fn type_name() -> Cow<'static, str> {
+                Cow::Borrowed($name)
+            }
+
+            fn create_type_info(registry: &mut Registry) -> String {
+                registry.create_type::<Self, _>(|_| MetaType::Scalar {
+                    name: $name.to_string(),
+                    description: Some(format!(
+                        "An integer from {} to {}, as a number or a string.",
+                        <$inner>::MIN,
+                        <$inner>::MAX
+                    )),
+                    is_valid: |value| <Self as ScalarType>::is_valid(value),
+                    specified_by_url: None,
+                })
+            }
+        }
+
+        impl InputValueType for $ty {
+            fn parse(value: Option<Value>) -> InputValueResult<Self> {
+                <Self as ScalarType>::parse(value.unwrap_or_default())
+            }
+
+            fn to_value(&self) -> Value {
+                <Self as ScalarType>::to_value(self)
+            }
+        }
+
+        #[async_trait::async_trait(?Send)]
+        impl OutputValueType for $ty {
+            async fn resolve(
+                &self,
+                ctx: &ContextSelectionSet<'_>,
+                _field: &Positioned<Field>,
+            ) -> ServerResult<Value> {
+                Ok(match ctx.schema_env.int64_representation {
+                    Int64Representation::Number => <Self as ScalarType>::to_value(self),
+                    Int64Representation::String => Value::String(self.0.to_string()),
+                })
+            }
+        }
+    };
+}
+
+int64_scalar!(
+    /// The `Int64` scalar type, a signed 64-bit integer, output as set with
+    /// [`SchemaBuilder::int64_representation`](struct.SchemaBuilder.html#method.int64_representation).
+    ///
+    /// `i64` is an `Int`, which the specification limits to 32 bits, this is a scalar of its own
+    /// that accepts numbers and strings, and rejects the values out of its range.
+    Int64,
+    i64,
+    "Int64",
+    as_i64,
+    is_i64
+);
+
+int64_scalar!(
+    /// The `UInt64` scalar type, an unsigned 64-bit integer, output as set with
+    /// [`SchemaBuilder::int64_representation`](struct.SchemaBuilder.html#method.int64_representation).
+    ///
+    /// `u64` is an `Int`, which the specification limits to 32 bits, this is a scalar of its own
+    /// that accepts numbers and strings, and rejects the values out of its range.
+    UInt64,
+    u64,
+    "UInt64",
+    as_u64,
+    is_u64
+);
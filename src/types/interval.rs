@@ -0,0 +1,121 @@
+use chrono::{DateTime, Utc};
+
+use crate::types::external::duration::{parse_iso8601_duration, to_chrono_duration};
+use crate::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+/// A time interval, as an ISO 8601 interval: its start and end, e.g.
+/// `2021-03-01T09:00:00Z/2021-03-01T17:00:00Z`, or one of them and its duration, e.g.
+/// `2021-03-01T09:00:00Z/PT8H` or `PT8H/2021-03-01T17:00:00Z`.
+///
+/// The dates are in RFC 3339 format and the durations as accepted by the `Duration` scalar, the
+/// interval is output with its start and end.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "chrono")))]
+pub struct Interval {
+    /// The start of the interval.
+    pub start: DateTime<Utc>,
+
+    /// The end of the interval, not before its start.
+    pub end: DateTime<Utc>,
+}
+
+impl Interval {
+    /// The duration of the interval.
+    pub fn duration(&self) -> chrono::Duration {
+        self.end - self.start
+    }
+
+    /// Returns `true` if `time` is within the interval, its end excluded.
+    pub fn contains(&self, time: &DateTime<Utc>) -> bool {
+        self.start <= *time && *time < self.end
+    }
+}
+
+fn parse_interval(s: &str) -> Result<Interval, String> {
+    let (start, end) = match s.find('/') {
+        Some(pos) => (&s[..pos], &s[pos + 1..]),
+        None => {
+            return Err(format!(
+                r#"Invalid ISO 8601 interval "{}": its start and end must be separated by "/"."#,
+                s
+            ))
+        }
+    };
+    let parse_time = |time: &str| {
+        time.parse::<DateTime<Utc>>()
+            .map_err(|err| format!(r#"Invalid date "{}": {}."#, time, err))
+    };
+    let parse_duration = |duration: &str| {
+        let parsed = parse_iso8601_duration(duration)?;
+        if parsed.negative {
+            return Err(format!(
+                r#"Invalid duration "{}": it must not be negative."#,
+                duration
+            ));
+        }
+        to_chrono_duration(duration, parsed)
+    };
+    let too_late = || format!(r#"Invalid ISO 8601 interval "{}": it is out of range."#, s);
+
+    let interval = match (start.starts_with('P'), end.starts_with('P')) {
+        (false, false) => Interval {
+            start: parse_time(start)?,
+            end: parse_time(end)?,
+        },
+        (false, true) => {
+            let start = parse_time(start)?;
+            Interval {
+                start,
+                end: start
+                    .checked_add_signed(parse_duration(end)?)
+                    .ok_or_else(too_late)?,
+            }
+        }
+        (true, false) => {
+            let end = parse_time(end)?;
+            Interval {
+                start: end
+                    .checked_sub_signed(parse_duration(start)?)
+                    .ok_or_else(too_late)?,
+                end,
+            }
+        }
+        (true, true) => {
+            return Err(format!(
+                r#"Invalid ISO 8601 interval "{}": its start or end must be a date."#,
+                s
+            ))
+        }
+    };
+    if interval.end < interval.start {
+        return Err(format!(
+            r#"Invalid ISO 8601 interval "{}": its end is before its start."#,
+            s
+        ));
+    }
+    Ok(interval)
+}
+
+/// A time interval, as an ISO 8601 interval: its start and end, or one of them and its
+/// duration, e.g. `2021-03-01T09:00:00Z/PT8H`.
+#[Scalar(internal)]
+impl ScalarType for Interval {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(s) => parse_interval(s).map_err(InputValueError::custom),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(format!(
+            "{}/{}",
+            self.start.to_rfc3339(),
+            self.end.to_rfc3339()
+        ))
+    }
+}
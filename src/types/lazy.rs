@@ -0,0 +1,156 @@
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::rc::Rc;
+
+use futures_util::future::{FutureExt, LocalBoxFuture, Shared};
+use once_cell::unsync::OnceCell;
+
+use crate::parser::types::Field;
+use crate::{
+    registry, ContextSelectionSet, OutputValueType, Positioned, ServerResult, Type, Value,
+};
+
+/// A value computed the first time it is selected.
+///
+/// The initialization function is called when the field is resolved for the first time, and
+/// the value is kept for the other selections of the field, e.g. with aliases, including the
+/// ones resolved concurrently. If the field isn't selected, the function is never called.
+///
+/// A field whose value can't be computed can be a `Lazy<Result<T>>`, the error is returned for
+/// each of its selections.
+///
+/// The type of the initialization function is boxed by default, so that a lazy value can be
+/// the field of a struct, e.g. of a `SimpleObject`.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+///
+/// #[derive(SimpleObject)]
+/// struct Query {
+///     name: String,
+///     posts_count: Lazy<i32>,
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let calls = Rc::new(Cell::new(0));
+///     let counter = calls.clone();
+///     let query = Query {
+///         name: "me".to_string(),
+///         posts_count: Lazy::boxed(move || async move {
+///             counter.set(counter.get() + 1);
+///             42
+///         }),
+///     };
+///     let schema = Schema::new(query, EmptyMutation, EmptySubscription);
+///
+///     let res = schema.execute("{ a: postsCount b: postsCount }").await;
+///     assert_eq!(res.data, value!({ "a": 42, "b": 42 }));
+///     let res = schema.execute("{ name }").await;
+///     assert_eq!(res.data, value!({ "name": "me" }));
+///     assert_eq!(calls.get(), 1);
+/// });
+/// ```
+pub struct Lazy<T, F = Box<dyn FnOnce() -> LocalBoxFuture<'static, T>>> {
+    init: Cell<Option<F>>,
+    future: RefCell<Option<Shared<LocalBoxFuture<'static, ()>>>>,
+    value: Rc<OnceCell<T>>,
+}
+
+impl<T: 'static> Lazy<T> {
+    /// Create a lazy value with a boxed initialization function.
+    pub fn boxed<Fut>(init: impl FnOnce() -> Fut + 'static) -> Self
+    where
+        Fut: Future<Output = T> + 'static,
+    {
+        Lazy::new(Box::new(move || init().boxed_local()))
+    }
+}
+
+impl<T, F, Fut> Lazy<T, F>
+where
+    T: 'static,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = T> + 'static,
+{
+    /// Create a lazy value with its initialization function.
+    pub fn new(init: F) -> Self {
+        Self {
+            init: Cell::new(Some(init)),
+            future: RefCell::new(None),
+            value: Rc::new(OnceCell::new()),
+        }
+    }
+
+    /// Create a lazy value that is already initialized.
+    pub fn with_value(value: T) -> Self {
+        Self {
+            init: Cell::new(None),
+            future: RefCell::new(None),
+            value: Rc::new(OnceCell::from(value)),
+        }
+    }
+
+    /// Get the value, calling the initialization function if it hasn't been called.
+    pub async fn get(&self) -> &T {
+        if let Some(value) = self.value.get() {
+            return value;
+        }
+        let future = self
+            .future
+            .borrow_mut()
+            .get_or_insert_with(|| {
+                let init = self.init.take().expect("the value is being initialized");
+                let future = init();
+                let value = self.value.clone();
+                async move {
+                    let _ = value.set(future.await);
+                }
+                .boxed_local()
+                .shared()
+            })
+            .clone();
+        future.await;
+        self.future.borrow_mut().take();
+        self.value.get().unwrap()
+    }
+
+    /// Get the value if it has been computed.
+    pub fn get_now(&self) -> Option<&T> {
+        self.value.get()
+    }
+}
+
+impl<T: Type, F> Type for Lazy<T, F> {
+    fn type_name() -> Cow<'static, str> {
+        T::type_name()
+    }
+
+    fn qualified_type_name() -> String {
+        T::qualified_type_name()
+    }
+
+    fn create_type_info(registry: &mut registry::Registry) -> String {
+        T::create_type_info(registry)
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<T, F, Fut> OutputValueType for Lazy<T, F>
+where
+    T: OutputValueType + 'static,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = T> + 'static,
+{
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        self.get().await.resolve(ctx, field).await
+    }
+}
@@ -0,0 +1,90 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
+
+use futures_util::stream::{LocalBoxStream, Stream, StreamExt};
+
+use crate::parser::types::Field;
+use crate::resolver_utils::resolve_list_stream;
+use crate::{
+    registry, ContextSelectionSet, OutputValueType, Positioned, ServerResult, Type, Value,
+};
+
+/// A list whose items are produced by a stream.
+///
+/// Without `@stream`, the whole stream is collected before the list is resolved. With
+/// `@stream(initialCount: n)` and
+/// [`Schema::execute_incremental`](struct.Schema.html#method.execute_incremental), only the
+/// first `n` items are part of the initial response, and each of the remaining items is pulled
+/// from the stream, resolved and delivered in its own payload when the response stream is
+/// polled for it. A slow consumer therefore slows down the producer, instead of the items
+/// piling up in memory.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use futures_util::stream::{self, StreamExt};
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn numbers(&self) -> ListStream<i32> {
+///         ListStream::new(stream::iter(1..=3))
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+///     let responses: Vec<_> = schema
+///         .execute_incremental("{ numbers @stream(initialCount: 2) }")
+///         .map(|resp| serde_json::to_value(resp).unwrap())
+///         .collect()
+///         .await;
+///     assert_eq!(
+///         responses,
+///         vec![
+///             serde_json::json!({ "data": { "numbers": [1, 2] }, "hasNext": true }),
+///             serde_json::json!({ "items": [3], "path": ["numbers", 2], "hasNext": true }),
+///             serde_json::json!({ "hasNext": false }),
+///         ]
+///     );
+/// });
+/// ```
+pub struct ListStream<T>(RefCell<Option<LocalBoxStream<'static, T>>>);
+
+impl<T> ListStream<T> {
+    /// Create a list from a stream of items.
+    pub fn new(stream: impl Stream<Item = T> + 'static) -> Self {
+        Self(RefCell::new(Some(stream.boxed_local())))
+    }
+}
+
+impl<T: Type> Type for ListStream<T> {
+    fn type_name() -> Cow<'static, str> {
+        Vec::<T>::type_name()
+    }
+
+    fn qualified_type_name() -> String {
+        Vec::<T>::qualified_type_name()
+    }
+
+    fn create_type_info(registry: &mut registry::Registry) -> String {
+        Vec::<T>::create_type_info(registry)
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<T: OutputValueType + 'static> OutputValueType for ListStream<T> {
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        let stream = self.0.borrow_mut().take();
+        match stream {
+            Some(stream) => resolve_list_stream(ctx, field, stream).await,
+            None => Ok(Value::List(Vec::new())),
+        }
+    }
+}
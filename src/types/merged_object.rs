@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use indexmap::IndexMap;
 
 use crate::parser::types::Field;
-use crate::registry::{MetaType, Registry};
+use crate::registry::{MetaField, MetaType, Registry};
 use crate::resolver_utils::resolve_container;
 use crate::{
     CacheControl, ContainerType, Context, ContextSelectionSet, ObjectType, OutputValueType,
@@ -14,53 +14,102 @@ use async_graphql_value::ConstValue;
 #[doc(hidden)]
 pub struct MergedObject<A, B>(pub A, pub B);
 
-impl<A: Type, B: Type> Type for MergedObject<A, B> {
+impl<A: Type, B: Type + MergedObjectParts> Type for MergedObject<A, B> {
     fn type_name() -> Cow<'static, str> {
         Cow::Owned(format!("{}_{}", A::type_name(), B::type_name()))
     }
 
     fn create_type_info(registry: &mut Registry) -> String {
         registry.create_type::<Self, _>(|registry| {
-            let mut fields = IndexMap::new();
-            let mut cc = CacheControl::default();
-
-            if let MetaType::Object {
-                fields: a_fields,
-                cache_control: a_cc,
-                ..
-            } = registry.create_dummy_type::<A>()
-            {
-                fields.extend(a_fields);
-                cc = cc.merge(&a_cc);
-            }
-
-            if let MetaType::Object {
-                fields: b_fields,
-                cache_control: b_cc,
-                ..
-            } = registry.create_dummy_type::<B>()
-            {
-                fields.extend(b_fields);
-                cc = cc.merge(&b_cc);
-            }
-
+            let mut parts = Vec::new();
+            Self::collect_parts(registry, &mut parts);
+            let (fields, cache_control, keys) = merge_object_parts(&Self::type_name(), parts);
             MetaType::Object {
                 name: Self::type_name().to_string(),
                 description: None,
                 fields,
-                cache_control: cc,
+                cache_control,
                 extends: false,
-                keys: None,
+                keys,
             }
         })
     }
 }
 
+#[doc(hidden)]
+pub trait MergedObjectParts {
+    /// Create the types of the parts, in declaration order, with the names of their Rust types.
+    fn collect_parts(registry: &mut Registry, parts: &mut Vec<(&'static str, MetaType)>);
+}
+
+impl<A: Type, B: MergedObjectParts> MergedObjectParts for MergedObject<A, B> {
+    fn collect_parts(registry: &mut Registry, parts: &mut Vec<(&'static str, MetaType)>) {
+        B::collect_parts(registry, parts);
+        parts.push((
+            std::any::type_name::<A>(),
+            registry.create_dummy_type::<A>(),
+        ));
+    }
+}
+
+impl MergedObjectParts for MergedObjectTail {
+    fn collect_parts(_registry: &mut Registry, _parts: &mut Vec<(&'static str, MetaType)>) {}
+}
+
+/// Merge the fields, cache controls and keys of the parts of the merged object `type_name`, the
+/// parts are the types of the parts with the names of their Rust types.
+///
+/// Panics if two parts define a field with the same name.
+#[doc(hidden)]
+pub fn merge_object_parts(
+    type_name: &str,
+    parts: Vec<(&'static str, MetaType)>,
+) -> (
+    IndexMap<String, MetaField>,
+    CacheControl,
+    Option<Vec<String>>,
+) {
+    let mut fields = IndexMap::new();
+    let mut owners: IndexMap<String, &'static str> = IndexMap::new();
+    let mut cache_control = CacheControl::default();
+    let mut keys: Option<Vec<String>> = None;
+
+    for (rust_type, ty) in parts {
+        if let MetaType::Object {
+            fields: part_fields,
+            cache_control: part_cache_control,
+            keys: part_keys,
+            ..
+        } = ty
+        {
+            for (name, field) in part_fields {
+                if let Some(owner) = owners.get(&name) {
+                    panic!(
+                        "Field \"{}.{}\" is defined by both \"{}\" and \"{}\".",
+                        type_name, name, owner, rust_type
+                    );
+                }
+                owners.insert(name.clone(), rust_type);
+                fields.insert(name, field);
+            }
+            cache_control = cache_control.merge(&part_cache_control);
+            for key in part_keys.into_iter().flatten() {
+                let keys = keys.get_or_insert_with(Vec::new);
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+        }
+    }
+
+    (fields, cache_control, keys)
+}
+
 #[async_trait::async_trait(?Send)]
 impl<A, B> ContainerType for MergedObject<A, B>
 where
     A: ObjectType,
-    B: ObjectType,
+    B: ObjectType + MergedObjectParts,
 {
     async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
         match self.0.resolve_field(ctx).await {
@@ -87,7 +136,7 @@ where
 impl<A, B> OutputValueType for MergedObject<A, B>
 where
     A: ObjectType,
-    B: ObjectType,
+    B: ObjectType + MergedObjectParts,
 {
     async fn resolve(
         &self,
@@ -101,7 +150,7 @@ where
 impl<A, B> ObjectType for MergedObject<A, B>
 where
     A: ObjectType,
-    B: ObjectType,
+    B: ObjectType + MergedObjectParts,
 {
 }
 
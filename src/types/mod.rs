@@ -1,30 +1,61 @@
 //! Useful GraphQL types.
 
 pub mod connection;
+pub mod crud;
 
 mod any;
+#[cfg(feature = "currency")]
+mod currency_code;
+mod dynamic_object;
 mod empty_mutation;
 mod empty_subscription;
+#[cfg(feature = "geo")]
+mod geo;
 mod id;
+mod int64;
+#[cfg(feature = "chrono")]
+mod interval;
 mod json;
+mod lazy;
+mod list_stream;
 mod maybe_undefined;
 mod merged_object;
+mod mutation_result;
 mod query_root;
+mod raw;
+mod runtime_scalar;
 #[cfg(feature = "string_number")]
 mod string_number;
+mod subscription_message;
 mod upload;
 
 mod external;
 
 pub use any::Any;
+#[cfg(feature = "currency")]
+pub use currency_code::CurrencyCode;
+pub use dynamic_object::DynamicObject;
 pub use empty_mutation::EmptyMutation;
 pub use empty_subscription::EmptySubscription;
+#[cfg(feature = "geo")]
+pub use geo::{GeoJsonGeometry, GeoObject};
 pub use id::ID;
+pub use int64::{Int64, Int64Representation, UInt64};
+#[cfg(feature = "chrono")]
+pub use interval::Interval;
 pub use json::{Json, OutputJson};
+pub use lazy::Lazy;
+pub use list_stream::ListStream;
 pub use maybe_undefined::MaybeUndefined;
-pub use merged_object::{MergedObject, MergedObjectTail};
+pub use merged_object::{merge_object_parts, MergedObject, MergedObjectParts, MergedObjectTail};
+pub use mutation_result::{DefaultPayloadName, MutationResult, PayloadName, UserError};
+pub use raw::Raw;
+pub use runtime_scalar::RuntimeScalar;
 #[cfg(feature = "string_number")]
 pub use string_number::StringNumber;
+pub use subscription_message::SubscriptionMessage;
 pub use upload::{Upload, UploadValue};
 
 pub(crate) use query_root::QueryRoot;
+pub(crate) use runtime_scalar::register_scalar;
+pub(crate) use subscription_message::SubscriptionFrame;
@@ -0,0 +1,264 @@
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+use crate::parser::types::Field;
+use crate::resolver_utils::{resolve_container, resolve_field_value, ContainerType};
+use crate::{
+    registry, Context, ContextSelectionSet, ObjectType, OutputValueType, Positioned, ServerResult,
+    SimpleObject, Type, Value,
+};
+
+/// An error of a mutation caused by its input, e.g. a value that is already taken, returned in
+/// the `userErrors` of a [`MutationResult`](struct.MutationResult.html).
+#[derive(SimpleObject, Debug, Clone, Eq, PartialEq)]
+#[graphql(internal)]
+pub struct UserError {
+    /// The description of the error.
+    pub message: String,
+
+    /// The path to the input field that caused the error, e.g. `["input", "email"]`, empty if
+    /// the error is not caused by a specific field.
+    pub field: Vec<String>,
+
+    /// A machine-readable code of the error.
+    pub code: Option<String>,
+}
+
+impl UserError {
+    /// Create an error that is not caused by a specific field.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            field: Vec::new(),
+            code: None,
+        }
+    }
+
+    /// Set the path to the input field that caused the error.
+    pub fn with_field<I, S>(self, field: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            field: field.into_iter().map(Into::into).collect(),
+            ..self
+        }
+    }
+
+    /// Set the code of the error.
+    pub fn with_code(self, code: impl Into<String>) -> Self {
+        Self {
+            code: Some(code.into()),
+            ..self
+        }
+    }
+}
+
+/// The name of the payload type of a [`MutationResult`](struct.MutationResult.html).
+pub trait PayloadName {
+    /// The name of the payload type of a mutation returning `T`.
+    fn type_name<T: Type>() -> String;
+}
+
+/// Name the payload of a mutation returning `T` `TPayload`, e.g. `UserPayload` for `User`.
+pub struct DefaultPayloadName;
+
+impl PayloadName for DefaultPayloadName {
+    fn type_name<T: Type>() -> String {
+        format!("{}Payload", T::type_name())
+    }
+}
+
+/// The payload of a mutation returning `T`, with the errors caused by its input.
+///
+/// Its type has the following fields, and is named by `N`, `TPayload` by default:
+///
+/// ```graphql
+/// type UserPayload {
+///   success: Boolean!
+///   data: User
+///   userErrors: [UserError!]!
+/// }
+/// ```
+///
+/// The user errors are of type `E`, [`UserError`](struct.UserError.html) by default. They are
+/// part of the data of the response, unlike the errors returned by resolvers. The mutations
+/// returning the same `T` share the payload type, implement [`PayloadName`](trait.PayloadName.html)
+/// to give each one its own name.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// #[derive(SimpleObject)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// struct CreateUser;
+///
+/// impl PayloadName for CreateUser {
+///     fn type_name<T: Type>() -> String {
+///         "CreateUserPayload".to_string()
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn value(&self) -> i32 {
+///         10
+///     }
+/// }
+///
+/// struct Mutation;
+///
+/// #[Object]
+/// impl Mutation {
+///     async fn create_user(&self, name: String) -> MutationResult<User, UserError, CreateUser> {
+///         if name.is_empty() {
+///             return MutationResult::error(UserError::new("The name is empty.").with_field(["name"]));
+///         }
+///         MutationResult::ok(User { name })
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(Query, Mutation, EmptySubscription);
+///     let res = schema
+///         .execute(r#"mutation { createUser(name: "") { success data { name } userErrors { message field } } }"#)
+///         .await;
+///     assert_eq!(res.data, value!({
+///         "createUser": {
+///             "success": false,
+///             "data": null,
+///             "userErrors": [{ "message": "The name is empty.", "field": ["name"] }],
+///         }
+///     }));
+/// });
+/// ```
+pub struct MutationResult<T, E = UserError, N = DefaultPayloadName> {
+    data: Option<T>,
+    user_errors: Vec<E>,
+    _marker: PhantomData<N>,
+}
+
+impl<T, E, N> MutationResult<T, E, N> {
+    /// Create a successful result.
+    pub fn ok(data: T) -> Self {
+        Self {
+            data: Some(data),
+            user_errors: Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Create a result that failed with a user error.
+    pub fn error(error: impl Into<E>) -> Self {
+        Self::errors(std::iter::once(error.into()))
+    }
+
+    /// Create a result that failed with some user errors.
+    pub fn errors(errors: impl IntoIterator<Item = E>) -> Self {
+        Self {
+            data: None,
+            user_errors: errors.into_iter().collect(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns `true` if the mutation has no user errors.
+    pub fn is_success(&self) -> bool {
+        self.user_errors.is_empty()
+    }
+}
+
+impl<T, E, N> From<std::result::Result<T, E>> for MutationResult<T, E, N> {
+    fn from(res: std::result::Result<T, E>) -> Self {
+        match res {
+            Ok(data) => Self::ok(data),
+            Err(err) => Self::error(err),
+        }
+    }
+}
+
+impl<T, E, N> From<std::result::Result<T, Vec<E>>> for MutationResult<T, E, N> {
+    fn from(res: std::result::Result<T, Vec<E>>) -> Self {
+        match res {
+            Ok(data) => Self::ok(data),
+            Err(errors) => Self::errors(errors),
+        }
+    }
+}
+
+impl<T, E, N> Type for MutationResult<T, E, N>
+where
+    T: OutputValueType,
+    E: ObjectType,
+    N: PayloadName,
+{
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(N::type_name::<T>())
+    }
+
+    fn create_type_info(registry: &mut registry::Registry) -> String {
+        registry.create_type::<Self, _>(|registry| {
+            registry::MetaType::object(
+                Self::type_name(),
+                Some("The result of a mutation.".to_string()),
+                vec![
+                    registry::MetaField::new("success", bool::create_type_info(registry))
+                        .description("Did the mutation succeed, i.e. has it no user errors?"),
+                    registry::MetaField::new("data", Option::<T>::create_type_info(registry))
+                        .description("The result of the mutation, null if it failed"),
+                    registry::MetaField::new("userErrors", Vec::<E>::create_type_info(registry))
+                        .description("The errors caused by the input of the mutation"),
+                ],
+            )
+        })
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<T, E, N> ContainerType for MutationResult<T, E, N>
+where
+    T: OutputValueType,
+    E: ObjectType,
+    N: PayloadName,
+{
+    async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+        match ctx.item.node.name.node.as_str() {
+            "success" => Ok(Some(Value::Boolean(self.is_success()))),
+            "data" => resolve_field_value(ctx, &self.data).await,
+            "userErrors" => resolve_field_value(ctx, &self.user_errors).await,
+            _ => Ok(None),
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<T, E, N> OutputValueType for MutationResult<T, E, N>
+where
+    T: OutputValueType,
+    E: ObjectType,
+    N: PayloadName,
+{
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        resolve_container(ctx, self).await
+    }
+}
+
+impl<T, E, N> ObjectType for MutationResult<T, E, N>
+where
+    T: OutputValueType,
+    E: ObjectType,
+    N: PayloadName,
+{
+}
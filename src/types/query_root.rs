@@ -2,8 +2,10 @@ use std::borrow::Cow;
 
 use indexmap::map::IndexMap;
 
-use crate::model::{__Schema, __Type};
+use crate::extensions::{ErrorLogger, ExtensionContext, ResolveInfo};
+use crate::model::{__MetadataEntry, __Schema, __Type};
 use crate::parser::types::Field;
+use crate::registry::MetaTypeName;
 use crate::resolver_utils::{resolve_container, ContainerType};
 use crate::{
     registry, Any, Context, ContextSelectionSet, ObjectType, OutputValueType, Positioned,
@@ -63,6 +65,7 @@ impl<T: Type> Type for QueryRoot<T> {
                                 ty: "String!".to_string(),
                                 default_value: None,
                                 validator: None,
+                                is_secret: false,
                             },
                         );
                         args
@@ -80,6 +83,58 @@ impl<T: Type> Type for QueryRoot<T> {
     }
 }
 
+impl<T: ObjectType> QueryRoot<T> {
+    async fn resolve_entity(
+        &self,
+        ctx: &Context<'_>,
+        representation: &Value,
+    ) -> ServerResult<Value> {
+        let find_entity = async {
+            self.inner
+                .find_entity(ctx, representation)
+                .await?
+                .ok_or_else(|| ServerError::new("Entity not found.").at(ctx.item.pos))
+        };
+
+        let ctx_extension = ExtensionContext {
+            schema_data: &ctx.schema_env.data,
+            query_data: &ctx.query_env.ctx_data,
+        };
+
+        if ctx.query_env.extensions.is_empty() {
+            return find_entity
+                .await
+                .log_error(&ctx_extension, &ctx.query_env.extensions);
+        }
+
+        let typename = match representation {
+            Value::Object(params) => match params.get("__typename") {
+                Some(Value::String(typename)) => typename.as_str(),
+                _ => "_Entity",
+            },
+            _ => "_Entity",
+        };
+        let resolve_info = ResolveInfo {
+            resolve_id: ctx.resolve_id,
+            path_node: ctx.path_node.as_ref().unwrap(),
+            parent_type: "[_Entity]",
+            return_type: typename,
+            arguments: None,
+        };
+
+        ctx.query_env
+            .extensions
+            .resolve_start(&ctx_extension, &resolve_info);
+        let res = find_entity
+            .await
+            .log_error(&ctx_extension, &ctx.query_env.extensions);
+        ctx.query_env
+            .extensions
+            .resolve_end(&ctx_extension, &resolve_info);
+        res
+    }
+}
+
 #[async_trait::async_trait(?Send)]
 impl<T: ObjectType> ContainerType for QueryRoot<T> {
     async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
@@ -100,13 +155,30 @@ impl<T: ObjectType> ContainerType for QueryRoot<T> {
             .map(Some);
         } else if ctx.item.node.name.node == "__type" {
             let type_name: String = ctx.param_value("name", None)?;
+            let registry = &ctx.schema_env.registry;
+            let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
+            return OutputValueType::resolve(
+                &MetaTypeName::normalize(&type_name)
+                    .filter(|type_name| {
+                        registry
+                            .types
+                            .contains_key(MetaTypeName::concrete_typename(type_name))
+                    })
+                    .map(|type_name| __Type::new(registry, &type_name)),
+                &ctx_obj,
+                ctx.item,
+            )
+            .await
+            .map(Some);
+        } else if ctx.item.node.name.node == "__metadata" {
+            if self.disable_introspection {
+                return Err(ServerError::new("Query introspection is disabled.").at(ctx.item.pos));
+            }
+
+            let coordinate: String = ctx.param_value("coordinate", None)?;
             let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
             return OutputValueType::resolve(
-                &ctx.schema_env
-                    .registry
-                    .types
-                    .get(&type_name)
-                    .map(|ty| __Type::new_simple(&ctx.schema_env.registry, ty)),
+                &__MetadataEntry::list(&ctx.schema_env.registry, &coordinate),
                 &ctx_obj,
                 ctx.item,
             )
@@ -114,14 +186,17 @@ impl<T: ObjectType> ContainerType for QueryRoot<T> {
             .map(Some);
         } else if ctx.item.node.name.node == "_entities" {
             let representations: Vec<Any> = ctx.param_value("representations", None)?;
-            let mut res = Vec::new();
-            for item in representations {
-                res.push(
-                    self.inner
-                        .find_entity(ctx, &item.0)
-                        .await?
-                        .ok_or_else(|| ServerError::new("Entity not found.").at(ctx.item.pos))?,
-                );
+            let mut res = Vec::with_capacity(representations.len());
+            for (idx, item) in representations.iter().enumerate() {
+                // Each representation is resolved on its own, so a failure only nulls its entry.
+                let ctx_idx = ctx.with_index(idx);
+                res.push(match self.resolve_entity(&ctx_idx, &item.0).await {
+                    Ok(value) => value,
+                    Err(err) => {
+                        ctx_idx.add_error(err);
+                        Value::Null
+                    }
+                });
             }
             return Ok(Some(Value::List(res)));
         } else if ctx.item.node.name.node == "_service" {
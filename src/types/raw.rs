@@ -0,0 +1,150 @@
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+use crate::parser::types::Field;
+use crate::registry::{MetaType, MetaTypeName, Registry};
+use crate::{
+    ContextSelectionSet, OutputValueType, Positioned, RawValue, ServerError, ServerResult, Type,
+    Value,
+};
+
+/// An already serialized JSON value of the GraphQL type `T`, e.g. a cached response or the
+/// response of another service.
+///
+/// The JSON is written to the response as is, without deserializing and serializing it again,
+/// so it must already have the fields selected by the query, in the order they were selected.
+/// By default it is not checked at all, [`validate`](#method.validate) checks its shape against
+/// `T` when the field is resolved.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// #[derive(SimpleObject)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn user(&self) -> Raw<User> {
+///         Raw::new(r#"{"name":"Alice"}"#).unwrap().validate()
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+///     let res = schema.execute("{ user { name } }").await;
+///     assert_eq!(
+///         serde_json::to_string(&res.data).unwrap(),
+///         r#"{"user":{"name":"Alice"}}"#
+///     );
+/// });
+/// ```
+pub struct Raw<T> {
+    value: RawValue,
+    validate: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<T> Raw<T> {
+    /// Create a raw value from a JSON text.
+    ///
+    /// # Errors
+    ///
+    /// Fails if `json` is not valid JSON.
+    pub fn new(json: impl Into<String>) -> serde_json::Result<Self> {
+        Ok(Self {
+            value: RawValue::from_string(json.into())?,
+            validate: false,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Check that the JSON is a value of the kind of `T`, e.g. an object for an object type or a
+    /// list for a list type, and that it is not `null` if `T` is non-null. The content of objects
+    /// and lists is not checked.
+    #[must_use]
+    pub fn validate(self) -> Self {
+        Self {
+            validate: true,
+            ..self
+        }
+    }
+
+    /// Get the JSON text of this value.
+    pub fn get(&self) -> &str {
+        self.value.get()
+    }
+}
+
+impl<T: Type> Type for Raw<T> {
+    fn type_name() -> Cow<'static, str> {
+        T::type_name()
+    }
+
+    fn qualified_type_name() -> String {
+        T::qualified_type_name()
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        T::create_type_info(registry)
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<T: Type> OutputValueType for Raw<T> {
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        if self.validate {
+            let type_name = T::qualified_type_name();
+            if let Some(expected) = check_kind(&ctx.schema_env.registry, &type_name, self.get()) {
+                return Err(ServerError::new(format!(
+                    r#"Invalid raw value for type "{}", expected {}."#,
+                    type_name, expected
+                ))
+                .at(field.pos));
+            }
+        }
+        Ok(Value::Raw(self.value.clone()))
+    }
+}
+
+/// Check the kind of the JSON value `json` against the qualified type `type_name`, and return
+/// the expected kind if they don't match.
+fn check_kind(registry: &Registry, type_name: &str, json: &str) -> Option<&'static str> {
+    let first = json.trim_start().as_bytes().first().copied();
+    if first == Some(b'n') {
+        return match MetaTypeName::create(type_name) {
+            MetaTypeName::NonNull(_) => Some("a non-null value"),
+            _ => None,
+        };
+    }
+
+    let (expected, matches): (_, fn(u8) -> bool) = match MetaTypeName::create(type_name) {
+        MetaTypeName::NonNull(type_name) => return check_kind(registry, type_name, json),
+        MetaTypeName::List(_) => ("a list", |first| first == b'['),
+        MetaTypeName::Named("Int") | MetaTypeName::Named("Float") => {
+            ("a number", |first| first == b'-' || first.is_ascii_digit())
+        }
+        MetaTypeName::Named("String") | MetaTypeName::Named("ID") => {
+            ("a string", |first| first == b'"')
+        }
+        MetaTypeName::Named("Boolean") => ("a boolean", |first| first == b't' || first == b'f'),
+        MetaTypeName::Named(type_name) => match registry.types.get(type_name) {
+            Some(MetaType::Enum { .. }) => ("a string", |first| first == b'"'),
+            Some(MetaType::Scalar { .. }) | None => return None,
+            Some(_) => ("an object", |first| first == b'{'),
+        },
+    };
+    match first {
+        Some(first) if matches(first) => None,
+        _ => Some(expected),
+    }
+}
@@ -0,0 +1,173 @@
+use std::any::{Any, TypeId};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
+
+use once_cell::sync::Lazy;
+
+use crate::parser::types::Field;
+use crate::registry::{MetaType, Registry};
+use crate::{
+    ContextSelectionSet, InputValueError, InputValueResult, InputValueType, OutputValueType,
+    Positioned, ScalarType, ServerResult, Type, Value,
+};
+
+/// The functions of a scalar registered with `SchemaBuilder::register_scalar`.
+struct ScalarFns<T> {
+    name: String,
+    parse: fn(Value) -> Result<T, InputValueError<RuntimeScalar<T>>>,
+    to_value: fn(&T) -> Value,
+    is_valid: fn(&Value) -> bool,
+}
+
+/// The runtime scalar table, the functions of the registered scalars by the type they convert.
+static SCALARS: Lazy<spin::RwLock<HashMap<TypeId, Box<dyn Any + Send + Sync>>>> =
+    Lazy::new(Default::default);
+
+pub(crate) fn register_scalar<T: 'static>(
+    name: String,
+    parse: fn(Value) -> Result<T, InputValueError<RuntimeScalar<T>>>,
+    to_value: fn(&T) -> Value,
+    is_valid: fn(&Value) -> bool,
+) {
+    let fns = ScalarFns {
+        name,
+        parse,
+        to_value,
+        is_valid,
+    };
+    SCALARS.write().insert(TypeId::of::<T>(), Box::new(fns));
+}
+
+fn with_scalar<T: 'static, R>(f: impl FnOnce(&ScalarFns<T>) -> R) -> R {
+    let scalars = SCALARS.read();
+    match scalars
+        .get(&TypeId::of::<T>())
+        .and_then(|fns| fns.downcast_ref::<ScalarFns<T>>())
+    {
+        Some(fns) => f(fns),
+        None => panic!(
+            "The scalar for `{}` is not registered, call `SchemaBuilder::register_scalar` for it.",
+            std::any::type_name::<T>()
+        ),
+    }
+}
+
+/// A scalar of a type that can't implement `ScalarType`, e.g. a type of another crate, which
+/// is converted by the functions registered with
+/// [`SchemaBuilder::register_scalar`](struct.SchemaBuilder.html#method.register_scalar).
+///
+/// The functions are registered for the whole process, each type is a single scalar in all the
+/// schemas. Using a `RuntimeScalar<T>` whose functions are not registered panics.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use std::net::Ipv4Addr;
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn next(&self, addr: RuntimeScalar<Ipv4Addr>) -> RuntimeScalar<Ipv4Addr> {
+///         RuntimeScalar(Ipv4Addr::from(u32::from(*addr) + 1))
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+///         .register_scalar::<Ipv4Addr>(
+///             "Ipv4",
+///             |value| match &value {
+///                 Value::String(s) => Ok(s.parse()?),
+///                 _ => Err(InputValueError::expected_type(value)),
+///             },
+///             |addr| Value::String(addr.to_string()),
+///             |value| matches!(value, Value::String(_)),
+///         )
+///         .finish();
+///     let res = schema.execute(r#"{ next(addr: "10.0.0.1") }"#).await;
+///     assert_eq!(res.data, value!({ "next": "10.0.0.2" }));
+/// });
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
+pub struct RuntimeScalar<T>(pub T);
+
+impl<T> RuntimeScalar<T> {
+    /// Get the converted value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for RuntimeScalar<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for RuntimeScalar<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> From<T> for RuntimeScalar<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: 'static> ScalarType for RuntimeScalar<T> {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        let parse = with_scalar::<T, _>(|fns| fns.parse);
+        parse(value).map(RuntimeScalar)
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        with_scalar::<T, _>(|fns| (fns.is_valid)(value))
+    }
+
+    fn to_value(&self) -> Value {
+        with_scalar::<T, _>(|fns| (fns.to_value)(&self.0))
+    }
+}
+
+impl<T: 'static> Type for RuntimeScalar<T> {
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(with_scalar::<T, _>(|fns| fns.name.clone()))
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        registry.create_type::<Self, _>(|_| MetaType::Scalar {
+            name: Self::type_name().into_owned(),
+            description: None,
+            is_valid: |value| <Self as ScalarType>::is_valid(value),
+            specified_by_url: None,
+        })
+    }
+}
+
+impl<T: 'static> InputValueType for RuntimeScalar<T> {
+    fn parse(value: Option<Value>) -> InputValueResult<Self> {
+        <Self as ScalarType>::parse(value.unwrap_or_default())
+    }
+
+    fn to_value(&self) -> Value {
+        <Self as ScalarType>::to_value(self)
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<T: 'static> OutputValueType for RuntimeScalar<T> {
+    async fn resolve(
+        &self,
+        _: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        Ok(<Self as ScalarType>::to_value(self))
+    }
+}
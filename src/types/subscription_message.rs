@@ -0,0 +1,111 @@
+use std::borrow::Cow;
+
+use crate::parser::types::Field;
+use crate::{
+    registry, ContextSelectionSet, OutputValueType, Positioned, ServerResult, Type, Value,
+};
+
+/// A message of the stream of a subscription, either an event or a frame without data that keeps
+/// the connection alive, e.g. while a long computation runs, so that proxies don't close it.
+///
+/// The field has the type of `T`. An event is output like a `T`, the other messages are output
+/// as responses without data:
+///
+/// - `KeepAlive` is a response with `Response::keep_alive` set, sent by
+///   [`WebSocket`](http/struct.WebSocket.html) as a keep-alive message and by
+///   [`sse_events`](http/fn.sse_events.html) as a comment.
+/// - `Progress` is a response with the value in its `progress` extension.
+///
+/// [`SubscriptionStreamExt::heartbeat`](trait.SubscriptionStreamExt.html#method.heartbeat)
+/// adds a `KeepAlive` message whenever a stream has no event for a while.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use futures_util::stream::{self, Stream, StreamExt};
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn value(&self) -> i32 {
+///         10
+///     }
+/// }
+///
+/// struct Subscription;
+///
+/// #[Subscription]
+/// impl Subscription {
+///     async fn report(&self) -> impl Stream<Item = SubscriptionMessage<String>> {
+///         stream::iter(vec![
+///             SubscriptionMessage::Progress(value!({ "percent": 50 })),
+///             SubscriptionMessage::KeepAlive,
+///             SubscriptionMessage::Event("done".to_string()),
+///         ])
+///     }
+/// }
+///
+/// async_std::task::block_on(async move {
+///     let schema = Schema::new(Query, EmptyMutation, Subscription);
+///     let responses: Vec<Response> = schema.execute_stream("subscription { report }").collect().await;
+///     assert_eq!(responses[0].extensions, Some(value!({ "progress": { "percent": 50 } })));
+///     assert!(responses[1].keep_alive);
+///     assert_eq!(responses[2].data, value!({ "report": "done" }));
+/// });
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubscriptionMessage<T> {
+    /// An event of the subscription.
+    Event(T),
+    /// Keep the connection alive.
+    KeepAlive,
+    /// The progress of the computation of the next event.
+    Progress(Value),
+}
+
+impl<T> From<T> for SubscriptionMessage<T> {
+    fn from(event: T) -> Self {
+        SubscriptionMessage::Event(event)
+    }
+}
+
+/// A response of a subscription without data.
+pub(crate) enum SubscriptionFrame {
+    KeepAlive,
+    Progress(Value),
+}
+
+impl<T: Type> Type for SubscriptionMessage<T> {
+    fn type_name() -> Cow<'static, str> {
+        T::type_name()
+    }
+
+    fn qualified_type_name() -> String {
+        T::qualified_type_name()
+    }
+
+    fn create_type_info(registry: &mut registry::Registry) -> String {
+        T::create_type_info(registry)
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<T: OutputValueType> OutputValueType for SubscriptionMessage<T> {
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        let frame = match self {
+            SubscriptionMessage::Event(event) => return event.resolve(ctx, field).await,
+            SubscriptionMessage::KeepAlive => SubscriptionFrame::KeepAlive,
+            SubscriptionMessage::Progress(progress) => {
+                SubscriptionFrame::Progress(progress.clone())
+            }
+        };
+        *ctx.query_env.subscription_frame.lock() = Some(frame);
+        Ok(Value::Null)
+    }
+}
@@ -8,6 +8,8 @@ mod utils;
 mod visitor;
 mod visitors;
 
+use std::collections::BTreeSet;
+
 use crate::parser::types::ExecutableDocument;
 use crate::registry::Registry;
 use crate::{CacheControl, ServerError, Variables};
@@ -19,6 +21,24 @@ pub struct CheckResult {
     pub depth: usize,
 }
 
+/// The result of the static analysis of a query, returned by
+/// [`Schema::analyze`](struct.Schema.html#method.analyze).
+///
+/// It covers all the operations of the document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryAnalysis {
+    /// The depth of the most nested selection.
+    pub depth: usize,
+    /// The complexity, as limited by `SchemaBuilder::limit_complexity`.
+    pub complexity: usize,
+    /// The cache control of the query.
+    pub cache_control: CacheControl,
+    /// The fields that are selected, as `Type.field` coordinates, sorted.
+    pub fields: Vec<String>,
+    /// The names of the types whose fields are selected and of the types of those fields, sorted.
+    pub types: Vec<String>,
+}
+
 /// Validation mode
 #[derive(Copy, Clone, Debug)]
 pub enum ValidationMode {
@@ -29,6 +49,30 @@ pub enum ValidationMode {
     Fast,
 }
 
+/// The edition of the GraphQL specification the schema follows, default is
+/// `SpecVersion::June2018`.
+///
+/// Pin the edition your clients were written against while migrating them.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SpecVersion {
+    /// [June 2018](https://spec.graphql.org/June2018/).
+    ///
+    /// * No directive can be repeated at the same location.
+    /// * `__Type.specifiedByURL` and `__Directive.isRepeatable` can't be queried, and the SDL has
+    ///   no `@specifiedBy` directives.
+    /// * `__Type.interfaces` is `null` for interfaces.
+    June2018,
+
+    /// [October 2021](https://spec.graphql.org/October2021/).
+    ///
+    /// * Directives defined as `repeatable` can be repeated at the same location.
+    /// * Custom scalars can link to their specification with `specifiedByURL`.
+    /// * `__Type.interfaces` is a list for interfaces as well, interfaces can implement
+    ///   interfaces. Interfaces defined with this crate don't implement any yet, so the list is
+    ///   empty.
+    October2021,
+}
+
 pub fn check_rules(
     registry: &Registry,
     doc: &ExecutableDocument,
@@ -46,6 +90,7 @@ pub fn check_rules(
                 .with(rules::ArgumentsOfCorrectType::default())
                 .with(rules::DefaultValuesOfCorrectType)
                 .with(rules::FieldsOnCorrectType)
+                .with(rules::FragmentArguments)
                 .with(rules::FragmentsOnCompositeTypes)
                 .with(rules::KnownArgumentNames::default())
                 .with(rules::NoFragmentCycles::default())
@@ -54,6 +99,7 @@ pub fn check_rules(
                 .with(rules::NoUndefinedVariables::default())
                 .with(rules::NoUnusedFragments::default())
                 .with(rules::NoUnusedVariables::default())
+                .with(rules::NullabilityDesignators)
                 .with(rules::UniqueArgumentNames::default())
                 .with(rules::UniqueVariableNames::default())
                 .with(rules::VariablesAreInputTypes)
@@ -62,6 +108,7 @@ pub fn check_rules(
                 .with(rules::PossibleFragmentSpreads::default())
                 .with(rules::ProvidedNonNullArguments)
                 .with(rules::KnownDirectives::default())
+                .with(rules::UniqueDirectivesPerLocation)
                 .with(rules::OverlappingFieldsCanBeMerged)
                 .with(rules::UploadFile)
                 .with(visitors::CacheControlCalculate {
@@ -98,3 +145,20 @@ pub fn check_rules(
         depth: depth as usize,
     })
 }
+
+/// Collect the fields and types a valid document uses.
+pub fn collect_type_usage(
+    registry: &Registry,
+    doc: &ExecutableDocument,
+    variables: Option<&Variables>,
+) -> (Vec<String>, Vec<String>) {
+    let mut ctx = VisitorContext::new(registry, doc, variables);
+    let mut fields = BTreeSet::new();
+    let mut types = BTreeSet::new();
+    let mut visitor = visitors::TypeUsageCollect {
+        fields: &mut fields,
+        types: &mut types,
+    };
+    visit(&mut visitor, &mut ctx, doc);
+    (fields.into_iter().collect(), types.into_iter().collect())
+}
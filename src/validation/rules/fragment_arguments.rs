@@ -0,0 +1,252 @@
+use std::collections::HashSet;
+
+use crate::context::QueryPathNode;
+use crate::parser::types::{FragmentDefinition, FragmentSpread};
+use crate::registry::MetaTypeName;
+use crate::validation::utils::is_valid_input_value;
+use crate::validation::visitor::{Visitor, VisitorContext};
+use crate::{Name, Positioned, QueryPathSegment};
+
+/// Validates the arguments of fragment spreads against the variables of the fragments, or
+/// rejects them if the `fragment_arguments` feature is disabled.
+#[derive(Default)]
+pub struct FragmentArguments;
+
+impl<'a> Visitor<'a> for FragmentArguments {
+    fn enter_fragment_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        name: &'a Name,
+        fragment_definition: &'a Positioned<FragmentDefinition>,
+    ) {
+        if !cfg!(feature = "fragment_arguments")
+            && !fragment_definition.node.variable_definitions.is_empty()
+        {
+            ctx.report_error(
+                vec![fragment_definition.pos],
+                format!(
+                    "Fragment \"{}\" cannot define variables, fragment arguments are not enabled",
+                    name
+                ),
+            );
+        }
+    }
+
+    fn enter_fragment_spread(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        fragment_spread: &'a Positioned<FragmentSpread>,
+    ) {
+        let fragment_name = &fragment_spread.node.fragment_name.node;
+
+        if !cfg!(feature = "fragment_arguments") {
+            if !fragment_spread.node.arguments.is_empty() {
+                ctx.report_error(
+                    vec![fragment_spread.pos],
+                    format!(
+                        "Fragment spread \"{}\" cannot have arguments, fragment arguments are not enabled",
+                        fragment_name
+                    ),
+                );
+            }
+            return;
+        }
+
+        let fragment = match ctx.fragment(fragment_name) {
+            Some(fragment) => fragment,
+            None => return,
+        };
+        let definitions = &fragment.node.variable_definitions;
+
+        let mut names = HashSet::new();
+        for (name, value) in &fragment_spread.node.arguments {
+            if !names.insert(name.node.as_str()) {
+                ctx.report_error(
+                    vec![name.pos],
+                    format!("There can only be one argument named \"{}\"", name),
+                );
+                continue;
+            }
+
+            let definition = match definitions
+                .iter()
+                .find(|definition| definition.node.name.node == name.node)
+            {
+                Some(definition) => definition,
+                None => {
+                    ctx.report_error(
+                        vec![name.pos],
+                        format!(
+                            "Unknown argument \"{}\" on fragment \"{}\".",
+                            name, fragment_name
+                        ),
+                    );
+                    continue;
+                }
+            };
+
+            let value = value
+                .node
+                .clone()
+                .into_const_with(|var_name| {
+                    ctx.variables
+                        .and_then(|variables| variables.0.get(&var_name))
+                        .cloned()
+                        .ok_or(())
+                })
+                .ok();
+            if let Some(reason) = value.and_then(|value| {
+                is_valid_input_value(
+                    ctx.registry,
+                    &definition.node.var_type.node.to_string(),
+                    &value,
+                    QueryPathNode {
+                        parent: None,
+                        segment: QueryPathSegment::Name(&name.node),
+                    },
+                )
+            }) {
+                ctx.report_error(
+                    vec![name.pos],
+                    format!("Invalid value for argument {}", reason),
+                );
+            }
+        }
+
+        for definition in definitions {
+            let var_type = definition.node.var_type.node.to_string();
+            if MetaTypeName::create(&var_type).is_non_null()
+                && definition.node.default_value.is_none()
+                && !names.contains(definition.node.name.node.as_str())
+            {
+                ctx.report_error(
+                    vec![fragment_spread.pos],
+                    format!(
+                        "Fragment \"{}\" argument \"{}\" of type \"{}\" is required but not provided",
+                        fragment_name, definition.node.name.node, var_type
+                    ),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub fn factory() -> FragmentArguments {
+        FragmentArguments
+    }
+
+    #[test]
+    fn no_fragment_arguments() {
+        expect_passes_rule!(
+            factory,
+            r#"
+          {
+            dog { ...dogFields }
+          }
+          fragment dogFields on Dog { name }
+        "#,
+        );
+    }
+
+    #[cfg(not(feature = "fragment_arguments"))]
+    #[test]
+    fn fragment_arguments_disabled() {
+        expect_fails_rule!(
+            factory,
+            r#"
+          {
+            dog { ...dogFields(surname: true) }
+          }
+          fragment dogFields($surname: Boolean) on Dog { name(surname: $surname) }
+        "#,
+        );
+    }
+
+    #[cfg(feature = "fragment_arguments")]
+    #[test]
+    fn valid_fragment_arguments() {
+        expect_passes_rule!(
+            factory,
+            r#"
+          {
+            dog { ...dogFields(surname: true) ...dogFields }
+          }
+          fragment dogFields($surname: Boolean) on Dog { name(surname: $surname) }
+        "#,
+        );
+    }
+
+    #[cfg(feature = "fragment_arguments")]
+    #[test]
+    fn unknown_fragment_argument() {
+        expect_fails_rule!(
+            factory,
+            r#"
+          {
+            dog { ...dogFields(unknown: true) }
+          }
+          fragment dogFields($surname: Boolean) on Dog { name(surname: $surname) }
+        "#,
+        );
+    }
+
+    #[cfg(feature = "fragment_arguments")]
+    #[test]
+    fn duplicate_fragment_argument() {
+        expect_fails_rule!(
+            factory,
+            r#"
+          {
+            dog { ...dogFields(surname: true, surname: false) }
+          }
+          fragment dogFields($surname: Boolean) on Dog { name(surname: $surname) }
+        "#,
+        );
+    }
+
+    #[cfg(feature = "fragment_arguments")]
+    #[test]
+    fn fragment_argument_of_incorrect_type() {
+        expect_fails_rule!(
+            factory,
+            r#"
+          {
+            dog { ...dogFields(surname: "yes") }
+          }
+          fragment dogFields($surname: Boolean) on Dog { name(surname: $surname) }
+        "#,
+        );
+    }
+
+    #[cfg(feature = "fragment_arguments")]
+    #[test]
+    fn missing_required_fragment_argument() {
+        expect_fails_rule!(
+            factory,
+            r#"
+          {
+            dog { ...dogFields }
+          }
+          fragment dogFields($surname: Boolean!) on Dog { name(surname: $surname) }
+        "#,
+        );
+    }
+
+    #[cfg(feature = "fragment_arguments")]
+    #[test]
+    fn required_fragment_argument_with_default() {
+        expect_passes_rule!(
+            factory,
+            r#"
+          {
+            dog { ...dogFields }
+          }
+          fragment dogFields($surname: Boolean! = true) on Dog { name(surname: $surname) }
+        "#,
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use crate::model::__DirectiveLocation;
 use crate::parser::types::{
     Directive, Field, FragmentDefinition, FragmentSpread, InlineFragment, OperationDefinition,
-    OperationType,
+    OperationType, VariableDefinition,
 };
 use crate::validation::visitor::{Visitor, VisitorContext};
 use crate::{Name, Positioned};
@@ -54,6 +54,23 @@ impl<'a> Visitor<'a> for KnownDirectives {
         self.location_stack.pop();
     }
 
+    fn enter_variable_definition(
+        &mut self,
+        _ctx: &mut VisitorContext<'a>,
+        _variable_definition: &'a Positioned<VariableDefinition>,
+    ) {
+        self.location_stack
+            .push(__DirectiveLocation::VARIABLE_DEFINITION);
+    }
+
+    fn exit_variable_definition(
+        &mut self,
+        _ctx: &mut VisitorContext<'a>,
+        _variable_definition: &'a Positioned<VariableDefinition>,
+    ) {
+        self.location_stack.pop();
+    }
+
     fn enter_directive(
         &mut self,
         ctx: &mut VisitorContext<'a>,
@@ -234,4 +251,28 @@ mod tests {
         "#,
         );
     }
+
+    #[test]
+    fn with_misplaced_directive_on_variable_definition() {
+        expect_fails_rule!(
+            factory,
+            r#"
+          query Foo($var: Boolean @include(if: true)) {
+            name @skip(if: $var)
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn with_unknown_directive_on_variable_definition() {
+        expect_fails_rule!(
+            factory,
+            r#"
+          query Foo($var: Boolean @unknown) {
+            name @skip(if: $var)
+          }
+        "#,
+        );
+    }
 }
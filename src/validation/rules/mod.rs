@@ -1,6 +1,7 @@
 mod arguments_of_correct_type;
 mod default_values_of_correct_type;
 mod fields_on_correct_type;
+mod fragment_arguments;
 mod fragments_on_composite_types;
 mod known_argument_names;
 mod known_directives;
@@ -10,11 +11,13 @@ mod no_fragment_cycles;
 mod no_undefined_variables;
 mod no_unused_fragments;
 mod no_unused_variables;
+mod nullability_designators;
 mod overlapping_fields_can_be_merged;
 mod possible_fragment_spreads;
 mod provided_non_null_arguments;
 mod scalar_leafs;
 mod unique_argument_names;
+mod unique_directives_per_location;
 mod unique_variable_names;
 mod upload_file;
 mod variables_are_input_types;
@@ -23,6 +26,7 @@ mod variables_in_allowed_position;
 pub use arguments_of_correct_type::ArgumentsOfCorrectType;
 pub use default_values_of_correct_type::DefaultValuesOfCorrectType;
 pub use fields_on_correct_type::FieldsOnCorrectType;
+pub use fragment_arguments::FragmentArguments;
 pub use fragments_on_composite_types::FragmentsOnCompositeTypes;
 pub use known_argument_names::KnownArgumentNames;
 pub use known_directives::KnownDirectives;
@@ -32,11 +36,13 @@ pub use no_fragment_cycles::NoFragmentCycles;
 pub use no_undefined_variables::NoUndefinedVariables;
 pub use no_unused_fragments::NoUnusedFragments;
 pub use no_unused_variables::NoUnusedVariables;
+pub use nullability_designators::NullabilityDesignators;
 pub use overlapping_fields_can_be_merged::OverlappingFieldsCanBeMerged;
 pub use possible_fragment_spreads::PossibleFragmentSpreads;
 pub use provided_non_null_arguments::ProvidedNonNullArguments;
 pub use scalar_leafs::ScalarLeafs;
 pub use unique_argument_names::UniqueArgumentNames;
+pub use unique_directives_per_location::UniqueDirectivesPerLocation;
 pub use unique_variable_names::UniqueVariableNames;
 pub use upload_file::UploadFile;
 pub use variables_are_input_types::VariablesAreInputTypes;
@@ -14,6 +14,7 @@ pub struct NoUndefinedVariables<'a> {
     used_variables: HashMap<Scope<'a>, HashMap<&'a str, Pos>>,
     current_scope: Option<Scope<'a>>,
     spreads: HashMap<Scope<'a>, Vec<&'a str>>,
+    fragment_variables: HashMap<&'a str, HashSet<&'a str>>,
 }
 
 impl<'a> NoUndefinedVariables<'a> {
@@ -30,9 +31,17 @@ impl<'a> NoUndefinedVariables<'a> {
 
         visited.insert(scope.clone());
 
+        // The variables of a fragment are set by the arguments of its spreads.
+        let fragment_vars = match scope {
+            Scope::Fragment(name) => self.fragment_variables.get(name),
+            Scope::Operation(_) => None,
+        };
+
         if let Some(used_vars) = self.used_variables.get(scope) {
             for (var, pos) in used_vars {
-                if !defined.contains(var) {
+                if !defined.contains(var)
+                    && fragment_vars.filter(|vars| vars.contains(var)).is_none()
+                {
                     unused.push((*var, *pos));
                 }
             }
@@ -100,10 +109,19 @@ impl<'a> Visitor<'a> for NoUndefinedVariables<'a> {
         _ctx: &mut VisitorContext<'a>,
         variable_definition: &'a Positioned<VariableDefinition>,
     ) {
-        if let Some(Scope::Operation(ref name)) = self.current_scope {
-            if let Some(&mut (_, ref mut vars)) = self.defined_variables.get_mut(name) {
-                vars.insert(&variable_definition.node.name.node);
+        match self.current_scope {
+            Some(Scope::Operation(ref name)) => {
+                if let Some(&mut (_, ref mut vars)) = self.defined_variables.get_mut(name) {
+                    vars.insert(&variable_definition.node.name.node);
+                }
+            }
+            Some(Scope::Fragment(name)) => {
+                self.fragment_variables
+                    .entry(name)
+                    .or_default()
+                    .insert(&variable_definition.node.name.node);
             }
+            None => {}
         }
     }
 
@@ -135,6 +153,15 @@ impl<'a> Visitor<'a> for NoUndefinedVariables<'a> {
                 .entry(scope.clone())
                 .or_insert_with(Vec::new)
                 .push(&fragment_spread.node.fragment_name.node);
+
+            let used_vars = self.used_variables.entry(scope.clone()).or_default();
+            for (name, value) in &fragment_spread.node.arguments {
+                used_vars.extend(
+                    referenced_variables(&value.node)
+                        .into_iter()
+                        .map(|n| (n, name.pos)),
+                );
+            }
         }
     }
 }
@@ -14,6 +14,7 @@ pub struct NoUnusedVariables<'a> {
     used_variables: HashMap<Scope<'a>, Vec<&'a str>>,
     current_scope: Option<Scope<'a>>,
     spreads: HashMap<Scope<'a>, Vec<&'a str>>,
+    fragment_variables: HashMap<&'a str, HashSet<(&'a str, Pos)>>,
 }
 
 impl<'a> NoUnusedVariables<'a> {
@@ -30,9 +31,19 @@ impl<'a> NoUnusedVariables<'a> {
 
         visited.insert(from.clone());
 
+        // The variables of a fragment shadow the variables of the operation.
+        let fragment_vars = match from {
+            Scope::Fragment(name) => self.fragment_variables.get(name),
+            Scope::Operation(_) => None,
+        };
+
         if let Some(used_vars) = self.used_variables.get(from) {
             for var in used_vars {
-                if defined.contains(var) {
+                if defined.contains(var)
+                    && fragment_vars
+                        .filter(|vars| vars.iter().any(|(name, _)| name == var))
+                        .is_none()
+                {
                     used.insert(var);
                 }
             }
@@ -72,6 +83,22 @@ impl<'a> Visitor<'a> for NoUnusedVariables<'a> {
                 }
             }
         }
+
+        for (fragment_name, def_vars) in &self.fragment_variables {
+            let used = self.used_variables.get(&Scope::Fragment(fragment_name));
+            for (var, pos) in def_vars
+                .iter()
+                .filter(|(var, _)| used.filter(|used| used.contains(var)).is_none())
+            {
+                ctx.report_error(
+                    vec![*pos],
+                    format!(
+                        r#"Variable "${}" is not used by fragment "{}""#,
+                        var, fragment_name
+                    ),
+                );
+            }
+        }
     }
 
     fn enter_operation_definition(
@@ -99,10 +126,20 @@ impl<'a> Visitor<'a> for NoUnusedVariables<'a> {
         _ctx: &mut VisitorContext<'a>,
         variable_definition: &'a Positioned<VariableDefinition>,
     ) {
-        if let Some(Scope::Operation(ref name)) = self.current_scope {
-            if let Some(vars) = self.defined_variables.get_mut(name) {
-                vars.insert((&variable_definition.node.name.node, variable_definition.pos));
+        let var = (
+            &*variable_definition.node.name.node,
+            variable_definition.pos,
+        );
+        match self.current_scope {
+            Some(Scope::Operation(ref name)) => {
+                if let Some(vars) = self.defined_variables.get_mut(name) {
+                    vars.insert(var);
+                }
+            }
+            Some(Scope::Fragment(name)) => {
+                self.fragment_variables.entry(name).or_default().insert(var);
             }
+            None => {}
         }
     }
 
@@ -130,6 +167,11 @@ impl<'a> Visitor<'a> for NoUnusedVariables<'a> {
                 .entry(scope.clone())
                 .or_insert_with(Vec::new)
                 .push(&fragment_spread.node.fragment_name.node);
+
+            let used_vars = self.used_variables.entry(scope.clone()).or_default();
+            for (_, value) in &fragment_spread.node.arguments {
+                used_vars.append(&mut referenced_variables(&value.node));
+            }
         }
     }
 }
@@ -0,0 +1,73 @@
+use crate::parser::types::Field;
+use crate::validation::visitor::{Visitor, VisitorContext};
+use crate::Positioned;
+
+/// Rejects the nullability designators of fields if the `client_controlled_nullability` feature
+/// is disabled.
+#[derive(Default)]
+pub struct NullabilityDesignators;
+
+impl<'a> Visitor<'a> for NullabilityDesignators {
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
+        if cfg!(feature = "client_controlled_nullability") {
+            return;
+        }
+
+        if let Some(nullability) = field.node.nullability {
+            ctx.report_error(
+                vec![field.pos],
+                format!(
+                    "Field \"{}\" cannot have the nullability designator \"{}\", client controlled nullability is not enabled",
+                    field.node.name.node, nullability
+                ),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub fn factory() -> NullabilityDesignators {
+        NullabilityDesignators
+    }
+
+    #[test]
+    fn no_nullability_designators() {
+        expect_passes_rule!(
+            factory,
+            r#"
+          {
+            dog { name }
+          }
+        "#,
+        );
+    }
+
+    #[cfg(not(feature = "client_controlled_nullability"))]
+    #[test]
+    fn nullability_designators_disabled() {
+        expect_fails_rule!(
+            factory,
+            r#"
+          {
+            dog! { name? }
+          }
+        "#,
+        );
+    }
+
+    #[cfg(feature = "client_controlled_nullability")]
+    #[test]
+    fn nullability_designators_enabled() {
+        expect_passes_rule!(
+            factory,
+            r#"
+          {
+            dog! { name? }
+          }
+        "#,
+        );
+    }
+}
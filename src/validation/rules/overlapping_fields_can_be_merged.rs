@@ -62,6 +62,12 @@ impl<'a, 'ctx> FindConflicts<'a, 'ctx> {
                             name, prev_field.node.name.node, field.node.name.node));
             }
 
+            if prev_field.node.nullability != field.node.nullability {
+                self.ctx.report_error(
+                    vec![prev_field.pos, field.pos],
+                    format!("Fields \"{}\" conflict because they have differing nullability designators. Use different aliases on the fields to fetch both if this was intentional.", name));
+            }
+
             // check arguments
             if prev_field.node.arguments.len() != field.node.arguments.len() {
                 self.ctx.report_error(
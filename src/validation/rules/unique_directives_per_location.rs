@@ -0,0 +1,163 @@
+use std::collections::HashSet;
+
+use crate::parser::types::{
+    Directive, Field, FragmentDefinition, FragmentSpread, InlineFragment, OperationDefinition,
+};
+use crate::validation::visitor::{Visitor, VisitorContext};
+use crate::{Name, Positioned};
+
+pub struct UniqueDirectivesPerLocation;
+
+impl UniqueDirectivesPerLocation {
+    fn check<'a>(&self, ctx: &mut VisitorContext<'a>, directives: &'a [Positioned<Directive>]) {
+        let mut names = HashSet::new();
+        for directive in directives {
+            let name = directive.node.name.node.as_str();
+            let is_repeatable = ctx
+                .registry
+                .directives
+                .get(name)
+                .map(|directive| directive.is_repeatable)
+                .unwrap_or_default();
+            if !is_repeatable && !names.insert(name) {
+                ctx.report_error(
+                    vec![directive.pos],
+                    format!(
+                        "The directive \"{}\" can only be used once at this location",
+                        name
+                    ),
+                );
+            }
+        }
+    }
+}
+
+impl<'a> Visitor<'a> for UniqueDirectivesPerLocation {
+    fn enter_operation_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        _name: Option<&'a Name>,
+        operation_definition: &'a Positioned<OperationDefinition>,
+    ) {
+        self.check(ctx, &operation_definition.node.directives);
+    }
+
+    fn enter_fragment_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        _name: &'a Name,
+        fragment_definition: &'a Positioned<FragmentDefinition>,
+    ) {
+        self.check(ctx, &fragment_definition.node.directives);
+    }
+
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
+        self.check(ctx, &field.node.directives);
+    }
+
+    fn enter_fragment_spread(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        fragment_spread: &'a Positioned<FragmentSpread>,
+    ) {
+        self.check(ctx, &fragment_spread.node.directives);
+    }
+
+    fn enter_inline_fragment(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        inline_fragment: &'a Positioned<InlineFragment>,
+    ) {
+        self.check(ctx, &inline_fragment.node.directives);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub fn factory() -> UniqueDirectivesPerLocation {
+        UniqueDirectivesPerLocation
+    }
+
+    #[test]
+    fn no_directives() {
+        expect_passes_rule!(
+            factory,
+            r#"
+          {
+            dog { name }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn unique_directives_in_different_locations() {
+        expect_passes_rule!(
+            factory,
+            r#"
+          query Q @skip(if: false) {
+            dog @skip(if: false) {
+              ...F @skip(if: false)
+              ... @skip(if: false) { name @skip(if: false) }
+            }
+          }
+          fragment F on Dog @skip(if: false) {
+            name
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn distinct_directives_in_one_location() {
+        expect_passes_rule!(
+            factory,
+            r#"
+          {
+            dog @skip(if: false) @include(if: true) { name }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn duplicate_directives_on_field() {
+        expect_fails_rule!(
+            factory,
+            r#"
+          {
+            dog @skip(if: false) @skip(if: true) { name }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn duplicate_directives_on_fragment_spread() {
+        expect_fails_rule!(
+            factory,
+            r#"
+          {
+            dog { ...F @include(if: true) @include(if: true) }
+          }
+          fragment F on Dog {
+            name
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn duplicate_directives_on_inline_fragment() {
+        expect_fails_rule!(
+            factory,
+            r#"
+          {
+            dog { ... @skip(if: false) @skip(if: false) { name } }
+          }
+        "#,
+        );
+    }
+}
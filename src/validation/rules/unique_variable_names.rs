@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use crate::parser::types::{OperationDefinition, VariableDefinition};
+use crate::parser::types::{FragmentDefinition, OperationDefinition, VariableDefinition};
 use crate::validation::visitor::{Visitor, VisitorContext};
 use crate::{Name, Positioned};
 
@@ -19,6 +19,15 @@ impl<'a> Visitor<'a> for UniqueVariableNames<'a> {
         self.names.clear();
     }
 
+    fn enter_fragment_definition(
+        &mut self,
+        _ctx: &mut VisitorContext<'a>,
+        _name: &'a Name,
+        _fragment_definition: &'a Positioned<FragmentDefinition>,
+    ) {
+        self.names.clear();
+    }
+
     fn enter_variable_definition(
         &mut self,
         ctx: &mut VisitorContext<'a>,
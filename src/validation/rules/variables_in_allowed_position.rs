@@ -1,25 +1,39 @@
 use std::collections::{HashMap, HashSet};
 
+use indexmap::map::IndexMap;
+
 use crate::parser::types::{
-    ExecutableDocument, FragmentDefinition, FragmentSpread, OperationDefinition, VariableDefinition,
+    Directive, ExecutableDocument, Field, FragmentDefinition, FragmentSpread, OperationDefinition,
+    VariableDefinition,
 };
-use crate::registry::MetaTypeName;
+use crate::registry::{MetaInputValue, MetaType, MetaTypeName};
 use crate::validation::utils::Scope;
 use crate::validation::visitor::{Visitor, VisitorContext};
 use crate::{Name, Pos, Positioned};
-use async_graphql_value::Value;
+use async_graphql_value::{ConstValue, Value};
+
+/// A variable used in a position expecting the specified type, and whether the argument or
+/// input object field at this position has a default value.
+type VariableUsage<'a> = (&'a str, Pos, MetaTypeName<'a>, bool);
+
+/// A variable used as the argument of a fragment spread, and the fragment variable it sets.
+type FragmentArgumentUsage<'a> = (&'a str, Pos, &'a Positioned<VariableDefinition>);
 
 #[derive(Default)]
 pub struct VariableInAllowedPosition<'a> {
     spreads: HashMap<Scope<'a>, HashSet<&'a str>>,
-    variable_usages: HashMap<Scope<'a>, Vec<(&'a str, Pos, MetaTypeName<'a>)>>,
+    variable_usages: HashMap<Scope<'a>, Vec<VariableUsage<'a>>>,
+    fragment_argument_usages: HashMap<Scope<'a>, Vec<FragmentArgumentUsage<'a>>>,
     variable_defs: HashMap<Scope<'a>, Vec<&'a Positioned<VariableDefinition>>>,
     current_scope: Option<Scope<'a>>,
+    current_args: Option<&'a IndexMap<&'static str, MetaInputValue>>,
+    values_with_default: Vec<&'a Value>,
 }
 
 impl<'a> VariableInAllowedPosition<'a> {
     fn collect_incorrect_usages(
         &self,
+        root: &Scope<'a>,
         from: &Scope<'a>,
         var_defs: &[&'a Positioned<VariableDefinition>],
         ctx: &mut VisitorContext<'a>,
@@ -31,42 +45,103 @@ impl<'a> VariableInAllowedPosition<'a> {
 
         visited.insert(from.clone());
 
+        // The variables of a fragment are set by the arguments of its spreads, so they are only
+        // checked against the definitions of the fragment itself.
+        let local_defs = if from != root {
+            self.variable_defs.get(from)
+        } else {
+            None
+        };
+        let find_def = |var_name: &str| {
+            if local_defs
+                .filter(|defs| defs.iter().any(|def| def.node.name.node == var_name))
+                .is_some()
+            {
+                return None;
+            }
+            var_defs
+                .iter()
+                .find(|def| def.node.name.node == var_name)
+                .copied()
+        };
+
         if let Some(usages) = self.variable_usages.get(from) {
-            for (var_name, usage_pos, var_type) in usages {
-                if let Some(def) = var_defs.iter().find(|def| def.node.name.node == *var_name) {
-                    let expected_type =
-                        if def.node.var_type.node.nullable && def.node.default_value.is_some() {
-                            // A nullable type with a default value functions as a non-nullable
-                            format!("{}!", def.node.var_type.node)
-                        } else {
-                            def.node.var_type.node.to_string()
-                        };
-
-                    if !var_type.is_subtype(&MetaTypeName::create(&expected_type)) {
-                        ctx.report_error(
-                            vec![def.pos, *usage_pos],
-                            format!(
-                                "Variable \"{}\" of type \"{}\" used in position expecting type \"{}\"",
-                                var_name, var_type, expected_type
-                            ),
-                        );
-                    }
+            for (var_name, usage_pos, location_type, has_location_default) in usages {
+                if let Some(def) = find_def(var_name) {
+                    check_usage(ctx, def, *usage_pos, *location_type, *has_location_default);
+                }
+            }
+        }
+
+        if let Some(usages) = self.fragment_argument_usages.get(from) {
+            for (var_name, usage_pos, fragment_def) in usages {
+                if let Some(def) = find_def(var_name) {
+                    let location_type = fragment_def.node.var_type.node.to_string();
+                    check_usage(
+                        ctx,
+                        def,
+                        *usage_pos,
+                        MetaTypeName::create(&location_type),
+                        fragment_def.node.default_value.is_some(),
+                    );
                 }
             }
         }
 
         if let Some(spreads) = self.spreads.get(from) {
             for spread in spreads {
-                self.collect_incorrect_usages(&Scope::Fragment(spread), var_defs, ctx, visited);
+                self.collect_incorrect_usages(
+                    root,
+                    &Scope::Fragment(spread),
+                    var_defs,
+                    ctx,
+                    visited,
+                );
             }
         }
     }
 }
 
+fn check_usage<'a>(
+    ctx: &mut VisitorContext<'a>,
+    def: &'a Positioned<VariableDefinition>,
+    usage_pos: Pos,
+    location_type: MetaTypeName<'_>,
+    has_location_default: bool,
+) {
+    let var_type = def.node.var_type.node.to_string();
+
+    // A nullable variable can be used in a non-null position if either the variable or the
+    // position has a default value, since a default value that is not null replaces the missing
+    // value.
+    let has_non_null_default = matches!(
+        &def.node.default_value,
+        Some(value) if value.node != ConstValue::Null
+    );
+    let location_type = if location_type.is_non_null()
+        && def.node.var_type.node.nullable
+        && (has_non_null_default || has_location_default)
+    {
+        location_type.unwrap_non_null()
+    } else {
+        location_type
+    };
+
+    if !location_type.is_subtype(&MetaTypeName::create(&var_type)) {
+        ctx.report_error(
+            vec![def.pos, usage_pos],
+            format!(
+                "Variable \"{}\" of type \"{}\" used in position expecting type \"{}\"",
+                def.node.name.node, var_type, location_type
+            ),
+        );
+    }
+}
+
 impl<'a> Visitor<'a> for VariableInAllowedPosition<'a> {
     fn exit_document(&mut self, ctx: &mut VisitorContext<'a>, _doc: &'a ExecutableDocument) {
         for (op_scope, var_defs) in &self.variable_defs {
-            self.collect_incorrect_usages(op_scope, var_defs, ctx, &mut HashSet::new());
+            self.collect_incorrect_usages(op_scope, op_scope, var_defs, ctx, &mut HashSet::new());
         }
     }
 
@@ -103,7 +178,7 @@ impl<'a> Visitor<'a> for VariableInAllowedPosition<'a> {
 
     fn enter_fragment_spread(
         &mut self,
-        _ctx: &mut VisitorContext<'a>,
+        ctx: &mut VisitorContext<'a>,
         fragment_spread: &'a Positioned<FragmentSpread>,
     ) {
         if let Some(ref scope) = self.current_scope {
@@ -111,25 +186,115 @@ impl<'a> Visitor<'a> for VariableInAllowedPosition<'a> {
                 .entry(scope.clone())
                 .or_insert_with(HashSet::new)
                 .insert(&fragment_spread.node.fragment_name.node);
+
+            if let Some(fragment) = ctx.fragment(&fragment_spread.node.fragment_name.node) {
+                for (name, value) in &fragment_spread.node.arguments {
+                    if let (Value::Variable(var_name), Some(def)) = (
+                        &value.node,
+                        fragment
+                            .node
+                            .variable_definitions
+                            .iter()
+                            .find(|def| def.node.name.node == name.node),
+                    ) {
+                        self.fragment_argument_usages
+                            .entry(scope.clone())
+                            .or_default()
+                            .push((var_name, name.pos, def));
+                    }
+                }
+            }
         }
     }
 
-    fn enter_input_value(
+    fn enter_directive(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        directive: &'a Positioned<Directive>,
+    ) {
+        self.current_args = ctx
+            .registry
+            .directives
+            .get(directive.node.name.node.as_str())
+            .map(|d| &d.args);
+    }
+
+    fn exit_directive(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
+        _directive: &'a Positioned<Directive>,
+    ) {
+        self.current_args = None;
+    }
+
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
+        self.current_args = ctx
+            .parent_type()
+            .and_then(|p| p.field_by_name(&field.node.name.node))
+            .map(|f| &f.args);
+    }
+
+    fn exit_field(&mut self, _ctx: &mut VisitorContext<'a>, _field: &'a Positioned<Field>) {
+        self.current_args = None;
+    }
+
+    fn enter_argument(
+        &mut self,
+        _ctx: &mut VisitorContext<'a>,
+        name: &'a Positioned<Name>,
+        value: &'a Positioned<Value>,
+    ) {
+        let has_default = matches!(
+            self.current_args.and_then(|args| args.get(name.node.as_str())),
+            Some(arg) if arg.default_value.is_some()
+        );
+        if has_default {
+            self.values_with_default.push(&value.node);
+        }
+    }
+
+    fn enter_input_value(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
         pos: Pos,
         expected_type: &Option<MetaTypeName<'a>>,
         value: &'a Value,
     ) {
-        if let Value::Variable(name) = value {
-            if let Some(expected_type) = expected_type {
-                if let Some(scope) = &self.current_scope {
-                    self.variable_usages
-                        .entry(scope.clone())
-                        .or_insert_with(Vec::new)
-                        .push((name, pos, *expected_type));
+        match value {
+            Value::Variable(name) => {
+                if let Some(expected_type) = expected_type {
+                    if let Some(scope) = &self.current_scope {
+                        let has_location_default = self
+                            .values_with_default
+                            .iter()
+                            .any(|default_value| std::ptr::eq(*default_value, value));
+                        self.variable_usages
+                            .entry(scope.clone())
+                            .or_insert_with(Vec::new)
+                            .push((name, pos, *expected_type, has_location_default));
+                    }
                 }
             }
+            Value::Object(fields) => {
+                if let Some(MetaTypeName::Named(type_name)) =
+                    expected_type.map(|ty| ty.unwrap_non_null())
+                {
+                    if let Some(MetaType::InputObject { input_fields, .. }) =
+                        ctx.registry.types.get(type_name)
+                    {
+                        for (field_name, field_value) in fields {
+                            let has_default = matches!(
+                                input_fields.get(field_name.as_str()),
+                                Some(field) if field.default_value.is_some()
+                            );
+                            if has_default {
+                                self.values_with_default.push(field_value);
+                            }
+                        }
+                    }
+                }
+            }
+            _ => {}
         }
     }
 }
@@ -456,4 +621,80 @@ mod tests {
         "#,
         );
     }
+
+    #[test]
+    fn int_into_non_null_int_with_null_default() {
+        expect_fails_rule!(
+            factory,
+            r#"
+          query Query($intArg: Int = null)
+          {
+            complicatedArgs {
+              nonNullIntArgField(nonNullIntArg: $intArg)
+            }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn int_into_non_null_int_argument_with_default() {
+        expect_passes_rule!(
+            factory,
+            r#"
+          query Query($intArg: Int)
+          {
+            complicatedArgs {
+              multipleOpts(opt1: $intArg)
+            }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn int_into_non_null_int_input_field_with_default() {
+        expect_passes_rule!(
+            factory,
+            r#"
+          query Query($intArg: Int)
+          {
+            complicatedArgs {
+              complexArgField(complexArg: { requiredField: true, nonNullIntFieldWithDefault: $intArg })
+            }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn int_into_non_null_int_with_and_without_argument_default() {
+        expect_fails_rule!(
+            factory,
+            r#"
+          query Query($intArg: Int)
+          {
+            complicatedArgs {
+              multipleOpts(opt1: $intArg)
+              nonNullIntArgField(nonNullIntArg: $intArg)
+            }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn boolean_into_non_null_boolean_input_field_without_default() {
+        expect_fails_rule!(
+            factory,
+            r#"
+          query Query($boolArg: Boolean)
+          {
+            complicatedArgs {
+              complexArgField(complexArg: { requiredField: $boolArg })
+            }
+          }
+        "#,
+        );
+    }
 }
@@ -219,6 +219,8 @@ struct ComplexInput {
     string_field: Option<String>,
     boolean_field: Option<bool>,
     string_list_field: Option<Vec<Option<String>>>,
+    #[graphql(default = 0)]
+    non_null_int_field_with_default: i32,
 }
 
 struct ComplicatedArgs;
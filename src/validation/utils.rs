@@ -14,6 +14,17 @@ fn valid_error(path_node: &QueryPathNode, msg: String) -> String {
     format!("\"{}\", {}", path_node, msg)
 }
 
+/// Whether an enum has a value, or an alias of a value, named `name`.
+fn contains_enum_value(
+    enum_values: &indexmap::IndexMap<&'static str, registry::MetaEnumValue>,
+    name: &str,
+) -> bool {
+    enum_values.contains_key(name)
+        || enum_values
+            .values()
+            .any(|value| value.aliases.contains(&name))
+}
+
 pub fn referenced_variables(value: &Value) -> Vec<&str> {
     let mut vars = Vec::new();
     referenced_variables_to_vec(value, &mut vars);
@@ -83,10 +94,11 @@ pub fn is_valid_input_value(
                 registry::MetaType::Enum {
                     enum_values,
                     name: enum_name,
+                    has_fallback,
                     ..
                 } => match value {
                     ConstValue::Enum(name) => {
-                        if !enum_values.contains_key(name.as_str()) {
+                        if !has_fallback && !contains_enum_value(enum_values, name) {
                             Some(valid_error(
                                 &path_node,
                                 format!(
@@ -99,7 +111,7 @@ pub fn is_valid_input_value(
                         }
                     }
                     ConstValue::String(name) => {
-                        if !enum_values.contains_key(name.as_str()) {
+                        if !has_fallback && !contains_enum_value(enum_values, name) {
                             Some(valid_error(
                                 &path_node,
                                 format!(
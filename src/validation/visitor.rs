@@ -455,6 +455,28 @@ where
         self.0.exit_inline_fragment(ctx, inline_fragment);
         self.1.exit_inline_fragment(ctx, inline_fragment);
     }
+
+    fn enter_input_value(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        pos: Pos,
+        expected_type: &Option<MetaTypeName<'a>>,
+        value: &'a Value,
+    ) {
+        self.0.enter_input_value(ctx, pos, expected_type, value);
+        self.1.enter_input_value(ctx, pos, expected_type, value);
+    }
+
+    fn exit_input_value(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        pos: Pos,
+        expected_type: &Option<MetaTypeName<'a>>,
+        value: &Value,
+    ) {
+        self.0.exit_input_value(ctx, pos, expected_type, value);
+        self.1.exit_input_value(ctx, pos, expected_type, value);
+    }
 }
 
 pub(crate) fn visit<'a, V: Visitor<'a>>(
@@ -652,6 +674,7 @@ fn visit_variable_definitions<'a, V: Visitor<'a>>(
 ) {
     for d in variable_definitions {
         v.enter_variable_definition(ctx, d);
+        visit_directives(v, ctx, &d.node.directives);
         v.exit_variable_definition(ctx, d);
     }
 }
@@ -688,6 +711,7 @@ fn visit_fragment_definition<'a, V: Visitor<'a>>(
     fragment: &'a Positioned<FragmentDefinition>,
 ) {
     v.enter_fragment_definition(ctx, name, fragment);
+    visit_variable_definitions(v, ctx, &fragment.node.variable_definitions);
     visit_directives(v, ctx, &fragment.node.directives);
     visit_selection_set(v, ctx, &fragment.node.selection_set);
     v.exit_fragment_definition(ctx, name, fragment);
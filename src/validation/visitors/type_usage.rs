@@ -0,0 +1,27 @@
+use std::collections::BTreeSet;
+
+use crate::parser::types::Field;
+use crate::registry::MetaTypeName;
+use crate::validation::visitor::{Visitor, VisitorContext};
+use crate::Positioned;
+
+pub struct TypeUsageCollect<'a> {
+    pub fields: &'a mut BTreeSet<String>,
+    pub types: &'a mut BTreeSet<String>,
+}
+
+impl<'ctx, 'a> Visitor<'ctx> for TypeUsageCollect<'a> {
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'ctx>, field: &Positioned<Field>) {
+        let parent_type = match ctx.parent_type() {
+            Some(parent_type) => parent_type,
+            None => return,
+        };
+        if let Some(meta_field) = parent_type.field_by_name(&field.node.name.node) {
+            self.fields
+                .insert(format!("{}.{}", parent_type.name(), meta_field.name));
+            self.types.insert(parent_type.name().to_string());
+            self.types
+                .insert(MetaTypeName::concrete_typename(&meta_field.ty).to_string());
+        }
+    }
+}
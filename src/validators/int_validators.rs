@@ -1,4 +1,4 @@
-use crate::validators::InputValueValidator;
+use crate::validators::{InputValueValidator, ValidatorDescription};
 use crate::Value;
 
 /// Integer range validator
@@ -24,6 +24,16 @@ impl InputValueValidator for IntRange {
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<ValidatorDescription> {
+        Some(ValidatorDescription::new(
+            "IntRange",
+            vec![
+                ("min", Value::from(self.min)),
+                ("max", Value::from(self.max)),
+            ],
+        ))
+    }
 }
 
 /// Integer less then validator
@@ -46,6 +56,13 @@ impl InputValueValidator for IntLessThan {
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<ValidatorDescription> {
+        Some(ValidatorDescription::new(
+            "IntLessThan",
+            vec![("value", Value::from(self.value))],
+        ))
+    }
 }
 
 /// Integer greater then validator
@@ -68,6 +85,13 @@ impl InputValueValidator for IntGreaterThan {
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<ValidatorDescription> {
+        Some(ValidatorDescription::new(
+            "IntGreaterThan",
+            vec![("value", Value::from(self.value))],
+        ))
+    }
 }
 
 /// Integer nonzero validator
@@ -84,6 +108,10 @@ impl InputValueValidator for IntNonZero {
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<ValidatorDescription> {
+        Some(ValidatorDescription::new("IntNonZero", Vec::new()))
+    }
 }
 
 /// Integer equal validator
@@ -106,4 +134,11 @@ impl InputValueValidator for IntEqual {
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<ValidatorDescription> {
+        Some(ValidatorDescription::new(
+            "IntEqual",
+            vec![("value", Value::from(self.value))],
+        ))
+    }
 }
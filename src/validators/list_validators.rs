@@ -1,4 +1,4 @@
-use crate::validators::InputValueValidator;
+use crate::validators::{InputValueValidator, ValidatorDescription};
 use crate::Value;
 
 /// List minimum length validator
@@ -23,6 +23,13 @@ impl InputValueValidator for ListMinLength {
             Ok(())
         }
     }
+
+    fn describe(&self) -> Option<ValidatorDescription> {
+        Some(ValidatorDescription::new(
+            "ListMinLength",
+            vec![("length", Value::from(self.length))],
+        ))
+    }
 }
 
 /// List maximum length validator
@@ -47,4 +54,11 @@ impl InputValueValidator for ListMaxLength {
             Ok(())
         }
     }
+
+    fn describe(&self) -> Option<ValidatorDescription> {
+        Some(ValidatorDescription::new(
+            "ListMaxLength",
+            vec![("length", Value::from(self.length))],
+        ))
+    }
 }
@@ -4,11 +4,16 @@ mod int_validators;
 mod list_validators;
 mod string_validators;
 
-use crate::Value;
+use std::collections::BTreeMap;
+
+use crate::{Context, InputValueType, Value};
 
 pub use int_validators::{IntEqual, IntGreaterThan, IntLessThan, IntNonZero, IntRange};
 pub use list_validators::{ListMaxLength, ListMinLength};
-pub use string_validators::{Email, StringMaxLength, StringMinLength, MAC};
+pub use string_validators::{
+    CharsMaxLength, CharsMinLength, Email, Ip, Regex, RegexPattern, StringMaxLength,
+    StringMinLength, Url, MAC,
+};
 
 /// Input value validator
 ///
@@ -18,7 +23,7 @@ pub use string_validators::{Email, StringMaxLength, StringMinLength, MAC};
 ///
 /// ```no_run
 /// use async_graphql::*;
-/// use async_graphql::validators::{Email, MAC, IntRange};
+/// use async_graphql::validators::{CharsMaxLength, Email, IntRange, Ip, Regex, Url, MAC};
 ///
 /// struct QueryRoot;
 ///
@@ -38,6 +43,16 @@ pub use string_validators::{Email, StringMaxLength, StringMinLength, MAC};
 ///     async fn value3(&self, #[graphql(validator(IntRange(min = "100", max = "200")))] value: i32) -> i32 {
 ///         unimplemented!()
 ///     }
+///
+///     // Input is a URL or an IP address of at most 64 characters
+///     async fn value4(&self, #[graphql(validator(and(or(Url, Ip), CharsMaxLength(length = "64"))))] host: String) -> i32 {
+///         unimplemented!()
+///     }
+///
+///     // Input is a lowercase identifier
+///     async fn value5(&self, #[graphql(validator(Regex(pattern = r#""[a-z_]+""#)))] ident: String) -> i32 {
+///         unimplemented!()
+///     }
 /// }
 /// ```
 pub trait InputValueValidator {
@@ -45,6 +60,110 @@ pub trait InputValueValidator {
     ///
     /// If the input type is different from the required type, return `Ok(())` directly, and other validators will find this error.
     fn is_valid(&self, value: &Value) -> Result<(), String>;
+
+    /// Describe the validator, so that clients can check values before sending them. Returns
+    /// `None` by default, which means the validator can't be introspected.
+    fn describe(&self) -> Option<ValidatorDescription> {
+        None
+    }
+}
+
+/// The description of a validator, returned by
+/// [`InputValueValidator::describe`](trait.InputValueValidator.html#method.describe).
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ValidatorDescription {
+    /// A single validator, with the name of its type and its parameters.
+    Validator {
+        /// The validator name, e.g. `IntRange`.
+        name: &'static str,
+        /// The parameters, e.g. `min` and `max`.
+        params: BTreeMap<&'static str, Value>,
+    },
+    /// All of the validators must succeed.
+    And(Vec<ValidatorDescription>),
+    /// At least one of the validators must succeed.
+    Or(Vec<ValidatorDescription>),
+}
+
+impl ValidatorDescription {
+    pub(crate) fn new(name: &'static str, params: Vec<(&'static str, Value)>) -> Self {
+        ValidatorDescription::Validator {
+            name,
+            params: params.into_iter().collect(),
+        }
+    }
+
+    fn into_list(self, and: bool) -> Vec<ValidatorDescription> {
+        match self {
+            ValidatorDescription::And(list) if and => list,
+            ValidatorDescription::Or(list) if !and => list,
+            description => vec![description],
+        }
+    }
+}
+
+/// Input value validator with access to the context
+///
+/// Unlike [`InputValueValidator`](trait.InputValueValidator.html), which checks the query before it
+/// is executed, a custom validator checks the parsed argument right before the resolver is called.
+/// It can be asynchronous and use the context, e.g. to check that a value exists in a database.
+///
+/// Use it on an argument with `#[graphql(custom_validator = "expr")]`, where `expr` evaluates to
+/// the validator.
+///
+/// This trait is defined through the [`async-trait`](https://crates.io/crates/async-trait) macro.
+///
+/// # Examples
+///
+/// ```
+/// use async_graphql::*;
+/// use async_graphql::validators::CustomValidator;
+///
+/// struct UsernameAvailable;
+///
+/// #[async_trait::async_trait(?Send)]
+/// impl CustomValidator<String> for UsernameAvailable {
+///     async fn check(&self, ctx: &Context<'_>, value: &String) -> Result<(), String> {
+///         let taken = ctx.data_unchecked::<Vec<String>>();
+///         if taken.contains(value) {
+///             Err(format!("the username \"{}\" is already taken", value))
+///         } else {
+///             Ok(())
+///         }
+///     }
+/// }
+///
+/// struct MutationRoot;
+///
+/// #[Object]
+/// impl MutationRoot {
+///     async fn sign_up(
+///         &self,
+///         #[graphql(custom_validator = "UsernameAvailable")] username: String,
+///     ) -> String {
+///         username
+///     }
+/// }
+///
+/// # struct QueryRoot;
+/// # #[Object]
+/// # impl QueryRoot { async fn value(&self) -> i32 { 0 } }
+/// async_std::task::block_on(async move {
+///     let schema = Schema::build(QueryRoot, MutationRoot, EmptySubscription)
+///         .data(vec!["admin".to_string()])
+///         .finish();
+///     assert!(schema.execute(r#"mutation { signUp(username: "alice") }"#).await.is_ok());
+///     assert_eq!(
+///         schema.execute(r#"mutation { signUp(username: "admin") }"#).await.errors[0].message,
+///         r#"Invalid value for argument "username", the username "admin" is already taken"#,
+///     );
+/// });
+/// ```
+#[async_trait::async_trait(?Send)]
+pub trait CustomValidator<T: InputValueType> {
+    /// Check the parsed value, returns the reason for the error if it fails.
+    async fn check(&self, ctx: &Context<'_>, value: &T) -> Result<(), String>;
 }
 
 /// An extension trait for `InputValueValidator`
@@ -79,6 +198,18 @@ where
         self.0.is_valid(value)?;
         self.1.is_valid(value)
     }
+
+    fn describe(&self) -> Option<ValidatorDescription> {
+        // A validator that can't be described is left out, the others still apply.
+        match (self.0.describe(), self.1.describe()) {
+            (Some(a), Some(b)) => {
+                let mut list = a.into_list(true);
+                list.extend(b.into_list(true));
+                Some(ValidatorDescription::And(list))
+            }
+            (a, b) => a.or(b),
+        }
+    }
 }
 
 /// Invalidator for `InputValueValidator::or`
@@ -96,6 +227,12 @@ where
             Ok(())
         }
     }
+
+    fn describe(&self) -> Option<ValidatorDescription> {
+        let mut list = self.0.describe()?.into_list(false);
+        list.extend(self.1.describe()?.into_list(false));
+        Some(ValidatorDescription::Or(list))
+    }
 }
 
 /// Invalidator for `InputValueValidator::map_err`
@@ -109,4 +246,8 @@ where
     fn is_valid(&self, value: &Value) -> Result<(), String> {
         self.0.is_valid(value).map_err(&self.1)
     }
+
+    fn describe(&self) -> Option<ValidatorDescription> {
+        self.0.describe()
+    }
 }
@@ -1,7 +1,9 @@
+use std::net::IpAddr;
+
 use once_cell::sync::Lazy;
-use regex::Regex;
+use regex::Regex as Re;
 
-use crate::validators::InputValueValidator;
+use crate::validators::{InputValueValidator, ValidatorDescription};
 use crate::Value;
 
 /// String minimum length validator
@@ -26,6 +28,13 @@ impl InputValueValidator for StringMinLength {
             Ok(())
         }
     }
+
+    fn describe(&self) -> Option<ValidatorDescription> {
+        Some(ValidatorDescription::new(
+            "StringMinLength",
+            vec![("length", Value::from(self.length))],
+        ))
+    }
 }
 
 /// String maximum length validator
@@ -50,10 +59,73 @@ impl InputValueValidator for StringMaxLength {
             Ok(())
         }
     }
+
+    fn describe(&self) -> Option<ValidatorDescription> {
+        Some(ValidatorDescription::new(
+            "StringMaxLength",
+            vec![("length", Value::from(self.length))],
+        ))
+    }
 }
 
-static EMAIL_RE: Lazy<Regex> = Lazy::new(|| {
-    Regex::new("^(([0-9A-Za-z!#$%&'*+-/=?^_`{|}~&&[^@]]+)|(\"([0-9A-Za-z!#$%&'*+-/=?^_`{|}~ \"(),:;<>@\\[\\\\\\]]+)\"))@").unwrap()
+/// String minimum length validator, counting unicode characters instead of bytes.
+pub struct CharsMinLength {
+    /// Must be greater than or equal to this value.
+    pub length: i32,
+}
+
+impl InputValueValidator for CharsMinLength {
+    fn is_valid(&self, value: &Value) -> Result<(), String> {
+        if let Value::String(s) = value {
+            let count = s.chars().count();
+            if count < self.length as usize {
+                return Err(format!(
+                    "the value \"{}\" has {} characters, must be greater than or equal to {}",
+                    s, count, self.length
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<ValidatorDescription> {
+        Some(ValidatorDescription::new(
+            "CharsMinLength",
+            vec![("length", Value::from(self.length))],
+        ))
+    }
+}
+
+/// String maximum length validator, counting unicode characters instead of bytes.
+pub struct CharsMaxLength {
+    /// Must be less than or equal to this value.
+    pub length: i32,
+}
+
+impl InputValueValidator for CharsMaxLength {
+    fn is_valid(&self, value: &Value) -> Result<(), String> {
+        if let Value::String(s) = value {
+            let count = s.chars().count();
+            if count > self.length as usize {
+                return Err(format!(
+                    "the value \"{}\" has {} characters, must be less than or equal to {}",
+                    s, count, self.length
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<ValidatorDescription> {
+        Some(ValidatorDescription::new(
+            "CharsMaxLength",
+            vec![("length", Value::from(self.length))],
+        ))
+    }
+}
+
+static EMAIL_RE: Lazy<Re> = Lazy::new(|| {
+    Re::new("^(([0-9A-Za-z!#$%&'*+-/=?^_`{|}~&&[^@]]+)|(\"([0-9A-Za-z!#$%&'*+-/=?^_`{|}~ \"(),:;<>@\\[\\\\\\]]+)\"))@").unwrap()
 });
 
 /// Email validator
@@ -71,12 +143,15 @@ impl InputValueValidator for Email {
             Ok(())
         }
     }
+
+    fn describe(&self) -> Option<ValidatorDescription> {
+        Some(ValidatorDescription::new("Email", Vec::new()))
+    }
 }
 
-static MAC_ADDRESS_RE: Lazy<Regex> =
-    Lazy::new(|| Regex::new("^([0-9a-fA-F]{2}:){5}[0-9a-fA-F]{2}$").unwrap());
-static MAC_ADDRESS_NO_COLON_RE: Lazy<Regex> =
-    Lazy::new(|| Regex::new("^[0-9a-fA-F]{12}$").unwrap());
+static MAC_ADDRESS_RE: Lazy<Re> =
+    Lazy::new(|| Re::new("^([0-9a-fA-F]{2}:){5}[0-9a-fA-F]{2}$").unwrap());
+static MAC_ADDRESS_NO_COLON_RE: Lazy<Re> = Lazy::new(|| Re::new("^[0-9a-fA-F]{12}$").unwrap());
 
 /// MAC address validator
 pub struct MAC {
@@ -102,4 +177,122 @@ impl InputValueValidator for MAC {
             Ok(())
         }
     }
+
+    fn describe(&self) -> Option<ValidatorDescription> {
+        Some(ValidatorDescription::new(
+            "MAC",
+            vec![("colon", Value::from(self.colon))],
+        ))
+    }
+}
+
+static URL_RE: Lazy<Re> = Lazy::new(|| {
+    Re::new(r"^[a-zA-Z][a-zA-Z0-9+.-]*://[^\s/?#@]+(@[^\s/?#]+)?([/?#]\S*)?$").unwrap()
+});
+
+/// URL validator, accepts absolute URLs such as `https://example.com/path`.
+pub struct Url {}
+
+impl InputValueValidator for Url {
+    fn is_valid(&self, value: &Value) -> Result<(), String> {
+        if let Value::String(s) = value {
+            if !URL_RE.is_match(s) {
+                return Err(format!("the value \"{}\" is not a valid URL", s));
+            }
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<ValidatorDescription> {
+        Some(ValidatorDescription::new("Url", Vec::new()))
+    }
+}
+
+/// IP address validator, accepts both IPv4 and IPv6 addresses.
+pub struct Ip {}
+
+impl InputValueValidator for Ip {
+    fn is_valid(&self, value: &Value) -> Result<(), String> {
+        if let Value::String(s) = value {
+            if s.parse::<IpAddr>().is_err() {
+                return Err(format!("the value \"{}\" is not a valid IP address", s));
+            }
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<ValidatorDescription> {
+        Some(ValidatorDescription::new("Ip", Vec::new()))
+    }
+}
+
+/// A pattern of the [`Regex`](struct.Regex.html) validator, which is compiled when it's created.
+pub struct RegexPattern {
+    pattern: String,
+    regex: Re,
+}
+
+impl RegexPattern {
+    /// Compile a pattern, using the syntax of the `regex` crate.
+    pub fn new(pattern: impl Into<String>) -> Result<Self, regex::Error> {
+        let pattern = pattern.into();
+        let regex = Re::new(&format!("^(?:{})$", pattern))?;
+        Ok(Self { pattern, regex })
+    }
+
+    /// The pattern as written.
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+}
+
+impl From<&str> for RegexPattern {
+    /// # Panics
+    ///
+    /// Panics if the pattern is invalid, so that an invalid validator of a derived type is
+    /// reported when the schema is built.
+    fn from(pattern: &str) -> Self {
+        match Self::new(pattern) {
+            Ok(pattern) => pattern,
+            Err(err) => panic!("Invalid pattern \"{}\": {}", pattern, err),
+        }
+    }
+}
+
+impl From<String> for RegexPattern {
+    /// # Panics
+    ///
+    /// Panics if the pattern is invalid.
+    fn from(pattern: String) -> Self {
+        Self::from(pattern.as_str())
+    }
+}
+
+/// Regular expression validator, the whole value must match unless the pattern is anchored
+/// otherwise.
+pub struct Regex {
+    /// The pattern, using the syntax of the `regex` crate.
+    pub pattern: RegexPattern,
+}
+
+impl InputValueValidator for Regex {
+    fn is_valid(&self, value: &Value) -> Result<(), String> {
+        if let Value::String(s) = value {
+            if !self.pattern.regex.is_match(s) {
+                return Err(format!(
+                    "the value \"{}\" does not match the pattern \"{}\"",
+                    s,
+                    self.pattern.as_str()
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<ValidatorDescription> {
+        Some(ValidatorDescription::new(
+            "Regex",
+            vec![("pattern", Value::from(self.pattern.as_str()))],
+        ))
+    }
 }
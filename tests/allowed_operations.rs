@@ -0,0 +1,92 @@
+use async_graphql::parser::types::OperationType;
+use async_graphql::*;
+use futures_util::stream::{Stream, StreamExt};
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn value(&self) -> i32 {
+        10
+    }
+}
+
+struct Mutation;
+
+#[Object]
+impl Mutation {
+    async fn set_value(&self) -> i32 {
+        20
+    }
+}
+
+struct Subscription;
+
+#[Subscription]
+impl Subscription {
+    async fn values(&self) -> impl Stream<Item = i32> {
+        futures_util::stream::iter(vec![1, 2])
+    }
+}
+
+#[async_std::test]
+pub async fn test_allowed_operations() {
+    let schema = Schema::new(Query, Mutation, Subscription);
+
+    assert_eq!(
+        schema
+            .execute(Request::new("{ value }").allowed_operations(vec![OperationType::Query]))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": 10 })
+    );
+
+    assert_eq!(
+        schema
+            .execute(
+                Request::new("query A { value } mutation B { setValue }")
+                    .operation_name("B")
+                    .allowed_operations(vec![OperationType::Query]),
+            )
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: "The mutation operation is not allowed for this request.".to_string(),
+            locations: vec![Pos {
+                line: 1,
+                column: 19
+            }],
+            path: Vec::new(),
+            extensions: None,
+        }]
+    );
+
+    let responses: Vec<_> = schema
+        .execute_stream(
+            Request::new("subscription { values }")
+                .allowed_operations(vec![OperationType::Query, OperationType::Mutation]),
+        )
+        .collect()
+        .await;
+    assert_eq!(responses.len(), 1);
+    assert_eq!(
+        responses[0].errors[0].message,
+        "The subscription operation is not allowed for this request."
+    );
+
+    let responses: Vec<_> = schema
+        .execute_stream(
+            Request::new("subscription { values }")
+                .allowed_operations(vec![OperationType::Subscription]),
+        )
+        .map(|resp| resp.data)
+        .collect()
+        .await;
+    assert_eq!(
+        responses,
+        vec![value!({ "values": 1 }), value!({ "values": 2 })]
+    );
+}
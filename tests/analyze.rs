@@ -0,0 +1,68 @@
+use async_graphql::*;
+
+#[derive(SimpleObject)]
+struct Post {
+    title: String,
+}
+
+#[derive(SimpleObject)]
+struct User {
+    name: String,
+    posts: Vec<Post>,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn user(&self) -> User {
+        panic!("resolvers must not run")
+    }
+
+    async fn value(&self) -> i32 {
+        panic!("resolvers must not run")
+    }
+}
+
+#[test]
+pub fn test_analyze() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_depth(1)
+        .finish();
+
+    assert_eq!(
+        schema
+            .analyze(
+                r#"
+                query A { user { ...UserFields } }
+                query B { value __typename }
+                fragment UserFields on User { name posts { title } }
+                "#
+            )
+            .unwrap(),
+        QueryAnalysis {
+            depth: 1,
+            complexity: 5,
+            cache_control: CacheControl::default(),
+            fields: vec![
+                "Post.title".to_string(),
+                "Query.user".to_string(),
+                "Query.value".to_string(),
+                "User.name".to_string(),
+                "User.posts".to_string(),
+            ],
+            types: vec![
+                "Int".to_string(),
+                "Post".to_string(),
+                "Query".to_string(),
+                "String".to_string(),
+                "User".to_string(),
+            ],
+        }
+    );
+
+    assert_eq!(
+        schema.analyze("{ unknown }").unwrap_err()[0].message,
+        r#"Unknown field "unknown" on type "Query"."#
+    );
+}
@@ -0,0 +1,142 @@
+#[cfg(feature = "apollo_persisted_queries")]
+#[async_std::test]
+pub async fn test_apollo_persisted_queries() {
+    use async_graphql::extensions::apollo_persisted_queries::{
+        ApolloPersistedQueries, LruCacheStorage,
+    };
+    use async_graphql::*;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            100
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(ApolloPersistedQueries::new(LruCacheStorage::new(256)))
+        .finish();
+
+    let mut request = Request::new("{ value }");
+    request.extensions.insert(
+        "persistedQuery".to_string(),
+        value!({
+            "version": 1,
+            "sha256Hash": "854174ebed716fe24fd6659c30290aecd9bc1d17dc4f47939a1848a1b8ed3c6b",
+        }),
+    );
+
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        value!({
+            "value": 100
+        })
+    );
+
+    let mut request = Request::new("");
+    request.extensions.insert(
+        "persistedQuery".to_string(),
+        value!({
+            "version": 1,
+            "sha256Hash": "854174ebed716fe24fd6659c30290aecd9bc1d17dc4f47939a1848a1b8ed3c6b",
+        }),
+    );
+
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        value!({
+            "value": 100
+        })
+    );
+
+    let mut request = Request::new("");
+    request.extensions.insert(
+        "persistedQuery".to_string(),
+        value!({
+            "version": 1,
+            "sha256Hash": "def",
+        }),
+    );
+
+    let errors = schema.execute(request).await.into_result().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].message, "PersistedQueryNotFound");
+    assert_eq!(
+        errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&value!("PERSISTED_QUERY_NOT_FOUND"))
+    );
+
+    let mut request = Request::new("{ value }");
+    request.extensions.insert(
+        "persistedQuery".to_string(),
+        value!({
+            "version": 1,
+            "sha256Hash": "def",
+        }),
+    );
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap_err(),
+        vec![ServerError::new("provided sha does not match query")]
+    );
+}
+
+#[cfg(feature = "redis")]
+#[test]
+pub fn test_redis_cache_storage_unavailable() {
+    use async_graphql::extensions::apollo_persisted_queries::{
+        ApolloPersistedQueries, RedisCacheStorage,
+    };
+    use async_graphql::*;
+    use deadpool_redis::{Config, Runtime};
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            100
+        }
+    }
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    rt.block_on(async {
+        // Nothing listens on this port, the cache always misses.
+        let pool = Config::from_url("redis://127.0.0.1:1/")
+            .create_pool(Some(Runtime::Tokio1))
+            .unwrap();
+        let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+            .extension(ApolloPersistedQueries::new(
+                RedisCacheStorage::new(pool).prefix("test:").ttl(60),
+            ))
+            .finish();
+
+        let persisted_query = value!({
+            "version": 1,
+            "sha256Hash": "854174ebed716fe24fd6659c30290aecd9bc1d17dc4f47939a1848a1b8ed3c6b",
+        });
+
+        let mut request = Request::new("{ value }");
+        request
+            .extensions
+            .insert("persistedQuery".to_string(), persisted_query.clone());
+        assert_eq!(
+            schema.execute(request).await.into_result().unwrap().data,
+            value!({
+                "value": 100
+            })
+        );
+
+        let mut request = Request::new("");
+        request
+            .extensions
+            .insert("persistedQuery".to_string(), persisted_query);
+        let errors = schema.execute(request).await.into_result().unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message, "PersistedQueryNotFound");
+    });
+}
@@ -0,0 +1,161 @@
+use async_graphql::extensions::{AuditLog, AuditRecord};
+use async_graphql::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+struct CurrentUser(&'static str);
+
+#[derive(InputObject)]
+struct Credentials {
+    name: String,
+    #[graphql(secret)]
+    password: String,
+}
+
+#[derive(InputObject)]
+struct CreateUsers {
+    users: Vec<Credentials>,
+    admin: Option<bool>,
+}
+
+#[derive(SimpleObject)]
+struct User {
+    name: String,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn value(&self, #[graphql(secret)] token: Option<String>) -> i32 {
+        let _ = token;
+        10
+    }
+}
+
+struct Mutation;
+
+#[Object]
+impl Mutation {
+    async fn login(&self, credentials: Credentials) -> User {
+        User {
+            name: credentials.name,
+        }
+    }
+
+    async fn create_users(&self, input: CreateUsers) -> i32 {
+        input.users.len() as i32
+    }
+
+    async fn reset_password(
+        &self,
+        name: String,
+        #[graphql(secret)] password: String,
+    ) -> Result<bool> {
+        let _ = (name, password);
+        Err("Not allowed".into())
+    }
+}
+
+fn schema(records: Rc<RefCell<Vec<AuditRecord>>>) -> Schema<Query, Mutation, EmptySubscription> {
+    Schema::build(Query, Mutation, EmptySubscription)
+        .extension(
+            AuditLog::new(move |record: AuditRecord| records.borrow_mut().push(record))
+                .identity(|ctx| ctx.data_opt::<CurrentUser>().map(|user| user.0.to_string())),
+        )
+        .finish()
+}
+
+fn arguments(record: &AuditRecord) -> Value {
+    Value::Object(record.arguments.clone())
+}
+
+#[async_std::test]
+pub async fn test_audit_log() {
+    let records = Rc::new(RefCell::new(Vec::new()));
+    let schema = schema(records.clone());
+
+    let query = r#"
+        mutation Setup($password: String!, $admin: Boolean) {
+            login(credentials: { name: "me", password: $password }) { name }
+            renamed: createUsers(input: {
+                users: [{ name: "a", password: "1" }, { name: "b", password: "2" }],
+                admin: $admin,
+            })
+        }
+    "#;
+    let request = Request::new(query)
+        .variables(Variables::from_value(value!({ "password": "1234" })))
+        .data(CurrentUser("root"));
+    schema.execute(request).await.into_result().unwrap();
+
+    {
+        let records = records.borrow();
+        assert_eq!(records.len(), 2);
+
+        assert_eq!(records[0].operation_name.as_deref(), Some("Setup"));
+        assert_eq!(records[0].field_name, "login");
+        assert_eq!(records[0].identity.as_deref(), Some("root"));
+        assert_eq!(
+            arguments(&records[0]),
+            value!({ "credentials": { "name": "me", "password": "[REDACTED]" } })
+        );
+        assert!(records[0].error.is_none());
+
+        assert_eq!(records[1].field_name, "createUsers");
+        assert_eq!(
+            arguments(&records[1]),
+            value!({
+                "input": {
+                    "users": [
+                        { "name": "a", "password": "[REDACTED]" },
+                        { "name": "b", "password": "[REDACTED]" },
+                    ],
+                },
+            })
+        );
+    }
+    records.borrow_mut().clear();
+
+    let resp = schema
+        .execute(r#"mutation { resetPassword(name: "me", password: "1234") }"#)
+        .await;
+    assert_eq!(resp.errors.len(), 1);
+    {
+        let records = records.borrow();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].operation_name, None);
+        assert_eq!(records[0].identity, None);
+        assert_eq!(
+            arguments(&records[0]),
+            value!({ "name": "me", "password": "[REDACTED]" })
+        );
+        assert_eq!(
+            records[0].error.as_ref().map(|err| err.message.as_str()),
+            Some("Not allowed")
+        );
+    }
+    records.borrow_mut().clear();
+
+    // Queries are not audited.
+    schema
+        .execute(r#"{ value(token: "abc") }"#)
+        .await
+        .into_result()
+        .unwrap();
+    assert!(records.borrow().is_empty());
+}
+
+#[test]
+pub fn test_secret_in_schema_view() {
+    let schema = schema(Default::default());
+    let registry = schema.registry();
+    let is_secret = |coordinate| match registry.lookup_coordinate(coordinate) {
+        Some(SchemaMember::Argument(arg)) | Some(SchemaMember::InputField(arg)) => arg.is_secret(),
+        _ => panic!("{} is not an input value", coordinate),
+    };
+    assert!(is_secret("Credentials.password"));
+    assert!(!is_secret("Credentials.name"));
+    assert!(is_secret("Mutation.resetPassword(password:)"));
+    assert!(!is_secret("Mutation.resetPassword(name:)"));
+}
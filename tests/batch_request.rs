@@ -31,3 +31,62 @@ pub async fn test_batch_request() {
         ])
     );
 }
+
+#[async_std::test]
+pub async fn test_batch_request_json() {
+    #[derive(Clone)]
+    struct Token(String);
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self, a: i32) -> i32 {
+            a
+        }
+
+        async fn token<'a>(&self, ctx: &'a Context<'_>) -> &'a str {
+            &ctx.data_unchecked::<Token>().0
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let body = r#"[{"query": "{ value(a: 10) }"}, {"query": "{ token }"}]"#;
+    let batch = http::receive_batch_json(body.as_bytes())
+        .await
+        .unwrap()
+        .data(Token("abc".to_string()));
+    assert!(matches!(batch, BatchRequest::Batch(_)));
+    assert_eq!(batch.iter().count(), 2);
+    let resp = schema.execute_batch(batch).await;
+    assert_eq!(
+        serde_json::to_value(&resp).unwrap(),
+        serde_json::json!([
+            {"data": { "value": 10 }},
+            {"data": { "token": "abc" }},
+        ])
+    );
+
+    let body = r#"{"query": "{ token }"}"#;
+    let batch = http::receive_batch_json(body.as_bytes())
+        .await
+        .unwrap()
+        .data(Token("abc".to_string()));
+    assert!(matches!(batch, BatchRequest::Single(_)));
+    let resp = schema.execute_batch(batch).await;
+    assert_eq!(
+        serde_json::to_value(&resp).unwrap(),
+        serde_json::json!({"data": { "token": "abc" }})
+    );
+
+    assert!(http::receive_batch_json("[]".as_bytes()).await.is_err());
+    assert!(matches!(
+        http::receive_json(body.as_bytes()).await,
+        Ok(Request { .. })
+    ));
+    assert!(matches!(
+        http::receive_json(r#"[{"query": "{ token }"}]"#.as_bytes()).await,
+        Err(ParseRequestError::UnsupportedBatch)
+    ));
+}
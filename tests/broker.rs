@@ -0,0 +1,179 @@
+use async_graphql::broker::{self, MemoryBroker, SubscriptionBroker};
+use async_graphql::*;
+use futures_util::stream::{Stream, StreamExt};
+use futures_util::FutureExt;
+
+#[derive(SimpleObject, serde::Serialize, serde::Deserialize)]
+struct Event {
+    id: i32,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn value(&self) -> i32 {
+        10
+    }
+}
+
+struct Subscription;
+
+#[Subscription]
+impl Subscription {
+    async fn events(
+        &self,
+        ctx: &Context<'_>,
+        topic: String,
+    ) -> Result<impl Stream<Item = Result<Event>>> {
+        broker::subscribe(ctx.data::<MemoryBroker>()?, &topic).await
+    }
+}
+
+#[async_std::test]
+pub async fn test_memory_broker() {
+    let broker = MemoryBroker::default();
+    let schema = Schema::build(Query, EmptyMutation, Subscription)
+        .data(broker.clone())
+        .finish();
+
+    let mut a1 = schema
+        .execute_stream(r#"subscription { events(topic: "a") { id } }"#)
+        .boxed_local();
+    let mut a2 = schema
+        .execute_stream(r#"subscription { events(topic: "a") { id } }"#)
+        .boxed_local();
+    let mut b = schema
+        .execute_stream(r#"subscription { events(topic: "b") { id } }"#)
+        .boxed_local();
+    assert!(a1.next().now_or_never().is_none());
+    assert!(a2.next().now_or_never().is_none());
+    assert!(b.next().now_or_never().is_none());
+
+    broker::publish(&broker, "a", &Event { id: 1 })
+        .await
+        .unwrap();
+    broker::publish(&broker, "a", &Event { id: 2 })
+        .await
+        .unwrap();
+    broker::publish(&broker, "b", &Event { id: 3 })
+        .await
+        .unwrap();
+
+    for stream in [&mut a1, &mut a2].iter_mut() {
+        assert_eq!(
+            stream.next().await.unwrap().data,
+            value!({ "events": { "id": 1 } })
+        );
+        assert_eq!(
+            stream.next().await.unwrap().data,
+            value!({ "events": { "id": 2 } })
+        );
+    }
+    assert_eq!(
+        b.next().await.unwrap().data,
+        value!({ "events": { "id": 3 } })
+    );
+
+    drop(a1);
+    broker
+        .publish_bytes("a", b"{ \"invalid\": true }".to_vec())
+        .await
+        .unwrap();
+    let resp = a2.next().await.unwrap();
+    assert_eq!(resp.errors.len(), 1);
+}
+
+#[cfg(feature = "redis")]
+#[test]
+pub fn test_redis_broker_unavailable() {
+    use async_graphql::broker::RedisBroker;
+    use deadpool_redis::redis::Client;
+    use deadpool_redis::{Config, Runtime};
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    rt.block_on(async {
+        // Nothing listens on this port.
+        let pool = Config::from_url("redis://127.0.0.1:1/")
+            .create_pool(Some(Runtime::Tokio1))
+            .unwrap();
+        let client = Client::open("redis://127.0.0.1:1/").unwrap();
+        let broker = RedisBroker::new(pool, client);
+
+        assert!(broker::publish(&broker, "a", &Event { id: 1 })
+            .await
+            .is_err());
+        assert!(broker::subscribe::<_, Event>(&broker, "a").await.is_err());
+    });
+}
+
+#[cfg(feature = "nats")]
+#[test]
+pub fn test_nats_broker_disconnected() {
+    use async_graphql::broker::NatsBroker;
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    rt.block_on(async {
+        // Nothing listens on this port, the client keeps reconnecting in the background.
+        let client = async_nats::ConnectOptions::new()
+            .retry_on_initial_connect()
+            .connect("nats://127.0.0.1:1")
+            .await
+            .unwrap();
+        let schema = Schema::build(Query, EmptyMutation, NatsSubscription)
+            .data(NatsBroker::new(client))
+            .finish();
+
+        let mut stream = schema
+            .execute_stream(r#"subscription { events(topic: "a") { id } }"#)
+            .boxed_local();
+        assert!(stream.next().now_or_never().is_none());
+    });
+}
+
+#[cfg(feature = "nats")]
+struct NatsSubscription;
+
+#[cfg(feature = "nats")]
+#[Subscription]
+impl NatsSubscription {
+    async fn events(
+        &self,
+        ctx: &Context<'_>,
+        topic: String,
+    ) -> Result<impl Stream<Item = Result<Event>>> {
+        broker::subscribe(ctx.data::<async_graphql::broker::NatsBroker>()?, &topic).await
+    }
+}
+
+#[cfg(feature = "kafka")]
+#[test]
+pub fn test_kafka_event_source() {
+    use async_graphql::broker::KafkaEventSource;
+    use rdkafka::ClientConfig;
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    rt.block_on(async {
+        let mut config = ClientConfig::new();
+        config.set("bootstrap.servers", "127.0.0.1:1");
+        let source = KafkaEventSource::new(config.clone());
+        let mut stream = source.subscribe::<Event>("a").unwrap().boxed_local();
+        assert!(stream.next().now_or_never().is_none());
+
+        let source = KafkaEventSource::new(config.clone()).group_id("group");
+        assert!(source.subscribe::<Event>("a").is_ok());
+
+        config.set("unknown.property", "1");
+        let source = KafkaEventSource::new(config);
+        assert!(source.subscribe::<Event>("a").is_err());
+    });
+}
@@ -0,0 +1,48 @@
+#[cfg(feature = "bson")]
+#[async_std::test]
+pub async fn test_bson_extended_json_round_trip() {
+    use async_graphql::*;
+    use bson::oid::ObjectId;
+    use bson::{Bson, Document};
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn document(&self, value: Document) -> Document {
+            value
+        }
+
+        async fn bson(&self, value: Bson) -> Bson {
+            value
+        }
+
+        async fn id(&self, value: Document) -> Option<ObjectId> {
+            value.get_object_id("_id").ok().cloned()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let document = value!({
+        "_id": { "$oid": "5f8f2b9d4b5a3c1a2b3c4d5e" },
+        "createdAt": { "$date": "2020-10-20T10:30:00Z" },
+        "name": "alice",
+    });
+    let query = r#"query($value: JSONObject!, $bson: JSON!) {
+        document(value: $value)
+        bson(value: $bson)
+        id(value: $value)
+    }"#;
+    let request = Request::new(query).variables(Variables::from_value(value!({
+        "value": document.clone(),
+        "bson": [document.clone(), 1],
+    })));
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        value!({
+            "document": document.clone(),
+            "bson": [document, 1],
+            "id": "5f8f2b9d4b5a3c1a2b3c4d5e",
+        })
+    );
+}
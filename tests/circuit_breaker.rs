@@ -0,0 +1,155 @@
+use async_graphql::resolver_utils::{
+    CircuitBreakers, CircuitState, ConsecutiveFailureBreaker, ResilientResolver,
+};
+use async_graphql::runtime::Timer;
+use async_graphql::*;
+use futures_util::future::{self, LocalBoxFuture};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A timer whose clock only moves when it is advanced.
+#[derive(Clone, Default)]
+struct ManualTimer(Arc<Mutex<Duration>>);
+
+impl ManualTimer {
+    fn advance(&self, duration: Duration) {
+        *self.0.lock().unwrap() += duration;
+    }
+}
+
+impl Timer for ManualTimer {
+    fn sleep(&self, _duration: Duration) -> LocalBoxFuture<'static, ()> {
+        Box::pin(future::pending())
+    }
+
+    fn now(&self) -> Duration {
+        *self.0.lock().unwrap()
+    }
+}
+
+#[derive(Default)]
+struct Downstream {
+    healthy: Cell<bool>,
+    calls: Cell<i32>,
+}
+
+#[derive(Default)]
+struct Query(Rc<Downstream>);
+
+#[Object]
+impl Query {
+    async fn price(&self, ctx: &Context<'_>) -> Result<i32> {
+        ResilientResolver::from_ctx(ctx, "pricing")?
+            .resolve(|| async {
+                self.0.calls.set(self.0.calls.get() + 1);
+                if self.0.healthy.get() {
+                    Ok(10)
+                } else {
+                    Err("Timeout".into())
+                }
+            })
+            .await
+    }
+
+    async fn stock(&self, ctx: &Context<'_>) -> Result<i32> {
+        ResilientResolver::from_ctx(ctx, "inventory")?
+            .resolve(|| async { Ok(5) })
+            .await
+    }
+}
+
+fn circuit_open() -> Option<ErrorExtensionValues> {
+    let mut extensions = ErrorExtensionValues::default();
+    extensions.set("code", "CIRCUIT_OPEN");
+    Some(extensions)
+}
+
+#[async_std::test]
+pub async fn test_circuit_breaker() {
+    let timer = ManualTimer::default();
+    let breaker = ConsecutiveFailureBreaker::new(2, Duration::from_secs(30), timer.clone());
+    let downstream = Rc::new(Downstream::default());
+    let schema = Schema::build(Query(downstream.clone()), EmptyMutation, EmptySubscription)
+        .data(CircuitBreakers::default().with("pricing", breaker.clone()))
+        .finish();
+
+    for _ in 0..2 {
+        let res = schema.execute("{ price }").await;
+        assert_eq!(res.errors[0].message, "Timeout");
+    }
+    assert_eq!(breaker.state(), CircuitState::Open);
+    assert_eq!(downstream.calls.get(), 2);
+
+    // Fails fast without calling the downstream service.
+    let res = schema.execute("{ price }").await;
+    assert_eq!(
+        res.errors[0].message,
+        "The downstream service is unavailable."
+    );
+    assert_eq!(res.errors[0].extensions, circuit_open());
+    assert_eq!(downstream.calls.get(), 2);
+
+    // The trial call fails and opens the circuit again.
+    timer.advance(Duration::from_secs(20));
+    assert_eq!(breaker.state(), CircuitState::Open);
+    timer.advance(Duration::from_secs(10));
+    assert_eq!(breaker.state(), CircuitState::HalfOpen);
+    let res = schema.execute("{ price }").await;
+    assert_eq!(res.errors[0].message, "Timeout");
+    assert_eq!(downstream.calls.get(), 3);
+    assert_eq!(breaker.state(), CircuitState::Open);
+
+    // The trial call succeeds and closes the circuit.
+    downstream.healthy.set(true);
+    timer.advance(Duration::from_secs(30));
+    let res = schema.execute("{ price }").await.into_result().unwrap();
+    assert_eq!(res.data, value!({ "price": 10 }));
+    assert_eq!(breaker.state(), CircuitState::Closed);
+}
+
+#[async_std::test]
+pub async fn test_circuit_breaker_reset_on_success() {
+    let breaker =
+        ConsecutiveFailureBreaker::new(2, Duration::from_secs(60), ManualTimer::default());
+    let downstream = Rc::new(Downstream::default());
+    let schema = Schema::build(Query(downstream.clone()), EmptyMutation, EmptySubscription)
+        .data(CircuitBreakers::default().with("pricing", breaker.clone()))
+        .finish();
+
+    schema.execute("{ price }").await;
+    downstream.healthy.set(true);
+    schema.execute("{ price }").await.into_result().unwrap();
+    downstream.healthy.set(false);
+    schema.execute("{ price }").await;
+    assert_eq!(breaker.state(), CircuitState::Closed);
+}
+
+#[async_std::test]
+pub async fn test_circuit_breaker_missing() {
+    let schema = Schema::build(Query::default(), EmptyMutation, EmptySubscription)
+        .data(CircuitBreakers::default())
+        .finish();
+    let res = schema.execute("{ stock }").await;
+    assert_eq!(
+        res.errors[0].message,
+        "Circuit breaker `inventory` does not exist."
+    );
+}
+
+#[async_std::test]
+pub async fn test_circuit_breaker_reset_timeout_overflow() {
+    let timer = ManualTimer::default();
+    timer.advance(Duration::from_secs(60));
+    let breaker = ConsecutiveFailureBreaker::new(1, Duration::MAX, timer.clone());
+    let schema = Schema::build(Query::default(), EmptyMutation, EmptySubscription)
+        .data(CircuitBreakers::default().with("pricing", breaker.clone()))
+        .finish();
+
+    schema.execute("{ price }").await;
+    assert_eq!(breaker.state(), CircuitState::Open);
+    timer.advance(Duration::from_secs(3600));
+    let res = schema.execute("{ price }").await;
+    assert_eq!(res.errors[0].extensions, circuit_open());
+}
@@ -92,3 +92,242 @@ pub async fn test_connection_additional_fields() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_connection_descriptions() {
+    /// A user of the system.
+    ///
+    /// Users can follow each other.
+    #[derive(SimpleObject)]
+    struct User {
+        name: String,
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn users(&self) -> Connection<usize, User> {
+            Connection::new(false, false)
+        }
+    }
+
+    let query = r#"{
+        connection: __type(name: "UserConnection") { description }
+        edge: __type(name: "UserEdge") { description }
+    }"#;
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({
+            "connection": {
+                "description": "A connection to a list of User items.",
+            },
+            "edge": {
+                "description": "An edge in a connection.\n\nA user of the system.\n\nUsers can follow each other.",
+            },
+        })
+    );
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .override_description::<User>("A person.".to_string())
+        .finish();
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({
+            "connection": {
+                "description": "A connection to a list of User items.",
+            },
+            "edge": {
+                "description": "An edge in a connection.\n\nA person.",
+            },
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_keyset_pagination() {
+    #[derive(SimpleObject, Clone)]
+    struct Post {
+        id: i32,
+        created_at: i32,
+    }
+
+    fn posts() -> Vec<Post> {
+        vec![(10, 1), (10, 2), (11, 3), (12, 4), (12, 5)]
+            .into_iter()
+            .map(|(created_at, id)| Post { id, created_at })
+            .collect()
+    }
+
+    // Runs the keyset query on the posts in memory, like the database would.
+    fn run(keyset: &KeysetQuery) -> Vec<Post> {
+        let key = |post: &Post| (post.created_at, post.id);
+        let bound = |params: &[Value]| match params {
+            [Value::Number(created_at), Value::Number(id)] => (
+                created_at.as_i64().unwrap() as i32,
+                id.as_i64().unwrap() as i32,
+            ),
+            _ => panic!(),
+        };
+        let mut params = keyset.params().chunks(2);
+        let mut rows = posts();
+        if let Some(condition) = keyset.condition() {
+            for part in condition.split(" AND ") {
+                let value = bound(params.next().unwrap());
+                if part.contains('>') {
+                    rows.retain(|post| key(post) > value);
+                } else {
+                    rows.retain(|post| key(post) < value);
+                }
+            }
+        }
+        if keyset.order_by().ends_with("DESC") {
+            rows.reverse();
+        }
+        if let Some(limit) = keyset.limit() {
+            rows.truncate(limit);
+        }
+        rows
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn posts(
+            &self,
+            after: Option<String>,
+            before: Option<String>,
+            first: Option<i32>,
+            last: Option<i32>,
+        ) -> Result<Connection<KeysetCursor, Post>> {
+            query(
+                after,
+                before,
+                first,
+                last,
+                |after, before, first, last| async move {
+                    let keyset = KeysetPagination::new(&["created_at", "id"])
+                        .build(after, before, first, last)?;
+                    Ok(keyset.connection(run(&keyset), |post| {
+                        vec![post.created_at.into(), post.id.into()]
+                    }))
+                },
+            )
+            .await
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let execute = |args: &str| {
+        let query = format!(
+            "{{ posts({}) {{ edges {{ cursor node {{ id }} }} pageInfo {{ hasPreviousPage hasNextPage }} }} }}",
+            args
+        );
+        let schema = &schema;
+        async move { schema.execute(query).await.into_result().unwrap().data }
+    };
+
+    assert_eq!(
+        execute("first: 2").await,
+        value!({
+            "posts": {
+                "edges": [
+                    { "cursor": "[10,1]", "node": { "id": 1 } },
+                    { "cursor": "[10,2]", "node": { "id": 2 } },
+                ],
+                "pageInfo": { "hasPreviousPage": false, "hasNextPage": true },
+            }
+        })
+    );
+    assert_eq!(
+        execute(r#"first: 2, after: "[10,2]""#).await,
+        value!({
+            "posts": {
+                "edges": [
+                    { "cursor": "[11,3]", "node": { "id": 3 } },
+                    { "cursor": "[12,4]", "node": { "id": 4 } },
+                ],
+                "pageInfo": { "hasPreviousPage": true, "hasNextPage": true },
+            }
+        })
+    );
+    assert_eq!(
+        execute(r#"first: 2, after: "[12,4]""#).await,
+        value!({
+            "posts": {
+                "edges": [{ "cursor": "[12,5]", "node": { "id": 5 } }],
+                "pageInfo": { "hasPreviousPage": true, "hasNextPage": false },
+            }
+        })
+    );
+    assert_eq!(
+        execute(r#"last: 2, before: "[12,4]""#).await,
+        value!({
+            "posts": {
+                "edges": [
+                    { "cursor": "[10,2]", "node": { "id": 2 } },
+                    { "cursor": "[11,3]", "node": { "id": 3 } },
+                ],
+                "pageInfo": { "hasPreviousPage": true, "hasNextPage": true },
+            }
+        })
+    );
+
+    let res = schema
+        .execute(r#"{ posts(first: 1, after: "[10]") { edges { cursor } } }"#)
+        .await;
+    assert_eq!(
+        res.errors[0].message,
+        "The cursor must have 2 values, but it has 1"
+    );
+}
+
+#[test]
+pub fn test_keyset_sql() {
+    let cursor = |created_at: i32, id: i32| Some(KeysetCursor(vec![created_at.into(), id.into()]));
+
+    let keyset = KeysetPagination::new(&["created_at", "id"])
+        .build(cursor(10, 1), None, Some(10), None)
+        .unwrap();
+    assert_eq!(
+        keyset.sql(),
+        " WHERE (created_at, id) > (?, ?) ORDER BY created_at ASC, id ASC LIMIT 11"
+    );
+    assert_eq!(keyset.params(), &[Value::from(10), Value::from(1)]);
+
+    let keyset = KeysetPagination::new(&["created_at", "id"])
+        .descending(true)
+        .placeholder(Placeholder::Numbered(1))
+        .build(cursor(10, 1), cursor(5, 7), None, Some(3))
+        .unwrap();
+    assert_eq!(
+        keyset.sql(),
+        " WHERE (created_at, id) < ($2, $3) AND (created_at, id) > ($4, $5) ORDER BY created_at ASC, id ASC LIMIT 4"
+    );
+    assert_eq!(
+        keyset.params(),
+        &[
+            Value::from(10),
+            Value::from(1),
+            Value::from(5),
+            Value::from(7)
+        ]
+    );
+
+    let keyset = KeysetPagination::new(&["id"])
+        .build(None, None, None, None)
+        .unwrap();
+    assert_eq!(keyset.sql(), " ORDER BY id ASC");
+    assert_eq!(keyset.limit(), None);
+
+    assert_eq!(
+        KeysetPagination::new(&["id"])
+            .build(None, None, Some(2), Some(1))
+            .unwrap_err()
+            .message,
+        "The \"first\" and \"last\" parameters cannot exist at the same time"
+    );
+}
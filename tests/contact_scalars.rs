@@ -0,0 +1,78 @@
+#[cfg(feature = "email_address")]
+#[async_std::test]
+pub async fn test_email_address() {
+    use async_graphql::*;
+    use email_address::EmailAddress;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn email(&self, address: EmailAddress) -> EmailAddress {
+            address
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ email(address: "John.Doe@Example.COM") }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "email": "John.Doe@example.com" })
+    );
+
+    for address in &["john.doe", "John Doe <john.doe@example.com>"] {
+        let res = schema
+            .execute(format!(r#"{{ email(address: "{}") }}"#, address))
+            .await;
+        assert!(res.errors[0]
+            .message
+            .starts_with(r#"Failed to parse "EmailAddress": "#));
+    }
+}
+
+#[cfg(feature = "phonenumber")]
+#[async_std::test]
+pub async fn test_phone_number() {
+    use async_graphql::*;
+    use phonenumber::PhoneNumber;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn phone(&self, number: PhoneNumber) -> PhoneNumber {
+            number
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                r#"{ a: phone(number: "+31 20 123 4567") b: phone(number: "+1 (650) 253-0000") }"#
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "a": "+31201234567",
+            "b": "+16502530000",
+        })
+    );
+
+    let res = schema.execute(r#"{ phone(number: "+31 12") }"#).await;
+    assert_eq!(
+        res.errors[0].message,
+        r#"Failed to parse "PhoneNumber": Invalid phone number "+31 12"."#
+    );
+
+    let res = schema.execute(r#"{ phone(number: "020 123 4567") }"#).await;
+    assert!(res.errors[0]
+        .message
+        .starts_with(r#"Failed to parse "PhoneNumber": "#));
+}
@@ -0,0 +1,178 @@
+use async_graphql::*;
+
+#[derive(SimpleObject)]
+struct Account {
+    #[graphql(meta(key = "tag", value = "public"))]
+    id: i32,
+    #[graphql(meta(key = "tag", value = "internal"))]
+    password_hash: String,
+}
+
+#[derive(SimpleObject)]
+#[graphql(meta(key = "tag", value = "internal"))]
+struct AuditLog {
+    entries: Vec<AuditEntry>,
+}
+
+#[derive(SimpleObject)]
+struct AuditEntry {
+    message: String,
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+enum Role {
+    User,
+    #[graphql(meta(key = "tag", value = "internal"))]
+    Admin,
+}
+
+#[derive(InputObject)]
+struct AccountFilter {
+    role: Option<Role>,
+    #[graphql(meta(key = "tag", value = "internal"))]
+    include_deleted: Option<bool>,
+}
+
+#[derive(InputObject)]
+struct AccountInput {
+    name: String,
+    #[graphql(meta(key = "tag", value = "internal"))]
+    owner_id: i32,
+}
+
+#[derive(Union)]
+enum SearchResult {
+    Account(Account),
+    AuditLog(AuditLog),
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn accounts(&self, _filter: Option<AccountFilter>) -> Vec<Account> {
+        vec![Account {
+            id: 1,
+            password_hash: "secret".to_string(),
+        }]
+    }
+
+    async fn find_account(&self, _input: AccountInput) -> Option<Account> {
+        None
+    }
+
+    async fn audit_log(&self) -> AuditLog {
+        AuditLog {
+            entries: Vec::new(),
+        }
+    }
+
+    #[graphql(meta(key = "tag", value = "public"))]
+    async fn search(&self) -> Vec<SearchResult> {
+        Vec::new()
+    }
+}
+
+#[async_std::test]
+pub async fn test_contract_exclude() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let contract = schema.build_contract(TagFilter::new().exclude("internal"));
+
+    // The original schema is unchanged.
+    assert!(schema
+        .execute("{ auditLog { entries { message } } }")
+        .await
+        .is_ok());
+    assert!(schema
+        .execute("{ accounts { passwordHash } }")
+        .await
+        .is_ok());
+
+    assert!(contract
+        .execute("{ auditLog { entries { message } } }")
+        .await
+        .is_err());
+    assert!(contract
+        .execute("{ accounts { passwordHash } }")
+        .await
+        .is_err());
+    assert!(contract
+        .execute("{ accounts(filter: { includeDeleted: true }) { id } }")
+        .await
+        .is_err());
+    assert!(contract
+        .execute("{ accounts(filter: { role: ADMIN }) { id } }")
+        .await
+        .is_err());
+    assert_eq!(
+        contract
+            .execute("{ accounts(filter: { role: USER }) { id } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "accounts": [{ "id": 1 }] })
+    );
+
+    // An input type with a hidden required field is removed with the fields using it.
+    assert!(schema
+        .execute(r#"{ findAccount(input: { name: "a", ownerId: 1 }) { id } }"#)
+        .await
+        .is_ok());
+    assert!(contract
+        .execute(r#"{ findAccount(input: { name: "a" }) { id } }"#)
+        .await
+        .is_err());
+    assert!(!contract.sdl().contains("AccountInput"));
+
+    // Types that are only reachable through hidden types are removed.
+    let res = contract
+        .execute(
+            r#"{
+                auditLog: __type(name: "AuditLog") { name }
+                auditEntry: __type(name: "AuditEntry") { name }
+                searchResult: __type(name: "SearchResult") { possibleTypes { name } }
+            }"#,
+        )
+        .await
+        .into_result()
+        .unwrap()
+        .data;
+    assert_eq!(
+        res,
+        value!({
+            "auditLog": null,
+            "auditEntry": null,
+            "searchResult": { "possibleTypes": [{ "name": "Account" }] },
+        })
+    );
+    assert!(!contract.sdl().contains("AuditEntry"));
+    assert!(schema.sdl().contains("AuditEntry"));
+}
+
+#[async_std::test]
+pub async fn test_contract_include() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let contract = schema.build_contract(TagFilter::new().include("public"));
+
+    let res = contract
+        .execute(
+            r#"{
+                query: __type(name: "Query") { fields { name } }
+                account: __type(name: "Account") { fields { name } }
+                auditLog: __type(name: "AuditLog") { name }
+            }"#,
+        )
+        .await
+        .into_result()
+        .unwrap()
+        .data;
+    assert_eq!(
+        res,
+        value!({
+            "query": { "fields": [{ "name": "search" }] },
+            "account": { "fields": [{ "name": "id" }] },
+            "auditLog": null,
+        })
+    );
+}
@@ -0,0 +1,216 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use async_graphql::connection::*;
+use async_graphql::crud::*;
+use async_graphql::*;
+
+#[derive(SimpleObject, Clone)]
+struct Person {
+    id: ID,
+    name: String,
+}
+
+#[derive(InputObject)]
+struct PersonInput {
+    name: String,
+}
+
+#[derive(Clone, Default)]
+struct People(Rc<RefCell<Vec<Person>>>);
+
+#[async_trait::async_trait(?Send)]
+impl DataSource for People {
+    type Item = Person;
+    type Cursor = usize;
+    type CreateInput = PersonInput;
+    type UpdateInput = PersonInput;
+
+    fn plural_name() -> String {
+        "people".to_string()
+    }
+
+    async fn get(&self, _ctx: &Context<'_>, id: ID) -> Result<Option<Person>> {
+        Ok(self.0.borrow().iter().find(|p| p.id == id).cloned())
+    }
+
+    async fn list(
+        &self,
+        _ctx: &Context<'_>,
+        after: Option<usize>,
+        _before: Option<usize>,
+        first: Option<usize>,
+        _last: Option<usize>,
+    ) -> Result<Connection<usize, Person>> {
+        let people = self.0.borrow();
+        let start = after.map(|after| after + 1).unwrap_or(0);
+        let end = first
+            .map(|first| (start + first).min(people.len()))
+            .unwrap_or_else(|| people.len());
+        let mut connection = Connection::new(start > 0, end < people.len());
+        connection.append((start..end).map(|n| Edge::new(n, people[n].clone())));
+        Ok(connection)
+    }
+
+    async fn create(&self, _ctx: &Context<'_>, input: PersonInput) -> Result<Person> {
+        if input.name.is_empty() {
+            return Err("The name must not be empty".into());
+        }
+        let mut people = self.0.borrow_mut();
+        let person = Person {
+            id: people.len().into(),
+            name: input.name,
+        };
+        people.push(person.clone());
+        Ok(person)
+    }
+
+    async fn update(
+        &self,
+        _ctx: &Context<'_>,
+        id: ID,
+        input: PersonInput,
+    ) -> Result<Option<Person>> {
+        let mut people = self.0.borrow_mut();
+        Ok(people.iter_mut().find(|p| p.id == id).map(|person| {
+            person.name = input.name;
+            person.clone()
+        }))
+    }
+
+    async fn delete(&self, _ctx: &Context<'_>, id: ID) -> Result<bool> {
+        let mut people = self.0.borrow_mut();
+        let len = people.len();
+        people.retain(|p| p.id != id);
+        Ok(people.len() < len)
+    }
+}
+
+#[derive(Default)]
+struct VersionQuery;
+
+#[Object]
+impl VersionQuery {
+    async fn version(&self) -> &str {
+        "1.0"
+    }
+}
+
+#[derive(MergedObject)]
+struct Query(CrudQuery<People>, VersionQuery);
+
+fn schema() -> Schema<Query, CrudMutation<People>, EmptySubscription> {
+    let people = People::default();
+    Schema::new(
+        Query(CrudQuery(people.clone()), VersionQuery),
+        CrudMutation(people),
+        EmptySubscription,
+    )
+}
+
+#[async_std::test]
+pub async fn test_crud_sdl() {
+    let sdl = schema().sdl();
+    assert!(sdl.contains("person(id: ID!): Person"));
+    assert!(sdl.contains(
+        "people(after: String, before: String, first: Int, last: Int): PersonConnection!"
+    ));
+    assert!(sdl.contains("version: String!"));
+    assert!(sdl.contains("createPerson(input: PersonInput!): Person!"));
+    assert!(sdl.contains("updatePerson(id: ID!, input: PersonInput!): Person"));
+    assert!(sdl.contains("deletePerson(id: ID!): Boolean!"));
+}
+
+#[async_std::test]
+pub async fn test_crud_operations() {
+    let schema = schema();
+
+    for name in &["Alice", "Bob", "Carol"] {
+        let query = format!(
+            r#"mutation {{ createPerson(input: {{ name: "{}" }}) {{ id }} }}"#,
+            name
+        );
+        assert!(schema.execute(&query).await.is_ok());
+    }
+
+    assert_eq!(
+        schema
+            .execute(r#"{ person(id: "1") { name } missing: person(id: "9") { name } version }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "person": { "name": "Bob" },
+            "missing": null,
+            "version": "1.0",
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ people(first: 2, after: "0") { edges { node { name } } pageInfo { hasNextPage } } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "people": {
+                "edges": [{ "node": { "name": "Bob" } }, { "node": { "name": "Carol" } }],
+                "pageInfo": { "hasNextPage": false },
+            }
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(
+                r#"mutation {
+                    updatePerson(id: "0", input: { name: "Alicia" }) { name }
+                    deletePerson(id: "1")
+                    deleteMissing: deletePerson(id: "9")
+                }"#
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "updatePerson": { "name": "Alicia" },
+            "deletePerson": true,
+            "deleteMissing": false,
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ people { edges { node { name } } } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "people": {
+                "edges": [{ "node": { "name": "Alicia" } }, { "node": { "name": "Carol" } }],
+            }
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_crud_errors() {
+    let schema = schema();
+
+    let resp = schema
+        .execute(r#"mutation { createPerson(input: { name: "" }) { id } }"#)
+        .await;
+    assert_eq!(resp.errors[0].message, "The name must not be empty");
+
+    let resp = schema
+        .execute("{ people(first: 1, last: 1) { edges { cursor } } }")
+        .await;
+    assert_eq!(
+        resp.errors[0].message,
+        "The \"first\" and \"last\" parameters cannot exist at the same time"
+    );
+}
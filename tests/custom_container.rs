@@ -0,0 +1,140 @@
+use std::borrow::Cow;
+
+use async_graphql::parser::types::Field;
+use async_graphql::registry::{MetaField, MetaType, Registry};
+use async_graphql::resolver_utils::{resolve_container, resolve_field_value, ContainerType};
+use async_graphql::*;
+
+struct Page<T> {
+    number: i32,
+    items: Vec<T>,
+}
+
+impl<T: OutputValueType> Type for Page<T> {
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(format!("{}Page", T::type_name()))
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        registry.create_type::<Self, _>(|registry| {
+            MetaType::object(
+                Self::type_name(),
+                Some("A page of items.".to_string()),
+                vec![
+                    MetaField::new("number", i32::create_type_info(registry))
+                        .description("The number of the page"),
+                    MetaField::new("items", Vec::<T>::create_type_info(registry)),
+                ],
+            )
+        })
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<T: OutputValueType> ContainerType for Page<T> {
+    async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+        match ctx.item.node.name.node.as_str() {
+            "number" => resolve_field_value(ctx, &self.number).await,
+            "items" => resolve_field_value(ctx, &self.items).await,
+            _ => Ok(None),
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<T: OutputValueType> OutputValueType for Page<T> {
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        resolve_container(ctx, self).await
+    }
+}
+
+impl<T: OutputValueType> ObjectType for Page<T> {}
+
+#[derive(SimpleObject)]
+struct Item {
+    name: String,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn page(&self) -> Page<Item> {
+        Page {
+            number: 2,
+            items: vec![
+                Item {
+                    name: "a".to_string(),
+                },
+                Item {
+                    name: "b".to_string(),
+                },
+            ],
+        }
+    }
+}
+
+#[async_std::test]
+pub async fn test_custom_container() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"{
+        page {
+            __typename
+            n: number
+            ... on ItemPage { items { name } }
+            ... PageFields
+        }
+    }
+
+    fragment PageFields on ItemPage {
+        number
+    }"#;
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({
+            "page": {
+                "__typename": "ItemPage",
+                "n": 2,
+                "items": [{ "name": "a" }, { "name": "b" }],
+                "number": 2,
+            }
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_custom_container_type_info() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"{
+        __type(name: "ItemPage") {
+            kind
+            description
+            fields { name description type { kind ofType { name } } }
+        }
+    }"#;
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({
+            "__type": {
+                "kind": "OBJECT",
+                "description": "A page of items.",
+                "fields": [
+                    {
+                        "name": "number",
+                        "description": "The number of the page",
+                        "type": { "kind": "NON_NULL", "ofType": { "name": "Int" } },
+                    },
+                    {
+                        "name": "items",
+                        "description": null,
+                        "type": { "kind": "NON_NULL", "ofType": { "name": null } },
+                    },
+                ],
+            }
+        })
+    );
+}
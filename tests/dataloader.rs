@@ -0,0 +1,227 @@
+use async_graphql::dataloader::{DataLoader, Loader};
+use async_graphql::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+struct UserLoader {
+    batches: Rc<RefCell<Vec<Vec<i32>>>>,
+}
+
+#[async_trait::async_trait(?Send)]
+impl Loader<i32> for UserLoader {
+    type Value = String;
+    type Error = Error;
+
+    async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, String>> {
+        let mut keys = keys.to_vec();
+        keys.sort_unstable();
+        self.batches.borrow_mut().push(keys.clone());
+        if keys.contains(&0) {
+            return Err("Invalid id".into());
+        }
+        Ok(keys
+            .into_iter()
+            .filter(|id| *id < 100)
+            .map(|id| (id, format!("user{}", id)))
+            .collect())
+    }
+}
+
+struct Post(i32);
+
+#[Object]
+impl Post {
+    async fn author(&self, ctx: &Context<'_>) -> Result<Option<String>> {
+        ctx.data_unchecked::<DataLoader<UserLoader>>()
+            .load_one(self.0)
+            .await
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn posts(&self, ids: Vec<i32>) -> Vec<Post> {
+        ids.into_iter().map(Post).collect()
+    }
+}
+
+fn schema(
+    configure: impl FnOnce(DataLoader<UserLoader>) -> DataLoader<UserLoader>,
+) -> (
+    Schema<Query, EmptyMutation, EmptySubscription>,
+    Rc<RefCell<Vec<Vec<i32>>>>,
+) {
+    let batches = Rc::new(RefCell::new(Vec::new()));
+    let loader = configure(DataLoader::new(UserLoader {
+        batches: batches.clone(),
+    }));
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(loader)
+        .finish();
+    (schema, batches)
+}
+
+#[async_std::test]
+pub async fn test_dataloader_batch() {
+    let (schema, batches) = schema(|loader| loader);
+    let res = schema
+        .execute("{ posts(ids: [1, 2, 1, 200]) { author } }")
+        .await;
+    assert_eq!(
+        res.data,
+        value!({
+            "posts": [
+                { "author": "user1" },
+                { "author": "user2" },
+                { "author": "user1" },
+                { "author": null },
+            ]
+        })
+    );
+    assert_eq!(*batches.borrow(), vec![vec![1, 2, 200]]);
+
+    // Without cache, the keys are loaded again.
+    schema.execute("{ posts(ids: [1]) { author } }").await;
+    assert_eq!(*batches.borrow(), vec![vec![1, 2, 200], vec![1]]);
+}
+
+#[async_std::test]
+pub async fn test_dataloader_max_batch_size() {
+    let (schema, batches) = schema(|loader| loader.max_batch_size(2));
+    let res = schema
+        .execute("{ posts(ids: [1, 2, 3, 4, 5]) { author } }")
+        .await;
+    assert!(res.is_ok());
+    assert_eq!(*batches.borrow(), vec![vec![1, 2], vec![3, 4], vec![5]]);
+}
+
+#[async_std::test]
+pub async fn test_dataloader_cache() {
+    let (schema, batches) = schema(|loader| loader.with_cache());
+    schema.execute("{ posts(ids: [1, 2]) { author } }").await;
+    let res = schema.execute("{ posts(ids: [2, 3]) { author } }").await;
+    assert_eq!(
+        res.data,
+        value!({
+            "posts": [{ "author": "user2" }, { "author": "user3" }]
+        })
+    );
+    assert_eq!(*batches.borrow(), vec![vec![1, 2], vec![3]]);
+}
+
+#[async_std::test]
+pub async fn test_dataloader_error() {
+    let (schema, batches) = schema(|loader| loader);
+    let res = schema.execute("{ posts(ids: [0, 1]) { author } }").await;
+    assert_eq!(
+        res.errors
+            .into_iter()
+            .map(|err| err.message)
+            .collect::<Vec<_>>(),
+        vec!["Invalid id".to_string()]
+    );
+    assert_eq!(*batches.borrow(), vec![vec![0, 1]]);
+}
+
+#[async_std::test]
+pub async fn test_dataloader_per_request() {
+    let (schema, batches) = schema(|loader| loader);
+    let query = "{ posts(ids: [1]) { author } }";
+    for _ in 0..2 {
+        let loader = DataLoader::new(UserLoader {
+            batches: batches.clone(),
+        })
+        .with_cache();
+        let res = schema.execute(Request::new(query).data(loader)).await;
+        assert_eq!(res.data, value!({ "posts": [{ "author": "user1" }] }));
+    }
+    assert_eq!(*batches.borrow(), vec![vec![1], vec![1]]);
+}
+
+#[async_std::test]
+pub async fn test_dataloader_clear() {
+    let batches = Rc::new(RefCell::new(Vec::new()));
+    let loader = DataLoader::new(UserLoader {
+        batches: batches.clone(),
+    })
+    .with_cache();
+    assert_eq!(
+        loader.load_many(vec![1, 2]).await.unwrap(),
+        vec![(1, "user1".to_string()), (2, "user2".to_string())]
+            .into_iter()
+            .collect()
+    );
+    assert_eq!(loader.load_one(1).await.unwrap(), Some("user1".to_string()));
+    loader.clear::<i32>();
+    assert_eq!(loader.load_one(1).await.unwrap(), Some("user1".to_string()));
+    assert_eq!(*batches.borrow(), vec![vec![1, 2], vec![1]]);
+}
+
+#[cfg(feature = "dataloader_adapters")]
+#[async_std::test]
+pub async fn test_dataloader_adapters() {
+    use async_graphql::dataloader::{ForeignKeyLoader, PrimaryKeyLoader};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Row {
+        id: i32,
+        user_id: i32,
+    }
+
+    let rows = Rc::new(vec![
+        Row { id: 1, user_id: 1 },
+        Row { id: 2, user_id: 2 },
+        Row { id: 3, user_id: 1 },
+    ]);
+    let batches = Rc::new(RefCell::new(Vec::new()));
+
+    let loader = DataLoader::new(PrimaryKeyLoader::new(
+        {
+            let rows = rows.clone();
+            let batches = batches.clone();
+            move |mut ids: Vec<i32>| {
+                ids.sort_unstable();
+                batches.borrow_mut().push(ids.clone());
+                let rows = rows
+                    .iter()
+                    .filter(|row| ids.contains(&row.id))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                async move { Ok::<_, Error>(rows) }
+            }
+        },
+        |row: &Row| row.id,
+    ));
+    let (row1, row4) = futures_util::future::join(loader.load_one(1), loader.load_one(4)).await;
+    assert_eq!(row1.unwrap(), Some(Row { id: 1, user_id: 1 }));
+    assert_eq!(row4.unwrap(), None);
+    assert_eq!(*batches.borrow(), vec![vec![1, 4]]);
+
+    let loader = DataLoader::new(ForeignKeyLoader::new(
+        move |user_ids: Vec<i32>| {
+            let rows = rows
+                .iter()
+                .filter(|row| user_ids.contains(&row.user_id))
+                .cloned()
+                .collect::<Vec<_>>();
+            async move { Ok::<_, Error>(rows) }
+        },
+        |row: &Row| row.user_id,
+    ));
+    let posts = loader.load_many(vec![1, 2, 3]).await.unwrap();
+    assert_eq!(
+        posts[&1],
+        vec![Row { id: 1, user_id: 1 }, Row { id: 3, user_id: 1 }]
+    );
+    assert_eq!(posts[&2], vec![Row { id: 2, user_id: 2 }]);
+    assert_eq!(posts[&3], vec![]);
+
+    let loader = DataLoader::new(PrimaryKeyLoader::new(
+        |_: Vec<i32>| async { Err::<Vec<Row>, _>(Error::new("Failed")) },
+        |row: &Row| row.id,
+    ));
+    assert_eq!(loader.load_one(1).await.unwrap_err().message, "Failed");
+}
@@ -1,3 +1,4 @@
+use async_graphql::validators::CustomValidator;
 use async_graphql::*;
 
 #[async_std::test]
@@ -102,3 +103,143 @@ pub async fn test_default_value_inputobject() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_default_value_with_ctx() {
+    struct Config {
+        page_size: i32,
+    }
+
+    struct Locale(String);
+
+    struct MaxPageSize(i32);
+
+    #[async_trait::async_trait(?Send)]
+    impl CustomValidator<i32> for MaxPageSize {
+        async fn check(&self, _ctx: &Context<'_>, value: &i32) -> Result<(), String> {
+            if *value > self.0 {
+                Err(format!("{} is too large", value))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn page_size(
+            &self,
+            #[graphql(
+                default_with_ctx = "ctx.data::<Config>()?.page_size",
+                custom_validator = "MaxPageSize(50)"
+            )]
+            size: i32,
+        ) -> i32 {
+            size
+        }
+
+        async fn locale(
+            &self,
+            #[graphql(default_with_ctx = "ctx.data::<Locale>()?.0.clone()")] locale: String,
+        ) -> String {
+            locale
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(Config { page_size: 20 })
+        .finish();
+    assert!(schema
+        .sdl()
+        .contains("pageSize(size: Int): Int!\n\tlocale(locale: String): String!"));
+
+    let request =
+        Request::new(r#"{ a: pageSize b: pageSize(size: 10) c: pageSize(size: null) locale }"#)
+            .data(Locale("fr".to_string()));
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        value!({
+            "a": 20,
+            "b": 10,
+            "c": 20,
+            "locale": "fr",
+        })
+    );
+
+    assert!(schema
+        .execute("{ locale }")
+        .await
+        .into_result()
+        .unwrap_err()[0]
+        .message
+        .ends_with("Locale` does not exist."));
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(Config { page_size: 100 })
+        .finish();
+    assert_eq!(
+        schema
+            .execute("{ pageSize }")
+            .await
+            .into_result()
+            .unwrap_err()[0]
+            .message,
+        r#"Invalid value for argument "size", 100 is too large"#
+    );
+}
+
+#[async_std::test]
+pub async fn test_default_value_literal() {
+    #[derive(Enum, Copy, Clone, Eq, PartialEq)]
+    enum Color {
+        Red,
+        Blue,
+    }
+
+    #[derive(InputObject)]
+    struct Style {
+        #[graphql(default_with = "vec![Color::Red, Color::Blue]")]
+        colors: Vec<Color>,
+        #[graphql(default_with = r#""first\nsecond".to_string()"#)]
+        text: String,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn color(&self, #[graphql(default_with = "Color::Blue")] color: Color) -> Color {
+            color
+        }
+
+        async fn label(
+            &self,
+            #[graphql(default_with = r#""say \"hi\"".to_string()"#)] label: String,
+        ) -> String {
+            label
+        }
+
+        async fn style(&self, _style: Style) -> bool {
+            true
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let sdl = schema.sdl();
+    assert!(sdl.contains("color(color: Color! = BLUE): Color!"));
+    assert!(sdl.contains(r#"label(label: String! = "say \"hi\""): String!"#));
+    assert!(sdl.contains("colors: [Color!]! = [RED, BLUE]"));
+    assert!(sdl.contains("text: String! = \"\"\"first\nsecond\"\"\""));
+    assert_eq!(
+        value!({ "a": [1, "x"], "b": null }).to_graphql_literal(),
+        r#"{a: [1, "x"], b: null}"#
+    );
+    assert_eq!(
+        value!("lines\n  indented").to_graphql_literal(),
+        r#""lines\n  indented""#
+    );
+    assert_eq!(value!("\u{1}").to_graphql_literal(), r#""\u0001""#);
+    assert_eq!(value!([1, { "a": "x" }]).to_string(), r#"[1,{a: "x",},]"#);
+}
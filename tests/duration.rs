@@ -0,0 +1,120 @@
+#[cfg(feature = "duration")]
+#[async_std::test]
+pub async fn test_std_duration() {
+    use async_graphql::*;
+    use std::time::Duration;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn double(&self, duration: Duration) -> Duration {
+            duration * 2
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ a: double(duration: "P1DT2H30M0.25S") b: double(duration: "PT0S") c: double(duration: "P1W") }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "a": "PT53H0.5S",
+            "b": "PT0S",
+            "c": "PT336H",
+        })
+    );
+
+    for (duration, message) in &[
+        (
+            "P1Y",
+            r#"Invalid ISO 8601 duration "P1Y": years and months are not accepted because their length varies."#,
+        ),
+        (
+            "PT",
+            r#"Invalid ISO 8601 duration "PT": "T" must be followed by hours, minutes or seconds."#,
+        ),
+        (
+            "P1H",
+            r#"Invalid ISO 8601 duration "P1H": "H" is not a unit here."#,
+        ),
+        (
+            "PT1S2M",
+            r#"Invalid ISO 8601 duration "PT1S2M": "M" is out of order."#,
+        ),
+        (
+            "1H",
+            r#"Invalid ISO 8601 duration "1H": it must start with "P"."#,
+        ),
+        (
+            "-PT1S",
+            r#"Invalid duration "-PT1S": it must not be negative."#,
+        ),
+    ] {
+        let res = schema
+            .execute(format!(r#"{{ double(duration: "{}") }}"#, duration))
+            .await;
+        assert_eq!(
+            res.errors[0].message,
+            format!(r#"Failed to parse "Duration": {}"#, message)
+        );
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[async_std::test]
+pub async fn test_chrono_duration_and_interval() {
+    use async_graphql::*;
+    use chrono::Duration;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn negate(&self, duration: Duration) -> Duration {
+            -duration
+        }
+
+        async fn length(&self, interval: Interval) -> Duration {
+            interval.duration()
+        }
+
+        async fn interval(&self, interval: Interval) -> Interval {
+            interval
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    negate(duration: "-PT1H30M")
+                    length(interval: "2021-03-01T09:00:00Z/2021-03-01T17:30:00Z")
+                    a: interval(interval: "2021-03-01T09:00:00Z/PT8H")
+                    b: interval(interval: "P1D/2021-03-01T00:00:00+01:00")
+                }"#
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "negate": "PT1H30M",
+            "length": "PT8H30M",
+            "a": "2021-03-01T09:00:00+00:00/2021-03-01T17:00:00+00:00",
+            "b": "2021-02-27T23:00:00+00:00/2021-02-28T23:00:00+00:00",
+        })
+    );
+
+    let res = schema
+        .execute(r#"{ length(interval: "2021-03-01T17:00:00Z/2021-03-01T09:00:00Z") }"#)
+        .await;
+    assert_eq!(
+        res.errors[0].message,
+        r#"Failed to parse "Interval": Invalid ISO 8601 interval "2021-03-01T17:00:00Z/2021-03-01T09:00:00Z": its end is before its start."#
+    );
+}
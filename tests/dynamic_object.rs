@@ -0,0 +1,92 @@
+use async_graphql::*;
+
+#[derive(SimpleObject)]
+struct Author {
+    name: String,
+}
+
+#[derive(SimpleObject)]
+struct Book {
+    title: String,
+    author: Author,
+}
+
+#[derive(SimpleObject)]
+struct Movie {
+    title: String,
+    minutes: i32,
+}
+
+#[derive(Union)]
+enum Media {
+    Book(Book),
+    Movie(Movie),
+}
+
+fn media_typename(value: &Value) -> String {
+    match value {
+        Value::Object(object) if object.contains_key("author") => "Book".to_string(),
+        Value::Object(object) if object.contains_key("minutes") => "Movie".to_string(),
+        _ => "Unknown".to_string(),
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn media(&self) -> Vec<DynamicObject<Media>> {
+        DynamicObject::list(
+            vec![
+                value!({ "title": "Dune", "author": { "name": "Frank Herbert" } }),
+                value!({ "title": "Alien", "minutes": 117 }),
+            ],
+            media_typename,
+        )
+    }
+
+    async fn unknown(&self) -> DynamicObject<Media> {
+        DynamicObject::new(value!({ "title": "Unknown" }), media_typename)
+    }
+}
+
+#[async_std::test]
+pub async fn test_dynamic_object_list() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"{
+        media {
+            kind: __typename
+            ... on Book { title author { name } }
+            ...MovieFields
+            ... @skip(if: true) { ... on Movie { skipped: title } }
+        }
+    }
+
+    fragment MovieFields on Movie { name: title minutes }
+    "#;
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({
+            "media": [
+                { "kind": "Book", "title": "Dune", "author": { "name": "Frank Herbert" } },
+                { "kind": "Movie", "name": "Alien", "minutes": 117 },
+            ]
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_dynamic_object_impossible_type() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let res = schema.execute("{ unknown { __typename } }").await;
+    assert_eq!(res.data, Value::Null);
+    assert_eq!(
+        res.errors,
+        vec![ServerError {
+            message: r#""Unknown" is not a possible type of "Media"."#.to_string(),
+            locations: vec![Pos { line: 1, column: 3 }],
+            path: vec![PathSegment::Field("unknown".to_string())],
+            extensions: None,
+        }]
+    );
+}
@@ -92,3 +92,159 @@ pub async fn test_remote_enum() {
     let _: remote::RemoteEnum = LocalEnum::A.into();
     let _: LocalEnum = remote::RemoteEnum::A.into();
 }
+
+#[async_std::test]
+pub async fn test_enum_fallback() {
+    #[derive(Enum, Clone, Eq, PartialEq, Debug)]
+    enum Color {
+        Red,
+        Green,
+        #[graphql(fallback)]
+        Unknown(String),
+    }
+
+    #[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+    enum Shape {
+        Circle,
+        #[graphql(fallback)]
+        Other,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn color(&self, color: Color) -> Color {
+            color
+        }
+
+        async fn is_known_shape(&self, shape: Shape) -> bool {
+            shape != Shape::Other
+        }
+
+        async fn shape(&self) -> Shape {
+            Shape::Other
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    red: color(color: RED)
+                    blue: color(color: BLUE)
+                    circle: isKnownShape(shape: CIRCLE)
+                    square: isKnownShape(shape: SQUARE)
+                }"#
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "red": "RED",
+            "blue": "BLUE",
+            "circle": true,
+            "square": false,
+        })
+    );
+
+    let errors = schema.execute("{ shape }").await.into_result().unwrap_err();
+    assert_eq!(
+        errors[0].message,
+        r#"Cannot output the fallback value of enum "Shape"."#
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Color") { enumValues { name } } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "__type": {
+                "enumValues": [
+                    { "name": "RED" },
+                    { "name": "GREEN" },
+                ]
+            }
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_enum_item_value_and_aliases() {
+    #[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
+    #[graphql(rename_items = "lowercase")]
+    enum Status {
+        #[graphql(value = "ACTIVE_LEGACY")]
+        Active,
+        #[graphql(alias = "DISABLED", alias = "OFF")]
+        Inactive,
+    }
+
+    #[derive(Enum, Clone, Eq, PartialEq, Debug)]
+    enum Level {
+        #[graphql(alias = "LOW")]
+        Minor,
+        #[graphql(fallback)]
+        Unknown(String),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn status(&self, status: Status) -> Status {
+            status
+        }
+
+        async fn level(&self, level: Level) -> Level {
+            level
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let request = Request::new(
+        r#"query($status: Status!) {
+            a: status(status: ACTIVE_LEGACY)
+            b: status(status: inactive)
+            c: status(status: DISABLED)
+            d: status(status: $status)
+            e: level(level: LOW)
+        }"#,
+    )
+    .variables(Variables::from_value(value!({ "status": "OFF" })));
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        value!({
+            "a": "ACTIVE_LEGACY",
+            "b": "inactive",
+            "c": "inactive",
+            "d": "inactive",
+            "e": "MINOR",
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Status") { enumValues { name } } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "__type": {
+                "enumValues": [
+                    { "name": "ACTIVE_LEGACY" },
+                    { "name": "inactive" },
+                ]
+            }
+        })
+    );
+
+    let errors = schema.execute("{ status(status: active) }").await.errors;
+    assert_eq!(errors.len(), 1);
+}
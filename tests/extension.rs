@@ -19,7 +19,6 @@ pub async fn test_extension_ctx() {
 
     struct MyExtensionImpl;
 
-    #[async_trait::async_trait]
     impl Extension for MyExtensionImpl {
         fn parse_start(
             &mut self,
@@ -66,3 +65,141 @@ pub async fn test_extension_ctx() {
         assert_eq!(*data.0.lock(), 100);
     }
 }
+
+#[async_std::test]
+pub async fn test_extension_transform_document() {
+    use async_graphql::parser::types::{ExecutableDocument, Field};
+    use async_graphql::parser::visit::{self, VisitMut};
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn items(&self, #[graphql(default)] tenant: String) -> String {
+            tenant
+        }
+    }
+
+    struct InjectTenant;
+
+    impl VisitMut for InjectTenant {
+        fn visit_field_mut(&mut self, field: &mut Positioned<Field>) {
+            if field.node.name.node == "items" {
+                field.node.arguments.push((
+                    Positioned::new(Name::new("tenant"), field.pos),
+                    Positioned::new(Value::String("acme".to_string()).into_value(), field.pos),
+                ));
+            }
+            visit::visit_field_mut(self, field);
+        }
+    }
+
+    struct MyExtensionImpl;
+
+    impl Extension for MyExtensionImpl {
+        fn transform_document(
+            &mut self,
+            _ctx: &ExtensionContext<'_>,
+            document: &mut ExecutableDocument,
+        ) {
+            InjectTenant.visit_document_mut(document);
+        }
+    }
+
+    struct MyExtension;
+
+    impl ExtensionFactory for MyExtension {
+        fn create(&self) -> Box<dyn Extension> {
+            Box::new(MyExtensionImpl)
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(MyExtension)
+        .finish();
+    assert_eq!(
+        schema
+            .execute("{ items a: items }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "items": "acme", "a": "acme" })
+    );
+}
+
+#[async_std::test]
+pub async fn test_extension_resolve_arguments() {
+    use async_graphql::extensions::ResolveInfo;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn add(&self, a: i32, #[graphql(default = 1)] b: i32) -> i32 {
+            a + b
+        }
+
+        async fn args(
+            &self,
+            ctx: &Context<'_>,
+            _a: Option<i32>,
+            _b: Option<i32>,
+        ) -> Json<Map<Value>> {
+            Json(ctx.arguments().unwrap())
+        }
+    }
+
+    #[derive(Default, Clone)]
+    struct Arguments(Arc<Mutex<Vec<(String, Map<Value>)>>>);
+
+    struct MyExtensionImpl(Arguments);
+
+    impl Extension for MyExtensionImpl {
+        fn resolve_start(&mut self, _ctx: &ExtensionContext<'_>, info: &ResolveInfo<'_>) {
+            if let Some(arguments) = info.arguments {
+                self.0
+                     .0
+                    .lock()
+                    .push((info.path_node.to_string(), arguments.clone()));
+            }
+        }
+    }
+
+    struct MyExtension(Arguments);
+
+    impl ExtensionFactory for MyExtension {
+        fn create(&self) -> Box<dyn Extension> {
+            Box::new(MyExtensionImpl(self.0.clone()))
+        }
+    }
+
+    let arguments = Arguments::default();
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(MyExtension(arguments.clone()))
+        .finish();
+    let request = Request::new("query($x: Int!, $y: Int) { add(a: $x) args(a: 1, b: $y) }")
+        .variables(Variables::from_value(value!({ "x": 10 })));
+    assert_eq!(
+        schema.execute(request).await.into_result().unwrap().data,
+        value!({ "add": 11, "args": { "a": 1 } })
+    );
+
+    let mut arguments = arguments.0.lock().clone();
+    arguments.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(
+        arguments,
+        vec![
+            ("add".to_string(), {
+                let mut args = Map::default();
+                args.insert(Name::new("a"), value!(10));
+                args
+            }),
+            ("args".to_string(), {
+                let mut args = Map::default();
+                args.insert(Name::new("a"), value!(1));
+                args
+            }),
+        ]
+    );
+}
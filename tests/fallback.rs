@@ -0,0 +1,79 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_fallback() {
+    #[derive(SimpleObject, Default)]
+    struct Review {
+        rating: i32,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        #[graphql(fallback = "Vec::new()")]
+        async fn reviews(&self, fail: bool) -> Result<Vec<Review>> {
+            if fail {
+                Err("Reviews are unavailable".into())
+            } else {
+                Ok(vec![Review { rating: 5 }])
+            }
+        }
+
+        #[graphql(fallback = "-1")]
+        async fn count(&self) -> Result<i32> {
+            Err("Count is unavailable".into())
+        }
+
+        async fn title(&self) -> Result<String> {
+            Err("Title is unavailable".into())
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let resp = schema
+        .execute("{ reviews(fail: false) { rating } }")
+        .await
+        .into_result()
+        .unwrap();
+    assert_eq!(resp.data, value!({ "reviews": [{ "rating": 5 }] }));
+    assert_eq!(resp.extensions, None);
+
+    let resp = schema
+        .execute("{ reviews(fail: true) { rating } total: count }")
+        .await
+        .into_result()
+        .unwrap();
+    assert_eq!(resp.data, value!({ "reviews": [], "total": -1 }));
+    assert_eq!(
+        resp.extensions,
+        Some(value!({
+            "warnings": [
+                {
+                    "message": "Reviews are unavailable",
+                    "locations": [{ "line": 1, "column": 3 }],
+                    "path": ["reviews"],
+                },
+                {
+                    "message": "Count is unavailable",
+                    "locations": [{ "line": 1, "column": 34 }],
+                    "path": ["total"],
+                },
+            ]
+        }))
+    );
+
+    let resp = schema.execute("{ count title }").await;
+    assert_eq!(resp.errors[0].message, "Title is unavailable");
+    assert_eq!(
+        resp.extensions,
+        Some(value!({
+            "warnings": [{
+                "message": "Count is unavailable",
+                "locations": [{ "line": 1, "column": 3 }],
+                "path": ["count"],
+            }]
+        }))
+    );
+}
@@ -87,3 +87,200 @@ pub async fn test_federation() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_entities_error_isolation() {
+    struct Item {
+        id: i32,
+    }
+
+    #[Object(extends)]
+    impl Item {
+        #[graphql(external)]
+        async fn id(&self) -> i32 {
+            self.id
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        #[graphql(entity)]
+        async fn find_item_by_id(&self, id: i32) -> Result<Item> {
+            if id < 0 {
+                Err("Invalid id".into())
+            } else {
+                Ok(Item { id })
+            }
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"{
+            _entities(representations: [
+                {__typename: "Item", id: 1},
+                {__typename: "Item", id: -1},
+                {__typename: "Unknown", id: 3}
+            ]) {
+                ... on Item {
+                    id
+                }
+            }
+        }"#;
+    let resp = schema.execute(query).await;
+    assert_eq!(
+        resp.data,
+        value!({
+            "_entities": [
+                {"id": 1},
+                null,
+                null,
+            ]
+        })
+    );
+    assert_eq!(
+        resp.errors
+            .iter()
+            .map(|err| (err.message.as_str(), err.path.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                "Invalid id",
+                vec![
+                    PathSegment::Field("_entities".to_string()),
+                    PathSegment::Index(1)
+                ]
+            ),
+            (
+                "Entity not found.",
+                vec![
+                    PathSegment::Field("_entities".to_string()),
+                    PathSegment::Index(2)
+                ]
+            ),
+        ]
+    );
+}
+
+#[async_std::test]
+pub async fn test_entity_interface() {
+    #[derive(SimpleObject)]
+    struct Book {
+        id: ID,
+        title: String,
+    }
+
+    #[derive(SimpleObject)]
+    struct Movie {
+        id: ID,
+        director: String,
+    }
+
+    #[derive(SimpleObject)]
+    struct Song {
+        id: ID,
+        artist: String,
+    }
+
+    #[derive(Interface)]
+    #[graphql(field(name = "id", type = "&ID"))]
+    enum Media {
+        Book(Book),
+        Movie(Movie),
+        Song(Song),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        #[graphql(entity)]
+        async fn find_media_by_id(&self, id: ID) -> Media {
+            if id.starts_with('b') {
+                Book {
+                    id,
+                    title: "Dune".to_string(),
+                }
+                .into()
+            } else {
+                Movie {
+                    id,
+                    director: "Villeneuve".to_string(),
+                }
+                .into()
+            }
+        }
+
+        #[graphql(entity)]
+        async fn find_song_by_id(&self, id: ID) -> Song {
+            Song {
+                id,
+                artist: "Zimmer".to_string(),
+            }
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let query = r#"{
+            _entities(representations: [
+                {__typename: "Book", id: "b1"},
+                {__typename: "Media", id: "m1"},
+                {__typename: "Song", id: "s1"},
+            ]) {
+                __typename
+                ... on Book { title }
+                ... on Movie { director }
+                ... on Song { artist }
+            }
+        }"#;
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({
+            "_entities": [
+                {"__typename": "Book", "title": "Dune"},
+                {"__typename": "Movie", "director": "Villeneuve"},
+                {"__typename": "Song", "artist": "Zimmer"},
+            ]
+        })
+    );
+
+    let entity = schema
+        .execute(r#"{ __type(name: "_Entity") { possibleTypes { name } } }"#)
+        .await
+        .into_result()
+        .unwrap()
+        .data;
+    assert_eq!(
+        entity,
+        value!({
+            "__type": {
+                "possibleTypes": [{ "name": "Book" }, { "name": "Movie" }, { "name": "Song" }]
+            }
+        })
+    );
+
+    let sdl = match schema
+        .execute("{ _service { sdl } }")
+        .await
+        .into_result()
+        .unwrap()
+        .data
+    {
+        Value::Object(mut data) => match data.remove("_service") {
+            Some(Value::Object(mut service)) => match service.remove("sdl") {
+                Some(Value::String(sdl)) => sdl,
+                _ => panic!(),
+            },
+            _ => panic!(),
+        },
+        _ => panic!(),
+    };
+    assert!(
+        sdl.starts_with(r#"extend schema @link(url: "https://specs.apollo.dev/federation/v2.3""#)
+    );
+    assert!(sdl.contains(r#"interface Media @key(fields: "id") {"#));
+    assert!(sdl.contains(r#"type Book implements Media @key(fields: "id") {"#));
+    assert!(sdl.contains(r#"type Song implements Media @key(fields: "id") {"#));
+}
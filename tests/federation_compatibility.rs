@@ -0,0 +1,264 @@
+//! The `products` subgraph of the
+//! [apollo-federation-subgraph-compatibility](https://github.com/apollographql/apollo-federation-subgraph-compatibility)
+//! suite, and the checks the suite runs against it.
+//!
+//! The suite itself drives a subgraph over HTTP, here the same requests are executed against the
+//! schema directly. Nested keys such as `sku variation { id }` aren't supported, so that check is
+//! left out.
+
+use async_graphql::*;
+
+#[derive(SimpleObject, Clone)]
+struct ProductVariation {
+    id: ID,
+}
+
+#[derive(SimpleObject, Clone)]
+struct ProductDimension {
+    size: Option<String>,
+    weight: Option<f64>,
+}
+
+#[derive(Clone)]
+struct User {
+    email: ID,
+    total_products_created: Option<i32>,
+}
+
+#[Object(extends)]
+impl User {
+    #[graphql(external)]
+    async fn email(&self) -> &ID {
+        &self.email
+    }
+
+    #[graphql(external)]
+    async fn total_products_created(&self) -> Option<i32> {
+        self.total_products_created
+    }
+}
+
+#[derive(SimpleObject, Clone)]
+struct Product {
+    id: ID,
+    sku: Option<String>,
+    package: Option<String>,
+    variation: Option<ProductVariation>,
+    dimensions: Option<ProductDimension>,
+    #[graphql(provides = "totalProductsCreated")]
+    created_by: Option<User>,
+}
+
+fn products() -> Vec<Product> {
+    let dimensions = ProductDimension {
+        size: Some("small".to_string()),
+        weight: Some(1.0),
+    };
+    let created_by = User {
+        email: "support@apollographql.com".into(),
+        total_products_created: Some(1337),
+    };
+    vec![
+        Product {
+            id: "apollo-federation".into(),
+            sku: Some("federation".to_string()),
+            package: Some("@apollo/federation".to_string()),
+            variation: Some(ProductVariation { id: "OSS".into() }),
+            dimensions: Some(dimensions.clone()),
+            created_by: Some(created_by.clone()),
+        },
+        Product {
+            id: "apollo-studio".into(),
+            sku: Some("studio".to_string()),
+            package: Some(String::new()),
+            variation: Some(ProductVariation {
+                id: "platform".into(),
+            }),
+            dimensions: Some(dimensions),
+            created_by: Some(created_by),
+        },
+    ]
+}
+
+struct Query;
+
+#[Object(extends)]
+impl Query {
+    async fn product(&self, id: ID) -> Option<Product> {
+        products().into_iter().find(|product| product.id == id)
+    }
+
+    #[graphql(entity)]
+    async fn find_product_by_id(&self, id: ID) -> Option<Product> {
+        products().into_iter().find(|product| product.id == id)
+    }
+
+    #[graphql(entity)]
+    async fn find_product_by_sku_and_package(
+        &self,
+        sku: String,
+        package: String,
+    ) -> Option<Product> {
+        products().into_iter().find(|product| {
+            product.sku.as_ref() == Some(&sku) && product.package.as_ref() == Some(&package)
+        })
+    }
+
+    #[graphql(entity)]
+    async fn find_user_by_email(&self, email: ID) -> User {
+        User {
+            email,
+            total_products_created: Some(1337),
+        }
+    }
+}
+
+fn schema() -> Schema<Query, EmptyMutation, EmptySubscription> {
+    Schema::new(Query, EmptyMutation, EmptySubscription)
+}
+
+async fn execute(query: &str) -> Value {
+    schema().execute(query).await.into_result().unwrap().data
+}
+
+#[async_std::test]
+pub async fn test_compatibility_service_sdl() {
+    let sdl = match execute("{ _service { sdl } }").await {
+        Value::Object(mut data) => match data.remove("_service") {
+            Some(Value::Object(mut service)) => match service.remove("sdl") {
+                Some(Value::String(sdl)) => sdl,
+                sdl => panic!("unexpected sdl {:?}", sdl),
+            },
+            service => panic!("unexpected _service {:?}", service),
+        },
+        data => panic!("unexpected data {:?}", data),
+    };
+
+    let product = sdl
+        .split("}\n")
+        .find(|ty| ty.starts_with("type Product "))
+        .unwrap();
+    assert!(product.contains(r#"@key(fields: "id")"#));
+    assert!(product.contains(r#"@key(fields: "sku package")"#));
+    assert!(product.contains(r#"createdBy: User @provides(fields: "totalProductsCreated")"#));
+
+    let user = sdl
+        .split("}\n")
+        .find(|ty| ty.starts_with("extend type User "))
+        .unwrap();
+    assert!(user.contains(r#"@key(fields: "email")"#));
+    assert!(user.contains("email: ID! @external"));
+    assert!(user.contains("totalProductsCreated: Int @external"));
+
+    // The federation types are added by the gateway, not declared by the subgraph.
+    assert!(!sdl.contains("_Service"));
+    assert!(!sdl.contains("_Entity"));
+    assert!(!sdl.contains("_entities"));
+}
+
+#[async_std::test]
+pub async fn test_compatibility_key_single() {
+    assert_eq!(
+        execute(
+            r#"{
+                _entities(representations: [{ __typename: "Product", id: "apollo-federation" }]) {
+                    ... on Product { sku package variation { id } }
+                }
+            }"#
+        )
+        .await,
+        value!({
+            "_entities": [{
+                "sku": "federation",
+                "package": "@apollo/federation",
+                "variation": { "id": "OSS" },
+            }]
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_compatibility_key_composite() {
+    assert_eq!(
+        execute(
+            r#"{
+                _entities(representations: [
+                    { __typename: "Product", sku: "studio", package: "" },
+                    { __typename: "Product", sku: "federation", package: "@apollo/federation" }
+                ]) {
+                    ... on Product { id }
+                }
+            }"#
+        )
+        .await,
+        value!({
+            "_entities": [
+                { "id": "apollo-studio" },
+                { "id": "apollo-federation" },
+            ]
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_compatibility_key_multiple() {
+    // The same entity can be resolved by any of its keys.
+    assert_eq!(
+        execute(
+            r#"{
+                _entities(representations: [
+                    { __typename: "Product", id: "apollo-studio" },
+                    { __typename: "Product", sku: "studio", package: "" }
+                ]) {
+                    __typename
+                    ... on Product { id sku }
+                }
+            }"#
+        )
+        .await,
+        value!({
+            "_entities": [
+                { "__typename": "Product", "id": "apollo-studio", "sku": "studio" },
+                { "__typename": "Product", "id": "apollo-studio", "sku": "studio" },
+            ]
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_compatibility_provides() {
+    assert_eq!(
+        execute(
+            r#"{ product(id: "apollo-federation") { createdBy { email totalProductsCreated } } }"#
+        )
+        .await,
+        value!({
+            "product": {
+                "createdBy": {
+                    "email": "support@apollographql.com",
+                    "totalProductsCreated": 1337,
+                }
+            }
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_compatibility_external() {
+    assert_eq!(
+        execute(
+            r#"{
+                _entities(representations: [{ __typename: "User", email: "support@apollographql.com" }]) {
+                    ... on User { email totalProductsCreated }
+                }
+            }"#
+        )
+        .await,
+        value!({
+            "_entities": [{
+                "email": "support@apollographql.com",
+                "totalProductsCreated": 1337,
+            }]
+        })
+    );
+}
@@ -0,0 +1,72 @@
+use async_graphql::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+struct Item {
+    id: i32,
+    resolved: Rc<Cell<i32>>,
+}
+
+#[Object]
+impl Item {
+    async fn id(&self) -> i32 {
+        self.resolved.set(self.resolved.get() + 1);
+        self.id
+    }
+}
+
+#[derive(Default)]
+struct Query {
+    resolved: Rc<Cell<i32>>,
+}
+
+#[Object]
+impl Query {
+    async fn items(&self, count: i32) -> Vec<Item> {
+        (0..count)
+            .map(|id| Item {
+                id,
+                resolved: self.resolved.clone(),
+            })
+            .collect()
+    }
+}
+
+#[async_std::test]
+pub async fn test_limit_resolved_fields() {
+    let query = Query::default();
+    let resolved = query.resolved.clone();
+    let schema = Schema::build(query, EmptyMutation, EmptySubscription)
+        .limit_resolved_fields(11)
+        .finish();
+
+    // The field `items` and the `id` of 10 items.
+    let res = schema
+        .execute("{ items(count: 10) { id __typename } }")
+        .await;
+    assert!(res.is_ok());
+    assert_eq!(resolved.get(), 10);
+
+    resolved.set(0);
+    let res = schema.execute("{ items(count: 1000) { id } }").await;
+    assert_eq!(res.data, Value::Null);
+    let mut extensions = ErrorExtensionValues::default();
+    extensions.set("code", "FIELD_BUDGET_EXCEEDED");
+    assert_eq!(
+        res.errors,
+        vec![ServerError {
+            message: "The request resolves more than 11 fields.".to_string(),
+            locations: vec![Pos {
+                line: 1,
+                column: 24
+            }],
+            path: vec![
+                PathSegment::Field("items".to_string()),
+                PathSegment::Index(10),
+                PathSegment::Field("id".to_string()),
+            ],
+            extensions: Some(extensions),
+        }]
+    );
+    assert_eq!(resolved.get(), 10);
+}
@@ -0,0 +1,85 @@
+use async_graphql::extensions::{FieldUsage, FieldUsageRecord};
+use async_graphql::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(SimpleObject)]
+struct User {
+    name: String,
+    email: String,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn me(&self) -> User {
+        User {
+            name: "me".to_string(),
+            email: "me@example.com".to_string(),
+        }
+    }
+
+    async fn value(&self) -> i32 {
+        10
+    }
+}
+
+fn record(operation_name: Option<&str>, fields: &[&str]) -> FieldUsageRecord {
+    FieldUsageRecord {
+        operation_name: operation_name.map(ToString::to_string),
+        fields: fields.iter().map(ToString::to_string).collect(),
+    }
+}
+
+#[async_std::test]
+pub async fn test_field_usage() {
+    let batches = Rc::new(RefCell::new(Vec::new()));
+    let usage = FieldUsage::new({
+        let batches = batches.clone();
+        move |batch: Vec<FieldUsageRecord>| batches.borrow_mut().push(batch)
+    })
+    .batch_size(2);
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(usage.clone())
+        .limit_depth(2)
+        .finish();
+
+    schema
+        .execute("{ me { ...UserFields } } fragment UserFields on User { name }")
+        .await
+        .into_result()
+        .unwrap();
+    assert!(batches.borrow().is_empty());
+
+    // Invalid requests and requests over the limits are not recorded.
+    assert!(schema.execute("{ unknown }").await.is_err());
+    assert!(schema.execute("{ me { me { name } } }").await.is_err());
+
+    schema
+        .execute(Request::new("query A { value } query B { me { email } }").operation_name("B"))
+        .await
+        .into_result()
+        .unwrap();
+    assert_eq!(
+        *batches.borrow(),
+        vec![vec![
+            record(None, &["Query.me", "User.name"]),
+            record(Some("B"), &["Query.me", "Query.value", "User.email"]),
+        ]]
+    );
+
+    schema.execute("{ value }").await.into_result().unwrap();
+    assert_eq!(batches.borrow().len(), 1);
+    usage.flush();
+    assert_eq!(batches.borrow()[1], vec![record(None, &["Query.value"])]);
+
+    schema
+        .execute("{ value __typename }")
+        .await
+        .into_result()
+        .unwrap();
+    drop(schema);
+    drop(usage);
+    assert_eq!(batches.borrow()[2], vec![record(None, &["Query.value"])]);
+}
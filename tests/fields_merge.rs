@@ -82,3 +82,56 @@ pub async fn test_field_object_merge() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_field_merge_resolves_once() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[derive(SimpleObject)]
+    struct MyObject {
+        a: i32,
+        b: i32,
+    }
+
+    struct Query {
+        calls: Rc<Cell<i32>>,
+    }
+
+    #[Object]
+    impl Query {
+        async fn obj(&self, n: i32) -> MyObject {
+            self.calls.set(self.calls.get() + 1);
+            MyObject { a: n, b: n + 1 }
+        }
+    }
+
+    let query = r#"
+        {
+            obj(n: 1) { a }
+            ... { obj(n: 1) { b } }
+            ... A
+            other: obj(n: 10) { a }
+        }
+
+        fragment A on Query {
+            obj(n: 1) { a b }
+        }
+    "#;
+    let calls = Rc::new(Cell::new(0));
+    let schema = Schema::new(
+        Query {
+            calls: calls.clone(),
+        },
+        EmptyMutation,
+        EmptySubscription,
+    );
+    assert_eq!(
+        schema.execute(query).await.data,
+        value!({
+            "obj": { "a": 1, "b": 2 },
+            "other": { "a": 10 },
+        })
+    );
+    assert_eq!(calls.get(), 2);
+}
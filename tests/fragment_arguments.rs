@@ -0,0 +1,159 @@
+use async_graphql::*;
+
+struct User;
+
+#[Object]
+impl User {
+    async fn avatar(&self, #[graphql(default = 16)] size: i32) -> String {
+        format!("avatar-{}", size)
+    }
+
+    async fn name(&self, upper: bool) -> String {
+        if upper {
+            "USER".to_string()
+        } else {
+            "user".to_string()
+        }
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn user(&self) -> User {
+        User
+    }
+}
+
+fn schema() -> Schema<Query, EmptyMutation, EmptySubscription> {
+    Schema::new(Query, EmptyMutation, EmptySubscription)
+}
+
+async fn error_messages(query: &str) -> Vec<String> {
+    let mut messages: Vec<_> = schema()
+        .execute(query)
+        .await
+        .errors
+        .into_iter()
+        .map(|err| err.message)
+        .collect();
+    messages.sort();
+    messages
+}
+
+#[cfg(feature = "fragment_arguments")]
+#[async_std::test]
+pub async fn test_fragment_arguments() {
+    let query = r#"
+        query($upper: Boolean!) {
+            user {
+                ...Avatar(size: 64)
+                ...Avatar
+                ...Name(upper: $upper)
+            }
+            other: user {
+                ...Name(upper: false) @skip(if: $upper)
+                ...Nested(size: 128)
+            }
+        }
+
+        fragment Avatar($size: Int = 32) on User {
+            avatar(size: $size)
+        }
+
+        fragment Name($upper: Boolean!) on User {
+            name(upper: $upper)
+        }
+
+        fragment Nested($size: Int) on User {
+            ...Avatar(size: $size)
+            small: avatar(size: $size)
+            ...Unset
+        }
+
+        fragment Unset($unset: Int) on User {
+            unset: avatar(size: $unset)
+        }
+    "#;
+    let request = Request::new(query).variables(Variables::from_value(value!({
+        "upper": true,
+    })));
+    assert_eq!(
+        schema().execute(request).await.into_result().unwrap().data,
+        value!({
+            "user": {
+                "avatar": "avatar-64",
+                "name": "USER",
+            },
+            "other": {
+                "avatar": "avatar-128",
+                "small": "avatar-128",
+                "unset": "avatar-16",
+            },
+        })
+    );
+}
+
+#[cfg(feature = "fragment_arguments")]
+#[async_std::test]
+pub async fn test_fragment_arguments_validation() {
+    assert_eq!(
+        error_messages(
+            r#"{ user { ...Avatar(size: "big", unknown: 1) } }
+            fragment Avatar($size: Int) on User { avatar(size: $size) }"#
+        )
+        .await,
+        vec![
+            r#"Invalid value for argument "size", expected type "Int""#,
+            r#"Unknown argument "unknown" on fragment "Avatar"."#,
+        ]
+    );
+
+    assert_eq!(
+        error_messages(
+            r#"{ user { ...Name } }
+            fragment Name($upper: Boolean!, $unused: Int) on User { name(upper: $upper) }"#
+        )
+        .await,
+        vec![
+            r#"Fragment "Name" argument "upper" of type "Boolean!" is required but not provided"#,
+            r#"Variable "$unused" is not used by fragment "Name""#,
+        ]
+    );
+
+    assert_eq!(
+        error_messages(
+            r#"query($upper: Boolean) { user { ...Name(upper: $upper) ...Name(upper: $undefined) } }
+            fragment Name($upper: Boolean!) on User { name(upper: $upper) }"#
+        )
+        .await,
+        vec![
+            r#"Variable "$undefined" is not defined"#,
+            r#"Variable "upper" of type "Boolean" used in position expecting type "Boolean!""#,
+        ]
+    );
+
+    assert_eq!(
+        error_messages(
+            r#"{ user { ...Avatar } }
+            fragment Avatar($size: Boolean) on User { avatar(size: $size) }"#
+        )
+        .await,
+        vec![r#"Variable "size" of type "Boolean" used in position expecting type "Int""#]
+    );
+}
+
+#[cfg(not(feature = "fragment_arguments"))]
+#[async_std::test]
+pub async fn test_fragment_arguments_disabled() {
+    let query = r#"{ user { ...Avatar(size: 64) } }
+        fragment Avatar($size: Int) on User { avatar(size: $size) }"#;
+    assert_eq!(
+        error_messages(query).await,
+        vec![
+            r#"Fragment "Avatar" cannot define variables, fragment arguments are not enabled"#,
+            r#"Fragment spread "Avatar" cannot have arguments, fragment arguments are not enabled"#,
+        ]
+    );
+}
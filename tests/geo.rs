@@ -0,0 +1,75 @@
+#[cfg(feature = "geo")]
+#[async_std::test]
+pub async fn test_geo_scalars() {
+    use async_graphql::*;
+    use geo_types::{LineString, Point, Polygon};
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn centroid(&self, area: Polygon<f64>) -> Point<f64> {
+            let exterior = area.exterior();
+            let n = (exterior.0.len() - 1) as f64;
+            let (x, y) = exterior.0[1..]
+                .iter()
+                .fold((0.0, 0.0), |(x, y), c| (x + c.x, y + c.y));
+            Point::new(x / n, y / n)
+        }
+
+        async fn route(&self) -> GeoObject<LineString<f64>> {
+            GeoObject(vec![(4.89, 52.37), (2.35, 48.86)].into())
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    centroid(area: { type: "Polygon", coordinates: [[[0, 0], [4, 0], [4, 2], [0, 2], [0, 0]]] })
+                    route { type coordinates }
+                    __type(name: "Point") { kind description }
+                }"#
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "centroid": { "type": "Point", "coordinates": [2.0, 1.0] },
+            "route": { "type": "LineString", "coordinates": [[4.89, 52.37], [2.35, 48.86]] },
+            "__type": {
+                "kind": "SCALAR",
+                "description": r#"A GeoJSON `Point` geometry, a position, e.g. `{ "type": "Point", "coordinates": ... }`."#,
+            },
+        })
+    );
+
+    for (area, message) in &[
+        (
+            r#"{ type: "Point", coordinates: [0, 0] }"#,
+            r#"The type of the geometry must be "Polygon"."#,
+        ),
+        (
+            r#"{ type: "Polygon", coordinates: [[[0, 0], [4, 0], [4, 95], [0, 0]]] }"#,
+            "The latitude 95 is not from -90 to 90.",
+        ),
+        (
+            r#"{ type: "Polygon", coordinates: [[[0, 0], [4, 0], [4, 2], [0, 2]]] }"#,
+            "A linear ring must end with its first position.",
+        ),
+        (
+            r#"{ type: "Polygon", coordinates: [[[0, 0, 1], [4, 0], [4, 2], [0, 0]]] }"#,
+            "A position must have a longitude and a latitude, found 3 numbers.",
+        ),
+    ] {
+        let res = schema
+            .execute(format!("{{ centroid(area: {}) }}", area))
+            .await;
+        assert_eq!(
+            res.errors[0].message,
+            format!(r#"Failed to parse "Polygon": {}"#, message)
+        );
+    }
+}
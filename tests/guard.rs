@@ -12,7 +12,7 @@ struct RoleGuard {
     role: Role,
 }
 
-#[async_trait::async_trait]
+#[async_trait::async_trait(?Send)]
 impl Guard for RoleGuard {
     async fn check(&self, ctx: &Context<'_>) -> Result<()> {
         if ctx.data_opt::<Role>() == Some(&self.role) {
@@ -29,7 +29,7 @@ struct UserGuard {
     username: String,
 }
 
-#[async_trait::async_trait]
+#[async_trait::async_trait(?Send)]
 impl Guard for UserGuard {
     async fn check(&self, ctx: &Context<'_>) -> Result<()> {
         if ctx.data_opt::<Username>().map(|name| &name.0).as_deref() == Some(&self.username) {
@@ -46,7 +46,7 @@ struct AgeGuard {
     age: i32,
 }
 
-#[async_trait::async_trait]
+#[async_trait::async_trait(?Send)]
 impl Guard for AgeGuard {
     async fn check(&self, ctx: &Context<'_>) -> Result<()> {
         if ctx.data_opt::<Age>().map(|name| &name.0) == Some(&self.age) {
@@ -117,7 +117,7 @@ pub async fn test_guard_simple_rule() {
     assert_eq!(
         schema
             .execute_stream(Request::new("subscription { values }").data(Role::Guest))
-            .boxed()
+            .boxed_local()
             .next()
             .await
             .unwrap()
@@ -287,6 +287,51 @@ pub async fn test_guard_or_operator() {
     );
 }
 
+#[async_std::test]
+pub async fn test_guard_or_short_circuit() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    struct CountGuard;
+
+    #[async_trait::async_trait(?Send)]
+    impl Guard for CountGuard {
+        async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+            let count = ctx.data_unchecked::<Rc<Cell<usize>>>();
+            count.set(count.get() + 1);
+            Ok(())
+        }
+    }
+
+    #[derive(SimpleObject)]
+    struct Query {
+        #[graphql(guard(or(RoleGuard(role = "Role::Admin"), CountGuard)))]
+        value: i32,
+    }
+
+    let schema = Schema::new(Query { value: 10 }, EmptyMutation, EmptySubscription);
+    let count = Rc::new(Cell::new(0usize));
+
+    let query = "{ value }";
+    assert_eq!(
+        schema
+            .execute(Request::new(query).data(Role::Admin).data(count.clone()))
+            .await
+            .data,
+        value!({"value": 10})
+    );
+    assert_eq!(count.get(), 0);
+
+    assert_eq!(
+        schema
+            .execute(Request::new(query).data(Role::Guest).data(count.clone()))
+            .await
+            .data,
+        value!({"value": 10})
+    );
+    assert_eq!(count.get(), 1);
+}
+
 #[async_std::test]
 pub async fn test_guard_chain_operator() {
     #[derive(SimpleObject)]
@@ -486,3 +531,303 @@ pub async fn test_guard_race_operator() {
         }]
     );
 }
+
+#[async_std::test]
+pub async fn test_guard_expression() {
+    struct OwnerGuard {
+        owner: String,
+    }
+
+    impl OwnerGuard {
+        fn new(owner: &str) -> Self {
+            Self {
+                owner: owner.to_string(),
+            }
+        }
+    }
+
+    #[async_trait::async_trait(?Send)]
+    impl Guard for OwnerGuard {
+        async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+            if ctx.data_opt::<Username>().map(|name| &name.0) == Some(&self.owner) {
+                Ok(())
+            } else {
+                Err("Forbidden".into())
+            }
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        #[graphql(guard = "RoleGuard { role: Role::Admin }.or(OwnerGuard::new(owner))")]
+        async fn profile(&self, owner: String) -> String {
+            owner
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"{ profile(owner: "alice") }"#;
+
+    assert_eq!(
+        schema
+            .execute(Request::new(query).data(Role::Admin))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "profile": "alice" })
+    );
+
+    assert_eq!(
+        schema
+            .execute(
+                Request::new(query)
+                    .data(Role::Guest)
+                    .data(Username("alice".to_string()))
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "profile": "alice" })
+    );
+
+    assert_eq!(
+        schema
+            .execute(
+                Request::new(query)
+                    .data(Role::Guest)
+                    .data(Username("bob".to_string()))
+            )
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: "Forbidden".to_string(),
+            locations: vec![Pos { line: 1, column: 3 }],
+            path: vec![PathSegment::Field("profile".to_owned())],
+            extensions: None,
+        }]
+    );
+}
+
+#[async_std::test]
+pub async fn test_guard_on_type() {
+    #[derive(SimpleObject)]
+    #[graphql(guard(RoleGuard(role = "Role::Admin")))]
+    struct Secret {
+        value: i32,
+    }
+
+    struct AdminQuery;
+
+    #[Object(guard(RoleGuard(role = "Role::Admin")))]
+    impl AdminQuery {
+        async fn admin_value(&self) -> i32 {
+            1
+        }
+
+        async fn secret(&self) -> Secret {
+            Secret { value: 2 }
+        }
+    }
+
+    #[derive(SimpleObject)]
+    struct PublicQuery {
+        public_value: i32,
+    }
+
+    #[derive(MergedObject)]
+    struct Query(AdminQuery, PublicQuery);
+
+    let schema = Schema::new(
+        Query(AdminQuery, PublicQuery { public_value: 3 }),
+        EmptyMutation,
+        EmptySubscription,
+    );
+
+    assert_eq!(
+        schema
+            .execute(Request::new("{ adminValue secret { value } publicValue }").data(Role::Admin))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "adminValue": 1, "secret": { "value": 2 }, "publicValue": 3 })
+    );
+
+    assert_eq!(
+        schema
+            .execute(Request::new("{ publicValue }").data(Role::Guest))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "publicValue": 3 })
+    );
+
+    assert_eq!(
+        schema
+            .execute(Request::new("{ adminValue }").data(Role::Guest))
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: "Forbidden".to_string(),
+            locations: vec![Pos { line: 1, column: 3 }],
+            path: vec![PathSegment::Field("adminValue".to_owned())],
+            extensions: None,
+        }]
+    );
+
+    #[derive(MergedObject)]
+    #[graphql(guard(RoleGuard(role = "Role::Admin")))]
+    struct GuardedQuery(PublicQuery);
+
+    let schema = Schema::new(
+        GuardedQuery(PublicQuery { public_value: 3 }),
+        EmptyMutation,
+        EmptySubscription,
+    );
+
+    assert_eq!(
+        schema
+            .execute(Request::new("{ publicValue }").data(Role::Guest))
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: "Forbidden".to_string(),
+            locations: vec![Pos { line: 1, column: 3 }],
+            path: vec![PathSegment::Field("publicValue".to_owned())],
+            extensions: None,
+        }]
+    );
+}
+
+#[async_std::test]
+pub async fn test_guard_not_lists_and_errors() {
+    use async_graphql::guard::{AllGuard, RaceGuard};
+
+    struct CodeGuard;
+
+    #[async_trait::async_trait(?Send)]
+    impl Guard for CodeGuard {
+        async fn check(&self, _ctx: &Context<'_>) -> Result<()> {
+            Err(Error::new("Forbidden").extend_with(|_, e| e.set("code", "FORBIDDEN")))
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        #[graphql(guard(not(RoleGuard(role = "Role::Admin"))))]
+        async fn not_admin(&self) -> i32 {
+            1
+        }
+
+        #[graphql(
+            guard = "RaceGuard::new(vec![Box::new(RoleGuard { role: Role::Admin }), \
+                          Box::new(CodeGuard)])"
+        )]
+        async fn any(&self) -> i32 {
+            2
+        }
+
+        #[graphql(
+            guard = "AllGuard::new(vec![Box::new(RoleGuard { role: Role::Admin }), \
+                          Box::new(CodeGuard)])"
+        )]
+        async fn all(&self) -> i32 {
+            3
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema
+            .execute(Request::new("{ notAdmin any }").data(Role::Guest))
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: "Forbidden".to_string(),
+            locations: vec![Pos {
+                line: 1,
+                column: 12
+            }],
+            path: vec![PathSegment::Field("any".to_owned())],
+            extensions: Some({
+                let mut extensions = ErrorExtensionValues::default();
+                extensions.set("code", "FORBIDDEN");
+                extensions
+            }),
+        }]
+    );
+
+    assert_eq!(
+        schema
+            .execute(Request::new("{ notAdmin }").data(Role::Guest))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "notAdmin": 1 })
+    );
+
+    assert_eq!(
+        schema
+            .execute(Request::new("{ any }").data(Role::Admin))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "any": 2 })
+    );
+
+    assert_eq!(
+        schema
+            .execute(Request::new("{ notAdmin }").data(Role::Admin))
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: "Forbidden".to_string(),
+            locations: vec![Pos { line: 1, column: 3 }],
+            path: vec![PathSegment::Field("notAdmin".to_owned())],
+            extensions: None,
+        }]
+    );
+
+    assert_eq!(
+        schema
+            .execute(Request::new("{ all }").data(Role::Admin))
+            .await
+            .into_result()
+            .unwrap_err()[0]
+            .message,
+        "Forbidden"
+    );
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .hide_forbidden_fields()
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute(Request::new("{ all }").data(Role::Admin))
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Unknown field "all" on type "Query"."#.to_string(),
+            locations: vec![Pos { line: 1, column: 3 }],
+            path: vec![PathSegment::Field("all".to_owned())],
+            extensions: None,
+        }]
+    );
+}
@@ -0,0 +1,125 @@
+use async_graphql::extensions::{Extension, ExtensionFactory};
+use async_graphql::parser::types::Field;
+use async_graphql::registry::Registry;
+use async_graphql::*;
+use std::borrow::Cow;
+
+struct Noop;
+
+impl Extension for Noop {}
+
+impl ExtensionFactory for Noop {
+    fn create(&self) -> Box<dyn Extension> {
+        Box::new(Noop)
+    }
+}
+
+struct Broken;
+
+impl ExtensionFactory for Broken {
+    fn create(&self) -> Box<dyn Extension> {
+        panic!("missing configuration")
+    }
+}
+
+#[async_std::test]
+pub async fn test_check_health() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(Noop)
+        .finish();
+    let status = schema.check_health().await;
+    assert!(status.healthy);
+    assert_eq!(
+        serde_json::to_value(&status).unwrap(),
+        serde_json::json!({
+            "healthy": true,
+            "checks": [
+                { "name": "extensions", "healthy": true },
+                { "name": "query", "healthy": true },
+                { "name": "registry", "healthy": true },
+            ],
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_check_health_extension_panics() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(Noop)
+        .extension(Broken)
+        .finish();
+    let status = schema.check_health().await;
+    assert!(!status.healthy);
+    assert_eq!(
+        status.checks[0].errors,
+        vec![r#"Extension "health::Broken" can't be created."#]
+    );
+    assert_eq!(
+        status.checks[1].errors,
+        vec!["Not executed, an extension can't be created."]
+    );
+    assert!(status.checks[2].healthy);
+}
+
+#[async_std::test]
+pub async fn test_check_health_unregistered_type() {
+    struct Ghost;
+
+    impl Type for Ghost {
+        fn type_name() -> Cow<'static, str> {
+            Cow::Borrowed("Ghost")
+        }
+
+        fn create_type_info(_registry: &mut Registry) -> String {
+            Self::qualified_type_name()
+        }
+    }
+
+    #[async_trait::async_trait(?Send)]
+    impl OutputValueType for Ghost {
+        async fn resolve(
+            &self,
+            _ctx: &ContextSelectionSet<'_>,
+            _field: &Positioned<Field>,
+        ) -> ServerResult<Value> {
+            Ok(Value::Null)
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn ghost(&self) -> Ghost {
+            Ghost
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let status = schema.check_health().await;
+    assert!(!status.healthy);
+    assert!(status.checks[0].healthy);
+    assert!(status.checks[1].healthy);
+    assert_eq!(
+        status.checks[2].errors,
+        vec![r#"Type "Ghost" of field "Query.ghost" is not registered."#]
+    );
+}
@@ -0,0 +1,248 @@
+use async_graphql::idempotency::{IdempotencyStore, MemoryIdempotencyStore, StoredResponse};
+use async_graphql::runtime::Timer;
+use async_graphql::*;
+use futures_util::future::{self, LocalBoxFuture};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A timer whose clock only moves when it is advanced.
+#[derive(Clone, Default)]
+struct ManualTimer(Arc<Mutex<Duration>>);
+
+impl ManualTimer {
+    fn advance(&self, duration: Duration) {
+        *self.0.lock().unwrap() += duration;
+    }
+}
+
+impl Timer for ManualTimer {
+    fn sleep(&self, _duration: Duration) -> LocalBoxFuture<'static, ()> {
+        Box::pin(future::pending())
+    }
+
+    fn now(&self) -> Duration {
+        *self.0.lock().unwrap()
+    }
+}
+
+fn memory_store() -> MemoryIdempotencyStore {
+    MemoryIdempotencyStore::new(ManualTimer::default())
+}
+
+#[derive(Default)]
+struct Counter(Cell<i32>);
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn value(&self, ctx: &Context<'_>) -> i32 {
+        let counter = ctx.data_unchecked::<Rc<Counter>>();
+        counter.0.set(counter.0.get() + 1);
+        counter.0.get()
+    }
+}
+
+struct Mutation;
+
+#[Object]
+impl Mutation {
+    async fn increment(&self, ctx: &Context<'_>) -> i32 {
+        let counter = ctx.data_unchecked::<Rc<Counter>>();
+        counter.0.set(counter.0.get() + 1);
+        counter.0.get()
+    }
+
+    async fn fail(&self, ctx: &Context<'_>) -> Result<i32> {
+        let counter = ctx.data_unchecked::<Rc<Counter>>();
+        counter.0.set(counter.0.get() + 1);
+        Err(format!("Failure {}", counter.0.get()).into())
+    }
+}
+
+fn schema(
+    store: impl IdempotencyStore,
+    ttl: Duration,
+) -> (Schema<Query, Mutation, EmptySubscription>, Rc<Counter>) {
+    schema_with(Schema::build(Query, Mutation, EmptySubscription).idempotency_store(store, ttl))
+}
+
+fn schema_with(
+    builder: SchemaBuilder<Query, Mutation, EmptySubscription>,
+) -> (Schema<Query, Mutation, EmptySubscription>, Rc<Counter>) {
+    let counter = Rc::new(Counter::default());
+    let schema = builder.data(counter.clone()).finish();
+    (schema, counter)
+}
+
+fn request(query: &str, key: impl Into<Value>) -> Request {
+    let mut request = Request::new(query);
+    request
+        .extensions
+        .insert("idempotencyKey".to_string(), key.into());
+    request
+}
+
+#[async_std::test]
+pub async fn test_idempotency_key() {
+    let (schema, counter) = schema(memory_store(), Duration::from_secs(60));
+
+    let resp = schema.execute(request("mutation { increment }", "a")).await;
+    assert_eq!(resp.data, value!({ "increment": 1 }));
+    let resp = schema.execute(request("mutation { increment }", "a")).await;
+    assert_eq!(resp.data, value!({ "increment": 1 }));
+    assert_eq!(counter.0.get(), 1);
+
+    let resp = schema.execute(request("mutation { increment }", "b")).await;
+    assert_eq!(resp.data, value!({ "increment": 2 }));
+    let resp = schema.execute("mutation { increment }").await;
+    assert_eq!(resp.data, value!({ "increment": 3 }));
+
+    // Errors are replayed as well.
+    let resp = schema.execute(request("mutation { fail }", "c")).await;
+    assert_eq!(resp.errors[0].message, "Failure 4");
+    let resp = schema.execute(request("mutation { fail }", "c")).await;
+    assert_eq!(resp.errors[0].message, "Failure 4");
+    assert_eq!(counter.0.get(), 4);
+
+    // The key is ignored for queries.
+    let resp = schema.execute(request("{ value }", "d")).await;
+    assert_eq!(resp.data, value!({ "value": 5 }));
+    let resp = schema.execute(request("{ value }", "d")).await;
+    assert_eq!(resp.data, value!({ "value": 6 }));
+
+    let resp = schema.execute(request("mutation { increment }", 1)).await;
+    assert_eq!(
+        resp.errors
+            .into_iter()
+            .map(|err| err.message)
+            .collect::<Vec<_>>(),
+        vec![r#"Invalid "idempotencyKey" extension, expected a string."#]
+    );
+    assert_eq!(counter.0.get(), 6);
+}
+
+#[async_std::test]
+pub async fn test_idempotency_key_expired() {
+    let timer = ManualTimer::default();
+    let store = MemoryIdempotencyStore::new(timer.clone());
+    let (schema, counter) = schema(store, Duration::from_secs(60));
+
+    schema.execute(request("mutation { increment }", "a")).await;
+    timer.advance(Duration::from_secs(59));
+    let resp = schema.execute(request("mutation { increment }", "a")).await;
+    assert_eq!(resp.data, value!({ "increment": 1 }));
+    timer.advance(Duration::from_secs(1));
+    let resp = schema.execute(request("mutation { increment }", "a")).await;
+    assert_eq!(resp.data, value!({ "increment": 2 }));
+    assert_eq!(counter.0.get(), 2);
+}
+
+#[async_std::test]
+pub async fn test_idempotency_ttl_overflow() {
+    // A ttl that overflows the clock never expires.
+    let timer = ManualTimer::default();
+    timer.advance(Duration::from_secs(60));
+    let store = MemoryIdempotencyStore::new(timer.clone());
+    let (schema, counter) = schema(store, Duration::MAX);
+    schema.execute(request("mutation { increment }", "a")).await;
+    timer.advance(Duration::from_secs(3600));
+    schema.execute(request("mutation { increment }", "a")).await;
+    assert_eq!(counter.0.get(), 1);
+}
+
+#[async_std::test]
+pub async fn test_idempotency_key_reused() {
+    let (schema, counter) = schema(memory_store(), Duration::from_secs(60));
+
+    schema.execute(request("mutation { increment }", "a")).await;
+    let resp = schema.execute(request("mutation { fail }", "a")).await;
+    assert_eq!(
+        resp.errors[0].message,
+        r#"The "idempotencyKey" was already used by another request."#
+    );
+    let resp = schema
+        .execute(
+            request("mutation { increment }", "a")
+                .variables(Variables::from_value(value!({ "a": 1 }))),
+        )
+        .await;
+    assert_eq!(
+        resp.errors[0].message,
+        r#"The "idempotencyKey" was already used by another request."#
+    );
+    assert_eq!(counter.0.get(), 1);
+}
+
+#[async_std::test]
+pub async fn test_idempotency_key_by() {
+    struct User(&'static str);
+
+    let (schema, counter) = schema_with(
+        Schema::build(Query, Mutation, EmptySubscription)
+            .idempotency_store(memory_store(), Duration::from_secs(60))
+            .idempotency_key_by(|request| {
+                let user = request
+                    .data
+                    .get(&std::any::TypeId::of::<User>())?
+                    .downcast_ref::<User>()?;
+                match request.extensions.get("idempotencyKey") {
+                    Some(Value::String(key)) => Some(format!("{}:{}", user.0, key)),
+                    _ => None,
+                }
+            }),
+    );
+
+    let resp = schema
+        .execute(request("mutation { increment }", "a").data(User("alice")))
+        .await;
+    assert_eq!(resp.data, value!({ "increment": 1 }));
+    let resp = schema
+        .execute(request("mutation { increment }", "a").data(User("bob")))
+        .await;
+    assert_eq!(resp.data, value!({ "increment": 2 }));
+    let resp = schema
+        .execute(request("mutation { increment }", "a").data(User("alice")))
+        .await;
+    assert_eq!(resp.data, value!({ "increment": 1 }));
+
+    // The requests without user are executed without idempotency.
+    schema.execute(request("mutation { increment }", "a")).await;
+    let resp = schema.execute(request("mutation { increment }", "a")).await;
+    assert_eq!(resp.data, value!({ "increment": 4 }));
+    assert_eq!(counter.0.get(), 4);
+}
+
+#[async_std::test]
+pub async fn test_custom_idempotency_store() {
+    #[derive(Clone)]
+    struct LoggingStore(Arc<Mutex<Vec<String>>>, MemoryIdempotencyStore);
+
+    #[async_trait::async_trait(?Send)]
+    impl IdempotencyStore for LoggingStore {
+        async fn get(&self, key: &str) -> Option<StoredResponse> {
+            self.0.lock().unwrap().push(format!("get {}", key));
+            self.1.get(key).await
+        }
+
+        async fn set(&self, key: String, response: StoredResponse, ttl: Duration) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("set {} {}s", key, ttl.as_secs()));
+            self.1.set(key, response, ttl).await
+        }
+    }
+
+    let store = LoggingStore(Default::default(), memory_store());
+    let (schema, _) = schema(store.clone(), Duration::from_secs(30));
+    schema.execute(request("mutation { increment }", "a")).await;
+    schema.execute(request("mutation { increment }", "a")).await;
+    schema.execute(request("{ value }", "a")).await;
+    assert_eq!(
+        *store.0.lock().unwrap(),
+        vec!["get a", "set a 30s", "get a"]
+    );
+}
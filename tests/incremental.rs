@@ -0,0 +1,189 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use async_graphql::*;
+use futures_util::stream::{self, StreamExt};
+
+struct Produced(Rc<Cell<i32>>);
+
+struct Item(i32);
+
+#[Object]
+impl Item {
+    async fn value(&self) -> Result<i32> {
+        if self.0 < 0 {
+            Err("negative".into())
+        } else {
+            Ok(self.0)
+        }
+    }
+
+    async fn children(&self) -> ListStream<Item> {
+        let value = self.0;
+        ListStream::new(stream::iter(1..=2).map(move |n| Item(value * 10 + n)))
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn numbers(&self, ctx: &Context<'_>, count: i32) -> ListStream<i32> {
+        let produced = ctx.data_unchecked::<Produced>().0.clone();
+        ListStream::new(stream::iter(0..count).map(move |n| {
+            produced.set(produced.get() + 1);
+            n
+        }))
+    }
+
+    async fn items(&self, values: Vec<i32>) -> ListStream<Item> {
+        ListStream::new(stream::iter(values).map(Item))
+    }
+}
+
+fn schema() -> (
+    Schema<Query, EmptyMutation, EmptySubscription>,
+    Rc<Cell<i32>>,
+) {
+    let produced = Rc::new(Cell::new(0));
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(Produced(produced.clone()))
+        .finish();
+    (schema, produced)
+}
+
+async fn execute_incremental(
+    schema: &Schema<Query, EmptyMutation, EmptySubscription>,
+    query: &str,
+) -> Vec<serde_json::Value> {
+    schema
+        .execute_incremental(query)
+        .map(|resp| serde_json::to_value(resp).unwrap())
+        .collect()
+        .await
+}
+
+#[async_std::test]
+pub async fn test_list_stream_without_stream_directive() {
+    let (schema, _) = schema();
+    assert_eq!(
+        schema
+            .execute("{ numbers(count: 3) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "numbers": [0, 1, 2] })
+    );
+    assert_eq!(
+        schema
+            .execute("{ numbers(count: 3) @stream(initialCount: 1) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "numbers": [0, 1, 2] })
+    );
+    assert_eq!(
+        execute_incremental(&schema, "{ numbers(count: 3) }").await,
+        vec![serde_json::json!({ "data": { "numbers": [0, 1, 2] }, "hasNext": false })]
+    );
+    assert_eq!(
+        execute_incremental(
+            &schema,
+            "{ numbers(count: 3) @stream(if: false, initialCount: 1) }"
+        )
+        .await,
+        vec![serde_json::json!({ "data": { "numbers": [0, 1, 2] }, "hasNext": false })]
+    );
+}
+
+#[async_std::test]
+pub async fn test_list_stream_payloads() {
+    let (schema, _) = schema();
+    assert_eq!(
+        execute_incremental(
+            &schema,
+            r#"{ numbers(count: 3) @stream(initialCount: 1, label: "numbers") }"#
+        )
+        .await,
+        vec![
+            serde_json::json!({ "data": { "numbers": [0] }, "hasNext": true }),
+            serde_json::json!({
+                "items": [1],
+                "path": ["numbers", 1],
+                "label": "numbers",
+                "hasNext": true,
+            }),
+            serde_json::json!({
+                "items": [2],
+                "path": ["numbers", 2],
+                "label": "numbers",
+                "hasNext": true,
+            }),
+            serde_json::json!({ "hasNext": false }),
+        ]
+    );
+}
+
+#[async_std::test]
+pub async fn test_list_stream_backpressure() {
+    let (schema, produced) = schema();
+    let mut stream = schema
+        .execute_incremental("{ numbers(count: 100) @stream(initialCount: 2) }")
+        .boxed_local();
+
+    stream.next().await.unwrap();
+    assert_eq!(produced.get(), 2);
+    stream.next().await.unwrap();
+    assert_eq!(produced.get(), 3);
+    stream.next().await.unwrap();
+    assert_eq!(produced.get(), 4);
+}
+
+#[async_std::test]
+pub async fn test_list_stream_errors_and_nested_streams() {
+    let (schema, _) = schema();
+    assert_eq!(
+        execute_incremental(
+            &schema,
+            "{ items(values: [1, -2]) @stream { value children @stream(initialCount: 1) { value } } }"
+        )
+        .await,
+        vec![
+            serde_json::json!({ "data": { "items": [] }, "hasNext": true }),
+            serde_json::json!({
+                "items": [{ "value": 1, "children": [{ "value": 11 }] }],
+                "path": ["items", 0],
+                "hasNext": true,
+            }),
+            serde_json::json!({
+                "items": [{ "value": 12 }],
+                "path": ["items", 0, "children", 1],
+                "hasNext": true,
+            }),
+            serde_json::json!({
+                "items": null,
+                "path": ["items", 1],
+                "errors": [{
+                    "message": "negative",
+                    "locations": [{ "line": 1, "column": 36 }],
+                    "path": ["items", 1, "value"],
+                }],
+                "hasNext": true,
+            }),
+            serde_json::json!({ "hasNext": false }),
+        ]
+    );
+}
+
+#[async_std::test]
+pub async fn test_list_stream_negative_initial_count() {
+    let (schema, _) = schema();
+    let responses =
+        execute_incremental(&schema, "{ numbers(count: 3) @stream(initialCount: -1) }").await;
+    assert_eq!(
+        responses[0]["errors"][0]["message"],
+        "The initialCount of @stream must not be negative."
+    );
+}
@@ -287,3 +287,66 @@ pub async fn test_inputobject_flatten_multiple() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_inputobject_validator() {
+    #[derive(InputObject)]
+    #[graphql(validator = "validate_range")]
+    struct Range {
+        start: i32,
+        end: i32,
+    }
+
+    fn validate_range(range: &Range) -> Result<(), InputValueError<Range>> {
+        if range.start >= range.end {
+            return Err(InputValueError::custom("end must be greater than start").with_field("end"));
+        }
+        Ok(())
+    }
+
+    #[derive(InputObject)]
+    struct Filter {
+        range: Range,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn len(&self, filter: Filter) -> i32 {
+            filter.range.end - filter.range.start
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ len(filter: { range: { start: 1, end: 5 } }) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "len": 4 })
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ len(filter: { range: { start: 5, end: 1 } }) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "Range": end must be greater than start (at field "end") (occurred while parsing "Filter")"#.to_owned(),
+            locations: vec![Pos {
+                line: 1,
+                column: 15
+            }],
+            path: vec![PathSegment::Field("len".to_owned())],
+            extensions: Some({
+                let mut extensions = ErrorExtensionValues::default();
+                extensions.set("inputPath", vec!["range", "end"]);
+                extensions
+            }),
+        }]
+    );
+}
@@ -1,6 +1,7 @@
 use async_graphql::validators::{
-    Email, IntEqual, IntGreaterThan, IntLessThan, IntNonZero, IntRange, ListMaxLength,
-    ListMinLength, StringMaxLength, StringMinLength, MAC,
+    CharsMaxLength, CharsMinLength, CustomValidator, Email, IntEqual, IntGreaterThan, IntLessThan,
+    IntNonZero, IntRange, Ip, ListMaxLength, ListMinLength, Regex, StringMaxLength,
+    StringMinLength, Url, MAC,
 };
 use async_graphql::*;
 
@@ -1772,3 +1773,204 @@ pub async fn test_input_validator_variable() {
         }
     }
 }
+
+#[async_std::test]
+pub async fn test_input_validator_string_formats() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn url(&self, #[graphql(validator(Url))] _value: String) -> bool {
+            true
+        }
+
+        async fn ip(&self, #[graphql(validator(Ip))] _value: String) -> bool {
+            true
+        }
+
+        async fn regex(
+            &self,
+            #[graphql(validator(Regex(pattern = r#""[a-z_]+""#)))] _value: String,
+        ) -> bool {
+            true
+        }
+
+        async fn chars(
+            &self,
+            #[graphql(validator(and(
+                CharsMinLength(length = "2"),
+                CharsMaxLength(length = "3")
+            )))]
+            _value: String,
+        ) -> bool {
+            true
+        }
+
+        async fn host(&self, #[graphql(validator(or(Url, Ip)))] _value: Vec<String>) -> bool {
+            true
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let test_cases = [
+        ("url", "https://example.com/a?b=c#d", None),
+        ("url", "ftp://user@host:21", None),
+        (
+            "url",
+            "example.com",
+            Some(r#"the value "example.com" is not a valid URL"#),
+        ),
+        ("ip", "127.0.0.1", None),
+        ("ip", "::1", None),
+        (
+            "ip",
+            "256.0.0.1",
+            Some(r#"the value "256.0.0.1" is not a valid IP address"#),
+        ),
+        ("regex", "snake_case", None),
+        (
+            "regex",
+            "camelCase",
+            Some(r#"the value "camelCase" does not match the pattern "[a-z_]+""#),
+        ),
+        ("chars", "äöü", None),
+        (
+            "chars",
+            "ä",
+            Some(r#"the value "ä" has 1 characters, must be greater than or equal to 2"#),
+        ),
+        (
+            "chars",
+            "äöüß",
+            Some(r#"the value "äöüß" has 4 characters, must be less than or equal to 3"#),
+        ),
+        ("host", "10.0.0.1", None),
+        (
+            "host",
+            "localhost",
+            Some(r#"the value "localhost" is not a valid IP address"#),
+        ),
+    ];
+
+    for (field, case, error) in &test_cases {
+        let query = format!("{{ {}(value: \"{}\") }}", field, case);
+        let res = schema.execute(&query).await.into_result();
+        match error {
+            Some(error) => assert_eq!(
+                res.unwrap_err()[0].message,
+                format!("Invalid value for argument \"value\", {}", error),
+                "{}",
+                query
+            ),
+            None => assert!(res.is_ok(), "{}", query),
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = r#"Invalid pattern "[a-z""#)]
+pub fn test_input_validator_invalid_regex() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn regex(
+            &self,
+            #[graphql(validator(Regex(pattern = r#""[a-z""#)))] _value: String,
+        ) -> bool {
+            true
+        }
+    }
+
+    // The pattern is compiled when the schema is built.
+    Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+}
+
+#[async_std::test]
+pub async fn test_custom_validator() {
+    struct Exists;
+
+    #[async_trait::async_trait(?Send)]
+    impl CustomValidator<i32> for Exists {
+        async fn check(&self, ctx: &Context<'_>, value: &i32) -> Result<(), String> {
+            if ctx.data_unchecked::<Vec<i32>>().contains(value) {
+                Ok(())
+            } else {
+                Err(format!("no item with id {}", value))
+            }
+        }
+    }
+
+    struct MaxLen(usize);
+
+    #[async_trait::async_trait(?Send)]
+    impl CustomValidator<Option<String>> for MaxLen {
+        async fn check(&self, _ctx: &Context<'_>, value: &Option<String>) -> Result<(), String> {
+            match value {
+                Some(value) if value.len() > self.0 => Err(format!("\"{}\" is too long", value)),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn item(
+            &self,
+            #[graphql(custom_validator = "Exists")] id: i32,
+            #[graphql(custom_validator = "MaxLen(3)")] label: Option<String>,
+        ) -> i32 {
+            id + label.map(|label| label.len() as i32).unwrap_or_default()
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .data(vec![1, 2, 3])
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute(r#"{ item(id: 2, label: "ab") }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "item": 4 })
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ item(id: 4) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Invalid value for argument "id", no item with id 4"#.to_owned(),
+            locations: vec![Pos {
+                line: 1,
+                column: 12
+            }],
+            path: vec![PathSegment::Field("item".to_owned())],
+            extensions: None,
+        }]
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ item(id: 1, label: "abcd") }"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Invalid value for argument "label", "abcd" is too long"#.to_owned(),
+            locations: vec![Pos {
+                line: 1,
+                column: 22
+            }],
+            path: vec![PathSegment::Field("item".to_owned())],
+            extensions: None,
+        }]
+    );
+}
@@ -27,3 +27,60 @@ pub async fn test_input_value_custom_error() {
         }],
     );
 }
+
+#[async_std::test]
+pub async fn test_input_value_error_details() {
+    struct Color(String);
+
+    #[Scalar]
+    impl ScalarType for Color {
+        fn parse(value: Value) -> InputValueResult<Self> {
+            match value {
+                Value::String(s) if s.starts_with('#') => Ok(Color(s)),
+                Value::String(_) => Err(InputValueError::custom("must start with '#'")
+                    .with_extension("code", "INVALID_COLOR")),
+                value => Err(InputValueError::expected_type(value)),
+            }
+        }
+
+        fn to_value(&self) -> Value {
+            Value::String(self.0.clone())
+        }
+    }
+
+    let err = InputValueError::<Color>::expected_type(value!(true))
+        .with_field("b")
+        .with_field("a");
+    assert_eq!(err.expected(), Some("Color"));
+    assert_eq!(err.actual(), Some("boolean"));
+    assert_eq!(err.field_path(), ["a", "b"]);
+    assert_eq!(err.message(), r#"Expected input type "Color", found true."#);
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn color(&self, color: Color) -> String {
+            color.0
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let errors = schema.execute("{ color(color: 10) }").await.errors;
+    assert_eq!(
+        errors[0].message,
+        r#"Expected input type "Color", found 10."#
+    );
+    let extensions = errors[0].extensions.as_ref().unwrap();
+    assert_eq!(extensions.get("expectedType"), Some(&value!("Color")));
+    assert_eq!(extensions.get("actualKind"), Some(&value!("number")));
+
+    let errors = schema.execute(r#"{ color(color: "red") }"#).await.errors;
+    assert_eq!(
+        errors[0].message,
+        r#"Failed to parse "Color": must start with '#'"#
+    );
+    let extensions = errors[0].extensions.as_ref().unwrap();
+    assert_eq!(extensions.get("code"), Some(&value!("INVALID_COLOR")));
+    assert_eq!(extensions.get("expectedType"), None);
+}
@@ -0,0 +1,70 @@
+use async_graphql::*;
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn next(&self, n: Int64) -> Int64 {
+        Int64(*n + 1)
+    }
+
+    async fn max(&self) -> UInt64 {
+        UInt64(u64::MAX)
+    }
+}
+
+#[async_std::test]
+pub async fn test_int64() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ a: next(n: 9007199254740993) b: next(n: "-10") max }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "a": 9007199254740994i64,
+            "b": -9,
+            "max": u64::MAX,
+        })
+    );
+
+    let res = schema
+        .execute(r#"{ next(n: "9223372036854775808") }"#)
+        .await;
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid value for argument "n", expected type "Int64""#
+    );
+
+    let res = schema
+        .execute(
+            Request::new("query($n: Int64!) { next(n: $n) }")
+                .variables(Variables::from_value(value!({ "n": u64::MAX }))),
+        )
+        .await;
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid value for argument "n", expected type "Int64""#
+    );
+}
+
+#[async_std::test]
+pub async fn test_int64_representation() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .int64_representation(Int64Representation::String)
+        .finish();
+    assert_eq!(
+        schema
+            .execute(r#"{ next(n: "9007199254740993") max }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "next": "9007199254740994",
+            "max": "18446744073709551615",
+        })
+    );
+}
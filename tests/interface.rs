@@ -473,3 +473,269 @@ pub async fn test_issue_330() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_interface_field_object_resolver() {
+    #[derive(SimpleObject)]
+    struct Author {
+        name: String,
+    }
+
+    #[derive(SimpleObject)]
+    struct Book {
+        id: i32,
+        author: Author,
+    }
+
+    struct Magazine {
+        id: i32,
+    }
+
+    #[Object]
+    impl Magazine {
+        async fn id(&self) -> &i32 {
+            &self.id
+        }
+
+        async fn author(&self, #[graphql(default = "editor")] role: String) -> Option<Author> {
+            Some(Author { name: role })
+        }
+    }
+
+    #[derive(Interface)]
+    #[graphql(field(name = "name", type = "String"))]
+    enum Person {
+        Author(Author),
+    }
+
+    #[derive(Interface)]
+    #[graphql(
+        field(name = "id", type = "Option<i32>", object_resolver),
+        field(name = "author", type = "Option<Person>", object_resolver)
+    )]
+    enum Publication {
+        Book(Book),
+        Magazine(Magazine),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn publications(&self) -> Vec<Publication> {
+            vec![
+                Book {
+                    id: 1,
+                    author: Author {
+                        name: "a".to_string(),
+                    },
+                }
+                .into(),
+                Magazine { id: 2 }.into(),
+            ]
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ publications { id author { name } } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "publications": [
+                { "id": 1, "author": { "name": "a" } },
+                { "id": 2, "author": { "name": "editor" } },
+            ]
+        })
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = r#"Field "Book.id" has type "String!", which is not compatible with type "Int!" of the interface "Publication"."#
+)]
+pub fn test_interface_field_object_resolver_incompatible() {
+    #[derive(SimpleObject)]
+    struct Book {
+        id: String,
+    }
+
+    #[derive(Interface)]
+    #[graphql(field(name = "id", type = "i32", object_resolver))]
+    enum Publication {
+        Book(Book),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn publication(&self) -> Publication {
+            Book {
+                id: "1".to_string(),
+            }
+            .into()
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
+
+#[test]
+#[should_panic(
+    expected = r#"Argument "limit" expected by interface "Publication" is not provided by field "Book.title"."#
+)]
+pub fn test_interface_field_object_resolver_missing_argument() {
+    #[derive(SimpleObject)]
+    struct Book {
+        title: String,
+    }
+
+    #[derive(Interface)]
+    #[graphql(field(
+        name = "title",
+        type = "String",
+        object_resolver,
+        arg(name = "limit", type = "i32")
+    ))]
+    enum Publication {
+        Book(Book),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn publication(&self) -> Publication {
+            Book {
+                title: "a".to_string(),
+            }
+            .into()
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
+
+#[async_std::test]
+pub async fn test_interface_auto_fields() {
+    #[derive(SimpleObject)]
+    struct Book {
+        id: i32,
+        /// The title
+        title: String,
+        pages: i32,
+    }
+
+    struct Magazine {
+        id: i32,
+    }
+
+    #[Object]
+    impl Magazine {
+        async fn id(&self) -> i32 {
+            self.id
+        }
+
+        /// The title
+        async fn title(&self) -> String {
+            format!("Magazine {}", self.id)
+        }
+
+        async fn issue(&self) -> i32 {
+            3
+        }
+    }
+
+    #[derive(Interface)]
+    #[graphql(auto_fields)]
+    enum Publication {
+        Book(Book),
+        Magazine(Magazine),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn publications(&self) -> Vec<Publication> {
+            vec![
+                Book {
+                    id: 1,
+                    title: "a".to_string(),
+                    pages: 10,
+                }
+                .into(),
+                Magazine { id: 2 }.into(),
+            ]
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ publications { id title ... on Book { pages } } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "publications": [
+                { "id": 1, "title": "a", "pages": 10 },
+                { "id": 2, "title": "Magazine 2" },
+            ]
+        })
+    );
+    assert!(schema.sdl().contains(
+        "interface Publication {\n\tid: Int!\n\t\"\"\"\n\tThe title\n\t\"\"\"\n\ttitle: String!\n}"
+    ));
+
+    let res = schema.execute("{ publications { pages } }").await;
+    assert_eq!(
+        res.errors[0].message,
+        r#"Unknown field "pages" on type "Publication"."#
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = r#"Field "Publication.id" has type "Int!" in "Book" and type "String!" in "Magazine"."#
+)]
+pub fn test_interface_auto_fields_conflict() {
+    #[derive(SimpleObject)]
+    struct Book {
+        id: i32,
+        title: String,
+    }
+
+    #[derive(SimpleObject)]
+    struct Magazine {
+        id: String,
+        title: String,
+    }
+
+    #[derive(Interface)]
+    #[graphql(auto_fields)]
+    enum Publication {
+        Book(Book),
+        Magazine(Magazine),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn publication(&self) -> Publication {
+            Book {
+                id: 1,
+                title: "a".to_string(),
+            }
+            .into()
+        }
+    }
+
+    Schema::new(Query, EmptyMutation, EmptySubscription);
+}
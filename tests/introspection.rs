@@ -356,7 +356,7 @@ pub async fn test_introspection_deprecation() {
         }
     });
 
-    let mut res = schema.execute(&query).await.into_result().unwrap().data;
+    let mut res = schema.execute(query).await.into_result().unwrap().data;
 
     assert_eq!(res, res_json);
 
@@ -415,7 +415,7 @@ pub async fn test_introspection_deprecation() {
         }
     });
 
-    res = schema.execute(&query).await.into_result().unwrap().data;
+    res = schema.execute(query).await.into_result().unwrap().data;
 
     assert_eq!(res, res_json);
 
@@ -434,7 +434,7 @@ pub async fn test_introspection_deprecation() {
         }
     });
 
-    res = schema.execute(&query).await.into_result().unwrap().data;
+    res = schema.execute(query).await.into_result().unwrap().data;
 
     assert_eq!(res, res_json);
 
@@ -447,7 +447,7 @@ pub async fn test_introspection_deprecation() {
         }
     });
 
-    res = schema.execute(&query).await.into_result().unwrap().data;
+    res = schema.execute(query).await.into_result().unwrap().data;
 
     assert_eq!(res, res_json);
 
@@ -488,7 +488,7 @@ pub async fn test_introspection_deprecation() {
         }
     });
 
-    res = schema.execute(&query).await.into_result().unwrap().data;
+    res = schema.execute(query).await.into_result().unwrap().data;
 
     assert_eq!(res, res_json);
 
@@ -507,7 +507,7 @@ pub async fn test_introspection_deprecation() {
         }
     });
 
-    res = schema.execute(&query).await.into_result().unwrap().data;
+    res = schema.execute(query).await.into_result().unwrap().data;
 
     assert_eq!(res, res_json);
 }
@@ -538,7 +538,7 @@ pub async fn test_introspection_type_kind() {
         }
     });
 
-    let mut res = schema.execute(&query).await.into_result().unwrap().data;
+    let mut res = schema.execute(query).await.into_result().unwrap().data;
 
     assert_eq!(res, res_json);
 
@@ -552,7 +552,7 @@ pub async fn test_introspection_type_kind() {
         }
     });
 
-    res = schema.execute(&query).await.into_result().unwrap().data;
+    res = schema.execute(query).await.into_result().unwrap().data;
 
     assert_eq!(res, res_json);
 
@@ -566,7 +566,7 @@ pub async fn test_introspection_type_kind() {
         }
     });
 
-    res = schema.execute(&query).await.into_result().unwrap().data;
+    res = schema.execute(query).await.into_result().unwrap().data;
 
     assert_eq!(res, res_json);
 
@@ -580,7 +580,7 @@ pub async fn test_introspection_type_kind() {
         }
     });
 
-    res = schema.execute(&query).await.into_result().unwrap().data;
+    res = schema.execute(query).await.into_result().unwrap().data;
 
     assert_eq!(res, res_json);
 
@@ -594,7 +594,7 @@ pub async fn test_introspection_type_kind() {
         }
     });
 
-    res = schema.execute(&query).await.into_result().unwrap().data;
+    res = schema.execute(query).await.into_result().unwrap().data;
 
     assert_eq!(res, res_json);
 
@@ -633,7 +633,7 @@ pub async fn test_introspection_type_kind() {
           }
     });
 
-    res = schema.execute(&query).await.into_result().unwrap().data;
+    res = schema.execute(query).await.into_result().unwrap().data;
 
     assert_eq!(res, res_json);
 
@@ -666,7 +666,7 @@ pub async fn test_introspection_type_kind() {
         }
     });
 
-    res = schema.execute(&query).await.into_result().unwrap().data;
+    res = schema.execute(query).await.into_result().unwrap().data;
 
     assert_eq!(res, res_json);
 }
@@ -1189,3 +1189,254 @@ pub async fn test_introspection_subscription() {
 //
 //     assert_eq!(res, res_json)
 // }
+
+#[async_std::test]
+pub async fn test_introspection_metadata() {
+    #[derive(SimpleObject)]
+    #[graphql(meta(key = "owner", value = "accounts"))]
+    struct User {
+        #[graphql(
+            meta(key = "example", value = "Alice"),
+            meta(key = "pii", value = "true")
+        )]
+        name: String,
+        age: i32,
+    }
+
+    #[derive(Enum, Copy, Clone, Eq, PartialEq)]
+    enum Role {
+        #[graphql(meta(key = "example", value = "ADMIN"))]
+        Admin,
+        Guest,
+    }
+
+    #[derive(InputObject)]
+    struct UserFilter {
+        #[graphql(meta(key = "widget", value = "select"))]
+        role: Role,
+    }
+
+    struct Query;
+
+    #[Object(meta(key = "owner", value = "gateway"))]
+    impl Query {
+        #[graphql(meta(key = "example", value = "{ users { name } }"))]
+        async fn users(&self, _filter: Option<UserFilter>) -> Vec<User> {
+            Vec::new()
+        }
+    }
+
+    let query = r#"{
+        query: __metadata(coordinate: "Query") { key value }
+        users: __metadata(coordinate: "Query.users") { key value }
+        user: __metadata(coordinate: "User") { key value }
+        name: __metadata(coordinate: "User.name") { key value }
+        age: __metadata(coordinate: "User.age") { key value }
+        admin: __metadata(coordinate: "Role.ADMIN") { key value }
+        role: __metadata(coordinate: "UserFilter.role") { key value }
+    }"#;
+
+    // The field must be enabled explicitly.
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap_err()[0].message,
+        r#"Unknown field "__metadata" on type "Query"."#
+    );
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .enable_metadata_introspection()
+        .finish();
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({
+            "query": [{ "key": "owner", "value": "gateway" }],
+            "users": [{ "key": "example", "value": "{ users { name } }" }],
+            "user": [{ "key": "owner", "value": "accounts" }],
+            "name": [
+                { "key": "example", "value": "Alice" },
+                { "key": "pii", "value": "true" },
+            ],
+            "age": [],
+            "admin": [{ "key": "example", "value": "ADMIN" }],
+            "role": [{ "key": "widget", "value": "select" }],
+        })
+    );
+
+    // The field is not part of the introspected schema.
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Query") { fields { name } } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "__type": { "fields": [{ "name": "users" }] } })
+    );
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .enable_metadata_introspection()
+        .disable_introspection()
+        .finish();
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap_err()[0].message,
+        "Query introspection is disabled."
+    );
+}
+
+#[async_std::test]
+pub async fn test_introspect_input_type() {
+    use async_graphql::validators::{
+        IntRange, StringMaxLength, StringMinLength, ValidatorDescription,
+    };
+
+    #[derive(Enum, Copy, Clone, Eq, PartialEq)]
+    enum Color {
+        Red,
+        Blue,
+    }
+
+    /// A new user
+    #[derive(InputObject)]
+    struct NewUser {
+        #[graphql(validator(and(StringMinLength(length = "1"), StringMaxLength(length = "10"))))]
+        name: String,
+        #[graphql(default = 18, validator(IntRange(min = "0", max = "150")))]
+        age: i32,
+        colors: Option<Vec<Color>>,
+        friends: Option<Vec<NewUser>>,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn add(&self, _user: NewUser) -> bool {
+            true
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(schema.introspect_input_type("Query"), None);
+    assert_eq!(schema.introspect_input_type("Unknown"), None);
+
+    let validator = |name, params: Vec<(&'static str, Value)>| ValidatorDescription::Validator {
+        name,
+        params: params.into_iter().collect(),
+    };
+    assert_eq!(
+        schema.introspect_input_type("NewUser").unwrap(),
+        IntrospectedInputType::InputObject {
+            name: "NewUser".to_string(),
+            description: Some("A new user".to_string()),
+            fields: vec![
+                IntrospectedInputField {
+                    name: "name".to_string(),
+                    description: None,
+                    type_ref: "String!".to_string(),
+                    required: true,
+                    default_value: None,
+                    validator: Some(ValidatorDescription::And(vec![
+                        validator("StringMinLength", vec![("length", value!(1))]),
+                        validator("StringMaxLength", vec![("length", value!(10))]),
+                    ])),
+                    ty: IntrospectedInputType::Scalar {
+                        name: "String".to_string()
+                    },
+                },
+                IntrospectedInputField {
+                    name: "age".to_string(),
+                    description: None,
+                    type_ref: "Int!".to_string(),
+                    required: false,
+                    default_value: Some("18".to_string()),
+                    validator: Some(validator(
+                        "IntRange",
+                        vec![("min", value!(0)), ("max", value!(150))]
+                    )),
+                    ty: IntrospectedInputType::Scalar {
+                        name: "Int".to_string()
+                    },
+                },
+                IntrospectedInputField {
+                    name: "colors".to_string(),
+                    description: None,
+                    type_ref: "[Color!]".to_string(),
+                    required: false,
+                    default_value: None,
+                    validator: None,
+                    ty: IntrospectedInputType::Enum {
+                        name: "Color".to_string(),
+                        values: vec!["RED".to_string(), "BLUE".to_string()],
+                    },
+                },
+                IntrospectedInputField {
+                    name: "friends".to_string(),
+                    description: None,
+                    type_ref: "[NewUser!]".to_string(),
+                    required: false,
+                    default_value: None,
+                    validator: None,
+                    ty: IntrospectedInputType::Recursive {
+                        name: "NewUser".to_string()
+                    },
+                },
+            ],
+        }
+    );
+}
+
+#[async_std::test]
+pub async fn test_introspection_wrapped_type_name() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let query = r#"
+    {
+        wrapped: __type(name: "[SimpleObject!]!") {
+            kind
+            name
+            ofType {
+                kind
+                name
+                ofType {
+                    kind
+                    name
+                    ofType { kind name }
+                }
+            }
+        }
+        spaced: __type(name: " [ SimpleObject ! ] ") { kind ofType { kind ofType { name } } }
+        unknown: __type(name: "[Unknown]") { kind }
+        unclosed: __type(name: "[SimpleObject") { kind }
+        doubleNonNull: __type(name: "SimpleObject!!") { kind }
+        splitName: __type(name: "Simple Object") { kind }
+    }
+    "#;
+
+    let res_json = value!({
+        "wrapped": {
+            "kind": "NON_NULL",
+            "name": null,
+            "ofType": {
+                "kind": "LIST",
+                "name": null,
+                "ofType": {
+                    "kind": "NON_NULL",
+                    "name": null,
+                    "ofType": { "kind": "OBJECT", "name": "SimpleObject" }
+                }
+            }
+        },
+        "spaced": {
+            "kind": "LIST",
+            "ofType": { "kind": "NON_NULL", "ofType": { "name": "SimpleObject" } }
+        },
+        "unknown": null,
+        "unclosed": null,
+        "doubleNonNull": null,
+        "splitName": null,
+    });
+
+    let res = schema.execute(query).await.into_result().unwrap().data;
+
+    assert_eq!(res, res_json);
+}
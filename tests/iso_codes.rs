@@ -0,0 +1,112 @@
+#[cfg(feature = "isocountry")]
+#[async_std::test]
+pub async fn test_country_code() {
+    use async_graphql::*;
+    use isocountry::CountryCode;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn country(&self, code: CountryCode) -> CountryCode {
+            code
+        }
+
+        async fn name(&self, code: CountryCode) -> &'static str {
+            code.name()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ a: country(code: "NL") b: country(code: "NLD") name(code: "JP") }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "a": "NL",
+            "b": "NL",
+            "name": "Japan",
+        })
+    );
+
+    let res = schema.execute(r#"{ country(code: "XX") }"#).await;
+    assert_eq!(
+        res.errors[0].message,
+        r#"Failed to parse "CountryCode": invalid alpha-2 country code string: XX"#
+    );
+}
+
+#[cfg(feature = "currency")]
+#[async_std::test]
+pub async fn test_currency_code() {
+    use async_graphql::*;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn currency(&self, code: CurrencyCode) -> CurrencyCode {
+            code
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ currency(code: "EUR") }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "currency": "EUR" })
+    );
+
+    let res = schema.execute(r#"{ currency(code: "eur") }"#).await;
+    assert_eq!(
+        res.errors[0].message,
+        r#"Failed to parse "CurrencyCode": invalid ISO 4217 currency code: eur"#
+    );
+}
+
+#[cfg(feature = "language-tags")]
+#[async_std::test]
+pub async fn test_language_tag() {
+    use async_graphql::*;
+    use language_tags::LanguageTag;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn tag(&self, tag: LanguageTag) -> LanguageTag {
+            tag
+        }
+
+        async fn region(&self, tag: LanguageTag) -> Option<String> {
+            tag.region().map(ToString::to_string)
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ tag(tag: "en-us") region(tag: "zh-Hant-TW") }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "tag": "en-US",
+            "region": "TW",
+        })
+    );
+
+    let res = schema.execute(r#"{ tag(tag: "xxa-US") }"#).await;
+    assert_eq!(
+        res.errors[0].message,
+        r#"Failed to parse "LanguageTag": the primary language is not in the IANA Language Subtag Registry"#
+    );
+}
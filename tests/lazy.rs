@@ -0,0 +1,80 @@
+use async_graphql::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[async_std::test]
+pub async fn test_lazy() {
+    #[derive(SimpleObject)]
+    struct User {
+        name: String,
+    }
+
+    struct Query {
+        calls: Rc<Cell<i32>>,
+    }
+
+    #[Object]
+    impl Query {
+        async fn user(&self) -> UserQuery {
+            let calls = self.calls.clone();
+            let failures = self.calls.clone();
+            UserQuery {
+                user: Lazy::boxed(move || async move {
+                    calls.set(calls.get() + 1);
+                    async_std::task::yield_now().await;
+                    User {
+                        name: "abc".to_string(),
+                    }
+                }),
+                error: Lazy::boxed(move || async move {
+                    failures.set(failures.get() + 10);
+                    Err("Failed".into())
+                }),
+                value: Lazy::with_value(7),
+            }
+        }
+    }
+
+    #[derive(SimpleObject)]
+    struct UserQuery {
+        user: Lazy<User>,
+        error: Lazy<Result<i32>>,
+        value: Lazy<i32>,
+    }
+
+    let calls = Rc::new(Cell::new(0));
+    let schema = Schema::new(
+        Query {
+            calls: calls.clone(),
+        },
+        EmptyMutation,
+        EmptySubscription,
+    );
+
+    let res = schema
+        .execute("{ user { a: user { name } b: user { name } value } }")
+        .await;
+    assert_eq!(
+        res.data,
+        value!({
+            "user": {
+                "a": { "name": "abc" },
+                "b": { "name": "abc" },
+                "value": 7,
+            }
+        })
+    );
+    assert_eq!(calls.get(), 1);
+
+    let res = schema.execute("{ user { value } }").await;
+    assert_eq!(res.data, value!({ "user": { "value": 7 } }));
+    assert_eq!(calls.get(), 1);
+
+    let res = schema.execute("{ user { error } }").await;
+    assert_eq!(res.errors[0].message, "Failed");
+    assert_eq!(calls.get(), 11);
+
+    let sdl = schema.sdl();
+    assert!(sdl.contains("user: User!"));
+    assert!(sdl.contains("error: Int!"));
+}
@@ -0,0 +1,157 @@
+use async_graphql::lint::*;
+use async_graphql::*;
+
+/// A user
+#[derive(SimpleObject)]
+struct User {
+    /// The user id
+    id: i32,
+    active: bool,
+    /// Whether the user is an admin
+    is_admin: bool,
+}
+
+#[derive(SimpleObject)]
+#[graphql(name = "unused_thing")]
+struct UnusedThing {
+    value: i32,
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+enum Role {
+    #[graphql(name = "admin")]
+    Admin,
+    User,
+}
+
+struct Query;
+
+/// The root of all queries
+#[Object]
+impl Query {
+    /// Find a user
+    async fn user(&self, #[graphql(name = "user_id")] id: i32, role: Option<Role>) -> User {
+        let _ = role;
+        User {
+            id,
+            active: true,
+            is_admin: false,
+        }
+    }
+}
+
+fn schema() -> Schema<Query, EmptyMutation, EmptySubscription> {
+    Schema::build(Query, EmptyMutation, EmptySubscription)
+        .register_type::<UnusedThing>()
+        .finish()
+}
+
+fn coordinates(diagnostics: &[LintDiagnostic], rule: &str) -> Vec<String> {
+    diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.rule == rule)
+        .map(|diagnostic| diagnostic.coordinate.clone())
+        .collect()
+}
+
+#[test]
+pub fn test_lint_default_rules() {
+    let schema = schema();
+    let diagnostics = Linter::default().lint(schema.registry());
+
+    assert_eq!(
+        coordinates(&diagnostics, "naming-conventions"),
+        vec!["Query.user(user_id:)", "Role.admin", "unused_thing"]
+    );
+    assert_eq!(
+        coordinates(&diagnostics, "missing-descriptions"),
+        vec!["Role", "User.active", "unused_thing", "unused_thing.value"]
+    );
+    assert_eq!(
+        coordinates(&diagnostics, "empty-enums"),
+        Vec::<String>::new()
+    );
+    assert_eq!(
+        coordinates(&diagnostics, "unused-types"),
+        vec!["unused_thing"]
+    );
+    assert_eq!(
+        coordinates(&diagnostics, "boolean-prefix"),
+        vec!["User.active"]
+    );
+}
+
+#[test]
+pub fn test_lint_configure_rules() {
+    let schema = schema();
+    let diagnostics = Linter::default()
+        .without("naming-conventions")
+        .without("unused-types")
+        .rule(
+            MissingDescriptions::default()
+                .arguments(true)
+                .enum_values(true),
+        )
+        .rule(BooleanPrefix::new(&["is", "was"]))
+        .lint(schema.registry());
+
+    assert_eq!(
+        coordinates(&diagnostics, "naming-conventions"),
+        Vec::<String>::new()
+    );
+    assert_eq!(
+        coordinates(&diagnostics, "unused-types"),
+        Vec::<String>::new()
+    );
+    let boolean_prefix: Vec<_> = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.rule == "boolean-prefix")
+        .map(|diagnostic| diagnostic.message.as_str())
+        .collect();
+    assert_eq!(
+        boolean_prefix,
+        vec!["Boolean fields should start with one of: is, was"]
+    );
+    assert_eq!(
+        coordinates(&diagnostics, "missing-descriptions"),
+        vec![
+            "Query.user(user_id:)",
+            "Query.user(role:)",
+            "Role",
+            "Role.admin",
+            "Role.USER",
+            "User.active",
+            "unused_thing",
+            "unused_thing.value",
+        ]
+    );
+}
+
+#[test]
+pub fn test_lint_custom_rule() {
+    struct NoIdFields;
+
+    impl LintRule for NoIdFields {
+        fn name(&self) -> &'static str {
+            "no-id-fields"
+        }
+
+        fn check(&self, ctx: &mut LintContext, schema: SchemaView<'_>) {
+            for ty in schema.types_iter() {
+                for field in ty.fields().filter(|field| field.name() == "id") {
+                    ctx.report(format!("{}.{}", ty.name(), field.name()), "No ids");
+                }
+            }
+        }
+    }
+
+    let schema = schema();
+    assert_eq!(
+        Linter::new().rule(NoIdFields).lint(schema.registry()),
+        vec![LintDiagnostic {
+            rule: "no-id-fields",
+            coordinate: "User.id".to_string(),
+            message: "No ids".to_string(),
+        }]
+    );
+}
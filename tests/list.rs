@@ -117,3 +117,134 @@ pub async fn test_list_type() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_list_concurrency() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[derive(Default)]
+    struct Running {
+        current: Cell<usize>,
+        max: Cell<usize>,
+    }
+
+    struct Item(i32);
+
+    #[Object]
+    impl Item {
+        async fn value(&self, ctx: &Context<'_>) -> i32 {
+            let running = ctx.data_unchecked::<Rc<Running>>();
+            running.current.set(running.current.get() + 1);
+            running
+                .max
+                .set(running.max.get().max(running.current.get()));
+            for _ in 0..(5 - self.0) {
+                async_std::task::yield_now().await;
+            }
+            running.current.set(running.current.get() - 1);
+            self.0
+        }
+    }
+
+    fn items() -> Vec<Item> {
+        (0..5).map(Item).collect()
+    }
+
+    #[derive(SimpleObject)]
+    struct Lists {
+        #[graphql(list_concurrency = 2)]
+        limited: Vec<Item>,
+        unlimited: Vec<Item>,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn lists(&self) -> Lists {
+            Lists {
+                limited: items(),
+                unlimited: items(),
+            }
+        }
+
+        #[graphql(list_concurrency = 3)]
+        async fn resolved(&self) -> Vec<Item> {
+            items()
+        }
+
+        async fn nested(&self) -> Vec<Vec<Item>> {
+            vec![items()]
+        }
+    }
+
+    let running = Rc::new(Running::default());
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(running.clone())
+        .finish();
+    let values = value!([
+        { "value": 0 },
+        { "value": 1 },
+        { "value": 2 },
+        { "value": 3 },
+        { "value": 4 },
+    ]);
+
+    running.max.set(0);
+    assert_eq!(
+        schema
+            .execute("{ lists { limited { value } } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "lists": { "limited": values.clone() } })
+    );
+    assert_eq!(running.max.get(), 2);
+
+    running.max.set(0);
+    assert_eq!(
+        schema
+            .execute("{ resolved { value } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "resolved": values.clone() })
+    );
+    assert_eq!(running.max.get(), 3);
+
+    running.max.set(0);
+    schema
+        .execute("{ lists { unlimited { value } } }")
+        .await
+        .into_result()
+        .unwrap();
+    assert_eq!(running.max.get(), 5);
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(running.clone())
+        .list_concurrency(4)
+        .finish();
+
+    running.max.set(0);
+    schema
+        .execute("{ lists { unlimited { value } } }")
+        .await
+        .into_result()
+        .unwrap();
+    assert_eq!(running.max.get(), 4);
+
+    running.max.set(0);
+    assert_eq!(
+        schema
+            .execute("{ nested { value } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "nested": [values] })
+    );
+    assert_eq!(running.max.get(), 4);
+}
@@ -0,0 +1,62 @@
+use async_graphql::*;
+use std::cell::Cell;
+
+#[async_std::test]
+pub async fn test_memoize() {
+    #[derive(Default)]
+    struct Query {
+        calls: Cell<i32>,
+    }
+
+    impl Query {
+        async fn current_user(&self, ctx: &Context<'_>) -> Result<String> {
+            ctx.memoize("currentUser", || async {
+                self.calls.set(self.calls.get() + 1);
+                // Let the other fields start before the value is computed.
+                async_std::task::yield_now().await;
+                match ctx.data_opt::<String>() {
+                    Some(token) => Ok(format!("user-{}", token)),
+                    None => Err("Not logged in".into()),
+                }
+            })
+            .await
+        }
+    }
+
+    #[Object]
+    impl Query {
+        async fn name(&self, ctx: &Context<'_>) -> Result<String> {
+            self.current_user(ctx).await
+        }
+
+        async fn greeting(&self, ctx: &Context<'_>) -> Result<String> {
+            Ok(format!("Hello {}", self.current_user(ctx).await?))
+        }
+
+        async fn key_type(&self, ctx: &Context<'_>) -> i32 {
+            // The same key with another value type is memoized separately.
+            ctx.memoize("currentUser", || async { 7 }).await
+        }
+
+        async fn calls(&self) -> i32 {
+            self.calls.get()
+        }
+    }
+
+    let schema = Schema::new(Query::default(), EmptyMutation, EmptySubscription);
+
+    let request = Request::new("{ name greeting keyType }").data("1".to_string());
+    let resp = schema.execute(request).await.into_result().unwrap();
+    assert_eq!(
+        resp.data,
+        value!({ "name": "user-1", "greeting": "Hello user-1", "keyType": 7 })
+    );
+    let resp = schema.execute("{ calls }").await.into_result().unwrap();
+    assert_eq!(resp.data, value!({ "calls": 1 }));
+
+    // Each request computes its own value, errors included.
+    let resp = schema.execute("{ name greeting }").await;
+    assert_eq!(resp.errors[0].message, "Not logged in");
+    let resp = schema.execute("{ calls }").await.into_result().unwrap();
+    assert_eq!(resp.data, value!({ "calls": 2 }));
+}
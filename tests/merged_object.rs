@@ -197,7 +197,7 @@ pub async fn test_merged_subscription() {
         let mut stream = schema
             .execute_stream("subscription { events1 }")
             .map(|resp| resp.into_result().unwrap().data)
-            .boxed();
+            .boxed_local();
         for i in 0i32..10 {
             assert_eq!(
                 value!({
@@ -213,7 +213,7 @@ pub async fn test_merged_subscription() {
         let mut stream = schema
             .execute_stream("subscription { events2 }")
             .map(|resp| resp.into_result().unwrap().data)
-            .boxed();
+            .boxed_local();
         for i in 10i32..20 {
             assert_eq!(
                 value!({
@@ -387,3 +387,229 @@ pub async fn test_issue_333() {
         })
     )
 }
+
+#[test]
+#[should_panic(expected = r#"Field "Query.value" is defined by both"#)]
+pub fn test_merged_object_duplicate_field() {
+    #[derive(SimpleObject, Default)]
+    struct QueryA {
+        value: i32,
+    }
+
+    #[derive(SimpleObject, Default)]
+    struct QueryB {
+        value: i32,
+    }
+
+    #[derive(MergedObject, Default)]
+    struct Query(QueryA, QueryB);
+
+    Schema::new(Query::default(), EmptyMutation, EmptySubscription);
+}
+
+#[async_std::test]
+pub async fn test_merged_object_cache_control() {
+    #[derive(SimpleObject, Default)]
+    #[graphql(cache_control(max_age = 60))]
+    struct ObjectA {
+        a: i32,
+    }
+
+    #[derive(SimpleObject, Default)]
+    #[graphql(cache_control(max_age = 30))]
+    struct ObjectB {
+        b: i32,
+    }
+
+    #[derive(MergedObject, Default)]
+    struct Merged(ObjectA, ObjectB);
+
+    #[derive(MergedObject, Default)]
+    #[graphql(cache_control(max_age = 10))]
+    struct Overridden(ObjectA, ObjectB);
+
+    #[derive(Default)]
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn merged(&self) -> Merged {
+            Merged::default()
+        }
+
+        async fn overridden(&self) -> Overridden {
+            Overridden::default()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let res = schema.execute("{ merged { a } }").await;
+    assert_eq!(res.cache_control.max_age, 30);
+    let res = schema.execute("{ overridden { a } }").await;
+    assert_eq!(res.cache_control.max_age, 10);
+}
+
+#[async_std::test]
+pub async fn test_merged_object_keys() {
+    struct UserKey;
+
+    impl Type for UserKey {
+        fn type_name() -> std::borrow::Cow<'static, str> {
+            "UserKey".into()
+        }
+
+        fn create_type_info(registry: &mut registry::Registry) -> String {
+            registry.create_type::<Self, _>(|_| registry::MetaType::Object {
+                name: "UserKey".to_string(),
+                description: None,
+                fields: Default::default(),
+                cache_control: Default::default(),
+                extends: false,
+                keys: Some(vec!["id".to_string()]),
+            })
+        }
+    }
+
+    #[async_trait::async_trait(?Send)]
+    impl resolver_utils::ContainerType for UserKey {
+        async fn resolve_field(&self, _ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+            Ok(None)
+        }
+    }
+
+    #[async_trait::async_trait(?Send)]
+    impl OutputValueType for UserKey {
+        async fn resolve(
+            &self,
+            ctx: &ContextSelectionSet<'_>,
+            _field: &Positioned<parser::types::Field>,
+        ) -> ServerResult<Value> {
+            resolver_utils::resolve_container(ctx, self).await
+        }
+    }
+
+    impl ObjectType for UserKey {}
+
+    #[derive(SimpleObject)]
+    struct UserProfile {
+        id: ID,
+        name: String,
+    }
+
+    #[derive(MergedObject)]
+    struct User(UserKey, UserProfile);
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        #[graphql(entity)]
+        async fn find_user_by_name(&self, name: String) -> User {
+            User(
+                UserKey,
+                UserProfile {
+                    id: ID::from("1"),
+                    name,
+                },
+            )
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let res = schema
+        .execute("{ _service { sdl } }")
+        .await
+        .into_result()
+        .unwrap();
+    let sdl = match res.data {
+        Value::Object(obj) => match &obj["_service"] {
+            Value::Object(service) => service["sdl"].to_string(),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    assert!(sdl.contains(r#"type User @key(fields: \"id\") @key(fields: \"name\")"#));
+}
+
+#[async_std::test]
+pub async fn test_merged_object_generic_parts() {
+    #[derive(MergedObject)]
+    struct MyObj<T: ObjectType>(Object1, T);
+
+    #[derive(MergedObject)]
+    #[graphql(name = "MyNamedObj")]
+    struct MyNamedObj<T> {
+        object1: Object1,
+        other: T,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn obj(&self) -> MyObj<Object2> {
+            MyObj(Object1 { a: 10 }, Object2 { b: 20 })
+        }
+
+        async fn named_obj(&self) -> MyNamedObj<Object3> {
+            MyNamedObj {
+                object1: Object1 { a: 10 },
+                other: Object3 { c: 30 },
+            }
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = "{ obj { a b } namedObj { a c } }";
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({
+            "obj": {
+                "a": 10,
+                "b": 20,
+            },
+            "namedObj": {
+                "a": 10,
+                "c": 30,
+            }
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_merged_object_cfg_parts() {
+    #[derive(MergedObject)]
+    struct MyObj(
+        Object1,
+        #[cfg(feature = "abc")] Object2,
+        #[cfg(not(feature = "abc"))] Object3,
+    );
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn obj(&self) -> MyObj {
+            MyObj(
+                Object1 { a: 10 },
+                #[cfg(feature = "abc")]
+                Object2 { b: 20 },
+                #[cfg(not(feature = "abc"))]
+                Object3 { c: 30 },
+            )
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = "{ obj { a c } }";
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({
+            "obj": {
+                "a": 10,
+                "c": 30,
+            }
+        })
+    );
+    assert!(schema.execute("{ obj { b } }").await.is_err());
+}
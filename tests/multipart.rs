@@ -0,0 +1,221 @@
+#[cfg(feature = "multipart")]
+mod multipart {
+    use std::io::Read;
+
+    use async_graphql::http::{receive_batch_body, receive_body, MultipartOptions};
+    use async_graphql::*;
+
+    const BOUNDARY: &str = "boundary";
+
+    fn multipart_body(operations: &str, map: &str, files: &[(&str, &str, &str)]) -> Vec<u8> {
+        let mut body = String::new();
+        for (name, value) in &[("operations", operations), ("map", map)] {
+            body.push_str(&format!(
+                "--{}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n",
+                BOUNDARY, name, value
+            ));
+        }
+        for (name, filename, content) in files {
+            body.push_str(&format!(
+                "--{}\r\nContent-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: text/plain\r\n\r\n{}\r\n",
+                BOUNDARY, name, filename, content
+            ));
+        }
+        body.push_str(&format!("--{}--\r\n", BOUNDARY));
+        body.into_bytes()
+    }
+
+    fn content_type() -> String {
+        format!("multipart/form-data; boundary={}", BOUNDARY)
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    struct Mutation;
+
+    #[Object]
+    impl Mutation {
+        async fn upload(&self, ctx: &Context<'_>, file: Upload) -> String {
+            let upload = file.value(ctx).unwrap();
+            let mut content = String::new();
+            upload.into_read().read_to_string(&mut content).unwrap();
+            content
+        }
+
+        async fn uploads(&self, ctx: &Context<'_>, files: Vec<Upload>) -> Vec<String> {
+            files
+                .iter()
+                .map(|file| {
+                    let upload = file.value(ctx).unwrap();
+                    format!(
+                        "{}:{}",
+                        upload.filename,
+                        upload.content_type.unwrap_or_default()
+                    )
+                })
+                .collect()
+        }
+    }
+
+    #[async_std::test]
+    pub async fn test_multipart_upload() {
+        let schema = Schema::new(Query, Mutation, EmptySubscription);
+
+        let body = multipart_body(
+            r#"{ "query": "mutation ($file: Upload!) { upload(file: $file) }", "variables": { "file": null } }"#,
+            r#"{ "0": ["variables.file"] }"#,
+            &[("0", "a.txt", "hello")],
+        );
+        let request = receive_body(
+            Some(content_type()),
+            futures_util::io::Cursor::new(body),
+            MultipartOptions::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            schema.execute(request).await.into_result().unwrap().data,
+            value!({ "upload": "hello" })
+        );
+
+        let body = multipart_body(
+            r#"{ "query": "mutation ($files: [Upload!]!) { uploads(files: $files) }", "variables": { "files": [null, null] } }"#,
+            r#"{ "0": ["variables.files.0"], "1": ["variables.files.1"] }"#,
+            &[("0", "a.txt", "a"), ("1", "b.txt", "b")],
+        );
+        let request = receive_body(
+            Some(content_type()),
+            futures_util::io::Cursor::new(body),
+            MultipartOptions::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            schema.execute(request).await.into_result().unwrap().data,
+            value!({ "uploads": ["a.txt:text/plain", "b.txt:text/plain"] })
+        );
+    }
+
+    #[async_std::test]
+    pub async fn test_multipart_batch_upload() {
+        let schema = Schema::new(Query, Mutation, EmptySubscription);
+
+        let body = multipart_body(
+            r#"[
+                { "query": "mutation ($file: Upload!) { upload(file: $file) }", "variables": { "file": null } },
+                { "query": "mutation ($file: Upload!) { upload(file: $file) }", "variables": { "file": null } }
+            ]"#,
+            r#"{ "0": ["0.variables.file"], "1": ["1.variables.file"] }"#,
+            &[("0", "a.txt", "a"), ("1", "b.txt", "b")],
+        );
+        let request = receive_batch_body(
+            Some(content_type()),
+            futures_util::io::Cursor::new(body),
+            MultipartOptions::default(),
+        )
+        .await
+        .unwrap();
+        match schema.execute_batch(request).await {
+            BatchResponse::Batch(responses) => {
+                let data: Vec<_> = responses.into_iter().map(|resp| resp.data).collect();
+                assert_eq!(
+                    data,
+                    vec![value!({ "upload": "a" }), value!({ "upload": "b" })]
+                );
+            }
+            BatchResponse::Single(_) => panic!("expected a batch response"),
+        }
+    }
+
+    #[async_std::test]
+    pub async fn test_multipart_errors() {
+        let operations = r#"{ "query": "mutation ($file: Upload!) { upload(file: $file) }", "variables": { "file": null } }"#;
+
+        let body = multipart_body(operations, r#"{ "0": ["variables.file"] }"#, &[]);
+        let err = receive_body(
+            Some(content_type()),
+            futures_util::io::Cursor::new(body),
+            MultipartOptions::default(),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, ParseRequestError::MissingFiles));
+
+        let body = multipart_body(
+            operations,
+            r#"{ "0": ["variables.file"], "1": ["variables.file"] }"#,
+            &[("0", "a.txt", "a"), ("1", "b.txt", "b")],
+        );
+        let err = receive_body(
+            Some(content_type()),
+            futures_util::io::Cursor::new(body),
+            MultipartOptions::default().max_num_files(1),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, ParseRequestError::PayloadTooLarge));
+
+        let body = multipart_body(
+            operations,
+            r#"{ "0": ["variables.file"] }"#,
+            &[("0", "a.txt", "too large")],
+        );
+        let err = receive_body(
+            Some(content_type()),
+            futures_util::io::Cursor::new(body),
+            MultipartOptions::default().max_file_size(4),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, ParseRequestError::PayloadTooLarge));
+    }
+
+    #[async_std::test]
+    pub async fn test_multipart_not_send_body() {
+        use futures_util::io::{AsyncRead, Cursor};
+        use std::marker::PhantomData;
+        use std::pin::Pin;
+        use std::rc::Rc;
+        use std::task::{Context, Poll};
+
+        struct RcReader(Cursor<Vec<u8>>, PhantomData<Rc<()>>);
+
+        impl AsyncRead for RcReader {
+            fn poll_read(
+                mut self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+                buf: &mut [u8],
+            ) -> Poll<std::io::Result<usize>> {
+                Pin::new(&mut self.0).poll_read(cx, buf)
+            }
+        }
+
+        let schema = Schema::new(Query, Mutation, EmptySubscription);
+        let body = multipart_body(
+            r#"{ "query": "mutation ($file: Upload!) { upload(file: $file) }", "variables": { "file": null } }"#,
+            r#"{ "0": ["variables.file"] }"#,
+            &[("0", "a.txt", "hello")],
+        );
+        // The limit of the whole body saturates instead of overflowing.
+        let request = receive_body(
+            Some(content_type()),
+            RcReader(Cursor::new(body), PhantomData),
+            MultipartOptions::default()
+                .max_file_size(usize::MAX)
+                .max_num_files(2),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            schema.execute(request).await.into_result().unwrap().data,
+            value!({ "upload": "hello" })
+        );
+    }
+}
@@ -84,3 +84,71 @@ pub async fn test_mutation_fragment() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_mutation_result() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    #[derive(SimpleObject)]
+    struct User {
+        name: String,
+    }
+
+    struct MutationRoot;
+
+    #[Object]
+    impl MutationRoot {
+        async fn create_user(&self, name: String) -> MutationResult<User> {
+            if name.is_empty() {
+                Err(vec![
+                    UserError::new("The name is empty.").with_field(["name"]),
+                    UserError::new("Try again later.").with_code("RATE_LIMITED"),
+                ])
+                .into()
+            } else {
+                MutationResult::ok(User { name })
+            }
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, MutationRoot, EmptySubscription);
+    let query = r#"mutation {
+        a: createUser(name: "Alice") { success data { name } userErrors { message } }
+        b: createUser(name: "") { success data { name } userErrors { message field code } }
+    }"#;
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({
+            "a": { "success": true, "data": { "name": "Alice" }, "userErrors": [] },
+            "b": {
+                "success": false,
+                "data": null,
+                "userErrors": [
+                    { "message": "The name is empty.", "field": ["name"], "code": null },
+                    { "message": "Try again later.", "field": [], "code": "RATE_LIMITED" },
+                ],
+            },
+        })
+    );
+
+    let query = r#"{ __type(name: "UserPayload") { fields { name type { kind } } } }"#;
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({
+            "__type": {
+                "fields": [
+                    { "name": "success", "type": { "kind": "NON_NULL" } },
+                    { "name": "data", "type": { "kind": "OBJECT" } },
+                    { "name": "userErrors", "type": { "kind": "NON_NULL" } },
+                ]
+            }
+        })
+    );
+}
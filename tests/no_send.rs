@@ -0,0 +1,79 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use async_graphql::guard::Guard;
+use async_graphql::*;
+use futures_util::stream::{Stream, StreamExt};
+
+#[async_std::test]
+pub async fn test_no_send() {
+    struct Cache(Rc<RefCell<Vec<i32>>>);
+
+    struct CacheGuard;
+
+    #[async_trait::async_trait(?Send)]
+    impl Guard for CacheGuard {
+        async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+            let cache = ctx.data_unchecked::<Cache>().0.clone();
+            async_std::task::yield_now().await;
+            if cache.borrow().is_empty() {
+                Err("Empty".into())
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn push(&self, ctx: &Context<'_>, value: i32) -> i32 {
+            let cache = ctx.data_unchecked::<Cache>().0.clone();
+            async_std::task::yield_now().await;
+            cache.borrow_mut().push(value);
+            let len = cache.borrow().len();
+            len as i32
+        }
+
+        #[graphql(guard(CacheGuard()))]
+        async fn values(&self, ctx: &Context<'_>) -> Vec<i32> {
+            ctx.data_unchecked::<Cache>().0.borrow().clone()
+        }
+    }
+
+    struct Subscription;
+
+    #[Subscription]
+    impl Subscription {
+        async fn values(&self, ctx: &Context<'_>) -> impl Stream<Item = i32> {
+            let cache = ctx.data_unchecked::<Cache>().0.clone();
+            let values = cache.borrow().clone();
+            futures_util::stream::iter(values).map(move |value| value * cache.borrow().len() as i32)
+        }
+    }
+
+    let cache = Rc::new(RefCell::new(Vec::new()));
+    let schema = Schema::build(Query, EmptyMutation, Subscription)
+        .data(Cache(cache.clone()))
+        .finish();
+
+    assert!(schema.execute("{ values }").await.is_err());
+    assert_eq!(
+        schema.execute("{ a: push(value: 1) }").await.data,
+        value!({ "a": 1 })
+    );
+    assert_eq!(
+        schema.execute("{ b: push(value: 2) values }").await.data,
+        value!({ "b": 2, "values": [1, 2] })
+    );
+    assert_eq!(*cache.borrow(), vec![1, 2]);
+
+    let mut stream = schema
+        .execute_stream("subscription { values }")
+        .map(|resp| resp.into_result().unwrap().data)
+        .boxed_local();
+    assert_eq!(stream.next().await, Some(value!({ "values": 2 })));
+    assert_eq!(stream.next().await, Some(value!({ "values": 4 })));
+    assert_eq!(stream.next().await, None);
+}
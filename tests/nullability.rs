@@ -0,0 +1,145 @@
+use async_graphql::*;
+
+struct User;
+
+#[Object]
+impl User {
+    async fn name(&self) -> Option<String> {
+        None
+    }
+
+    async fn age(&self) -> i32 {
+        42
+    }
+
+    async fn broken(&self) -> Result<i32> {
+        Err("broken".into())
+    }
+
+    async fn friends(&self) -> Vec<User> {
+        vec![User, User]
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn user(&self) -> Option<User> {
+        Some(User)
+    }
+}
+
+fn schema() -> Schema<Query, EmptyMutation, EmptySubscription> {
+    Schema::new(Query, EmptyMutation, EmptySubscription)
+}
+
+fn path(segments: &[&str]) -> Vec<PathSegment> {
+    segments
+        .iter()
+        .map(|segment| match segment.parse() {
+            Ok(idx) => PathSegment::Index(idx),
+            Err(_) => PathSegment::Field(segment.to_string()),
+        })
+        .collect()
+}
+
+#[cfg(feature = "client_controlled_nullability")]
+#[async_std::test]
+pub async fn test_required_field() {
+    let resp = schema().execute("{ user { age! name! } }").await;
+    assert_eq!(resp.data, Value::Null);
+    assert_eq!(resp.errors.len(), 1);
+    assert_eq!(
+        resp.errors[0].message,
+        r#"Cannot return null for required field "User.name"."#
+    );
+    assert_eq!(resp.errors[0].path, path(&["user", "name"]));
+
+    assert_eq!(
+        schema()
+            .execute("{ user! { age! } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "user": { "age": 42 } })
+    );
+}
+
+#[cfg(feature = "client_controlled_nullability")]
+#[async_std::test]
+pub async fn test_optional_field() {
+    let resp = schema()
+        .execute("{ user? { age name! } other: user { age broken? } }")
+        .await;
+    assert_eq!(
+        resp.data,
+        value!({
+            "user": null,
+            "other": { "age": 42, "broken": null },
+        })
+    );
+    let mut errors: Vec<_> = resp
+        .errors
+        .into_iter()
+        .map(|err| (err.message, err.path))
+        .collect();
+    errors.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(
+        errors,
+        vec![
+            (
+                r#"Cannot return null for required field "User.name"."#.to_string(),
+                path(&["user", "name"])
+            ),
+            ("broken".to_string(), path(&["other", "broken"])),
+        ]
+    );
+
+    let resp = schema().execute("{ user { friends { broken? } } }").await;
+    assert_eq!(
+        resp.data,
+        value!({ "user": { "friends": [{ "broken": null }, { "broken": null }] } })
+    );
+    assert_eq!(
+        resp.errors
+            .into_iter()
+            .map(|err| err.path)
+            .collect::<Vec<_>>(),
+        vec![
+            path(&["user", "friends", "0", "broken"]),
+            path(&["user", "friends", "1", "broken"]),
+        ]
+    );
+}
+
+#[cfg(feature = "client_controlled_nullability")]
+#[async_std::test]
+pub async fn test_conflicting_nullability() {
+    let resp = schema().execute("{ user { name! name } }").await;
+    assert_eq!(
+        resp.errors
+            .into_iter()
+            .map(|err| err.message)
+            .collect::<Vec<_>>(),
+        vec![
+            r#"Fields "name" conflict because they have differing nullability designators. Use different aliases on the fields to fetch both if this was intentional."#
+        ]
+    );
+}
+
+#[cfg(not(feature = "client_controlled_nullability"))]
+#[async_std::test]
+pub async fn test_nullability_disabled() {
+    let resp = schema().execute("{ user { name! } }").await;
+    assert_eq!(
+        resp.errors
+            .into_iter()
+            .map(|err| err.message)
+            .collect::<Vec<_>>(),
+        vec![
+            r#"Field "name" cannot have the nullability designator "!", client controlled nullability is not enabled"#
+        ]
+    );
+}
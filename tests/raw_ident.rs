@@ -65,7 +65,7 @@ pub async fn test_input_value_custom_error() {
         .execute_stream("subscription { type }")
         .map(|resp| resp.into_result())
         .map_ok(|resp| resp.data)
-        .boxed();
+        .boxed_local();
     for i in 0..10 {
         assert_eq!(value!({ "type": i }), stream.next().await.unwrap().unwrap());
     }
@@ -0,0 +1,75 @@
+use async_graphql::*;
+
+#[derive(SimpleObject)]
+struct User {
+    id: i32,
+    name: String,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn user(&self) -> Raw<User> {
+        Raw::new(r#"{"id": 1, "name": "Alice"}"#).unwrap()
+    }
+
+    async fn users(&self) -> Raw<Vec<User>> {
+        Raw::new(r#"{"id": 1}"#).unwrap().validate()
+    }
+
+    async fn maybe_user(&self) -> Raw<Option<User>> {
+        Raw::new("null").unwrap().validate()
+    }
+
+    async fn required_user(&self) -> Raw<User> {
+        Raw::new("null").unwrap().validate()
+    }
+}
+
+#[async_std::test]
+pub async fn test_raw_value() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let res = schema
+        .execute("{ user { id name } maybeUser { id } }")
+        .await;
+    assert_eq!(
+        res.data,
+        value!({
+            "user": { "id": 1, "name": "Alice" },
+            "maybeUser": null,
+        })
+    );
+    assert_eq!(
+        serde_json::to_string(&res.data).unwrap(),
+        r#"{"user":{"id": 1, "name": "Alice"},"maybeUser":null}"#
+    );
+    assert_eq!(
+        from_value::<serde_json::Value>(res.data).unwrap(),
+        serde_json::json!({
+            "user": { "id": 1, "name": "Alice" },
+            "maybeUser": null,
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_raw_value_validate() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let res = schema.execute("{ users { id } }").await;
+    assert_eq!(
+        res.errors,
+        vec![ServerError {
+            message: r#"Invalid raw value for type "[User!]!", expected a list."#.to_string(),
+            locations: vec![Pos { line: 1, column: 3 }],
+            path: vec![PathSegment::Field("users".to_string())],
+            extensions: None,
+        }]
+    );
+
+    let res = schema.execute("{ requiredUser { id } }").await;
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid raw value for type "User!", expected a non-null value."#
+    );
+}
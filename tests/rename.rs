@@ -103,6 +103,59 @@ pub async fn test_input_object() {
     );
 }
 
+#[async_std::test]
+pub async fn test_camel_case_acronyms() {
+    #[derive(Enum, Eq, PartialEq, Copy, Clone)]
+    #[graphql(rename_items = "camelCase_acronyms")]
+    enum Format {
+        HTMLPage,
+        JsonDocument,
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(rename_fields = "camelCase_acronyms")]
+    struct Page {
+        user_id: i32,
+        html_body: String,
+        canonical_url_path: String,
+        format: Format,
+    }
+
+    struct Query;
+
+    #[Object(
+        rename_fields = "camelCase_acronyms",
+        rename_args = "camelCase_acronyms"
+    )]
+    impl Query {
+        async fn page_by_id(&self, page_id: i32, format: Format) -> Page {
+            Page {
+                user_id: page_id,
+                html_body: "<p></p>".to_string(),
+                canonical_url_path: "/".to_string(),
+                format,
+            }
+        }
+    }
+
+    assert_eq!(
+        Schema::new(Query, EmptyMutation, EmptySubscription)
+            .execute("{ pageByID(pageID: 1, format: htmlPage) { userID htmlBody canonicalURLPath format } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "pageByID": {
+                "userID": 1,
+                "htmlBody": "<p></p>",
+                "canonicalURLPath": "/",
+                "format": "htmlPage",
+            }
+        })
+    );
+}
+
 #[async_std::test]
 pub async fn test_subscription() {
     struct Query;
@@ -127,7 +180,7 @@ pub async fn test_subscription() {
     assert_eq!(
         Schema::new(Query, EmptyMutation, Subscription)
             .execute_stream("subscription { CREATE_OBJECT(objectid: 100) }")
-            .boxed()
+            .boxed_local()
             .next()
             .await
             .unwrap()
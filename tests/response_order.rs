@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use async_graphql::*;
+
+#[derive(SimpleObject)]
+struct MyObject {
+    a: i32,
+    b: i32,
+    c: i32,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn value(&self, n: i32) -> i32 {
+        n
+    }
+
+    async fn slow(&self, millis: u64) -> u64 {
+        async_std::task::sleep(Duration::from_millis(millis)).await;
+        millis
+    }
+
+    async fn obj(&self) -> MyObject {
+        MyObject { a: 1, b: 2, c: 3 }
+    }
+}
+
+async fn execute(query: &str) -> String {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let resp = schema.execute(query).await;
+    assert!(resp.is_ok(), "{:?}", resp.errors);
+    serde_json::to_string(&resp.data).unwrap()
+}
+
+#[async_std::test]
+pub async fn test_response_order_matches_query() {
+    assert_eq!(
+        execute("{ z: value(n: 1) a: value(n: 2) m: value(n: 3) }").await,
+        r#"{"z":1,"a":2,"m":3}"#
+    );
+    assert_eq!(
+        execute("{ obj { c a b } }").await,
+        r#"{"obj":{"c":3,"a":1,"b":2}}"#
+    );
+}
+
+#[async_std::test]
+pub async fn test_response_order_with_fragments() {
+    assert_eq!(
+        execute(
+            r#"
+            {
+                z: value(n: 1)
+                ... A
+                obj { c }
+                ... on Query { b: value(n: 3) obj { b } }
+                z: value(n: 1)
+            }
+
+            fragment A on Query {
+                y: value(n: 2)
+                obj { a c }
+            }
+            "#
+        )
+        .await,
+        r#"{"z":1,"y":2,"obj":{"a":1,"c":3,"b":2},"b":3}"#
+    );
+}
+
+#[async_std::test]
+pub async fn test_response_order_with_concurrent_resolution() {
+    assert_eq!(
+        execute(
+            "{ c: slow(millis: 30) b: slow(millis: 20) a: slow(millis: 10) d: slow(millis: 0) }"
+        )
+        .await,
+        r#"{"c":30,"b":20,"a":10,"d":0}"#
+    );
+}
+
+#[async_std::test]
+pub async fn test_response_value_keys_order() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let data = schema
+        .execute("{ obj { b c a } }")
+        .await
+        .into_result()
+        .unwrap()
+        .data;
+    let obj = match data {
+        Value::Object(mut map) => map.shift_remove("obj").unwrap(),
+        _ => unreachable!(),
+    };
+    match obj {
+        Value::Object(map) => {
+            assert_eq!(
+                map.keys().map(Name::as_str).collect::<Vec<_>>(),
+                vec!["b", "c", "a"]
+            );
+        }
+        _ => unreachable!(),
+    }
+}
@@ -0,0 +1,94 @@
+use async_graphql::*;
+
+#[derive(SimpleObject)]
+struct Item {
+    id: i32,
+    name: String,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn items(&self, count: i32) -> Vec<Item> {
+        (0..count)
+            .map(|id| Item {
+                id,
+                name: format!("item{}", id),
+            })
+            .collect()
+    }
+
+    async fn numbers(&self, count: i32) -> Vec<i32> {
+        (0..count).collect()
+    }
+}
+
+fn response_too_large(path: Vec<PathSegment>) -> ServerError {
+    let mut extensions = ErrorExtensionValues::default();
+    extensions.set("code", "RESPONSE_TOO_LARGE");
+    ServerError {
+        message: "The response is too large.".to_string(),
+        locations: Vec::new(),
+        path,
+        extensions: Some(extensions),
+    }
+}
+
+#[async_std::test]
+pub async fn test_limit_response_nodes() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_response_nodes(7)
+        .finish();
+
+    // The field `items`, 2 items and their 2 fields.
+    let res = schema.execute("{ items(count: 2) { id name } }").await;
+    assert_eq!(
+        res.data,
+        value!({
+            "items": [
+                { "id": 0, "name": "item0" },
+                { "id": 1, "name": "item1" },
+            ]
+        })
+    );
+
+    let res = schema.execute("{ numbers(count: 10) }").await;
+    assert_eq!(res.data, Value::Null);
+    assert_eq!(
+        res.errors,
+        vec![response_too_large(vec![
+            PathSegment::Field("numbers".to_string()),
+            PathSegment::Index(7),
+        ])]
+    );
+
+    let res = schema.execute("{ items(count: 3) { id name } }").await;
+    assert_eq!(res.data, Value::Null);
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(res.errors[0].message, "The response is too large.");
+}
+
+#[async_std::test]
+pub async fn test_limit_response_bytes() {
+    let query = "{ items(count: 2) { id name } }";
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let data = schema.execute(query).await.data;
+    let size = serde_json::to_string(&data).unwrap().len();
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_response_bytes(size + 5)
+        .finish();
+    assert_eq!(schema.execute(query).await.data, data);
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_response_bytes(size - 10)
+        .finish();
+    let res = schema.execute(query).await;
+    assert_eq!(res.data, Value::Null);
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].extensions,
+        response_too_large(Vec::new()).extensions
+    );
+}
@@ -0,0 +1,56 @@
+use async_graphql::http::{response_size, write_response, ResponseStream};
+use async_graphql::*;
+
+fn responses() -> Vec<BatchResponse> {
+    let data = value!({
+        "a": [1, 2.5, "three", true, null, [], {}],
+        "b": { "c": "long \"escaped\" string\n", "d": [{ "e": "f" }] },
+    });
+    let errors = vec![ServerError::new("failed")];
+    vec![
+        Response::new(data.clone()).into(),
+        Response::new(Value::Null).into(),
+        Response::from_errors(errors.clone()).into(),
+        Response::new(data.clone())
+            .extensions(Some(value!({ "x": 1 })))
+            .into(),
+        vec![
+            Response::new(data.clone()),
+            Response {
+                errors,
+                ..Response::new(data)
+            },
+        ]
+        .into(),
+        Vec::<Response>::new().into(),
+    ]
+}
+
+#[test]
+pub fn test_response_stream_matches_serde() {
+    for chunk_size in &[1, 7, 64, 100_000] {
+        for response in responses() {
+            let expected = serde_json::to_vec(&response).unwrap();
+            assert_eq!(response_size(&response), expected.len());
+
+            let chunks: Vec<_> = ResponseStream::new(response, *chunk_size).collect();
+            for chunk in &chunks[..chunks.len() - 1] {
+                assert!(chunk.len() >= *chunk_size);
+            }
+            assert_eq!(
+                String::from_utf8(chunks.concat()).unwrap(),
+                String::from_utf8(expected).unwrap()
+            );
+        }
+    }
+}
+
+#[async_std::test]
+pub async fn test_write_response() {
+    for response in responses() {
+        let expected = serde_json::to_vec(&response).unwrap();
+        let mut output = Vec::new();
+        write_response(response, &mut output, 16).await.unwrap();
+        assert_eq!(output, expected);
+    }
+}
@@ -0,0 +1,156 @@
+use async_graphql::runtime::Timer;
+use async_graphql::*;
+use futures_util::future::{FutureExt, LocalBoxFuture};
+use std::cell::Cell;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+fn is_transient(err: &Error) -> bool {
+    err.message == "Unavailable"
+}
+
+#[derive(Default)]
+struct Query {
+    calls: Cell<i32>,
+}
+
+impl Query {
+    fn call(&self, fail_until: i32, message: &str) -> Result<i32> {
+        self.calls.set(self.calls.get() + 1);
+        if self.calls.get() < fail_until {
+            Err(message.into())
+        } else {
+            Ok(self.calls.get())
+        }
+    }
+}
+
+#[Object]
+impl Query {
+    #[graphql(retry(attempts = 3))]
+    async fn flaky(&self, fail_until: i32) -> Result<i32> {
+        self.call(fail_until, "Unavailable")
+    }
+
+    #[graphql(retry(attempts = 3, backoff = "constant", delay = 10))]
+    async fn constant(&self, fail_until: i32) -> Result<i32> {
+        self.call(fail_until, "Unavailable")
+    }
+
+    #[graphql(retry(attempts = 3, delay = 0))]
+    async fn immediate(&self, fail_until: i32) -> Result<i32> {
+        self.call(fail_until, "Unavailable")
+    }
+
+    #[graphql(retry(attempts = 5, predicate = "is_transient"))]
+    async fn invalid(&self, message: String) -> Result<i32> {
+        self.call(i32::MAX, &message)
+    }
+}
+
+/// A timer that records the durations it waits for instead of waiting.
+struct RecordingTimer(Arc<Mutex<Vec<Duration>>>);
+
+impl Timer for RecordingTimer {
+    fn sleep(&self, duration: Duration) -> LocalBoxFuture<'static, ()> {
+        self.0.lock().unwrap().push(duration);
+        async {}.boxed_local()
+    }
+
+    fn now(&self) -> Duration {
+        Duration::default()
+    }
+}
+
+fn schema(sleeps: Arc<Mutex<Vec<Duration>>>) -> Schema<Query, EmptyMutation, EmptySubscription> {
+    Schema::build(Query::default(), EmptyMutation, EmptySubscription)
+        .timer(RecordingTimer(sleeps))
+        .finish()
+}
+
+fn attempts(attempts: i32) -> Option<ErrorExtensionValues> {
+    let mut extensions = ErrorExtensionValues::default();
+    extensions.set("attempts", attempts);
+    Some(extensions)
+}
+
+#[async_std::test]
+pub async fn test_retry() {
+    let sleeps = Arc::new(Mutex::new(Vec::new()));
+    let schema = schema(sleeps.clone());
+
+    assert_eq!(
+        schema
+            .execute("{ flaky(failUntil: 3) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "flaky": 3 })
+    );
+    let delays = sleeps.lock().unwrap().clone();
+    assert_eq!(delays.len(), 2);
+    // Exponential backoff from 100ms with a jitter of up to half of the delay.
+    assert!(delays[0] >= Duration::from_millis(50) && delays[0] <= Duration::from_millis(100));
+    assert!(delays[1] >= Duration::from_millis(100) && delays[1] <= Duration::from_millis(200));
+}
+
+#[async_std::test]
+pub async fn test_retry_exhausted() {
+    let sleeps = Arc::new(Mutex::new(Vec::new()));
+    let schema = schema(sleeps.clone());
+
+    let resp = schema.execute("{ constant(failUntil: 10) }").await;
+    assert_eq!(
+        resp.errors,
+        vec![ServerError {
+            message: "Unavailable".to_string(),
+            locations: vec![Pos { line: 1, column: 3 }],
+            path: vec![PathSegment::Field("constant".to_string())],
+            extensions: attempts(3),
+        }]
+    );
+    for delay in sleeps.lock().unwrap().iter() {
+        assert!(*delay >= Duration::from_millis(5) && *delay <= Duration::from_millis(10));
+    }
+    assert_eq!(sleeps.lock().unwrap().len(), 2);
+}
+
+#[async_std::test]
+pub async fn test_retry_predicate() {
+    let sleeps = Arc::new(Mutex::new(Vec::new()));
+    let schema = schema(sleeps.clone());
+
+    let resp = schema.execute(r#"{ invalid(message: "Invalid") }"#).await;
+    assert_eq!(resp.errors.len(), 1);
+    assert_eq!(resp.errors[0].message, "Invalid");
+    assert_eq!(resp.errors[0].extensions, attempts(1));
+    assert!(sleeps.lock().unwrap().is_empty());
+
+    let resp = schema
+        .execute(r#"{ invalid(message: "Unavailable") }"#)
+        .await;
+    assert_eq!(resp.errors[0].extensions, attempts(5));
+    assert_eq!(sleeps.lock().unwrap().len(), 4);
+}
+
+#[async_std::test]
+pub async fn test_retry_without_timer() {
+    let schema = Schema::new(Query::default(), EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ immediate(failUntil: 3) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "immediate": 3 })
+    );
+}
+
+#[async_std::test]
+#[should_panic(expected = "requires a timer")]
+pub async fn test_retry_delay_requires_timer() {
+    let schema = Schema::new(Query::default(), EmptyMutation, EmptySubscription);
+    schema.execute("{ flaky(failUntil: 3) }").await;
+}
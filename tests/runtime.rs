@@ -0,0 +1,69 @@
+#[cfg(feature = "async-std")]
+#[async_std::test]
+pub async fn test_async_std_runtime() {
+    use async_graphql::runtime::{AsyncStdRuntime, Spawner, Timer};
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    let runtime = AsyncStdRuntime;
+    let start = runtime.now();
+    runtime.sleep(Duration::from_millis(20)).await;
+    assert!(runtime.now() - start >= Duration::from_millis(20));
+
+    let (tx, rx) = async_channel::bounded(1);
+    let ran = Rc::new(Cell::new(false));
+    runtime.spawn(Box::pin({
+        let ran = ran.clone();
+        async move {
+            ran.set(true);
+            tx.send(()).await.unwrap();
+        }
+    }));
+    rx.recv().await.unwrap();
+    assert!(ran.get());
+}
+
+#[cfg(feature = "smol")]
+#[test]
+pub fn test_smol_runtime() {
+    use async_graphql::runtime::{SmolRuntime, Spawner, Timer};
+    use std::time::Duration;
+
+    SmolRuntime::block_on(async {
+        let runtime = SmolRuntime;
+        let start = runtime.now();
+        runtime.sleep(Duration::from_millis(20)).await;
+        assert!(runtime.now() - start >= Duration::from_millis(20));
+
+        let (tx, rx) = async_channel::bounded(1);
+        runtime.spawn(Box::pin(async move {
+            tx.send(()).await.unwrap();
+        }));
+        rx.recv().await.unwrap();
+    });
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+pub fn test_tokio_runtime() {
+    use async_graphql::runtime::{Spawner, Timer, TokioRuntime};
+    use std::time::Duration;
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap();
+    tokio::task::LocalSet::new().block_on(&rt, async {
+        let runtime = TokioRuntime;
+        let start = runtime.now();
+        runtime.sleep(Duration::from_millis(20)).await;
+        assert!(runtime.now() - start >= Duration::from_millis(20));
+
+        let (tx, rx) = async_channel::bounded(1);
+        runtime.spawn(Box::pin(async move {
+            tx.send(()).await.unwrap();
+        }));
+        rx.recv().await.unwrap();
+    });
+}
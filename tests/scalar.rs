@@ -38,3 +38,62 @@ pub async fn test_scalar_macro() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_register_scalar() {
+    use std::net::SocketAddr;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn port(&self, addr: RuntimeScalar<SocketAddr>) -> i32 {
+            addr.port() as i32
+        }
+
+        async fn local(&self) -> RuntimeScalar<SocketAddr> {
+            RuntimeScalar("127.0.0.1:8000".parse().unwrap())
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .register_scalar::<SocketAddr>(
+            "SocketAddr",
+            |value| match &value {
+                Value::String(s) => Ok(s.parse()?),
+                _ => Err(InputValueError::expected_type(value)),
+            },
+            |addr| Value::String(addr.to_string()),
+            |value| matches!(value, Value::String(_)),
+        )
+        .override_description::<RuntimeScalar<SocketAddr>>("An address and a port.")
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute(r#"{ port(addr: "10.0.0.1:443") local __type(name: "SocketAddr") { kind description } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "port": 443,
+            "local": "127.0.0.1:8000",
+            "__type": {
+                "kind": "SCALAR",
+                "description": "An address and a port.",
+            },
+        })
+    );
+
+    let res = schema.execute(r#"{ port(addr: "10.0.0.1") }"#).await;
+    assert!(res.errors[0]
+        .message
+        .starts_with(r#"Failed to parse "SocketAddr""#));
+
+    let res = schema.execute(r#"{ port(addr: 443) }"#).await;
+    assert_eq!(
+        res.errors[0].message,
+        r#"Invalid value for argument "addr", expected type "SocketAddr""#
+    );
+}
@@ -16,3 +16,168 @@ pub async fn test_schema_default() {
 
     let _schema = MySchema::default();
 }
+
+#[async_std::test]
+pub async fn test_operation_selection() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn a(&self) -> i32 {
+            1
+        }
+
+        async fn b(&self) -> i32 {
+            2
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let query = "query A { a } query B { b }";
+
+    assert_eq!(
+        schema
+            .execute(Request::new(query).operation_name("B"))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "b": 2 })
+    );
+
+    let errors = schema.execute(query).await.into_result().unwrap_err();
+    assert_eq!(errors[0].message, "Operation name required in request.");
+    assert_eq!(
+        errors[0].locations,
+        vec![
+            Pos { line: 1, column: 1 },
+            Pos {
+                line: 1,
+                column: 15
+            }
+        ]
+    );
+
+    let errors = schema
+        .execute(Request::new(query).operation_name("C"))
+        .await
+        .into_result()
+        .unwrap_err();
+    assert_eq!(errors[0].message, r#"Unknown operation named "C""#);
+
+    let document = schema.validate(query).unwrap();
+    let mut names = document
+        .operations
+        .iter()
+        .map(|(name, _)| name.unwrap().to_string())
+        .collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(names, vec!["A", "B"]);
+
+    assert!(schema.validate("query A { a } query B { c }").is_err());
+}
+
+#[async_std::test]
+pub async fn test_execute_typed() {
+    #[derive(SimpleObject, serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn scale(&self, factor: i32, offset: Option<i32>) -> Point {
+            let offset = offset.unwrap_or_default();
+            Point {
+                x: factor + offset,
+                y: factor * 2 + offset,
+            }
+        }
+    }
+
+    #[derive(serde::Serialize)]
+    struct Variables {
+        factor: i32,
+        offset: Option<i32>,
+    }
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Data {
+        scale: Point,
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let query =
+        "query($factor: Int!, $offset: Int) { scale(factor: $factor, offset: $offset) { x y } }";
+
+    let data: Data = schema
+        .execute_typed(
+            query,
+            Variables {
+                factor: 2,
+                offset: Some(1),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        data,
+        Data {
+            scale: Point { x: 3, y: 5 }
+        }
+    );
+
+    let errors = schema.execute_typed::<Data>(query, ()).await.unwrap_err();
+    assert_eq!(errors[0].message, "Variable factor is not defined.");
+
+    let errors = schema
+        .execute_typed::<Data>(query, vec![1])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        errors[0].message,
+        "Variables must be serialized to an object."
+    );
+}
+
+#[test]
+pub fn test_validate_static() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn value(&self, a: i32) -> i32 {
+            a
+        }
+    }
+
+    struct MutationRoot;
+
+    #[Object]
+    impl MutationRoot {
+        async fn reset(&self) -> bool {
+            true
+        }
+    }
+
+    type MySchema = Schema<QueryRoot, MutationRoot, EmptySubscription>;
+
+    const QUERY: &str = validate_query!("query Value($a: Int!) { value(a: $a) }");
+    const MUTATION: &str = validate_query!("mutation { reset }");
+    assert!(MySchema::validate_static(QUERY).is_ok());
+    assert!(MySchema::validate_static(MUTATION).is_ok());
+
+    let errors =
+        MySchema::validate_static("query Value($a: String!) { value(a: $a) }").unwrap_err();
+    assert_eq!(
+        errors[0].message,
+        r#"Variable "a" of type "String!" used in position expecting type "Int!""#
+    );
+    assert_eq!(
+        MySchema::validate_static("subscription { value }").unwrap_err()[0].message,
+        r#"Schema is not configured for subscriptions."#
+    );
+}
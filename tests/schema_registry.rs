@@ -0,0 +1,198 @@
+use async_graphql::schema_registry::{
+    ApolloRegistry, CosmoRegistry, HiveRegistry, HttpClient, PublishError, SchemaMetadata,
+};
+use async_graphql::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn value(&self) -> i32 {
+        10
+    }
+}
+
+#[derive(Debug)]
+struct SentRequest {
+    url: String,
+    headers: Vec<(String, String)>,
+    body: serde_json::Value,
+}
+
+#[derive(Clone)]
+struct MockClient {
+    response: Result<String, String>,
+    requests: Rc<RefCell<Vec<SentRequest>>>,
+}
+
+impl MockClient {
+    fn new(response: Result<&str, &str>) -> Self {
+        Self {
+            response: response
+                .map(ToString::to_string)
+                .map_err(ToString::to_string),
+            requests: Default::default(),
+        }
+    }
+
+    fn request(&self) -> SentRequest {
+        self.requests.borrow_mut().remove(0)
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl HttpClient for MockClient {
+    async fn post(
+        &self,
+        url: &str,
+        headers: &[(&str, String)],
+        body: String,
+    ) -> std::result::Result<String, String> {
+        self.requests.borrow_mut().push(SentRequest {
+            url: url.to_string(),
+            headers: headers
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.clone()))
+                .collect(),
+            body: serde_json::from_str(&body).unwrap(),
+        });
+        self.response.clone()
+    }
+}
+
+fn metadata() -> SchemaMetadata {
+    SchemaMetadata::new("products")
+        .version("1.4.0")
+        .commit("4f2a9c1")
+        .url("http://products:8000/graphql")
+}
+
+#[async_std::test]
+pub async fn test_hive_registry() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let client = MockClient::new(Ok(
+        r#"{ "data": { "schemaPublish": { "__typename": "SchemaPublishSuccess" } } }"#,
+    ));
+    let registry = HiveRegistry::new(client.clone(), "token");
+    assert_eq!(schema.publish(&registry, &metadata()).await, Ok(()));
+
+    let request = client.request();
+    assert_eq!(request.url, "https://app.graphql-hive.com/graphql");
+    assert!(request
+        .headers
+        .contains(&("authorization".to_string(), "Bearer token".to_string())));
+    assert_eq!(
+        request.body["variables"]["input"],
+        serde_json::json!({
+            "sdl": schema.sdl(),
+            "author": "products",
+            "commit": "1.4.0",
+            "service": "products",
+            "url": "http://products:8000/graphql",
+        })
+    );
+
+    let client = MockClient::new(Ok(r#"{ "data": { "schemaPublish": {
+        "__typename": "SchemaPublishError",
+        "errors": { "nodes": [{ "message": "Field \"value\" was removed" }] }
+    } } }"#));
+    let registry = HiveRegistry::new(client, "token").endpoint("http://hive/graphql");
+    assert_eq!(
+        schema.publish(&registry, &metadata()).await,
+        Err(PublishError::Rejected(vec![
+            "Field \"value\" was removed".to_string()
+        ]))
+    );
+}
+
+#[async_std::test]
+pub async fn test_apollo_registry() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let client = MockClient::new(Ok(
+        r#"{ "data": { "graph": { "publishSubgraph": { "errors": [] } } } }"#,
+    ));
+    let registry = ApolloRegistry::new(client.clone(), "key", "shop@staging");
+    assert_eq!(schema.publish(&registry, &metadata()).await, Ok(()));
+
+    let request = client.request();
+    assert_eq!(request.url, "https://api.apollographql.com/api/graphql");
+    assert!(request
+        .headers
+        .contains(&("x-api-key".to_string(), "key".to_string())));
+    assert_eq!(
+        request.body["variables"],
+        serde_json::json!({
+            "graphId": "shop",
+            "variant": "staging",
+            "name": "products",
+            "url": "http://products:8000/graphql",
+            "revision": "1.4.0",
+            "sdl": schema.sdl(),
+        })
+    );
+
+    let client = MockClient::new(Ok(r#"{ "data": { "graph": null } }"#));
+    let registry = ApolloRegistry::new(client, "key", "shop");
+    assert_eq!(
+        schema.publish(&registry, &metadata()).await,
+        Err(PublishError::InvalidResponse(
+            "Unknown graph \"shop\".".to_string()
+        ))
+    );
+
+    let client = MockClient::new(Ok(r#"{ "errors": [{ "message": "Invalid API key" }] }"#));
+    let registry = ApolloRegistry::new(client, "key", "shop");
+    assert_eq!(
+        schema.publish(&registry, &metadata()).await,
+        Err(PublishError::Rejected(vec!["Invalid API key".to_string()]))
+    );
+}
+
+#[async_std::test]
+pub async fn test_cosmo_registry() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let client = MockClient::new(Ok(r#"{ "response": {} }"#));
+    let registry = CosmoRegistry::new(client.clone(), "key").namespace("prod");
+    assert_eq!(schema.publish(&registry, &metadata()).await, Ok(()));
+
+    let request = client.request();
+    assert_eq!(
+        request.url,
+        "https://cosmo-cp.wundergraph.com/wg.cosmo.platform.v1.PlatformService/PublishFederatedSubgraph"
+    );
+    assert_eq!(
+        request.body,
+        serde_json::json!({
+            "name": "products",
+            "namespace": "prod",
+            "schema": schema.sdl(),
+            "routingUrl": "http://products:8000/graphql",
+        })
+    );
+
+    let client = MockClient::new(Ok(
+        r#"{ "response": { "code": "ERR_SUBGRAPH_COMPOSITION_FAILED" },
+             "compositionErrors": [{ "message": "Type \"Query\" is invalid" }] }"#,
+    ));
+    let registry = CosmoRegistry::new(client, "key");
+    assert_eq!(
+        schema.publish(&registry, &metadata()).await,
+        Err(PublishError::Rejected(vec![
+            "Type \"Query\" is invalid".to_string()
+        ]))
+    );
+
+    let client = MockClient::new(Err("503 Service Unavailable"));
+    let registry = CosmoRegistry::new(client, "key");
+    assert_eq!(
+        schema.publish(&registry, &metadata()).await,
+        Err(PublishError::Transport(
+            "503 Service Unavailable".to_string()
+        ))
+    );
+}
@@ -0,0 +1,191 @@
+use async_graphql::*;
+
+/// An account
+#[derive(SimpleObject)]
+#[graphql(cache_control(max_age = 60))]
+struct Account {
+    id: i32,
+    /// The name of the account
+    #[graphql(meta(key = "tag", value = "public"))]
+    name: String,
+}
+
+#[derive(Interface)]
+#[graphql(field(name = "id", type = "&i32"))]
+enum Node {
+    Account(Account),
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+enum Role {
+    User,
+    #[graphql(deprecation = "Use User")]
+    Admin,
+}
+
+#[derive(InputObject)]
+struct AccountFilter {
+    role: Option<Role>,
+    #[graphql(default = 10)]
+    limit: i32,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    /// Find accounts
+    async fn accounts(
+        &self,
+        filter: AccountFilter,
+        #[graphql(default)] offset: i32,
+    ) -> Vec<Account> {
+        let _ = (filter, offset);
+        Vec::new()
+    }
+
+    #[graphql(deprecation = "Use accounts")]
+    async fn node(&self) -> Option<Node> {
+        None
+    }
+}
+
+#[test]
+pub fn test_schema_view() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let registry = schema.registry();
+
+    let query = registry.query_type();
+    assert_eq!(query.name(), "Query");
+    assert_eq!(query.kind(), TypeKind::Object);
+    assert!(registry.mutation_type().is_none());
+    assert!(registry.subscription_type().is_none());
+
+    let accounts = query.field_by_name("accounts").unwrap();
+    assert_eq!(accounts.description(), Some("Find accounts"));
+    assert_eq!(accounts.type_ref(), "[Account!]!");
+    assert_eq!(accounts.type_name(), "Account");
+    assert!(!accounts.is_deprecated());
+    assert_eq!(
+        accounts
+            .args()
+            .map(|arg| (arg.name(), arg.type_ref(), arg.default_value()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("filter", "AccountFilter!", None),
+            ("offset", "Int!", Some("0")),
+        ]
+    );
+    let node = query.field_by_name("node").unwrap();
+    assert_eq!(node.deprecation_reason(), Some("Use accounts"));
+
+    let account = registry.type_by_name("Account").unwrap();
+    assert_eq!(account.description(), Some("An account"));
+    assert_eq!(
+        account.cache_control(),
+        Some(CacheControl {
+            public: true,
+            max_age: 60
+        })
+    );
+    assert_eq!(account.interfaces(), vec!["Node"]);
+    let name = account.field_by_name("name").unwrap();
+    assert_eq!(name.description(), Some("The name of the account"));
+    assert_eq!(name.metadata(), &[("tag", "public")]);
+
+    let node = registry.type_by_name("Node").unwrap();
+    assert_eq!(node.kind(), TypeKind::Interface);
+    assert_eq!(node.possible_types().collect::<Vec<_>>(), vec!["Account"]);
+    assert_eq!(
+        node.fields().map(|field| field.name()).collect::<Vec<_>>(),
+        vec!["id"]
+    );
+
+    let role = registry.type_by_name("Role").unwrap();
+    assert_eq!(
+        role.enum_values()
+            .map(|value| (value.name(), value.deprecation_reason()))
+            .collect::<Vec<_>>(),
+        vec![("USER", None), ("ADMIN", Some("Use User"))]
+    );
+    assert_eq!(role.fields().count(), 0);
+
+    let filter = registry.type_by_name("AccountFilter").unwrap();
+    assert_eq!(
+        filter
+            .input_fields()
+            .map(|field| (field.name(), field.type_ref(), field.default_value()))
+            .collect::<Vec<_>>(),
+        vec![("role", "Role", None), ("limit", "Int!", Some("10"))]
+    );
+
+    let types: Vec<_> = registry
+        .types_iter()
+        .map(|ty| ty.name())
+        .filter(|name| !name.starts_with("__"))
+        .collect();
+    for name in &[
+        "Query",
+        "Account",
+        "Node",
+        "Role",
+        "AccountFilter",
+        "Int",
+        "String",
+    ] {
+        assert!(types.contains(name), "{} is missing", name);
+    }
+
+    let directives: Vec<_> = registry
+        .directives_iter()
+        .map(|directive| directive.name())
+        .collect();
+    assert_eq!(directives, vec!["ifdef", "include", "skip", "stream"]);
+}
+
+#[test]
+pub fn test_lookup_coordinate() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let registry = schema.registry();
+
+    let name = |coordinate: &str| match registry.lookup_coordinate(coordinate) {
+        Some(SchemaMember::Type(ty)) => Some(ty.name()),
+        Some(SchemaMember::Field(field)) => Some(field.name()),
+        Some(SchemaMember::Argument(arg)) => Some(arg.name()),
+        Some(SchemaMember::InputField(field)) => Some(field.name()),
+        Some(SchemaMember::EnumValue(value)) => Some(value.name()),
+        Some(SchemaMember::Directive(directive)) => Some(directive.name()),
+        Some(SchemaMember::DirectiveArgument(arg)) => Some(arg.name()),
+        None => None,
+    };
+
+    assert_eq!(name("Account"), Some("Account"));
+    assert_eq!(name("Account.name"), Some("name"));
+    assert_eq!(name("Node.id"), Some("id"));
+    assert_eq!(name("Query.accounts(offset:)"), Some("offset"));
+    assert_eq!(name("AccountFilter.limit"), Some("limit"));
+    assert_eq!(name("Role.ADMIN"), Some("ADMIN"));
+    assert_eq!(name("@skip"), Some("skip"));
+    assert_eq!(name("@skip(if:)"), Some("if"));
+
+    assert_eq!(name("Unknown"), None);
+    assert_eq!(name("Account.email"), None);
+    assert_eq!(name("Query.accounts(limit:)"), None);
+    assert_eq!(name("Int.value"), None);
+    assert_eq!(name("@unknown"), None);
+    assert_eq!(name("@skip(unless:)"), None);
+    assert_eq!(name("Account.name.length"), None);
+
+    match registry.lookup_coordinate("Role.ADMIN") {
+        Some(SchemaMember::EnumValue(value)) => {
+            assert_eq!(value.deprecation_reason(), Some("Use User"))
+        }
+        _ => panic!("expected an enum value"),
+    }
+
+    let coordinate: SchemaCoordinate = "AccountFilter.role".parse().unwrap();
+    assert!(matches!(
+        registry.lookup(&coordinate),
+        Some(SchemaMember::InputField(field)) if field.type_ref() == "Role"
+    ));
+}
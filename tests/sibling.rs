@@ -0,0 +1,116 @@
+use async_graphql::*;
+use std::cell::Cell;
+
+#[async_std::test]
+pub async fn test_sibling() {
+    #[derive(Default)]
+    struct Order {
+        subtotal_calls: Cell<i32>,
+    }
+
+    #[Object]
+    impl Order {
+        async fn subtotal(&self) -> i32 {
+            self.subtotal_calls.set(self.subtotal_calls.get() + 1);
+            100
+        }
+
+        async fn tax(&self, #[graphql(sibling = "subtotal")] subtotal: i32) -> i32 {
+            subtotal / 5
+        }
+
+        async fn total(
+            &self,
+            #[graphql(sibling = "subtotal")] subtotal: i32,
+            #[graphql(sibling = "tax")] tax: i32,
+            discount: i32,
+        ) -> i32 {
+            subtotal + tax - discount
+        }
+
+        async fn subtotal_calls(&self) -> i32 {
+            self.subtotal_calls.get()
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn orders(&self) -> Vec<Order> {
+            vec![Order::default(), Order::default()]
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let query = "{ orders { total(discount: 10) tax subtotal } }";
+    let resp = schema.execute(query).await.into_result().unwrap();
+    assert_eq!(
+        resp.data,
+        value!({
+            "orders": [
+                { "total": 110, "tax": 20, "subtotal": 100 },
+                { "total": 110, "tax": 20, "subtotal": 100 },
+            ]
+        })
+    );
+
+    // The resolver is called once per object, whether the field is selected or not.
+    let query = "{ orders { total(discount: 0) ... on Order { tax } } }";
+    let resp = schema.execute(query).await.into_result().unwrap();
+    assert_eq!(
+        resp.data,
+        value!({ "orders": [{ "total": 120, "tax": 20 }, { "total": 120, "tax": 20 }] })
+    );
+
+    struct Counted(Order);
+
+    #[Object]
+    impl Counted {
+        async fn order(&self) -> &Order {
+            &self.0
+        }
+    }
+
+    let order = Order::default();
+    let schema = Schema::new(Counted(order), EmptyMutation, EmptySubscription);
+    let query = "{ order { total(discount: 0) tax subtotal } }";
+    schema.execute(query).await.into_result().unwrap();
+    let query = "{ order { subtotalCalls } }";
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({ "order": { "subtotalCalls": 1 } })
+    );
+}
+
+#[async_std::test]
+pub async fn test_sibling_error() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn rate(&self) -> Result<i32> {
+            Err("Rate is unavailable".into())
+        }
+
+        async fn price(&self, #[graphql(sibling = "rate")] rate: i32) -> i32 {
+            rate * 10
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let errors = schema.execute("{ price }").await.into_result().unwrap_err();
+    assert_eq!(
+        errors,
+        vec![ServerError {
+            message: "Rate is unavailable".to_string(),
+            locations: vec![Pos { line: 1, column: 3 }],
+            path: vec![PathSegment::Field("price".to_owned())],
+            extensions: None,
+        }]
+    );
+
+    let sdl = schema.sdl();
+    assert!(sdl.contains("price: Int!"));
+}
@@ -0,0 +1,67 @@
+use async_graphql::*;
+use async_std::sync::{Mutex, RwLock};
+use std::cell::OnceCell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[async_std::test]
+pub async fn test_simple_object_wrapped_fields() {
+    #[derive(SimpleObject, Clone)]
+    struct Item {
+        value: i32,
+    }
+
+    #[derive(SimpleObject)]
+    struct Query {
+        arc: Arc<String>,
+        rc: Rc<Item>,
+        boxed: Box<Vec<i32>>,
+        #[graphql(owned)]
+        owned_arc: Arc<i32>,
+        mutex: Mutex<Item>,
+        rw_lock: RwLock<Vec<String>>,
+        once_cell: OnceCell<i32>,
+        empty_once_cell: OnceCell<Item>,
+    }
+
+    let query = Query {
+        arc: Arc::new("abc".to_string()),
+        rc: Rc::new(Item { value: 1 }),
+        boxed: Box::new(vec![1, 2]),
+        owned_arc: Arc::new(3),
+        mutex: Mutex::new(Item { value: 4 }),
+        rw_lock: RwLock::new(vec!["a".to_string()]),
+        once_cell: OnceCell::new(),
+        empty_once_cell: OnceCell::new(),
+    };
+    query.once_cell.set(5).unwrap();
+    query.rw_lock.write().await.push("b".to_string());
+
+    let schema = Schema::new(query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                "{ arc rc { value } boxed ownedArc mutex { value } rwLock onceCell emptyOnceCell { value } }"
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "arc": "abc",
+            "rc": { "value": 1 },
+            "boxed": [1, 2],
+            "ownedArc": 3,
+            "mutex": { "value": 4 },
+            "rwLock": ["a", "b"],
+            "onceCell": 5,
+            "emptyOnceCell": null,
+        })
+    );
+
+    let sdl = schema.sdl();
+    assert!(sdl.contains("arc: String!"));
+    assert!(sdl.contains("mutex: Item!"));
+    assert!(sdl.contains("rwLock: [String!]!"));
+    assert!(sdl.contains("onceCell: Int\n"));
+}
@@ -0,0 +1,118 @@
+use async_graphql::*;
+
+#[derive(SimpleObject)]
+struct MyObj {
+    id: i32,
+}
+
+#[derive(Interface)]
+#[graphql(field(name = "id", type = "&i32"))]
+enum Node {
+    MyObj(MyObj),
+}
+
+struct Rfc3339(String);
+
+#[Scalar(specified_by_url = "https://tools.ietf.org/html/rfc3339")]
+impl ScalarType for Rfc3339 {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(s) => Ok(Rfc3339(s)),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.0.clone())
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn node(&self) -> Node {
+        MyObj { id: 1 }.into()
+    }
+
+    async fn date(&self) -> Option<Rfc3339> {
+        None
+    }
+}
+
+fn schema(spec_version: SpecVersion) -> Schema<Query, EmptyMutation, EmptySubscription> {
+    Schema::build(Query, EmptyMutation, EmptySubscription)
+        .spec_version(spec_version)
+        .finish()
+}
+
+#[async_std::test]
+pub async fn test_spec_version_june_2018() {
+    let schema = schema(SpecVersion::June2018);
+
+    assert!(schema
+        .execute(r#"{ __type(name: "Rfc3339") { specifiedByURL } }"#)
+        .await
+        .is_err());
+    assert!(schema
+        .execute("{ __schema { directives { isRepeatable } } }")
+        .await
+        .is_err());
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Node") { interfaces { name } } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "__type": { "interfaces": null } })
+    );
+    assert!(schema.sdl().contains("scalar Rfc3339\n"));
+    assert!(schema
+        .execute("{ node @skip(if: false) @skip(if: true) { id } }")
+        .await
+        .is_err());
+}
+
+#[async_std::test]
+pub async fn test_spec_version_october_2021() {
+    let schema = schema(SpecVersion::October2021);
+
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    date: __type(name: "Rfc3339") { specifiedByURL }
+                    int: __type(name: "Int") { specifiedByURL }
+                    node: __type(name: "Node") { interfaces { name } }
+                    myObj: __type(name: "MyObj") { interfaces { name } }
+                    __schema { directives { name isRepeatable } }
+                }"#
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "date": { "specifiedByURL": "https://tools.ietf.org/html/rfc3339" },
+            "int": { "specifiedByURL": null },
+            "node": { "interfaces": [] },
+            "myObj": { "interfaces": [{ "name": "Node" }] },
+            "__schema": {
+                "directives": [
+                    { "name": "ifdef", "isRepeatable": false },
+                    { "name": "include", "isRepeatable": false },
+                    { "name": "skip", "isRepeatable": false },
+                    { "name": "stream", "isRepeatable": false },
+                ]
+            }
+        })
+    );
+    assert!(schema
+        .sdl()
+        .contains(r#"scalar Rfc3339 @specifiedBy(url: "https://tools.ietf.org/html/rfc3339")"#));
+    assert!(schema
+        .execute("{ node @skip(if: false) @skip(if: true) { id } }")
+        .await
+        .is_err());
+}
@@ -1,4 +1,5 @@
 use async_graphql::*;
+use futures_util::future::{FutureExt, LocalBoxFuture};
 use futures_util::stream::{Stream, StreamExt, TryStreamExt};
 
 struct QueryRoot;
@@ -37,7 +38,7 @@ pub async fn test_subscription() {
         let mut stream = schema
             .execute_stream("subscription { values(start: 10, end: 20) }")
             .map(|resp| resp.into_result().unwrap().data)
-            .boxed();
+            .boxed_local();
         for i in 10..20 {
             assert_eq!(value!({ "values": i }), stream.next().await.unwrap());
         }
@@ -48,7 +49,7 @@ pub async fn test_subscription() {
         let mut stream = schema
             .execute_stream("subscription { events(start: 10, end: 20) { a b } }")
             .map(|resp| resp.into_result().unwrap().data)
-            .boxed();
+            .boxed_local();
         for i in 10..20 {
             assert_eq!(
                 value!({ "events": {"a": i, "b": i * 10} }),
@@ -99,7 +100,7 @@ pub async fn test_subscription_with_ctx_data() {
         let mut stream = schema
             .execute_stream(Request::new("subscription { values objects { value } }").data(100i32))
             .map(|resp| resp.data)
-            .boxed();
+            .boxed_local();
         assert_eq!(value!({ "values": 100 }), stream.next().await.unwrap());
         assert_eq!(
             value!({ "objects": { "value": 100 } }),
@@ -142,7 +143,7 @@ pub async fn test_subscription_with_token() {
                 Request::new("subscription { values }").data(Token("123456".to_string())),
             )
             .map(|resp| resp.into_result().unwrap().data)
-            .boxed();
+            .boxed_local();
         assert_eq!(value!({ "values": 100 }), stream.next().await.unwrap());
         assert!(stream.next().await.is_none());
     }
@@ -152,7 +153,7 @@ pub async fn test_subscription_with_token() {
             .execute_stream(
                 Request::new("subscription { values }").data(Token("654321".to_string()))
             )
-            .boxed()
+            .boxed_local()
             .next()
             .await
             .unwrap()
@@ -201,7 +202,7 @@ pub async fn test_subscription_inline_fragment() {
             "#,
         )
         .map(|resp| resp.data)
-        .boxed();
+        .boxed_local();
     for i in 10..20 {
         assert_eq!(
             value!({ "events": {"a": i, "b": i * 10} }),
@@ -251,7 +252,7 @@ pub async fn test_subscription_fragment() {
             "#,
         )
         .map(|resp| resp.data)
-        .boxed();
+        .boxed_local();
     for i in 10i32..20 {
         assert_eq!(
             value!({ "events": {"a": i, "b": i * 10} }),
@@ -302,7 +303,7 @@ pub async fn test_subscription_fragment2() {
             "#,
         )
         .map(|resp| resp.data)
-        .boxed();
+        .boxed_local();
     for i in 10..20 {
         assert_eq!(
             value!({ "events": {"a": i, "b": i * 10} }),
@@ -343,7 +344,7 @@ pub async fn test_subscription_error() {
         .execute_stream("subscription { events { value } }")
         .map(|resp| resp.into_result())
         .map_ok(|resp| resp.data)
-        .boxed();
+        .boxed_local();
     for i in 0i32..5 {
         assert_eq!(
             value!({ "events": { "value": i } }),
@@ -389,7 +390,7 @@ pub async fn test_subscription_fieldresult() {
         .execute_stream("subscription { values }")
         .map(|resp| resp.into_result())
         .map_ok(|resp| resp.data)
-        .boxed();
+        .boxed_local();
     for i in 0i32..5 {
         assert_eq!(
             value!({ "values": i }),
@@ -411,3 +412,249 @@ pub async fn test_subscription_fieldresult() {
 
     assert!(stream.next().await.is_none());
 }
+
+#[async_std::test]
+pub async fn test_shared_subscription() {
+    use futures_util::FutureExt;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use async_graphql::broker::{self, MemoryBroker};
+
+    #[derive(Default, Clone)]
+    struct Executions(Rc<Cell<usize>>);
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn messages(
+            &self,
+            ctx: &Context<'_>,
+            topic: String,
+        ) -> Result<impl Stream<Item = Result<String>>> {
+            let executions = &ctx.data_unchecked::<Executions>().0;
+            executions.set(executions.get() + 1);
+            broker::subscribe(ctx.data::<MemoryBroker>()?, &topic).await
+        }
+    }
+
+    let broker = MemoryBroker::default();
+    let executions = Executions::default();
+    let schema = Schema::build(QueryRoot, EmptyMutation, SubscriptionRoot)
+        .data(broker.clone())
+        .data(executions.clone())
+        .share_subscriptions_by(|request, _| Some(request.query.clone()))
+        .finish();
+
+    let mut stream1 = schema
+        .execute_stream(r#"subscription { messages(topic: "a") }"#)
+        .boxed_local();
+    let mut stream2 = schema
+        .execute_stream(r#"subscription { messages(topic: "a") }"#)
+        .boxed_local();
+    let mut stream3 = schema
+        .execute_stream(r#"subscription { messages(topic: "b") }"#)
+        .boxed_local();
+    assert!(stream1.next().now_or_never().is_none());
+    assert!(stream2.next().now_or_never().is_none());
+    assert!(stream3.next().now_or_never().is_none());
+    assert_eq!(executions.0.get(), 2);
+
+    broker::publish(&broker, "a", &"hello").await.unwrap();
+    assert_eq!(
+        stream2.next().await.unwrap().data,
+        value!({ "messages": "hello" })
+    );
+    assert_eq!(
+        stream1.next().await.unwrap().data,
+        value!({ "messages": "hello" })
+    );
+
+    // The other stream keeps receiving the responses once the one polling the subscription is
+    // dropped.
+    drop(stream2);
+    broker::publish(&broker, "a", &"world").await.unwrap();
+    assert_eq!(
+        stream1.next().await.unwrap().data,
+        value!({ "messages": "world" })
+    );
+    assert!(stream3.next().now_or_never().is_none());
+    assert_eq!(executions.0.get(), 2);
+
+    // The requests of different users aren't shared when the key includes the user.
+    struct User(&'static str);
+
+    let executions = Executions::default();
+    let schema = Schema::build(QueryRoot, EmptyMutation, SubscriptionRoot)
+        .data(broker.clone())
+        .data(executions.clone())
+        .share_subscriptions_by(|request, data| {
+            let user = data
+                .get(&std::any::TypeId::of::<User>())?
+                .downcast_ref::<User>()?;
+            Some(format!("{}{}", user.0, request.query))
+        })
+        .finish();
+    let query = r#"subscription { messages(topic: "a") }"#;
+    let mut streams = vec![
+        schema.execute_stream(Request::new(query).data(User("alice"))),
+        schema.execute_stream(Request::new(query).data(User("alice"))),
+        schema.execute_stream(Request::new(query).data(User("bob"))),
+        schema.execute_stream(Request::new(query)),
+    ];
+    for stream in &mut streams {
+        assert!(stream.next().now_or_never().is_none());
+    }
+    assert_eq!(executions.0.get(), 3);
+}
+
+/// A timer that fires when a tick is sent.
+struct TickTimer(async_channel::Receiver<()>);
+
+impl runtime::Timer for TickTimer {
+    fn sleep(&self, _duration: std::time::Duration) -> LocalBoxFuture<'static, ()> {
+        let ticks = self.0.clone();
+        async move {
+            ticks.recv().await.ok();
+        }
+        .boxed_local()
+    }
+
+    fn now(&self) -> std::time::Duration {
+        std::time::Duration::default()
+    }
+}
+
+#[async_std::test]
+pub async fn test_subscription_stream_combinators() {
+    use std::time::Duration;
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn debounced<'a>(&self, ctx: &'a Context<'a>) -> impl Stream<Item = i32> + 'a {
+            ctx.data_unchecked::<async_channel::Receiver<i32>>()
+                .clone()
+                .debounce(ctx, Duration::from_secs(1))
+        }
+
+        async fn throttled<'a>(&self, ctx: &'a Context<'a>) -> impl Stream<Item = i32> + 'a {
+            ctx.data_unchecked::<async_channel::Receiver<i32>>()
+                .clone()
+                .throttle(ctx, Duration::from_secs(1))
+        }
+
+        async fn batched<'a>(&self, ctx: &'a Context<'a>) -> impl Stream<Item = Vec<i32>> + 'a {
+            ctx.data_unchecked::<async_channel::Receiver<i32>>()
+                .clone()
+                .batch_within(ctx, Duration::from_secs(1), 3)
+        }
+    }
+
+    // The timers only fire when a tick is sent.
+    let (ticks_tx, ticks_rx) = async_channel::unbounded::<()>();
+    let execute = |query: &str| {
+        let (events_tx, events_rx) = async_channel::unbounded::<i32>();
+        let schema = Schema::build(QueryRoot, EmptyMutation, SubscriptionRoot)
+            .data(events_rx)
+            .timer(TickTimer(ticks_rx.clone()))
+            .finish();
+        let stream = schema
+            .execute_stream(query.to_string())
+            .map(|resp| resp.into_result().unwrap().data)
+            .boxed_local();
+        (events_tx, stream)
+    };
+
+    let (events, mut stream) = execute("subscription { debounced }");
+    events.send(1).await.unwrap();
+    events.send(2).await.unwrap();
+    assert!(stream.next().now_or_never().is_none());
+    ticks_tx.send(()).await.unwrap();
+    assert_eq!(stream.next().await, Some(value!({ "debounced": 2 })));
+    events.send(3).await.unwrap();
+    assert!(stream.next().now_or_never().is_none());
+    events.close();
+    assert_eq!(stream.next().await, Some(value!({ "debounced": 3 })));
+    assert_eq!(stream.next().await, None);
+
+    let (events, mut stream) = execute("subscription { throttled }");
+    events.send(1).await.unwrap();
+    events.send(2).await.unwrap();
+    assert_eq!(stream.next().await, Some(value!({ "throttled": 1 })));
+    assert!(stream.next().now_or_never().is_none());
+    ticks_tx.send(()).await.unwrap();
+    assert!(stream.next().now_or_never().is_none());
+    events.send(3).await.unwrap();
+    assert_eq!(stream.next().await, Some(value!({ "throttled": 3 })));
+
+    let (events, mut stream) = execute("subscription { batched }");
+    events.send(1).await.unwrap();
+    events.send(2).await.unwrap();
+    assert!(stream.next().now_or_never().is_none());
+    ticks_tx.send(()).await.unwrap();
+    assert_eq!(stream.next().await, Some(value!({ "batched": [1, 2] })));
+    for n in 3..=5 {
+        events.send(n).await.unwrap();
+    }
+    assert_eq!(stream.next().await, Some(value!({ "batched": [3, 4, 5] })));
+}
+
+#[async_std::test]
+#[should_panic(expected = "SubscriptionStreamExt::debounce requires a timer")]
+pub async fn test_subscription_stream_combinators_require_timer() {
+    use std::time::Duration;
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn debounced<'a>(&self, ctx: &'a Context<'a>) -> impl Stream<Item = i32> + 'a {
+            futures_util::stream::iter(vec![1, 2]).debounce(ctx, Duration::from_secs(1))
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    schema
+        .execute_stream("subscription { debounced }")
+        .next()
+        .await;
+}
+
+#[async_std::test]
+pub async fn test_subscription_heartbeat() {
+    use std::time::Duration;
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn values<'a>(
+            &self,
+            ctx: &'a Context<'a>,
+        ) -> impl Stream<Item = SubscriptionMessage<i32>> + 'a {
+            ctx.data_unchecked::<async_channel::Receiver<i32>>()
+                .clone()
+                .heartbeat(ctx, Duration::from_secs(10))
+        }
+    }
+
+    let (ticks_tx, ticks_rx) = async_channel::unbounded::<()>();
+    let (events_tx, events_rx) = async_channel::unbounded::<i32>();
+    let schema = Schema::build(QueryRoot, EmptyMutation, SubscriptionRoot)
+        .data(events_rx)
+        .timer(TickTimer(ticks_rx))
+        .finish();
+    let mut stream = schema.execute_stream("subscription { values }");
+
+    events_tx.send(1).await.unwrap();
+    assert_eq!(stream.next().await.unwrap().data, value!({ "values": 1 }));
+    ticks_tx.send(()).await.unwrap();
+    assert!(stream.next().await.unwrap().keep_alive);
+    events_tx.send(2).await.unwrap();
+    assert_eq!(stream.next().await.unwrap().data, value!({ "values": 2 }));
+    events_tx.close();
+    assert!(stream.next().await.is_none());
+}
@@ -1,5 +1,26 @@
 use async_graphql::*;
+use futures_util::future::{self, FutureExt, LocalBoxFuture};
 use futures_util::stream::{Stream, StreamExt};
+use std::time::Duration;
+
+/// A timer that fires when a tick is sent, and never once the ticks stop.
+struct TickTimer(async_channel::Receiver<()>);
+
+impl runtime::Timer for TickTimer {
+    fn sleep(&self, _duration: Duration) -> LocalBoxFuture<'static, ()> {
+        let ticks = self.0.clone();
+        async move {
+            if ticks.recv().await.is_err() {
+                future::pending::<()>().await;
+            }
+        }
+        .boxed_local()
+    }
+
+    fn now(&self) -> Duration {
+        Duration::default()
+    }
+}
 
 #[async_std::test]
 pub async fn test_subscription_ws_transport() {
@@ -324,3 +345,452 @@ pub async fn test_query_over_websocket() {
         serde_json::from_str(&stream.next().await.unwrap()).unwrap()
     );
 }
+
+#[async_std::test]
+pub async fn test_websocket_keep_alive() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn value(&self) -> i32 {
+            999
+        }
+    }
+
+    let (tick_tx, tick_rx) = async_channel::unbounded();
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .timer(TickTimer(tick_rx))
+        .finish();
+    let (tx, rx) = async_channel::unbounded();
+    let mut stream = http::WebSocket::new(schema, rx).keep_alive(Duration::from_secs(10));
+
+    tx.send(
+        serde_json::to_string(&value!({
+            "type": "connection_init",
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        Some(value!({
+            "type": "connection_ack",
+        })),
+        serde_json::from_str(&stream.next().await.unwrap()).unwrap()
+    );
+
+    tick_tx.send(()).await.unwrap();
+    assert_eq!(
+        Some(value!({
+            "type": "ka",
+        })),
+        serde_json::from_str(&stream.next().await.unwrap()).unwrap()
+    );
+
+    // The connection is still usable once the ticks stop.
+    drop(tick_tx);
+    tx.send(
+        serde_json::to_string(&value!({
+            "type": "start",
+            "id": "1",
+            "payload": {
+                "query": "query { value }"
+            },
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        Some(value!({
+            "type": "data",
+            "id": "1",
+            "payload": { "data": { "value": 999 } },
+        })),
+        serde_json::from_str(&stream.next().await.unwrap()).unwrap()
+    );
+}
+
+#[async_std::test]
+pub async fn test_subscription_ws_transport_allowed_operations() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn values(&self) -> impl Stream<Item = i32> {
+            futures_util::stream::iter(0..10)
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    let (tx, rx) = async_channel::unbounded();
+    let mut stream = http::WebSocket::new(schema, rx)
+        .allowed_operations(vec![parser::types::OperationType::Subscription]);
+
+    tx.send(
+        serde_json::to_string(&value!({
+            "type": "start",
+            "id": "1",
+            "payload": {
+                "query": "{ value }"
+            },
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap()).unwrap(),
+        serde_json::json!({
+            "type": "data",
+            "id": "1",
+            "payload": {
+                "data": null,
+                "errors": [{
+                    "message": "The query operation is not allowed for this request.",
+                    "locations": [{ "line": 1, "column": 1 }],
+                }],
+            },
+        }),
+    );
+}
+
+#[async_std::test]
+pub async fn test_subscription_ws_transport_resume() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn values<'a>(&self, ctx: &'a Context<'a>) -> impl Stream<Item = i32> + 'a {
+            let after: i32 = ctx
+                .last_event_id()
+                .and_then(|id| id.parse().ok())
+                .unwrap_or(0);
+            futures_util::stream::iter(after + 1..=3)
+                .event_ids(ctx, |n| n.to_string())
+                .start_with(async { 0 })
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    let (tx, rx) = async_channel::unbounded();
+    let mut stream = http::WebSocket::new(schema, rx);
+
+    tx.send(
+        serde_json::to_string(&value!({
+            "type": "connection_init",
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+    stream.next().await.unwrap();
+
+    tx.send(
+        serde_json::to_string(&value!({
+            "type": "start",
+            "id": "1",
+            "payload": {
+                "query": "subscription { values }",
+                "lastEventId": "1",
+            },
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+
+    // The initial value has no event id.
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap()).unwrap(),
+        serde_json::json!({
+            "type": "data",
+            "id": "1",
+            "payload": { "data": { "values": 0 } },
+        }),
+    );
+    for i in 2..=3 {
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap()).unwrap(),
+            serde_json::json!({
+                "type": "data",
+                "id": "1",
+                "eventId": i.to_string(),
+                "payload": { "data": { "values": i } },
+            }),
+        );
+    }
+}
+
+#[async_std::test]
+pub async fn test_subscription_ws_transport_keep_alive_messages() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn report(&self) -> impl Stream<Item = SubscriptionMessage<i32>> {
+            futures_util::stream::iter(vec![
+                SubscriptionMessage::KeepAlive,
+                SubscriptionMessage::Progress(value!({ "percent": 50 })),
+                SubscriptionMessage::Event(1),
+            ])
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    let (tx, rx) = async_channel::unbounded();
+    let mut stream = http::WebSocket::new(schema, rx);
+
+    tx.send(
+        serde_json::to_string(&value!({
+            "type": "connection_init",
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+    stream.next().await.unwrap();
+
+    tx.send(
+        serde_json::to_string(&value!({
+            "type": "start",
+            "id": "1",
+            "payload": {
+                "query": "subscription { report }",
+            },
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap()).unwrap(),
+        serde_json::json!({
+            "type": "ka",
+        }),
+    );
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap()).unwrap(),
+        serde_json::json!({
+            "type": "data",
+            "id": "1",
+            "payload": { "data": null, "extensions": { "progress": { "percent": 50 } } },
+        }),
+    );
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap()).unwrap(),
+        serde_json::json!({
+            "type": "data",
+            "id": "1",
+            "payload": { "data": { "report": 1 } },
+        }),
+    );
+}
+
+#[async_std::test]
+pub async fn test_subscription_graphql_transport_ws() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn values(&self) -> impl Stream<Item = i32> {
+            futures_util::stream::iter(0..2)
+        }
+    }
+
+    assert_eq!(
+        http::WebSocketProtocols::from_sec_websocket_protocol("graphql-transport-ws, graphql-ws"),
+        Some(http::WebSocketProtocols::GraphQLWS)
+    );
+    assert_eq!(
+        http::WebSocketProtocols::from_sec_websocket_protocol("other"),
+        None
+    );
+
+    let (tick_tx, tick_rx) = async_channel::unbounded();
+    tick_tx.send(()).await.unwrap();
+    let schema = Schema::build(QueryRoot, EmptyMutation, SubscriptionRoot)
+        .timer(TickTimer(tick_rx))
+        .finish();
+    let (tx, rx) = async_channel::unbounded();
+    let mut stream = http::WebSocket::new(schema, rx)
+        .protocol(http::WebSocketProtocols::GraphQLWS)
+        .keep_alive(Duration::from_secs(10));
+
+    tx.send(
+        serde_json::to_string(&value!({
+            "type": "connection_init",
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap()).unwrap(),
+        serde_json::json!({
+            "type": "connection_ack",
+        }),
+    );
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap()).unwrap(),
+        serde_json::json!({
+            "type": "ping",
+        }),
+    );
+
+    tx.send(
+        serde_json::to_string(&value!({
+            "type": "ping",
+            "payload": { "a": 1 },
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap()).unwrap(),
+        serde_json::json!({
+            "type": "pong",
+            "payload": { "a": 1 },
+        }),
+    );
+
+    tx.send(
+        serde_json::to_string(&value!({
+            "type": "subscribe",
+            "id": "1",
+            "payload": {
+                "query": "subscription { values }"
+            },
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+    for i in 0..2 {
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap()).unwrap(),
+            serde_json::json!({
+                "type": "next",
+                "id": "1",
+                "payload": { "data": { "values": i } },
+            }),
+        );
+    }
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap()).unwrap(),
+        serde_json::json!({
+            "type": "complete",
+            "id": "1",
+        }),
+    );
+
+    // The messages of the legacy protocol close the connection.
+    tx.send(
+        serde_json::to_string(&value!({
+            "type": "start",
+            "id": "2",
+            "payload": {
+                "query": "subscription { values }"
+            },
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+    assert!(stream.next().await.is_none());
+}
+
+#[async_std::test]
+pub async fn test_subscription_graphql_transport_ws_complete() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        async fn values(&self) -> impl Stream<Item = i32> {
+            futures_util::stream::pending()
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    let (tx, rx) = async_channel::unbounded();
+    let mut stream = http::WebSocket::new(schema, rx).protocol(http::WebSocketProtocols::GraphQLWS);
+
+    for message in vec![
+        value!({ "type": "connection_init" }),
+        value!({
+            "type": "subscribe",
+            "id": "1",
+            "payload": { "query": "subscription { values }" },
+        }),
+        value!({ "type": "complete", "id": "1" }),
+        value!({ "type": "pong", "payload": { "a": 1 } }),
+        value!({ "type": "ping" }),
+    ] {
+        tx.send(serde_json::to_string(&message).unwrap())
+            .await
+            .unwrap();
+    }
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap()).unwrap(),
+        serde_json::json!({
+            "type": "connection_ack",
+        }),
+    );
+    // The subscription is completed without a message from the server.
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap()).unwrap(),
+        serde_json::json!({
+            "type": "pong",
+        }),
+    );
+}
@@ -0,0 +1,111 @@
+use async_graphql::trusted_documents::TrustedDocuments;
+use async_graphql::*;
+use std::cell::RefCell;
+
+#[derive(SimpleObject)]
+struct User {
+    id: i32,
+    name: String,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn user(&self, id: i32) -> User {
+        User {
+            id,
+            name: format!("user{}", id),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Mutation(RefCell<String>);
+
+#[Object]
+impl Mutation {
+    async fn rename(&self, ctx: &Context<'_>, name: String) -> String {
+        let prefix = ctx.data_opt::<&str>().copied().unwrap_or_default();
+        *self.0.borrow_mut() = format!("{}{}", prefix, name);
+        self.0.borrow().clone()
+    }
+}
+
+#[async_std::test]
+pub async fn test_trusted_documents() {
+    let documents = include_trusted_documents!("tests/trusted_documents");
+    let hashes: Vec<_> = documents
+        .hashes()
+        .map(|(name, hash)| (name.to_string(), hash))
+        .collect();
+    assert_eq!(
+        hashes,
+        vec![
+            (
+                "rename.graphql".to_string(),
+                TrustedDocuments::hash(include_str!("trusted_documents/rename.graphql"))
+            ),
+            (
+                "user.graphql".to_string(),
+                TrustedDocuments::hash(include_str!("trusted_documents/user.graphql"))
+            ),
+        ]
+    );
+
+    let schema = Schema::build(Query, Mutation::default(), EmptySubscription)
+        .trusted_documents(documents)
+        .finish();
+
+    let variables = Variables::from_value(value!({ "id": 5 }));
+    let resp = schema.execute_persisted(&hashes[1].1, variables).await;
+    assert_eq!(
+        resp.into_result().unwrap().data,
+        value!({ "user": { "id": 5, "name": "user5" } })
+    );
+
+    let mut data = Data::default();
+    data.insert("new-");
+    let variables = Variables::from_value(value!({ "name": "a" }));
+    let resp = schema
+        .execute_persisted_with_data(&hashes[0].1, variables, data)
+        .await;
+    assert_eq!(
+        resp.into_result().unwrap().data,
+        value!({ "rename": "new-a" })
+    );
+
+    // The variables are checked when the arguments are parsed.
+    let variables = Variables::from_value(value!({ "id": "5" }));
+    let resp = schema.execute_persisted(&hashes[1].1, variables).await;
+    assert!(resp.is_err());
+
+    let resp = schema.execute_persisted("abc", Variables::default()).await;
+    assert_eq!(resp.errors[0].message, r#"Unknown trusted document "abc"."#);
+
+    assert_eq!(
+        TrustedDocuments::hash("abc"),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = r#"Invalid trusted document `invalid.graphql`: Unknown field "unknown" on type "Query"."#
+)]
+pub fn test_invalid_trusted_document() {
+    Schema::build(Query, Mutation::default(), EmptySubscription)
+        .trusted_documents(TrustedDocuments::new().add("invalid.graphql", "{ unknown }"))
+        .finish();
+}
+
+#[test]
+#[should_panic(expected = "Invalid trusted document `two.graphql`")]
+pub fn test_trusted_document_operations() {
+    Schema::build(Query, Mutation::default(), EmptySubscription)
+        .trusted_documents(TrustedDocuments::new().add(
+            "two.graphql",
+            "query A { user(id: 1) { id } } query B { user(id: 2) { id } }",
+        ))
+        .finish();
+}
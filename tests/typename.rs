@@ -0,0 +1,66 @@
+use async_graphql::*;
+
+#[derive(SimpleObject)]
+struct A {
+    a: i32,
+}
+
+#[derive(SimpleObject)]
+struct B {
+    b: i32,
+}
+
+#[derive(Union)]
+enum U {
+    A(A),
+    B(B),
+}
+
+#[derive(Interface)]
+#[graphql(field(name = "a", type = "&i32"))]
+enum I {
+    A(A),
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn u(&self) -> U {
+        A { a: 1 }.into()
+    }
+    async fn us(&self) -> Vec<U> {
+        vec![A { a: 1 }.into(), B { b: 2 }.into()]
+    }
+    async fn i(&self) -> I {
+        A { a: 1 }.into()
+    }
+}
+
+#[async_std::test]
+pub async fn test_typename_aliases_and_fragments() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let res = schema
+        .execute(
+            r#"{
+        __typename
+        t: __typename
+        x: u { t1: __typename __typename ... on A { t2: __typename a } ...F }
+        us { ty: __typename ... on B { ty: __typename b } }
+        i { __typename ... on I { it: __typename } }
+    }
+    fragment F on U { t3: __typename __typename }
+    "#,
+        )
+        .await;
+    assert_eq!(
+        res.into_result().unwrap().data,
+        value!({
+            "__typename": "Query",
+            "t": "Query",
+            "x": { "t1": "A", "__typename": "A", "t2": "A", "a": 1, "t3": "A" },
+            "us": [{ "ty": "A" }, { "ty": "B", "b": 2 }],
+            "i": { "__typename": "A", "it": "A" },
+        })
+    );
+}
@@ -367,3 +367,125 @@ pub async fn test_union_flatten() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_union_from_result() {
+    #[derive(SimpleObject)]
+    struct User {
+        name: String,
+    }
+
+    #[derive(SimpleObject)]
+    struct UserError {
+        message: String,
+    }
+
+    #[derive(Union)]
+    #[graphql(from_result)]
+    enum CreateUserPayload {
+        User(User),
+        UserError(UserError),
+    }
+
+    fn create_user(name: &str) -> std::result::Result<User, UserError> {
+        if name.is_empty() {
+            Err(UserError {
+                message: "the name is empty".to_string(),
+            })
+        } else {
+            Ok(User {
+                name: name.to_string(),
+            })
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn create_user(&self, name: String) -> CreateUserPayload {
+            create_user(&name).into()
+        }
+    }
+
+    let query = r#"{
+            a: createUser(name: "Alice") { __typename ... on User { name } ... on UserError { message } }
+            b: createUser(name: "") { __typename ... on User { name } ... on UserError { message } }
+        }"#;
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({
+            "a": { "__typename": "User", "name": "Alice" },
+            "b": { "__typename": "UserError", "message": "the name is empty" },
+        })
+    );
+}
+
+#[cfg(feature = "either")]
+#[async_std::test]
+pub async fn test_either_union() {
+    use async_graphql::either::Either;
+
+    #[derive(SimpleObject)]
+    struct User {
+        name: String,
+    }
+
+    #[derive(SimpleObject)]
+    struct NotFound {
+        message: String,
+    }
+
+    #[derive(Union)]
+    #[graphql(name = "UserResult", from_either)]
+    enum UserResult {
+        User(User),
+        NotFound(NotFound),
+    }
+
+    fn find_user(id: i32) -> Either<User, NotFound> {
+        if id == 1 {
+            Either::Left(User {
+                name: "Alice".to_string(),
+            })
+        } else {
+            Either::Right(NotFound {
+                message: "no such user".to_string(),
+            })
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn user(&self, id: i32) -> Either<User, NotFound> {
+            find_user(id)
+        }
+
+        async fn named_user(&self, id: i32) -> UserResult {
+            find_user(id).into()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"{
+            a: user(id: 1) { __typename ... on User { name } ... on NotFound { message } }
+            b: user(id: 2) { __typename ... on User { name } ... on NotFound { message } }
+            c: namedUser(id: 2) { __typename ... on NotFound { message } }
+            __type(name: "UserOrNotFound") { kind possibleTypes { name } }
+        }"#;
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({
+            "a": { "__typename": "User", "name": "Alice" },
+            "b": { "__typename": "NotFound", "message": "no such user" },
+            "c": { "__typename": "NotFound", "message": "no such user" },
+            "__type": {
+                "kind": "UNION",
+                "possibleTypes": [{ "name": "User" }, { "name": "NotFound" }],
+            },
+        })
+    );
+}
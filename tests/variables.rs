@@ -130,6 +130,119 @@ pub async fn test_variable_null() {
     );
 }
 
+#[async_std::test]
+pub async fn test_variable_no_value_with_location_default() {
+    #[derive(InputObject)]
+    struct MyInput {
+        #[graphql(default = 5)]
+        value: i32,
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        pub async fn int_val(&self, #[graphql(default = 10)] value: i32) -> i32 {
+            value
+        }
+
+        pub async fn input_val(&self, input: MyInput) -> i32 {
+            input.value
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let query = r#"
+        query QueryWithVariables($intVal: Int) {
+            intVal(value: $intVal)
+            inputVal(input: { value: $intVal })
+        }
+    "#;
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({
+            "intVal": 10,
+            "inputVal": 5,
+        })
+    );
+
+    let query = Request::new(query).variables(Variables::from_value(value!({
+        "intVal": 1,
+    })));
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({
+            "intVal": 1,
+            "inputVal": 1,
+        })
+    );
+
+    // A default value of null doesn't make the variable usable in a non-null position.
+    #[derive(InputObject)]
+    struct RequiredInput {
+        value: i32,
+    }
+
+    struct RequiredQueryRoot;
+
+    #[Object]
+    impl RequiredQueryRoot {
+        pub async fn int_val(&self, value: i32) -> i32 {
+            value
+        }
+
+        pub async fn input_val(&self, input: RequiredInput) -> i32 {
+            input.value
+        }
+    }
+
+    let schema = Schema::new(RequiredQueryRoot, EmptyMutation, EmptySubscription);
+    let resp = schema
+        .execute("query QueryWithVariables($intVal: Int = null) { intVal(value: $intVal) }")
+        .await;
+    assert_eq!(
+        resp.errors[0].message,
+        r#"Variable "intVal" of type "Int" used in position expecting type "Int!""#
+    );
+    let resp = schema
+        .execute("query QueryWithVariables($intVal: Int) { inputVal(input: { value: $intVal }) }")
+        .await;
+    assert_eq!(
+        resp.errors[0].message,
+        r#"Variable "intVal" of type "Int" used in position expecting type "Int!""#
+    );
+    assert!(schema
+        .execute("query QueryWithVariables($intVal: Int = 1) { intVal(value: $intVal) }")
+        .await
+        .is_ok());
+}
+
+#[async_std::test]
+pub async fn test_variable_definition_directives() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        pub async fn int_val(&self, value: Option<i32>) -> Option<i32> {
+            value
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let resp = schema
+        .execute("query($intVal: Int @skip(if: true)) { intVal(value: $intVal) }")
+        .await;
+    assert_eq!(
+        resp.errors[0].message,
+        r#"Directive "skip" may not be used on "VARIABLE_DEFINITION""#
+    );
+
+    let doc = parser::parse_query("query($intVal: Int = 1 @custom(x: 1)) { intVal }").unwrap();
+    let (_, operation) = doc.operations.iter().next().unwrap();
+    let directives = &operation.node.variable_definitions[0].node.directives;
+    assert_eq!(directives[0].node.name.node, "custom");
+}
+
 #[async_std::test]
 pub async fn test_variable_in_input_object() {
     #[derive(InputObject)]
@@ -244,7 +357,7 @@ pub async fn test_variables_enum() {
     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
     let query = Request::new(
         r#"
-            query QueryWithVariables($value1: MyEnum, $value2: MyEnum, $value3: MyEnum) {
+            query QueryWithVariables($value1: MyEnum!, $value2: MyEnum!, $value3: MyEnum!) {
                 a: value(value: $value1)
                 b: value(value: $value2)
                 c: value(value: $value3)
@@ -281,7 +394,7 @@ pub async fn test_variables_json() {
     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
     let query = Request::new(
         r#"
-            query QueryWithVariables($value: JSON) {
+            query QueryWithVariables($value: JSON!) {
                 value(value: $value)
             }
         "#,
@@ -297,3 +410,76 @@ pub async fn test_variables_json() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_variables_from_json() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        pub async fn int_val(&self, value: i32) -> i32 {
+            value
+        }
+
+        pub async fn string_list_val(&self, value: Vec<String>) -> Vec<String> {
+            value
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let query = r#"
+        query QueryWithVariables($intVal: Int!, $stringListVal: [String!]!) {
+            intVal(value: $intVal)
+            stringListVal(value: $stringListVal)
+        }
+    "#;
+
+    let variables = Variables::from_json(serde_json::json!({
+        "intVal": 10,
+        "stringListVal": ["a", "b"],
+    }));
+    assert_eq!(
+        schema
+            .execute(Request::new(query).variables(variables))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "intVal": 10,
+            "stringListVal": ["a", "b"],
+        })
+    );
+
+    let variables = Variables::default()
+        .with("intVal", 20)
+        .with("stringListVal", vec!["c".to_string()]);
+    assert_eq!(
+        schema
+            .execute(Request::new(query).variables(variables))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "intVal": 20,
+            "stringListVal": ["c"],
+        })
+    );
+
+    assert!(Variables::try_from_json(serde_json::Value::Null)
+        .unwrap()
+        .0
+        .is_empty());
+    assert_eq!(
+        Variables::try_from_json(serde_json::json!([1, 2])).unwrap_err(),
+        VariablesError::NotAnObject("a list")
+    );
+    assert_eq!(
+        Variables::try_from_json(serde_json::json!({ "int-val": 10 })).unwrap_err(),
+        VariablesError::InvalidName("int-val".to_string())
+    );
+    assert!(Variables::from_json(serde_json::json!({ "int-val": 10 }))
+        .0
+        .is_empty());
+}
@@ -0,0 +1,114 @@
+use async_graphql::parser::types::OperationType;
+use async_graphql::*;
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn add(&self, a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    async fn value(&self) -> i32 {
+        10
+    }
+}
+
+#[async_std::test]
+pub async fn test_warm_up() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let add = "query Add($a: Int!) { add(a: $a, b: 10) }";
+    let operations = "query A { value } query B { add(a: 1, b: 2) }";
+    schema.warm_up(&[add, operations]).await.unwrap();
+
+    let request = Request::new(add).variables(Variables::from_value(value!({ "a": 5 })));
+    assert_eq!(schema.execute(request).await.data, value!({ "add": 15 }));
+
+    let request = Request::new(add).variables(Variables::from_value(value!({ "a": "5" })));
+    let errors = schema.execute(request).await.errors;
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0]
+        .message
+        .starts_with("Invalid value for argument \"a\""));
+
+    assert_eq!(
+        schema
+            .execute(Request::new(operations).operation_name("B"))
+            .await
+            .data,
+        value!({ "add": 3 })
+    );
+    assert_eq!(
+        schema.execute(operations).await.errors[0].message,
+        "Operation name required in request."
+    );
+}
+
+#[async_std::test]
+pub async fn test_warm_up_invalid() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_depth(1)
+        .finish();
+    let errors = schema
+        .warm_up(&["{ valeu }", "{ value }", "{ __schema { types { name } } }"])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        errors
+            .iter()
+            .map(|err| err.message.as_str())
+            .collect::<Vec<_>>(),
+        vec![
+            r#"Unknown field "valeu" on type "Query"."#,
+            "Query is nested too deep."
+        ]
+    );
+    assert_eq!(
+        schema.execute("{ value }").await.data,
+        value!({ "value": 10 })
+    );
+}
+
+#[async_std::test]
+pub async fn test_warm_up_introspection() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = "query Root { __schema { queryType { name } } }";
+    let expected = schema.execute(query).await;
+    schema.warm_up(&[query]).await.unwrap();
+
+    assert_eq!(schema.execute(query).await.data, expected.data);
+    assert_eq!(
+        schema
+            .execute(Request::new(query).operation_name("Root"))
+            .await
+            .data,
+        expected.data
+    );
+    assert_eq!(
+        schema
+            .execute(Request::new(query).operation_name("Other"))
+            .await
+            .errors[0]
+            .message,
+        r#"Unknown operation named "Other""#
+    );
+    assert_eq!(
+        schema
+            .execute(Request::new(query).allowed_operations(vec![OperationType::Mutation]))
+            .await
+            .errors[0]
+            .message,
+        "The query operation is not allowed for this request."
+    );
+}
+
+#[async_std::test]
+pub async fn test_warm_up_contract() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    schema.warm_up(&["{ value }"]).await.unwrap();
+    let contract = schema.build_contract(TagFilter::new().exclude("internal"));
+    assert_eq!(
+        contract.execute("{ value }").await.data,
+        value!({ "value": 10 })
+    );
+}
@@ -1,7 +1,8 @@
-use std::collections::BTreeMap;
-use std::{fmt, vec};
+use alloc::string::{String, ToString};
+use alloc::vec::{self, Vec};
+use core::fmt;
 
-use crate::{ConstValue, Name};
+use crate::{ConstValue, Map, Name};
 
 use serde::de::{
     self, Deserialize, DeserializeOwned, DeserializeSeed, EnumAccess, Error as DeError,
@@ -19,7 +20,7 @@ impl de::Error for DeserializerError {
     }
 }
 
-impl std::error::Error for DeserializerError {
+impl de::StdError for DeserializerError {
     fn description(&self) -> &str {
         "Value deserializer error"
     }
@@ -49,6 +50,7 @@ impl ConstValue {
             ConstValue::Enum(v) => Unexpected::Str(v),
             ConstValue::List(_) => Unexpected::Seq,
             ConstValue::Object(_) => Unexpected::Map,
+            ConstValue::Raw(_) => Unexpected::Other("raw JSON"),
         }
     }
 }
@@ -71,10 +73,7 @@ where
     }
 }
 
-fn visit_object<'de, V>(
-    object: BTreeMap<Name, ConstValue>,
-    visitor: V,
-) -> Result<V::Value, DeserializerError>
+fn visit_object<'de, V>(object: Map<ConstValue>, visitor: V) -> Result<V::Value, DeserializerError>
 where
     V: Visitor<'de>,
 {
@@ -109,6 +108,7 @@ impl<'de> de::Deserializer<'de> for ConstValue {
             ConstValue::Enum(v) => visitor.visit_str(v.as_str()),
             ConstValue::List(v) => visit_array(v, visitor),
             ConstValue::Object(v) => visit_object(v, visitor),
+            ConstValue::Raw(v) => v.to_value().deserialize_any(visitor),
         }
     }
 
@@ -124,6 +124,7 @@ impl<'de> de::Deserializer<'de> for ConstValue {
     {
         match self {
             ConstValue::Null => visitor.visit_none(),
+            ConstValue::Raw(v) => v.to_value().deserialize_option(visitor),
             _ => visitor.visit_some(self),
         }
     }
@@ -141,8 +142,8 @@ impl<'de> de::Deserializer<'de> for ConstValue {
 
     fn deserialize_enum<V>(
         self,
-        _name: &'static str,
-        _variants: &'static [&'static str],
+        name: &'static str,
+        variants: &'static [&'static str],
         visitor: V,
     ) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
@@ -171,6 +172,7 @@ impl<'de> de::Deserializer<'de> for ConstValue {
             }
             ConstValue::String(variant) => (Name::new(&variant), None),
             ConstValue::Enum(variant) => (variant, None),
+            ConstValue::Raw(v) => return v.to_value().deserialize_enum(name, variants, visitor),
             other => {
                 return Err(DeserializerError::invalid_type(
                     other.unexpected(),
@@ -346,12 +348,12 @@ impl<'de> SeqAccess<'de> for SeqDeserializer {
 }
 
 struct MapDeserializer {
-    iter: <BTreeMap<Name, ConstValue> as IntoIterator>::IntoIter,
+    iter: <Map<ConstValue> as IntoIterator>::IntoIter,
     value: Option<ConstValue>,
 }
 
 impl MapDeserializer {
-    fn new(map: BTreeMap<Name, ConstValue>) -> Self {
+    fn new(map: Map<ConstValue>) -> Self {
         MapDeserializer {
             iter: map.into_iter(),
             value: None,
@@ -474,7 +476,75 @@ impl<'de> de::Deserializer<'de> for NameDeserializer {
     }
 }
 
+/// A deserializer that reads from a `ConstValue`.
+///
+/// This is what [`from_value`](fn.from_value.html) uses, it can be passed to any
+/// `Deserialize::deserialize` implementation directly.
+///
+/// ```
+/// use async_graphql_value::{value, ValueDeserializer};
+/// use serde::Deserialize;
+///
+/// let n = Vec::<i32>::deserialize(ValueDeserializer::new(value!([1, 2]))).unwrap();
+/// assert_eq!(n, vec![1, 2]);
+/// ```
+pub struct ValueDeserializer(ConstValue);
+
+impl ValueDeserializer {
+    /// Create a deserializer for the given value.
+    pub fn new(value: ConstValue) -> Self {
+        Self(value)
+    }
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer {
+    type Error = DeserializerError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.deserialize_any(visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.deserialize_option(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.deserialize_newtype_struct(name, visitor)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.deserialize_enum(name, variants, visitor)
+    }
+}
+
 /// Interpret a `ConstValue` as an instance of type `T`.
 pub fn from_value<T: DeserializeOwned>(value: ConstValue) -> Result<T, DeserializerError> {
-    T::deserialize(value)
+    T::deserialize(ValueDeserializer(value))
 }
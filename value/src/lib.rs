@@ -1,27 +1,46 @@
 //! Value for GraphQL. Used in the [`async-graphql`](https://crates.io/crates/async-graphql) crate.
+//!
+//! Without the default `std` feature the crate only depends on `alloc`.
 
 #![warn(missing_docs)]
 #![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 mod de;
 mod macros;
+mod raw;
 mod ser;
 
-use std::borrow::{Borrow, Cow};
-use std::collections::BTreeMap;
-use std::convert::{TryFrom, TryInto};
-use std::fmt::{self, Display, Formatter, Write};
-use std::iter::FromIterator;
-use std::ops::Deref;
-use std::sync::Arc;
+use alloc::borrow::{Borrow, Cow};
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::convert::{TryFrom, TryInto};
+use core::fmt::{self, Display, Formatter, Write};
+use core::iter::FromIterator;
+use core::ops::Deref;
 
 use serde::ser::Error;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-pub use de::{from_value, DeserializerError};
-pub use ser::{to_value, SerializerError};
+pub use de::{from_value, DeserializerError, ValueDeserializer};
+pub use raw::RawValue;
+pub use ser::{to_value, SerializerError, ValueSerializer};
 pub use serde_json::Number;
 
+#[cfg(feature = "std")]
+type MapHasher = std::collections::hash_map::RandomState;
+#[cfg(not(feature = "std"))]
+type MapHasher = core::hash::BuildHasherDefault<fnv::FnvHasher>;
+
+/// The map of the fields of an object.
+///
+/// The fields keep their insertion order, so the fields of a response are serialized in the
+/// order they were selected in the query.
+pub type Map<V> = indexmap::IndexMap<Name, V, MapHasher>;
+
 /// A GraphQL name.
 ///
 /// [Reference](https://spec.graphql.org/June2018/#Name).
@@ -29,7 +48,7 @@ pub use serde_json::Number;
 pub struct Name(Arc<str>);
 
 impl Serialize for Name {
-    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
         serializer.serialize_str(&self.0)
     }
 }
@@ -135,7 +154,10 @@ pub enum ConstValue {
     /// A list of values.
     List(Vec<ConstValue>),
     /// An object. This is a map of keys to values.
-    Object(BTreeMap<Name, ConstValue>),
+    Object(Map<ConstValue>),
+    /// An already serialized JSON value, compared with the other values by its parsed value.
+    #[serde(skip_deserializing)]
+    Raw(RawValue),
 }
 
 impl PartialEq for ConstValue {
@@ -148,6 +170,9 @@ impl PartialEq for ConstValue {
             (ConstValue::Enum(a), ConstValue::String(b)) => a == b,
             (ConstValue::String(a), ConstValue::Enum(b)) => a == b,
             (ConstValue::Enum(a), ConstValue::Enum(b)) => a == b,
+            (ConstValue::Raw(a), ConstValue::Raw(b)) => a == b,
+            (ConstValue::Raw(a), b) => a.to_value() == *b,
+            (a, ConstValue::Raw(b)) => *a == b.to_value(),
             (ConstValue::List(a), ConstValue::List(b)) => {
                 if a.len() != b.len() {
                     return false;
@@ -249,8 +274,8 @@ impl<T: Into<ConstValue>> From<Vec<T>> for ConstValue {
     }
 }
 
-impl From<BTreeMap<Name, ConstValue>> for ConstValue {
-    fn from(f: BTreeMap<Name, ConstValue>) -> Self {
+impl From<Map<ConstValue>> for ConstValue {
+    fn from(f: Map<ConstValue>) -> Self {
         ConstValue::Object(f)
     }
 }
@@ -273,6 +298,7 @@ impl ConstValue {
                     .map(|(key, value)| (key, value.into_value()))
                     .collect(),
             ),
+            Self::Raw(raw) => raw.to_value().into_value(),
         }
     }
 
@@ -293,6 +319,15 @@ impl ConstValue {
     pub fn from_json(json: serde_json::Value) -> serde_json::Result<Self> {
         json.try_into()
     }
+
+    /// Format the value as a GraphQL literal, e.g. to use it as a default value in SDL.
+    ///
+    /// Enum values are unquoted, strings with several lines are written as block strings when
+    /// that preserves their content, and the items of lists and objects are separated by `, `.
+    #[must_use]
+    pub fn to_graphql_literal(&self) -> String {
+        alloc::format!("{:#}", self)
+    }
 }
 
 impl Default for ConstValue {
@@ -301,10 +336,15 @@ impl Default for ConstValue {
     }
 }
 
+/// With the alternate flag, `{:#}`, the value is formatted as a GraphQL literal, like
+/// [`to_graphql_literal`](#method.to_graphql_literal).
 impl Display for ConstValue {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             Self::Number(num) => write!(f, "{}", *num),
+            Self::String(val) if f.alternate() && is_block_string(val) => {
+                write!(f, "\"\"\"{}\"\"\"", val.replace("\"\"\"", "\\\"\"\""))
+            }
             Self::String(val) => write_quoted(val, f),
             Self::Boolean(true) => f.write_str("true"),
             Self::Boolean(false) => f.write_str("false"),
@@ -312,6 +352,7 @@ impl Display for ConstValue {
             Self::Enum(name) => f.write_str(name),
             Self::List(items) => write_list(items, f),
             Self::Object(map) => write_object(map, f),
+            Self::Raw(raw) => raw.to_value().fmt(f),
         }
     }
 }
@@ -358,7 +399,7 @@ pub enum Value {
     /// A list of values.
     List(Vec<Value>),
     /// An object. This is a map of keys to values.
-    Object(BTreeMap<Name, Value>),
+    Object(Map<Value>),
 }
 
 impl Value {
@@ -467,6 +508,22 @@ fn fail_serialize_variable<S: Serializer>(_: &str, _: S) -> Result<S::Ok, S::Err
     Err(S::Error::custom("cannot serialize variable"))
 }
 
+/// Whether a string can be written as a block string, which would not change its content: it
+/// has several lines, no control characters other than tabs, no blank first or last line, no
+/// indented lines, whose indentation would be removed, and doesn't end with a quote.
+fn is_block_string(s: &str) -> bool {
+    let first_line = s.split('\n').next().unwrap_or_default();
+    let last_line = s.rsplit('\n').next().unwrap_or_default();
+    s.contains('\n')
+        && !s.chars().any(|c| c.is_control() && c != '\n' && c != '\t')
+        && !s.ends_with('"')
+        && !first_line.trim().is_empty()
+        && !last_line.trim().is_empty()
+        && s.split('\n')
+            .skip(1)
+            .all(|line| !line.starts_with(&[' ', '\t'][..]))
+}
+
 fn write_quoted(s: &str, f: &mut Formatter<'_>) -> fmt::Result {
     f.write_char('"')?;
     for c in s.chars() {
@@ -476,27 +533,46 @@ fn write_quoted(s: &str, f: &mut Formatter<'_>) -> fmt::Result {
             '\t' => f.write_str("\\t"),
             '"' => f.write_str("\\\""),
             '\\' => f.write_str("\\\\"),
-            c if c.is_control() => write!(f, "\\u{:04}", c as u32),
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32),
             c => f.write_char(c),
         }?
     }
     f.write_char('"')
 }
+/// Writes the items of a list, each followed by `,`, or separated by `, ` with the alternate flag.
 fn write_list<T: Display>(list: impl IntoIterator<Item = T>, f: &mut Formatter<'_>) -> fmt::Result {
     f.write_char('[')?;
-    for item in list {
+    for (idx, item) in list.into_iter().enumerate() {
+        write_separator(idx, f)?;
         item.fmt(f)?;
-        f.write_char(',')?;
+        write_terminator(f)?;
     }
     f.write_char(']')
 }
+/// Writes the fields of an object like `write_list`.
 fn write_object<K: Display, V: Display>(
     object: impl IntoIterator<Item = (K, V)>,
     f: &mut Formatter<'_>,
 ) -> fmt::Result {
     f.write_char('{')?;
-    for (name, value) in object {
-        write!(f, "{}: {},", name, value)?;
+    for (idx, (name, value)) in object.into_iter().enumerate() {
+        write_separator(idx, f)?;
+        name.fmt(f)?;
+        f.write_str(": ")?;
+        value.fmt(f)?;
+        write_terminator(f)?;
     }
     f.write_char('}')
 }
+fn write_separator(idx: usize, f: &mut Formatter<'_>) -> fmt::Result {
+    if f.alternate() && idx > 0 {
+        f.write_str(", ")?;
+    }
+    Ok(())
+}
+fn write_terminator(f: &mut Formatter<'_>) -> fmt::Result {
+    if !f.alternate() {
+        f.write_char(',')?;
+    }
+    Ok(())
+}
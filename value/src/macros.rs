@@ -1,4 +1,26 @@
-/// Construct a `ConstValue`.
+/// Construct a `ConstValue` with JSON-like syntax.
+///
+/// Any Rust expression implementing `Serialize` can be interpolated as a value, and
+/// parenthesized expressions can be used as keys.
+///
+/// ```
+/// use async_graphql_value::{value, ConstValue, Name};
+///
+/// let key = "b";
+/// let list = vec![1, 2];
+/// let value = value!({ "a": list, (key): null });
+/// assert_eq!(
+///     value,
+///     ConstValue::Object(
+///         vec![
+///             (Name::new("a"), ConstValue::List(vec![1.into(), 2.into()])),
+///             (Name::new("b"), ConstValue::Null),
+///         ]
+///         .into_iter()
+///         .collect()
+///     ),
+/// );
+/// ```
 #[macro_export]
 macro_rules! value {
     ($($json:tt)+) => {
@@ -191,7 +213,7 @@ macro_rules! value_internal {
 
     ({ $($tt:tt)+ }) => {
         $crate::ConstValue::Object({
-            let mut object = std::collections::BTreeMap::new();
+            let mut object = $crate::Map::default();
             $crate::value_internal!(@object object () ($($tt)+) ($($tt)+));
             object
         })
@@ -226,8 +248,7 @@ macro_rules! value_expect_expr_comma {
 
 #[cfg(test)]
 mod tests {
-    use crate::{ConstValue, Name};
-    use std::collections::BTreeMap;
+    use crate::{ConstValue, Map, Name};
 
     #[test]
     fn test_macro() {
@@ -254,7 +275,7 @@ mod tests {
             )
         );
         assert_eq!(value!({"a": 10, "b": true}), {
-            let mut map = BTreeMap::new();
+            let mut map = Map::default();
             map.insert(Name::new("a"), ConstValue::Number(10.into()));
             map.insert(Name::new("b"), ConstValue::Boolean(true));
             ConstValue::Object(map)
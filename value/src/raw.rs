@@ -0,0 +1,59 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::fmt::{self, Debug, Formatter};
+
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::ConstValue;
+
+/// An already serialized JSON value.
+///
+/// It is written as is when the value containing it is serialized with `serde_json`, so a
+/// response can include JSON produced elsewhere, e.g. by a cache or another service, without
+/// deserializing and serializing it again.
+#[derive(Clone)]
+pub struct RawValue(Box<serde_json::value::RawValue>);
+
+impl RawValue {
+    /// Create a raw value from a JSON text.
+    ///
+    /// # Errors
+    ///
+    /// Fails if `json` is not valid JSON.
+    pub fn from_string(json: String) -> serde_json::Result<Self> {
+        serde_json::value::RawValue::from_string(json).map(Self)
+    }
+
+    /// Get the JSON text of this value.
+    #[must_use]
+    pub fn get(&self) -> &str {
+        self.0.get()
+    }
+
+    /// Parse the JSON text of this value.
+    #[must_use]
+    pub fn to_value(&self) -> ConstValue {
+        ConstValue::deserialize(&mut serde_json::Deserializer::from_str(self.get()))
+            .unwrap_or_default()
+    }
+}
+
+impl Debug for RawValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RawValue").field(&self.get()).finish()
+    }
+}
+
+impl PartialEq for RawValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl Eq for RawValue {}
+
+impl Serialize for RawValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
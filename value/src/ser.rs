@@ -1,11 +1,12 @@
-use std::collections::BTreeMap;
-use std::error::Error;
-use std::fmt;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
 
 use serde::ser::{self, Impossible};
 use serde::Serialize;
 
-use crate::{ConstValue, Name, Number};
+use crate::{ConstValue, Map, Name, Number};
 
 /// This type represents errors that can occur when serializing.
 #[derive(Debug)]
@@ -19,7 +20,7 @@ impl fmt::Display for SerializerError {
     }
 }
 
-impl Error for SerializerError {
+impl ser::StdError for SerializerError {
     fn description(&self) -> &str {
         "ConstValue serializer error"
     }
@@ -33,12 +34,23 @@ impl ser::Error for SerializerError {
 
 /// Convert a `T` into `ConstValue` which is an enum that can represent any valid GraphQL data.
 pub fn to_value<T: ser::Serialize>(value: T) -> Result<ConstValue, SerializerError> {
-    value.serialize(Serializer)
+    value.serialize(ValueSerializer)
 }
 
-struct Serializer;
-
-impl ser::Serializer for Serializer {
+/// A serializer whose output is a `ConstValue`.
+///
+/// This is what [`to_value`](fn.to_value.html) uses, it can be passed to any
+/// `Serialize::serialize` implementation directly.
+///
+/// ```
+/// use async_graphql_value::{value, ValueSerializer};
+/// use serde::Serialize;
+///
+/// assert_eq!(vec![1, 2].serialize(ValueSerializer).unwrap(), value!([1, 2]));
+/// ```
+pub struct ValueSerializer;
+
+impl ser::Serializer for ValueSerializer {
     type Ok = ConstValue;
     type Error = SerializerError;
     type SerializeSeq = SerializeSeq;
@@ -158,7 +170,7 @@ impl ser::Serializer for Serializer {
         T: ser::Serialize,
     {
         value.serialize(self).map(|v| {
-            let mut map = BTreeMap::new();
+            let mut map = Map::default();
             map.insert(Name::new(variant), v);
             ConstValue::Object(map)
         })
@@ -195,7 +207,7 @@ impl ser::Serializer for Serializer {
 
     fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
         Ok(SerializeMap {
-            map: BTreeMap::new(),
+            map: Map::default(),
             key: None,
         })
     }
@@ -205,7 +217,7 @@ impl ser::Serializer for Serializer {
         _name: &'static str,
         _len: usize,
     ) -> Result<Self::SerializeStruct, Self::Error> {
-        Ok(SerializeStruct(BTreeMap::new()))
+        Ok(SerializeStruct(Map::default()))
     }
 
     fn serialize_struct_variant(
@@ -215,11 +227,12 @@ impl ser::Serializer for Serializer {
         variant: &'static str,
         _len: usize,
     ) -> Result<Self::SerializeStructVariant, Self::Error> {
-        Ok(SerializeStructVariant(Name::new(variant), BTreeMap::new()))
+        Ok(SerializeStructVariant(Name::new(variant), Map::default()))
     }
 }
 
-struct SerializeSeq(Vec<ConstValue>);
+#[doc(hidden)]
+pub struct SerializeSeq(Vec<ConstValue>);
 
 impl ser::SerializeSeq for SerializeSeq {
     type Ok = ConstValue;
@@ -229,7 +242,7 @@ impl ser::SerializeSeq for SerializeSeq {
     where
         T: ser::Serialize,
     {
-        let value = value.serialize(Serializer)?;
+        let value = value.serialize(ValueSerializer)?;
         self.0.push(value);
         Ok(())
     }
@@ -239,7 +252,8 @@ impl ser::SerializeSeq for SerializeSeq {
     }
 }
 
-struct SerializeTuple(Vec<ConstValue>);
+#[doc(hidden)]
+pub struct SerializeTuple(Vec<ConstValue>);
 
 impl ser::SerializeTuple for SerializeTuple {
     type Ok = ConstValue;
@@ -249,7 +263,7 @@ impl ser::SerializeTuple for SerializeTuple {
     where
         T: ser::Serialize,
     {
-        let value = value.serialize(Serializer)?;
+        let value = value.serialize(ValueSerializer)?;
         self.0.push(value);
         Ok(())
     }
@@ -259,7 +273,8 @@ impl ser::SerializeTuple for SerializeTuple {
     }
 }
 
-struct SerializeTupleStruct(Vec<ConstValue>);
+#[doc(hidden)]
+pub struct SerializeTupleStruct(Vec<ConstValue>);
 
 impl ser::SerializeTupleStruct for SerializeTupleStruct {
     type Ok = ConstValue;
@@ -269,7 +284,7 @@ impl ser::SerializeTupleStruct for SerializeTupleStruct {
     where
         T: ser::Serialize,
     {
-        let value = value.serialize(Serializer)?;
+        let value = value.serialize(ValueSerializer)?;
         self.0.push(value);
         Ok(())
     }
@@ -279,7 +294,8 @@ impl ser::SerializeTupleStruct for SerializeTupleStruct {
     }
 }
 
-struct SerializeTupleVariant(Name, Vec<ConstValue>);
+#[doc(hidden)]
+pub struct SerializeTupleVariant(Name, Vec<ConstValue>);
 
 impl ser::SerializeTupleVariant for SerializeTupleVariant {
     type Ok = ConstValue;
@@ -289,20 +305,21 @@ impl ser::SerializeTupleVariant for SerializeTupleVariant {
     where
         T: ser::Serialize,
     {
-        let value = value.serialize(Serializer)?;
+        let value = value.serialize(ValueSerializer)?;
         self.1.push(value);
         Ok(())
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        let mut map = BTreeMap::new();
+        let mut map = Map::default();
         map.insert(self.0, ConstValue::List(self.1));
         Ok(ConstValue::Object(map))
     }
 }
 
-struct SerializeMap {
-    map: BTreeMap<Name, ConstValue>,
+#[doc(hidden)]
+pub struct SerializeMap {
+    map: Map<ConstValue>,
     key: Option<Name>,
 }
 
@@ -323,7 +340,7 @@ impl ser::SerializeMap for SerializeMap {
     where
         T: ser::Serialize,
     {
-        let value = value.serialize(Serializer)?;
+        let value = value.serialize(ValueSerializer)?;
         self.map.insert(self.key.take().unwrap(), value);
         Ok(())
     }
@@ -333,7 +350,8 @@ impl ser::SerializeMap for SerializeMap {
     }
 }
 
-struct SerializeStruct(BTreeMap<Name, ConstValue>);
+#[doc(hidden)]
+pub struct SerializeStruct(Map<ConstValue>);
 
 impl ser::SerializeStruct for SerializeStruct {
     type Ok = ConstValue;
@@ -348,7 +366,7 @@ impl ser::SerializeStruct for SerializeStruct {
         T: ser::Serialize,
     {
         let key = Name::new(key);
-        let value = value.serialize(Serializer)?;
+        let value = value.serialize(ValueSerializer)?;
         self.0.insert(key, value);
         Ok(())
     }
@@ -358,7 +376,8 @@ impl ser::SerializeStruct for SerializeStruct {
     }
 }
 
-struct SerializeStructVariant(Name, BTreeMap<Name, ConstValue>);
+#[doc(hidden)]
+pub struct SerializeStructVariant(Name, Map<ConstValue>);
 
 impl ser::SerializeStructVariant for SerializeStructVariant {
     type Ok = ConstValue;
@@ -373,13 +392,13 @@ impl ser::SerializeStructVariant for SerializeStructVariant {
         T: ser::Serialize,
     {
         let key = Name::new(key);
-        let value = value.serialize(Serializer)?;
+        let value = value.serialize(ValueSerializer)?;
         self.1.insert(key, value);
         Ok(())
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        let mut map = BTreeMap::new();
+        let mut map = Map::default();
         map.insert(self.0, ConstValue::Object(self.1));
         Ok(ConstValue::Object(map))
     }
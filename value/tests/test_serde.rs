@@ -52,3 +52,36 @@ fn test_serde() {
         b: Some(Enum::B),
     });
 }
+
+#[test]
+fn test_serializer_and_deserializer() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Inner {
+        x: Vec<Option<i32>>,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Outer {
+        name: String,
+        inner: Inner,
+    }
+
+    let outer = Outer {
+        name: "abc".to_string(),
+        inner: Inner {
+            x: vec![Some(1), None],
+        },
+    };
+    let value = outer.serialize(ValueSerializer).unwrap();
+    assert_eq!(
+        value,
+        value!({
+            "name": "abc",
+            "inner": { "x": [1, null] },
+        })
+    );
+    assert_eq!(
+        Outer::deserialize(ValueDeserializer::new(value)).unwrap(),
+        outer
+    );
+}